@@ -0,0 +1,285 @@
+use std::{
+    collections::{BTreeSet, HashMap, HashSet},
+    mem,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+/// Approximate amount of bytes that a set member occupies
+const SET_MEMBER_SIZE: usize = mem::size_of::<u64>();
+
+/// In-process replacement for redis.
+///
+/// Entries with an expiration are removed once they expire or, if the memory
+/// limit is exceeded, in order of their expiration. Entries without
+/// expiration, i.e. discord data, are never evicted.
+pub(crate) struct MemoryCache {
+    inner: Mutex<MemoryStore>,
+}
+
+impl MemoryCache {
+    pub(crate) fn new(max_size: usize) -> Self {
+        let store = MemoryStore {
+            entries: HashMap::new(),
+            sets: HashMap::new(),
+            expirations: BTreeSet::new(),
+            size: 0,
+            max_size,
+        };
+
+        Self {
+            inner: Mutex::new(store),
+        }
+    }
+
+    pub(crate) fn get(&self, key: &[u8]) -> Option<Vec<u8>> {
+        let mut store = self.inner.lock().unwrap();
+        let now = Instant::now();
+
+        let entry = store.entries.get(key)?;
+
+        if entry.is_expired(now) {
+            store.remove_entry(key);
+
+            return None;
+        }
+
+        Some(entry.bytes.clone())
+    }
+
+    pub(crate) fn set(&self, key: &[u8], bytes: &[u8], expire_seconds: Option<usize>) {
+        let mut store = self.inner.lock().unwrap();
+        store.remove_entry(key);
+
+        let key = Box::<[u8]>::from(key);
+
+        let expires_at = expire_seconds.map(|seconds| {
+            let expires_at = Instant::now() + Duration::from_secs(seconds as u64);
+            store.expirations.insert((expires_at, key.clone()));
+
+            expires_at
+        });
+
+        let entry = Entry {
+            bytes: bytes.to_vec(),
+            expires_at,
+        };
+
+        store.size += key.len() + entry.bytes.len();
+        store.entries.insert(key, entry);
+        store.evict();
+    }
+
+    /// Remove the entry and return its bytes if it has not expired yet.
+    pub(crate) fn getdel(&self, key: &[u8]) -> Option<Vec<u8>> {
+        let entry = self.inner.lock().unwrap().remove_entry(key)?;
+
+        (!entry.is_expired(Instant::now())).then_some(entry.bytes)
+    }
+
+    pub(crate) fn del(&self, key: &[u8]) {
+        let mut store = self.inner.lock().unwrap();
+        store.remove_entry(key);
+        store.remove_set(key);
+    }
+
+    pub(crate) fn sadd(&self, key: &[u8], members: &[u64]) -> isize {
+        self.inner.lock().unwrap().sadd(key, members) as isize
+    }
+
+    pub(crate) fn srem(&self, key: &[u8], members: &[u64]) -> isize {
+        self.inner.lock().unwrap().srem(key, members) as isize
+    }
+
+    pub(crate) fn smembers(&self, key: &[u8]) -> Vec<u64> {
+        let store = self.inner.lock().unwrap();
+
+        store
+            .sets
+            .get(key)
+            .map_or_else(Vec::new, |set| set.iter().copied().collect())
+    }
+
+    pub(crate) fn scard(&self, key: &[u8]) -> isize {
+        let store = self.inner.lock().unwrap();
+
+        store.sets.get(key).map_or(0, HashSet::len) as isize
+    }
+
+    pub(crate) fn smove(&self, src: &[u8], dst: &[u8], member: u64) -> bool {
+        // Same as redis, the member is never missing from both sets
+        let mut store = self.inner.lock().unwrap();
+
+        if store.srem(src, &[member]) == 0 {
+            return false;
+        }
+
+        store.sadd(dst, &[member]);
+
+        true
+    }
+
+    pub(crate) fn flush(&self) {
+        let mut store = self.inner.lock().unwrap();
+        store.entries.clear();
+        store.sets.clear();
+        store.expirations.clear();
+        store.size = 0;
+    }
+}
+
+struct MemoryStore {
+    entries: HashMap<Box<[u8]>, Entry>,
+    sets: HashMap<Box<[u8]>, HashSet<u64>>,
+    /// Keys of entries with an expiration, sorted by their expiration
+    expirations: BTreeSet<(Instant, Box<[u8]>)>,
+    /// Approximate amount of occupied bytes
+    size: usize,
+    max_size: usize,
+}
+
+impl MemoryStore {
+    fn sadd(&mut self, key: &[u8], members: &[u64]) -> usize {
+        if !self.sets.contains_key(key) {
+            self.size += key.len();
+        }
+
+        let set = self.sets.entry(Box::from(key)).or_default();
+        let added = members.iter().filter(|&&member| set.insert(member)).count();
+        self.size += added * SET_MEMBER_SIZE;
+        self.evict();
+
+        added
+    }
+
+    fn srem(&mut self, key: &[u8], members: &[u64]) -> usize {
+        let Some(set) = self.sets.get_mut(key) else {
+            return 0;
+        };
+
+        let removed = members.iter().filter(|member| set.remove(member)).count();
+        self.size -= removed * SET_MEMBER_SIZE;
+
+        // Same as redis, empty sets are removed
+        if set.is_empty() {
+            self.remove_set(key);
+        }
+
+        removed
+    }
+
+    fn remove_entry(&mut self, key: &[u8]) -> Option<Entry> {
+        let (key, entry) = self.entries.remove_entry(key)?;
+
+        self.size -= key.len() + entry.bytes.len();
+
+        if let Some(expires_at) = entry.expires_at {
+            self.expirations.remove(&(expires_at, key));
+        }
+
+        Some(entry)
+    }
+
+    fn remove_set(&mut self, key: &[u8]) {
+        if let Some((key, set)) = self.sets.remove_entry(key) {
+            self.size -= key.len() + set.len() * SET_MEMBER_SIZE;
+        }
+    }
+
+    /// Remove expired entries and, if the memory limit is still exceeded,
+    /// those that expire next.
+    fn evict(&mut self) {
+        let now = Instant::now();
+
+        while let Some((expires_at, key)) = self.expirations.first() {
+            if *expires_at > now && self.size <= self.max_size {
+                break;
+            }
+
+            let key = key.clone();
+            self.remove_entry(&key);
+        }
+    }
+}
+
+struct Entry {
+    bytes: Vec<u8>,
+    expires_at: Option<Instant>,
+}
+
+impl Entry {
+    fn is_expired(&self, now: Instant) -> bool {
+        self.expires_at.is_some_and(|expires_at| expires_at <= now)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn entries() {
+        let cache = MemoryCache::new(1024);
+
+        cache.set(b"a", b"value", None);
+        cache.set(b"b", b"expired", Some(0));
+
+        assert_eq!(cache.get(b"a").as_deref(), Some(b"value".as_slice()));
+        assert_eq!(cache.get(b"b"), None);
+
+        cache.del(b"a");
+
+        assert_eq!(cache.get(b"a"), None);
+        assert_eq!(cache.inner.lock().unwrap().size, 0);
+
+        cache.set(b"c", b"once", Some(60));
+
+        assert_eq!(cache.getdel(b"c").as_deref(), Some(b"once".as_slice()));
+        assert_eq!(cache.getdel(b"c"), None);
+        assert_eq!(cache.inner.lock().unwrap().size, 0);
+    }
+
+    #[test]
+    fn memory_limit() {
+        let cache = MemoryCache::new(25);
+
+        cache.set(b"discord", b"data", None);
+        cache.set(b"first", b"12345", Some(60));
+        cache.set(b"second", b"12345", Some(120));
+
+        // Entries expiring first are evicted first
+        assert_eq!(cache.get(b"first"), None);
+        assert!(cache.get(b"second").is_some());
+        assert!(cache.get(b"discord").is_some());
+
+        // Entries without expiration are kept even if exceeding the limit
+        cache.set(b"large", &[0; 32], None);
+
+        assert_eq!(cache.get(b"second"), None);
+        assert!(cache.get(b"large").is_some());
+    }
+
+    #[test]
+    fn sets() {
+        let cache = MemoryCache::new(1024);
+
+        assert_eq!(cache.sadd(b"set", &[1, 2, 3]), 3);
+        assert_eq!(cache.sadd(b"set", &[3, 4]), 1);
+        assert_eq!(cache.srem(b"set", &[1, 5]), 1);
+        assert_eq!(cache.scard(b"set"), 3);
+
+        assert!(cache.smove(b"set", b"other", 2));
+        assert!(!cache.smove(b"set", b"other", 2));
+
+        let mut members = cache.smembers(b"set");
+        members.sort_unstable();
+
+        assert_eq!(members, [3, 4]);
+        assert_eq!(cache.smembers(b"other"), [2]);
+
+        cache.flush();
+
+        assert_eq!(cache.scard(b"set"), 0);
+        assert_eq!(cache.inner.lock().unwrap().size, 0);
+    }
+}
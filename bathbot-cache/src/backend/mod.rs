@@ -0,0 +1,193 @@
+use bb8_redis::{
+    bb8::{Pool, PooledConnection},
+    redis::{aio::ConnectionLike, AsyncCommands, Cmd, ToRedisArgs},
+    RedisConnectionManager,
+};
+use eyre::{Result, WrapErr};
+
+pub(crate) use self::memory::MemoryCache;
+use crate::key::RedisKey;
+
+mod memory;
+
+/// Where cached data is stored.
+pub(crate) enum CacheBackend {
+    Redis(Pool<RedisConnectionManager>),
+    Memory(MemoryCache),
+}
+
+impl CacheBackend {
+    pub(crate) async fn connection(&self) -> Result<Connection<'_>> {
+        match self {
+            Self::Redis(redis) => redis
+                .get()
+                .await
+                .map(Connection::Redis)
+                .wrap_err("Failed to get redis connection"),
+            Self::Memory(memory) => Ok(Connection::Memory(memory)),
+        }
+    }
+}
+
+/// A connection to the [`CacheBackend`].
+///
+/// Provides the subset of redis commands that the cache makes use of.
+pub(crate) enum Connection<'c> {
+    Redis(PooledConnection<'c, RedisConnectionManager>),
+    Memory(&'c MemoryCache),
+}
+
+impl Connection<'_> {
+    pub(crate) async fn get(&mut self, key: RedisKey<'_>) -> Result<Option<Vec<u8>>> {
+        match self {
+            Self::Redis(conn) => conn.get(key).await.map_err(Into::into),
+            Self::Memory(memory) => Ok(memory.get(&key.to_bytes())),
+        }
+    }
+
+    /// Get the bytes of the key and delete it in the same step.
+    pub(crate) async fn getdel(&mut self, key: RedisKey<'_>) -> Result<Option<Vec<u8>>> {
+        match self {
+            Self::Redis(conn) => {
+                let mut cmd = Cmd::new();
+                cmd.arg("GETDEL").arg(key);
+
+                cmd.query_async(&mut **conn).await.map_err(Into::into)
+            }
+            Self::Memory(memory) => Ok(memory.getdel(&key.to_bytes())),
+        }
+    }
+
+    /// Missing keys are skipped.
+    pub(crate) async fn mget(&mut self, keys: &[RedisKey<'_>]) -> Result<Vec<Vec<u8>>> {
+        if keys.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        match self {
+            Self::Redis(conn) => conn
+                .mget::<_, Vec<Option<Vec<u8>>>>(keys)
+                .await
+                .map(|values| values.into_iter().flatten().collect())
+                .map_err(Into::into),
+            Self::Memory(memory) => Ok(keys
+                .iter()
+                .filter_map(|key| memory.get(&key.to_bytes()))
+                .collect()),
+        }
+    }
+
+    /// Store bytes for the key, optionally expiring after the given amount of
+    /// seconds.
+    pub(crate) async fn set(
+        &mut self,
+        key: RedisKey<'_>,
+        bytes: &[u8],
+        expire_seconds: Option<usize>,
+    ) -> Result<()> {
+        match self {
+            Self::Redis(conn) => match expire_seconds {
+                Some(seconds) => conn.set_ex(key, bytes, seconds).await,
+                None => conn.set(key, bytes).await,
+            }
+            .map_err(Into::into),
+            Self::Memory(memory) => {
+                memory.set(&key.to_bytes(), bytes, expire_seconds);
+
+                Ok(())
+            }
+        }
+    }
+
+    pub(crate) async fn mset<V>(&mut self, items: &[(RedisKey<'_>, V)]) -> Result<()>
+    where
+        V: AsRef<[u8]> + ToRedisArgs + Send + Sync,
+    {
+        match self {
+            Self::Redis(conn) => conn.mset(items).await.map_err(Into::into),
+            Self::Memory(memory) => {
+                for (key, bytes) in items {
+                    memory.set(&key.to_bytes(), bytes.as_ref(), None);
+                }
+
+                Ok(())
+            }
+        }
+    }
+
+    pub(crate) async fn del(&mut self, keys: &[RedisKey<'_>]) -> Result<()> {
+        match self {
+            Self::Redis(conn) => conn.del(keys).await.map_err(Into::into),
+            Self::Memory(memory) => {
+                for key in keys {
+                    memory.del(&key.to_bytes());
+                }
+
+                Ok(())
+            }
+        }
+    }
+
+    /// Returns how many members were newly added.
+    pub(crate) async fn sadd(&mut self, key: RedisKey<'_>, members: &[u64]) -> Result<isize> {
+        match self {
+            Self::Redis(conn) => conn.sadd(key, members).await.map_err(Into::into),
+            Self::Memory(memory) => Ok(memory.sadd(&key.to_bytes(), members)),
+        }
+    }
+
+    /// Returns how many members were removed.
+    pub(crate) async fn srem(&mut self, key: RedisKey<'_>, members: &[u64]) -> Result<isize> {
+        match self {
+            Self::Redis(conn) => conn.srem(key, members).await.map_err(Into::into),
+            Self::Memory(memory) => Ok(memory.srem(&key.to_bytes(), members)),
+        }
+    }
+
+    pub(crate) async fn smembers(&mut self, key: RedisKey<'_>) -> Result<Vec<u64>> {
+        match self {
+            Self::Redis(conn) => conn.smembers(key).await.map_err(Into::into),
+            Self::Memory(memory) => Ok(memory.smembers(&key.to_bytes())),
+        }
+    }
+
+    pub(crate) async fn scard(&mut self, key: RedisKey<'_>) -> Result<isize> {
+        match self {
+            Self::Redis(conn) => conn.scard(key).await.map_err(Into::into),
+            Self::Memory(memory) => Ok(memory.scard(&key.to_bytes())),
+        }
+    }
+
+    /// Returns whether the member was moved.
+    pub(crate) async fn smove(
+        &mut self,
+        src: RedisKey<'_>,
+        dst: RedisKey<'_>,
+        member: u64,
+    ) -> Result<bool> {
+        match self {
+            Self::Redis(conn) => conn.smove(src, dst, member).await.map_err(Into::into),
+            Self::Memory(memory) => Ok(memory.smove(&src.to_bytes(), &dst.to_bytes(), member)),
+        }
+    }
+
+    /// Remove all entries.
+    pub(crate) async fn flush(&mut self) -> Result<()> {
+        match self {
+            Self::Redis(conn) => {
+                let mut cmd = Cmd::new();
+                cmd.arg("FLUSHDB");
+
+                conn.req_packed_command(&cmd)
+                    .await
+                    .map(|_| ())
+                    .map_err(Into::into)
+            }
+            Self::Memory(memory) => {
+                memory.flush();
+
+                Ok(())
+            }
+        }
+    }
+}
@@ -1,7 +1,6 @@
 use std::{collections::HashMap, hash::BuildHasher};
 
 use bathbot_model::twilight_gateway::SessionsRkyv;
-use bb8_redis::redis::{aio::ConnectionLike, AsyncCommands, Cmd};
 use eyre::{Result, WrapErr};
 use rkyv::with::With;
 use tracing::info;
@@ -19,7 +18,11 @@ impl Cache {
 
         self.connection()
             .await?
-            .set_ex(RedisKey::resume_data(), bytes.as_slice(), STORE_DURATION)
+            .set(
+                RedisKey::resume_data(),
+                bytes.as_slice(),
+                Some(STORE_DURATION),
+            )
             .await
             .wrap_err("Failed to store resume data bytes")?;
 
@@ -31,23 +34,21 @@ impl Cache {
     pub async fn defrost<S: BuildHasher + Default>(&self) -> Result<HashMap<u64, Session, S>> {
         let mut conn = self.connection().await?;
 
-        let resume_data_opt: Option<CachedArchive<HashMap<u64, Session, S>>> = conn
+        let resume_data_opt = conn
             .get(RedisKey::resume_data())
             .await
             .wrap_err("Failed to get stored resume data")?;
 
-        if let Some(resume_data) = resume_data_opt {
+        if let Some(bytes) = resume_data_opt {
+            let resume_data = CachedArchive::<HashMap<u64, Session, S>>::new(bytes);
             info!("Successfully defrosted cache");
 
             return Ok(resume_data.deserialize_with::<SessionsRkyv>());
         }
 
-        let mut cmd = Cmd::new();
-        cmd.arg("FLUSHDB");
-
-        conn.req_packed_command(&cmd)
+        conn.flush()
             .await
-            .wrap_err("Failed to flush redis entries")?;
+            .wrap_err("Failed to flush cache entries")?;
 
         info!("Empty resume data, starting with fresh cache");
 
@@ -1,13 +1,12 @@
 use std::iter;
 
-use bb8_redis::{bb8::PooledConnection, redis::AsyncCommands, RedisConnectionManager};
 use eyre::{Result, WrapErr};
 use twilight_model::id::{
     marker::{ChannelMarker, GuildMarker, RoleMarker, UserMarker},
     Id,
 };
 
-use crate::{key::RedisKey, model::CacheChange, Cache};
+use crate::{backend::Connection, key::RedisKey, model::CacheChange, Cache};
 
 impl Cache {
     pub(crate) async fn delete_channel(
@@ -17,18 +16,18 @@ impl Cache {
     ) -> Result<CacheChange> {
         let mut conn = self.connection().await?;
 
-        conn.del(RedisKey::channel(guild, channel))
+        conn.del(&[RedisKey::channel(guild, channel)])
             .await
             .wrap_err("Failed to delete channel entry")?;
 
         if let Some(guild) = guild {
-            conn.srem(RedisKey::guild_channels(guild), channel.get())
+            conn.srem(RedisKey::guild_channels(guild), &[channel.get()])
                 .await
                 .wrap_err("Failed to remove channel as guild channel")?;
         }
 
-        let removed: isize = conn
-            .srem(RedisKey::channels(), channel.get())
+        let removed = conn
+            .srem(RedisKey::channels(), &[channel.get()])
             .await
             .wrap_err("Failed to remove channel from channel ids")?;
 
@@ -41,12 +40,12 @@ impl Cache {
     pub(crate) async fn delete_guild(&self, guild: Id<GuildMarker>) -> Result<CacheChange> {
         let mut conn = self.connection().await?;
 
-        conn.del(RedisKey::guild(guild))
+        conn.del(&[RedisKey::guild(guild)])
             .await
             .wrap_err("Failed to delete guild entry")?;
 
-        let removed: isize = conn
-            .srem(RedisKey::guilds(), guild.get())
+        let removed = conn
+            .srem(RedisKey::guilds(), &[guild.get()])
             .await
             .wrap_err("Failed to remove guild id entry")?;
 
@@ -59,7 +58,7 @@ impl Cache {
 
     pub(crate) async fn delete_guild_items(&self, guild: Id<GuildMarker>) -> Result<CacheChange> {
         async fn remove_ids<G, I, K, C>(
-            conn: &mut PooledConnection<'_, RedisConnectionManager>,
+            conn: &mut Connection<'_>,
             guild: Id<GuildMarker>,
             guild_set_key_fn: G,
             id_set_key_fn: Option<I>,
@@ -74,13 +73,13 @@ impl Cache {
         {
             let guild_key = (guild_set_key_fn)(guild);
 
-            let guild_ids: Vec<u64> = conn
-                .smembers(&guild_key)
+            let guild_ids = conn
+                .smembers(guild_key.clone())
                 .await
                 .wrap_err("Failed smembers")?;
 
             if let Some(id_set_key_fn) = id_set_key_fn.filter(|_| !guild_ids.is_empty()) {
-                let removed: isize = conn
+                let removed = conn
                     .srem((id_set_key_fn)(), &guild_ids)
                     .await
                     .wrap_err("Failed srem")?;
@@ -146,11 +145,11 @@ impl Cache {
 
         let key = RedisKey::member(guild, user);
 
-        conn.del(key)
+        conn.del(&[key])
             .await
             .wrap_err("Failed to delete member entry")?;
 
-        conn.srem(RedisKey::guild_members(guild), user.get())
+        conn.srem(RedisKey::guild_members(guild), &[user.get()])
             .await
             .wrap_err("Failed to remove guild member entry")?;
 
@@ -169,16 +168,16 @@ impl Cache {
     ) -> Result<CacheChange> {
         let mut conn = self.connection().await?;
 
-        conn.del(RedisKey::role(guild, role))
+        conn.del(&[RedisKey::role(guild, role)])
             .await
             .wrap_err("Failed to delete role entry")?;
 
-        conn.srem(RedisKey::guild_roles(guild), role.get())
+        conn.srem(RedisKey::guild_roles(guild), &[role.get()])
             .await
             .wrap_err("Failed to remove role as guild role")?;
 
-        let removed: isize = conn
-            .srem(RedisKey::roles(), role.get())
+        let removed = conn
+            .srem(RedisKey::roles(), &[role.get()])
             .await
             .wrap_err("Failed to remove role from role ids")?;
 
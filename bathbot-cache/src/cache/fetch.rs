@@ -3,7 +3,6 @@ use bathbot_model::twilight_model::{
     guild::{Guild, Member, Role},
     user::{CurrentUser, User},
 };
-use bb8_redis::redis::AsyncCommands;
 use eyre::{Result, WrapErr};
 use twilight_model::id::{
     marker::{ChannelMarker, GuildMarker, RoleMarker, UserMarker},
@@ -26,12 +25,26 @@ impl Cache {
     {
         let mut conn = self.connection().await?;
 
-        conn.get::<_, Option<CachedArchive<T>>>(RedisKey::from(key))
+        conn.get(RedisKey::from(key))
             .await
-            .map(|archived| archived.ok_or(CacheConnection(conn)))
+            .map(|bytes| bytes.map(CachedArchive::new).ok_or(CacheConnection(conn)))
             .wrap_err("Failed to fetch stored data")
     }
 
+    /// Fetch the data of a key and remove it so it can only be fetched once.
+    #[inline]
+    pub async fn fetch_del<K, T>(&self, key: &K) -> Result<Option<CachedArchive<T>>>
+    where
+        K: ToCacheKey + ?Sized,
+    {
+        self.connection()
+            .await?
+            .getdel(RedisKey::from(key))
+            .await
+            .map(|bytes| bytes.map(CachedArchive::new))
+            .wrap_err("Failed to fetch and delete stored data")
+    }
+
     #[inline]
     pub async fn channel(
         &self,
@@ -42,6 +55,7 @@ impl Cache {
             .await?
             .get(RedisKey::channel(guild, channel))
             .await
+            .map(|bytes| bytes.map(CachedArchive::new))
             .wrap_err("Failed to get stored channel")
     }
 
@@ -51,6 +65,7 @@ impl Cache {
             .await?
             .get(RedisKey::current_user())
             .await
+            .map(|bytes| bytes.map(CachedArchive::new))
             .wrap_err("Failed to get stored current user")
     }
 
@@ -60,6 +75,7 @@ impl Cache {
             .await?
             .get(RedisKey::guild(guild))
             .await
+            .map(|bytes| bytes.map(CachedArchive::new))
             .wrap_err("Failed to get stored guild")
     }
 
@@ -82,6 +98,7 @@ impl Cache {
             .await?
             .get(RedisKey::member(guild, user))
             .await
+            .map(|bytes| bytes.map(CachedArchive::new))
             .wrap_err("Failed to get stored member")
     }
 
@@ -91,6 +108,7 @@ impl Cache {
             .await?
             .get(RedisKey::role(guild, role))
             .await
+            .map(|bytes| bytes.map(CachedArchive::new))
             .wrap_err("Failed to get stored role")
     }
 
@@ -110,8 +128,9 @@ impl Cache {
 
         self.connection()
             .await?
-            .mget(keys)
+            .mget(&keys)
             .await
+            .map(|bytes| bytes.into_iter().map(CachedArchive::new).collect())
             .wrap_err("Failed to get stored roles")
     }
 
@@ -121,6 +140,7 @@ impl Cache {
             .await?
             .get(RedisKey::user(user))
             .await
+            .map(|bytes| bytes.map(CachedArchive::new))
             .wrap_err("Failed to get stored user")
     }
 }
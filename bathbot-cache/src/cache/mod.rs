@@ -1,15 +1,15 @@
 use std::fmt::Display;
 
-use bb8_redis::{
-    bb8::{Pool, PooledConnection},
-    RedisConnectionManager,
-};
+use bb8_redis::{bb8::Pool, RedisConnectionManager};
 use eyre::{Result, WrapErr};
 use tracing::error;
 use twilight_gateway::Event;
-use twilight_model::application::interaction::InteractionData;
+use twilight_model::application::interaction::{Interaction, InteractionData};
 
-use crate::model::{CacheChange, CacheStats, CacheStatsInternal};
+use crate::{
+    backend::{CacheBackend, Connection, MemoryCache},
+    model::{CacheChange, CacheStats, CacheStatsInternal},
+};
 
 mod cold_resume;
 mod delete;
@@ -17,11 +17,12 @@ mod fetch;
 mod store;
 
 pub struct Cache {
-    redis: Pool<RedisConnectionManager>,
+    backend: CacheBackend,
     stats: CacheStatsInternal,
 }
 
 impl Cache {
+    /// Create a cache that stores its data in redis.
     pub async fn new(host: impl Display, port: u16, db_idx: u8) -> Result<Self> {
         let redis_uri = format!("redis://{host}:{port}/{db_idx}");
 
@@ -34,11 +35,24 @@ impl Cache {
             .await
             .wrap_err("Failed to create redis pool")?;
 
-        let stats = CacheStatsInternal::new(&redis)
+        let backend = CacheBackend::Redis(redis);
+
+        let stats = CacheStatsInternal::new(&backend)
             .await
             .wrap_err("Failed to create cache stats")?;
 
-        Ok(Self { redis, stats })
+        Ok(Self { backend, stats })
+    }
+
+    /// Create a cache that stores its data in-process instead of in redis.
+    ///
+    /// Once `max_size` bytes are exceeded, entries with an expiration are
+    /// evicted early. Note that resume data does not persist across restarts.
+    pub fn in_memory(max_size: usize) -> Self {
+        Self {
+            backend: CacheBackend::Memory(MemoryCache::new(max_size)),
+            stats: CacheStatsInternal::default(),
+        }
     }
 
     pub async fn update(&self, event: &Event) -> Option<CacheChange> {
@@ -56,38 +70,7 @@ impl Cache {
                     }
                 }
                 Event::GuildUpdate(e) => cache.cache_partial_guild(e).await?,
-                Event::InteractionCreate(e) => {
-                    let mut change = CacheChange::default();
-
-                    if let (Some(guild_id), Some(member)) = (e.guild_id, &e.member) {
-                        if let Some(ref user) = member.user {
-                            change += cache.cache_partial_member(guild_id, member, user).await?;
-                        }
-                    } else if let Some(ref user) = e.user {
-                        change += cache.cache_user(user).await?;
-                    }
-
-                    if let Some(InteractionData::ApplicationCommand(ref data)) = e.data {
-                        if let Some(ref resolved) = data.resolved {
-                            for user in resolved.users.values() {
-                                if let Some(member) = resolved.members.get(&user.id) {
-                                    if let Some(guild_id) = e.guild_id {
-                                        change += cache
-                                            .cache_interaction_member(guild_id, member, user)
-                                            .await?;
-                                    }
-                                }
-                            }
-
-                            if let Some(guild_id) = e.guild_id {
-                                change +=
-                                    cache.cache_roles(guild_id, resolved.roles.values()).await?;
-                            }
-                        }
-                    }
-
-                    change
-                }
+                Event::InteractionCreate(e) => cache.cache_interaction(e).await?,
                 Event::MemberAdd(e) => cache.cache_member(e.guild_id, &e.member).await?,
                 Event::MemberRemove(e) => cache.delete_member(e.guild_id, e.user.id).await?,
                 Event::MemberUpdate(e) => cache.cache_member_update(e).await?,
@@ -132,7 +115,53 @@ impl Cache {
             Ok(Some(change))
         }
 
-        match update(self, event).await {
+        let res = update(self, event).await;
+
+        self.process_change(res, event.kind().name().unwrap_or("<unnamed>"))
+    }
+
+    /// Same as [`Cache::update`] but for interactions that were not received
+    /// through the gateway.
+    pub async fn update_interaction(&self, interaction: &Interaction) -> Option<CacheChange> {
+        let res = self.cache_interaction(interaction).await.map(Some);
+
+        self.process_change(res, "INTERACTION_CREATE")
+    }
+
+    async fn cache_interaction(&self, interaction: &Interaction) -> Result<CacheChange> {
+        let mut change = CacheChange::default();
+
+        if let (Some(guild_id), Some(member)) = (interaction.guild_id, &interaction.member) {
+            if let Some(ref user) = member.user {
+                change += self.cache_partial_member(guild_id, member, user).await?;
+            }
+        } else if let Some(ref user) = interaction.user {
+            change += self.cache_user(user).await?;
+        }
+
+        if let Some(InteractionData::ApplicationCommand(ref data)) = interaction.data {
+            if let Some(ref resolved) = data.resolved {
+                for user in resolved.users.values() {
+                    if let Some(member) = resolved.members.get(&user.id) {
+                        if let Some(guild_id) = interaction.guild_id {
+                            change += self
+                                .cache_interaction_member(guild_id, member, user)
+                                .await?;
+                        }
+                    }
+                }
+
+                if let Some(guild_id) = interaction.guild_id {
+                    change += self.cache_roles(guild_id, resolved.roles.values()).await?;
+                }
+            }
+        }
+
+        Ok(change)
+    }
+
+    fn process_change(&self, res: Result<Option<CacheChange>>, event: &str) -> Option<CacheChange> {
+        match res {
             Ok(Some(change)) => {
                 self.stats.update(&change);
 
@@ -140,7 +169,6 @@ impl Cache {
             }
             Ok(None) => None,
             Err(err) => {
-                let event = event.kind().name().unwrap_or("<unnamed>");
                 error!(event, ?err, "Failed to update cache");
 
                 None
@@ -152,10 +180,7 @@ impl Cache {
         self.stats.get()
     }
 
-    pub(crate) async fn connection(&self) -> Result<PooledConnection<RedisConnectionManager>> {
-        self.redis
-            .get()
-            .await
-            .wrap_err("Failed to get redis connection")
+    pub(crate) async fn connection(&self) -> Result<Connection<'_>> {
+        self.backend.connection().await
     }
 }
@@ -1,6 +1,5 @@
 use bathbot_model::twilight_model::guild::Member;
-use bb8_redis::redis::AsyncCommands;
-use eyre::{Report, Result, WrapErr};
+use eyre::{Result, WrapErr};
 use rkyv::{
     with::{ArchiveWith, SerializeWith},
     AlignedVec, Serialize,
@@ -50,9 +49,7 @@ impl Cache {
     {
         let key = RedisKey::from(key);
 
-        conn.set_ex(key, bytes, expire_seconds)
-            .await
-            .map_err(Report::new)
+        conn.set(key, bytes, Some(expire_seconds)).await
     }
 
     /// Serializes a values into bytes, opens a new connection, and stores the
@@ -98,7 +95,7 @@ impl Cache {
         K: ToCacheKey + ?Sized,
     {
         let key = RedisKey::from(key);
-        let count: u8 = self.connection().await?.sadd(key, value).await?;
+        let count = self.connection().await?.sadd(key, &[value]).await?;
 
         Ok(count == 1)
     }
@@ -108,20 +105,20 @@ impl Cache {
         let mut conn = self.connection().await?;
         let key = RedisKey::from(channel);
 
-        conn.set(key, bytes.as_slice())
+        conn.set(key, bytes.as_slice(), None)
             .await
             .wrap_err("Failed to store channel bytes")?;
 
         if let Some(guild) = channel.guild_id {
             let guild_key = RedisKey::guild_channels(guild);
 
-            conn.sadd(guild_key, channel.id.get())
+            conn.sadd(guild_key, &[channel.id.get()])
                 .await
                 .wrap_err("Failed to add channel as guild channel")?;
         }
 
-        let added: isize = conn
-            .sadd(RedisKey::channels(), channel.id.get())
+        let added = conn
+            .sadd(RedisKey::channels(), &[channel.id.get()])
             .await
             .wrap_err("Failed to add channel as channel id")?;
 
@@ -168,7 +165,7 @@ impl Cache {
             .await
             .wrap_err("Failed to add users as guild members")?;
 
-        let added: isize = conn
+        let added = conn
             .sadd(RedisKey::channels(), &channel_ids)
             .await
             .wrap_err("Failed to add channels as channel ids")?;
@@ -184,7 +181,7 @@ impl Cache {
 
         self.connection()
             .await?
-            .set(RedisKey::current_user(), bytes.as_slice())
+            .set(RedisKey::current_user(), bytes.as_slice(), None)
             .await
             .wrap_err("Failed to store current user bytes")?;
 
@@ -203,17 +200,17 @@ impl Cache {
         let mut conn = self.connection().await?;
         let key = RedisKey::from(guild);
 
-        conn.set(key, bytes.as_slice())
+        conn.set(key, bytes.as_slice(), None)
             .await
             .wrap_err("Failed to store guild bytes")?;
 
-        let guilds_added: isize = conn
-            .sadd(RedisKey::guilds(), guild.id.get())
+        let guilds_added = conn
+            .sadd(RedisKey::guilds(), &[guild.id.get()])
             .await
             .wrap_err("Failed to add guild as guild id")?;
 
-        let unavailable_guilds_removed: isize = conn
-            .srem(RedisKey::unavailable_guilds(), guild.id.get())
+        let unavailable_guilds_removed = conn
+            .srem(RedisKey::unavailable_guilds(), &[guild.id.get()])
             .await
             .wrap_err("Failed to remove guild as unavailable guild id")?;
 
@@ -276,12 +273,12 @@ impl Cache {
 
             let guild_key = RedisKey::guild_members(guild);
 
-            conn.sadd(guild_key, user.id.get())
+            conn.sadd(guild_key, &[user.id.get()])
                 .await
                 .wrap_err("Failed to add user as guild member")?;
 
-            let added: isize = conn
-                .sadd(RedisKey::users(), user.id.get())
+            let added = conn
+                .sadd(RedisKey::users(), &[user.id.get()])
                 .await
                 .wrap_err("Failed to add user as user id")?;
 
@@ -351,7 +348,7 @@ impl Cache {
             .await
             .wrap_err("Failed to add users as guild members")?;
 
-        let added: isize = conn
+        let added = conn
             .sadd(RedisKey::users(), &member_ids)
             .await
             .wrap_err("Failed to add users as user ids")?;
@@ -370,17 +367,17 @@ impl Cache {
         let bytes = SingleSerializer::guild(guild)?;
         let key = RedisKey::guild(guild.id);
 
-        conn.set(key, bytes.as_slice())
+        conn.set(key, bytes.as_slice(), None)
             .await
             .wrap_err("Failed to store guild bytes")?;
 
-        let guilds_added: isize = conn
-            .sadd(RedisKey::guilds(), guild.id.get())
+        let guilds_added = conn
+            .sadd(RedisKey::guilds(), &[guild.id.get()])
             .await
             .wrap_err("Failed to add guild as guild id")?;
 
-        let unavailable_guilds_removed: isize = conn
-            .srem(RedisKey::unavailable_guilds(), guild.id.get())
+        let unavailable_guilds_removed = conn
+            .srem(RedisKey::unavailable_guilds(), &[guild.id.get()])
             .await
             .wrap_err("Failed to remove guild as unavailable guild id")?;
 
@@ -408,18 +405,18 @@ impl Cache {
         let mut conn = self.connection().await?;
         let key = RedisKey::role(guild, role.id);
 
-        conn.set(key, bytes.as_slice())
+        conn.set(key, bytes.as_slice(), None)
             .await
             .wrap_err("Failed to store role bytes")?;
 
         let guild_key = RedisKey::guild_roles(guild);
 
-        conn.sadd(guild_key, role.id.get())
+        conn.sadd(guild_key, &[role.id.get()])
             .await
             .wrap_err("Failed to add role as guild role")?;
 
-        let added: isize = conn
-            .sadd(RedisKey::roles(), role.id.get())
+        let added = conn
+            .sadd(RedisKey::roles(), &[role.id.get()])
             .await
             .wrap_err("Failed to add role as role id")?;
 
@@ -468,7 +465,7 @@ impl Cache {
             .await
             .wrap_err("Failed to add roles as guild roles")?;
 
-        let added: isize = conn
+        let added = conn
             .sadd(RedisKey::roles(), &role_ids)
             .await
             .wrap_err("Failed to add roles as role ids")?;
@@ -485,7 +482,7 @@ impl Cache {
     ) -> Result<CacheChange> {
         let mut conn = self.connection().await?;
 
-        let is_moved: bool = conn
+        let is_moved = conn
             .smove(
                 RedisKey::guilds(),
                 RedisKey::unavailable_guilds(),
@@ -495,7 +492,7 @@ impl Cache {
             .wrap_err("Failed to move guild id")?;
 
         let change = if is_moved {
-            conn.del(RedisKey::guild(guild))
+            conn.del(&[RedisKey::guild(guild)])
                 .await
                 .wrap_err("Failed to delete guild entry")?;
 
@@ -509,8 +506,8 @@ impl Cache {
 
             change
         } else {
-            let added: isize = conn
-                .sadd(RedisKey::unavailable_guilds(), guild.get())
+            let added = conn
+                .sadd(RedisKey::unavailable_guilds(), &[guild.get()])
                 .await
                 .wrap_err("Failed to add guild to unavailable guilds")?;
 
@@ -529,12 +526,12 @@ impl Cache {
         let bytes = SingleSerializer::user(user)?;
         let key = RedisKey::from(user);
 
-        conn.set(key, bytes.as_slice())
+        conn.set(key, bytes.as_slice(), None)
             .await
             .wrap_err("Failed to store user bytes")?;
 
-        let added: isize = conn
-            .sadd(RedisKey::users(), user.id.get())
+        let added = conn
+            .sadd(RedisKey::users(), &[user.id.get()])
             .await
             .wrap_err("Failed to add user as user id")?;
 
@@ -86,7 +86,7 @@ impl RedisKey<'_> {
         Self::Set(SetEntry::Users)
     }
 
-    fn to_bytes(&self) -> Cow<'_, [u8]> {
+    pub(crate) fn to_bytes(&self) -> Cow<'_, [u8]> {
         match self {
             Self::Single(key) => key.to_bytes(),
             Self::Set(key) => key.to_bytes(),
@@ -2,6 +2,7 @@ pub use self::{cache::Cache, key::ToCacheKey, serializer::CacheSerializer};
 
 pub mod model;
 
+mod backend;
 mod cache;
 mod key;
 mod serializer;
@@ -1,6 +1,5 @@
 use std::{marker::PhantomData, ops::Deref};
 
-use bb8_redis::redis::{ErrorKind, FromRedisValue, RedisError, RedisResult, Value};
 use rkyv::{
     with::{ArchiveWith, DeserializeWith, With},
     Archive, Archived, Deserialize, Infallible,
@@ -26,7 +25,7 @@ impl<T: Archive> Deref for CachedArchive<T> {
 
     #[inline]
     fn deref(&self) -> &Self::Target {
-        // SAFETY: Bytes originate from redis which only stores valid archived data
+        // SAFETY: Bytes originate from the cache which only stores valid archived data
         unsafe { rkyv::archived_root::<T>(&self.bytes) }
     }
 }
@@ -45,26 +44,9 @@ impl<T> CachedArchive<T> {
     where
         W: ArchiveWith<T> + DeserializeWith<<W as ArchiveWith<T>>::Archived, T, Infallible>,
     {
-        // SAFETY: Bytes originate from redis which only stores valid archived data
+        // SAFETY: Bytes originate from the cache which only stores valid archived data
         let archived = unsafe { rkyv::archived_root::<With<_, W>>(&self.bytes) };
 
         W::deserialize_with(archived, &mut Infallible).unwrap()
     }
 }
-
-impl<T> FromRedisValue for CachedArchive<T> {
-    #[inline]
-    fn from_redis_value(v: &Value) -> RedisResult<Self> {
-        match v {
-            Value::Data(bytes) => Ok(Self::new(bytes.to_owned())),
-            _ => Err(RedisError::from((
-                ErrorKind::TypeError,
-                "Response was of incompatible type",
-                format!(
-                    "Response type not byte list compatible. (response was {:?})",
-                    std::any::type_name::<T>()
-                ),
-            ))),
-        }
-    }
-}
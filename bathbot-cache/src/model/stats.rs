@@ -3,10 +3,9 @@ use std::{
     sync::Mutex,
 };
 
-use bb8_redis::{bb8::Pool, redis::AsyncCommands, RedisConnectionManager};
 use eyre::{Result, WrapErr};
 
-use crate::key::RedisKey;
+use crate::{backend::CacheBackend, key::RedisKey};
 
 #[derive(Clone, Debug, Default)]
 pub struct CacheStats {
@@ -57,16 +56,14 @@ impl AddAssign for CacheChange {
     }
 }
 
+#[derive(Default)]
 pub(crate) struct CacheStatsInternal {
     inner: Mutex<CacheStats>,
 }
 
 impl CacheStatsInternal {
-    pub(crate) async fn new(redis: &Pool<RedisConnectionManager>) -> Result<Self> {
-        let mut conn = redis
-            .get()
-            .await
-            .wrap_err("Failed to get redis connection")?;
+    pub(crate) async fn new(backend: &CacheBackend) -> Result<Self> {
+        let mut conn = backend.connection().await?;
 
         macro_rules! scard {
             ($name:ident) => {
@@ -12,3 +12,10 @@ impl ToRedisArgs for AlignedVecRedisArgs {
         self.0.as_slice().write_redis_args(out)
     }
 }
+
+impl AsRef<[u8]> for AlignedVecRedisArgs {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        self.0.as_slice()
+    }
+}
@@ -201,6 +201,13 @@ impl Client {
             req = req.header(AUTHORIZATION, self.github_auth.as_ref());
         }
 
+        #[cfg(feature = "twitch")]
+        if site == Site::Twitch {
+            req = req
+                .header("Client-ID", self.twitch.client_id.clone())
+                .header(AUTHORIZATION, format!("Bearer {}", self.twitch.oauth_token));
+        }
+
         let req = req
             .body(Body::from(json))
             .wrap_err("Failed to build POST json request")?;
@@ -221,6 +228,51 @@ impl Client {
         bytes_res
     }
 
+    pub(crate) async fn make_delete_request(
+        &self,
+        url: impl AsRef<str>,
+        site: Site,
+    ) -> Result<Bytes, ClientError> {
+        let url = url.as_ref();
+        trace!("DELETE request to url {url}");
+
+        let req = Request::builder()
+            .uri(url)
+            .method(Method::DELETE)
+            .header(USER_AGENT, MY_USER_AGENT);
+
+        let req = match site {
+            #[cfg(not(feature = "twitch"))]
+            Site::Twitch => {
+                return Err(ClientError::Report(eyre::Report::msg(
+                    "twitch request without twitch feature",
+                )))
+            }
+            #[cfg(feature = "twitch")]
+            Site::Twitch => req
+                .header("Client-ID", self.twitch.client_id.clone())
+                .header(AUTHORIZATION, format!("Bearer {}", self.twitch.oauth_token)),
+            _ => req,
+        };
+
+        let req = req
+            .body(Body::empty())
+            .wrap_err("Failed to build DELETE request")?;
+
+        let (response, start) = self
+            .send_request(req, site)
+            .await
+            .wrap_err("Failed to receive DELETE response")?;
+
+        let status = response.status();
+        let bytes_res = Self::error_for_status(response, url).await;
+
+        let latency = start.elapsed();
+        ClientMetrics::observe(site, status, latency);
+
+        bytes_res
+    }
+
     pub(crate) async fn error_for_status(
         response: Response<Body>,
         url: &str,
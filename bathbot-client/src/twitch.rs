@@ -4,12 +4,16 @@ use std::{
     time::Duration,
 };
 
-use bathbot_model::{TwitchDataList, TwitchStream, TwitchUser, TwitchVideo};
+use bathbot_model::{
+    TwitchDataList, TwitchStream, TwitchSubscription, TwitchSubscriptionList, TwitchUser,
+    TwitchVideo,
+};
 use bathbot_util::constants::{
-    TWITCH_STREAM_ENDPOINT, TWITCH_USERS_ENDPOINT, TWITCH_VIDEOS_ENDPOINT,
+    TWITCH_EVENTSUB_ENDPOINT, TWITCH_STREAM_ENDPOINT, TWITCH_USERS_ENDPOINT, TWITCH_VIDEOS_ENDPOINT,
 };
 use bytes::Bytes;
 use eyre::{Result, WrapErr};
+use serde::Serialize;
 use tokio::time::interval;
 
 use crate::{Client, ClientError, Site};
@@ -216,4 +220,104 @@ impl Client {
 
         Ok(videos.data.pop())
     }
+
+    /// Subscribe to an EventSub event such as `stream.online` of the user so
+    /// that twitch notifies the webhook at `callback`.
+    pub async fn subscribe_twitch_event(
+        &self,
+        user_id: u64,
+        kind: &str,
+        callback: &str,
+        secret: &str,
+    ) -> Result<()> {
+        #[derive(Serialize)]
+        struct Body<'s> {
+            #[serde(rename = "type")]
+            kind: &'s str,
+            version: &'s str,
+            condition: Condition,
+            transport: Transport<'s>,
+        }
+
+        #[derive(Serialize)]
+        struct Condition {
+            broadcaster_user_id: String,
+        }
+
+        #[derive(Serialize)]
+        struct Transport<'s> {
+            method: &'s str,
+            callback: &'s str,
+            secret: &'s str,
+        }
+
+        let body = Body {
+            kind,
+            version: "1",
+            condition: Condition {
+                broadcaster_user_id: user_id.to_string(),
+            },
+            transport: Transport {
+                method: "webhook",
+                callback,
+                secret,
+            },
+        };
+
+        let json = serde_json::to_vec(&body).unwrap();
+
+        self.make_json_post_request(TWITCH_EVENTSUB_ENDPOINT, Site::Twitch, json)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Get all EventSub subscriptions or only those of a specific user.
+    pub async fn get_twitch_subscriptions(
+        &self,
+        user_id: Option<u64>,
+    ) -> Result<Vec<TwitchSubscription>> {
+        let mut subscriptions = Vec::new();
+        let mut cursor: Option<Box<str>> = None;
+
+        loop {
+            let mut data = Vec::with_capacity(2);
+
+            if let Some(user_id) = user_id {
+                data.push(("user_id", Cow::Owned(user_id.to_string())));
+            }
+
+            if let Some(ref cursor) = cursor {
+                data.push(("after", Cow::Borrowed(cursor.as_ref())));
+            }
+
+            let bytes = self
+                .make_twitch_get_request(TWITCH_EVENTSUB_ENDPOINT, data)
+                .await?;
+
+            let mut list: TwitchSubscriptionList =
+                serde_json::from_slice(&bytes).wrap_err_with(|| {
+                    let body = String::from_utf8_lossy(&bytes);
+
+                    format!("Failed to deserialize twitch subscriptions: {body}")
+                })?;
+
+            subscriptions.append(&mut list.data);
+
+            cursor = list.pagination.cursor;
+
+            if cursor.is_none() {
+                break;
+            }
+        }
+
+        Ok(subscriptions)
+    }
+
+    pub async fn delete_twitch_subscription(&self, subscription_id: &str) -> Result<()> {
+        let url = format!("{TWITCH_EVENTSUB_ENDPOINT}?id={subscription_id}");
+        self.make_delete_request(url, Site::Twitch).await?;
+
+        Ok(())
+    }
 }
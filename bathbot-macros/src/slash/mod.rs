@@ -23,19 +23,27 @@ pub fn derive(input: DeriveInput) -> Result<TokenStream> {
     let static_name = format_ident!("{}", name_str.to_uppercase(), span = name.span());
     let slash_cmd = format_ident!("slash_{}", name_str.to_lowercase(), span = name.span());
     let exec = format_ident!("{slash_cmd}__", span = name.span());
+    let create = format_ident!("create_{}__", name_str.to_lowercase(), span = name.span());
     let path = quote!(crate::core::commands::interaction::SlashCommand);
 
     let tokens = quote! {
         #[linkme::distributed_slice(crate::core::commands::interaction::__SLASH_COMMANDS)]
         pub static #static_name: #path = #path {
             bucket: #bucket,
-            create: #name::create_command,
+            create: #create,
             exec: #exec,
             flags: #flags,
             name: #name_lit,
             id: std::sync::OnceLock::new(),
         };
 
+        fn #create() -> twilight_interactions::command::ApplicationCommandData {
+            let mut command = #name::create_command();
+            crate::core::locale::localize_command(&mut command);
+
+            command
+        }
+
         fn #exec(
             ctx: std::sync::Arc<crate::core::Context>,
             command: crate::util::interaction::InteractionCommand,
@@ -6,6 +6,7 @@ use std::{
 };
 
 use bathbot_util::CowUtils;
+use rkyv::{Archive, Serialize};
 use twilight_interactions::command::{CommandOption, CreateOption};
 
 pub struct BgGameScore {
@@ -18,12 +19,57 @@ pub struct HlGameScore {
     pub highscore: i32,
 }
 
+pub struct TriviaScore {
+    pub discord_id: i64,
+    pub score: i32,
+}
+
+/// State of a running background game so that it can be resumed after a
+/// restart.
+#[derive(Archive, Serialize)]
+pub struct BgGameSnapshot {
+    pub channel: u64,
+    pub mode: u8,
+    /// Whether backgrounds or patterns are shown
+    pub kind: u8,
+    pub mapset_id: u32,
+    /// Bits of the included [`MapsetTags`]
+    pub included: u32,
+    /// Bits of the excluded [`MapsetTags`]
+    pub excluded: u32,
+    /// Bits of the [`Effects`]
+    pub effects: u8,
+    pub difficulty: u8,
+    /// Discord user ids and their score in the current game
+    pub scores: Vec<(u64, u32)>,
+}
+
+/// State of a running higherlower game so that it can be resumed after a
+/// restart.
+#[derive(Archive, Serialize)]
+pub struct HlGameSnapshot {
+    pub channel: u64,
+    pub msg: u64,
+    pub user: u64,
+    pub version: u8,
+    pub mode: u8,
+    pub score: u32,
+}
+
 #[derive(Copy, Clone, CommandOption, CreateOption)]
 pub enum HlVersion {
     #[option(name = "Score PP", value = "score_pp")]
     ScorePp = 0,
     #[option(name = "Farm", value = "farm")]
     FarmMaps = 1,
+    #[option(name = "Star rating", value = "stars")]
+    StarRating = 2,
+    #[option(name = "BPM", value = "bpm")]
+    Bpm = 3,
+    #[option(name = "Playcount", value = "playcount")]
+    Playcount = 4,
+    #[option(name = "Rank", value = "rank")]
+    Rank = 5,
 }
 
 bitflags::bitflags! {
@@ -415,7 +415,7 @@ pub struct SnipePlayerOldest {
 
 #[derive(Debug)]
 pub struct SnipeRecent {
-    pub uid: u32,
+    pub uid: u64,
     pub map_id: u32,
     pub user_id: u32,
     pub country: CountryCode,
@@ -447,7 +447,7 @@ impl<'de> Deserialize<'de> for SnipeRecent {
     fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
         #[derive(Deserialize)]
         pub struct SnipeRecentInner<'mods> {
-            uid: u32,
+            uid: u64,
             map_id: u32,
             #[serde(rename = "player_id")]
             user_id: u32,
@@ -554,7 +554,7 @@ impl<'de> Visitor<'de> for SnipeModsVisitor {
 }
 
 pub struct SnipeScore {
-    pub uid: u32,
+    pub uid: u64,
     pub user_id: u32,
     pub username: Username,
     pub country: CountryCode,
@@ -582,7 +582,7 @@ impl<'de> Deserialize<'de> for SnipeScore {
     fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
         #[derive(Deserialize)]
         struct SnipeScoreInner<'mods> {
-            uid: u32,
+            uid: u64,
             #[serde(rename = "player_id")]
             user_id: u32,
             username: Username,
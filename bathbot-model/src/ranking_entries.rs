@@ -6,8 +6,8 @@ use time::OffsetDateTime;
 use twilight_model::id::{marker::GuildMarker, Id};
 
 use crate::{
-    twilight_model::util::ImageHash, BgGameScore, HlGameScore, HlVersion, UserModeStatsColumn,
-    UserStatsColumn, UserStatsEntries, UserStatsEntry,
+    twilight_model::util::ImageHash, BgGameScore, HlGameScore, HlVersion, TriviaScore,
+    UserModeStatsColumn, UserStatsColumn, UserStatsEntries, UserStatsEntry,
 };
 
 pub struct RankingEntry<V> {
@@ -213,6 +213,9 @@ pub enum RankingKind {
     RankedScore {
         mode: GameMode,
     },
+    TriviaScores {
+        scores: Vec<TriviaScore>,
+    },
     UserStats {
         guild_icon: Option<(Id<GuildMarker>, ImageHash)>,
         kind: UserStatsKind,
@@ -254,6 +257,10 @@ impl RankingKind {
                 let text = match version {
                     HlVersion::ScorePp => "Server leaderboard for Higherlower (Score PP)",
                     HlVersion::FarmMaps => "Server leaderboard for Higherlower (Farm)",
+                    HlVersion::StarRating => "Server leaderboard for Higherlower (Star rating)",
+                    HlVersion::Bpm => "Server leaderboard for Higherlower (BPM)",
+                    HlVersion::Playcount => "Server leaderboard for Higherlower (Playcount)",
+                    HlVersion::Rank => "Server leaderboard for Higherlower (Rank)",
                 };
 
                 EmbedHeader::Author(AuthorBuilder::new(text))
@@ -345,6 +352,11 @@ impl RankingKind {
 
                 EmbedHeader::title(text, url)
             }
+            Self::TriviaScores { .. } => {
+                let text = "Server leaderboard for correct trivia answers";
+
+                EmbedHeader::Author(AuthorBuilder::new(text))
+            }
             Self::UserStats { guild_icon, kind } => {
                 let mut author_text = "Server leaderboard".to_owned();
 
@@ -55,6 +55,8 @@ pub struct TwitchUser {
 pub struct TwitchStream {
     #[serde(rename = "game_id", deserialize_with = "str_to_maybe_u64")]
     pub game_id: Option<u64>,
+    /// Empty if no game was specified
+    pub game_name: Box<str>,
     #[serde(rename = "id", deserialize_with = "str_to_u64")]
     pub stream_id: u64,
     // Gets modified inside the struct so required to keep as `String`
@@ -87,6 +89,74 @@ pub struct TwitchDataList<T> {
     pub data: Vec<T>,
 }
 
+/// An EventSub subscription
+#[derive(Debug, Deserialize)]
+pub struct TwitchSubscription {
+    pub id: Box<str>,
+    #[serde(rename = "type")]
+    pub kind: Box<str>,
+    pub status: Box<str>,
+    pub condition: TwitchBroadcaster,
+    pub transport: TwitchTransport,
+}
+
+impl TwitchSubscription {
+    /// Whether the subscription is enabled or about to be
+    pub fn is_active(&self) -> bool {
+        matches!(
+            self.status.as_ref(),
+            "enabled" | "webhook_callback_verification_pending"
+        )
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TwitchTransport {
+    /// Only present for webhook subscriptions
+    #[serde(default)]
+    pub callback: Option<Box<str>>,
+}
+
+#[derive(Deserialize)]
+pub struct TwitchSubscriptionList {
+    pub data: Vec<TwitchSubscription>,
+    #[serde(default)]
+    pub pagination: TwitchPagination,
+}
+
+#[derive(Default, Deserialize)]
+pub struct TwitchPagination {
+    #[serde(default)]
+    pub cursor: Option<Box<str>>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TwitchBroadcaster {
+    #[serde(rename = "broadcaster_user_id", deserialize_with = "str_to_u64")]
+    pub user_id: u64,
+}
+
+/// Body of a request that twitch sends to an EventSub webhook
+#[derive(Debug, Deserialize)]
+pub struct TwitchEventSubPayload {
+    pub subscription: TwitchSubscription,
+    /// Only present for `webhook_callback_verification` messages
+    #[serde(default)]
+    pub challenge: Option<Box<str>>,
+    /// Only present for `notification` messages
+    #[serde(default)]
+    pub event: Option<TwitchBroadcaster>,
+}
+
+/// A stream changing its status or having its subscription revoked as
+/// notified through EventSub
+#[derive(Copy, Clone, Debug)]
+pub enum TwitchStreamEvent {
+    Online { user_id: u64 },
+    Offline { user_id: u64 },
+    Revoked { user_id: u64 },
+}
+
 #[derive(Debug, Deserialize)]
 pub struct TwitchVideo {
     #[serde(with = "datetime_z")]
@@ -0,0 +1,10 @@
+DROP TABLE snipe_player_history;
+
+DROP INDEX snipe_national_changes_sniped_index;
+DROP INDEX snipe_national_changes_sniper_index;
+
+DROP TABLE snipe_national_changes;
+
+DROP INDEX snipe_national_firsts_user_index;
+
+DROP TABLE snipe_national_firsts;
\ No newline at end of file
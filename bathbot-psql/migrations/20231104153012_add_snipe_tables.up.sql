@@ -0,0 +1,32 @@
+CREATE TABLE IF NOT EXISTS snipe_national_firsts (
+    country_code VARCHAR(2) NOT NULL,
+    map_id       INT4 NOT NULL,
+    user_id      INT4 NOT NULL,
+    score_id     INT8 NOT NULL,
+    score        INT4 NOT NULL,
+    last_update  TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (country_code, map_id)
+);
+
+CREATE INDEX snipe_national_firsts_user_index ON snipe_national_firsts (country_code, user_id);
+
+CREATE TABLE IF NOT EXISTS snipe_national_changes (
+    country_code VARCHAR(2) NOT NULL,
+    map_id       INT4 NOT NULL,
+    score_id     INT8 NOT NULL,
+    sniper_id    INT4 NOT NULL,
+    sniped_id    INT4,
+    changed_at   TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (country_code, map_id, score_id)
+);
+
+CREATE INDEX snipe_national_changes_sniper_index ON snipe_national_changes (sniper_id, changed_at);
+CREATE INDEX snipe_national_changes_sniped_index ON snipe_national_changes (sniped_id, changed_at);
+
+CREATE TABLE IF NOT EXISTS snipe_player_history (
+    country_code VARCHAR(2) NOT NULL,
+    user_id      INT4 NOT NULL,
+    date         DATE NOT NULL DEFAULT CURRENT_DATE,
+    count        INT4 NOT NULL,
+    PRIMARY KEY (country_code, user_id, date)
+);
\ No newline at end of file
@@ -0,0 +1 @@
+DROP TABLE snipe_notifications;
\ No newline at end of file
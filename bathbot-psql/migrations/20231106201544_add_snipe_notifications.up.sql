@@ -0,0 +1,8 @@
+CREATE TABLE IF NOT EXISTS snipe_notifications (
+    discord_id    INT8 NOT NULL,
+    osu_id        INT4 NOT NULL,
+    country_code  VARCHAR(2) NOT NULL,
+    channel_id    INT8, -- NULL if notifications are sent via DM
+    last_notified TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (discord_id)
+);
\ No newline at end of file
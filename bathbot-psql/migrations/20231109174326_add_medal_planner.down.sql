@@ -0,0 +1,2 @@
+DROP TABLE user_medal_progress;
+DROP TABLE user_medal_plans;
\ No newline at end of file
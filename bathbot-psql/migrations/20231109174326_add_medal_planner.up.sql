@@ -0,0 +1,15 @@
+CREATE TABLE IF NOT EXISTS user_medal_plans (
+    discord_id INT8 NOT NULL,
+    medal_id   INT4 NOT NULL,
+    status     INT2 NOT NULL, -- 0: in progress, 1: hidden
+    PRIMARY KEY (discord_id, medal_id)
+);
+
+CREATE TABLE IF NOT EXISTS user_medal_progress (
+    discord_id  INT8 NOT NULL,
+    osu_id      INT4 NOT NULL,
+    medal_id    INT4 NOT NULL,
+    achieved_at TIMESTAMPTZ NOT NULL,
+    tracked_at  TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (discord_id, osu_id, medal_id)
+);
\ No newline at end of file
@@ -0,0 +1,4 @@
+DROP INDEX bookmark_collection_shares_user_index;
+DROP TABLE bookmark_collection_shares;
+DROP TABLE bookmark_collection_maps;
+DROP TABLE bookmark_collections;
\ No newline at end of file
@@ -0,0 +1,23 @@
+CREATE TABLE IF NOT EXISTS bookmark_collections (
+    owner_id   INT8 NOT NULL,
+    name       VARCHAR(32) NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (owner_id, name)
+);
+
+CREATE TABLE IF NOT EXISTS bookmark_collection_maps (
+    owner_id    INT8 NOT NULL,
+    name        VARCHAR(32) NOT NULL,
+    map_id      INT4 NOT NULL,
+    insert_date TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (owner_id, name, map_id)
+);
+
+CREATE TABLE IF NOT EXISTS bookmark_collection_shares (
+    owner_id INT8 NOT NULL,
+    name     VARCHAR(32) NOT NULL,
+    user_id  INT8 NOT NULL,
+    PRIMARY KEY (owner_id, name, user_id)
+);
+
+CREATE INDEX bookmark_collection_shares_user_index ON bookmark_collection_shares (user_id);
\ No newline at end of file
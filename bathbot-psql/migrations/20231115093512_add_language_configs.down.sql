@@ -0,0 +1,2 @@
+ALTER TABLE user_configs DROP COLUMN language;
+ALTER TABLE guild_configs DROP COLUMN language;
\ No newline at end of file
@@ -0,0 +1 @@
+ALTER TABLE guild_configs DROP COLUMN command_aliases;
\ No newline at end of file
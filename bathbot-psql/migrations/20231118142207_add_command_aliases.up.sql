@@ -0,0 +1 @@
+ALTER TABLE guild_configs ADD COLUMN command_aliases BYTEA;
\ No newline at end of file
@@ -0,0 +1 @@
+ALTER TABLE guild_configs DROP COLUMN command_suggestions;
\ No newline at end of file
@@ -0,0 +1 @@
+DROP TABLE minesweeper_times;
\ No newline at end of file
@@ -0,0 +1,6 @@
+CREATE TABLE IF NOT EXISTS minesweeper_times (
+    discord_id INT8 NOT NULL,
+    difficulty INT2 NOT NULL,
+    best_time  INT4 NOT NULL,
+    PRIMARY KEY (discord_id, difficulty)
+);
\ No newline at end of file
@@ -0,0 +1 @@
+DROP TABLE trivia_scores;
\ No newline at end of file
@@ -0,0 +1,6 @@
+CREATE TABLE IF NOT EXISTS trivia_scores (
+    guild_id   INT8 NOT NULL,
+    discord_id INT8 NOT NULL,
+    score      INT4 NOT NULL DEFAULT 0,
+    PRIMARY KEY (guild_id, discord_id)
+);
\ No newline at end of file
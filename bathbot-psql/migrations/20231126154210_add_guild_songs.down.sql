@@ -0,0 +1 @@
+DROP TABLE guild_songs;
\ No newline at end of file
@@ -0,0 +1,6 @@
+CREATE TABLE IF NOT EXISTS guild_songs (
+    guild_id INT8 NOT NULL,
+    name     VARCHAR(32) NOT NULL,
+    lyrics   TEXT NOT NULL, -- one line per lyric, optionally prefixed with "<delay ms> | "
+    PRIMARY KEY (guild_id, name)
+);
\ No newline at end of file
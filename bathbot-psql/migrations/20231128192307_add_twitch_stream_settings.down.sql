@@ -0,0 +1,5 @@
+ALTER TABLE tracked_twitch_streams
+    DROP COLUMN role_id,
+    DROP COLUMN message,
+    DROP COLUMN keywords,
+    DROP COLUMN edit_offline;
\ No newline at end of file
@@ -0,0 +1,5 @@
+ALTER TABLE tracked_twitch_streams
+    ADD COLUMN role_id      INT8,
+    ADD COLUMN message      VARCHAR(500),
+    ADD COLUMN keywords     VARCHAR(32)[] NOT NULL DEFAULT '{}',
+    ADD COLUMN edit_offline BOOL NOT NULL DEFAULT TRUE;
\ No newline at end of file
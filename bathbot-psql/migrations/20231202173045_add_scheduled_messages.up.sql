@@ -0,0 +1,12 @@
+CREATE TABLE IF NOT EXISTS scheduled_messages (
+    schedule_id SERIAL NOT NULL,
+    author_id   INT8 NOT NULL,
+    guild_id    INT8, -- NULL for reminders in DMs
+    channel_id  INT8 NOT NULL,
+    content     VARCHAR(1000) NOT NULL,
+    is_reminder BOOL NOT NULL, -- reminders mention their author, announcements are sent as is
+    trigger_at  TIMESTAMPTZ NOT NULL,
+    PRIMARY KEY (schedule_id)
+);
+
+CREATE INDEX scheduled_messages_trigger_at_index ON scheduled_messages (trigger_at);
\ No newline at end of file
@@ -0,0 +1,34 @@
+CREATE TABLE IF NOT EXISTS tournaments (
+    tournament_id SERIAL NOT NULL,
+    guild_id      INT8 NOT NULL,
+    channel_id    INT8 NOT NULL, -- reminders and results are posted here
+    name          VARCHAR(32) NOT NULL,
+    created_at    TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (tournament_id),
+    UNIQUE (guild_id, name)
+);
+
+CREATE TABLE IF NOT EXISTS tournament_players (
+    tournament_id INT4 NOT NULL,
+    user_id       INT4 NOT NULL,
+    username      VARCHAR(32) NOT NULL,
+    team          VARCHAR(32) NOT NULL,
+    PRIMARY KEY (tournament_id, user_id)
+);
+
+CREATE TABLE IF NOT EXISTS tournament_matches (
+    match_id      SERIAL NOT NULL,
+    tournament_id INT4 NOT NULL,
+    round         VARCHAR(32) NOT NULL,
+    team_a        VARCHAR(32) NOT NULL,
+    team_b        VARCHAR(32) NOT NULL,
+    scheduled_at  TIMESTAMPTZ NOT NULL,
+    reminder_id   INT4, -- scheduled message that reminds the players
+    mp_id         INT4,
+    warmups       INT2 NOT NULL DEFAULT 0,
+    score_a       INT2, -- NULL until the linked match is over
+    score_b       INT2,
+    PRIMARY KEY (match_id)
+);
+
+CREATE INDEX tournament_matches_tournament_index ON tournament_matches (tournament_id);
\ No newline at end of file
@@ -0,0 +1,2 @@
+DROP TABLE IF EXISTS mappool_maps;
+DROP TABLE IF EXISTS mappools;
\ No newline at end of file
@@ -0,0 +1,16 @@
+CREATE TABLE IF NOT EXISTS mappools (
+    mappool_id SERIAL NOT NULL,
+    guild_id   INT8 NOT NULL,
+    name       VARCHAR(32) NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (mappool_id),
+    UNIQUE (guild_id, name)
+);
+
+CREATE TABLE IF NOT EXISTS mappool_maps (
+    mappool_id INT4 NOT NULL,
+    slot       VARCHAR(8) NOT NULL, -- e.g. NM1 or TB
+    map_id     INT4 NOT NULL,
+    mods       INT4, -- required mods, NULL for freemod
+    PRIMARY KEY (mappool_id, slot)
+);
\ No newline at end of file
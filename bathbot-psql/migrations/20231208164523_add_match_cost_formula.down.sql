@@ -0,0 +1 @@
+ALTER TABLE guild_configs DROP COLUMN match_cost_formula;
\ No newline at end of file
@@ -0,0 +1 @@
+ALTER TABLE guild_configs ADD COLUMN match_cost_formula VARCHAR(128);
\ No newline at end of file
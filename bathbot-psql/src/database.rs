@@ -8,7 +8,7 @@ use sqlx::{
 
 use crate::refresh::refresh_materialized_views;
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct Database {
     pool: PgPool,
 }
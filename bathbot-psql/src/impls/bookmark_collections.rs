@@ -0,0 +1,316 @@
+use eyre::{Result, WrapErr};
+use futures::StreamExt;
+use twilight_model::id::{marker::UserMarker, Id};
+
+use crate::{
+    model::osu::{DbBookmarkCollection, DbCollectionMap},
+    util::parse_mode,
+    Database,
+};
+
+impl Database {
+    /// Returns all collections owned by or shared with the user.
+    pub async fn select_bookmark_collections(
+        &self,
+        user_id: Id<UserMarker>,
+    ) -> Result<Vec<DbBookmarkCollection>> {
+        let query = sqlx::query_as!(
+            DbBookmarkCollection,
+            r#"
+SELECT 
+  collections.owner_id, 
+  collections.name, 
+  collections.created_at, 
+  COUNT(maps.map_id) AS "map_count!" 
+FROM 
+  bookmark_collections AS collections 
+  LEFT JOIN bookmark_collection_maps AS maps ON collections.owner_id = maps.owner_id 
+  AND collections.name = maps.name 
+WHERE 
+  collections.owner_id = $1 
+  OR EXISTS (
+    SELECT 
+      1 
+    FROM 
+      bookmark_collection_shares AS shares 
+    WHERE 
+      shares.owner_id = collections.owner_id 
+      AND shares.name = collections.name 
+      AND shares.user_id = $1 
+  ) 
+GROUP BY 
+  collections.owner_id, 
+  collections.name, 
+  collections.created_at 
+ORDER BY 
+  collections.created_at DESC"#,
+            user_id.get() as i64
+        );
+
+        query.fetch_all(self).await.wrap_err("Failed to fetch all")
+    }
+
+    /// Returns `false` if the collection already existed.
+    pub async fn insert_bookmark_collection(
+        &self,
+        owner_id: Id<UserMarker>,
+        name: &str,
+    ) -> Result<bool> {
+        let query = sqlx::query!(
+            r#"
+INSERT INTO bookmark_collections (owner_id, name) 
+VALUES 
+  ($1, $2) ON CONFLICT (owner_id, name) DO NOTHING"#,
+            owner_id.get() as i64,
+            name
+        );
+
+        let res = query
+            .execute(self)
+            .await
+            .wrap_err("Failed to execute query")?;
+
+        Ok(res.rows_affected() > 0)
+    }
+
+    /// Deletes the collection alongside its maps and shares.
+    ///
+    /// Returns `false` if the collection did not exist.
+    pub async fn delete_bookmark_collection(
+        &self,
+        owner_id: Id<UserMarker>,
+        name: &str,
+    ) -> Result<bool> {
+        let mut tx = self.begin().await.wrap_err("Failed to begin transaction")?;
+
+        let query = sqlx::query!(
+            r#"
+DELETE FROM 
+  bookmark_collection_maps 
+WHERE 
+  owner_id = $1 
+  AND name = $2"#,
+            owner_id.get() as i64,
+            name
+        );
+
+        query
+            .execute(&mut *tx)
+            .await
+            .wrap_err("Failed to execute maps query")?;
+
+        let query = sqlx::query!(
+            r#"
+DELETE FROM 
+  bookmark_collection_shares 
+WHERE 
+  owner_id = $1 
+  AND name = $2"#,
+            owner_id.get() as i64,
+            name
+        );
+
+        query
+            .execute(&mut *tx)
+            .await
+            .wrap_err("Failed to execute shares query")?;
+
+        let query = sqlx::query!(
+            r#"
+DELETE FROM 
+  bookmark_collections 
+WHERE 
+  owner_id = $1 
+  AND name = $2"#,
+            owner_id.get() as i64,
+            name
+        );
+
+        let res = query
+            .execute(&mut *tx)
+            .await
+            .wrap_err("Failed to execute collection query")?;
+
+        tx.commit().await.wrap_err("Failed to commit transaction")?;
+
+        Ok(res.rows_affected() > 0)
+    }
+
+    pub async fn select_bookmark_collection_maps(
+        &self,
+        owner_id: Id<UserMarker>,
+        name: &str,
+    ) -> Result<Vec<DbCollectionMap>> {
+        let query = sqlx::query!(
+            r#"
+SELECT 
+  collection.insert_date, 
+  maps.map_id, 
+  maps.mapset_id, 
+  maps.checksum, 
+  maps.map_version, 
+  maps.gamemode, 
+  mapsets.artist, 
+  mapsets.title 
+FROM 
+  (
+    SELECT 
+      map_id, 
+      insert_date 
+    FROM 
+      bookmark_collection_maps 
+    WHERE 
+      owner_id = $1 
+      AND name = $2 
+  ) AS collection 
+  JOIN (
+    SELECT 
+      map_id, 
+      mapset_id, 
+      checksum, 
+      map_version, 
+      gamemode 
+    FROM 
+      osu_maps 
+  ) AS maps ON collection.map_id = maps.map_id 
+  JOIN (
+    SELECT 
+      mapset_id, 
+      artist, 
+      title 
+    FROM 
+      osu_mapsets 
+  ) AS mapsets ON maps.mapset_id = mapsets.mapset_id 
+ORDER BY 
+  collection.insert_date DESC"#,
+            owner_id.get() as i64,
+            name
+        );
+
+        let mut rows = query.fetch(self);
+        let mut maps = Vec::new();
+
+        while let Some(row_res) = rows.next().await {
+            let row = row_res.wrap_err("Failed to fetch next")?;
+
+            maps.push(DbCollectionMap {
+                insert_date: row.insert_date,
+                map_id: row.map_id as u32,
+                mapset_id: row.mapset_id as u32,
+                checksum: row.checksum.into_boxed_str(),
+                artist: row.artist.into_boxed_str(),
+                title: row.title.into_boxed_str(),
+                version: row.map_version.into_boxed_str(),
+                mode: parse_mode(row.gamemode),
+            });
+        }
+
+        Ok(maps)
+    }
+
+    /// Returns `false` if the map was already in the collection.
+    pub async fn insert_bookmark_collection_map(
+        &self,
+        owner_id: Id<UserMarker>,
+        name: &str,
+        map_id: u32,
+    ) -> Result<bool> {
+        let query = sqlx::query!(
+            r#"
+INSERT INTO bookmark_collection_maps (owner_id, name, map_id) 
+VALUES 
+  ($1, $2, $3) ON CONFLICT (owner_id, name, map_id) DO NOTHING"#,
+            owner_id.get() as i64,
+            name,
+            map_id as i32
+        );
+
+        let res = query
+            .execute(self)
+            .await
+            .wrap_err("Failed to execute query")?;
+
+        Ok(res.rows_affected() > 0)
+    }
+
+    /// Returns `false` if the map was not in the collection.
+    pub async fn delete_bookmark_collection_map(
+        &self,
+        owner_id: Id<UserMarker>,
+        name: &str,
+        map_id: u32,
+    ) -> Result<bool> {
+        let query = sqlx::query!(
+            r#"
+DELETE FROM 
+  bookmark_collection_maps 
+WHERE 
+  owner_id = $1 
+  AND name = $2 
+  AND map_id = $3"#,
+            owner_id.get() as i64,
+            name,
+            map_id as i32
+        );
+
+        let res = query
+            .execute(self)
+            .await
+            .wrap_err("Failed to execute query")?;
+
+        Ok(res.rows_affected() > 0)
+    }
+
+    /// Returns `false` if the collection was already shared with the user.
+    pub async fn insert_bookmark_collection_share(
+        &self,
+        owner_id: Id<UserMarker>,
+        name: &str,
+        user_id: Id<UserMarker>,
+    ) -> Result<bool> {
+        let query = sqlx::query!(
+            r#"
+INSERT INTO bookmark_collection_shares (owner_id, name, user_id) 
+VALUES 
+  ($1, $2, $3) ON CONFLICT (owner_id, name, user_id) DO NOTHING"#,
+            owner_id.get() as i64,
+            name,
+            user_id.get() as i64
+        );
+
+        let res = query
+            .execute(self)
+            .await
+            .wrap_err("Failed to execute query")?;
+
+        Ok(res.rows_affected() > 0)
+    }
+
+    /// Returns `false` if the collection was not shared with the user.
+    pub async fn delete_bookmark_collection_share(
+        &self,
+        owner_id: Id<UserMarker>,
+        name: &str,
+        user_id: Id<UserMarker>,
+    ) -> Result<bool> {
+        let query = sqlx::query!(
+            r#"
+DELETE FROM 
+  bookmark_collection_shares 
+WHERE 
+  owner_id = $1 
+  AND name = $2 
+  AND user_id = $3"#,
+            owner_id.get() as i64,
+            name,
+            user_id.get() as i64
+        );
+
+        let res = query
+            .execute(self)
+            .await
+            .wrap_err("Failed to execute query")?;
+
+        Ok(res.rows_affected() > 0)
+    }
+}
@@ -31,7 +31,11 @@ SELECT
   list_size, 
   render_button, 
   allow_custom_skins, 
-  hide_medal_solution 
+  hide_medal_solution, 
+  language, 
+  command_aliases, 
+  command_suggestions, 
+  match_cost_formula 
 FROM 
   guild_configs"#
         );
@@ -65,6 +69,10 @@ FROM
             render_button,
             allow_custom_skins,
             hide_medal_solution,
+            language,
+            command_aliases,
+            command_suggestions,
+            match_cost_formula,
         } = config;
 
         let authorities =
@@ -73,18 +81,23 @@ FROM
         let prefixes =
             rkyv::to_bytes::<_, 32>(prefixes).wrap_err("failed to serialize prefixes")?;
 
+        let command_aliases = rkyv::to_bytes::<_, 256>(command_aliases)
+            .wrap_err("failed to serialize command aliases")?;
+
         let query = sqlx::query!(
             r#"
 INSERT INTO guild_configs (
   guild_id, authorities, prefixes, allow_songs, 
   score_size, retries, osu_track_limit, 
   minimized_pp, list_size, render_button, 
-  allow_custom_skins, hide_medal_solution
+  allow_custom_skins, hide_medal_solution, 
+  language, command_aliases, command_suggestions, 
+  match_cost_formula
 ) 
 VALUES 
   (
     $1, $2, $3, $4, $5, $6, $7, $8, $9, $10, 
-    $11, $12
+    $11, $12, $13, $14, $15, $16
   ) ON CONFLICT (guild_id) DO 
 UPDATE 
 SET 
@@ -98,7 +111,11 @@ SET
   list_size = $9, 
   render_button = $10, 
   allow_custom_skins = $11, 
-  hide_medal_solution = $12"#,
+  hide_medal_solution = $12, 
+  language = $13, 
+  command_aliases = $14, 
+  command_suggestions = $15, 
+  match_cost_formula = $16"#,
             guild_id.get() as i64,
             &authorities as &[u8],
             &prefixes as &[u8],
@@ -111,6 +128,10 @@ SET
             *render_button,
             *allow_custom_skins,
             hide_medal_solution.map(i16::from),
+            language.map(i16::from),
+            &command_aliases as &[u8],
+            *command_suggestions,
+            match_cost_formula.as_deref(),
         );
 
         query
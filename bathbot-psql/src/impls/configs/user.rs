@@ -6,8 +6,8 @@ use twilight_model::id::{marker::UserMarker, Id};
 
 use crate::{
     model::configs::{
-        DbSkinEntry, DbUserConfig, ListSize, MinimizedPp, OsuUserId, OsuUsername, Retries,
-        ScoreSize, SkinEntry, UserConfig,
+        DbSkinEntry, DbUserConfig, Language, ListSize, MinimizedPp, OsuUserId, OsuUsername,
+        Retries, ScoreSize, SkinEntry, UserConfig,
     },
     Database,
 };
@@ -29,7 +29,8 @@ SELECT
   retries, 
   twitch_id, 
   timezone_seconds, 
-  render_button 
+  render_button, 
+  language 
 FROM 
   user_configs 
 WHERE 
@@ -67,7 +68,8 @@ SELECT
   retries, 
   twitch_id, 
   timezone_seconds, 
-  render_button 
+  render_button, 
+  language 
 FROM 
   user_configs 
 WHERE 
@@ -95,6 +97,7 @@ WHERE
                     .map(UtcOffset::from_whole_seconds)
                     .map(Result::unwrap),
                 render_button: row.render_button,
+                language: row.language.map(Language::try_from).and_then(Result::ok),
             });
 
         Ok(config_opt)
@@ -318,6 +321,7 @@ FROM
             twitch_id,
             timezone,
             render_button,
+            language,
         } = config;
 
         let query = sqlx::query!(
@@ -325,10 +329,14 @@ FROM
 INSERT INTO user_configs (
   discord_id, osu_id, gamemode, twitch_id, 
   score_size, retries, minimized_pp, 
-  list_size, timezone_seconds, render_button
+  list_size, timezone_seconds, render_button, 
+  language
 ) 
 VALUES 
-  ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10) ON CONFLICT (discord_id) DO 
+  (
+    $1, $2, $3, $4, $5, $6, $7, $8, $9, $10, 
+    $11
+  ) ON CONFLICT (discord_id) DO 
 UPDATE 
 SET 
   osu_id = $2, 
@@ -339,7 +347,8 @@ SET
   minimized_pp = $7, 
   list_size = $8, 
   timezone_seconds = $9, 
-  render_button = $10"#,
+  render_button = $10, 
+  language = $11"#,
             user_id.get() as i64,
             osu.map(|id| id as i32),
             mode.map(|mode| mode as i16) as Option<i16>,
@@ -350,6 +359,7 @@ SET
             list_size.map(i16::from),
             timezone.map(UtcOffset::whole_seconds),
             *render_button,
+            language.map(i16::from),
         );
 
         query
@@ -454,4 +464,48 @@ WHERE
 
         Ok(row_opt.and_then(|row| row.gamemode.map(|mode| GameMode::from(mode as u8))))
     }
+
+    pub async fn select_user_language(&self, user_id: Id<UserMarker>) -> Result<Option<Language>> {
+        let query = sqlx::query!(
+            r#"
+SELECT 
+  language 
+FROM 
+  user_configs 
+WHERE 
+  discord_id = $1"#,
+            user_id.get() as i64
+        );
+
+        let language_opt = query
+            .fetch_optional(self)
+            .await
+            .wrap_err("failed to fetch optional")?
+            .and_then(|row| row.language)
+            .and_then(|language| Language::try_from(language).ok());
+
+        Ok(language_opt)
+    }
+
+    pub async fn select_user_timezone(&self, user_id: Id<UserMarker>) -> Result<Option<UtcOffset>> {
+        let query = sqlx::query!(
+            r#"
+SELECT 
+  timezone_seconds 
+FROM 
+  user_configs 
+WHERE 
+  discord_id = $1"#,
+            user_id.get() as i64
+        );
+
+        let timezone_opt = query
+            .fetch_optional(self)
+            .await
+            .wrap_err("failed to fetch optional")?
+            .and_then(|row| row.timezone_seconds)
+            .and_then(|seconds| UtcOffset::from_whole_seconds(seconds).ok());
+
+        Ok(timezone_opt)
+    }
 }
@@ -4,7 +4,10 @@ use bathbot_model::HlGameScore;
 use eyre::{Result, WrapErr};
 use twilight_model::id::{marker::UserMarker, Id};
 
-use crate::{model::games::DbHlGameScore, Database};
+use crate::{
+    model::games::{DbHlGameScore, DbHlMap},
+    Database,
+};
 
 impl Database {
     pub async fn select_higherlower_scores_by_version(
@@ -90,4 +93,42 @@ WHERE
 
         Ok(row_opt.is_some())
     }
+
+    /// Random ranked, approved, or loved osu!standard maps that have nomod
+    /// difficulty attributes stored.
+    pub async fn select_higherlower_maps(&self, amount: usize) -> Result<Vec<DbHlMap>> {
+        let query = sqlx::query_as!(
+            DbHlMap,
+            r#"
+SELECT 
+  maps.map_id, 
+  maps.mapset_id, 
+  mapsets.artist, 
+  mapsets.title, 
+  maps.map_version, 
+  maps.seconds_drain, 
+  maps.bpm, 
+  maps.cs, 
+  maps.ar, 
+  maps.od, 
+  maps.hp, 
+  diffs.stars, 
+  diffs.max_combo 
+FROM 
+  osu_maps AS maps 
+  JOIN osu_mapsets AS mapsets ON maps.mapset_id = mapsets.mapset_id 
+  JOIN osu_map_difficulty AS diffs ON maps.map_id = diffs.map_id 
+WHERE 
+  maps.gamemode = 0 
+  AND diffs.mods = 0 
+  AND mapsets.rank_status IN (1, 2, 4) 
+ORDER BY 
+  RANDOM() 
+LIMIT 
+  $1"#,
+            amount as i64
+        );
+
+        query.fetch_all(self).await.wrap_err("failed to fetch all")
+    }
 }
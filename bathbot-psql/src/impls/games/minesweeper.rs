@@ -0,0 +1,65 @@
+use eyre::{Result, WrapErr};
+use twilight_model::id::{marker::UserMarker, Id};
+
+use crate::Database;
+
+impl Database {
+    /// Best time in milliseconds
+    pub async fn select_minesweeper_best_time(
+        &self,
+        user_id: Id<UserMarker>,
+        difficulty: i16,
+    ) -> Result<Option<u32>> {
+        let query = sqlx::query!(
+            r#"
+SELECT 
+  best_time 
+FROM 
+  minesweeper_times 
+WHERE 
+  discord_id = $1 
+  AND difficulty = $2"#,
+            user_id.get() as i64,
+            difficulty,
+        );
+
+        let row_opt = query
+            .fetch_optional(self)
+            .await
+            .wrap_err("failed to fetch optional")?;
+
+        Ok(row_opt.map(|row| row.best_time as u32))
+    }
+
+    /// Returns whether the time is a new best time
+    pub async fn upsert_minesweeper_time(
+        &self,
+        user_id: Id<UserMarker>,
+        difficulty: i16,
+        time_ms: u32,
+    ) -> Result<bool> {
+        let query = sqlx::query!(
+            r#"
+INSERT INTO minesweeper_times (
+  discord_id, difficulty, best_time
+) 
+VALUES 
+  ($1, $2, $3) ON CONFLICT (discord_id, difficulty) DO 
+UPDATE 
+SET 
+  best_time = $3 
+WHERE 
+  minesweeper_times.best_time > $3 RETURNING best_time"#,
+            user_id.get() as i64,
+            difficulty,
+            time_ms as i32,
+        );
+
+        let row_opt = query
+            .fetch_optional(self)
+            .await
+            .wrap_err("failed to fetch optional")?;
+
+        Ok(row_opt.is_some())
+    }
+}
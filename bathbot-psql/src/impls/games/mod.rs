@@ -1,2 +1,4 @@
 mod bg;
 mod hl;
+mod minesweeper;
+mod trivia;
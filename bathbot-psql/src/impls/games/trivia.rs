@@ -0,0 +1,95 @@
+use std::mem;
+
+use bathbot_model::TriviaScore;
+use eyre::{Result, WrapErr};
+use twilight_model::id::{
+    marker::{GuildMarker, UserMarker},
+    Id,
+};
+
+use crate::{
+    model::games::{DbTriviaMapset, DbTriviaScore},
+    Database,
+};
+
+impl Database {
+    pub async fn increment_trivia_score(
+        &self,
+        guild_id: Id<GuildMarker>,
+        user_id: Id<UserMarker>,
+        amount: i32,
+    ) -> Result<()> {
+        let query = sqlx::query!(
+            r#"
+INSERT INTO trivia_scores (guild_id, discord_id, score) 
+VALUES 
+  ($1, $2, $3) ON CONFLICT (guild_id, discord_id) DO 
+UPDATE 
+SET 
+  score = trivia_scores.score + $3"#,
+            guild_id.get() as i64,
+            user_id.get() as i64,
+            amount
+        );
+
+        query
+            .execute(self)
+            .await
+            .wrap_err("failed to execute query")?;
+
+        Ok(())
+    }
+
+    pub async fn select_trivia_scores(
+        &self,
+        guild_id: Id<GuildMarker>,
+    ) -> Result<Vec<TriviaScore>> {
+        let query = sqlx::query_as!(
+            DbTriviaScore,
+            r#"
+SELECT 
+  discord_id, 
+  score 
+FROM 
+  trivia_scores 
+WHERE 
+  guild_id = $1"#,
+            guild_id.get() as i64
+        );
+
+        let scores = query
+            .fetch_all(self)
+            .await
+            .wrap_err("failed to fetch all")?;
+
+        // SAFETY: the two types have the exact same structure
+        Ok(unsafe { mem::transmute(scores) })
+    }
+
+    /// Random ranked, approved, or loved mapsets that have a ranked date.
+    pub async fn select_trivia_mapsets(&self, amount: usize) -> Result<Vec<DbTriviaMapset>> {
+        let query = sqlx::query_as!(
+            DbTriviaMapset,
+            r#"
+SELECT 
+  mapset_id, 
+  user_id, 
+  artist, 
+  title, 
+  creator, 
+  ranked_date 
+FROM 
+  osu_mapsets 
+WHERE 
+  rank_status IN (1, 2, 4) 
+  AND ranked_date IS NOT NULL 
+ORDER BY 
+  RANDOM() 
+LIMIT 
+  $1"#,
+            amount as i64
+        );
+
+        query.fetch_all(self).await.wrap_err("failed to fetch all")
+    }
+}
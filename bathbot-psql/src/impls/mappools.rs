@@ -0,0 +1,184 @@
+use eyre::{Result, WrapErr};
+use twilight_model::id::{marker::GuildMarker, Id};
+
+use crate::{
+    model::mappool::{DbMappool, DbMappoolMap},
+    Database,
+};
+
+impl Database {
+    pub async fn select_mappools(&self, guild_id: Id<GuildMarker>) -> Result<Vec<DbMappool>> {
+        let query = sqlx::query_as!(
+            DbMappool,
+            r#"
+SELECT 
+  mappool_id, 
+  guild_id, 
+  name, 
+  created_at 
+FROM 
+  mappools 
+WHERE 
+  guild_id = $1 
+ORDER BY 
+  created_at DESC"#,
+            guild_id.get() as i64
+        );
+
+        query.fetch_all(self).await.wrap_err("Failed to fetch all")
+    }
+
+    pub async fn select_mappool(
+        &self,
+        guild_id: Id<GuildMarker>,
+        name: &str,
+    ) -> Result<Option<DbMappool>> {
+        let query = sqlx::query_as!(
+            DbMappool,
+            r#"
+SELECT 
+  mappool_id, 
+  guild_id, 
+  name, 
+  created_at 
+FROM 
+  mappools 
+WHERE 
+  guild_id = $1 
+  AND name = $2"#,
+            guild_id.get() as i64,
+            name
+        );
+
+        query
+            .fetch_optional(self)
+            .await
+            .wrap_err("Failed to fetch optional")
+    }
+
+    /// Returns `false` if the guild already has a mappool with that name.
+    pub async fn insert_mappool(&self, guild_id: Id<GuildMarker>, name: &str) -> Result<bool> {
+        let query = sqlx::query!(
+            r#"
+INSERT INTO mappools (guild_id, name) 
+VALUES 
+  ($1, $2) ON CONFLICT (guild_id, name) DO NOTHING"#,
+            guild_id.get() as i64,
+            name
+        );
+
+        let res = query
+            .execute(self)
+            .await
+            .wrap_err("Failed to execute query")?;
+
+        Ok(res.rows_affected() > 0)
+    }
+
+    /// Deletes the mappool alongside its maps.
+    pub async fn delete_mappool(&self, mappool_id: i32) -> Result<()> {
+        let mut tx = self.begin().await.wrap_err("Failed to begin transaction")?;
+
+        let query = sqlx::query!(
+            r#"
+DELETE FROM 
+  mappool_maps 
+WHERE 
+  mappool_id = $1"#,
+            mappool_id
+        );
+
+        query
+            .execute(&mut *tx)
+            .await
+            .wrap_err("Failed to execute maps query")?;
+
+        let query = sqlx::query!(
+            r#"
+DELETE FROM 
+  mappools 
+WHERE 
+  mappool_id = $1"#,
+            mappool_id
+        );
+
+        query
+            .execute(&mut *tx)
+            .await
+            .wrap_err("Failed to execute mappool query")?;
+
+        tx.commit().await.wrap_err("Failed to commit transaction")?;
+
+        Ok(())
+    }
+
+    pub async fn select_mappool_maps(&self, mappool_id: i32) -> Result<Vec<DbMappoolMap>> {
+        let query = sqlx::query_as!(
+            DbMappoolMap,
+            r#"
+SELECT 
+  slot, 
+  map_id, 
+  mods 
+FROM 
+  mappool_maps 
+WHERE 
+  mappool_id = $1"#,
+            mappool_id
+        );
+
+        query.fetch_all(self).await.wrap_err("Failed to fetch all")
+    }
+
+    /// Sets the map of the slot, replacing the previous one if there was any.
+    pub async fn upsert_mappool_map(
+        &self,
+        mappool_id: i32,
+        slot: &str,
+        map_id: u32,
+        mods: Option<u32>,
+    ) -> Result<()> {
+        let query = sqlx::query!(
+            r#"
+INSERT INTO mappool_maps (mappool_id, slot, map_id, mods) 
+VALUES 
+  ($1, $2, $3, $4) ON CONFLICT (mappool_id, slot) DO 
+UPDATE 
+SET 
+  map_id = $3, 
+  mods = $4"#,
+            mappool_id,
+            slot,
+            map_id as i32,
+            mods.map(|mods| mods as i32)
+        );
+
+        query
+            .execute(self)
+            .await
+            .wrap_err("Failed to execute query")?;
+
+        Ok(())
+    }
+
+    /// Returns `false` if the mappool had no such slot.
+    pub async fn delete_mappool_map(&self, mappool_id: i32, slot: &str) -> Result<bool> {
+        let query = sqlx::query!(
+            r#"
+DELETE FROM 
+  mappool_maps 
+WHERE 
+  mappool_id = $1 
+  AND slot = $2"#,
+            mappool_id,
+            slot
+        );
+
+        let res = query
+            .execute(self)
+            .await
+            .wrap_err("Failed to execute query")?;
+
+        Ok(res.rows_affected() > 0)
+    }
+}
@@ -0,0 +1,185 @@
+use eyre::{Result, WrapErr};
+use futures::StreamExt;
+use time::OffsetDateTime;
+use twilight_model::id::{marker::UserMarker, Id};
+
+use crate::{
+    model::osu::{DbMedalPlan, DbMedalProgress, MedalPlanStatus},
+    Database,
+};
+
+impl Database {
+    pub async fn select_medal_plans(&self, user_id: Id<UserMarker>) -> Result<Vec<DbMedalPlan>> {
+        let query = sqlx::query!(
+            r#"
+SELECT 
+  medal_id, 
+  status 
+FROM 
+  user_medal_plans 
+WHERE 
+  discord_id = $1"#,
+            user_id.get() as i64
+        );
+
+        let mut rows = query.fetch(self);
+        let mut plans = Vec::new();
+
+        while let Some(row_res) = rows.next().await {
+            let row = row_res.wrap_err("Failed to fetch next")?;
+
+            let Ok(status) = MedalPlanStatus::try_from(row.status) else {
+                warn!(status = row.status, "Invalid medal plan status");
+
+                continue;
+            };
+
+            plans.push(DbMedalPlan {
+                medal_id: row.medal_id as u32,
+                status,
+            });
+        }
+
+        Ok(plans)
+    }
+
+    pub async fn upsert_medal_plan(
+        &self,
+        user_id: Id<UserMarker>,
+        medal_id: u32,
+        status: MedalPlanStatus,
+    ) -> Result<()> {
+        let query = sqlx::query!(
+            r#"
+INSERT INTO user_medal_plans (discord_id, medal_id, status) 
+VALUES 
+  ($1, $2, $3) ON CONFLICT (discord_id, medal_id) DO 
+UPDATE 
+SET 
+  status = $3"#,
+            user_id.get() as i64,
+            medal_id as i32,
+            i16::from(status),
+        );
+
+        query
+            .execute(self)
+            .await
+            .wrap_err("Failed to execute query")?;
+
+        Ok(())
+    }
+
+    /// Returns whether an entry was deleted
+    pub async fn delete_medal_plan(&self, user_id: Id<UserMarker>, medal_id: u32) -> Result<bool> {
+        let query = sqlx::query!(
+            r#"
+DELETE FROM 
+  user_medal_plans 
+WHERE 
+  discord_id = $1 
+  AND medal_id = $2"#,
+            user_id.get() as i64,
+            medal_id as i32
+        );
+
+        let res = query
+            .execute(self)
+            .await
+            .wrap_err("Failed to execute query")?;
+
+        Ok(res.rows_affected() > 0)
+    }
+
+    pub async fn delete_medal_plans(
+        &self,
+        user_id: Id<UserMarker>,
+        medal_ids: &[i32],
+    ) -> Result<()> {
+        let query = sqlx::query!(
+            r#"
+DELETE FROM 
+  user_medal_plans 
+WHERE 
+  discord_id = $1 
+  AND medal_id = ANY($2)"#,
+            user_id.get() as i64,
+            medal_ids
+        );
+
+        query
+            .execute(self)
+            .await
+            .wrap_err("Failed to execute query")?;
+
+        Ok(())
+    }
+
+    pub async fn select_medal_progress(
+        &self,
+        user_id: Id<UserMarker>,
+        osu_id: u32,
+    ) -> Result<Vec<DbMedalProgress>> {
+        let query = sqlx::query!(
+            r#"
+SELECT 
+  medal_id, 
+  achieved_at, 
+  tracked_at 
+FROM 
+  user_medal_progress 
+WHERE 
+  discord_id = $1 
+  AND osu_id = $2"#,
+            user_id.get() as i64,
+            osu_id as i32
+        );
+
+        let mut rows = query.fetch(self);
+        let mut progress = Vec::new();
+
+        while let Some(row_res) = rows.next().await {
+            let row = row_res.wrap_err("Failed to fetch next")?;
+
+            progress.push(DbMedalProgress {
+                medal_id: row.medal_id as u32,
+                achieved_at: row.achieved_at,
+                tracked_at: row.tracked_at,
+            });
+        }
+
+        Ok(progress)
+    }
+
+    pub async fn insert_medal_progress(
+        &self,
+        user_id: Id<UserMarker>,
+        osu_id: u32,
+        medal_ids: &[i32],
+        achieved_at: &[OffsetDateTime],
+    ) -> Result<()> {
+        let query = sqlx::query!(
+            r#"
+INSERT INTO user_medal_progress (
+  discord_id, osu_id, medal_id, achieved_at 
+) 
+SELECT 
+  $1, 
+  $2, 
+  * 
+FROM 
+  UNNEST($3 :: INT4[], $4 :: TIMESTAMPTZ[]) ON CONFLICT (discord_id, osu_id, medal_id) DO NOTHING"#,
+            user_id.get() as i64,
+            osu_id as i32,
+            medal_ids,
+            achieved_at,
+        );
+
+        query
+            .execute(self)
+            .await
+            .wrap_err("Failed to execute query")?;
+
+        Ok(())
+    }
+}
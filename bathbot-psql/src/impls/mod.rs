@@ -1,5 +1,11 @@
+mod bookmark_collections;
 mod bookmarks;
 mod configs;
 mod games;
+mod mappools;
+mod medal_plans;
 mod osu;
+mod schedule;
+mod songs;
+mod tournaments;
 mod tracked_streams;
@@ -0,0 +1,659 @@
+use std::{collections::BTreeMap, iter};
+
+use eyre::{Result, WrapErr};
+use futures::StreamExt;
+use time::{Date, OffsetDateTime};
+use twilight_model::id::{
+    marker::{ChannelMarker, UserMarker},
+    Id,
+};
+
+use crate::{
+    model::osu::{
+        DbSnipeChange, DbSnipeCountryScore, DbSnipeCountryStatistics, DbSnipeDifference,
+        DbSnipeNotification, DbSnipeScore,
+    },
+    util::parse_status,
+    Database,
+};
+
+impl Database {
+    /// Stores the given score as national #1 of the map for the country.
+    ///
+    /// If `authoritative` is `true`, the score is known to be the current #1
+    /// e.g. because it was taken from the map leaderboard and will replace any
+    /// stored score. Otherwise, it will only replace a stored score with lower
+    /// score value and will not create new entries.
+    ///
+    /// Returns whether the national #1 changed hands.
+    pub async fn upsert_snipe_national_first(
+        &self,
+        country_code: &str,
+        map_id: u32,
+        user_id: u32,
+        score_id: u64,
+        score: u32,
+        authoritative: bool,
+    ) -> Result<bool> {
+        let mut tx = self.begin().await.wrap_err("failed to begin transaction")?;
+
+        let query = sqlx::query!(
+            r#"
+SELECT 
+  user_id, 
+  score_id, 
+  score 
+FROM 
+  snipe_national_firsts 
+WHERE 
+  country_code = $1 
+  AND map_id = $2 FOR 
+UPDATE"#,
+            country_code,
+            map_id as i32
+        );
+
+        let prev = query
+            .fetch_optional(&mut *tx)
+            .await
+            .wrap_err("failed to fetch optional")?;
+
+        match prev {
+            Some(ref row) if row.score_id == score_id as i64 => return Ok(false),
+            Some(ref row) if !authoritative && row.score >= score as i32 => return Ok(false),
+            None if !authoritative => return Ok(false),
+            _ => {}
+        }
+
+        let query = sqlx::query!(
+            r#"
+INSERT INTO snipe_national_firsts (
+  country_code, map_id, user_id, score_id, 
+  score 
+) 
+VALUES 
+  ($1, $2, $3, $4, $5) ON CONFLICT (country_code, map_id) DO 
+UPDATE 
+SET 
+  user_id = $3, 
+  score_id = $4, 
+  score = $5, 
+  last_update = NOW()"#,
+            country_code,
+            map_id as i32,
+            user_id as i32,
+            score_id as i64,
+            score as i32,
+        );
+
+        query
+            .execute(&mut *tx)
+            .await
+            .wrap_err("failed to execute query")?;
+
+        let sniped_id = match prev {
+            Some(row) if row.user_id == user_id as i32 => {
+                tx.commit().await.wrap_err("failed to commit transaction")?;
+
+                // The user improved their own national #1
+                return Ok(false);
+            }
+            Some(row) => Some(row.user_id),
+            None => None,
+        };
+
+        let query = sqlx::query!(
+            r#"
+INSERT INTO snipe_national_changes (
+  country_code, map_id, score_id, sniper_id, 
+  sniped_id 
+) 
+VALUES 
+  ($1, $2, $3, $4, $5) ON CONFLICT (country_code, map_id, score_id) DO NOTHING"#,
+            country_code,
+            map_id as i32,
+            score_id as i64,
+            user_id as i32,
+            sniped_id,
+        );
+
+        query
+            .execute(&mut *tx)
+            .await
+            .wrap_err("failed to execute query")?;
+
+        for user_id in iter::once(user_id as i32).chain(sniped_id) {
+            let query = sqlx::query!(
+                r#"
+INSERT INTO snipe_player_history (country_code, user_id, count) 
+VALUES 
+  (
+    $1, 
+    $2, 
+    (
+      SELECT 
+        COUNT(*) :: INT4 
+      FROM 
+        snipe_national_firsts 
+      WHERE 
+        country_code = $1 
+        AND user_id = $2 
+    ) 
+  ) ON CONFLICT (country_code, user_id, date) DO 
+UPDATE 
+SET 
+  count = excluded.count"#,
+                country_code,
+                user_id,
+            );
+
+            query
+                .execute(&mut *tx)
+                .await
+                .wrap_err("failed to execute history query")?;
+        }
+
+        tx.commit().await.wrap_err("failed to commit transaction")?;
+
+        Ok(true)
+    }
+
+    pub async fn select_snipe_player_scores(
+        &self,
+        country_code: &str,
+        user_id: u32,
+    ) -> Result<Vec<DbSnipeScore>> {
+        let query = sqlx::query!(
+            r#"
+SELECT 
+  firsts.map_id, 
+  firsts.user_id, 
+  firsts.score_id, 
+  scores.score, 
+  scores.mods, 
+  scores.maxcombo, 
+  scores.count50, 
+  scores.count100, 
+  scores.count300, 
+  scores.countmiss, 
+  scores.ended_at, 
+  performance.pp :: FLOAT4, 
+  difficulty.stars :: FLOAT4, 
+  maps.mapset_id, 
+  maps.map_version, 
+  maps.count_circles, 
+  maps.count_sliders, 
+  maps.count_spinners, 
+  maps.max_combo AS map_max_combo, 
+  maps.hp, 
+  maps.cs, 
+  maps.od, 
+  maps.ar, 
+  maps.bpm, 
+  mapsets.artist, 
+  mapsets.title, 
+  mapsets.rank_status 
+FROM 
+  (
+    SELECT 
+      map_id, 
+      user_id, 
+      score_id 
+    FROM 
+      snipe_national_firsts 
+    WHERE 
+      country_code = $1 
+      AND user_id = $2 
+  ) AS firsts 
+  JOIN osu_scores AS scores ON firsts.score_id = scores.score_id 
+  LEFT JOIN osu_scores_performance AS performance ON firsts.score_id = performance.score_id 
+  LEFT JOIN osu_map_difficulty AS difficulty ON firsts.map_id = difficulty.map_id 
+  AND scores.mods = difficulty.mods 
+  JOIN osu_maps AS maps ON firsts.map_id = maps.map_id 
+  JOIN osu_mapsets AS mapsets ON maps.mapset_id = mapsets.mapset_id"#,
+            country_code,
+            user_id as i32,
+        );
+
+        let mut rows = query.fetch(self);
+        let mut scores = Vec::new();
+
+        while let Some(row_res) = rows.next().await {
+            let row = row_res.wrap_err("Failed to fetch next")?;
+
+            let score = DbSnipeScore {
+                map_id: row.map_id as u32,
+                mapset_id: row.mapset_id as u32,
+                user_id: row.user_id as u32,
+                score_id: row.score_id as u64,
+                score: row.score as u32,
+                pp: row.pp,
+                stars: row.stars,
+                mods: row.mods as u32,
+                max_combo: row.maxcombo as u32,
+                count_300: row.count300 as u32,
+                count_100: row.count100 as u32,
+                count_50: row.count50 as u32,
+                count_miss: row.countmiss as u32,
+                ended_at: row.ended_at,
+                artist: row.artist.into_boxed_str(),
+                title: row.title.into_boxed_str(),
+                version: row.map_version.into_boxed_str(),
+                count_circles: row.count_circles as u32,
+                count_sliders: row.count_sliders as u32,
+                count_spinners: row.count_spinners as u32,
+                map_max_combo: row.map_max_combo.map(|combo| combo as u32),
+                ar: row.ar,
+                cs: row.cs,
+                od: row.od,
+                hp: row.hp,
+                bpm: row.bpm,
+                status: parse_status(row.rank_status),
+            };
+
+            scores.push(score);
+        }
+
+        Ok(scores)
+    }
+
+    pub async fn select_snipe_country_scores(
+        &self,
+        country_code: &str,
+    ) -> Result<Vec<DbSnipeCountryScore>> {
+        let query = sqlx::query!(
+            r#"
+SELECT 
+  firsts.user_id, 
+  names.username, 
+  performance.pp :: FLOAT4, 
+  difficulty.stars :: FLOAT4 
+FROM 
+  (
+    SELECT 
+      map_id, 
+      user_id, 
+      score_id 
+    FROM 
+      snipe_national_firsts 
+    WHERE 
+      country_code = $1 
+  ) AS firsts 
+  JOIN osu_scores AS scores ON firsts.score_id = scores.score_id 
+  JOIN osu_user_names AS names ON firsts.user_id = names.user_id 
+  LEFT JOIN osu_scores_performance AS performance ON firsts.score_id = performance.score_id 
+  LEFT JOIN osu_map_difficulty AS difficulty ON firsts.map_id = difficulty.map_id 
+  AND scores.mods = difficulty.mods"#,
+            country_code
+        );
+
+        let mut rows = query.fetch(self);
+        let mut scores = Vec::new();
+
+        while let Some(row_res) = rows.next().await {
+            let row = row_res.wrap_err("Failed to fetch next")?;
+
+            let score = DbSnipeCountryScore {
+                user_id: row.user_id as u32,
+                username: row.username.into_boxed_str(),
+                pp: row.pp,
+                stars: row.stars,
+            };
+
+            scores.push(score);
+        }
+
+        Ok(scores)
+    }
+
+    pub async fn select_snipe_country_statistics(
+        &self,
+        country_code: &str,
+    ) -> Result<DbSnipeCountryStatistics> {
+        let query = sqlx::query!(
+            r#"
+SELECT 
+  (
+    SELECT 
+      COUNT(*) 
+    FROM 
+      snipe_national_firsts 
+    WHERE 
+      country_code = $1 
+  ) AS "total_maps!", 
+  (
+    SELECT 
+      COUNT(*) 
+    FROM 
+      osu_maps 
+      JOIN osu_mapsets ON osu_maps.mapset_id = osu_mapsets.mapset_id 
+    WHERE 
+      osu_maps.gamemode = 0 
+      AND osu_mapsets.rank_status IN (1, 2) 
+      AND osu_maps.map_id NOT IN (
+        SELECT 
+          map_id 
+        FROM 
+          snipe_national_firsts 
+        WHERE 
+          country_code = $1 
+      ) 
+  ) AS "unplayed_maps!""#,
+            country_code
+        );
+
+        let row = query
+            .fetch_one(self)
+            .await
+            .wrap_err("failed to fetch one")?;
+
+        Ok(DbSnipeCountryStatistics {
+            total_maps: row.total_maps as u32,
+            unplayed_maps: row.unplayed_maps as u32,
+        })
+    }
+
+    /// Gained minus lost national #1s per user of the country within the last
+    /// week.
+    pub async fn select_snipe_country_differences(
+        &self,
+        country_code: &str,
+    ) -> Result<Vec<DbSnipeDifference>> {
+        let query = sqlx::query!(
+            r#"
+SELECT 
+  changes.user_id AS "user_id!", 
+  names.username, 
+  SUM(changes.diff) :: INT4 AS "difference!", 
+  (
+    SELECT 
+      COUNT(*) 
+    FROM 
+      snipe_national_firsts AS firsts 
+    WHERE 
+      firsts.country_code = $1 
+      AND firsts.user_id = changes.user_id 
+  ) AS "count!" 
+FROM 
+  (
+    SELECT 
+      sniper_id AS user_id, 
+      1 AS diff 
+    FROM 
+      snipe_national_changes 
+    WHERE 
+      country_code = $1 
+      AND changed_at > NOW() - INTERVAL '7 days' 
+    UNION ALL 
+    SELECT 
+      sniped_id AS user_id, 
+      -1 AS diff 
+    FROM 
+      snipe_national_changes 
+    WHERE 
+      country_code = $1 
+      AND sniped_id IS NOT NULL 
+      AND changed_at > NOW() - INTERVAL '7 days' 
+  ) AS changes 
+  JOIN osu_user_names AS names ON changes.user_id = names.user_id 
+GROUP BY 
+  changes.user_id, 
+  names.username"#,
+            country_code
+        );
+
+        let mut rows = query.fetch(self);
+        let mut differences = Vec::new();
+
+        while let Some(row_res) = rows.next().await {
+            let row = row_res.wrap_err("Failed to fetch next")?;
+
+            let difference = DbSnipeDifference {
+                user_id: row.user_id as u32,
+                username: row.username.into_boxed_str(),
+                difference: row.difference,
+                count: row.count as u32,
+            };
+
+            differences.push(difference);
+        }
+
+        Ok(differences)
+    }
+
+    /// National #1s the user gained if `sniper` is `true`, otherwise national
+    /// #1s the user lost.
+    pub async fn select_snipe_changes(
+        &self,
+        country_code: &str,
+        user_id: u32,
+        sniper: bool,
+        from: OffsetDateTime,
+        until: OffsetDateTime,
+    ) -> Result<Vec<DbSnipeChange>> {
+        let query = sqlx::query!(
+            r#"
+SELECT 
+  changes.map_id, 
+  changes.score_id, 
+  changes.country_code, 
+  changes.sniper_id, 
+  sniper_names.username AS "sniper_name?", 
+  changes.sniped_id, 
+  sniped_names.username AS "sniped_name?", 
+  changes.changed_at, 
+  scores.mods, 
+  scores.maxcombo, 
+  scores.count50, 
+  scores.count100, 
+  scores.count300, 
+  scores.countmiss, 
+  performance.pp :: FLOAT4, 
+  difficulty.stars :: FLOAT4, 
+  maps.map_version, 
+  maps.hp, 
+  maps.cs, 
+  maps.od, 
+  maps.ar, 
+  maps.bpm, 
+  mapsets.artist, 
+  mapsets.title 
+FROM 
+  (
+    SELECT 
+      * 
+    FROM 
+      snipe_national_changes 
+    WHERE 
+      (
+        (
+          $2 
+          AND sniper_id = $1 
+        ) 
+        OR (
+          NOT $2 
+          AND sniped_id = $1 
+        ) 
+      ) 
+      AND changed_at BETWEEN $3 
+      AND $4 
+      AND country_code = $5 
+  ) AS changes 
+  JOIN osu_scores AS scores ON changes.score_id = scores.score_id 
+  LEFT JOIN osu_scores_performance AS performance ON changes.score_id = performance.score_id 
+  LEFT JOIN osu_map_difficulty AS difficulty ON changes.map_id = difficulty.map_id 
+  AND scores.mods = difficulty.mods 
+  JOIN osu_maps AS maps ON changes.map_id = maps.map_id 
+  JOIN osu_mapsets AS mapsets ON maps.mapset_id = mapsets.mapset_id 
+  LEFT JOIN osu_user_names AS sniper_names ON changes.sniper_id = sniper_names.user_id 
+  LEFT JOIN osu_user_names AS sniped_names ON changes.sniped_id = sniped_names.user_id"#,
+            user_id as i32,
+            sniper,
+            from,
+            until,
+            country_code,
+        );
+
+        let mut rows = query.fetch(self);
+        let mut changes = Vec::new();
+
+        while let Some(row_res) = rows.next().await {
+            let row = row_res.wrap_err("Failed to fetch next")?;
+
+            let change = DbSnipeChange {
+                map_id: row.map_id as u32,
+                score_id: row.score_id as u64,
+                country_code: row.country_code.into_boxed_str(),
+                sniper_id: row.sniper_id as u32,
+                sniper_name: row.sniper_name.map(String::into_boxed_str),
+                sniped_id: row.sniped_id.map(|user_id| user_id as u32),
+                sniped_name: row.sniped_name.map(String::into_boxed_str),
+                changed_at: row.changed_at,
+                pp: row.pp,
+                stars: row.stars,
+                mods: row.mods as u32,
+                max_combo: row.maxcombo as u32,
+                count_300: row.count300 as u32,
+                count_100: row.count100 as u32,
+                count_50: row.count50 as u32,
+                count_miss: row.countmiss as u32,
+                artist: row.artist.into_boxed_str(),
+                title: row.title.into_boxed_str(),
+                version: row.map_version.into_boxed_str(),
+                ar: row.ar,
+                cs: row.cs,
+                od: row.od,
+                hp: row.hp,
+                bpm: row.bpm,
+            };
+
+            changes.push(change);
+        }
+
+        Ok(changes)
+    }
+
+    pub async fn select_snipe_player_history(
+        &self,
+        country_code: &str,
+        user_id: u32,
+    ) -> Result<BTreeMap<Date, u32>> {
+        let query = sqlx::query!(
+            r#"
+SELECT 
+  date, 
+  count 
+FROM 
+  snipe_player_history 
+WHERE 
+  country_code = $1 
+  AND user_id = $2"#,
+            country_code,
+            user_id as i32,
+        );
+
+        let mut rows = query.fetch(self);
+        let mut history = BTreeMap::new();
+
+        while let Some(row_res) = rows.next().await {
+            let row = row_res.wrap_err("Failed to fetch next")?;
+            history.insert(row.date, row.count as u32);
+        }
+
+        Ok(history)
+    }
+
+    pub async fn select_snipe_notifications(&self) -> Result<Vec<DbSnipeNotification>> {
+        let query = sqlx::query_as!(
+            DbSnipeNotification,
+            r#"
+SELECT 
+  discord_id, 
+  osu_id, 
+  country_code, 
+  channel_id, 
+  last_notified 
+FROM 
+  snipe_notifications"#
+        );
+
+        query.fetch_all(self).await.wrap_err("failed to fetch all")
+    }
+
+    pub async fn upsert_snipe_notification(
+        &self,
+        user_id: Id<UserMarker>,
+        osu_id: u32,
+        country_code: &str,
+        channel_id: Option<Id<ChannelMarker>>,
+    ) -> Result<()> {
+        let query = sqlx::query!(
+            r#"
+INSERT INTO snipe_notifications (
+  discord_id, osu_id, country_code, channel_id
+) 
+VALUES 
+  ($1, $2, $3, $4) ON CONFLICT (discord_id) DO 
+UPDATE 
+SET 
+  osu_id = $2, 
+  country_code = $3, 
+  channel_id = $4, 
+  last_notified = NOW()"#,
+            user_id.get() as i64,
+            osu_id as i32,
+            country_code,
+            channel_id.map(|id| id.get() as i64),
+        );
+
+        query
+            .execute(self)
+            .await
+            .wrap_err("failed to execute query")?;
+
+        Ok(())
+    }
+
+    pub async fn update_snipe_notification_date(
+        &self,
+        user_id: Id<UserMarker>,
+        last_notified: OffsetDateTime,
+    ) -> Result<()> {
+        let query = sqlx::query!(
+            r#"
+UPDATE 
+  snipe_notifications 
+SET 
+  last_notified = $2 
+WHERE 
+  discord_id = $1"#,
+            user_id.get() as i64,
+            last_notified,
+        );
+
+        query
+            .execute(self)
+            .await
+            .wrap_err("failed to execute query")?;
+
+        Ok(())
+    }
+
+    pub async fn delete_snipe_notification(&self, user_id: Id<UserMarker>) -> Result<bool> {
+        let query = sqlx::query!(
+            r#"
+DELETE FROM 
+  snipe_notifications 
+WHERE 
+  discord_id = $1"#,
+            user_id.get() as i64
+        );
+
+        let res = query
+            .execute(self)
+            .await
+            .wrap_err("failed to execute query")?;
+
+        Ok(res.rows_affected() > 0)
+    }
+}
@@ -0,0 +1,200 @@
+use eyre::{Result, WrapErr};
+use time::OffsetDateTime;
+use twilight_model::id::{
+    marker::{ChannelMarker, GuildMarker, UserMarker},
+    Id,
+};
+
+use crate::{model::schedule::DbScheduledMessage, Database};
+
+impl Database {
+    /// Scheduled messages that are due until the given time, sorted by their
+    /// trigger time
+    pub async fn select_due_scheduled_messages(
+        &self,
+        until: OffsetDateTime,
+    ) -> Result<Vec<DbScheduledMessage>> {
+        let query = sqlx::query_as!(
+            DbScheduledMessage,
+            r#"
+SELECT 
+  schedule_id, 
+  author_id, 
+  guild_id, 
+  channel_id, 
+  content, 
+  is_reminder, 
+  trigger_at 
+FROM 
+  scheduled_messages 
+WHERE 
+  trigger_at <= $1 
+ORDER BY 
+  trigger_at"#,
+            until
+        );
+
+        query.fetch_all(self).await.wrap_err("failed to fetch all")
+    }
+
+    pub async fn select_user_reminders(
+        &self,
+        user_id: Id<UserMarker>,
+    ) -> Result<Vec<DbScheduledMessage>> {
+        let query = sqlx::query_as!(
+            DbScheduledMessage,
+            r#"
+SELECT 
+  schedule_id, 
+  author_id, 
+  guild_id, 
+  channel_id, 
+  content, 
+  is_reminder, 
+  trigger_at 
+FROM 
+  scheduled_messages 
+WHERE 
+  author_id = $1 
+  AND is_reminder 
+ORDER BY 
+  trigger_at"#,
+            user_id.get() as i64
+        );
+
+        query.fetch_all(self).await.wrap_err("failed to fetch all")
+    }
+
+    pub async fn select_guild_announcements(
+        &self,
+        guild_id: Id<GuildMarker>,
+    ) -> Result<Vec<DbScheduledMessage>> {
+        let query = sqlx::query_as!(
+            DbScheduledMessage,
+            r#"
+SELECT 
+  schedule_id, 
+  author_id, 
+  guild_id, 
+  channel_id, 
+  content, 
+  is_reminder, 
+  trigger_at 
+FROM 
+  scheduled_messages 
+WHERE 
+  guild_id = $1 
+  AND NOT is_reminder 
+ORDER BY 
+  trigger_at"#,
+            guild_id.get() as i64
+        );
+
+        query.fetch_all(self).await.wrap_err("failed to fetch all")
+    }
+
+    /// Returns the id of the inserted entry
+    pub async fn insert_scheduled_message(
+        &self,
+        author_id: Id<UserMarker>,
+        guild_id: Option<Id<GuildMarker>>,
+        channel_id: Id<ChannelMarker>,
+        content: &str,
+        is_reminder: bool,
+        trigger_at: OffsetDateTime,
+    ) -> Result<i32> {
+        let query = sqlx::query!(
+            r#"
+INSERT INTO scheduled_messages ( 
+  author_id, guild_id, channel_id, content, 
+  is_reminder, trigger_at 
+) 
+VALUES 
+  ($1, $2, $3, $4, $5, $6) RETURNING schedule_id"#,
+            author_id.get() as i64,
+            guild_id.map(|id| id.get() as i64),
+            channel_id.get() as i64,
+            content,
+            is_reminder,
+            trigger_at,
+        );
+
+        let row = query
+            .fetch_one(self)
+            .await
+            .wrap_err("failed to fetch one")?;
+
+        Ok(row.schedule_id)
+    }
+
+    /// Returns whether an entry was deleted
+    pub async fn delete_scheduled_message(&self, schedule_id: i32) -> Result<bool> {
+        let query = sqlx::query!(
+            r#"
+DELETE FROM 
+  scheduled_messages 
+WHERE 
+  schedule_id = $1"#,
+            schedule_id
+        );
+
+        let res = query
+            .execute(self)
+            .await
+            .wrap_err("failed to execute query")?;
+
+        Ok(res.rows_affected() > 0)
+    }
+
+    /// Returns whether an entry was deleted
+    pub async fn delete_user_reminder(
+        &self,
+        user_id: Id<UserMarker>,
+        schedule_id: i32,
+    ) -> Result<bool> {
+        let query = sqlx::query!(
+            r#"
+DELETE FROM 
+  scheduled_messages 
+WHERE 
+  schedule_id = $1 
+  AND author_id = $2 
+  AND is_reminder"#,
+            schedule_id,
+            user_id.get() as i64
+        );
+
+        let res = query
+            .execute(self)
+            .await
+            .wrap_err("failed to execute query")?;
+
+        Ok(res.rows_affected() > 0)
+    }
+
+    /// Returns whether an entry was deleted
+    pub async fn delete_guild_announcement(
+        &self,
+        guild_id: Id<GuildMarker>,
+        schedule_id: i32,
+    ) -> Result<bool> {
+        let query = sqlx::query!(
+            r#"
+DELETE FROM 
+  scheduled_messages 
+WHERE 
+  schedule_id = $1 
+  AND guild_id = $2 
+  AND NOT is_reminder"#,
+            schedule_id,
+            guild_id.get() as i64
+        );
+
+        let res = query
+            .execute(self)
+            .await
+            .wrap_err("failed to execute query")?;
+
+        Ok(res.rows_affected() > 0)
+    }
+}
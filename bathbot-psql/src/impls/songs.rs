@@ -0,0 +1,99 @@
+use eyre::{Result, WrapErr};
+use twilight_model::id::{marker::GuildMarker, Id};
+
+use crate::{model::songs::DbGuildSong, Database};
+
+impl Database {
+    pub async fn select_guild_songs(&self, guild_id: Id<GuildMarker>) -> Result<Vec<DbGuildSong>> {
+        let query = sqlx::query_as!(
+            DbGuildSong,
+            r#"
+SELECT 
+  name, 
+  lyrics 
+FROM 
+  guild_songs 
+WHERE 
+  guild_id = $1 
+ORDER BY 
+  name"#,
+            guild_id.get() as i64
+        );
+
+        query.fetch_all(self).await.wrap_err("failed to fetch all")
+    }
+
+    pub async fn select_guild_song(
+        &self,
+        guild_id: Id<GuildMarker>,
+        name: &str,
+    ) -> Result<Option<DbGuildSong>> {
+        let query = sqlx::query_as!(
+            DbGuildSong,
+            r#"
+SELECT 
+  name, 
+  lyrics 
+FROM 
+  guild_songs 
+WHERE 
+  guild_id = $1 
+  AND name = $2"#,
+            guild_id.get() as i64,
+            name
+        );
+
+        query
+            .fetch_optional(self)
+            .await
+            .wrap_err("failed to fetch optional")
+    }
+
+    pub async fn upsert_guild_song(
+        &self,
+        guild_id: Id<GuildMarker>,
+        name: &str,
+        lyrics: &str,
+    ) -> Result<()> {
+        let query = sqlx::query!(
+            r#"
+INSERT INTO guild_songs (guild_id, name, lyrics) 
+VALUES 
+  ($1, $2, $3) ON CONFLICT (guild_id, name) DO 
+UPDATE 
+SET 
+  lyrics = $3"#,
+            guild_id.get() as i64,
+            name,
+            lyrics
+        );
+
+        query
+            .execute(self)
+            .await
+            .wrap_err("failed to execute query")?;
+
+        Ok(())
+    }
+
+    /// Returns whether an entry was deleted
+    pub async fn delete_guild_song(&self, guild_id: Id<GuildMarker>, name: &str) -> Result<bool> {
+        let query = sqlx::query!(
+            r#"
+DELETE FROM 
+  guild_songs 
+WHERE 
+  guild_id = $1 
+  AND name = $2"#,
+            guild_id.get() as i64,
+            name
+        );
+
+        let res = query
+            .execute(self)
+            .await
+            .wrap_err("failed to execute query")?;
+
+        Ok(res.rows_affected() > 0)
+    }
+}
@@ -0,0 +1,482 @@
+use eyre::{Result, WrapErr};
+use time::OffsetDateTime;
+use twilight_model::id::{
+    marker::{ChannelMarker, GuildMarker},
+    Id,
+};
+
+use crate::{
+    model::tournament::{DbTournament, DbTournamentMatch, DbTournamentPlayer},
+    Database,
+};
+
+impl Database {
+    pub async fn select_tournaments(&self, guild_id: Id<GuildMarker>) -> Result<Vec<DbTournament>> {
+        let query = sqlx::query_as!(
+            DbTournament,
+            r#"
+SELECT 
+  tournament_id, 
+  guild_id, 
+  channel_id, 
+  name, 
+  created_at 
+FROM 
+  tournaments 
+WHERE 
+  guild_id = $1 
+ORDER BY 
+  created_at DESC"#,
+            guild_id.get() as i64
+        );
+
+        query.fetch_all(self).await.wrap_err("Failed to fetch all")
+    }
+
+    pub async fn select_tournament(
+        &self,
+        guild_id: Id<GuildMarker>,
+        name: &str,
+    ) -> Result<Option<DbTournament>> {
+        let query = sqlx::query_as!(
+            DbTournament,
+            r#"
+SELECT 
+  tournament_id, 
+  guild_id, 
+  channel_id, 
+  name, 
+  created_at 
+FROM 
+  tournaments 
+WHERE 
+  guild_id = $1 
+  AND name = $2"#,
+            guild_id.get() as i64,
+            name
+        );
+
+        query
+            .fetch_optional(self)
+            .await
+            .wrap_err("Failed to fetch optional")
+    }
+
+    pub async fn select_tournament_by_id(
+        &self,
+        tournament_id: i32,
+    ) -> Result<Option<DbTournament>> {
+        let query = sqlx::query_as!(
+            DbTournament,
+            r#"
+SELECT 
+  tournament_id, 
+  guild_id, 
+  channel_id, 
+  name, 
+  created_at 
+FROM 
+  tournaments 
+WHERE 
+  tournament_id = $1"#,
+            tournament_id
+        );
+
+        query
+            .fetch_optional(self)
+            .await
+            .wrap_err("Failed to fetch optional")
+    }
+
+    /// Returns `false` if the guild already has a tournament with that name.
+    pub async fn insert_tournament(
+        &self,
+        guild_id: Id<GuildMarker>,
+        channel_id: Id<ChannelMarker>,
+        name: &str,
+    ) -> Result<bool> {
+        let query = sqlx::query!(
+            r#"
+INSERT INTO tournaments (guild_id, channel_id, name) 
+VALUES 
+  ($1, $2, $3) ON CONFLICT (guild_id, name) DO NOTHING"#,
+            guild_id.get() as i64,
+            channel_id.get() as i64,
+            name
+        );
+
+        let res = query
+            .execute(self)
+            .await
+            .wrap_err("Failed to execute query")?;
+
+        Ok(res.rows_affected() > 0)
+    }
+
+    /// Deletes the tournament alongside its players and matches.
+    pub async fn delete_tournament(&self, tournament_id: i32) -> Result<()> {
+        let mut tx = self.begin().await.wrap_err("Failed to begin transaction")?;
+
+        let query = sqlx::query!(
+            r#"
+DELETE FROM 
+  tournament_matches 
+WHERE 
+  tournament_id = $1"#,
+            tournament_id
+        );
+
+        query
+            .execute(&mut *tx)
+            .await
+            .wrap_err("Failed to execute matches query")?;
+
+        let query = sqlx::query!(
+            r#"
+DELETE FROM 
+  tournament_players 
+WHERE 
+  tournament_id = $1"#,
+            tournament_id
+        );
+
+        query
+            .execute(&mut *tx)
+            .await
+            .wrap_err("Failed to execute players query")?;
+
+        let query = sqlx::query!(
+            r#"
+DELETE FROM 
+  tournaments 
+WHERE 
+  tournament_id = $1"#,
+            tournament_id
+        );
+
+        query
+            .execute(&mut *tx)
+            .await
+            .wrap_err("Failed to execute tournament query")?;
+
+        tx.commit().await.wrap_err("Failed to commit transaction")?;
+
+        Ok(())
+    }
+
+    /// Players sorted by team and username.
+    pub async fn select_tournament_players(
+        &self,
+        tournament_id: i32,
+    ) -> Result<Vec<DbTournamentPlayer>> {
+        let query = sqlx::query_as!(
+            DbTournamentPlayer,
+            r#"
+SELECT 
+  user_id, 
+  username, 
+  team 
+FROM 
+  tournament_players 
+WHERE 
+  tournament_id = $1 
+ORDER BY 
+  team, 
+  username"#,
+            tournament_id
+        );
+
+        query.fetch_all(self).await.wrap_err("Failed to fetch all")
+    }
+
+    /// Adds the players to the team, moving them over if they were already in
+    /// a different team.
+    pub async fn upsert_tournament_team(
+        &self,
+        tournament_id: i32,
+        team: &str,
+        players: &[(u32, &str)],
+    ) -> Result<()> {
+        let mut tx = self.begin().await.wrap_err("Failed to begin transaction")?;
+
+        for (user_id, username) in players {
+            let query = sqlx::query!(
+                r#"
+INSERT INTO tournament_players ( 
+  tournament_id, user_id, username, team 
+) 
+VALUES 
+  ($1, $2, $3, $4) ON CONFLICT (tournament_id, user_id) DO 
+UPDATE 
+SET 
+  username = $3, 
+  team = $4"#,
+                tournament_id,
+                *user_id as i32,
+                username,
+                team
+            );
+
+            query
+                .execute(&mut *tx)
+                .await
+                .wrap_err("Failed to execute query")?;
+        }
+
+        tx.commit().await.wrap_err("Failed to commit transaction")?;
+
+        Ok(())
+    }
+
+    /// Returns the amount of removed players.
+    pub async fn delete_tournament_team(&self, tournament_id: i32, team: &str) -> Result<u64> {
+        let query = sqlx::query!(
+            r#"
+DELETE FROM 
+  tournament_players 
+WHERE 
+  tournament_id = $1 
+  AND team = $2"#,
+            tournament_id,
+            team
+        );
+
+        let res = query
+            .execute(self)
+            .await
+            .wrap_err("Failed to execute query")?;
+
+        Ok(res.rows_affected())
+    }
+
+    /// Matches sorted by their scheduled time.
+    pub async fn select_tournament_matches(
+        &self,
+        tournament_id: i32,
+    ) -> Result<Vec<DbTournamentMatch>> {
+        let query = sqlx::query_as!(
+            DbTournamentMatch,
+            r#"
+SELECT 
+  match_id, 
+  tournament_id, 
+  round, 
+  team_a, 
+  team_b, 
+  scheduled_at, 
+  reminder_id, 
+  mp_id, 
+  warmups, 
+  score_a, 
+  score_b 
+FROM 
+  tournament_matches 
+WHERE 
+  tournament_id = $1 
+ORDER BY 
+  scheduled_at"#,
+            tournament_id
+        );
+
+        query.fetch_all(self).await.wrap_err("Failed to fetch all")
+    }
+
+    /// Returns `None` if the match does not belong to a tournament of the
+    /// guild.
+    pub async fn select_tournament_match(
+        &self,
+        guild_id: Id<GuildMarker>,
+        match_id: i32,
+    ) -> Result<Option<DbTournamentMatch>> {
+        let query = sqlx::query_as!(
+            DbTournamentMatch,
+            r#"
+SELECT 
+  matches.match_id, 
+  matches.tournament_id, 
+  matches.round, 
+  matches.team_a, 
+  matches.team_b, 
+  matches.scheduled_at, 
+  matches.reminder_id, 
+  matches.mp_id, 
+  matches.warmups, 
+  matches.score_a, 
+  matches.score_b 
+FROM 
+  tournament_matches AS matches 
+  JOIN tournaments ON matches.tournament_id = tournaments.tournament_id 
+WHERE 
+  matches.match_id = $1 
+  AND tournaments.guild_id = $2"#,
+            match_id,
+            guild_id.get() as i64
+        );
+
+        query
+            .fetch_optional(self)
+            .await
+            .wrap_err("Failed to fetch optional")
+    }
+
+    /// Matches that are linked to a multiplayer match but have no result yet.
+    pub async fn select_pending_tournament_matches(&self) -> Result<Vec<DbTournamentMatch>> {
+        let query = sqlx::query_as!(
+            DbTournamentMatch,
+            r#"
+SELECT 
+  match_id, 
+  tournament_id, 
+  round, 
+  team_a, 
+  team_b, 
+  scheduled_at, 
+  reminder_id, 
+  mp_id, 
+  warmups, 
+  score_a, 
+  score_b 
+FROM 
+  tournament_matches 
+WHERE 
+  mp_id IS NOT NULL 
+  AND score_a IS NULL"#
+        );
+
+        query.fetch_all(self).await.wrap_err("Failed to fetch all")
+    }
+
+    /// Returns the id of the inserted match.
+    pub async fn insert_tournament_match(
+        &self,
+        tournament_id: i32,
+        round: &str,
+        team_a: &str,
+        team_b: &str,
+        scheduled_at: OffsetDateTime,
+    ) -> Result<i32> {
+        let query = sqlx::query!(
+            r#"
+INSERT INTO tournament_matches ( 
+  tournament_id, round, team_a, team_b, 
+  scheduled_at 
+) 
+VALUES 
+  ($1, $2, $3, $4, $5) RETURNING match_id"#,
+            tournament_id,
+            round,
+            team_a,
+            team_b,
+            scheduled_at
+        );
+
+        let row = query
+            .fetch_one(self)
+            .await
+            .wrap_err("Failed to fetch one")?;
+
+        Ok(row.match_id)
+    }
+
+    pub async fn update_tournament_match_reminder(
+        &self,
+        match_id: i32,
+        reminder_id: Option<i32>,
+    ) -> Result<()> {
+        let query = sqlx::query!(
+            r#"
+UPDATE 
+  tournament_matches 
+SET 
+  reminder_id = $2 
+WHERE 
+  match_id = $1"#,
+            match_id,
+            reminder_id
+        );
+
+        query
+            .execute(self)
+            .await
+            .wrap_err("Failed to execute query")?;
+
+        Ok(())
+    }
+
+    /// Links the multiplayer match and resets a previous result.
+    pub async fn update_tournament_match_mp(
+        &self,
+        match_id: i32,
+        mp_id: u32,
+        warmups: u8,
+    ) -> Result<()> {
+        let query = sqlx::query!(
+            r#"
+UPDATE 
+  tournament_matches 
+SET 
+  mp_id = $2, 
+  warmups = $3, 
+  score_a = NULL, 
+  score_b = NULL 
+WHERE 
+  match_id = $1"#,
+            match_id,
+            mp_id as i32,
+            warmups as i16
+        );
+
+        query
+            .execute(self)
+            .await
+            .wrap_err("Failed to execute query")?;
+
+        Ok(())
+    }
+
+    pub async fn update_tournament_match_result(
+        &self,
+        match_id: i32,
+        score_a: u8,
+        score_b: u8,
+    ) -> Result<()> {
+        let query = sqlx::query!(
+            r#"
+UPDATE 
+  tournament_matches 
+SET 
+  score_a = $2, 
+  score_b = $3 
+WHERE 
+  match_id = $1"#,
+            match_id,
+            score_a as i16,
+            score_b as i16
+        );
+
+        query
+            .execute(self)
+            .await
+            .wrap_err("Failed to execute query")?;
+
+        Ok(())
+    }
+
+    pub async fn delete_tournament_match(&self, match_id: i32) -> Result<()> {
+        let query = sqlx::query!(
+            r#"
+DELETE FROM 
+  tournament_matches 
+WHERE 
+  match_id = $1"#,
+            match_id
+        );
+
+        query
+            .execute(self)
+            .await
+            .wrap_err("Failed to execute query")?;
+
+        Ok(())
+    }
+}
@@ -7,7 +7,7 @@ use eyre::{Result, WrapErr};
 use futures::StreamExt;
 use twilight_model::id::{marker::ChannelMarker, Id};
 
-use crate::database::Database;
+use crate::{database::Database, model::twitch::DbTrackedStream};
 
 impl Database {
     pub async fn select_tracked_twitch_streams<S>(
@@ -110,4 +110,90 @@ WHERE
 
         Ok(res.rows_affected() > 0)
     }
+
+    /// Notification settings of all channels that track the given user
+    pub async fn select_tracked_twitch_stream_settings(
+        &self,
+        user: u64,
+    ) -> Result<Vec<DbTrackedStream>> {
+        let query = sqlx::query_as!(
+            DbTrackedStream,
+            r#"
+SELECT 
+  channel_id, 
+  role_id, 
+  message, 
+  keywords, 
+  edit_offline 
+FROM 
+  tracked_twitch_streams 
+WHERE 
+  user_id = $1"#,
+            user as i64,
+        );
+
+        query.fetch_all(self).await.wrap_err("failed to fetch all")
+    }
+
+    pub async fn select_tracked_twitch_stream(
+        &self,
+        channel: Id<ChannelMarker>,
+        user: u64,
+    ) -> Result<Option<DbTrackedStream>> {
+        let query = sqlx::query_as!(
+            DbTrackedStream,
+            r#"
+SELECT 
+  channel_id, 
+  role_id, 
+  message, 
+  keywords, 
+  edit_offline 
+FROM 
+  tracked_twitch_streams 
+WHERE 
+  channel_id = $1 
+  AND user_id = $2"#,
+            channel.get() as i64,
+            user as i64,
+        );
+
+        query
+            .fetch_optional(self)
+            .await
+            .wrap_err("failed to fetch optional")
+    }
+
+    pub async fn update_tracked_twitch_stream(
+        &self,
+        user: u64,
+        settings: &DbTrackedStream,
+    ) -> Result<()> {
+        let query = sqlx::query!(
+            r#"
+UPDATE 
+  tracked_twitch_streams 
+SET 
+  role_id = $3, 
+  message = $4, 
+  keywords = $5, 
+  edit_offline = $6 
+WHERE 
+  channel_id = $1 
+  AND user_id = $2"#,
+            settings.channel_id,
+            user as i64,
+            settings.role_id,
+            settings.message,
+            &settings.keywords,
+            settings.edit_offline,
+        );
+
+        query
+            .execute(self)
+            .await
+            .wrap_err("failed to execute query")?;
+
+        Ok(())
+    }
 }
@@ -0,0 +1,80 @@
+use std::ops::Deref;
+
+use compact_str::CompactString;
+use rkyv::{Archive, Deserialize, Infallible, Serialize};
+
+/// A guild-specific name that expands to a prefix command and preset
+/// arguments.
+#[derive(Archive, Clone, Debug, Deserialize, Serialize)]
+pub struct CommandAlias {
+    pub name: CompactString,
+    /// The name of the prefix command the alias expands to
+    pub command: CompactString,
+    /// Arguments that are passed to the command in front of the user's
+    /// arguments
+    pub args: String,
+}
+
+#[derive(Archive, Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CommandAliases {
+    inner: Vec<CommandAlias>,
+}
+
+impl CommandAliases {
+    pub const LEN: usize = 25;
+
+    #[inline]
+    pub fn remaining_capacity(&self) -> usize {
+        Self::LEN.saturating_sub(self.inner.len())
+    }
+
+    /// Get the alias of the given name.
+    ///
+    /// The name is expected to be lowercase.
+    pub fn get(&self, name: &str) -> Option<&CommandAlias> {
+        self.inner.iter().find(|alias| alias.name == name)
+    }
+
+    /// Add an alias or overwrite the one of the same name.
+    ///
+    /// Returns `false` if there are already [`CommandAliases::LEN`] many
+    /// aliases and the alias could not be added.
+    pub fn insert(&mut self, alias: CommandAlias) -> bool {
+        if let Some(existing) = self.inner.iter_mut().find(|a| a.name == alias.name) {
+            *existing = alias;
+        } else if self.remaining_capacity() > 0 {
+            self.inner.push(alias);
+            self.inner.sort_unstable_by(|a, b| a.name.cmp(&b.name));
+        } else {
+            return false;
+        }
+
+        true
+    }
+
+    /// Remove the alias of the given name and return it.
+    pub fn remove(&mut self, name: &str) -> Option<CommandAlias> {
+        let idx = self.inner.iter().position(|alias| alias.name == name)?;
+
+        Some(self.inner.remove(idx))
+    }
+
+    /// # Safety
+    ///
+    /// The caller must ensure that the provided bytes are valid archived
+    /// aliases
+    pub(crate) unsafe fn deserialize(bytes: &[u8]) -> Self {
+        let archived_aliases = rkyv::archived_root::<Self>(bytes);
+
+        archived_aliases.deserialize(&mut Infallible).unwrap()
+    }
+}
+
+impl Deref for CommandAliases {
+    type Target = [CommandAlias];
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
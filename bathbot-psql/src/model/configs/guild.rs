@@ -1,6 +1,6 @@
 use super::{
     list_size::ListSize, minimized_pp::MinimizedPp, score_size::ScoreSize, Authorities,
-    HideSolutions, Prefixes, Retries,
+    CommandAliases, HideSolutions, Language, Prefixes, Retries,
 };
 
 pub struct DbGuildConfig {
@@ -16,6 +16,10 @@ pub struct DbGuildConfig {
     pub render_button: Option<bool>,
     pub allow_custom_skins: Option<bool>,
     pub hide_medal_solution: Option<i16>,
+    pub language: Option<i16>,
+    pub command_aliases: Option<Vec<u8>>,
+    pub command_suggestions: Option<bool>,
+    pub match_cost_formula: Option<String>,
 }
 
 #[derive(Clone, Default)]
@@ -31,6 +35,10 @@ pub struct GuildConfig {
     pub render_button: Option<bool>,
     pub allow_custom_skins: Option<bool>,
     pub hide_medal_solution: Option<HideSolutions>,
+    pub language: Option<Language>,
+    pub command_aliases: CommandAliases,
+    pub command_suggestions: Option<bool>,
+    pub match_cost_formula: Option<String>,
 }
 
 impl From<DbGuildConfig> for GuildConfig {
@@ -49,6 +57,10 @@ impl From<DbGuildConfig> for GuildConfig {
             render_button,
             allow_custom_skins,
             hide_medal_solution,
+            language,
+            command_aliases,
+            command_suggestions,
+            match_cost_formula,
         } = config;
 
         // SAFETY: The bytes originate from the DB which only provides valid archived
@@ -56,6 +68,10 @@ impl From<DbGuildConfig> for GuildConfig {
         let authorities = unsafe { Authorities::deserialize(&authorities) };
         let prefixes = unsafe { Prefixes::deserialize(&prefixes) };
 
+        let command_aliases = command_aliases
+            .map(|bytes| unsafe { CommandAliases::deserialize(&bytes) })
+            .unwrap_or_default();
+
         Self {
             authorities,
             score_size: score_size.map(ScoreSize::try_from).and_then(Result::ok),
@@ -70,6 +86,10 @@ impl From<DbGuildConfig> for GuildConfig {
             hide_medal_solution: hide_medal_solution
                 .map(HideSolutions::try_from)
                 .and_then(Result::ok),
+            language: language.map(Language::try_from).and_then(Result::ok),
+            command_aliases,
+            command_suggestions,
+            match_cost_formula,
         }
     }
 }
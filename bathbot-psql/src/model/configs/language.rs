@@ -0,0 +1,56 @@
+use twilight_interactions::command::{CommandOption, CreateOption};
+
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, PartialEq, CommandOption, CreateOption)]
+#[repr(u8)]
+pub enum Language {
+    #[default]
+    #[option(name = "English", value = "en")]
+    English = 0,
+    #[option(name = "Deutsch", value = "de")]
+    German = 1,
+    #[option(name = "Français", value = "fr")]
+    French = 2,
+}
+
+impl Language {
+    pub const ALL: [Self; 3] = [Self::English, Self::German, Self::French];
+
+    /// The locale identifier as used by discord
+    pub const fn discord_locale(self) -> &'static str {
+        match self {
+            Self::English => "en-US",
+            Self::German => "de",
+            Self::French => "fr",
+        }
+    }
+
+    pub fn from_discord_locale(locale: &str) -> Option<Self> {
+        match locale {
+            "en-US" | "en-GB" => Some(Self::English),
+            "de" => Some(Self::German),
+            "fr" => Some(Self::French),
+            _ => None,
+        }
+    }
+}
+
+impl From<Language> for i16 {
+    #[inline]
+    fn from(language: Language) -> Self {
+        language as Self
+    }
+}
+
+impl TryFrom<i16> for Language {
+    type Error = ();
+
+    #[inline]
+    fn try_from(value: i16) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::English),
+            1 => Ok(Self::German),
+            2 => Ok(Self::French),
+            _ => Err(()),
+        }
+    }
+}
@@ -1,7 +1,9 @@
 pub use self::{
+    aliases::{CommandAlias, CommandAliases},
     authorities::{Authorities, Authority},
     guild::{DbGuildConfig, GuildConfig},
     hide_solutions::HideSolutions,
+    language::Language,
     list_size::ListSize,
     minimized_pp::MinimizedPp,
     prefixes::{Prefix, Prefixes, DEFAULT_PREFIX},
@@ -11,9 +13,11 @@ pub use self::{
     user::{DbUserConfig, OsuId, OsuUserId, OsuUsername, UserConfig},
 };
 
+mod aliases;
 mod authorities;
 mod guild;
 mod hide_solutions;
+mod language;
 mod list_size;
 mod minimized_pp;
 mod prefixes;
@@ -1,7 +1,10 @@
 use rosu_v2::prelude::{GameMode, Username};
 use time::UtcOffset;
 
-use super::{list_size::ListSize, minimized_pp::MinimizedPp, score_size::ScoreSize, Retries};
+use super::{
+    language::Language, list_size::ListSize, minimized_pp::MinimizedPp, score_size::ScoreSize,
+    Retries,
+};
 
 pub struct DbUserConfig {
     pub score_size: Option<i16>,
@@ -13,6 +16,7 @@ pub struct DbUserConfig {
     pub twitch_id: Option<i64>,
     pub timezone_seconds: Option<i32>,
     pub render_button: Option<bool>,
+    pub language: Option<i16>,
 }
 
 pub trait OsuId {
@@ -44,6 +48,7 @@ pub struct UserConfig<O: OsuId> {
     pub twitch_id: Option<u64>,
     pub timezone: Option<UtcOffset>,
     pub render_button: Option<bool>,
+    pub language: Option<Language>,
 }
 
 impl<O: OsuId> Default for UserConfig<O> {
@@ -59,6 +64,7 @@ impl<O: OsuId> Default for UserConfig<O> {
             twitch_id: None,
             timezone: None,
             render_button: None,
+            language: None,
         }
     }
 }
@@ -76,6 +82,7 @@ impl From<DbUserConfig> for UserConfig<OsuUserId> {
             twitch_id,
             timezone_seconds,
             render_button,
+            language,
         } = config;
 
         Self {
@@ -90,6 +97,7 @@ impl From<DbUserConfig> for UserConfig<OsuUserId> {
                 .map(UtcOffset::from_whole_seconds)
                 .map(Result::unwrap),
             render_button,
+            language: language.map(Language::try_from).and_then(Result::ok),
         }
     }
 }
@@ -5,3 +5,20 @@ pub struct DbHlGameScore {
     pub discord_id: i64,
     pub highscore: i32,
 }
+
+#[derive(FromRow)]
+pub struct DbHlMap {
+    pub map_id: i32,
+    pub mapset_id: i32,
+    pub artist: String,
+    pub title: String,
+    pub map_version: String,
+    pub seconds_drain: i32,
+    pub bpm: f32,
+    pub cs: f32,
+    pub ar: f32,
+    pub od: f32,
+    pub hp: f32,
+    pub stars: f64,
+    pub max_combo: i32,
+}
@@ -1,4 +1,5 @@
-pub use self::{bg::*, hl::*};
+pub use self::{bg::*, hl::*, trivia::*};
 
 mod bg;
 mod hl;
+mod trivia;
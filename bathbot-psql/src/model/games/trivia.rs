@@ -0,0 +1,18 @@
+use sqlx::FromRow;
+use time::OffsetDateTime;
+
+#[derive(FromRow)]
+pub struct DbTriviaScore {
+    pub discord_id: i64,
+    pub score: i32,
+}
+
+#[derive(FromRow)]
+pub struct DbTriviaMapset {
+    pub mapset_id: i32,
+    pub user_id: i32,
+    pub artist: String,
+    pub title: String,
+    pub creator: String,
+    pub ranked_date: Option<OffsetDateTime>,
+}
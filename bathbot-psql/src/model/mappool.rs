@@ -0,0 +1,18 @@
+use sqlx::FromRow;
+use time::OffsetDateTime;
+
+#[derive(FromRow)]
+pub struct DbMappool {
+    pub mappool_id: i32,
+    pub guild_id: i64,
+    pub name: String,
+    pub created_at: OffsetDateTime,
+}
+
+#[derive(Clone, FromRow)]
+pub struct DbMappoolMap {
+    pub slot: String,
+    pub map_id: i32,
+    /// `None` if the slot is freemod
+    pub mods: Option<i32>,
+}
@@ -1,4 +1,9 @@
 pub mod configs;
 pub mod games;
+pub mod mappool;
 pub mod osu;
 pub mod render;
+pub mod schedule;
+pub mod songs;
+pub mod tournament;
+pub mod twitch;
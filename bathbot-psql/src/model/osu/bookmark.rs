@@ -28,3 +28,21 @@ pub struct MapBookmark {
     pub language: Language,
     pub cover_url: Box<str>,
 }
+
+pub struct DbBookmarkCollection {
+    pub owner_id: i64,
+    pub name: String,
+    pub created_at: OffsetDateTime,
+    pub map_count: i64,
+}
+
+pub struct DbCollectionMap {
+    pub insert_date: OffsetDateTime,
+    pub map_id: u32,
+    pub mapset_id: u32,
+    pub checksum: Box<str>,
+    pub artist: Box<str>,
+    pub title: Box<str>,
+    pub version: Box<str>,
+    pub mode: GameMode,
+}
@@ -0,0 +1,40 @@
+use time::OffsetDateTime;
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(u8)]
+pub enum MedalPlanStatus {
+    InProgress = 0,
+    Hidden = 1,
+}
+
+impl From<MedalPlanStatus> for i16 {
+    #[inline]
+    fn from(status: MedalPlanStatus) -> Self {
+        status as Self
+    }
+}
+
+impl TryFrom<i16> for MedalPlanStatus {
+    type Error = ();
+
+    #[inline]
+    fn try_from(value: i16) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::InProgress),
+            1 => Ok(Self::Hidden),
+            _ => Err(()),
+        }
+    }
+}
+
+pub struct DbMedalPlan {
+    pub medal_id: u32,
+    pub status: MedalPlanStatus,
+}
+
+/// A medal that was noticed to be achieved by the planner.
+pub struct DbMedalProgress {
+    pub medal_id: u32,
+    pub achieved_at: OffsetDateTime,
+    pub tracked_at: OffsetDateTime,
+}
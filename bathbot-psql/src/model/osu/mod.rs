@@ -1,8 +1,12 @@
-pub use self::{bookmark::*, map::*, mapset::*, score::*, tracked_user::*, user::*};
+pub use self::{
+    bookmark::*, map::*, mapset::*, medal_plan::*, score::*, snipe::*, tracked_user::*, user::*,
+};
 
 mod bookmark;
 mod map;
 mod mapset;
+mod medal_plan;
 mod score;
+mod snipe;
 mod tracked_user;
 mod user;
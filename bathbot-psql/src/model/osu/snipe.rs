@@ -0,0 +1,89 @@
+use rosu_v2::prelude::RankStatus;
+use time::OffsetDateTime;
+
+/// A national #1 score alongside its map data.
+pub struct DbSnipeScore {
+    pub map_id: u32,
+    pub mapset_id: u32,
+    pub user_id: u32,
+    pub score_id: u64,
+    pub score: u32,
+    pub pp: Option<f32>,
+    pub stars: Option<f32>,
+    pub mods: u32,
+    pub max_combo: u32,
+    pub count_300: u32,
+    pub count_100: u32,
+    pub count_50: u32,
+    pub count_miss: u32,
+    pub ended_at: OffsetDateTime,
+    pub artist: Box<str>,
+    pub title: Box<str>,
+    pub version: Box<str>,
+    pub count_circles: u32,
+    pub count_sliders: u32,
+    pub count_spinners: u32,
+    pub map_max_combo: Option<u32>,
+    pub ar: f32,
+    pub cs: f32,
+    pub od: f32,
+    pub hp: f32,
+    pub bpm: f32,
+    pub status: RankStatus,
+}
+
+pub struct DbSnipeCountryScore {
+    pub user_id: u32,
+    pub username: Box<str>,
+    pub pp: Option<f32>,
+    pub stars: Option<f32>,
+}
+
+/// Change in national #1 count of a user throughout the last week.
+pub struct DbSnipeDifference {
+    pub user_id: u32,
+    pub username: Box<str>,
+    pub difference: i32,
+    pub count: u32,
+}
+
+pub struct DbSnipeCountryStatistics {
+    pub total_maps: u32,
+    pub unplayed_maps: u32,
+}
+
+/// A national #1 that went to `sniper_id`, previously held by `sniped_id`.
+pub struct DbSnipeChange {
+    pub map_id: u32,
+    pub score_id: u64,
+    pub country_code: Box<str>,
+    pub sniper_id: u32,
+    pub sniper_name: Option<Box<str>>,
+    pub sniped_id: Option<u32>,
+    pub sniped_name: Option<Box<str>>,
+    pub changed_at: OffsetDateTime,
+    pub pp: Option<f32>,
+    pub stars: Option<f32>,
+    pub mods: u32,
+    pub max_combo: u32,
+    pub count_300: u32,
+    pub count_100: u32,
+    pub count_50: u32,
+    pub count_miss: u32,
+    pub artist: Box<str>,
+    pub title: Box<str>,
+    pub version: Box<str>,
+    pub ar: f32,
+    pub cs: f32,
+    pub od: f32,
+    pub hp: f32,
+    pub bpm: f32,
+}
+
+pub struct DbSnipeNotification {
+    pub discord_id: i64,
+    pub osu_id: i32,
+    pub country_code: String,
+    pub channel_id: Option<i64>,
+    pub last_notified: OffsetDateTime,
+}
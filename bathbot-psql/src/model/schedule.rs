@@ -0,0 +1,13 @@
+use sqlx::FromRow;
+use time::OffsetDateTime;
+
+#[derive(FromRow)]
+pub struct DbScheduledMessage {
+    pub schedule_id: i32,
+    pub author_id: i64,
+    pub guild_id: Option<i64>,
+    pub channel_id: i64,
+    pub content: String,
+    pub is_reminder: bool,
+    pub trigger_at: OffsetDateTime,
+}
@@ -0,0 +1,7 @@
+use sqlx::FromRow;
+
+#[derive(FromRow)]
+pub struct DbGuildSong {
+    pub name: String,
+    pub lyrics: String,
+}
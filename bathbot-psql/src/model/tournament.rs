@@ -0,0 +1,33 @@
+use sqlx::FromRow;
+use time::OffsetDateTime;
+
+#[derive(FromRow)]
+pub struct DbTournament {
+    pub tournament_id: i32,
+    pub guild_id: i64,
+    pub channel_id: i64,
+    pub name: String,
+    pub created_at: OffsetDateTime,
+}
+
+#[derive(FromRow)]
+pub struct DbTournamentPlayer {
+    pub user_id: i32,
+    pub username: String,
+    pub team: String,
+}
+
+#[derive(Clone, FromRow)]
+pub struct DbTournamentMatch {
+    pub match_id: i32,
+    pub tournament_id: i32,
+    pub round: String,
+    pub team_a: String,
+    pub team_b: String,
+    pub scheduled_at: OffsetDateTime,
+    pub reminder_id: Option<i32>,
+    pub mp_id: Option<i32>,
+    pub warmups: i16,
+    pub score_a: Option<i16>,
+    pub score_b: Option<i16>,
+}
@@ -0,0 +1,11 @@
+use sqlx::FromRow;
+
+/// Notification settings of a twitch stream that is tracked in a channel
+#[derive(FromRow)]
+pub struct DbTrackedStream {
+    pub channel_id: i64,
+    pub role_id: Option<i64>,
+    pub message: Option<String>,
+    pub keywords: Vec<String>,
+    pub edit_offline: bool,
+}
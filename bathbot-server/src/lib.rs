@@ -8,7 +8,7 @@ mod standby;
 mod state;
 
 pub use self::{
-    server::Server,
+    server::{Server, ServerTuple},
     standby::{AuthenticationStandby, AuthenticationStandbyError},
     state::AppStateBuilder,
 };
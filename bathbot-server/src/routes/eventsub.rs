@@ -0,0 +1,263 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+use axum::{
+    body::Bytes,
+    extract::State,
+    http::{HeaderMap, StatusCode},
+};
+use bathbot_model::{TwitchEventSubPayload, TwitchStreamEvent};
+use eyre::Report;
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+use time::{format_description::well_known::Rfc3339, Duration, OffsetDateTime};
+
+use crate::state::AppState;
+
+const MESSAGE_ID: &str = "Twitch-Eventsub-Message-Id";
+const MESSAGE_TIMESTAMP: &str = "Twitch-Eventsub-Message-Timestamp";
+const MESSAGE_SIGNATURE: &str = "Twitch-Eventsub-Message-Signature";
+const MESSAGE_TYPE: &str = "Twitch-Eventsub-Message-Type";
+
+/// Messages older than this are considered replayed
+const MAX_MESSAGE_AGE: Duration = Duration::minutes(10);
+
+/// Ids of recently handled messages to recognize retried deliveries.
+///
+/// Messages older than [`MAX_MESSAGE_AGE`] are rejected anyway so ids only
+/// need to be kept for that long.
+#[derive(Default)]
+pub struct RecentMessages {
+    ids: Mutex<HashMap<Box<str>, OffsetDateTime>>,
+}
+
+impl RecentMessages {
+    /// Returns `false` if the id has already been seen recently.
+    fn insert(&self, id: &str, now: OffsetDateTime) -> bool {
+        let mut ids = self.ids.lock().unwrap();
+        ids.retain(|_, seen_at| now - *seen_at <= MAX_MESSAGE_AGE);
+
+        if ids.contains_key(id) {
+            return false;
+        }
+
+        ids.insert(Box::from(id), now);
+
+        true
+    }
+}
+
+pub async fn receive_eventsub(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> (StatusCode, String) {
+    match handle_eventsub(&state, &headers, &body) {
+        Ok(response) => (StatusCode::OK, response),
+        Err(err) => {
+            let status_code = err.status_code();
+            warn!("{:?}", Report::new(err));
+
+            (status_code, String::new())
+        }
+    }
+}
+
+fn handle_eventsub(
+    state: &AppState,
+    headers: &HeaderMap,
+    body: &[u8],
+) -> Result<String, EventSubError> {
+    let secret = state
+        .twitch_eventsub_secret
+        .as_deref()
+        .ok_or(EventSubError::Disabled)?;
+
+    let header = |name: &'static str| {
+        headers
+            .get(name)
+            .and_then(|value| value.to_str().ok())
+            .ok_or(EventSubError::MissingHeader(name))
+    };
+
+    let id = header(MESSAGE_ID)?;
+    let timestamp = header(MESSAGE_TIMESTAMP)?;
+    let signature = header(MESSAGE_SIGNATURE)?;
+    let kind = header(MESSAGE_TYPE)?;
+
+    verify_signature(secret, id, timestamp, body, signature)?;
+
+    let timestamp = OffsetDateTime::parse(timestamp, &Rfc3339).map_err(EventSubError::Timestamp)?;
+
+    let now = OffsetDateTime::now_utc();
+
+    if now - timestamp > MAX_MESSAGE_AGE {
+        return Err(EventSubError::Outdated);
+    }
+
+    // Twitch resends messages it considers undelivered so duplicates are
+    // acknowledged without handling them again. Verifications still need
+    // their challenge as response.
+    if kind != "webhook_callback_verification" && !state.eventsub_messages.insert(id, now) {
+        debug!(id, "Skipping duplicate twitch EventSub message");
+
+        return Ok(String::new());
+    }
+
+    let payload: TwitchEventSubPayload =
+        serde_json::from_slice(body).map_err(EventSubError::Deserialize)?;
+
+    match kind {
+        "webhook_callback_verification" => {
+            let challenge = payload.challenge.ok_or(EventSubError::MissingChallenge)?;
+            info!(kind = %payload.subscription.kind, "Verified twitch EventSub subscription");
+
+            Ok(challenge.into_string())
+        }
+        "notification" => {
+            let user_id = payload.event.ok_or(EventSubError::MissingEvent)?.user_id;
+
+            let event = match payload.subscription.kind.as_ref() {
+                "stream.online" => TwitchStreamEvent::Online { user_id },
+                "stream.offline" => TwitchStreamEvent::Offline { user_id },
+                _ => {
+                    return Err(EventSubError::UnknownSubscription(
+                        payload.subscription.kind,
+                    ))
+                }
+            };
+
+            // The receiver being gone means events are of no interest
+            let _ = state.twitch_events.send(event);
+
+            Ok(String::new())
+        }
+        "revocation" => {
+            let user_id = payload.subscription.condition.user_id;
+
+            warn!(
+                user_id,
+                kind = %payload.subscription.kind,
+                status = %payload.subscription.status,
+                "Twitch revoked EventSub subscription",
+            );
+
+            // Let the bot resubscribe if the stream is still tracked
+            let _ = state
+                .twitch_events
+                .send(TwitchStreamEvent::Revoked { user_id });
+
+            Ok(String::new())
+        }
+        _ => Err(EventSubError::UnknownMessage(Box::from(kind))),
+    }
+}
+
+/// The signature is a HMAC-SHA256 over the message id, timestamp, and body
+/// using the secret that was provided when subscribing.
+fn verify_signature(
+    secret: &str,
+    id: &str,
+    timestamp: &str,
+    body: &[u8],
+    signature: &str,
+) -> Result<(), EventSubError> {
+    let signature = signature
+        .strip_prefix("sha256=")
+        .and_then(|digest| hex::decode(digest).ok())
+        .ok_or(EventSubError::InvalidSignature)?;
+
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes())
+        .map_err(|_| EventSubError::InvalidSignature)?;
+
+    mac.update(id.as_bytes());
+    mac.update(timestamp.as_bytes());
+    mac.update(body);
+
+    mac.verify_slice(&signature)
+        .map_err(|_| EventSubError::InvalidSignature)
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("twitch eventsub error")]
+pub enum EventSubError {
+    #[error("failed to deserialize payload")]
+    Deserialize(#[source] serde_json::Error),
+    #[error("no secret configured")]
+    Disabled,
+    #[error("invalid signature")]
+    InvalidSignature,
+    #[error("verification without challenge")]
+    MissingChallenge,
+    #[error("notification without event")]
+    MissingEvent,
+    #[error("missing header `{0}`")]
+    MissingHeader(&'static str),
+    #[error("outdated message")]
+    Outdated,
+    #[error("failed to parse timestamp")]
+    Timestamp(#[source] time::error::Parse),
+    #[error("unknown message type `{0}`")]
+    UnknownMessage(Box<str>),
+    #[error("unknown subscription type `{0}`")]
+    UnknownSubscription(Box<str>),
+}
+
+impl EventSubError {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            Self::Disabled => StatusCode::NOT_FOUND,
+            Self::InvalidSignature => StatusCode::FORBIDDEN,
+            // Twitch retries failed notifications so unknown types must not fail
+            Self::UnknownMessage(_) | Self::UnknownSubscription(_) => StatusCode::NO_CONTENT,
+            _ => StatusCode::BAD_REQUEST,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sign(secret: &str, id: &str, timestamp: &str, body: &[u8]) -> String {
+        let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes()).unwrap();
+        mac.update(id.as_bytes());
+        mac.update(timestamp.as_bytes());
+        mac.update(body);
+
+        format!("sha256={}", hex::encode(mac.finalize().into_bytes()))
+    }
+
+    #[test]
+    fn valid_signature() {
+        let (id, timestamp, body) = ("id", "2023-11-30T12:00:00.123456789Z", b"{}");
+        let signature = sign("secret", id, timestamp, body);
+
+        assert!(verify_signature("secret", id, timestamp, body, &signature).is_ok());
+    }
+
+    #[test]
+    fn duplicate_messages() {
+        let messages = RecentMessages::default();
+        let now = OffsetDateTime::now_utc();
+
+        assert!(messages.insert("a", now));
+        assert!(messages.insert("b", now));
+        assert!(!messages.insert("a", now + Duration::minutes(1)));
+
+        // Expired ids are forgotten
+        assert!(messages.insert("a", now + MAX_MESSAGE_AGE + Duration::minutes(1)));
+    }
+
+    #[test]
+    fn invalid_signature() {
+        let (id, timestamp, body) = ("id", "2023-11-30T12:00:00.123456789Z", b"{}");
+        let signature = sign("other secret", id, timestamp, body);
+
+        assert!(verify_signature("secret", id, timestamp, body, &signature).is_err());
+        assert!(verify_signature("secret", id, timestamp, body, "sha256=xyz").is_err());
+        assert!(verify_signature("secret", id, timestamp, body, "").is_err());
+    }
+}
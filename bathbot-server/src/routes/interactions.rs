@@ -0,0 +1,218 @@
+use std::sync::Arc;
+
+use axum::{
+    body::Bytes,
+    extract::State,
+    http::{HeaderMap, StatusCode},
+    response::{IntoResponse, Response},
+    Json,
+};
+use eyre::Report;
+use ring::signature::{UnparsedPublicKey, ED25519};
+use time::{Duration, OffsetDateTime};
+use twilight_model::{
+    application::interaction::{Interaction, InteractionType},
+    http::interaction::{InteractionResponse, InteractionResponseData, InteractionResponseType},
+};
+
+use crate::state::AppState;
+
+const SIGNATURE: &str = "X-Signature-Ed25519";
+const TIMESTAMP: &str = "X-Signature-Timestamp";
+
+/// Interactions with a timestamp further off than this are considered replayed
+const MAX_TIMESTAMP_OFFSET: Duration = Duration::minutes(5);
+
+/// Receives interactions that discord sends to the configured interactions
+/// endpoint url instead of through the gateway.
+///
+/// Aside from pings and autocompletes, interactions are acknowledged with a
+/// deferred response and forwarded to the bot which then follows up by
+/// updating that response.
+pub async fn receive_interaction(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> Response {
+    match handle_interaction(&state, &headers, &body) {
+        Ok(response) => Json(response).into_response(),
+        Err(err) => {
+            let status_code = err.status_code();
+            warn!("{:?}", Report::new(err));
+
+            status_code.into_response()
+        }
+    }
+}
+
+fn handle_interaction(
+    state: &AppState,
+    headers: &HeaderMap,
+    body: &[u8],
+) -> Result<InteractionResponse, InteractionError> {
+    let public_key = state
+        .discord_public_key
+        .as_deref()
+        .ok_or(InteractionError::Disabled)?;
+
+    let header = |name: &'static str| {
+        headers
+            .get(name)
+            .and_then(|value| value.to_str().ok())
+            .ok_or(InteractionError::MissingHeader(name))
+    };
+
+    let signature = header(SIGNATURE)?;
+    let timestamp = header(TIMESTAMP)?;
+
+    verify_signature(public_key, timestamp, body, signature)?;
+    verify_timestamp(timestamp, OffsetDateTime::now_utc())?;
+
+    let interaction: Interaction =
+        serde_json::from_slice(body).map_err(InteractionError::Deserialize)?;
+
+    let kind = match interaction.kind {
+        InteractionType::Ping => InteractionResponseType::Pong,
+        // Choices can't be deferred and the bot would be too slow to
+        // respond in time so no choices are suggested
+        InteractionType::ApplicationCommandAutocomplete => {
+            let response = InteractionResponse {
+                kind: InteractionResponseType::ApplicationCommandAutocompleteResult,
+                data: Some(InteractionResponseData {
+                    choices: Some(Vec::new()),
+                    ..Default::default()
+                }),
+            };
+
+            return Ok(response);
+        }
+        InteractionType::MessageComponent => InteractionResponseType::DeferredUpdateMessage,
+        InteractionType::ModalSubmit if interaction.message.is_some() => {
+            InteractionResponseType::DeferredUpdateMessage
+        }
+        _ => InteractionResponseType::DeferredChannelMessageWithSource,
+    };
+
+    if kind != InteractionResponseType::Pong {
+        state
+            .interactions
+            .send(interaction)
+            .map_err(|_| InteractionError::Unhandled)?;
+    }
+
+    Ok(InteractionResponse { kind, data: None })
+}
+
+/// The timestamp is in unix seconds and must be recent to prevent replays of
+/// intercepted requests.
+fn verify_timestamp(timestamp: &str, now: OffsetDateTime) -> Result<(), InteractionError> {
+    let timestamp = timestamp
+        .parse()
+        .ok()
+        .and_then(|secs| OffsetDateTime::from_unix_timestamp(secs).ok())
+        .ok_or(InteractionError::Outdated)?;
+
+    if (now - timestamp).abs() > MAX_TIMESTAMP_OFFSET {
+        return Err(InteractionError::Outdated);
+    }
+
+    Ok(())
+}
+
+/// The signature is an Ed25519 signature over the timestamp and body using
+/// the application's public key.
+fn verify_signature(
+    public_key: &[u8],
+    timestamp: &str,
+    body: &[u8],
+    signature: &str,
+) -> Result<(), InteractionError> {
+    let signature = hex::decode(signature).map_err(|_| InteractionError::InvalidSignature)?;
+
+    let mut message = Vec::with_capacity(timestamp.len() + body.len());
+    message.extend_from_slice(timestamp.as_bytes());
+    message.extend_from_slice(body);
+
+    UnparsedPublicKey::new(&ED25519, public_key)
+        .verify(&message, &signature)
+        .map_err(|_| InteractionError::InvalidSignature)
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("http interaction error")]
+pub enum InteractionError {
+    #[error("failed to deserialize interaction")]
+    Deserialize(#[source] serde_json::Error),
+    #[error("no public key configured")]
+    Disabled,
+    #[error("invalid signature")]
+    InvalidSignature,
+    #[error("missing header `{0}`")]
+    MissingHeader(&'static str),
+    #[error("outdated timestamp")]
+    Outdated,
+    #[error("interaction receiver is gone")]
+    Unhandled,
+}
+
+impl InteractionError {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            Self::Deserialize(_) | Self::MissingHeader(_) => StatusCode::BAD_REQUEST,
+            Self::Disabled => StatusCode::NOT_FOUND,
+            // Discord requires invalid signatures to be rejected with a 401
+            Self::InvalidSignature | Self::Outdated => StatusCode::UNAUTHORIZED,
+            Self::Unhandled => StatusCode::SERVICE_UNAVAILABLE,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ring::signature::{Ed25519KeyPair, KeyPair};
+
+    use super::*;
+
+    fn sign(key_pair: &Ed25519KeyPair, timestamp: &str, body: &[u8]) -> String {
+        let mut message = timestamp.as_bytes().to_vec();
+        message.extend_from_slice(body);
+
+        hex::encode(key_pair.sign(&message))
+    }
+
+    #[test]
+    fn valid_signature() {
+        let key_pair = Ed25519KeyPair::from_seed_unchecked(&[1; 32]).unwrap();
+        let public_key = key_pair.public_key().as_ref();
+        let (timestamp, body) = ("1700000000", br#"{"type":1}"#);
+        let signature = sign(&key_pair, timestamp, body);
+
+        assert!(verify_signature(public_key, timestamp, body, &signature).is_ok());
+    }
+
+    #[test]
+    fn invalid_signature() {
+        let key_pair = Ed25519KeyPair::from_seed_unchecked(&[1; 32]).unwrap();
+        let other_key_pair = Ed25519KeyPair::from_seed_unchecked(&[2; 32]).unwrap();
+        let public_key = key_pair.public_key().as_ref();
+        let (timestamp, body) = ("1700000000", br#"{"type":1}"#);
+        let signature = sign(&key_pair, timestamp, body);
+        let other_signature = sign(&other_key_pair, timestamp, body);
+
+        assert!(verify_signature(public_key, timestamp, body, &other_signature).is_err());
+        assert!(verify_signature(public_key, "1700000001", body, &signature).is_err());
+        assert!(verify_signature(public_key, timestamp, body, "xyz").is_err());
+        assert!(verify_signature(public_key, timestamp, body, "").is_err());
+    }
+
+    #[test]
+    fn outdated_timestamp() {
+        let now = OffsetDateTime::from_unix_timestamp(1_700_000_000).unwrap();
+
+        assert!(verify_timestamp("1700000000", now).is_ok());
+        assert!(verify_timestamp("1699999900", now).is_ok());
+        assert!(verify_timestamp("1699999000", now).is_err());
+        assert!(verify_timestamp("1700001000", now).is_err());
+        assert!(verify_timestamp("xyz", now).is_err());
+    }
+}
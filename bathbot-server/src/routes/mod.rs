@@ -1,4 +1,6 @@
 pub mod auth;
+pub mod eventsub;
 pub mod guild_count;
+pub mod interactions;
 pub mod metrics;
 pub mod osudirect;
@@ -4,20 +4,27 @@ use axum::{
     http::StatusCode,
     middleware,
     response::Response,
-    routing::{get, get_service},
+    routing::{get, get_service, post},
     Router,
 };
+use bathbot_model::TwitchStreamEvent;
 use eyre::Result;
 use hyper::Request;
-use tokio::sync::oneshot::{channel, Receiver, Sender};
+use tokio::sync::{
+    mpsc::{self, UnboundedReceiver},
+    oneshot::{channel, Receiver, Sender},
+};
 use tower_http::{services::ServeDir, trace::TraceLayer};
 use tracing::Span;
+use twilight_model::application::interaction::Interaction;
 
 use crate::{
     middleware::metrics::track_metrics,
     routes::{
         auth::{osu::auth_osu, twitch::auth_twitch},
+        eventsub::receive_eventsub,
         guild_count::get_guild_count,
+        interactions::receive_interaction,
         metrics::get_metrics,
         osudirect::redirect_osudirect,
     },
@@ -32,11 +39,23 @@ pub struct Server {
     shutdown_rx: Receiver<()>,
 }
 
+pub type ServerTuple = (
+    Server,
+    Arc<AuthenticationStandby>,
+    Sender<()>,
+    UnboundedReceiver<TwitchStreamEvent>,
+    UnboundedReceiver<Interaction>,
+);
+
 impl Server {
-    pub fn new(builder: AppStateBuilder) -> Result<(Self, Arc<AuthenticationStandby>, Sender<()>)> {
+    pub fn new(builder: AppStateBuilder) -> Result<ServerTuple> {
         let (shutdown_tx, shutdown_rx) = channel();
+        let (twitch_tx, twitch_rx) = mpsc::unbounded_channel();
+        let (interaction_tx, interaction_rx) = mpsc::unbounded_channel();
         let standby = Arc::new(AuthenticationStandby::new());
-        let (state, website_path) = builder.build(Arc::clone(&standby))?;
+
+        let (state, website_path) =
+            builder.build(Arc::clone(&standby), twitch_tx, interaction_tx)?;
 
         let server = Self {
             state,
@@ -44,7 +63,7 @@ impl Server {
             shutdown_rx,
         };
 
-        Ok((server, standby, shutdown_tx))
+        Ok((server, standby, shutdown_tx, twitch_rx, interaction_rx))
     }
 
     pub async fn run(self, port: u16) {
@@ -87,6 +106,8 @@ impl Server {
             .route("/metrics", get(get_metrics))
             .route("/guild_count", get(get_guild_count))
             .nest("/auth", Self::auth_app(website_path))
+            .route("/interactions", post(receive_interaction))
+            .route("/twitch/eventsub", post(receive_eventsub))
             .route("/osudirect/:mapset_id", get(redirect_osudirect))
             .layer(middleware::from_fn_with_state(state, track_metrics))
             .layer(trace)
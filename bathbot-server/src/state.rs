@@ -1,5 +1,6 @@
 use std::{path::PathBuf, sync::Arc};
 
+use bathbot_model::TwitchStreamEvent;
 use bathbot_util::MetricsReader;
 use eyre::{Result, WrapErr};
 use handlebars::Handlebars;
@@ -10,8 +11,10 @@ use hyper::{
 use hyper_rustls::{HttpsConnector, HttpsConnectorBuilder};
 use metrics::describe_histogram;
 use metrics_exporter_prometheus::PrometheusHandle;
+use tokio::sync::mpsc::UnboundedSender;
+use twilight_model::application::interaction::Interaction;
 
-use crate::standby::AuthenticationStandby;
+use crate::{routes::eventsub::RecentMessages, standby::AuthenticationStandby};
 
 pub struct AppState {
     pub client: Client<HttpsConnector<HttpConnector<GaiResolver>>, Body>,
@@ -22,6 +25,14 @@ pub struct AppState {
     pub osu_client_secret: Box<str>,
     pub twitch_client_id: Box<str>,
     pub twitch_token: Box<str>,
+    /// `None` if the EventSub endpoint is disabled
+    pub twitch_eventsub_secret: Option<Box<str>>,
+    pub twitch_events: UnboundedSender<TwitchStreamEvent>,
+    pub eventsub_messages: RecentMessages,
+    /// Decoded public key of the discord application; `None` if the
+    /// interactions endpoint is disabled
+    pub discord_public_key: Option<Box<[u8]>>,
+    pub interactions: UnboundedSender<Interaction>,
     pub redirect_base: Box<str>,
     pub standby: Arc<AuthenticationStandby>,
 }
@@ -34,11 +45,21 @@ pub struct AppStateBuilder {
     pub osu_client_secret: String,
     pub twitch_client_id: String,
     pub twitch_token: String,
+    /// `None` to disable the EventSub endpoint
+    pub twitch_eventsub_secret: Option<String>,
+    /// Hex encoded public key of the discord application; `None` to disable
+    /// the interactions endpoint
+    pub discord_public_key: Option<String>,
     pub redirect_base: String,
 }
 
 impl AppStateBuilder {
-    pub(crate) fn build(self, standby: Arc<AuthenticationStandby>) -> Result<(AppState, PathBuf)> {
+    pub(crate) fn build(
+        self,
+        standby: Arc<AuthenticationStandby>,
+        twitch_events: UnboundedSender<TwitchStreamEvent>,
+        interactions: UnboundedSender<Interaction>,
+    ) -> Result<(AppState, PathBuf)> {
         let Self {
             website_path,
             prometheus,
@@ -47,9 +68,16 @@ impl AppStateBuilder {
             osu_client_secret,
             twitch_client_id,
             twitch_token,
+            twitch_eventsub_secret,
+            discord_public_key,
             redirect_base,
         } = self;
 
+        let discord_public_key = discord_public_key
+            .map(|key| hex::decode(key).wrap_err("Failed to decode discord public key"))
+            .transpose()?
+            .map(Vec::into_boxed_slice);
+
         let connector = HttpsConnectorBuilder::new()
             .with_webpki_roots()
             .https_or_http()
@@ -80,6 +108,11 @@ impl AppStateBuilder {
             osu_client_secret: osu_client_secret.into_boxed_str(),
             twitch_client_id: twitch_client_id.into_boxed_str(),
             twitch_token: twitch_token.into_boxed_str(),
+            twitch_eventsub_secret: twitch_eventsub_secret.map(String::into_boxed_str),
+            twitch_events,
+            eventsub_messages: RecentMessages::default(),
+            discord_public_key,
+            interactions,
             redirect_base: redirect_base.into_boxed_str(),
             standby,
         };
@@ -20,6 +20,7 @@ pub const TWITCH_BASE: &str = "https://www.twitch.tv/";
 pub const TWITCH_STREAM_ENDPOINT: &str = "https://api.twitch.tv/helix/streams";
 pub const TWITCH_USERS_ENDPOINT: &str = "https://api.twitch.tv/helix/users";
 pub const TWITCH_VIDEOS_ENDPOINT: &str = "https://api.twitch.tv/helix/videos";
+pub const TWITCH_EVENTSUB_ENDPOINT: &str = "https://api.twitch.tv/helix/eventsub/subscriptions";
 pub const TWITCH_OAUTH: &str = "https://id.twitch.tv/oauth2/token";
 
 // Error messages
@@ -35,7 +36,9 @@ pub const TWITCH_API_ISSUE: &str = "Some issue with the twitch api, blame bade";
 pub const THREADS_UNAVAILABLE: &str = "Cannot start new thread from here";
 
 // Discord error codes
+pub const MISSING_ACCESS: u64 = 50001;
 pub const CANNOT_DM_USER: u64 = 50007;
+pub const MISSING_PERMISSIONS: u64 = 50013;
 pub const INVALID_ACTION_FOR_CHANNEL_TYPE: u64 = 50024;
 pub const MESSAGE_TOO_OLD_TO_BULK_DELETE: u64 = 50034;
 
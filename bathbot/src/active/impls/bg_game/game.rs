@@ -1,12 +1,12 @@
 use std::{collections::VecDeque, sync::RwLock};
 
 use bathbot_model::Effects;
-use bathbot_psql::model::games::MapsetTagsEntries;
+use bathbot_psql::model::games::{DbMapTagEntry, MapsetTagsEntries};
 use bathbot_util::{constants::OSU_BASE, CowUtils};
 use eyre::{Result, WrapErr};
 use image::{
     imageops::{self, colorops},
-    GenericImageView,
+    DynamicImage, GenericImageView,
 };
 use rosu_v2::model::GameMode;
 use tokio::{fs, sync::RwLock as TokioRwLock};
@@ -17,14 +17,17 @@ use twilight_model::id::{
 };
 use twilight_standby::future::WaitForMessageStream;
 
-use super::{hints::Hints, img_reveal::ImageReveal, mapset::GameMapset, util};
+use super::{
+    game_wrapper::BgGameKind, hints::Hints, img_reveal::ImageReveal, mapset::GameMapset,
+    pattern::PatternReveal, util,
+};
 use crate::{commands::fun::GameDifficulty, core::BotConfig, util::ChannelExt, Context};
 
 pub struct Game {
     pub mapset: GameMapset,
     difficulty: f32,
     hints: RwLock<Hints>,
-    reveal: RwLock<ImageReveal>,
+    reveal: RwLock<Reveal>,
 }
 
 impl Game {
@@ -32,11 +35,14 @@ impl Game {
         ctx: &Context,
         entries: &MapsetTagsEntries,
         previous_ids: &mut VecDeque<i32>,
+        kind: BgGameKind,
         effects: Effects,
         difficulty: GameDifficulty,
     ) -> (Self, Vec<u8>) {
         loop {
-            match Game::new_(ctx, entries, previous_ids, effects, difficulty).await {
+            let mapset = util::get_random_mapset(entries, previous_ids);
+
+            match Game::new_(ctx, entries.mode, mapset, kind, effects, difficulty).await {
                 Ok(game) => {
                     let sub_image_result = { game.reveal.read().unwrap().sub_image() };
 
@@ -58,82 +64,136 @@ impl Game {
         }
     }
 
-    async fn new_(
+    /// Continue a game with the given mapset.
+    ///
+    /// Falls back to a random mapset if the given one is not among the entries.
+    pub async fn resume(
         ctx: &Context,
         entries: &MapsetTagsEntries,
         previous_ids: &mut VecDeque<i32>,
+        mapset_id: u32,
+        kind: BgGameKind,
+        effects: Effects,
+        difficulty: GameDifficulty,
+    ) -> (Self, Vec<u8>) {
+        let entry = entries
+            .tags
+            .iter()
+            .find(|entry| entry.mapset_id == mapset_id as i32);
+
+        if let Some(mapset) = entry {
+            previous_ids.push_front(mapset.mapset_id);
+
+            match Game::new_(ctx, entries.mode, mapset, kind, effects, difficulty).await {
+                Ok(game) => {
+                    let sub_image_result = { game.reveal.read().unwrap().sub_image() };
+
+                    match sub_image_result {
+                        Ok(img) => return (game, img),
+                        Err(err) => warn!(mapset_id, ?err, "Failed to resume bg game"),
+                    }
+                }
+                Err(err) => warn!(mapset_id, ?err, "Error while resuming bg game"),
+            }
+        }
+
+        Self::new(ctx, entries, previous_ids, kind, effects, difficulty).await
+    }
+
+    async fn new_(
+        ctx: &Context,
+        mode: GameMode,
+        mapset: &DbMapTagEntry,
+        kind: BgGameKind,
         effects: Effects,
         difficulty: GameDifficulty,
     ) -> Result<Self> {
+        let mapset_id = mapset.mapset_id;
+        debug!("Next BG mapset id: {mapset_id}");
+
+        let reveal_fut = async {
+            match kind {
+                BgGameKind::Background => Self::background(mode, mapset, effects)
+                    .await
+                    .map(ImageReveal::new)
+                    .map(Reveal::Background),
+                BgGameKind::Pattern => PatternReveal::new(ctx, mapset_id as u32)
+                    .await
+                    .map(Reveal::Pattern),
+            }
+        };
+
+        let (mapset_, reveal) =
+            tokio::try_join!(GameMapset::new(ctx, mapset.mapset_id as u32), reveal_fut)?;
+
+        Ok(Self {
+            hints: RwLock::new(Hints::new(mapset_.title())),
+            difficulty: difficulty.factor(),
+            mapset: mapset_,
+            reveal: RwLock::new(reveal),
+        })
+    }
+
+    async fn background(
+        mode: GameMode,
+        mapset: &DbMapTagEntry,
+        effects: Effects,
+    ) -> Result<DynamicImage> {
         let mut path = BotConfig::get().paths.backgrounds.clone();
 
-        match entries.mode {
+        match mode {
             GameMode::Osu => path.push("osu"),
             GameMode::Mania => path.push("mania"),
-            _ => bail!("background game not available for {}", entries.mode),
+            _ => bail!("background game not available for {mode}"),
         }
 
-        let mapset = util::get_random_mapset(entries, previous_ids);
         let mapset_id = mapset.mapset_id;
-        debug!("Next BG mapset id: {mapset_id}");
         path.push(&mapset.image_filename);
 
-        let img_fut = async {
-            let bytes = fs::read(path)
-                .await
-                .wrap_err_with(|| format!("failed to read bg image for mapset {mapset_id}"))?;
+        let bytes = fs::read(path)
+            .await
+            .wrap_err_with(|| format!("failed to read bg image for mapset {mapset_id}"))?;
 
-            let mut img =
-                image::load_from_memory(&bytes).wrap_err("failed to load image from memory")?;
+        let mut img =
+            image::load_from_memory(&bytes).wrap_err("failed to load image from memory")?;
 
-            let (w, h) = img.dimensions();
-
-            // 800*600 (4:3)
-            if w * h > 480_000 {
-                img = img.thumbnail(800, 600);
-            }
+        let (w, h) = img.dimensions();
 
-            if effects.contains(Effects::Invert) {
-                img.invert();
-            }
-
-            if effects.contains(Effects::Contrast) {
-                colorops::contrast_in_place(&mut img, 18.0);
-            }
+        // 800*600 (4:3)
+        if w * h > 480_000 {
+            img = img.thumbnail(800, 600);
+        }
 
-            if effects.contains(Effects::FlipHorizontal) {
-                imageops::flip_horizontal_in_place(&mut img);
-            }
+        if effects.contains(Effects::Invert) {
+            img.invert();
+        }
 
-            if effects.contains(Effects::FlipVertical) {
-                imageops::flip_vertical_in_place(&mut img);
-            }
+        if effects.contains(Effects::Contrast) {
+            colorops::contrast_in_place(&mut img, 18.0);
+        }
 
-            if effects.contains(Effects::Grayscale) {
-                img = img.grayscale();
-            }
+        if effects.contains(Effects::FlipHorizontal) {
+            imageops::flip_horizontal_in_place(&mut img);
+        }
 
-            if effects.contains(Effects::Blur) {
-                img = img.blur(4.0);
-            }
+        if effects.contains(Effects::FlipVertical) {
+            imageops::flip_vertical_in_place(&mut img);
+        }
 
-            Ok(img)
-        };
+        if effects.contains(Effects::Grayscale) {
+            img = img.grayscale();
+        }
 
-        let (mapset_, img) =
-            tokio::try_join!(GameMapset::new(ctx, mapset.mapset_id as u32), img_fut)?;
+        if effects.contains(Effects::Blur) {
+            img = img.blur(4.0);
+        }
 
-        Ok(Self {
-            hints: RwLock::new(Hints::new(mapset_.title())),
-            difficulty: difficulty.factor(),
-            mapset: mapset_,
-            reveal: RwLock::new(ImageReveal::new(img)),
-        })
+        Ok(img)
     }
 
     pub fn sub_image(&self) -> Result<Vec<u8>> {
         let mut reveal = self.reveal.write().unwrap();
-        reveal.increase_radius();
+        reveal.increase();
 
         reveal.sub_image()
     }
@@ -167,11 +227,35 @@ impl Game {
     }
 }
 
+/// The image that is shown to players and can be enlarged over time
+enum Reveal {
+    Background(ImageReveal),
+    Pattern(PatternReveal),
+}
+
+impl Reveal {
+    fn increase(&mut self) {
+        match self {
+            Self::Background(reveal) => reveal.increase_radius(),
+            Self::Pattern(reveal) => reveal.increase_len(),
+        }
+    }
+
+    fn sub_image(&self) -> Result<Vec<u8>> {
+        match self {
+            Self::Background(reveal) => reveal.sub_image(),
+            Self::Pattern(reveal) => reveal.sub_image(),
+        }
+    }
+}
+
 #[derive(Clone, Copy)]
 pub enum LoopResult {
     Winner(Id<UserMarker>),
     Restart,
     Stop,
+    /// Quit silently so that the game can be resumed after a restart
+    Suspend,
 }
 
 pub async fn game_loop(
@@ -1,13 +1,14 @@
 use std::{
     collections::{HashMap, VecDeque},
     mem,
-    sync::Arc,
+    sync::{Arc, Mutex},
 };
 
-use bathbot_model::Effects;
-use bathbot_psql::model::games::MapsetTagsEntries;
+use bathbot_model::{ArchivedBgGameSnapshot, BgGameSnapshot, Effects, MapsetTags};
+use bathbot_psql::model::games::{DbMapTagsParams, MapsetTagsEntries};
 use bathbot_util::{constants::OSU_BASE, IntHasher, MessageBuilder};
-use eyre::Result;
+use eyre::{Result, WrapErr};
+use rosu_v2::prelude::GameMode;
 use tokio::{
     sync::{
         mpsc::{self, UnboundedSender},
@@ -17,7 +18,10 @@ use tokio::{
 };
 use twilight_model::{
     gateway::payload::incoming::MessageCreate,
-    id::{marker::ChannelMarker, Id},
+    id::{
+        marker::{ChannelMarker, UserMarker},
+        Id,
+    },
 };
 
 use super::game::{game_loop, Game, LoopResult};
@@ -25,9 +29,42 @@ use crate::{commands::fun::GameDifficulty, util::ChannelExt, Context};
 
 const GAME_LEN: Duration = Duration::from_secs(180);
 
+type Scores = HashMap<Id<UserMarker>, u32, IntHasher>;
+
+#[derive(Copy, Clone)]
+pub struct BgGameSettings {
+    pub kind: BgGameKind,
+    pub included: MapsetTags,
+    pub excluded: MapsetTags,
+    pub effects: Effects,
+    pub difficulty: GameDifficulty,
+}
+
+/// What players are shown to guess the map
+#[derive(Copy, Clone, Default)]
+pub enum BgGameKind {
+    /// Part of the mapset's background
+    #[default]
+    Background = 0,
+    /// A few seconds of hit objects of one of the mapset's difficulties
+    Pattern = 1,
+}
+
+impl From<u8> for BgGameKind {
+    fn from(kind: u8) -> Self {
+        match kind {
+            1 => Self::Pattern,
+            _ => Self::Background,
+        }
+    }
+}
+
 #[derive(Clone)]
 pub struct BackgroundGame {
     game: Arc<RwLock<Game>>,
+    scores: Arc<Mutex<Scores>>,
+    mode: GameMode,
+    settings: BgGameSettings,
     tx: UnboundedSender<LoopResult>,
 }
 
@@ -36,9 +73,69 @@ impl BackgroundGame {
         ctx: Arc<Context>,
         channel: Id<ChannelMarker>,
         entries: MapsetTagsEntries,
-        effects: Effects,
-        difficulty: GameDifficulty,
+        settings: BgGameSettings,
     ) -> Self {
+        Self::start(ctx, channel, entries, settings, Scores::default(), None).await
+    }
+
+    /// Continue a game that was snapshotted before a restart.
+    pub async fn resume(
+        ctx: Arc<Context>,
+        snapshot: &ArchivedBgGameSnapshot,
+    ) -> Result<(Id<ChannelMarker>, Self)> {
+        let Some(channel) = Id::new_checked(snapshot.channel) else {
+            bail!("Invalid channel id {}", snapshot.channel);
+        };
+
+        let settings = BgGameSettings {
+            kind: BgGameKind::from(snapshot.kind),
+            included: MapsetTags::from_bits_truncate(snapshot.included),
+            excluded: MapsetTags::from_bits_truncate(snapshot.excluded),
+            effects: Effects::from_bits_truncate(snapshot.effects),
+            difficulty: GameDifficulty::from(snapshot.difficulty),
+        };
+
+        let mut params = DbMapTagsParams::new(GameMode::from(snapshot.mode));
+        params.include(settings.included);
+        params.exclude(settings.excluded);
+
+        let entries = ctx
+            .games()
+            .bggame_tags(params)
+            .await
+            .wrap_err("Failed to get background game tags")?;
+
+        if entries.tags.is_empty() {
+            bail!("No stored backgrounds match the tags anymore");
+        }
+
+        let scores = snapshot
+            .scores
+            .iter()
+            .filter_map(|(user, score)| Some((Id::new_checked(*user)?, *score)))
+            .collect();
+
+        let mapset_id = Some(snapshot.mapset_id);
+        let game = Self::start(ctx, channel, entries, settings, scores, mapset_id).await;
+
+        Ok((channel, game))
+    }
+
+    async fn start(
+        ctx: Arc<Context>,
+        channel: Id<ChannelMarker>,
+        entries: MapsetTagsEntries,
+        settings: BgGameSettings,
+        scores: Scores,
+        resume_mapset: Option<u32>,
+    ) -> Self {
+        let BgGameSettings {
+            kind,
+            effects,
+            difficulty,
+            ..
+        } = settings;
+
         let (tx, mut rx) = mpsc::unbounded_channel();
 
         let mut msg_stream = ctx
@@ -46,18 +143,40 @@ impl BackgroundGame {
             .wait_for_message_stream(channel, |event: &MessageCreate| !event.author.bot);
 
         let mut previous_ids = VecDeque::with_capacity(50);
-        let mut scores = HashMap::with_hasher(IntHasher);
+        let mode = entries.mode;
+        let scores = Arc::new(Mutex::new(scores));
+        let scores_clone = Arc::clone(&scores);
 
         // Initialize game
-        let (game, mut img) =
-            Game::new(&ctx, &entries, &mut previous_ids, effects, difficulty).await;
+        let (game, mut img) = match resume_mapset {
+            Some(mapset_id) => {
+                Game::resume(
+                    &ctx,
+                    &entries,
+                    &mut previous_ids,
+                    mapset_id,
+                    kind,
+                    effects,
+                    difficulty,
+                )
+                .await
+            }
+            None => Game::new(&ctx, &entries, &mut previous_ids, kind, effects, difficulty).await,
+        };
+
         let game = Arc::new(RwLock::new(game));
         let game_clone = Arc::clone(&game);
 
+        let mut content = if resume_mapset.is_some() {
+            "I'm back, let's continue where we left off:"
+        } else {
+            "Here's the next one:"
+        };
+
         tokio::spawn(async move {
             loop {
                 let builder = MessageBuilder::new()
-                    .content("Here's the next one:")
+                    .content(mem::replace(&mut content, "Here's the next one:"))
                     .attachment("bg_img.png", mem::take(&mut img));
 
                 if let Err(err) = channel.create_message(&ctx, builder, None).await {
@@ -102,6 +221,8 @@ impl BackgroundGame {
                             warn!(?err, "Failed to show resolve for bg game stop");
                         }
 
+                        let scores = mem::take(&mut *scores_clone.lock().unwrap());
+
                         // Store score for winners
                         for (user, score) in scores {
                             if let Err(err) = ctx.games().bggame_increment_score(user, score).await
@@ -114,16 +235,21 @@ impl BackgroundGame {
                         info!(%channel, "Game finished");
                         break;
                     }
+                    LoopResult::Suspend => {
+                        // Scores are kept in the snapshot
+                        info!(%channel, "Game suspended");
+                        break;
+                    }
                     LoopResult::Winner(user_id) => {
                         if entries.tags.len() >= 20 {
-                            *scores.entry(user_id).or_insert(0) += 1;
+                            *scores_clone.lock().unwrap().entry(user_id).or_insert(0) += 1;
                         }
                     }
                 }
 
                 // Initialize next game
                 let (game, img_) =
-                    Game::new(&ctx, &entries, &mut previous_ids, effects, difficulty).await;
+                    Game::new(&ctx, &entries, &mut previous_ids, kind, effects, difficulty).await;
                 img = img_;
                 *game_clone.write().await = game;
             }
@@ -131,7 +257,13 @@ impl BackgroundGame {
             ctx.bg_games().write(&channel).await.remove();
         });
 
-        Self { game, tx }
+        Self {
+            game,
+            scores,
+            mode,
+            settings,
+            tx,
+        }
     }
 
     pub fn stop(&self) -> Result<()> {
@@ -146,6 +278,42 @@ impl BackgroundGame {
             .map_err(|_| eyre!("Failed to send restart token"))
     }
 
+    /// End the game without resolving the mapset or storing scores.
+    ///
+    /// Should only be used after taking a [`snapshot`](Self::snapshot).
+    pub fn suspend(&self) -> Result<()> {
+        self.tx
+            .send(LoopResult::Suspend)
+            .map_err(|_| eyre!("Failed to send suspend token"))
+    }
+
+    pub async fn snapshot(&self, channel: Id<ChannelMarker>) -> Result<BgGameSnapshot> {
+        let mapset_id = timeout(Duration::from_secs(1), self.game.read())
+            .await
+            .map_err(|_| eyre!("timeout while waiting for read"))?
+            .mapset_id();
+
+        let scores = self
+            .scores
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(user, score)| (user.get(), *score))
+            .collect();
+
+        Ok(BgGameSnapshot {
+            channel: channel.get(),
+            mode: self.mode as u8,
+            kind: self.settings.kind as u8,
+            mapset_id,
+            included: self.settings.included.bits(),
+            excluded: self.settings.excluded.bits(),
+            effects: self.settings.effects.bits(),
+            difficulty: self.settings.difficulty as u8,
+            scores,
+        })
+    }
+
     pub async fn sub_image(&self) -> Result<Vec<u8>> {
         timeout(Duration::from_secs(1), self.game.read())
             .await?
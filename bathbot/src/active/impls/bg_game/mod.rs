@@ -2,7 +2,7 @@ use std::sync::Arc;
 
 use bathbot_model::{Effects, MapsetTags};
 use bathbot_psql::model::games::DbMapTagsParams;
-use bathbot_util::{constants::GENERAL_ISSUE, fields, EmbedBuilder, FooterBuilder, MessageBuilder};
+use bathbot_util::{fields, EmbedBuilder, FooterBuilder, MessageBuilder};
 use eyre::{Report, Result};
 use futures::future::BoxFuture;
 use rosu_v2::prelude::GameMode;
@@ -17,11 +17,14 @@ use twilight_model::{
     },
 };
 
-pub use self::game_wrapper::BackgroundGame;
+pub use self::game_wrapper::{BackgroundGame, BgGameKind, BgGameSettings};
 use crate::{
     active::{BuildPage, ComponentResult, IActiveMessage},
     commands::fun::GameDifficulty,
-    core::Context,
+    core::{
+        locale::{self, ErrorKey},
+        Context,
+    },
     util::{interaction::InteractionComponent, Authored, ComponentExt},
 };
 
@@ -30,6 +33,7 @@ mod game_wrapper;
 mod hints;
 mod img_reveal;
 mod mapset;
+mod pattern;
 mod util;
 
 pub struct BackgroundGameSetup {
@@ -352,7 +356,10 @@ impl BackgroundGameSetup {
             Ok(entries) => entries,
             Err(err) => {
                 warn!(?err, "Failed to get background game tags");
-                let embed = EmbedBuilder::new().color_red().description(GENERAL_ISSUE);
+                let language = locale::language(&ctx, Some(self.msg_owner), None, None).await;
+                let embed = EmbedBuilder::new()
+                    .color_red()
+                    .description(ErrorKey::General.text(language));
 
                 return Ok(BuildPage::new(embed, true));
             }
@@ -403,13 +410,15 @@ impl BackgroundGameSetup {
                 "Starting game"
             );
 
-            let game_fut = BackgroundGame::new(
-                Arc::clone(&ctx),
-                channel,
-                entries,
-                self.effects,
-                self.difficulty,
-            );
+            let settings = BgGameSettings {
+                kind: BgGameKind::Background,
+                included: self.included,
+                excluded: self.excluded,
+                effects: self.effects,
+                difficulty: self.difficulty,
+            };
+
+            let game_fut = BackgroundGame::new(Arc::clone(&ctx), channel, entries, settings);
 
             let game = game_fut.await;
             ctx.bg_games().own(channel).await.insert(game);
@@ -0,0 +1,180 @@
+use eyre::{ContextCompat, Result, WrapErr};
+use rand::{seq::SliceRandom, Rng};
+use rosu_pp::{
+    parse::{HitObject, HitObjectKind, Pos2},
+    GameMode as Mode,
+};
+use skia_safe::{
+    paint::{Cap, Join, Style},
+    surfaces, Color, EncodedImageFormat, Paint, Path,
+};
+
+use crate::core::Context;
+
+const W: i32 = 864;
+const H: i32 = 648;
+
+/// Scale from osu!pixels to image pixels
+const SCALE: f32 = 1.5;
+const OFFSET_X: f32 = (W as f32 - 512.0 * SCALE) / 2.0;
+const OFFSET_Y: f32 = (H as f32 - 384.0 * SCALE) / 2.0;
+
+/// Initial amount of milliseconds of hit objects that are drawn
+const INITIAL_LEN: f64 = 2000.0;
+
+/// Additional milliseconds that are drawn each time the image is enlarged
+const LEN_INCREASE: f64 = 1500.0;
+
+const COMBO_COLORS: [Color; 4] = [
+    Color::from_rgb(255, 102, 170),
+    Color::from_rgb(102, 204, 255),
+    Color::from_rgb(255, 204, 34),
+    Color::from_rgb(136, 221, 136),
+];
+
+/// Renders a section of hit objects of one of the mapset's difficulties.
+///
+/// Similar to [`ImageReveal`](super::img_reveal::ImageReveal), the
+/// section can be extended to reveal more of the map.
+pub struct PatternReveal {
+    hit_objects: Vec<HitObject>,
+    radius: f32,
+    start: f64,
+    len: f64,
+}
+
+impl PatternReveal {
+    pub async fn new(ctx: &Context, mapset_id: u32) -> Result<Self> {
+        let versions = ctx
+            .osu_map()
+            .versions_by_mapset(mapset_id)
+            .await
+            .wrap_err("Failed to get map versions")?;
+
+        let map_id = versions
+            .choose(&mut rand::thread_rng())
+            .wrap_err("Mapset has no versions")?
+            .map_id as u32;
+
+        let map = ctx
+            .osu_map()
+            .pp_map(map_id)
+            .await
+            .wrap_err("Failed to get beatmap")?;
+
+        if map.mode != Mode::Osu {
+            bail!("Map {map_id} is not an osu!standard map");
+        }
+
+        let hit_objects = map.hit_objects;
+
+        let (first, last) = match (hit_objects.first(), hit_objects.last()) {
+            (Some(first), Some(last)) => (first.start_time, last.start_time),
+            _ => bail!("Map {map_id} has no hit objects"),
+        };
+
+        let start = if last - first > INITIAL_LEN {
+            rand::thread_rng().gen_range(first..last - INITIAL_LEN)
+        } else {
+            first
+        };
+
+        Ok(Self {
+            hit_objects,
+            radius: 54.4 - 4.48 * map.cs,
+            start,
+            len: INITIAL_LEN,
+        })
+    }
+
+    pub fn increase_len(&mut self) {
+        self.len += LEN_INCREASE;
+    }
+
+    pub fn sub_image(&self) -> Result<Vec<u8>> {
+        let mut surface =
+            surfaces::raster_n32_premul((W, H)).wrap_err("Failed to create surface")?;
+        let canvas = surface.canvas();
+        canvas.clear(Color::from_rgb(24, 24, 24));
+
+        let radius = self.radius * SCALE;
+        let end = self.start + self.len;
+
+        let mut fill = Paint::default();
+        fill.set_anti_alias(true);
+
+        let mut border = Paint::default();
+        border.set_anti_alias(true);
+        border.set_style(Style::Stroke);
+        border.set_stroke_width(radius * 0.15);
+        border.set_color(Color::WHITE);
+
+        let mut body = Paint::default();
+        body.set_anti_alias(true);
+        body.set_style(Style::Stroke);
+        body.set_stroke_cap(Cap::Round);
+        body.set_stroke_join(Join::Round);
+
+        let objects: Vec<_> = self
+            .hit_objects
+            .iter()
+            .enumerate()
+            .skip_while(|(_, h)| h.start_time < self.start)
+            .take_while(|(_, h)| h.start_time <= end)
+            .collect();
+
+        // Earlier objects are drawn on top of later ones
+        for &(i, h) in objects.iter().rev() {
+            let color = COMBO_COLORS[i % COMBO_COLORS.len()];
+            let (x, y) = to_canvas(h.pos);
+
+            match h.kind {
+                HitObjectKind::Circle => {}
+                HitObjectKind::Slider {
+                    ref control_points, ..
+                } => {
+                    let mut path = Path::new();
+                    path.move_to((x, y));
+
+                    // Straight lines between control points are close enough
+                    // to give an idea of the slider's shape
+                    for point in control_points.iter().skip(1) {
+                        path.line_to(to_canvas(h.pos + point.pos));
+                    }
+
+                    body.set_stroke_width(2.0 * radius);
+                    body.set_color(Color::WHITE);
+                    canvas.draw_path(&path, &body);
+
+                    body.set_stroke_width(1.7 * radius);
+                    body.set_color(color.with_a(200));
+                    canvas.draw_path(&path, &body);
+                }
+                HitObjectKind::Spinner { .. } | HitObjectKind::Hold { .. } => {
+                    let (cx, cy) = to_canvas(Pos2 { x: 256.0, y: 192.0 });
+
+                    canvas.draw_circle((cx, cy), 150.0 * SCALE, &border);
+                    canvas.draw_circle((cx, cy), 8.0 * SCALE, &border);
+
+                    continue;
+                }
+            }
+
+            fill.set_color(color);
+            canvas.draw_circle((x, y), radius, &fill);
+            canvas.draw_circle((x, y), radius, &border);
+        }
+
+        let png_bytes = surface
+            .image_snapshot()
+            .encode(None, EncodedImageFormat::PNG, None)
+            .wrap_err("Failed to encode image")?
+            .to_vec();
+
+        Ok(png_bytes)
+    }
+}
+
+fn to_canvas(pos: Pos2) -> (f32, f32) {
+    (OFFSET_X + pos.x * SCALE, OFFSET_Y + pos.y * SCALE)
+}
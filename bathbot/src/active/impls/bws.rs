@@ -0,0 +1,134 @@
+use std::{fmt::Write, sync::Arc};
+
+use bathbot_macros::PaginationBuilder;
+use bathbot_util::{
+    constants::OSU_BASE, numbers::WithComma, CowUtils, EmbedBuilder, FooterBuilder,
+};
+use eyre::Result;
+use futures::future::BoxFuture;
+use twilight_model::{
+    channel::message::Component,
+    id::{marker::UserMarker, Id},
+};
+
+use crate::{
+    active::{
+        pagination::{handle_pagination_component, handle_pagination_modal, Pages},
+        BuildPage, ComponentResult, IActiveMessage,
+    },
+    commands::osu::BwsEntry,
+    core::Context,
+    util::interaction::{InteractionComponent, InteractionModal},
+};
+
+/// How many usernames of players that were not found are listed at most
+const NOT_FOUND_LIMIT: usize = 10;
+
+#[derive(PaginationBuilder)]
+pub struct BwsListPagination {
+    #[pagination(per_page = 15)]
+    entries: Box<[BwsEntry]>,
+    filtered: usize,
+    unranked: usize,
+    not_found: Box<[Box<str>]>,
+    badge_years: Option<u32>,
+    msg_owner: Id<UserMarker>,
+    pages: Pages,
+}
+
+impl IActiveMessage for BwsListPagination {
+    fn build_page(&mut self, _: Arc<Context>) -> BoxFuture<'_, Result<BuildPage>> {
+        let pages = &self.pages;
+        let idx = pages.index();
+
+        let limit = self.entries.len().min(idx + pages.per_page());
+        let entries = &self.entries[idx..limit];
+
+        let mut description = String::with_capacity(entries.len() * 96);
+
+        for (entry, i) in entries.iter().zip(idx + 1..) {
+            let _ = writeln!(
+                description,
+                "`#{i}` [{name}]({OSU_BASE}u/{user_id}) • BWS `#{bws}` • \
+                Rank `#{rank}` • `{badges}` badge{plural}",
+                name = entry.username.cow_escape_markdown(),
+                user_id = entry.user_id,
+                bws = WithComma::new(entry.bws),
+                rank = WithComma::new(entry.global_rank),
+                badges = entry.badges,
+                plural = if entry.badges == 1 { "" } else { "s" },
+            );
+        }
+
+        if self.entries.is_empty() {
+            description.push_str("No player of the list fits the requirements\n");
+        }
+
+        if !self.not_found.is_empty() {
+            description.push_str("\n**Not found:** ");
+
+            let mut names = self.not_found.iter().take(NOT_FOUND_LIMIT);
+
+            if let Some(name) = names.next() {
+                let _ = write!(description, "`{name}`");
+
+                for name in names {
+                    let _ = write!(description, ", `{name}`");
+                }
+            }
+
+            if self.not_found.len() > NOT_FOUND_LIMIT {
+                let _ = write!(
+                    description,
+                    " and {} more",
+                    self.not_found.len() - NOT_FOUND_LIMIT
+                );
+            }
+        }
+
+        let page = pages.curr_page();
+        let pages = pages.last_page();
+
+        let mut footer_text = format!("Page {page}/{pages} • {} players", self.entries.len());
+
+        if self.filtered > 0 {
+            let _ = write!(footer_text, " • {} outside of the rank range", self.filtered);
+        }
+
+        if self.unranked > 0 {
+            let _ = write!(footer_text, " • {} unranked", self.unranked);
+        }
+
+        if let Some(years) = self.badge_years {
+            let plural = if years == 1 { "" } else { "s" };
+            let _ = write!(footer_text, " • Badges of the last {years} year{plural}");
+        }
+
+        let embed = EmbedBuilder::new()
+            .description(description)
+            .footer(FooterBuilder::new(footer_text))
+            .title("Badge weighted seeding");
+
+        BuildPage::new(embed, false).boxed()
+    }
+
+    fn build_components(&self) -> Vec<Component> {
+        self.pages.components()
+    }
+
+    fn handle_component<'a>(
+        &'a mut self,
+        ctx: Arc<Context>,
+        component: &'a mut InteractionComponent,
+    ) -> BoxFuture<'a, ComponentResult> {
+        handle_pagination_component(ctx, component, self.msg_owner, false, &mut self.pages)
+    }
+
+    fn handle_modal<'a>(
+        &'a mut self,
+        ctx: &'a Context,
+        modal: &'a mut InteractionModal,
+    ) -> BoxFuture<'a, Result<()>> {
+        handle_pagination_modal(ctx, modal, self.msg_owner, false, &mut self.pages)
+    }
+}
@@ -0,0 +1,104 @@
+use std::{fmt::Write, sync::Arc};
+
+use bathbot_macros::PaginationBuilder;
+use bathbot_psql::model::osu::DbCollectionMap;
+use bathbot_util::{constants::OSU_BASE, CowUtils, EmbedBuilder, FooterBuilder};
+use eyre::Result;
+use futures::future::BoxFuture;
+use twilight_model::{
+    channel::message::Component,
+    id::{marker::UserMarker, Id},
+};
+
+use crate::{
+    active::{
+        pagination::{handle_pagination_component, handle_pagination_modal, Pages},
+        BuildPage, ComponentResult, IActiveMessage,
+    },
+    core::Context,
+    util::{
+        interaction::{InteractionComponent, InteractionModal},
+        Emote,
+    },
+};
+
+#[derive(PaginationBuilder)]
+pub struct CollectionPagination {
+    #[pagination(per_page = 10)]
+    maps: Box<[DbCollectionMap]>,
+    name: Box<str>,
+    owner: Id<UserMarker>,
+    msg_owner: Id<UserMarker>,
+    pages: Pages,
+}
+
+impl IActiveMessage for CollectionPagination {
+    fn build_page(&mut self, _: Arc<Context>) -> BoxFuture<'_, Result<BuildPage>> {
+        let pages = &self.pages;
+        let idx = pages.index();
+
+        let limit = self.maps.len().min(idx + pages.per_page());
+        let maps = &self.maps[idx..limit];
+
+        let mut description = format!("Owner: <@{}>\n", self.owner);
+
+        #[cfg(not(feature = "server"))]
+        let url = "https://www.google.com";
+
+        #[cfg(feature = "server")]
+        let url = &crate::core::BotConfig::get().server.public_url;
+
+        for (map, i) in maps.iter().zip(idx + 1..) {
+            let _ = writeln!(
+                description,
+                "**#{i} {mode} [{artist} - {title} [{version}]]({OSU_BASE}b/{map_id})**\n\
+                ⤷ [osu!direct]({url}/osudirect/{mapset_id}) • [Mapset]({OSU_BASE}d/{mapset_id})",
+                mode = Emote::from(map.mode),
+                artist = map.artist.cow_escape_markdown(),
+                title = map.title.cow_escape_markdown(),
+                version = map.version.cow_escape_markdown(),
+                map_id = map.map_id,
+                mapset_id = map.mapset_id,
+            );
+        }
+
+        if self.maps.is_empty() {
+            description.push_str(
+                "This collection does not contain any maps yet.\n\
+                Maps can be added via `/collection add`.",
+            );
+        }
+
+        let page = pages.curr_page();
+        let pages = pages.last_page();
+
+        let footer = FooterBuilder::new(format!("Page {page}/{pages} • {} maps", self.maps.len()));
+
+        let embed = EmbedBuilder::new()
+            .description(description)
+            .footer(footer)
+            .title(format!("Collection `{}`", self.name));
+
+        BuildPage::new(embed, false).boxed()
+    }
+
+    fn build_components(&self) -> Vec<Component> {
+        self.pages.components()
+    }
+
+    fn handle_component<'a>(
+        &'a mut self,
+        ctx: Arc<Context>,
+        component: &'a mut InteractionComponent,
+    ) -> BoxFuture<'a, ComponentResult> {
+        handle_pagination_component(ctx, component, self.msg_owner, false, &mut self.pages)
+    }
+
+    fn handle_modal<'a>(
+        &'a mut self,
+        ctx: &'a Context,
+        modal: &'a mut InteractionModal,
+    ) -> BoxFuture<'a, Result<()>> {
+        handle_pagination_modal(ctx, modal, self.msg_owner, false, &mut self.pages)
+    }
+}
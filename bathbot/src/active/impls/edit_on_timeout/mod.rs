@@ -1,9 +1,6 @@
 use std::{mem, sync::Arc, time::Duration};
 
-use bathbot_util::{
-    constants::{GENERAL_ISSUE, ORDR_ISSUE},
-    EmbedBuilder, MessageBuilder,
-};
+use bathbot_util::{EmbedBuilder, MessageBuilder};
 use eyre::{Report, Result, WrapErr};
 use futures::future::{ready, BoxFuture};
 use twilight_model::{
@@ -23,7 +20,11 @@ use super::render::CachedRender;
 use crate::{
     active::{ActiveMessages, BuildPage, ComponentResult, IActiveMessage},
     commands::osu::{OngoingRender, RenderStatus, RenderStatusInner, RENDERER_NAME},
-    core::{buckets::BucketName, Context},
+    core::{
+        buckets::BucketName,
+        locale::{self, ErrorKey},
+        Context,
+    },
     manager::{OwnedReplayScore, ReplayScore},
     util::{interaction::InteractionComponent, Authored, Emote, MessageExt},
 };
@@ -403,7 +404,9 @@ async fn handle_render_button(
             };
         }
         Err(err) => {
-            let embed = EmbedBuilder::new().color_red().description(GENERAL_ISSUE);
+            let language = locale::language(&ctx, Some(owner), guild, None).await;
+            let content = ErrorKey::General.text(language);
+            let embed = EmbedBuilder::new().color_red().description(content);
             let builder = MessageBuilder::new().embed(embed);
 
             if let Some(update_fut) = msg.update(&ctx, builder, permissions) {
@@ -417,7 +420,9 @@ async fn handle_render_button(
     let settings = match settings_res {
         Ok(settings) => settings,
         Err(err) => {
-            let embed = EmbedBuilder::new().color_red().description(GENERAL_ISSUE);
+            let language = locale::language(&ctx, Some(owner), guild, None).await;
+            let content = ErrorKey::General.text(language);
+            let embed = EmbedBuilder::new().color_red().description(content);
             let builder = MessageBuilder::new().embed(embed);
 
             if let Some(update_fut) = msg.update(&ctx, builder, permissions) {
@@ -455,7 +460,9 @@ async fn handle_render_button(
     let render = match render_fut.await {
         Ok(render) => render,
         Err(err) => {
-            let embed = EmbedBuilder::new().color_red().description(ORDR_ISSUE);
+            let language = locale::language(&ctx, Some(owner), guild, None).await;
+            let content = ErrorKey::Ordr.text(language);
+            let embed = EmbedBuilder::new().color_red().description(content);
             let builder = MessageBuilder::new().embed(embed);
 
             if let Some(update_fut) = msg.update(&ctx, builder, permissions) {
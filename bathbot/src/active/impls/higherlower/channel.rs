@@ -0,0 +1,452 @@
+use std::{cmp::Reverse, collections::HashMap, fmt::Write, mem, sync::Arc, time::Duration};
+
+use bathbot_model::HlVersion;
+use bathbot_util::{EmbedBuilder, IntHasher, MessageBuilder};
+use eyre::{Report, Result, WrapErr};
+use futures::future::BoxFuture;
+use rosu_v2::prelude::GameMode;
+use tokio::{
+    sync::oneshot::{self, Receiver, Sender},
+    time::timeout,
+};
+use twilight_model::{
+    channel::message::{
+        component::{ActionRow, Button, ButtonStyle},
+        embed::EmbedField,
+        Component,
+    },
+    id::{
+        marker::{ChannelMarker, MessageMarker, UserMarker},
+        Id,
+    },
+};
+
+use super::{state::HigherLowerState, HlGuess, HlMapAttr};
+use crate::{
+    active::{ActiveMessages, BuildPage, ComponentResult, IActiveMessage},
+    core::Context,
+    util::{interaction::InteractionComponent, Authored, ChannelExt, ComponentExt, MessageExt},
+};
+
+const VOTE_WINDOW: Duration = Duration::from_secs(20);
+const MAX_ROUNDS: u32 = 100;
+const PODIUM_LEN: usize = 10;
+
+type Votes = HashMap<Id<UserMarker>, HlGuess, IntHasher>;
+
+/// Higherlower game in which everyone in a channel votes on each round.
+///
+/// Players join by voting in the first round and are eliminated as soon as
+/// they vote wrong or don't vote at all.
+pub struct HigherLowerChannelGame {
+    state: HigherLowerState,
+    img_url_rx: Receiver<String>,
+}
+
+impl HigherLowerChannelGame {
+    pub async fn new_score_pp(ctx: &Context, mode: GameMode) -> Result<Self> {
+        let (state, img_url_rx) = HigherLowerState::start_score_pp(ctx, mode).await?;
+
+        Ok(Self { state, img_url_rx })
+    }
+
+    pub async fn new_farm_maps(ctx: &Context) -> Result<Self> {
+        let entries = ctx.redis().osutracker_counts().await?;
+
+        let (state, img_url_rx) = HigherLowerState::start_farm_maps(ctx, entries)
+            .await
+            .wrap_err("Failed to create farm maps game state")?;
+
+        Ok(Self { state, img_url_rx })
+    }
+
+    pub async fn new_map_attr(ctx: &Context, attr: HlMapAttr) -> Result<Self> {
+        let (state, img_url_rx) = HigherLowerState::start_map_attr(ctx, attr).await?;
+
+        Ok(Self { state, img_url_rx })
+    }
+
+    pub async fn new_player_rank(ctx: &Context, mode: GameMode) -> Result<Self> {
+        let (state, img_url_rx) = HigherLowerState::start_player_rank(ctx, mode).await?;
+
+        Ok(Self { state, img_url_rx })
+    }
+
+    /// Run the game in the background.
+    ///
+    /// The channel should have been added to
+    /// [`Context::higherlower_channels`] beforehand, it will be removed once
+    /// the game is over.
+    pub fn start(self, ctx: Arc<Context>, channel: Id<ChannelMarker>) {
+        tokio::spawn(async move {
+            if let Err(err) = self.run(&ctx, channel).await {
+                warn!(%channel, ?err, "Failed to run higherlower channel game");
+            }
+
+            ctx.higherlower_channels().pin().remove(&channel);
+        });
+    }
+
+    async fn run(mut self, ctx: &Arc<Context>, channel: Id<ChannelMarker>) -> Result<()> {
+        let version = self.state.version();
+
+        // Players that are still in the game and their score
+        let mut alive: HashMap<Id<UserMarker>, u32, IntHasher> = HashMap::default();
+
+        // Players that were eliminated and their score
+        let mut eliminated = Vec::new();
+
+        let mut round = 1;
+
+        loop {
+            let image = match (&mut self.img_url_rx).await {
+                Ok(url) => Some(url),
+                Err(err) => {
+                    warn!(?err, "Failed to receive image url");
+
+                    None
+                }
+            };
+
+            let footer = if round == 1 {
+                format!(
+                    "Round 1 • Vote within {}s to join the game",
+                    VOTE_WINDOW.as_secs()
+                )
+            } else {
+                format!(
+                    "Round {round} • {} player{} remaining • Vote within {}s",
+                    alive.len(),
+                    if alive.len() == 1 { "" } else { "s" },
+                    VOTE_WINDOW.as_secs()
+                )
+            };
+
+            let mut embed = self.state.to_embed(false).footer(footer);
+
+            if let Some(ref image) = image {
+                embed = embed.image(image);
+            }
+
+            let eligible = (round > 1).then(|| alive.keys().copied().collect());
+            let (tx, rx) = oneshot::channel();
+            let voting = HigherLowerVoting::new(embed, eligible, tx);
+
+            ActiveMessages::builder(voting)
+                .begin(Arc::clone(ctx), channel)
+                .await
+                .wrap_err("Failed to begin voting")?;
+
+            let votes = match timeout(VOTE_WINDOW * 2, rx).await {
+                Ok(Ok(votes)) => votes,
+                // Active messages were cleared, most likely due to a shutdown
+                Ok(Err(_)) => break,
+                Err(_) => bail!("Timed out while waiting for votes"),
+            };
+
+            if round == 1 {
+                if votes.is_empty() {
+                    let content = "Nobody voted, cancelling the game";
+                    channel.plain_message(ctx, content).await?;
+
+                    return Ok(());
+                }
+
+                alive.extend(votes.keys().map(|&user| (user, 0)));
+            }
+
+            let mut correct = Vec::new();
+            let mut wrong = Vec::new();
+
+            alive.retain(|&user, score: &mut u32| match votes.get(&user) {
+                Some(&guess) if self.state.check_guess(guess) => {
+                    *score += 1;
+                    correct.push(user);
+
+                    true
+                }
+                guess => {
+                    wrong.push((user, guess.is_none()));
+                    eliminated.push((user, *score));
+
+                    false
+                }
+            });
+
+            let mut value = String::new();
+
+            if !correct.is_empty() {
+                value.push_str("Correct: ");
+                write_users(&mut value, correct.iter().copied());
+            }
+
+            if !wrong.is_empty() {
+                if !value.is_empty() {
+                    value.push('\n');
+                }
+
+                value.push_str("Eliminated: ");
+
+                let mut iter = wrong.iter();
+
+                if let Some((user, no_vote)) = iter.next() {
+                    let _ = write!(value, "<@{user}>");
+
+                    if *no_vote {
+                        value.push_str(" (no vote)");
+                    }
+
+                    for (user, no_vote) in iter {
+                        let _ = write!(value, ", <@{user}>");
+
+                        if *no_vote {
+                            value.push_str(" (no vote)");
+                        }
+                    }
+                }
+            }
+
+            let mut embed = self.state.to_embed(true);
+
+            if let Some(image) = image {
+                embed = embed.image(image);
+            }
+
+            embed.push_field(EmbedField {
+                inline: false,
+                name: format!("Round {round} results"),
+                value,
+            });
+
+            channel
+                .create_message(ctx, MessageBuilder::new().embed(embed), None)
+                .await
+                .wrap_err("Failed to send round results")?;
+
+            if alive.is_empty() || round == MAX_ROUNDS {
+                break;
+            }
+
+            self.img_url_rx = self
+                .state
+                .next(Arc::clone(ctx), round)
+                .await
+                .wrap_err("Failed to get next round")?;
+
+            round += 1;
+        }
+
+        let mut standings: Vec<_> = alive.into_iter().chain(eliminated).collect();
+
+        if standings.is_empty() {
+            return Ok(());
+        }
+
+        // Stable sort so that survivors stay in front of eliminated players on ties
+        standings.sort_by_key(|(_, score)| Reverse(*score));
+
+        for &(user, score) in standings.iter() {
+            let upsert_fut = ctx.games().upsert_higherlower_score(user, version, score);
+
+            if let Err(err) = upsert_fut.await {
+                warn!(?err, "Failed to record higherlower channel score");
+            }
+        }
+
+        let embed = podium_embed(&standings, version, round);
+
+        channel
+            .create_message(ctx, MessageBuilder::new().embed(embed), None)
+            .await
+            .wrap_err("Failed to send podium")?;
+
+        Ok(())
+    }
+}
+
+fn write_users(content: &mut String, mut users: impl Iterator<Item = Id<UserMarker>>) {
+    if let Some(user) = users.next() {
+        let _ = write!(content, "<@{user}>");
+
+        for user in users {
+            let _ = write!(content, ", <@{user}>");
+        }
+    }
+}
+
+fn podium_embed(
+    standings: &[(Id<UserMarker>, u32)],
+    version: HlVersion,
+    rounds: u32,
+) -> EmbedBuilder {
+    let mut description = String::new();
+
+    for (i, (user, score)) in standings.iter().enumerate().take(PODIUM_LEN) {
+        let place = match i {
+            0 => "🥇".to_owned(),
+            1 => "🥈".to_owned(),
+            2 => "🥉".to_owned(),
+            _ => format!("**#{}**", i + 1),
+        };
+
+        let _ = writeln!(
+            description,
+            "{place} <@{user}>: {score} correct guess{}",
+            if *score == 1 { "" } else { "es" }
+        );
+    }
+
+    if standings.len() > PODIUM_LEN {
+        let _ = write!(description, "...and {} more", standings.len() - PODIUM_LEN);
+    }
+
+    let title = match version {
+        HlVersion::ScorePp => "Higher or Lower: Score PP",
+        HlVersion::FarmMaps => "Higher or Lower: Farm maps",
+        HlVersion::StarRating => "Higher or Lower: Star rating",
+        HlVersion::Bpm => "Higher or Lower: BPM",
+        HlVersion::Playcount => "Higher or Lower: Mapset playcount",
+        HlVersion::Rank => "Higher or Lower: Player rank",
+    };
+
+    EmbedBuilder::new()
+        .title(format!("{title} • Final standings"))
+        .description(description)
+        .footer(format!(
+            "The game lasted {rounds} round{}",
+            if rounds == 1 { "" } else { "s" }
+        ))
+}
+
+/// Round of a [`HigherLowerChannelGame`] during which players can vote.
+pub struct HigherLowerVoting {
+    embed: EmbedBuilder,
+    /// `None` if anyone may vote
+    eligible: Option<Vec<Id<UserMarker>>>,
+    votes: Votes,
+    votes_tx: Option<Sender<Votes>>,
+}
+
+impl HigherLowerVoting {
+    fn new(
+        embed: EmbedBuilder,
+        eligible: Option<Vec<Id<UserMarker>>>,
+        votes_tx: Sender<Votes>,
+    ) -> Self {
+        Self {
+            embed,
+            eligible,
+            votes: Votes::default(),
+            votes_tx: Some(votes_tx),
+        }
+    }
+
+    async fn handle_vote(
+        &mut self,
+        ctx: Arc<Context>,
+        component: &InteractionComponent,
+        guess: HlGuess,
+    ) -> ComponentResult {
+        let user_id = match component.user_id() {
+            Ok(user_id) => user_id,
+            Err(err) => return ComponentResult::Err(err),
+        };
+
+        if let Some(ref eligible) = self.eligible {
+            if !eligible.contains(&user_id) {
+                return ComponentResult::Ignore;
+            }
+        }
+
+        self.votes.insert(user_id, guess);
+
+        let len = self.votes.len();
+        let content = format!("{len} vote{} so far", if len == 1 { "" } else { "s" });
+
+        // Only update the content so the timeout is not reset
+        let builder = MessageBuilder::new().content(content);
+
+        if let Err(err) = component.callback(&ctx, builder).await {
+            return ComponentResult::Err(Report::new(err).wrap_err("Failed to callback vote"));
+        }
+
+        ComponentResult::Ignore
+    }
+}
+
+impl IActiveMessage for HigherLowerVoting {
+    fn build_page(&mut self, _: Arc<Context>) -> BoxFuture<'_, Result<BuildPage>> {
+        BuildPage::new(mem::take(&mut self.embed), false).boxed()
+    }
+
+    fn build_components(&self) -> Vec<Component> {
+        let higher = Button {
+            custom_id: Some("hl_vote_higher".to_owned()),
+            disabled: false,
+            emoji: None,
+            label: Some("Higher".to_owned()),
+            style: ButtonStyle::Success,
+            url: None,
+        };
+
+        let lower = Button {
+            custom_id: Some("hl_vote_lower".to_owned()),
+            disabled: false,
+            emoji: None,
+            label: Some("Lower".to_owned()),
+            style: ButtonStyle::Danger,
+            url: None,
+        };
+
+        let components = vec![Component::Button(higher), Component::Button(lower)];
+
+        vec![Component::ActionRow(ActionRow { components })]
+    }
+
+    fn handle_component<'a>(
+        &'a mut self,
+        ctx: Arc<Context>,
+        component: &'a mut InteractionComponent,
+    ) -> BoxFuture<'a, ComponentResult> {
+        match component.data.custom_id.as_str() {
+            "hl_vote_higher" => Box::pin(self.handle_vote(ctx, component, HlGuess::Higher)),
+            "hl_vote_lower" => Box::pin(self.handle_vote(ctx, component, HlGuess::Lower)),
+            other => {
+                warn!(name = %other, ?component, "Unknown higherlower voting component");
+
+                ComponentResult::Ignore.boxed()
+            }
+        }
+    }
+
+    fn on_timeout<'a>(
+        &'a mut self,
+        ctx: &'a Context,
+        msg: Id<MessageMarker>,
+        channel: Id<ChannelMarker>,
+    ) -> BoxFuture<'a, Result<()>> {
+        if let Some(tx) = self.votes_tx.take() {
+            let _ = tx.send(mem::take(&mut self.votes));
+        }
+
+        let fut = async move {
+            let builder = MessageBuilder::new()
+                .content("Voting closed")
+                .components(Vec::new());
+
+            match (msg, channel).update(ctx, builder, None) {
+                Some(update_fut) => update_fut
+                    .await
+                    .map(|_| ())
+                    .wrap_err("Failed to remove components"),
+                None => Err(eyre!("Lacking permission to update message on timeout")),
+            }
+        };
+
+        Box::pin(fut)
+    }
+
+    fn until_timeout(&self) -> Option<Duration> {
+        Some(VOTE_WINDOW)
+    }
+}
@@ -0,0 +1,310 @@
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+use bathbot_model::HlVersion;
+use bathbot_psql::model::games::DbHlMap;
+use bathbot_util::{
+    constants::OSU_BASE,
+    datetime::SecToMinSec,
+    numbers::{round, WithComma},
+    EmbedBuilder,
+};
+use eyre::{ContextCompat, Result, WrapErr};
+use image::{GenericImageView, ImageBuffer};
+use rand::Rng;
+
+use super::state::{mapset_cover, HigherLowerState, H, W};
+use crate::core::Context;
+
+/// Amount of maps that are fetched from the database at once
+pub(super) const POOL_SIZE: usize = 1000;
+
+/// Which map attribute is being compared
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub enum HlMapAttr {
+    Stars,
+    Bpm,
+    Playcount,
+}
+
+pub(super) struct HlMap {
+    pub mapset_id: u32,
+    map_id: u32,
+    map_string: Box<str>,
+    seconds_drain: u32,
+    combo: u32,
+    cs: f32,
+    ar: f32,
+    od: f32,
+    hp: f32,
+    stars: f32,
+    bpm: f32,
+    playcount: u32,
+}
+
+impl HlMap {
+    /// Pick a map from the pool and remove it so it won't appear again.
+    ///
+    /// The higher the current score, the closer the star rating or BPM will be
+    /// to the previous map's.
+    pub(super) async fn random(
+        ctx: &Context,
+        attr: HlMapAttr,
+        pool: &mut Vec<DbHlMap>,
+        prev: Option<&Self>,
+        curr_score: u32,
+    ) -> Result<Self> {
+        if pool.len() < 2 {
+            *pool = map_pool(ctx).await.wrap_err("Failed to refill map pool")?;
+        }
+
+        match (attr, prev) {
+            (HlMapAttr::Stars, Some(prev)) => {
+                let prev = prev.stars as f64;
+                pool.sort_unstable_by(|a, b| {
+                    (a.stars - prev).abs().total_cmp(&(b.stars - prev).abs())
+                });
+            }
+            (HlMapAttr::Bpm, Some(prev)) => {
+                let prev = prev.bpm;
+                pool.sort_unstable_by(|a, b| (a.bpm - prev).abs().total_cmp(&(b.bpm - prev).abs()));
+            }
+            // The playcount is only known after requesting the mapset
+            (HlMapAttr::Playcount, _) | (_, None) => {}
+        }
+
+        let idx = {
+            let mut rng = rand::thread_rng();
+
+            let max_idx = match (attr, prev) {
+                (HlMapAttr::Playcount, _) | (_, None) => pool.len(),
+                _ => (pool.len() / (curr_score as usize + 1))
+                    .max(8)
+                    .min(pool.len()),
+            };
+
+            rng.gen_range(0..max_idx)
+        };
+
+        let entry = pool.swap_remove(idx);
+
+        let playcount = if attr == HlMapAttr::Playcount {
+            ctx.osu()
+                .beatmapset(entry.mapset_id as u32)
+                .await
+                .wrap_err("Failed to get mapset")?
+                .playcount
+        } else {
+            0
+        };
+
+        Ok(Self::new(entry, playcount))
+    }
+
+    pub(super) async fn image(ctx: &Context, mapset_id1: u32, mapset_id2: u32) -> Result<String> {
+        let cover1 = mapset_cover(mapset_id1);
+        let cover2 = mapset_cover(mapset_id2);
+
+        // Gather the map covers
+        let client = ctx.client();
+
+        let (bg_left, bg_right) = tokio::try_join!(
+            client.get_mapset_cover(&cover1),
+            client.get_mapset_cover(&cover2),
+        )
+        .wrap_err("Failed to get mapset cover")?;
+
+        let bg_left =
+            image::load_from_memory(&bg_left).wrap_err("Failed to load left bg from memory")?;
+
+        let bg_right =
+            image::load_from_memory(&bg_right).wrap_err("Failed to load right bg from memory")?;
+
+        // Combine the images
+        let mut blipped = ImageBuffer::new(W, H);
+
+        let iter = blipped
+            .enumerate_pixels_mut()
+            .zip(bg_left.pixels())
+            .zip(bg_right.pixels());
+
+        for (((x, _, pixel), (.., left)), (.., right)) in iter {
+            *pixel = if x <= W / 2 { left } else { right };
+        }
+
+        let content = format!("{mapset_id1} ~ {mapset_id2}");
+
+        HigherLowerState::upload_image(ctx, blipped.as_raw(), content).await
+    }
+
+    pub(super) fn log(attr: HlMapAttr, game1: &Self, game2: &Self) {
+        match attr {
+            HlMapAttr::Stars => debug!("stars: {} vs {}", game1.stars, game2.stars),
+            HlMapAttr::Bpm => debug!("bpm: {} vs {}", game1.bpm, game2.bpm),
+            HlMapAttr::Playcount => debug!("playcount: {} vs {}", game1.playcount, game2.playcount),
+        }
+    }
+
+    pub(super) fn to_embed(
+        attr: HlMapAttr,
+        previous: &Self,
+        next: &Self,
+        revealed: bool,
+    ) -> EmbedBuilder {
+        let description = format!(
+            "**__Previous:__ {prev}\n\
+            **__Next:__ {next}",
+            prev = MapFormatter::new(previous, attr, true),
+            next = MapFormatter::new(next, attr, revealed),
+        );
+
+        EmbedBuilder::new().description(description)
+    }
+
+    /// The value that is being compared.
+    pub(super) fn value(&self, attr: HlMapAttr) -> f32 {
+        match attr {
+            HlMapAttr::Stars => self.stars,
+            HlMapAttr::Bpm => self.bpm,
+            HlMapAttr::Playcount => self.playcount as f32,
+        }
+    }
+
+    fn new(entry: DbHlMap, playcount: u32) -> Self {
+        let DbHlMap {
+            map_id,
+            mapset_id,
+            artist,
+            title,
+            map_version,
+            seconds_drain,
+            bpm,
+            cs,
+            ar,
+            od,
+            hp,
+            stars,
+            max_combo,
+        } = entry;
+
+        Self {
+            mapset_id: mapset_id as u32,
+            map_id: map_id as u32,
+            map_string: format!("{artist} - {title} [{map_version}]").into_boxed_str(),
+            seconds_drain: seconds_drain as u32,
+            combo: max_combo as u32,
+            cs: round(cs),
+            ar: round(ar),
+            od: round(od),
+            hp: round(hp),
+            stars: round(stars as f32),
+            bpm: round(bpm),
+            playcount,
+        }
+    }
+}
+
+impl PartialEq for HlMap {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.map_id == other.map_id
+    }
+}
+
+struct MapFormatter<'m> {
+    map: &'m HlMap,
+    attr: HlMapAttr,
+    revealed: bool,
+}
+
+impl<'m> MapFormatter<'m> {
+    fn new(map: &'m HlMap, attr: HlMapAttr, revealed: bool) -> Self {
+        Self {
+            map,
+            attr,
+            revealed,
+        }
+    }
+}
+
+impl Display for MapFormatter<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let Self {
+            map,
+            attr,
+            revealed,
+        } = self;
+
+        let hidden = |compared: HlMapAttr| *attr == compared && !revealed;
+
+        writeln!(
+            f,
+            "[{map_string}]({OSU_BASE}b/{map_id})**",
+            map_string = map.map_string,
+            map_id = map.map_id,
+        )?;
+
+        if hidden(HlMapAttr::Stars) {
+            f.write_str("`???★`")?;
+        } else {
+            write!(f, "`{}★`", map.stars)?;
+        }
+
+        write!(
+            f,
+            " • `{len}` • `{combo}x` • ",
+            len = SecToMinSec::new(map.seconds_drain),
+            combo = map.combo,
+        )?;
+
+        if hidden(HlMapAttr::Bpm) {
+            f.write_str("**??? BPM**")?;
+        } else {
+            write!(f, "**{} BPM**", map.bpm)?;
+        }
+
+        write!(
+            f,
+            "\n`CS {}` `AR {}` `OD {}` `HP {}`",
+            map.cs, map.ar, map.od, map.hp
+        )?;
+
+        match attr {
+            HlMapAttr::Playcount if *revealed => {
+                write!(f, " • **{}** plays", WithComma::new(map.playcount))
+            }
+            HlMapAttr::Playcount => f.write_str(" • **???** plays"),
+            HlMapAttr::Stars | HlMapAttr::Bpm => Ok(()),
+        }
+    }
+}
+
+impl HlMapAttr {
+    pub(super) fn title(self) -> &'static str {
+        match self {
+            Self::Stars => "Star rating",
+            Self::Bpm => "BPM",
+            Self::Playcount => "Mapset playcount",
+        }
+    }
+
+    pub(super) fn version(self) -> HlVersion {
+        match self {
+            Self::Stars => HlVersion::StarRating,
+            Self::Bpm => HlVersion::Bpm,
+            Self::Playcount => HlVersion::Playcount,
+        }
+    }
+}
+
+/// Fill the pool and make sure it contains enough maps to start a game.
+pub(super) async fn map_pool(ctx: &Context) -> Result<Vec<DbHlMap>> {
+    let pool = ctx
+        .games()
+        .higherlower_maps(POOL_SIZE)
+        .await
+        .wrap_err("Failed to get map pool")?;
+
+    (pool.len() >= 2)
+        .then_some(pool)
+        .wrap_err("Not enough maps stored for a game")
+}
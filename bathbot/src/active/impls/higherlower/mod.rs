@@ -4,7 +4,7 @@ use std::{
     time::Duration,
 };
 
-use bathbot_model::HlVersion;
+use bathbot_model::{ArchivedHlGameSnapshot, HlGameSnapshot, HlVersion};
 use bathbot_util::{AuthorBuilder, EmbedBuilder, MessageBuilder};
 use eyre::{Result, WrapErr};
 use futures::future::BoxFuture;
@@ -23,6 +23,11 @@ use twilight_model::{
     },
 };
 
+pub use self::{
+    channel::{HigherLowerChannelGame, HigherLowerVoting},
+    map_attrs::HlMapAttr,
+};
+pub(super) use self::state::mapset_cover;
 use self::state::{ButtonState, HigherLowerState};
 use crate::{
     active::{BuildPage, ComponentResult, IActiveMessage},
@@ -30,7 +35,10 @@ use crate::{
     util::{interaction::InteractionComponent, Authored, ComponentExt, Emote, MessageExt},
 };
 
+mod channel;
 mod farm_maps;
+mod map_attrs;
+mod player_rank;
 mod score_pp;
 mod state;
 
@@ -42,6 +50,7 @@ pub struct HigherLowerGame {
     highscore: u32,
     buttons: ButtonState,
     msg_owner: Id<UserMarker>,
+    channel: Id<ChannelMarker>,
 }
 
 impl IActiveMessage for HigherLowerGame {
@@ -114,6 +123,7 @@ impl HigherLowerGame {
         ctx: &Context,
         mode: GameMode,
         msg_owner: Id<UserMarker>,
+        channel: Id<ChannelMarker>,
     ) -> Result<Self> {
         let game_fut = HigherLowerState::start_score_pp(ctx, mode);
         let highscore_fut = ctx
@@ -130,10 +140,15 @@ impl HigherLowerGame {
             highscore,
             buttons: ButtonState::HigherLower,
             msg_owner,
+            channel,
         })
     }
 
-    pub async fn new_farm_maps(ctx: &Context, msg_owner: Id<UserMarker>) -> Result<Self> {
+    pub async fn new_farm_maps(
+        ctx: &Context,
+        msg_owner: Id<UserMarker>,
+        channel: Id<ChannelMarker>,
+    ) -> Result<Self> {
         let entries_fut = ctx.redis().osutracker_counts();
         let highscore_fut = ctx
             .games()
@@ -154,6 +169,100 @@ impl HigherLowerGame {
             highscore,
             buttons: ButtonState::HigherLower,
             msg_owner,
+            channel,
+        })
+    }
+
+    pub async fn new_map_attr(
+        ctx: &Context,
+        attr: HlMapAttr,
+        msg_owner: Id<UserMarker>,
+        channel: Id<ChannelMarker>,
+    ) -> Result<Self> {
+        let game_fut = HigherLowerState::start_map_attr(ctx, attr);
+        let highscore_fut = ctx.games().higherlower_highscore(msg_owner, attr.version());
+
+        let ((state, rx), highscore) = tokio::try_join!(game_fut, highscore_fut)?;
+
+        Ok(Self {
+            state,
+            revealed: false,
+            img_url_rx: Some(rx),
+            current_score: 0,
+            highscore,
+            buttons: ButtonState::HigherLower,
+            msg_owner,
+            channel,
+        })
+    }
+
+    pub async fn new_player_rank(
+        ctx: &Context,
+        mode: GameMode,
+        msg_owner: Id<UserMarker>,
+        channel: Id<ChannelMarker>,
+    ) -> Result<Self> {
+        let game_fut = HigherLowerState::start_player_rank(ctx, mode);
+        let highscore_fut = ctx
+            .games()
+            .higherlower_highscore(msg_owner, HlVersion::Rank);
+
+        let ((state, rx), highscore) = tokio::try_join!(game_fut, highscore_fut)?;
+
+        Ok(Self {
+            state,
+            revealed: false,
+            img_url_rx: Some(rx),
+            current_score: 0,
+            highscore,
+            buttons: ButtonState::HigherLower,
+            msg_owner,
+            channel,
+        })
+    }
+
+    /// Continue a game that was snapshotted before a restart.
+    ///
+    /// The previous pair is not stored so a new one is generated but the
+    /// current score is kept.
+    pub async fn resume(ctx: &Context, snapshot: &ArchivedHlGameSnapshot) -> Result<Self> {
+        let (Some(msg_owner), Some(channel)) = (
+            Id::new_checked(snapshot.user),
+            Id::new_checked(snapshot.channel),
+        ) else {
+            bail!("Invalid user or channel id");
+        };
+
+        let mode = GameMode::from(snapshot.mode);
+
+        let mut game = match snapshot.version {
+            0 => Self::new_score_pp(ctx, mode, msg_owner, channel).await?,
+            1 => Self::new_farm_maps(ctx, msg_owner, channel).await?,
+            2 => Self::new_map_attr(ctx, HlMapAttr::Stars, msg_owner, channel).await?,
+            3 => Self::new_map_attr(ctx, HlMapAttr::Bpm, msg_owner, channel).await?,
+            4 => Self::new_map_attr(ctx, HlMapAttr::Playcount, msg_owner, channel).await?,
+            5 => Self::new_player_rank(ctx, mode, msg_owner, channel).await?,
+            other => bail!("Unknown higherlower version {other}"),
+        };
+
+        game.current_score = snapshot.score;
+
+        Ok(game)
+    }
+
+    /// Returns `None` if the game is already over.
+    pub fn snapshot(&self, msg: Id<MessageMarker>) -> Option<HlGameSnapshot> {
+        if let ButtonState::TryAgain { .. } = self.buttons {
+            return None;
+        }
+
+        Some(HlGameSnapshot {
+            channel: self.channel.get(),
+            msg: msg.get(),
+            user: self.msg_owner.get(),
+            version: self.state.version() as u8,
+            mode: self.state.mode() as u8,
+            score: self.current_score,
         })
     }
 
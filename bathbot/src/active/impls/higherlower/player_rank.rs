@@ -0,0 +1,211 @@
+use bathbot_util::{
+    numbers::{round, WithComma},
+    EmbedBuilder,
+};
+use eyre::{ContextCompat, Result, WrapErr};
+use image::{
+    imageops::{self, FilterType},
+    GenericImageView, ImageBuffer, Rgba,
+};
+use rand::Rng;
+use rosu_v2::prelude::GameMode;
+use twilight_model::channel::message::embed::EmbedField;
+
+use super::state::{HigherLowerState, H, W};
+use crate::{core::Context, manager::redis::RedisData};
+
+const BACKGROUND: Rgba<u8> = Rgba([38, 38, 38, 255]);
+
+pub(super) struct PlayerRank {
+    pub rank: u32,
+    pub avatar_url: Box<str>,
+    user_id: u32,
+    player_string: Box<str>,
+    pp: f32,
+    acc: f32,
+    playcount: u32,
+}
+
+impl PlayerRank {
+    /// Pick a random player from the cached pp rankings.
+    ///
+    /// The higher the current score, the closer the rank will be to the
+    /// previous player's.
+    pub(super) async fn random(
+        ctx: &Context,
+        mode: GameMode,
+        prev: Option<&Self>,
+        curr_score: u32,
+    ) -> Result<Self> {
+        let max_rank = 5000 - (mode != GameMode::Osu) as u32 * 1000;
+
+        let rank = {
+            let mut rng = rand::thread_rng();
+
+            match prev {
+                Some(prev) => {
+                    let spread = (max_rank >> curr_score.min(8)).max(25);
+                    let min = prev.rank.saturating_sub(spread).max(1);
+                    let max = (prev.rank + spread).min(max_rank);
+
+                    loop {
+                        let rank = rng.gen_range(min..=max);
+
+                        if rank != prev.rank {
+                            break rank;
+                        }
+                    }
+                }
+                None => rng.gen_range(1..=max_rank),
+            }
+        };
+
+        let page = ((rank - 1) / 50) + 1;
+        let idx = ((rank - 1) % 50) as usize;
+
+        let ranking = ctx
+            .redis()
+            .pp_ranking(mode, page, None)
+            .await
+            .wrap_err("Failed to get cached pp ranking")?;
+
+        let player = match ranking {
+            RedisData::Original(mut ranking) => {
+                let user = (idx < ranking.ranking.len())
+                    .then(|| ranking.ranking.swap_remove(idx))
+                    .wrap_err("Missing user in ranking")?;
+
+                let stats = user.statistics.wrap_err("Missing user statistics")?;
+
+                Self {
+                    rank: stats.global_rank.unwrap_or(rank),
+                    avatar_url: user.avatar_url.into_boxed_str(),
+                    user_id: user.user_id,
+                    player_string: player_string(&user.country_code, &user.username),
+                    pp: stats.pp,
+                    acc: stats.accuracy,
+                    playcount: stats.playcount,
+                }
+            }
+            RedisData::Archive(ranking) => {
+                let user = ranking
+                    .ranking
+                    .get(idx)
+                    .wrap_err("Missing user in ranking")?;
+
+                let stats = user
+                    .statistics
+                    .as_ref()
+                    .wrap_err("Missing user statistics")?;
+
+                Self {
+                    rank: match stats.global_rank {
+                        0 => rank,
+                        global_rank => global_rank,
+                    },
+                    avatar_url: user.avatar_url.as_ref().into(),
+                    user_id: user.user_id,
+                    player_string: player_string(user.country_code.as_str(), &user.username),
+                    pp: stats.pp,
+                    acc: stats.accuracy,
+                    playcount: stats.playcount,
+                }
+            }
+        };
+
+        Ok(player)
+    }
+
+    /// Both avatars centered on their half of the image.
+    pub(super) async fn image(ctx: &Context, pfp1: &str, pfp2: &str) -> Result<String> {
+        let client = ctx.client();
+
+        let (pfp_left, pfp_right) =
+            tokio::try_join!(client.get_avatar(pfp1), client.get_avatar(pfp2))
+                .wrap_err("Failed to retrieve avatars")?;
+
+        let pfp_left = image::load_from_memory(&pfp_left)
+            .wrap_err("Failed to load pfp1 from memory")?
+            .resize(H, H, FilterType::Lanczos3);
+
+        let pfp_right = image::load_from_memory(&pfp_right)
+            .wrap_err("Failed to load pfp2 from memory")?
+            .resize(H, H, FilterType::Lanczos3);
+
+        let mut blipped = ImageBuffer::from_pixel(W, H, BACKGROUND);
+
+        let left_x = (W / 2).saturating_sub(pfp_left.width()) / 2;
+        let right_x = W / 2 + (W / 2).saturating_sub(pfp_right.width()) / 2;
+
+        imageops::overlay(&mut blipped, &pfp_left.to_rgba8(), left_x as i64, 0);
+        imageops::overlay(&mut blipped, &pfp_right.to_rgba8(), right_x as i64, 0);
+
+        const ID_START_IDX: usize = 17; // "https://a.ppy.sh/{user_id}?{hash}.png"
+
+        let content = format!(
+            "{user1} ~ {user2}",
+            user1 = pfp1
+                .find('?')
+                .and_then(|idx| pfp1.get(ID_START_IDX..idx))
+                .unwrap_or(pfp1),
+            user2 = pfp2
+                .find('?')
+                .and_then(|idx| pfp2.get(ID_START_IDX..idx))
+                .unwrap_or(pfp2),
+        );
+
+        HigherLowerState::upload_image(ctx, blipped.as_raw(), content).await
+    }
+
+    pub(super) fn log(game1: &Self, game2: &Self) {
+        debug!("rank: #{} vs #{}", game1.rank, game2.rank);
+    }
+
+    pub(super) fn to_embed(previous: &Self, next: &Self, revealed: bool) -> EmbedBuilder {
+        let fields = vec![
+            EmbedField {
+                inline: false,
+                name: format!("__Previous:__ {}", previous.player_string),
+                value: previous.stats_string(true),
+            },
+            EmbedField {
+                inline: false,
+                name: format!("__Next:__ {}", next.player_string),
+                value: next.stats_string(revealed),
+            },
+        ];
+
+        EmbedBuilder::new().fields(fields)
+    }
+
+    fn stats_string(&self, revealed: bool) -> String {
+        if revealed {
+            format!(
+                "**#{rank}** • {pp}pp • {acc}% • {playcount} plays",
+                rank = WithComma::new(self.rank),
+                pp = WithComma::new(round(self.pp)),
+                acc = round(self.acc),
+                playcount = WithComma::new(self.playcount),
+            )
+        } else {
+            format!(
+                "**#???** • ???pp • {acc}% • {playcount} plays",
+                acc = round(self.acc),
+                playcount = WithComma::new(self.playcount),
+            )
+        }
+    }
+}
+
+fn player_string(country_code: &str, username: &str) -> Box<str> {
+    let country_code = country_code.to_lowercase();
+
+    format!(":flag_{country_code}: {username}").into_boxed_str()
+}
+
+impl PartialEq for PlayerRank {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.user_id == other.user_id
+    }
+}
@@ -1,6 +1,7 @@
 use std::{mem, sync::Arc};
 
 use bathbot_model::HlVersion;
+use bathbot_psql::model::games::DbHlMap;
 use bathbot_util::{EmbedBuilder, MessageBuilder};
 use eyre::{ContextCompat, Result, WrapErr};
 use image::{codecs::png::PngEncoder, ColorType, ImageEncoder};
@@ -9,6 +10,8 @@ use tokio::sync::oneshot::{self, Receiver};
 
 use super::{
     farm_maps::{FarmEntries, FarmMap},
+    map_attrs::{map_pool, HlMap, HlMapAttr},
+    player_rank::PlayerRank,
     score_pp::ScorePp,
     HlGuess,
 };
@@ -45,6 +48,17 @@ pub(super) enum HigherLowerState {
         previous: FarmMap,
         next: FarmMap,
     },
+    MapAttr {
+        attr: HlMapAttr,
+        pool: Vec<DbHlMap>,
+        previous: HlMap,
+        next: HlMap,
+    },
+    PlayerRank {
+        mode: GameMode,
+        previous: PlayerRank,
+        next: PlayerRank,
+    },
 }
 
 impl HigherLowerState {
@@ -130,10 +144,93 @@ impl HigherLowerState {
         Ok((inner, rx))
     }
 
+    pub(super) async fn start_map_attr(
+        ctx: &Context,
+        attr: HlMapAttr,
+    ) -> Result<(Self, Receiver<String>)> {
+        let mut pool = map_pool(ctx).await?;
+
+        let previous = HlMap::random(ctx, attr, &mut pool, None, 0)
+            .await
+            .wrap_err("Failed to create map entry")?;
+
+        let next = HlMap::random(ctx, attr, &mut pool, Some(&previous), 0)
+            .await
+            .wrap_err("Failed to create map entry")?;
+
+        HlMap::log(attr, &previous, &next);
+
+        let (tx, rx) = oneshot::channel();
+
+        let url = match HlMap::image(ctx, previous.mapset_id, next.mapset_id).await {
+            Ok(url) => url,
+            Err(err) => {
+                warn!(?err, "Failed to create image");
+
+                String::new()
+            }
+        };
+
+        let _ = tx.send(url);
+
+        let inner = Self::MapAttr {
+            attr,
+            pool,
+            previous,
+            next,
+        };
+
+        Ok((inner, rx))
+    }
+
+    pub(super) async fn start_player_rank(
+        ctx: &Context,
+        mode: GameMode,
+    ) -> Result<(Self, Receiver<String>)> {
+        let previous = PlayerRank::random(ctx, mode, None, 0)
+            .await
+            .wrap_err("Failed to create player rank entry")?;
+
+        let mut next = PlayerRank::random(ctx, mode, Some(&previous), 0)
+            .await
+            .wrap_err("Failed to create player rank entry")?;
+
+        while next == previous {
+            next = PlayerRank::random(ctx, mode, Some(&previous), 0)
+                .await
+                .wrap_err("Failed to create player rank entry")?;
+        }
+
+        PlayerRank::log(&previous, &next);
+
+        let (tx, rx) = oneshot::channel();
+
+        let url = match PlayerRank::image(ctx, &previous.avatar_url, &next.avatar_url).await {
+            Ok(url) => url,
+            Err(err) => {
+                warn!(?err, "Failed to create image");
+
+                String::new()
+            }
+        };
+
+        let _ = tx.send(url);
+
+        let inner = Self::PlayerRank {
+            mode,
+            previous,
+            next,
+        };
+
+        Ok((inner, rx))
+    }
+
     pub(super) async fn restart(&mut self, ctx: &Context) -> Result<(Self, Receiver<String>)> {
         match self {
             Self::ScorePp { mode, .. } => Self::start_score_pp(ctx, *mode).await,
             Self::FarmMaps { entries, .. } => Self::start_farm_maps(ctx, entries.to_owned()).await,
+            Self::MapAttr { attr, .. } => Self::start_map_attr(ctx, *attr).await,
+            Self::PlayerRank { mode, .. } => Self::start_player_rank(ctx, *mode).await,
         }
     }
 
@@ -221,6 +318,92 @@ impl HigherLowerState {
                     let _ = tx.send(url);
                 });
 
+                rx
+            }
+            Self::MapAttr {
+                attr,
+                pool,
+                previous,
+                next,
+            } => {
+                let attr = *attr;
+                mem::swap(previous, next);
+
+                *next = HlMap::random(&ctx, attr, pool, Some(&*previous), curr_score)
+                    .await
+                    .wrap_err("Failed to create map entry")?;
+
+                // The pool might have been refilled with the previous map
+                while previous == next {
+                    *next = HlMap::random(&ctx, attr, pool, Some(&*previous), curr_score)
+                        .await
+                        .wrap_err("Failed to create map entry")?;
+                }
+
+                HlMap::log(attr, &*previous, &*next);
+
+                let mapset1 = previous.mapset_id;
+                let mapset2 = next.mapset_id;
+
+                let (tx, rx) = oneshot::channel();
+
+                // Create the image in the background so it's available when needed later
+                tokio::spawn(async move {
+                    let url = match HlMap::image(&ctx, mapset1, mapset2).await {
+                        Ok(url) => url,
+                        Err(err) => {
+                            warn!(?err, "Failed to create image");
+
+                            String::new()
+                        }
+                    };
+
+                    let _ = tx.send(url);
+                });
+
+                rx
+            }
+            Self::PlayerRank {
+                mode,
+                previous,
+                next,
+            } => {
+                let mode = *mode;
+                mem::swap(previous, next);
+
+                *next = PlayerRank::random(&ctx, mode, Some(&*previous), curr_score)
+                    .await
+                    .wrap_err("Failed to create player rank entry")?;
+
+                while previous == next {
+                    *next = PlayerRank::random(&ctx, mode, Some(&*previous), curr_score)
+                        .await
+                        .wrap_err("Failed to create player rank entry")?;
+                }
+
+                PlayerRank::log(&*previous, &*next);
+
+                let pfp1 = mem::take(&mut previous.avatar_url);
+
+                // Clone this since it's needed in the next round
+                let pfp2 = next.avatar_url.clone();
+
+                let (tx, rx) = oneshot::channel();
+
+                // Create the image in the background so it's available when needed later
+                tokio::spawn(async move {
+                    let url = match PlayerRank::image(&ctx, &pfp1, &pfp2).await {
+                        Ok(url) => url,
+                        Err(err) => {
+                            warn!(?err, "Failed to create image");
+
+                            String::new()
+                        }
+                    };
+
+                    let _ = tx.send(url);
+                });
+
                 rx
             }
         };
@@ -284,6 +467,32 @@ impl HigherLowerState {
 
                 FarmMap::to_embed(previous, next, revealed)
             }
+            HigherLowerState::MapAttr {
+                attr,
+                pool: _,
+                previous,
+                next,
+            } => {
+                title.push_str(attr.title());
+
+                HlMap::to_embed(*attr, previous, next, revealed)
+            }
+            HigherLowerState::PlayerRank {
+                mode,
+                previous,
+                next,
+            } => {
+                title.push_str("Player rank");
+
+                match mode {
+                    GameMode::Osu => {}
+                    GameMode::Taiko => title.push_str(" (taiko)"),
+                    GameMode::Catch => title.push_str(" (ctb)"),
+                    GameMode::Mania => title.push_str(" (mania)"),
+                }
+
+                PlayerRank::to_embed(previous, next, revealed)
+            }
         };
 
         builder.title(title)
@@ -299,6 +508,19 @@ impl HigherLowerState {
                 HlGuess::Higher => next.farm >= previous.farm,
                 HlGuess::Lower => next.farm <= previous.farm,
             },
+            Self::MapAttr {
+                attr,
+                previous,
+                next,
+                ..
+            } => match guess {
+                HlGuess::Higher => next.value(*attr) >= previous.value(*attr),
+                HlGuess::Lower => next.value(*attr) <= previous.value(*attr),
+            },
+            Self::PlayerRank { previous, next, .. } => match guess {
+                HlGuess::Higher => next.rank >= previous.rank,
+                HlGuess::Lower => next.rank <= previous.rank,
+            },
         }
     }
 
@@ -306,10 +528,19 @@ impl HigherLowerState {
         match self {
             Self::ScorePp { .. } => HlVersion::ScorePp,
             Self::FarmMaps { .. } => HlVersion::FarmMaps,
+            Self::MapAttr { attr, .. } => attr.version(),
+            Self::PlayerRank { .. } => HlVersion::Rank,
+        }
+    }
+
+    pub(super) fn mode(&self) -> GameMode {
+        match self {
+            Self::ScorePp { mode, .. } | Self::PlayerRank { mode, .. } => *mode,
+            Self::FarmMaps { .. } | Self::MapAttr { .. } => GameMode::Osu,
         }
     }
 }
 
-pub(super) fn mapset_cover(mapset_id: u32) -> String {
+pub(crate) fn mapset_cover(mapset_id: u32) -> String {
     format!("https://assets.ppy.sh/beatmaps/{mapset_id}/covers/cover.jpg")
 }
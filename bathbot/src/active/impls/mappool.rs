@@ -0,0 +1,121 @@
+use std::{fmt::Write, sync::Arc};
+
+use bathbot_macros::PaginationBuilder;
+use bathbot_util::{
+    constants::OSU_BASE, datetime::SecToMinSec, numbers::round, CowUtils, EmbedBuilder,
+    FooterBuilder,
+};
+use eyre::Result;
+use futures::future::BoxFuture;
+use rosu_v2::prelude::GameModsIntermode;
+use twilight_model::{
+    channel::message::Component,
+    id::{marker::UserMarker, Id},
+};
+
+use crate::{
+    active::{
+        pagination::{handle_pagination_component, handle_pagination_modal, Pages},
+        BuildPage, ComponentResult, IActiveMessage,
+    },
+    commands::osu::MappoolEntry,
+    core::Context,
+    util::interaction::{InteractionComponent, InteractionModal},
+};
+
+#[derive(PaginationBuilder)]
+pub struct MappoolPagination {
+    #[pagination(per_page = 8)]
+    entries: Box<[MappoolEntry]>,
+    name: Box<str>,
+    msg_owner: Id<UserMarker>,
+    pages: Pages,
+}
+
+impl IActiveMessage for MappoolPagination {
+    fn build_page(&mut self, _: Arc<Context>) -> BoxFuture<'_, Result<BuildPage>> {
+        let pages = &self.pages;
+        let idx = pages.index();
+
+        let limit = self.entries.len().min(idx + pages.per_page());
+        let entries = &self.entries[idx..limit];
+
+        let mut description = String::with_capacity(entries.len() * 160);
+
+        for entry in entries {
+            let mods = match entry.mods {
+                Some(0) => "NM".to_owned(),
+                Some(bits) => GameModsIntermode::from_bits(bits).to_string(),
+                None => "FM".to_owned(),
+            };
+
+            let Some(ref map) = entry.map else {
+                let _ = writeln!(
+                    description,
+                    "**{slot}** • [Unavailable map]({OSU_BASE}b/{map_id}) `{mods}`",
+                    slot = entry.slot,
+                    map_id = entry.map_id,
+                );
+
+                continue;
+            };
+
+            let _ = writeln!(
+                description,
+                "**{slot}** • [{artist} - {title} [{version}]]({OSU_BASE}b/{map_id}) `{mods}`\n\
+                ⤷ `{stars:.2}★` • `{pp}pp` • `{len}` • `{bpm} BPM`",
+                slot = entry.slot,
+                artist = map.artist.cow_escape_markdown(),
+                title = map.title.cow_escape_markdown(),
+                version = map.version.cow_escape_markdown(),
+                map_id = entry.map_id,
+                stars = map.stars,
+                pp = round(map.pp),
+                len = SecToMinSec::new(map.seconds_drain),
+                bpm = round(map.bpm),
+            );
+        }
+
+        if self.entries.is_empty() {
+            description.push_str(
+                "This mappool does not contain any maps yet.\n\
+                Maps can be added via `/mappool add`.",
+            );
+        }
+
+        let page = pages.curr_page();
+        let pages = pages.last_page();
+
+        let footer = FooterBuilder::new(format!(
+            "Page {page}/{pages} • {} slots • Freemod slots are shown without mods",
+            self.entries.len()
+        ));
+
+        let embed = EmbedBuilder::new()
+            .description(description)
+            .footer(footer)
+            .title(format!("Mappool `{}`", self.name));
+
+        BuildPage::new(embed, false).boxed()
+    }
+
+    fn build_components(&self) -> Vec<Component> {
+        self.pages.components()
+    }
+
+    fn handle_component<'a>(
+        &'a mut self,
+        ctx: Arc<Context>,
+        component: &'a mut InteractionComponent,
+    ) -> BoxFuture<'a, ComponentResult> {
+        handle_pagination_component(ctx, component, self.msg_owner, false, &mut self.pages)
+    }
+
+    fn handle_modal<'a>(
+        &'a mut self,
+        ctx: &'a Context,
+        modal: &'a mut InteractionModal,
+    ) -> BoxFuture<'a, Result<()>> {
+        handle_pagination_modal(ctx, modal, self.msg_owner, false, &mut self.pages)
+    }
+}
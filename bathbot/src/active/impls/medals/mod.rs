@@ -1,9 +1,10 @@
 pub use self::{
     common::MedalsCommonPagination, list::MedalsListPagination, missing::MedalsMissingPagination,
-    recent::MedalsRecentPagination,
+    planner::MedalsPlannerPagination, recent::MedalsRecentPagination,
 };
 
 mod common;
 mod list;
 mod missing;
+mod planner;
 mod recent;
@@ -0,0 +1,188 @@
+use std::{fmt::Write, sync::Arc};
+
+use bathbot_macros::PaginationBuilder;
+use bathbot_model::rosu_v2::user::User;
+use bathbot_util::{
+    constants::OSU_BASE, datetime::DATE_FORMAT, osu::flag_url, AuthorBuilder, CowUtils,
+    EmbedBuilder, FooterBuilder,
+};
+use eyre::{Report, Result};
+use futures::future::{ready, BoxFuture};
+use time::OffsetDateTime;
+use twilight_model::{
+    channel::message::Component,
+    id::{marker::UserMarker, Id},
+};
+
+use crate::{
+    active::{
+        pagination::{handle_pagination_component, handle_pagination_modal, Pages},
+        BuildPage, ComponentResult, IActiveMessage,
+    },
+    commands::osu::MedalPlannerEntry,
+    core::Context,
+    manager::redis::RedisData,
+    util::interaction::{InteractionComponent, InteractionModal},
+};
+
+#[derive(PaginationBuilder)]
+pub struct MedalsPlannerPagination {
+    user: RedisData<User>,
+    #[pagination(per_page = 15)]
+    entries: Box<[MedalPlannerEntry]>,
+    newly_achieved: Box<[Box<str>]>,
+    medal_count: (usize, usize),
+    hidden_count: usize,
+    tracked_since: OffsetDateTime,
+    achieved_since: usize,
+    msg_owner: Id<UserMarker>,
+    pages: Pages,
+}
+
+impl IActiveMessage for MedalsPlannerPagination {
+    fn build_page(&mut self, _: Arc<Context>) -> BoxFuture<'_, Result<BuildPage>> {
+        let pages = &self.pages;
+        let idx = pages.index();
+
+        let limit = self.entries.len().min(idx + pages.per_page());
+        let entries = &self.entries[idx..limit];
+
+        let mut description = String::new();
+
+        if idx == 0 && !self.newly_achieved.is_empty() {
+            description.push_str("🎉 Newly achieved since your last check: ");
+
+            let mut iter = self.newly_achieved.iter();
+
+            if let Some(name) = iter.next() {
+                let _ = write!(description, "**{name}**");
+
+                for name in iter {
+                    let _ = write!(description, ", **{name}**");
+                }
+            }
+
+            description.push_str("\n\n");
+        }
+
+        for entry in entries {
+            match entry {
+                MedalPlannerEntry::Section(section) => {
+                    let _ = writeln!(description, "__**{section}:**__");
+                }
+                MedalPlannerEntry::Medal { medal, maps } => {
+                    let url = match medal.url() {
+                        Ok(url) => url,
+                        Err(err) => {
+                            warn!(?err);
+
+                            medal.backup_url()
+                        }
+                    };
+
+                    let _ = writeln!(
+                        description,
+                        "- [{name}]({url} \"Rarity: {rarity:.2}%\")",
+                        name = medal.name,
+                        rarity = medal.rarity,
+                    );
+
+                    for map in maps.iter() {
+                        let _ = writeln!(
+                            description,
+                            "  - [{artist} - {title} [{version}]]({OSU_BASE}b/{map_id}) ({stars:.2}★)",
+                            artist = map.artist.cow_escape_markdown(),
+                            title = map.title.cow_escape_markdown(),
+                            version = map.version.cow_escape_markdown(),
+                            map_id = map.map_id,
+                            stars = map.stars,
+                        );
+                    }
+                }
+            }
+        }
+
+        if self.entries.is_empty() {
+            description.push_str("No medals left to plan for 🥳");
+        }
+
+        let page = pages.curr_page();
+        let pages = pages.last_page();
+
+        let mut footer_text = format!(
+            "Page {page}/{pages} | Missing {}/{} medals",
+            self.medal_count.0, self.medal_count.1
+        );
+
+        if self.hidden_count > 0 {
+            let _ = write!(footer_text, " ({} hidden)", self.hidden_count);
+        }
+
+        let tracked_since = match self.tracked_since.format(DATE_FORMAT) {
+            Ok(date) => date,
+            Err(err) => {
+                let err = Report::new(err).wrap_err("Failed to format tracking date");
+
+                return Box::pin(ready(Err(err)));
+            }
+        };
+
+        let _ = write!(
+            footer_text,
+            " | Acquired {} since {tracked_since}",
+            self.achieved_since,
+        );
+
+        let (country_code, username, user_id, avatar_url) = match self.user {
+            RedisData::Original(ref user) => {
+                let country_code = user.country_code.as_str();
+                let username = user.username.as_str();
+                let user_id = user.user_id;
+                let avatar_url = user.avatar_url.as_ref();
+
+                (country_code, username, user_id, avatar_url)
+            }
+            RedisData::Archive(ref user) => {
+                let country_code = user.country_code.as_str();
+                let username = user.username.as_str();
+                let user_id = user.user_id;
+                let avatar_url = user.avatar_url.as_ref();
+
+                (country_code, username, user_id, avatar_url)
+            }
+        };
+
+        let author = AuthorBuilder::new(username)
+            .url(format!("{OSU_BASE}u/{user_id}"))
+            .icon_url(flag_url(country_code));
+
+        let embed = EmbedBuilder::new()
+            .author(author)
+            .description(description)
+            .footer(FooterBuilder::new(footer_text))
+            .thumbnail(avatar_url)
+            .title("Medal planner");
+
+        BuildPage::new(embed, false).boxed()
+    }
+
+    fn build_components(&self) -> Vec<Component> {
+        self.pages.components()
+    }
+
+    fn handle_component<'a>(
+        &'a mut self,
+        ctx: Arc<Context>,
+        component: &'a mut InteractionComponent,
+    ) -> BoxFuture<'a, ComponentResult> {
+        handle_pagination_component(ctx, component, self.msg_owner, false, &mut self.pages)
+    }
+
+    fn handle_modal<'a>(
+        &'a mut self,
+        ctx: &'a Context,
+        modal: &'a mut InteractionModal,
+    ) -> BoxFuture<'a, Result<()>> {
+        handle_pagination_modal(ctx, modal, self.msg_owner, false, &mut self.pages)
+    }
+}
@@ -0,0 +1,525 @@
+use std::{
+    fmt::Write,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use bathbot_util::{datetime::SecToMinSec, EmbedBuilder, FooterBuilder, Matrix};
+use eyre::Result;
+use futures::future::BoxFuture;
+use rand::Rng;
+use twilight_model::{
+    channel::message::{
+        component::{ActionRow, Button, ButtonStyle, SelectMenu, SelectMenuOption},
+        Component,
+    },
+    id::{marker::UserMarker, Id},
+};
+
+use crate::{
+    active::{BuildPage, ComponentResult, IActiveMessage},
+    commands::fun::MinesweeperDifficulty,
+    core::Context,
+    util::{interaction::InteractionComponent, Authored},
+};
+
+const KEYCAPS: [&str; 10] = [
+    "0\u{fe0f}\u{20e3}",
+    "1\u{fe0f}\u{20e3}",
+    "2\u{fe0f}\u{20e3}",
+    "3\u{fe0f}\u{20e3}",
+    "4\u{fe0f}\u{20e3}",
+    "5\u{fe0f}\u{20e3}",
+    "6\u{fe0f}\u{20e3}",
+    "7\u{fe0f}\u{20e3}",
+    "8\u{fe0f}\u{20e3}",
+    "9\u{fe0f}\u{20e3}",
+];
+
+pub struct MinesweeperGame {
+    difficulty: MinesweeperDifficulty,
+    field: Matrix<Cell>,
+    mines: u8,
+    column: Option<usize>,
+    row: Option<usize>,
+    start: Option<Instant>,
+    state: GameState,
+    msg_owner: Id<UserMarker>,
+}
+
+impl IActiveMessage for MinesweeperGame {
+    fn build_page(&mut self, _: Arc<Context>) -> BoxFuture<'_, Result<BuildPage>> {
+        let mut description = String::with_capacity(1024);
+
+        match self.state {
+            GameState::Ongoing => {
+                let _ = writeln!(
+                    description,
+                    "<@{}> select a cell through its column and row, \
+                    then reveal or flag it.",
+                    self.msg_owner
+                );
+            }
+            GameState::Won { time, best } => {
+                let _ = write!(description, "You won in **{:.3}s**", time.as_secs_f32());
+
+                match best {
+                    BestTime::New => description.push_str(", a new personal best!"),
+                    BestTime::Previous(ms) => {
+                        let _ = write!(description, " (personal best: {:.3}s)", ms as f32 / 1000.0);
+                    }
+                    BestTime::Unknown => {}
+                }
+
+                description.push('\n');
+            }
+            GameState::Lost => description.push_str("Boom! You hit a mine.\n"),
+            GameState::Aborted => description.push_str("You gave up.\n"),
+        }
+
+        description.push('\n');
+        self.write_field(&mut description);
+
+        let flags = self.count_status(CellStatus::Flagged);
+
+        let elapsed = match self.state {
+            GameState::Won { time, .. } => time,
+            _ => self.start.map_or(Duration::ZERO, |start| start.elapsed()),
+        };
+
+        let footer = format!(
+            "Mines: {mines} • Flags: {flags} • Time: {time}",
+            mines = self.mines,
+            time = SecToMinSec::new(elapsed.as_secs() as u32),
+        );
+
+        let embed = EmbedBuilder::new()
+            .description(description)
+            .footer(FooterBuilder::new(footer))
+            .title(format!("Minesweeper ({})", self.difficulty.name()));
+
+        BuildPage::new(embed, false).boxed()
+    }
+
+    fn build_components(&self) -> Vec<Component> {
+        if !matches!(self.state, GameState::Ongoing) {
+            return Vec::new();
+        }
+
+        let columns = (0..self.field.width())
+            .map(|x| SelectMenuOption {
+                default: self.column == Some(x),
+                description: None,
+                emoji: None,
+                label: format!("Column {}", x + 1),
+                value: x.to_string(),
+            })
+            .collect();
+
+        let column_menu = SelectMenu {
+            custom_id: "ms_column".to_owned(),
+            disabled: false,
+            max_values: Some(1),
+            min_values: Some(1),
+            options: columns,
+            placeholder: Some("Select a column".to_owned()),
+        };
+
+        let column_row = ActionRow {
+            components: vec![Component::SelectMenu(column_menu)],
+        };
+
+        let rows = (0..self.field.height())
+            .map(|y| SelectMenuOption {
+                default: self.row == Some(y),
+                description: None,
+                emoji: None,
+                label: format!("Row {}", row_letter(y)),
+                value: y.to_string(),
+            })
+            .collect();
+
+        let row_menu = SelectMenu {
+            custom_id: "ms_row".to_owned(),
+            disabled: false,
+            max_values: Some(1),
+            min_values: Some(1),
+            options: rows,
+            placeholder: Some("Select a row".to_owned()),
+        };
+
+        let row_row = ActionRow {
+            components: vec![Component::SelectMenu(row_menu)],
+        };
+
+        let selected = self.selected().map(|coords| self.field[coords].status);
+
+        let reveal_button = Button {
+            custom_id: Some("ms_reveal".to_owned()),
+            disabled: selected != Some(CellStatus::Hidden),
+            emoji: None,
+            label: Some("Reveal".to_owned()),
+            style: ButtonStyle::Success,
+            url: None,
+        };
+
+        let flag_label = if selected == Some(CellStatus::Flagged) {
+            "Unflag"
+        } else {
+            "Flag"
+        };
+
+        let flag_button = Button {
+            custom_id: Some("ms_flag".to_owned()),
+            disabled: !matches!(selected, Some(CellStatus::Hidden | CellStatus::Flagged)),
+            emoji: None,
+            label: Some(flag_label.to_owned()),
+            style: ButtonStyle::Primary,
+            url: None,
+        };
+
+        let give_up_button = Button {
+            custom_id: Some("ms_give_up".to_owned()),
+            disabled: false,
+            emoji: None,
+            label: Some("Give up".to_owned()),
+            style: ButtonStyle::Danger,
+            url: None,
+        };
+
+        let button_row = ActionRow {
+            components: vec![
+                Component::Button(reveal_button),
+                Component::Button(flag_button),
+                Component::Button(give_up_button),
+            ],
+        };
+
+        vec![
+            Component::ActionRow(column_row),
+            Component::ActionRow(row_row),
+            Component::ActionRow(button_row),
+        ]
+    }
+
+    fn handle_component<'a>(
+        &'a mut self,
+        ctx: Arc<Context>,
+        component: &'a mut InteractionComponent,
+    ) -> BoxFuture<'a, ComponentResult> {
+        let user_id = match component.user_id() {
+            Ok(user_id) => user_id,
+            Err(err) => return ComponentResult::Err(err).boxed(),
+        };
+
+        if user_id != self.msg_owner || !matches!(self.state, GameState::Ongoing) {
+            return ComponentResult::Ignore.boxed();
+        }
+
+        match component.data.custom_id.as_str() {
+            "ms_column" => self.column = selected_value(component, self.field.width()),
+            "ms_row" => self.row = selected_value(component, self.field.height()),
+            "ms_reveal" => return Box::pin(self.reveal(ctx)),
+            "ms_flag" => self.toggle_flag(),
+            "ms_give_up" => self.end(GameState::Aborted),
+            other => {
+                warn!(name = %other, ?component, "Unknown minesweeper component");
+
+                return ComponentResult::Ignore.boxed();
+            }
+        }
+
+        ComponentResult::BuildPage.boxed()
+    }
+
+    fn until_timeout(&self) -> Option<Duration> {
+        Some(Duration::from_secs(300))
+    }
+}
+
+impl MinesweeperGame {
+    pub fn new(difficulty: MinesweeperDifficulty, msg_owner: Id<UserMarker>) -> Self {
+        let (width, height, mines) = difficulty.dimensions();
+
+        Self {
+            difficulty,
+            field: Matrix::new(width, height),
+            mines,
+            column: None,
+            row: None,
+            start: None,
+            state: GameState::Ongoing,
+            msg_owner,
+        }
+    }
+
+    fn selected(&self) -> Option<(usize, usize)> {
+        self.column.zip(self.row)
+    }
+
+    async fn reveal(&mut self, ctx: Arc<Context>) -> ComponentResult {
+        let Some((x, y)) = self.selected() else {
+            return ComponentResult::Ignore;
+        };
+
+        if self.field[(x, y)].status != CellStatus::Hidden {
+            return ComponentResult::Ignore;
+        }
+
+        // Mines are placed only on the first reveal so that it's never a loss
+        let start = match self.start {
+            Some(start) => start,
+            None => {
+                self.place_mines(x, y);
+
+                *self.start.insert(Instant::now())
+            }
+        };
+
+        if self.field[(x, y)].mine {
+            self.field[(x, y)].status = CellStatus::Revealed;
+            self.end(GameState::Lost);
+
+            return ComponentResult::BuildPage;
+        }
+
+        self.flood_reveal(x, y);
+
+        let cells = self.field.width() * self.field.height();
+
+        if self.count_status(CellStatus::Revealed) + self.mines as usize == cells {
+            let time = start.elapsed();
+            let best = self.store_time(&ctx, time).await;
+            self.end(GameState::Won { time, best });
+        }
+
+        ComponentResult::BuildPage
+    }
+
+    async fn store_time(&self, ctx: &Context, time: Duration) -> BestTime {
+        let games = ctx.games();
+        let difficulty = self.difficulty as i16;
+        let time_ms = time.as_millis() as u32;
+
+        match games
+            .upsert_minesweeper_time(self.msg_owner, difficulty, time_ms)
+            .await
+        {
+            Ok(true) => BestTime::New,
+            Ok(false) => match games
+                .minesweeper_best_time(self.msg_owner, difficulty)
+                .await
+            {
+                Ok(Some(best)) => BestTime::Previous(best),
+                Ok(None) => BestTime::Unknown,
+                Err(err) => {
+                    warn!(?err, "Failed to get minesweeper best time");
+
+                    BestTime::Unknown
+                }
+            },
+            Err(err) => {
+                warn!(?err, "Failed to store minesweeper time");
+
+                BestTime::Unknown
+            }
+        }
+    }
+
+    fn toggle_flag(&mut self) {
+        let Some(coords) = self.selected() else {
+            return;
+        };
+
+        let cell = &mut self.field[coords];
+
+        cell.status = match cell.status {
+            CellStatus::Hidden => CellStatus::Flagged,
+            CellStatus::Flagged => CellStatus::Hidden,
+            CellStatus::Revealed => CellStatus::Revealed,
+        };
+    }
+
+    fn end(&mut self, state: GameState) {
+        self.state = state;
+
+        if matches!(self.state, GameState::Won { .. }) {
+            return;
+        }
+
+        // Reveal the remaining mines; flags stay as they are
+        for x in 0..self.field.width() {
+            for y in 0..self.field.height() {
+                let cell = &mut self.field[(x, y)];
+
+                if cell.mine && cell.status == CellStatus::Hidden {
+                    cell.status = CellStatus::Revealed;
+                }
+            }
+        }
+    }
+
+    /// Place mines anywhere but around the given cell and count the
+    /// neighboring mines of each cell.
+    fn place_mines(&mut self, safe_x: usize, safe_y: usize) {
+        let width = self.field.width();
+        let height = self.field.height();
+        let mut rng = rand::thread_rng();
+        let mut remaining = self.mines;
+
+        while remaining > 0 {
+            let x = rng.gen_range(0..width);
+            let y = rng.gen_range(0..height);
+
+            if x.abs_diff(safe_x) <= 1 && y.abs_diff(safe_y) <= 1 {
+                continue;
+            }
+
+            let cell = &mut self.field[(x, y)];
+
+            if !cell.mine {
+                cell.mine = true;
+                remaining -= 1;
+            }
+        }
+
+        for x in 0..width {
+            for y in 0..height {
+                let mut neighbors = 0;
+
+                for cx in x.saturating_sub(1)..width.min(x + 2) {
+                    for cy in y.saturating_sub(1)..height.min(y + 2) {
+                        neighbors += self.field[(cx, cy)].mine as u8;
+                    }
+                }
+
+                self.field[(x, y)].neighbors = neighbors - self.field[(x, y)].mine as u8;
+            }
+        }
+    }
+
+    /// Reveal the cell and, if it has no neighboring mines, all of its
+    /// neighbors recursively.
+    fn flood_reveal(&mut self, x: usize, y: usize) {
+        let width = self.field.width();
+        let height = self.field.height();
+        let mut stack = vec![(x, y)];
+
+        while let Some((x, y)) = stack.pop() {
+            let cell = &mut self.field[(x, y)];
+
+            if cell.status != CellStatus::Hidden {
+                continue;
+            }
+
+            cell.status = CellStatus::Revealed;
+
+            if cell.neighbors > 0 {
+                continue;
+            }
+
+            for cx in x.saturating_sub(1)..width.min(x + 2) {
+                for cy in y.saturating_sub(1)..height.min(y + 2) {
+                    if self.field[(cx, cy)].status == CellStatus::Hidden {
+                        stack.push((cx, cy));
+                    }
+                }
+            }
+        }
+    }
+
+    fn count_status(&self, status: CellStatus) -> usize {
+        let mut count = 0;
+
+        for x in 0..self.field.width() {
+            for y in 0..self.field.height() {
+                count += (self.field[(x, y)].status == status) as usize;
+            }
+        }
+
+        count
+    }
+
+    fn write_field(&self, content: &mut String) {
+        let selected = self.selected();
+        let ongoing = matches!(self.state, GameState::Ongoing);
+        let lost = matches!(self.state, GameState::Lost);
+
+        content.push('⬛');
+
+        for x in 0..self.field.width() {
+            content.push_str(KEYCAPS[x + 1]);
+        }
+
+        for y in 0..self.field.height() {
+            content.push('\n');
+            content.push(regional_indicator(y));
+
+            for x in 0..self.field.width() {
+                let cell = self.field[(x, y)];
+                let is_selected = selected == Some((x, y));
+
+                let emote = match cell.status {
+                    CellStatus::Hidden if ongoing && is_selected => "🟨",
+                    CellStatus::Hidden => "⬜",
+                    CellStatus::Flagged if !ongoing && !cell.mine => "❌",
+                    CellStatus::Flagged => "🚩",
+                    CellStatus::Revealed if cell.mine && lost && is_selected => "💥",
+                    CellStatus::Revealed if cell.mine => "💣",
+                    CellStatus::Revealed if cell.neighbors == 0 => "🟦",
+                    CellStatus::Revealed => KEYCAPS[cell.neighbors as usize],
+                };
+
+                content.push_str(emote);
+            }
+        }
+    }
+}
+
+#[derive(Copy, Clone, Default)]
+struct Cell {
+    mine: bool,
+    neighbors: u8,
+    status: CellStatus,
+}
+
+#[derive(Copy, Clone, Default, Eq, PartialEq)]
+enum CellStatus {
+    #[default]
+    Hidden,
+    Flagged,
+    Revealed,
+}
+
+#[derive(Copy, Clone)]
+enum GameState {
+    Ongoing,
+    Won { time: Duration, best: BestTime },
+    Lost,
+    Aborted,
+}
+
+#[derive(Copy, Clone)]
+enum BestTime {
+    New,
+    /// Previous best time in milliseconds
+    Previous(u32),
+    Unknown,
+}
+
+fn selected_value(component: &InteractionComponent, max: usize) -> Option<usize> {
+    component
+        .data
+        .values
+        .first()
+        .and_then(|value| value.parse().ok())
+        .filter(|&value| value < max)
+}
+
+fn row_letter(y: usize) -> char {
+    (b'A' + y as u8) as char
+}
+
+fn regional_indicator(y: usize) -> char {
+    char::from_u32(0x1F1E6 + y as u32).unwrap_or('⬛')
+}
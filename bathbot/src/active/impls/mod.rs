@@ -1,20 +1,24 @@
 pub use self::{
     badges::BadgesPagination,
-    bg_game::{BackgroundGame, BackgroundGameSetup},
+    bg_game::{BackgroundGame, BackgroundGameSetup, BgGameKind, BgGameSettings},
     bookmarks::BookmarksPagination,
+    bws::BwsListPagination,
     changelog::ChangelogPagination,
+    collection::CollectionPagination,
     compare::{CompareMostPlayedPagination, CompareScoresPagination, CompareTopPagination},
     edit_on_timeout::{EditOnTimeout, RecentScoreEdit, TopScoreEdit},
     help::{HelpInteractionCommand, HelpPrefixMenu},
-    higherlower::HigherLowerGame,
+    higherlower::{HigherLowerChannelGame, HigherLowerGame, HigherLowerVoting, HlMapAttr},
     leaderboard::LeaderboardPagination,
     map::MapPagination,
     map_search::MapSearchPagination,
+    mappool::MappoolPagination,
     match_compare::MatchComparePagination,
     medals::{
         MedalsCommonPagination, MedalsListPagination, MedalsMissingPagination,
-        MedalsRecentPagination,
+        MedalsPlannerPagination, MedalsRecentPagination,
     },
+    minesweeper::MinesweeperGame,
     most_played::MostPlayedPagination,
     nochoke::NoChokePagination,
     osekai::{MedalCountPagination, MedalRarityPagination},
@@ -36,12 +40,18 @@ pub use self::{
     snipe::{SnipeCountryListPagination, SnipeDifferencePagination, SnipePlayerListPagination},
     top::TopPagination,
     top_if::TopIfPagination,
+    tournament::{
+        TournamentMatchesPagination, TournamentStandingsPagination, TournamentTeamsPagination,
+    },
+    trivia::TriviaGame,
 };
 
 mod badges;
 mod bg_game;
 mod bookmarks;
+mod bws;
 mod changelog;
+mod collection;
 mod compare;
 mod edit_on_timeout;
 mod help;
@@ -49,8 +59,10 @@ mod higherlower;
 mod leaderboard;
 mod map;
 mod map_search;
+mod mappool;
 mod match_compare;
 mod medals;
+mod minesweeper;
 mod most_played;
 mod nochoke;
 mod osekai;
@@ -69,3 +81,5 @@ mod slash_commands;
 mod snipe;
 mod top;
 mod top_if;
+mod tournament;
+mod trivia;
@@ -8,7 +8,9 @@ use std::{
 };
 
 use bathbot_macros::PaginationBuilder;
-use bathbot_model::{BgGameScore, EmbedHeader, RankingEntries, RankingEntry, RankingKind};
+use bathbot_model::{
+    BgGameScore, EmbedHeader, RankingEntries, RankingEntry, RankingKind, TriviaScore,
+};
 use bathbot_util::{
     numbers::{round, WithComma},
     EmbedBuilder,
@@ -80,6 +82,7 @@ impl RankingPagination {
         matches!(
             self.kind,
             RankingKind::BgScores { .. }
+                | RankingKind::TriviaScores { .. }
                 | RankingKind::PpCountry { .. }
                 | RankingKind::PpGlobal { .. }
                 | RankingKind::RankedScore { .. }
@@ -225,36 +228,21 @@ impl RankingPagination {
                     for i in pages.index()..(pages.index() + pages.per_page()).min(self.total) {
                         if let Entry::Vacant(entry) = entries.entry(i) {
                             let BgGameScore { discord_id, score } = scores[i];
-                            let id = Id::new(discord_id as u64);
-
-                            let mut name_opt = match ctx.user_config().osu_name(id).await {
-                                Ok(Some(name)) => Some(name),
-                                Ok(None) => None,
-                                Err(err) => {
-                                    warn!(?err, "Failed to get osu user");
-
-                                    None
-                                }
-                            };
-
-                            name_opt = match name_opt {
-                                Some(name) => Some(name),
-                                None => match ctx.cache.user(id).await {
-                                    Ok(Some(user)) => Some(user.name.as_ref().into()),
-                                    Ok(None) => None,
-                                    Err(err) => {
-                                        warn!("{err:?}");
-
-                                        None
-                                    }
-                                },
-                            };
-
-                            entry.insert(RankingEntry {
-                                country: None,
-                                name: name_opt.unwrap_or_else(|| "Unknown user".into()),
-                                value: score as u64,
-                            });
+                            entry.insert(discord_user_entry(ctx, discord_id, score).await);
+                        }
+                    }
+                }
+                RankingKind::TriviaScores { scores } => {
+                    let RankingEntries::Amount(ref mut entries) = self.entries else {
+                        unreachable!()
+                    };
+
+                    // not necessary but less ugly than the iterator
+                    #[allow(clippy::needless_range_loop)]
+                    for i in pages.index()..(pages.index() + pages.per_page()).min(self.total) {
+                        if let Entry::Vacant(entry) = entries.entry(i) {
+                            let TriviaScore { discord_id, score } = scores[i];
+                            entry.insert(discord_user_entry(ctx, discord_id, score).await);
                         }
                     }
                 }
@@ -392,6 +380,40 @@ impl RankingPagination {
     }
 }
 
+/// Entry for a discord user, named after their linked osu! account if available.
+async fn discord_user_entry(ctx: &Context, discord_id: i64, score: i32) -> RankingEntry<u64> {
+    let id = Id::new(discord_id as u64);
+
+    let mut name_opt = match ctx.user_config().osu_name(id).await {
+        Ok(Some(name)) => Some(name),
+        Ok(None) => None,
+        Err(err) => {
+            warn!(?err, "Failed to get osu user");
+
+            None
+        }
+    };
+
+    name_opt = match name_opt {
+        Some(name) => Some(name),
+        None => match ctx.cache.user(id).await {
+            Ok(Some(user)) => Some(user.name.as_ref().into()),
+            Ok(None) => None,
+            Err(err) => {
+                warn!("{err:?}");
+
+                None
+            }
+        },
+    };
+
+    RankingEntry {
+        country: None,
+        name: name_opt.unwrap_or_else(|| "Unknown user".into()),
+        value: score as u64,
+    }
+}
+
 struct Lengths {
     idx: usize,
     name: usize,
@@ -1,9 +1,6 @@
 use std::{fmt::Write, future::ready, mem, sync::Arc};
 
-use bathbot_util::{
-    constants::{GENERAL_ISSUE, ORDR_ISSUE, OSU_API_ISSUE},
-    EmbedBuilder, MessageBuilder,
-};
+use bathbot_util::{EmbedBuilder, MessageBuilder};
 use eyre::{Report, Result, WrapErr};
 use futures::future::BoxFuture;
 use rosu_v2::prelude::GameMode;
@@ -21,7 +18,7 @@ use twilight_model::{
 use crate::{
     active::{BuildPage, ComponentResult, IActiveMessage},
     commands::osu::{OngoingRender, RenderStatus, RenderStatusInner, RENDERER_NAME},
-    core::{buckets::BucketName, Context},
+    core::{buckets::BucketName, locale::ErrorKey, Context},
     manager::{OwnedReplayScore, ReplayScore},
     util::{interaction::InteractionComponent, Authored, ComponentExt, MessageExt},
 };
@@ -120,7 +117,8 @@ impl CachedRender {
                 let score = match ctx.osu().score(self.score_id, GameMode::Osu).await {
                     Ok(score) => score,
                     Err(err) => {
-                        let embed = EmbedBuilder::new().color_red().description(OSU_API_ISSUE);
+                        let content = ErrorKey::OsuApi.text(component.language(&ctx).await);
+                        let embed = EmbedBuilder::new().color_red().description(content);
                         let builder = MessageBuilder::new().embed(embed);
                         let _ = component.update(&ctx, builder).await;
 
@@ -162,7 +160,8 @@ impl CachedRender {
                 return Ok(());
             }
             Err(err) => {
-                let embed = EmbedBuilder::new().color_red().description(GENERAL_ISSUE);
+                let content = ErrorKey::General.text(component.language(&ctx).await);
+                let embed = EmbedBuilder::new().color_red().description(content);
                 let builder = MessageBuilder::new().embed(embed);
                 let _ = component.update(&ctx, builder).await;
 
@@ -173,7 +172,8 @@ impl CachedRender {
         let settings = match settings_res {
             Ok(settings) => settings,
             Err(err) => {
-                let embed = EmbedBuilder::new().color_red().description(GENERAL_ISSUE);
+                let content = ErrorKey::General.text(component.language(&ctx).await);
+                let embed = EmbedBuilder::new().color_red().description(content);
                 let builder = MessageBuilder::new().embed(embed);
                 let _ = component.update(&ctx, builder).await;
 
@@ -206,7 +206,8 @@ impl CachedRender {
         let render = match render_fut.await {
             Ok(render) => render,
             Err(err) => {
-                let embed = EmbedBuilder::new().color_red().description(ORDR_ISSUE);
+                let content = ErrorKey::Ordr.text(component.language(&ctx).await);
+                let embed = EmbedBuilder::new().color_red().description(content);
                 let builder = MessageBuilder::new().embed(embed);
                 let _ = component.update(&ctx, builder).await;
 
@@ -90,8 +90,8 @@ impl SnipePlayerListPagination {
 
             // Get scores
             let scores = ctx
-                .client()
-                .get_national_firsts(&self.params)
+                .snipe()
+                .national_firsts(&self.params)
                 .await
                 .wrap_err("Failed to get national firsts")?;
 
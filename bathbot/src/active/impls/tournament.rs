@@ -0,0 +1,265 @@
+use std::{fmt::Write, sync::Arc};
+
+use bathbot_macros::PaginationBuilder;
+use bathbot_psql::model::tournament::DbTournamentMatch;
+use bathbot_util::{constants::OSU_BASE, CowUtils, EmbedBuilder, FooterBuilder};
+use eyre::Result;
+use futures::future::BoxFuture;
+use twilight_model::{
+    channel::message::Component,
+    id::{marker::UserMarker, Id},
+};
+
+use crate::{
+    active::{
+        pagination::{handle_pagination_component, handle_pagination_modal, Pages},
+        BuildPage, ComponentResult, IActiveMessage,
+    },
+    commands::osu::{TeamPlayers, TournamentStanding},
+    core::Context,
+    util::interaction::{InteractionComponent, InteractionModal},
+};
+
+#[derive(PaginationBuilder)]
+pub struct TournamentTeamsPagination {
+    #[pagination(per_page = 8)]
+    teams: Box<[TeamPlayers]>,
+    tournament: Box<str>,
+    msg_owner: Id<UserMarker>,
+    pages: Pages,
+}
+
+impl IActiveMessage for TournamentTeamsPagination {
+    fn build_page(&mut self, _: Arc<Context>) -> BoxFuture<'_, Result<BuildPage>> {
+        let pages = &self.pages;
+        let idx = pages.index();
+
+        let limit = self.teams.len().min(idx + pages.per_page());
+        let teams = &self.teams[idx..limit];
+
+        let mut description = String::with_capacity(teams.len() * 128);
+
+        for team in teams {
+            let _ = write!(description, "**{}**\n⤷ ", team.name.cow_escape_markdown());
+            let mut players = team.players.iter();
+
+            if let Some((user_id, username)) = players.next() {
+                let _ = write!(description, "[{username}]({OSU_BASE}u/{user_id})");
+
+                for (user_id, username) in players {
+                    let _ = write!(description, ", [{username}]({OSU_BASE}u/{user_id})");
+                }
+            }
+
+            description.push('\n');
+        }
+
+        if self.teams.is_empty() {
+            description.push_str(
+                "This tournament does not have any teams yet.\n\
+                Teams can be registered via `/tournament team add`.",
+            );
+        }
+
+        let page = pages.curr_page();
+        let pages = pages.last_page();
+
+        let footer =
+            FooterBuilder::new(format!("Page {page}/{pages} • {} teams", self.teams.len()));
+
+        let embed = EmbedBuilder::new()
+            .description(description)
+            .footer(footer)
+            .title(format!("Teams of `{}`", self.tournament));
+
+        BuildPage::new(embed, false).boxed()
+    }
+
+    fn build_components(&self) -> Vec<Component> {
+        self.pages.components()
+    }
+
+    fn handle_component<'a>(
+        &'a mut self,
+        ctx: Arc<Context>,
+        component: &'a mut InteractionComponent,
+    ) -> BoxFuture<'a, ComponentResult> {
+        handle_pagination_component(ctx, component, self.msg_owner, false, &mut self.pages)
+    }
+
+    fn handle_modal<'a>(
+        &'a mut self,
+        ctx: &'a Context,
+        modal: &'a mut InteractionModal,
+    ) -> BoxFuture<'a, Result<()>> {
+        handle_pagination_modal(ctx, modal, self.msg_owner, false, &mut self.pages)
+    }
+}
+
+#[derive(PaginationBuilder)]
+pub struct TournamentMatchesPagination {
+    #[pagination(per_page = 10)]
+    matches: Box<[DbTournamentMatch]>,
+    tournament: Box<str>,
+    msg_owner: Id<UserMarker>,
+    pages: Pages,
+}
+
+impl IActiveMessage for TournamentMatchesPagination {
+    fn build_page(&mut self, _: Arc<Context>) -> BoxFuture<'_, Result<BuildPage>> {
+        let pages = &self.pages;
+        let idx = pages.index();
+
+        let limit = self.matches.len().min(idx + pages.per_page());
+        let matches = &self.matches[idx..limit];
+
+        let mut description = String::with_capacity(matches.len() * 128);
+
+        for entry in matches {
+            let team_a = entry.team_a.cow_escape_markdown();
+            let team_b = entry.team_b.cow_escape_markdown();
+
+            let _ = write!(
+                description,
+                "`{id}` **{round}** • <t:{timestamp}:f>\n⤷ ",
+                id = entry.match_id,
+                round = entry.round.cow_escape_markdown(),
+                timestamp = entry.scheduled_at.unix_timestamp(),
+            );
+
+            match (entry.score_a, entry.score_b) {
+                (Some(score_a), Some(score_b)) => {
+                    let _ = write!(
+                        description,
+                        "**{team_a}** {score_a} - {score_b} **{team_b}**"
+                    );
+                }
+                _ => {
+                    let _ = write!(description, "**{team_a}** vs **{team_b}**");
+                }
+            }
+
+            if let Some(mp_id) = entry.mp_id {
+                let _ = write!(
+                    description,
+                    " • [Lobby]({OSU_BASE}community/matches/{mp_id})"
+                );
+            }
+
+            description.push('\n');
+        }
+
+        if self.matches.is_empty() {
+            description.push_str(
+                "This tournament does not have any matches yet.\n\
+                Matches can be scheduled via `/tournament match schedule`.",
+            );
+        }
+
+        let page = pages.curr_page();
+        let pages = pages.last_page();
+
+        let footer = FooterBuilder::new(format!(
+            "Page {page}/{pages} • {} matches",
+            self.matches.len()
+        ));
+
+        let embed = EmbedBuilder::new()
+            .description(description)
+            .footer(footer)
+            .title(format!("Matches of `{}`", self.tournament));
+
+        BuildPage::new(embed, false).boxed()
+    }
+
+    fn build_components(&self) -> Vec<Component> {
+        self.pages.components()
+    }
+
+    fn handle_component<'a>(
+        &'a mut self,
+        ctx: Arc<Context>,
+        component: &'a mut InteractionComponent,
+    ) -> BoxFuture<'a, ComponentResult> {
+        handle_pagination_component(ctx, component, self.msg_owner, false, &mut self.pages)
+    }
+
+    fn handle_modal<'a>(
+        &'a mut self,
+        ctx: &'a Context,
+        modal: &'a mut InteractionModal,
+    ) -> BoxFuture<'a, Result<()>> {
+        handle_pagination_modal(ctx, modal, self.msg_owner, false, &mut self.pages)
+    }
+}
+
+#[derive(PaginationBuilder)]
+pub struct TournamentStandingsPagination {
+    #[pagination(per_page = 15)]
+    standings: Box<[TournamentStanding]>,
+    tournament: Box<str>,
+    msg_owner: Id<UserMarker>,
+    pages: Pages,
+}
+
+impl IActiveMessage for TournamentStandingsPagination {
+    fn build_page(&mut self, _: Arc<Context>) -> BoxFuture<'_, Result<BuildPage>> {
+        let pages = &self.pages;
+        let idx = pages.index();
+
+        let limit = self.standings.len().min(idx + pages.per_page());
+        let standings = &self.standings[idx..limit];
+
+        let mut description = String::with_capacity(standings.len() * 64);
+
+        for (standing, i) in standings.iter().zip(idx + 1..) {
+            let diff = standing.maps_won as i64 - standing.maps_lost as i64;
+
+            let _ = writeln!(
+                description,
+                "**#{i} {team}**: {wins}W {losses}L • Maps {won}-{lost} ({diff:+})",
+                team = standing.team.cow_escape_markdown(),
+                wins = standing.wins,
+                losses = standing.losses,
+                won = standing.maps_won,
+                lost = standing.maps_lost,
+            );
+        }
+
+        if self.standings.is_empty() {
+            description.push_str("This tournament does not have any teams yet.");
+        }
+
+        let page = pages.curr_page();
+        let pages = pages.last_page();
+
+        let footer = FooterBuilder::new(format!("Page {page}/{pages}"));
+
+        let embed = EmbedBuilder::new()
+            .description(description)
+            .footer(footer)
+            .title(format!("Standings of `{}`", self.tournament));
+
+        BuildPage::new(embed, false).boxed()
+    }
+
+    fn build_components(&self) -> Vec<Component> {
+        self.pages.components()
+    }
+
+    fn handle_component<'a>(
+        &'a mut self,
+        ctx: Arc<Context>,
+        component: &'a mut InteractionComponent,
+    ) -> BoxFuture<'a, ComponentResult> {
+        handle_pagination_component(ctx, component, self.msg_owner, false, &mut self.pages)
+    }
+
+    fn handle_modal<'a>(
+        &'a mut self,
+        ctx: &'a Context,
+        modal: &'a mut InteractionModal,
+    ) -> BoxFuture<'a, Result<()>> {
+        handle_pagination_modal(ctx, modal, self.msg_owner, false, &mut self.pages)
+    }
+}
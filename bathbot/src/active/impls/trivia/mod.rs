@@ -0,0 +1,331 @@
+use std::{fmt::Write, mem, sync::Arc, time::Duration};
+
+use bathbot_util::{EmbedBuilder, FooterBuilder, IntHasher};
+use eyre::Result;
+use futures::future::BoxFuture;
+use hashbrown::HashMap;
+use twilight_model::{
+    channel::message::{
+        component::{ActionRow, Button, ButtonStyle},
+        Component,
+    },
+    id::{
+        marker::{GuildMarker, UserMarker},
+        Id,
+    },
+};
+
+use self::question::TriviaQuestion;
+use crate::{
+    active::{BuildPage, ComponentResult, IActiveMessage},
+    commands::fun::TriviaCategory,
+    core::Context,
+    util::{interaction::InteractionComponent, Authored, ComponentExt},
+};
+
+mod question;
+
+const ANSWER_PREFIX: &str = "trivia_answer_";
+
+pub struct TriviaGame {
+    guild: Id<GuildMarker>,
+    category: Option<TriviaCategory>,
+    question: TriviaQuestion,
+    round: usize,
+    rounds: usize,
+    /// Users that answered the current question incorrectly
+    wrong: Vec<Id<UserMarker>>,
+    winner: Option<Id<UserMarker>>,
+    revealed: bool,
+    stopped: bool,
+    deferred: bool,
+    /// Correct answers of this game's participants
+    scores: HashMap<Id<UserMarker>, u32, IntHasher>,
+    msg_owner: Id<UserMarker>,
+}
+
+impl IActiveMessage for TriviaGame {
+    fn build_page(&mut self, _: Arc<Context>) -> BoxFuture<'_, Result<BuildPage>> {
+        let mut description = self.question.prompt.clone();
+
+        if self.revealed {
+            let _ = write!(
+                description,
+                "\n\n**Answer:** {}",
+                self.question.choices[self.question.correct]
+            );
+
+            if let Some(ref details) = self.question.details {
+                let _ = write!(description, "\n{details}");
+            }
+
+            match self.winner {
+                Some(winner) => {
+                    let _ = write!(description, "\n\n<@{winner}> got it right!");
+                }
+                None => description.push_str("\n\nNobody got it right"),
+            }
+        }
+
+        if !self.wrong.is_empty() {
+            description.push_str("\n\nWrong answers: ");
+
+            for (i, user) in self.wrong.iter().enumerate() {
+                if i > 0 {
+                    description.push_str(", ");
+                }
+
+                let _ = write!(description, "<@{user}>");
+            }
+        }
+
+        if self.finished() {
+            description.push_str("\n\n**Final standings:**");
+
+            let mut scores: Vec<_> = self.scores.iter().collect();
+            scores.sort_unstable_by(|(_, a), (_, b)| b.cmp(a));
+
+            if scores.is_empty() {
+                description.push_str("\nNo correct answers");
+            }
+
+            for (i, (user, score)) in scores.into_iter().enumerate() {
+                let _ = write!(description, "\n{}. <@{user}>: **{score}**", i + 1);
+            }
+        }
+
+        let title = format!(
+            "Trivia • Question {round}/{rounds} • {category}",
+            round = self.round,
+            rounds = self.rounds,
+            category = self.question.category.name(),
+        );
+
+        let footer = if self.revealed {
+            "Only the game's starter can continue"
+        } else {
+            "Everyone can answer once, the first correct answer wins"
+        };
+
+        let mut embed = EmbedBuilder::new()
+            .description(description)
+            .footer(FooterBuilder::new(footer))
+            .title(title);
+
+        if let Some(ref image) = self.question.image {
+            embed = embed.image(image);
+        }
+
+        let defer = mem::take(&mut self.deferred);
+
+        BuildPage::new(embed, defer).boxed()
+    }
+
+    fn build_components(&self) -> Vec<Component> {
+        if self.finished() {
+            return Vec::new();
+        }
+
+        let answer_buttons = self
+            .question
+            .choices
+            .iter()
+            .enumerate()
+            .map(|(i, choice)| {
+                let style = if self.revealed && i == self.question.correct {
+                    ButtonStyle::Success
+                } else {
+                    ButtonStyle::Secondary
+                };
+
+                let button = Button {
+                    custom_id: Some(format!("{ANSWER_PREFIX}{i}")),
+                    disabled: self.revealed,
+                    emoji: None,
+                    label: Some(choice.chars().take(80).collect()),
+                    style,
+                    url: None,
+                };
+
+                Component::Button(button)
+            })
+            .collect();
+
+        let answer_row = ActionRow {
+            components: answer_buttons,
+        };
+
+        let control_buttons = if self.revealed {
+            let next = Button {
+                custom_id: Some("trivia_next".to_owned()),
+                disabled: false,
+                emoji: None,
+                label: Some("Next question".to_owned()),
+                style: ButtonStyle::Primary,
+                url: None,
+            };
+
+            let stop = Button {
+                custom_id: Some("trivia_stop".to_owned()),
+                disabled: false,
+                emoji: None,
+                label: Some("Stop".to_owned()),
+                style: ButtonStyle::Danger,
+                url: None,
+            };
+
+            vec![Component::Button(next), Component::Button(stop)]
+        } else {
+            let skip = Button {
+                custom_id: Some("trivia_skip".to_owned()),
+                disabled: false,
+                emoji: None,
+                label: Some("Skip".to_owned()),
+                style: ButtonStyle::Danger,
+                url: None,
+            };
+
+            vec![Component::Button(skip)]
+        };
+
+        let control_row = ActionRow {
+            components: control_buttons,
+        };
+
+        vec![
+            Component::ActionRow(answer_row),
+            Component::ActionRow(control_row),
+        ]
+    }
+
+    fn handle_component<'a>(
+        &'a mut self,
+        ctx: Arc<Context>,
+        component: &'a mut InteractionComponent,
+    ) -> BoxFuture<'a, ComponentResult> {
+        let user_id = match component.user_id() {
+            Ok(user_id) => user_id,
+            Err(err) => return ComponentResult::Err(err).boxed(),
+        };
+
+        if let Some(suffix) = component.data.custom_id.strip_prefix(ANSWER_PREFIX) {
+            return match suffix.parse() {
+                Ok(choice) => Box::pin(self.handle_answer(ctx, user_id, choice)),
+                Err(_) => {
+                    warn!(name = %component.data.custom_id, "Invalid trivia answer");
+
+                    ComponentResult::Ignore.boxed()
+                }
+            };
+        }
+
+        if user_id != self.msg_owner || self.finished() {
+            return ComponentResult::Ignore.boxed();
+        }
+
+        match component.data.custom_id.as_str() {
+            "trivia_skip" => self.revealed = true,
+            "trivia_next" => return Box::pin(self.handle_next(ctx, component)),
+            "trivia_stop" => {
+                self.revealed = true;
+                self.stopped = true;
+            }
+            other => {
+                warn!(name = %other, ?component, "Unknown trivia component");
+
+                return ComponentResult::Ignore.boxed();
+            }
+        }
+
+        ComponentResult::BuildPage.boxed()
+    }
+
+    fn until_timeout(&self) -> Option<Duration> {
+        Some(Duration::from_secs(120))
+    }
+}
+
+impl TriviaGame {
+    pub async fn new(
+        ctx: &Context,
+        guild: Id<GuildMarker>,
+        category: Option<TriviaCategory>,
+        rounds: usize,
+        msg_owner: Id<UserMarker>,
+    ) -> Result<Self> {
+        let question = TriviaQuestion::random(ctx, category).await?;
+
+        Ok(Self {
+            guild,
+            category,
+            question,
+            round: 1,
+            rounds,
+            wrong: Vec::new(),
+            winner: None,
+            revealed: false,
+            stopped: false,
+            deferred: false,
+            scores: HashMap::default(),
+            msg_owner,
+        })
+    }
+
+    fn finished(&self) -> bool {
+        self.revealed && (self.stopped || self.round >= self.rounds)
+    }
+
+    async fn handle_answer(
+        &mut self,
+        ctx: Arc<Context>,
+        user: Id<UserMarker>,
+        choice: usize,
+    ) -> ComponentResult {
+        if self.revealed || self.wrong.contains(&user) {
+            return ComponentResult::Ignore;
+        }
+
+        if choice != self.question.correct {
+            self.wrong.push(user);
+
+            return ComponentResult::BuildPage;
+        }
+
+        self.revealed = true;
+        self.winner = Some(user);
+        *self.scores.entry(user).or_default() += 1;
+
+        if let Err(err) = ctx
+            .games()
+            .trivia_increment_score(self.guild, user, 1)
+            .await
+        {
+            warn!(?err, "Failed to increment trivia score");
+        }
+
+        ComponentResult::BuildPage
+    }
+
+    async fn handle_next(
+        &mut self,
+        ctx: Arc<Context>,
+        component: &InteractionComponent,
+    ) -> ComponentResult {
+        if let Err(err) = component.defer(&ctx).await {
+            warn!(?err, "Failed to defer next trivia button");
+        }
+
+        self.question = match TriviaQuestion::random(&ctx, self.category).await {
+            Ok(question) => question,
+            Err(err) => return ComponentResult::Err(err.wrap_err("Failed to create question")),
+        };
+
+        self.round += 1;
+        self.wrong.clear();
+        self.winner = None;
+        self.revealed = false;
+        self.deferred = true;
+
+        ComponentResult::BuildPage
+    }
+}
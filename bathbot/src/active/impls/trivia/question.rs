@@ -0,0 +1,363 @@
+use std::fmt::Write;
+
+use bathbot_model::{MedalCount, OsekaiUserEntry};
+use bathbot_psql::model::games::DbTriviaMapset;
+use bathbot_util::{constants::OSU_BASE, numbers::round};
+use eyre::{ContextCompat, Result, WrapErr};
+use rand::{seq::SliceRandom, Rng};
+use rosu_v2::prelude::GameMode;
+use time::OffsetDateTime;
+
+use crate::{
+    active::impls::higherlower::mapset_cover, commands::fun::TriviaCategory, core::Context,
+    embeds::ModsFormatter, manager::redis::RedisData,
+};
+
+/// Amount of mapsets that are fetched to pick distinct mappers from
+const MAPSET_POOL: usize = 20;
+
+/// Only consider users within this rank of the medal ranking
+const MEDAL_RANK_LIMIT: usize = 1000;
+
+const CHOICES: usize = 4;
+
+/// How often generating a question is attempted before giving up
+const GENERATION_ATTEMPTS: u32 = 5;
+
+pub(super) struct TriviaQuestion {
+    pub category: TriviaCategory,
+    pub prompt: String,
+    pub image: Option<String>,
+    pub choices: Vec<String>,
+    pub correct: usize,
+    /// Additional info that is shown once the answer is revealed
+    pub details: Option<String>,
+}
+
+impl TriviaQuestion {
+    /// Generate a question of the given category or of a random one.
+    ///
+    /// Generation depends on random data so failed attempts are retried a few
+    /// times before giving up.
+    pub(super) async fn random(ctx: &Context, category: Option<TriviaCategory>) -> Result<Self> {
+        let mut attempt = 1;
+
+        loop {
+            match Self::generate(ctx, category).await {
+                Ok(question) => return Ok(question),
+                Err(err) if attempt < GENERATION_ATTEMPTS => {
+                    debug!(attempt, ?err, "Failed to generate trivia question");
+                    attempt += 1;
+                }
+                Err(err) => return Err(err),
+            }
+        }
+    }
+
+    async fn generate(ctx: &Context, category: Option<TriviaCategory>) -> Result<Self> {
+        let category = category.unwrap_or_else(|| {
+            let idx = rand::thread_rng().gen_range(0..TriviaCategory::ALL.len());
+
+            TriviaCategory::ALL[idx]
+        });
+
+        match category {
+            TriviaCategory::Mapper => Self::mapper(ctx).await,
+            TriviaCategory::RankedYear => Self::ranked_year(ctx).await,
+            TriviaCategory::Medals => Self::medals(ctx).await,
+            TriviaCategory::ScorePp => Self::score_pp(ctx).await,
+        }
+    }
+
+    async fn mapper(ctx: &Context) -> Result<Self> {
+        let mapsets = ctx
+            .games()
+            .trivia_mapsets(MAPSET_POOL)
+            .await
+            .wrap_err("Failed to get mapsets")?;
+
+        let mut picked: Vec<DbTriviaMapset> = Vec::with_capacity(CHOICES);
+
+        for mapset in mapsets {
+            if picked.iter().all(|picked| picked.user_id != mapset.user_id) {
+                picked.push(mapset);
+
+                if picked.len() == CHOICES {
+                    break;
+                }
+            }
+        }
+
+        if picked.len() < CHOICES {
+            bail!("Not enough distinct mappers");
+        }
+
+        let correct = rand::thread_rng().gen_range(0..CHOICES);
+        let mapset = &picked[correct];
+
+        let prompt = format!(
+            "Who mapped [{artist} - {title}]({OSU_BASE}s/{mapset_id})?",
+            artist = mapset.artist,
+            title = mapset.title,
+            mapset_id = mapset.mapset_id,
+        );
+
+        let image = mapset_cover(mapset.mapset_id as u32);
+        let choices = picked.iter().map(|mapset| mapset.creator.clone()).collect();
+
+        Ok(Self {
+            category: TriviaCategory::Mapper,
+            prompt,
+            image: Some(image),
+            choices,
+            correct,
+            details: None,
+        })
+    }
+
+    async fn ranked_year(ctx: &Context) -> Result<Self> {
+        let mapset = ctx
+            .games()
+            .trivia_mapsets(1)
+            .await
+            .wrap_err("Failed to get mapsets")?
+            .pop()
+            .wrap_err("No mapsets stored")?;
+
+        let ranked_date = mapset.ranked_date.wrap_err("Missing ranked date")?;
+        let year = ranked_date.year();
+        let current_year = OffsetDateTime::now_utc().year();
+
+        let mut years = vec![year];
+
+        {
+            let mut rng = rand::thread_rng();
+            let min = (year - 5).max(2007);
+            let max = (year + 5).min(current_year).max(min + CHOICES as i32);
+
+            while years.len() < CHOICES {
+                let year = rng.gen_range(min..=max);
+
+                if !years.contains(&year) {
+                    years.push(year);
+                }
+            }
+
+            years.shuffle(&mut rng);
+        }
+
+        let correct = years
+            .iter()
+            .position(|&candidate| candidate == year)
+            .wrap_err("Missing correct year")?;
+
+        let prompt = format!(
+            "In which year was [{artist} - {title}]({OSU_BASE}s/{mapset_id}) \
+            by {creator} ranked?",
+            artist = mapset.artist,
+            title = mapset.title,
+            mapset_id = mapset.mapset_id,
+            creator = mapset.creator,
+        );
+
+        let details = format!(
+            "It was ranked on {month} {day}, {year}",
+            month = ranked_date.month(),
+            day = ranked_date.day(),
+        );
+
+        Ok(Self {
+            category: TriviaCategory::RankedYear,
+            prompt,
+            image: Some(mapset_cover(mapset.mapset_id as u32)),
+            choices: years.iter().map(i32::to_string).collect(),
+            correct,
+            details: Some(details),
+        })
+    }
+
+    async fn medals(ctx: &Context) -> Result<Self> {
+        let ranking = ctx
+            .redis()
+            .osekai_ranking::<MedalCount>()
+            .await
+            .wrap_err("Failed to get cached medal count ranking")?
+            .into_original();
+
+        let pool = &ranking[..ranking.len().min(MEDAL_RANK_LIMIT)];
+
+        let mut entries = Vec::with_capacity(CHOICES);
+
+        {
+            let mut rng = rand::thread_rng();
+
+            // Users with the same medal count would make for an ambiguous answer
+            for _ in 0..100 {
+                let entry = pool.choose(&mut rng).wrap_err("Empty medal ranking")?;
+
+                if entries.iter().all(|picked: &&OsekaiUserEntry| {
+                    picked.user_id != entry.user_id && picked.medal_count != entry.medal_count
+                }) {
+                    entries.push(entry);
+
+                    if entries.len() == CHOICES {
+                        break;
+                    }
+                }
+            }
+        }
+
+        if entries.len() < CHOICES {
+            bail!("Not enough users with distinct medal counts");
+        }
+
+        let (correct, _) = entries
+            .iter()
+            .enumerate()
+            .max_by_key(|(_, entry)| entry.medal_count)
+            .wrap_err("Missing users")?;
+
+        let choices = entries
+            .iter()
+            .map(|entry| entry.username.as_str().to_owned())
+            .collect();
+
+        let mut details = String::new();
+
+        for entry in entries.iter() {
+            let _ = writeln!(
+                details,
+                ":flag_{country}: {name}: **{count}** medals",
+                country = entry.country_code.to_ascii_lowercase(),
+                name = entry.username,
+                count = entry.medal_count,
+            );
+        }
+
+        details.pop();
+
+        Ok(Self {
+            category: TriviaCategory::Medals,
+            prompt: "Who of these players has the most medals?".to_owned(),
+            image: None,
+            choices,
+            correct,
+            details: Some(details),
+        })
+    }
+
+    async fn score_pp(ctx: &Context) -> Result<Self> {
+        let rank = rand::thread_rng().gen_range(1..=5000_u32);
+        let page = ((rank - 1) / 50) + 1;
+        let idx = ((rank - 1) % 50) as usize;
+
+        let ranking = ctx
+            .redis()
+            .pp_ranking(GameMode::Osu, page, None)
+            .await
+            .wrap_err("Failed to get cached pp ranking")?;
+
+        let (user_id, username) = match ranking {
+            RedisData::Original(mut ranking) => {
+                let user = (idx < ranking.ranking.len())
+                    .then(|| ranking.ranking.swap_remove(idx))
+                    .wrap_err("Missing user in ranking")?;
+
+                (user.user_id, user.username.as_str().to_owned())
+            }
+            RedisData::Archive(ranking) => {
+                let user = ranking
+                    .ranking
+                    .get(idx)
+                    .wrap_err("Missing user in ranking")?;
+
+                (user.user_id, user.username.as_str().to_owned())
+            }
+        };
+
+        let mut scores = ctx
+            .osu()
+            .user_scores(user_id)
+            .limit(100)
+            .mode(GameMode::Osu)
+            .best()
+            .await
+            .wrap_err("Failed to get user scores")?;
+
+        scores.retain(|score| score.pp.is_some());
+
+        let score = {
+            let mut rng = rand::thread_rng();
+            let idx = rng.gen_range(0..scores.len().max(1));
+
+            (idx < scores.len())
+                .then(|| scores.swap_remove(idx))
+                .wrap_err("User has no scores with pp")?
+        };
+
+        let map = ctx
+            .osu_map()
+            .map_slim(score.map_id)
+            .await
+            .wrap_err("Failed to get beatmap")?;
+
+        let pp = score.pp.unwrap_or(0.0).round() as u32;
+        let mut values = vec![pp];
+
+        {
+            let mut rng = rand::thread_rng();
+
+            let max_offset = (pp / 3).max(20);
+
+            while values.len() < CHOICES {
+                let offset = rng.gen_range(5..=max_offset);
+
+                let value = if rng.gen_bool(0.5) && offset < pp {
+                    pp - offset
+                } else {
+                    pp + offset
+                };
+
+                // Choices should be clearly distinguishable
+                if values.iter().all(|&other| value.abs_diff(other) >= 5) {
+                    values.push(value);
+                }
+            }
+
+            values.shuffle(&mut rng);
+        }
+
+        let correct = values
+            .iter()
+            .position(|&value| value == pp)
+            .wrap_err("Missing correct pp")?;
+
+        let prompt = format!(
+            "How much pp is this score of **{username}** worth?\n\
+            [{artist} - {title} [{version}]]({OSU_BASE}b/{map_id}) **{mods}**\n\
+            **{acc}%** • **{combo}x** • **{misses}** miss(es)",
+            artist = map.artist(),
+            title = map.title(),
+            version = map.version(),
+            map_id = map.map_id(),
+            mods = ModsFormatter::new(&score.mods),
+            acc = round(score.accuracy),
+            combo = score.max_combo,
+            misses = score.statistics.count_miss,
+        );
+
+        let details = format!(
+            "The score is worth **{}pp**",
+            round(score.pp.unwrap_or(0.0))
+        );
+
+        Ok(Self {
+            category: TriviaCategory::ScorePp,
+            prompt,
+            image: Some(mapset_cover(map.mapset_id())),
+            choices: values.iter().map(|pp| format!("{pp}pp")).collect(),
+            correct,
+            details: Some(details),
+        })
+    }
+}
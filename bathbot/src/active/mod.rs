@@ -4,11 +4,12 @@ use std::{
     time::{Duration, Instant},
 };
 
+use bathbot_model::HlGameSnapshot;
 use bathbot_util::{modal::ModalBuilder, EmbedBuilder, IntHasher, MessageBuilder};
 use enum_dispatch::enum_dispatch;
 use eyre::{Report, Result, WrapErr};
 use flexmap::tokio::TokioMutexMap;
-use futures::future::BoxFuture;
+use futures::{future::BoxFuture, StreamExt};
 use tokio::sync::watch::Sender;
 use twilight_model::{
     channel::message::Component,
@@ -22,20 +23,22 @@ pub use self::origin::ActiveMessageOriginError;
 use self::{
     builder::ActiveMessagesBuilder,
     impls::{
-        BackgroundGameSetup, BadgesPagination, BookmarksPagination, CachedRender,
-        ChangelogPagination, CompareMostPlayedPagination, CompareScoresPagination,
-        CompareTopPagination, EditOnTimeout, HelpInteractionCommand, HelpPrefixMenu,
-        HigherLowerGame, LeaderboardPagination, MapPagination, MapSearchPagination,
-        MatchComparePagination, MedalCountPagination, MedalRarityPagination,
-        MedalsCommonPagination, MedalsListPagination, MedalsMissingPagination,
-        MedalsRecentPagination, MostPlayedPagination, NoChokePagination, OsuStatsBestPagination,
-        OsuStatsPlayersPagination, OsuStatsScoresPagination, PopularMappersPagination,
-        PopularMapsPagination, PopularMapsetsPagination, PopularModsPagination, ProfileMenu,
-        RankingCountriesPagination, RankingPagination, RecentListPagination, RegionTopPagination,
-        RenderSettingsActive, ScoresMapPagination, ScoresServerPagination, ScoresUserPagination,
-        SettingsImport, SimulateComponents, SkinsPagination, SlashCommandsPagination,
-        SnipeCountryListPagination, SnipeDifferencePagination, SnipePlayerListPagination,
-        TopIfPagination, TopPagination,
+        BackgroundGameSetup, BadgesPagination, BookmarksPagination, BwsListPagination, CachedRender,
+        ChangelogPagination, CollectionPagination, CompareMostPlayedPagination,
+        CompareScoresPagination, CompareTopPagination, EditOnTimeout, HelpInteractionCommand,
+        HelpPrefixMenu, HigherLowerGame, HigherLowerVoting, LeaderboardPagination, MapPagination,
+        MapSearchPagination, MappoolPagination, MatchComparePagination, MedalCountPagination,
+        MedalRarityPagination, MedalsCommonPagination, MedalsListPagination,
+        MedalsMissingPagination, MedalsPlannerPagination, MedalsRecentPagination, MinesweeperGame,
+        MostPlayedPagination, NoChokePagination, OsuStatsBestPagination, OsuStatsPlayersPagination,
+        OsuStatsScoresPagination, PopularMappersPagination, PopularMapsPagination,
+        PopularMapsetsPagination, PopularModsPagination, ProfileMenu, RankingCountriesPagination,
+        RankingPagination, RecentListPagination, RegionTopPagination, RenderSettingsActive,
+        ScoresMapPagination, ScoresServerPagination, ScoresUserPagination, SettingsImport,
+        SimulateComponents, SkinsPagination, SlashCommandsPagination, SnipeCountryListPagination,
+        SnipeDifferencePagination, SnipePlayerListPagination, TopIfPagination, TopPagination,
+        TournamentMatchesPagination, TournamentStandingsPagination, TournamentTeamsPagination,
+        TriviaGame,
     },
 };
 use crate::{
@@ -57,8 +60,10 @@ pub enum ActiveMessage {
     BackgroundGameSetup,
     BadgesPagination,
     BookmarksPagination,
+    BwsListPagination,
     CachedRender,
     ChangelogPagination,
+    CollectionPagination,
     CompareMostPlayedPagination,
     CompareScoresPagination,
     CompareTopPagination,
@@ -66,16 +71,20 @@ pub enum ActiveMessage {
     HelpInteractionCommand,
     HelpPrefixMenu,
     HigherLowerGame,
+    HigherLowerVoting,
     LeaderboardPagination,
     MapPagination,
     MapSearchPagination,
+    MappoolPagination,
     MatchComparePagination,
     MedalCountPagination,
     MedalRarityPagination,
     MedalsCommonPagination,
     MedalsListPagination,
     MedalsMissingPagination,
+    MedalsPlannerPagination,
     MedalsRecentPagination,
+    MinesweeperGame,
     MostPlayedPagination,
     NoChokePagination,
     OsuStatsBestPagination,
@@ -103,6 +112,10 @@ pub enum ActiveMessage {
     SnipePlayerListPagination,
     TopPagination,
     TopIfPagination,
+    TournamentMatchesPagination,
+    TournamentStandingsPagination,
+    TournamentTeamsPagination,
+    TriviaGame,
 }
 
 struct FullActiveMessage {
@@ -331,6 +344,21 @@ impl ActiveMessages {
         self.inner.clear().await
     }
 
+    /// Snapshots of all running higherlower games so they can be resumed
+    /// after a restart.
+    pub async fn higherlower_snapshots(&self) -> Vec<HlGameSnapshot> {
+        let mut snapshots = Vec::new();
+        let mut stream = self.inner.iter();
+
+        while let Some(guard) = stream.next().await {
+            if let ActiveMessage::HigherLowerGame(ref game) = guard.value().active_msg {
+                snapshots.extend(game.snapshot(*guard.key()));
+            }
+        }
+
+        snapshots
+    }
+
     pub async fn remove(&self, msg: Id<MessageMarker>) {
         self.remove_full(msg).await;
     }
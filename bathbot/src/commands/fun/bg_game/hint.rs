@@ -1,10 +1,14 @@
 use std::sync::Arc;
 
-use bathbot_util::{constants::GENERAL_ISSUE, MessageBuilder};
+use bathbot_util::MessageBuilder;
 use eyre::Result;
 use twilight_model::{channel::Message, guild::Permissions};
 
-use crate::{core::buckets::BucketName, util::ChannelExt, Context};
+use crate::{
+    core::{buckets::BucketName, commands::CommandOrigin, locale::ErrorKey},
+    util::ChannelExt,
+    Context,
+};
 
 pub async fn hint(
     ctx: Arc<Context>,
@@ -29,7 +33,9 @@ pub async fn hint(
                 msg.create_message(&ctx, builder, permissions).await?;
             }
             Err(err) => {
-                let _ = msg.error(&ctx, GENERAL_ISSUE).await;
+                let _ = CommandOrigin::from(msg)
+                    .error_localized(&ctx, ErrorKey::General)
+                    .await;
 
                 return Err(err.wrap_err("Failed to get hint"));
             }
@@ -1,12 +1,9 @@
 use std::sync::Arc;
 
 use bathbot_macros::{command, SlashCommand};
-use bathbot_model::Effects;
+use bathbot_model::{Effects, MapsetTags};
 use bathbot_psql::model::games::DbMapTagsParams;
-use bathbot_util::{
-    constants::{GENERAL_ISSUE, INVALID_ACTION_FOR_CHANNEL_TYPE, THREADS_UNAVAILABLE},
-    CowUtils, MessageBuilder,
-};
+use bathbot_util::{constants::INVALID_ACTION_FOR_CHANNEL_TYPE, CowUtils, MessageBuilder};
 use eyre::{Report, Result};
 use rosu_v2::prelude::GameMode;
 use twilight_http::{api_error::ApiError, error::ErrorType};
@@ -19,10 +16,11 @@ use twilight_model::{
 use self::{bigger::*, hint::*, rankings::*, skip::*, stop::*};
 use crate::{
     active::{
-        impls::{BackgroundGame, BackgroundGameSetup},
+        impls::{BackgroundGame, BackgroundGameSetup, BgGameKind, BgGameSettings},
         ActiveMessages,
     },
     commands::ThreadChannel,
+    core::locale::ErrorKey,
     util::{interaction::InteractionCommand, Authored, ChannelExt, InteractionCommandExt},
     Context,
 };
@@ -52,12 +50,14 @@ pub async fn prefix_backgroundgame(
         None | Some("help") => {
             let content = "Use `/bg` to start a new background guessing game.\n\
                 Given part of a map's background, try to guess the **title** of the map's song.\n\
+                With the `osu! patterns` mode you're shown a few seconds of the map's hit objects instead.\n\
                 You don't need to guess content in parentheses `(...)` or content after `ft.` or `feat.`.\n\n\
                 Use these prefix commands to initiate with the game:\n\
                 • `<bg s[kip]` / `<bg r[esolve]`: Resolve the current background and \
                 give a new one with the same tag specs.\n\
                 • `<bg h[int]`: Receive a hint (can be used multiple times).\n\
-                • `<bg b[igger]`: Increase the radius of the displayed image (can be used multiple times).\n\
+                • `<bg b[igger]`: Increase the radius of the displayed image or show more hit objects \
+                (can be used multiple times).\n\
                 • `<bg stop`: Resolve the current background and stop the game.
                 • `<bg l[eaderboard] s[erver]`: Check out the global leaderboard for \
                 amount of correct guesses. If `server` or `s` is added at the end, \
@@ -99,12 +99,14 @@ pub async fn prefix_backgroundgame(
     desc = "Start a new background guessing game",
     help = "Start a new background guessing game.\n\
     Given part of a map's background, try to guess the **title** of the map's song.\n\
+    With the `osu! patterns` mode you're shown a few seconds of the map's hit objects instead.\n\
     You don't need to guess content in parentheses `(...)` or content after `ft.` or `feat.`.\n\n\
     Use these prefix commands to initiate with the game:\n\
     • `<bg s[kip]` / `<bg r[esolve]`: Resolve the current background and \
     give a new one with the same tag specs.\n\
     • `<bg h[int]`: Receive a hint (can be used multiple times).\n\
-    • `<bg b[igger]`: Increase the radius of the displayed image (can be used multiple times).\n\
+    • `<bg b[igger]`: Increase the radius of the displayed image or show more hit objects \
+    (can be used multiple times).\n\
     • `<bg stop`: Resolve the current background and stop the game.
     • `<bg l[eaderboard] s[erver]`: Check out the global leaderboard for \
     amount of correct guesses. If `server` or `s` is added at the end, \
@@ -132,6 +134,8 @@ pub enum BgGameMode {
     Osu,
     #[option(name = "mania", value = "mania")]
     Mania,
+    #[option(name = "osu! patterns", value = "patterns")]
+    Patterns,
 }
 
 #[derive(Copy, Clone, Debug, CommandOption, CreateOption)]
@@ -160,6 +164,16 @@ impl Default for GameDifficulty {
     }
 }
 
+impl From<u8> for GameDifficulty {
+    fn from(difficulty: u8) -> Self {
+        match difficulty {
+            1 => Self::Hard,
+            2 => Self::Impossible,
+            _ => Self::Normal,
+        }
+    }
+}
+
 async fn slash_bg(ctx: Arc<Context>, mut command: InteractionCommand) -> Result<()> {
     let Bg {
         difficulty,
@@ -195,7 +209,9 @@ async fn slash_bg(ctx: Arc<Context>, mut command: InteractionCommand) -> Result<
 
     if let Some(ThreadChannel::Thread) = thread {
         if command.guild_id.is_none() {
-            command.error_callback(&ctx, THREADS_UNAVAILABLE).await?;
+            command
+                .error_callback_localized(&ctx, ErrorKey::ThreadsUnavailable)
+                .await?;
 
             return Ok(());
         }
@@ -236,25 +252,27 @@ async fn slash_bg(ctx: Arc<Context>, mut command: InteractionCommand) -> Result<
         match create_fut.await {
             Ok(res) => channel = res.model().await?.id,
             Err(err) => {
-                let content = match err.kind() {
+                let key = match err.kind() {
                     ErrorType::Response {
                         error: ApiError::General(err),
                         ..
                     } => match err.code {
-                        INVALID_ACTION_FOR_CHANNEL_TYPE => Some(THREADS_UNAVAILABLE),
+                        INVALID_ACTION_FOR_CHANNEL_TYPE => Some(ErrorKey::ThreadsUnavailable),
                         _ => None,
                     },
                     _ => None,
                 };
 
-                match content {
-                    Some(content) => {
-                        command.error_callback(&ctx, content).await?;
+                match key {
+                    Some(key) => {
+                        command.error_callback_localized(&ctx, key).await?;
 
                         return Ok(());
                     }
                     None => {
-                        let _ = command.error_callback(&ctx, GENERAL_ISSUE).await;
+                        let _ = command
+                            .error_callback_localized(&ctx, ErrorKey::General)
+                            .await;
 
                         return Err(Report::new(err).wrap_err("failed to create thread"));
                     }
@@ -297,22 +315,34 @@ async fn slash_bg(ctx: Arc<Context>, mut command: InteractionCommand) -> Result<
                     .await
             }
         }
-        Some(BgGameMode::Mania) => {
-            let params = DbMapTagsParams::new(GameMode::Mania);
+        Some(mode @ (BgGameMode::Mania | BgGameMode::Patterns)) => {
+            let (params, kind) = match mode {
+                BgGameMode::Mania => (
+                    DbMapTagsParams::new(GameMode::Mania),
+                    BgGameKind::Background,
+                ),
+                _ => (DbMapTagsParams::new(GameMode::Osu), BgGameKind::Pattern),
+            };
 
             let entries = match ctx.games().bggame_tags(params).await {
                 Ok(entries) => entries,
                 Err(err) => {
-                    let _ = command.error(&ctx, GENERAL_ISSUE).await;
+                    let _ = command.error_localized(&ctx, ErrorKey::General).await;
 
-                    return Err(err.wrap_err("failed to get all tagged mania mapsets"));
+                    return Err(err.wrap_err("failed to get all tagged mapsets"));
                 }
             };
 
-            let content = format!(
-                "Starting mania background guessing game with {} different backgrounds",
-                entries.tags.len()
-            );
+            let content = match kind {
+                BgGameKind::Background => format!(
+                    "Starting mania background guessing game with {} different backgrounds",
+                    entries.tags.len()
+                ),
+                BgGameKind::Pattern => format!(
+                    "Starting pattern guessing game with {} different mapsets",
+                    entries.tags.len()
+                ),
+            };
 
             let builder = MessageBuilder::new().embed(content);
 
@@ -325,13 +355,15 @@ async fn slash_bg(ctx: Arc<Context>, mut command: InteractionCommand) -> Result<
                 command.callback(&ctx, builder, false).await?;
             }
 
-            let game_fut = BackgroundGame::new(
-                Arc::clone(&ctx),
-                channel,
-                entries,
-                Effects::empty(),
+            let settings = BgGameSettings {
+                kind,
+                included: MapsetTags::empty(),
+                excluded: MapsetTags::empty(),
+                effects: Effects::empty(),
                 difficulty,
-            );
+            };
+
+            let game_fut = BackgroundGame::new(Arc::clone(&ctx), channel, entries, settings);
 
             ctx.bg_games().own(channel).await.insert(game_fut.await);
 
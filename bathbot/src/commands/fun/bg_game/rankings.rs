@@ -1,13 +1,14 @@
 use std::{collections::BTreeMap, sync::Arc};
 
 use bathbot_model::{RankingEntries, RankingEntry, RankingKind};
-use bathbot_util::{constants::GENERAL_ISSUE, IntHasher};
+use bathbot_util::IntHasher;
 use eyre::Result;
 use hashbrown::HashSet;
 use twilight_model::{channel::Message, id::Id};
 
 use crate::{
     active::{impls::RankingPagination, ActiveMessages},
+    core::{commands::CommandOrigin, locale::ErrorKey},
     util::ChannelExt,
     Context,
 };
@@ -16,7 +17,9 @@ pub async fn leaderboard(ctx: Arc<Context>, msg: &Message, global: bool) -> Resu
     let mut scores = match ctx.games().bggame_leaderboard().await {
         Ok(scores) => scores,
         Err(err) => {
-            let _ = msg.error(&ctx, GENERAL_ISSUE).await;
+            let _ = CommandOrigin::from(msg)
+                .error_localized(&ctx, ErrorKey::General)
+                .await;
 
             return Err(err.wrap_err("failed to get bggame scores"));
         }
@@ -1,10 +1,14 @@
 use std::sync::Arc;
 
-use bathbot_util::constants::{GENERAL_ISSUE, INVITE_LINK};
+use bathbot_util::constants::INVITE_LINK;
 use eyre::Result;
 use twilight_model::channel::Message;
 
-use crate::{core::buckets::BucketName, util::ChannelExt, Context};
+use crate::{
+    core::{buckets::BucketName, commands::CommandOrigin, locale::ErrorKey},
+    util::ChannelExt,
+    Context,
+};
 
 pub async fn skip(ctx: Arc<Context>, msg: &Message) -> Result<()> {
     if let Some(cooldown) = ctx.check_ratelimit(msg.author.id, BucketName::BgSkip) {
@@ -25,7 +29,9 @@ pub async fn skip(ctx: Arc<Context>, msg: &Message) -> Result<()> {
         Some(game) => match game.restart() {
             Ok(_) => {}
             Err(err) => {
-                let _ = msg.error(&ctx, GENERAL_ISSUE).await;
+                let _ = CommandOrigin::from(msg)
+                    .error_localized(&ctx, ErrorKey::General)
+                    .await;
 
                 return Err(err.wrap_err("Failed to restart game"));
             }
@@ -9,12 +9,13 @@ use twilight_model::{channel::ReactionType, gateway::event::Event, http::attachm
 
 use super::ReactionWrapper;
 use crate::{
+    core::{commands::CommandOrigin, locale::ErrorKey},
     database::MapsetTagWrapper,
     games::bg::MapsetTags,
     util::{
         constants::{
             common_literals::{MANIA, OSU},
-            GENERAL_ISSUE, OSU_BASE, OWNER_USER_ID,
+            OSU_BASE, OWNER_USER_ID,
         },
         send_reaction, CowUtils, Emote,
     },
@@ -123,7 +124,9 @@ async fn bgtagsmanual(ctx: Arc<Context>, data: CommandData) -> Result<()> {
             msg.create_message(&ctx, builder).await?;
         }
         Err(err) => {
-            let _ = msg.error(&ctx, GENERAL_ISSUE).await;
+            let _ = CommandOrigin::from(msg)
+                .error_localized(&ctx, ErrorKey::General)
+                .await;
 
             return Err(err);
         }
@@ -169,7 +172,9 @@ async fn bgtags(ctx: Arc<Context>, data: CommandData) -> Result<()> {
     let mut untagged = match ctx.psql().get_all_tags_mapset(mode).await {
         Ok(tags) => tags.iter().any(|tag| tag.untagged()),
         Err(err) => {
-            let _ = msg.error(&ctx, GENERAL_ISSUE).await;
+            let _ = CommandOrigin::from(msg)
+                .error_localized(&ctx, ErrorKey::General)
+                .await;
 
             return Err(err);
         }
@@ -215,7 +220,9 @@ async fn bgtags(ctx: Arc<Context>, data: CommandData) -> Result<()> {
                 tags
             }
             Err(err) => {
-                let _ = msg.error(&ctx, GENERAL_ISSUE).await;
+                let _ = CommandOrigin::from(msg)
+                    .error_localized(&ctx, ErrorKey::General)
+                    .await;
 
                 return Err(err);
             }
@@ -364,7 +371,9 @@ async fn bgtags(ctx: Arc<Context>, data: CommandData) -> Result<()> {
                 }
             }
             Err(err) => {
-                let _ = msg.error(&ctx, GENERAL_ISSUE).await;
+                let _ = CommandOrigin::from(msg)
+                    .error_localized(&ctx, ErrorKey::General)
+                    .await;
 
                 return Err(err);
             }
@@ -1,11 +1,12 @@
 use std::{
     collections::{BTreeMap, HashSet},
+    future::Future,
     sync::Arc,
 };
 
 use bathbot_macros::SlashCommand;
 use bathbot_model::{HlVersion, RankingEntries, RankingEntry, RankingKind};
-use bathbot_util::{constants::GENERAL_ISSUE, IntHasher};
+use bathbot_util::{IntHasher, MessageBuilder};
 use eyre::Result;
 use rosu_v2::prelude::GameMode;
 use twilight_interactions::command::{CommandModel, CreateCommand};
@@ -13,10 +14,11 @@ use twilight_model::id::Id;
 
 use crate::{
     active::{
-        impls::{HigherLowerGame, RankingPagination},
+        impls::{HigherLowerChannelGame, HigherLowerGame, HlMapAttr, RankingPagination},
         ActiveMessages,
     },
     commands::GameModeOption,
+    core::locale::ErrorKey,
     util::{interaction::InteractionCommand, Authored, InteractionCommandExt},
     Context,
 };
@@ -28,6 +30,14 @@ pub enum HigherLower {
     ScorePp(HigherLowerScorePp),
     #[command(name = "farm")]
     FarmMaps(HigherLowerFarmMaps),
+    #[command(name = "stars")]
+    StarRating(HigherLowerStarRating),
+    #[command(name = "bpm")]
+    Bpm(HigherLowerBpm),
+    #[command(name = "playcount")]
+    Playcount(HigherLowerPlaycount),
+    #[command(name = "rank")]
+    Rank(HigherLowerRank),
     #[command(name = "leaderboard")]
     Leaderboard(HigherLowerLeaderboard),
 }
@@ -39,11 +49,15 @@ pub enum HigherLower {
     help = "Is the score's pp value higher or lower?\n\
     The players are chosen randomly from the top 5,000 and the top score \
     is chosen randomly as well but the higher the current score is, the more \
-    likely it is that the next pp value is close to the previous pp."
+    likely it is that the next pp value is close to the previous pp.\n\
+    With `multiplayer` enabled, everyone in the channel can vote on each round and \
+    players are eliminated as soon as they guess wrong."
 )]
 pub struct HigherLowerScorePp {
     #[command(desc = "Specify a gamemode")]
     mode: Option<GameModeOption>,
+    #[command(desc = "Let everyone in the channel vote on each round")]
+    multiplayer: Option<bool>,
 }
 
 #[derive(CommandModel, CreateCommand)]
@@ -53,9 +67,76 @@ pub struct HigherLowerScorePp {
     help = "Is the amount of times the map appears in top scores higher or lower?\n\
     All counts are provided by [osutracker](https://osutracker.com) which only includes a portion \
     of the actual data but it should be representative, at least for >300pp scores.\n\
-    The maps are chosen randomly based on [this weight function](https://www.desmos.com/calculator/u4jt9t4jnj)."
+    The maps are chosen randomly based on [this weight function](https://www.desmos.com/calculator/u4jt9t4jnj).\n\
+    With `multiplayer` enabled, everyone in the channel can vote on each round and \
+    players are eliminated as soon as they guess wrong."
 )]
-pub struct HigherLowerFarmMaps;
+pub struct HigherLowerFarmMaps {
+    #[command(desc = "Let everyone in the channel vote on each round")]
+    multiplayer: Option<bool>,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "stars",
+    desc = "Is the map's star rating higher or lower?",
+    help = "Is the map's star rating higher or lower?\n\
+    Maps are chosen randomly among ranked, approved, and loved osu!standard maps \
+    but the higher the current score is, the closer the star ratings will be.\n\
+    With `multiplayer` enabled, everyone in the channel can vote on each round and \
+    players are eliminated as soon as they guess wrong."
+)]
+pub struct HigherLowerStarRating {
+    #[command(desc = "Let everyone in the channel vote on each round")]
+    multiplayer: Option<bool>,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "bpm",
+    desc = "Is the map's BPM higher or lower?",
+    help = "Is the map's BPM higher or lower?\n\
+    Maps are chosen randomly among ranked, approved, and loved osu!standard maps \
+    but the higher the current score is, the closer the BPMs will be.\n\
+    With `multiplayer` enabled, everyone in the channel can vote on each round and \
+    players are eliminated as soon as they guess wrong."
+)]
+pub struct HigherLowerBpm {
+    #[command(desc = "Let everyone in the channel vote on each round")]
+    multiplayer: Option<bool>,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "playcount",
+    desc = "Is the mapset's playcount higher or lower?",
+    help = "Is the mapset's playcount higher or lower?\n\
+    Maps are chosen randomly among ranked, approved, and loved osu!standard maps.\n\
+    With `multiplayer` enabled, everyone in the channel can vote on each round and \
+    players are eliminated as soon as they guess wrong."
+)]
+pub struct HigherLowerPlaycount {
+    #[command(desc = "Let everyone in the channel vote on each round")]
+    multiplayer: Option<bool>,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "rank",
+    desc = "Is the player's global rank higher or lower?",
+    help = "Is the player's global rank higher or lower?\n\
+    \"Higher\" refers to the rank's number so #1000 is higher than #10.\n\
+    The players are chosen randomly from the top 5,000 but the higher the current \
+    score is, the closer the ranks will be.\n\
+    With `multiplayer` enabled, everyone in the channel can vote on each round and \
+    players are eliminated as soon as they guess wrong."
+)]
+pub struct HigherLowerRank {
+    #[command(desc = "Specify a gamemode")]
+    mode: Option<GameModeOption>,
+    #[command(desc = "Let everyone in the channel vote on each round")]
+    multiplayer: Option<bool>,
+}
 
 #[derive(CommandModel, CreateCommand)]
 #[command(
@@ -78,9 +159,70 @@ async fn slash_higherlower(ctx: Arc<Context>, mut command: InteractionCommand) -
                 None => ctx.user_config().mode(user).await?.unwrap_or(GameMode::Osu),
             };
 
-            HigherLowerGame::new_score_pp(&ctx, mode, user).await
+            if args.multiplayer == Some(true) {
+                let game_fut = HigherLowerChannelGame::new_score_pp(&ctx, mode);
+
+                return higherlower_multiplayer(ctx, command, game_fut).await;
+            }
+
+            HigherLowerGame::new_score_pp(&ctx, mode, user, command.channel_id).await
+        }
+        HigherLower::FarmMaps(args) => {
+            if args.multiplayer == Some(true) {
+                let game_fut = HigherLowerChannelGame::new_farm_maps(&ctx);
+
+                return higherlower_multiplayer(ctx, command, game_fut).await;
+            }
+
+            HigherLowerGame::new_farm_maps(&ctx, user, command.channel_id).await
+        }
+        HigherLower::StarRating(args) => {
+            let attr = HlMapAttr::Stars;
+
+            if args.multiplayer == Some(true) {
+                let game_fut = HigherLowerChannelGame::new_map_attr(&ctx, attr);
+
+                return higherlower_multiplayer(ctx, command, game_fut).await;
+            }
+
+            HigherLowerGame::new_map_attr(&ctx, attr, user, command.channel_id).await
+        }
+        HigherLower::Bpm(args) => {
+            let attr = HlMapAttr::Bpm;
+
+            if args.multiplayer == Some(true) {
+                let game_fut = HigherLowerChannelGame::new_map_attr(&ctx, attr);
+
+                return higherlower_multiplayer(ctx, command, game_fut).await;
+            }
+
+            HigherLowerGame::new_map_attr(&ctx, attr, user, command.channel_id).await
+        }
+        HigherLower::Playcount(args) => {
+            let attr = HlMapAttr::Playcount;
+
+            if args.multiplayer == Some(true) {
+                let game_fut = HigherLowerChannelGame::new_map_attr(&ctx, attr);
+
+                return higherlower_multiplayer(ctx, command, game_fut).await;
+            }
+
+            HigherLowerGame::new_map_attr(&ctx, attr, user, command.channel_id).await
+        }
+        HigherLower::Rank(args) => {
+            let mode = match args.mode.map(GameMode::from) {
+                Some(mode) => mode,
+                None => ctx.user_config().mode(user).await?.unwrap_or(GameMode::Osu),
+            };
+
+            if args.multiplayer == Some(true) {
+                let game_fut = HigherLowerChannelGame::new_player_rank(&ctx, mode);
+
+                return higherlower_multiplayer(ctx, command, game_fut).await;
+            }
+
+            HigherLowerGame::new_player_rank(&ctx, mode, user, command.channel_id).await
         }
-        HigherLower::FarmMaps(_) => HigherLowerGame::new_farm_maps(&ctx, user).await,
         HigherLower::Leaderboard(ref args) => {
             return higherlower_leaderboard(ctx, command, args.version).await
         }
@@ -94,13 +236,49 @@ async fn slash_higherlower(ctx: Arc<Context>, mut command: InteractionCommand) -
                 .await
         }
         Err(err) => {
-            let _ = command.error(&ctx, GENERAL_ISSUE).await;
+            let _ = command.error_localized(&ctx, ErrorKey::General).await;
 
             Err(err)
         }
     }
 }
 
+async fn higherlower_multiplayer(
+    ctx: Arc<Context>,
+    command: InteractionCommand,
+    game_fut: impl Future<Output = Result<HigherLowerChannelGame>>,
+) -> Result<()> {
+    let channel = command.channel_id;
+
+    if !ctx.higherlower_channels().pin().insert(channel) {
+        let content = "There is already a multiplayer higherlower game running in this channel";
+        command.error(&ctx, content).await?;
+
+        return Ok(());
+    }
+
+    let game = match game_fut.await {
+        Ok(game) => game,
+        Err(err) => {
+            ctx.higherlower_channels().pin().remove(&channel);
+            let _ = command.error_localized(&ctx, ErrorKey::General).await;
+
+            return Err(err);
+        }
+    };
+
+    let content = "Starting a multiplayer game of higherlower!\n\
+        Everyone can join by voting in the first round. \
+        Guess wrong or miss a vote and you're out, the last ones standing win.";
+
+    let builder = MessageBuilder::new().embed(content);
+    command.update(&ctx, builder).await?;
+
+    game.start(ctx, channel);
+
+    Ok(())
+}
+
 async fn higherlower_leaderboard(
     ctx: Arc<Context>,
     mut command: InteractionCommand,
@@ -119,7 +297,7 @@ async fn higherlower_leaderboard(
     let mut scores = match ctx.games().higherlower_leaderboard(version).await {
         Ok(scores) => scores,
         Err(err) => {
-            let _ = command.error(&ctx, GENERAL_ISSUE).await;
+            let _ = command.error_localized(&ctx, ErrorKey::General).await;
 
             return Err(err);
         }
@@ -10,6 +10,7 @@ use rand::RngCore;
 use twilight_interactions::command::{CommandModel, CommandOption, CreateCommand, CreateOption};
 
 use crate::{
+    active::{impls::MinesweeperGame, ActiveMessages},
     core::{
         commands::{prefix::Args, CommandOrigin},
         Context,
@@ -22,16 +23,21 @@ use crate::{
     name = "minesweeper",
     desc = "Play a game of minesweeper",
     help = "Play a game of minesweeper.\n\
-    In case you don't know how it works: Each number indicates the amount of neighboring bombs."
+    In case you don't know how it works: Each number indicates the amount of neighboring bombs.\n\
+    By default the field is posted with spoiler tags. \
+    With the `interactive` option, cells are revealed and flagged through the message components \
+    instead and your best time for each difficulty is stored."
 )]
 #[flags(SKIP_DEFER)]
 pub struct Minesweeper {
     #[command(desc = "Choose a difficulty")]
-    difficulty: Difficulty,
+    difficulty: MinesweeperDifficulty,
+    #[command(desc = "Play through buttons and menus instead of spoiler tags")]
+    interactive: Option<bool>,
 }
 
-#[derive(CommandOption, CreateOption)]
-enum Difficulty {
+#[derive(Copy, Clone, CommandOption, CreateOption)]
+pub enum MinesweeperDifficulty {
     #[option(name = "easy", value = "easy")]
     Easy,
     #[option(name = "medium", value = "medium")]
@@ -45,7 +51,9 @@ enum Difficulty {
 pub async fn slash_minesweeper(ctx: Arc<Context>, mut command: InteractionCommand) -> Result<()> {
     let args = Minesweeper::from_interaction(command.input_data())?;
 
-    minesweeper(ctx, (&mut command).into(), args.difficulty).await
+    let interactive = args.interactive.unwrap_or(false);
+
+    minesweeper(ctx, (&mut command).into(), args.difficulty, interactive).await
 }
 
 #[command]
@@ -55,13 +63,15 @@ pub async fn slash_minesweeper(ctx: Arc<Context>, mut command: InteractionComman
     The available arguments are:\n\
     - `easy`: 6x6 grid\n\
     - `medium`: 8x8 grid\n\
-    - `hard`: 9x11 grid"
+    - `hard`: 9x11 grid\n\
+    Add `interactive` to play through buttons and menus instead of spoiler tags."
 )]
-#[usage("[easy / medium / hard]")]
+#[usage("[easy / medium / hard] [interactive]")]
+#[examples("medium", "hard interactive")]
 #[flags(SKIP_DEFER)]
 #[group(Games)]
 async fn prefix_minesweeper(ctx: Arc<Context>, msg: &Message, mut args: Args<'_>) -> Result<()> {
-    let difficulty = match Difficulty::args(&mut args) {
+    let difficulty = match MinesweeperDifficulty::args(&mut args) {
         Ok(difficulty) => difficulty,
         Err(content) => {
             msg.error(&ctx, content).await?;
@@ -70,14 +80,25 @@ async fn prefix_minesweeper(ctx: Arc<Context>, msg: &Message, mut args: Args<'_>
         }
     };
 
-    minesweeper(ctx, msg.into(), difficulty).await
+    let interactive = args
+        .next()
+        .is_some_and(|arg| arg.eq_ignore_ascii_case("interactive"));
+
+    minesweeper(ctx, msg.into(), difficulty, interactive).await
 }
 
 async fn minesweeper(
     ctx: Arc<Context>,
     orig: CommandOrigin<'_>,
-    difficulty: Difficulty,
+    difficulty: MinesweeperDifficulty,
+    interactive: bool,
 ) -> Result<()> {
+    if interactive {
+        let game = MinesweeperGame::new(difficulty, orig.user_id()?);
+
+        return ActiveMessages::builder(game).begin(ctx, orig).await;
+    }
+
     let game = difficulty.create();
     let (w, h) = game.dim();
     let mut field = String::with_capacity(w * h * 9);
@@ -99,7 +120,7 @@ async fn minesweeper(
     Ok(())
 }
 
-impl Difficulty {
+impl MinesweeperDifficulty {
     fn args(args: &mut Args<'_>) -> Result<Self, &'static str> {
         match args
             .next()
@@ -122,6 +143,23 @@ impl Difficulty {
             // Self::Expert => Game::new(13, 13, 40),
         }
     }
+
+    /// Width, height, and amount of mines
+    pub fn dimensions(self) -> (usize, usize, u8) {
+        match self {
+            Self::Easy => (6, 6, 6),
+            Self::Medium => (8, 8, 12),
+            Self::Hard => (9, 11, 20),
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Easy => "Easy",
+            Self::Medium => "Medium",
+            Self::Hard => "Hard",
+        }
+    }
 }
 
 struct Game {
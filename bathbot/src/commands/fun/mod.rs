@@ -1,5 +1,6 @@
-pub use self::{bg_game::*, higherlower_game::*, minesweeper::*};
+pub use self::{bg_game::*, higherlower_game::*, minesweeper::*, trivia::*};
 
 mod bg_game;
 mod higherlower_game;
 mod minesweeper;
+mod trivia;
@@ -0,0 +1,174 @@
+use std::{collections::BTreeMap, sync::Arc};
+
+use bathbot_macros::SlashCommand;
+use bathbot_model::{RankingEntries, RankingKind};
+use bathbot_util::{IntHasher, MessageBuilder};
+use eyre::Result;
+use hashbrown::HashSet;
+use twilight_interactions::command::{CommandModel, CommandOption, CreateCommand, CreateOption};
+
+use crate::{
+    active::{
+        impls::{RankingPagination, TriviaGame},
+        ActiveMessages,
+    },
+    core::locale::ErrorKey,
+    util::{interaction::InteractionCommand, Authored, InteractionCommandExt},
+    Context,
+};
+
+#[derive(CommandModel, CreateCommand, SlashCommand)]
+#[command(
+    name = "trivia",
+    dm_permission = false,
+    desc = "Answer osu! trivia questions"
+)]
+pub enum Trivia {
+    #[command(name = "play")]
+    Play(TriviaPlay),
+    #[command(name = "leaderboard")]
+    Leaderboard(TriviaLeaderboard),
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "play",
+    desc = "Start a round of osu! trivia questions",
+    help = "Start a round of osu! trivia questions.\n\
+    Everyone in the channel can answer through the buttons but only once per question. \
+    The first correct answer wins the question and earns a point on the server leaderboard.\n\
+    Questions are generated from:\n\
+    - `Mapper`: Mapsets stored by the bot\n\
+    - `Ranked year`: Ranked dates of stored mapsets\n\
+    - `Medals`: The [osekai](https://osekai.net) medal ranking\n\
+    - `Score pp`: Top scores of players within the top 5,000"
+)]
+pub struct TriviaPlay {
+    #[command(desc = "Only ask questions of this category")]
+    category: Option<TriviaCategory>,
+    #[command(
+        min_value = 1,
+        max_value = 25,
+        desc = "Amount of questions, defaults to 10"
+    )]
+    rounds: Option<u32>,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "leaderboard",
+    desc = "Get the server leaderboard for correct trivia answers"
+)]
+pub struct TriviaLeaderboard;
+
+#[derive(Copy, Clone, CommandOption, CreateOption, Eq, PartialEq)]
+pub enum TriviaCategory {
+    #[option(name = "Mapper", value = "mapper")]
+    Mapper,
+    #[option(name = "Ranked year", value = "ranked_year")]
+    RankedYear,
+    #[option(name = "Medals", value = "medals")]
+    Medals,
+    #[option(name = "Score pp", value = "score_pp")]
+    ScorePp,
+}
+
+impl TriviaCategory {
+    pub const ALL: [Self; 4] = [Self::Mapper, Self::RankedYear, Self::Medals, Self::ScorePp];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Mapper => "Mapper",
+            Self::RankedYear => "Ranked year",
+            Self::Medals => "Medals",
+            Self::ScorePp => "Score pp",
+        }
+    }
+}
+
+async fn slash_trivia(ctx: Arc<Context>, mut command: InteractionCommand) -> Result<()> {
+    match Trivia::from_interaction(command.input_data())? {
+        Trivia::Play(args) => trivia_play(ctx, command, args).await,
+        Trivia::Leaderboard(_) => trivia_leaderboard(ctx, command).await,
+    }
+}
+
+async fn trivia_play(
+    ctx: Arc<Context>,
+    mut command: InteractionCommand,
+    args: TriviaPlay,
+) -> Result<()> {
+    let guild = command.guild_id.unwrap(); // command is only processed in guilds
+    let owner = command.user_id()?;
+    let rounds = args.rounds.unwrap_or(10) as usize;
+
+    match TriviaGame::new(&ctx, guild, args.category, rounds, owner).await {
+        Ok(game) => {
+            ActiveMessages::builder(game)
+                .start_by_update(true)
+                .begin(ctx, &mut command)
+                .await
+        }
+        Err(err) => {
+            let _ = command.error_localized(&ctx, ErrorKey::General).await;
+
+            Err(err)
+        }
+    }
+}
+
+async fn trivia_leaderboard(ctx: Arc<Context>, mut command: InteractionCommand) -> Result<()> {
+    let guild = command.guild_id.unwrap(); // command is only processed in guilds
+
+    let mut scores = match ctx.games().trivia_leaderboard(guild).await {
+        Ok(scores) => scores,
+        Err(err) => {
+            let _ = command.error_localized(&ctx, ErrorKey::General).await;
+
+            return Err(err);
+        }
+    };
+
+    let members: HashSet<_, IntHasher> = ctx
+        .cache
+        .members(guild)
+        .await?
+        .into_iter()
+        .map(|id| id as i64)
+        .collect();
+
+    scores.retain(|row| members.contains(&row.discord_id));
+
+    if scores.is_empty() {
+        let content = "No one on this server has answered a trivia question correctly yet";
+        let builder = MessageBuilder::new().embed(content);
+        command.update(&ctx, builder).await?;
+
+        return Ok(());
+    }
+
+    let owner = command.user_id()?;
+    let author = owner.get() as i64;
+
+    scores.sort_unstable_by(|a, b| b.score.cmp(&a.score));
+    let author_idx = scores.iter().position(|row| row.discord_id == author);
+
+    // Usernames are gathered when building the pages
+    let entries = RankingEntries::Amount(BTreeMap::new());
+    let total = scores.len();
+    let data = RankingKind::TriviaScores { scores };
+
+    let pagination = RankingPagination::builder()
+        .entries(entries)
+        .total(total)
+        .author_idx(author_idx)
+        .kind(data)
+        .defer(false)
+        .msg_owner(owner)
+        .build();
+
+    ActiveMessages::builder(pagination)
+        .start_by_update(true)
+        .begin(ctx, &mut command)
+        .await
+}
@@ -1,8 +1,11 @@
 use std::{collections::BTreeMap, sync::Arc};
 
 use bathbot_macros::SlashCommand;
+use bathbot_psql::model::configs::{GuildConfig, DEFAULT_PREFIX};
 use bathbot_util::{
-    constants::{BATHBOT_GITHUB, BATHBOT_ROADMAP, BATHBOT_WORKSHOP, INVITE_LINK, KOFI},
+    constants::{
+        BATHBOT_GITHUB, BATHBOT_ROADMAP, BATHBOT_WORKSHOP, FIELD_VALUE_SIZE, INVITE_LINK, KOFI,
+    },
     datetime::HowLongAgoDynamic,
     numbers::WithComma,
     string_cmp::levenshtein_distance,
@@ -19,6 +22,7 @@ use twilight_model::{
 use super::failed_message_content;
 use crate::{
     active::{impls::HelpInteractionCommand, ActiveMessages},
+    commands::utility::write_aliases,
     core::{
         commands::interaction::{InteractionCommandKind, InteractionCommands},
         Context,
@@ -169,7 +173,7 @@ async fn help_slash_basic(ctx: Arc<Context>, command: InteractionCommand) -> Res
         value: format!("Donations through [Ko-fi]({KOFI}) are very much appreciated <3"),
     };
 
-    let fields = vec![
+    let mut fields = vec![
         join_server,
         command_help,
         invite,
@@ -181,6 +185,31 @@ async fn help_slash_basic(ctx: Arc<Context>, command: InteractionCommand) -> Res
         kofi,
     ];
 
+    if let Some(guild_id) = command.guild_id {
+        let f = |config: &GuildConfig| {
+            if config.command_aliases.is_empty() {
+                return None;
+            }
+
+            let mut value = String::new();
+            write_aliases(&mut value, &config.command_aliases);
+
+            Some((config.prefixes.first().cloned(), value))
+        };
+
+        if let Some((prefix, value)) = ctx.guild_config().peek(guild_id, f).await {
+            let prefix = prefix.as_deref().unwrap_or(DEFAULT_PREFIX);
+
+            let aliases = EmbedField {
+                inline: false,
+                name: format!("Custom prefix aliases of this server (prefix `{prefix}`)"),
+                value: truncate_field(value),
+            };
+
+            fields.push(aliases);
+        }
+    }
+
     let embed = EmbedBuilder::new().description(description).fields(fields);
 
     let builder = MessageBuilder::new().embed(embed);
@@ -212,3 +241,18 @@ async fn help_slash_command(
 
     ActiveMessages::builder(help).begin(ctx, command).await
 }
+
+/// Truncate the value to full lines so that it fits into a field.
+fn truncate_field(mut value: String) -> String {
+    if value.len() > FIELD_VALUE_SIZE {
+        let end = value.as_bytes()[..FIELD_VALUE_SIZE - 4]
+            .iter()
+            .rposition(|&byte| byte == b'\n')
+            .unwrap_or(0);
+
+        value.truncate(end);
+        value.push_str("\n...");
+    }
+
+    value
+}
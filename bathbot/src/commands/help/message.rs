@@ -3,7 +3,8 @@ use std::{collections::BTreeMap, fmt::Write, sync::Arc};
 use bathbot_macros::command;
 use bathbot_psql::model::configs::{GuildConfig, DEFAULT_PREFIX};
 use bathbot_util::{
-    string_cmp::levenshtein_distance, AuthorBuilder, EmbedBuilder, FooterBuilder, MessageBuilder,
+    string_cmp::levenshtein_distance, AuthorBuilder, CowUtils, EmbedBuilder, FooterBuilder,
+    MessageBuilder,
 };
 use eyre::Result;
 use hashbrown::HashSet;
@@ -34,12 +35,34 @@ async fn prefix_help(
     mut args: Args<'_>,
     permissions: Option<Permissions>,
 ) -> Result<()> {
-    match args.next() {
-        Some(arg) => match PrefixCommands::get().command(arg) {
-            Some(cmd) => command_help(ctx, msg, cmd, permissions).await,
-            None => failed_help(ctx, msg, arg).await,
-        },
-        None => dm_help(ctx, msg, permissions).await,
+    let Some(arg) = args.next() else {
+        return dm_help(ctx, msg, permissions).await;
+    };
+
+    if let Some(cmd) = PrefixCommands::get().command(arg) {
+        return command_help(ctx, msg, cmd, permissions).await;
+    }
+
+    // Custom aliases of the guild resolve to the help of their command
+    let alias_cmd = match msg.guild_id {
+        Some(guild_id) => {
+            let name = arg.cow_to_ascii_lowercase();
+
+            let f = |config: &GuildConfig| {
+                config
+                    .command_aliases
+                    .get(name.as_ref())
+                    .and_then(|alias| PrefixCommands::get().command(&alias.command))
+            };
+
+            ctx.guild_config().peek(guild_id, f).await
+        }
+        None => None,
+    };
+
+    match alias_cmd {
+        Some(cmd) => command_help(ctx, msg, cmd, permissions).await,
+        None => failed_help(ctx, msg, arg).await,
     }
 }
 
@@ -150,6 +173,38 @@ async fn command_help(
         fields.push(field);
     }
 
+    let custom_aliases = guild_config.as_ref().map(|config| {
+        config
+            .command_aliases
+            .iter()
+            .filter(|alias| alias.command == name)
+            .fold(String::new(), |mut value, alias| {
+                if !value.is_empty() {
+                    value.push('\n');
+                }
+
+                let _ = write!(value, "`{prefix}{}` → `{name}", alias.name);
+
+                if !alias.args.is_empty() {
+                    let _ = write!(value, " {}", alias.args);
+                }
+
+                value.push('`');
+
+                value
+            })
+    });
+
+    if let Some(value) = custom_aliases.filter(|value| !value.is_empty()) {
+        let field = EmbedField {
+            name: "Server aliases".to_owned(),
+            value,
+            inline: false,
+        };
+
+        fields.push(field);
+    }
+
     if cmd.flags.authority() {
         let value = if let Some(config) = guild_config {
             let authorities = config.authorities;
@@ -1,5 +1,6 @@
 use std::str::FromStr;
 
+use bathbot_psql::model::configs::Language;
 use rosu_v2::prelude::{GameMode, Grade};
 use time::UtcOffset;
 use twilight_interactions::command::{CommandOption, CreateOption};
@@ -31,6 +32,30 @@ pub enum EnableDisable {
     Disable,
 }
 
+#[derive(Copy, Clone, CommandOption, CreateOption, Eq, PartialEq)]
+pub enum LanguageOption {
+    #[option(name = "Default", value = "default")]
+    Default,
+    #[option(name = "English", value = "en")]
+    English,
+    #[option(name = "Deutsch", value = "de")]
+    German,
+    #[option(name = "Français", value = "fr")]
+    French,
+}
+
+impl From<LanguageOption> for Option<Language> {
+    #[inline]
+    fn from(language: LanguageOption) -> Self {
+        match language {
+            LanguageOption::Default => None,
+            LanguageOption::English => Some(Language::English),
+            LanguageOption::German => Some(Language::German),
+            LanguageOption::French => Some(Language::French),
+        }
+    }
+}
+
 #[derive(CommandOption, CreateOption)]
 pub enum ThreadChannel {
     #[option(name = "Stay in channel", value = "channel")]
@@ -2,10 +2,7 @@ use std::{borrow::Cow, sync::Arc};
 
 use bathbot_macros::{command, HasName, SlashCommand};
 use bathbot_util::{
-    constants::{GENERAL_ISSUE, OSU_API_ISSUE, OSU_BASE},
-    matcher,
-    osu::flag_url,
-    AuthorBuilder, EmbedBuilder, MessageBuilder,
+    constants::OSU_BASE, matcher, osu::flag_url, AuthorBuilder, EmbedBuilder, MessageBuilder,
 };
 use eyre::{Report, Result};
 use rosu_v2::{prelude::OsuError, request::UserId};
@@ -14,7 +11,10 @@ use twilight_model::id::{marker::UserMarker, Id};
 
 use super::{require_link, user_not_found};
 use crate::{
-    core::commands::{prefix::Args, CommandOrigin},
+    core::{
+        commands::{prefix::Args, CommandOrigin},
+        locale::ErrorKey,
+    },
     manager::redis::{osu::UserArgs, RedisData},
     util::{interaction::InteractionCommand, InteractionCommandExt},
     Context,
@@ -73,7 +73,7 @@ async fn avatar(ctx: Arc<Context>, orig: CommandOrigin<'_>, args: Avatar<'_>) ->
             Ok(Some(user_id)) => UserId::Id(user_id),
             Ok(None) => return require_link(&ctx, &orig).await,
             Err(err) => {
-                let _ = orig.error(&ctx, GENERAL_ISSUE).await;
+                let _ = orig.error_localized(&ctx, ErrorKey::General).await;
 
                 return Err(err);
             }
@@ -90,7 +90,7 @@ async fn avatar(ctx: Arc<Context>, orig: CommandOrigin<'_>, args: Avatar<'_>) ->
             return orig.error(&ctx, content).await;
         }
         Err(err) => {
-            let _ = orig.error(&ctx, OSU_API_ISSUE).await;
+            let _ = orig.error_localized(&ctx, ErrorKey::OsuApi).await;
             let err = Report::new(err).wrap_err("failed to get user");
 
             return Err(err);
@@ -1,11 +1,7 @@
 use std::{cmp::Ordering, collections::BTreeMap, fmt::Write, sync::Arc};
 
 use bathbot_model::OsekaiBadge;
-use bathbot_util::{
-    constants::{AVATAR_URL, OSEKAI_ISSUE},
-    string_cmp::levenshtein_similarity,
-    CowUtils,
-};
+use bathbot_util::{constants::AVATAR_URL, string_cmp::levenshtein_similarity, CowUtils};
 use eyre::{Result, WrapErr};
 use rkyv::{Deserialize, Infallible};
 use twilight_interactions::command::AutocompleteValue;
@@ -14,7 +10,7 @@ use twilight_model::application::command::{CommandOptionChoice, CommandOptionCho
 use super::BadgesQuery_;
 use crate::{
     active::{impls::BadgesPagination, ActiveMessages},
-    core::Context,
+    core::{locale::ErrorKey, Context},
     manager::redis::RedisData,
     util::{
         interaction::InteractionCommand, osu::get_combined_thumbnail, Authored,
@@ -38,7 +34,7 @@ pub(super) async fn query(
     let badges = match ctx.redis().badges().await {
         Ok(badges) => badges,
         Err(err) => {
-            let _ = command.error(&ctx, OSEKAI_ISSUE).await;
+            let _ = command.error_localized(&ctx, ErrorKey::Osekai).await;
 
             return Err(err.wrap_err("failed to get cached badges"));
         }
@@ -109,7 +105,7 @@ pub(super) async fn query(
         match owners_fut.await {
             Ok(owners) => owners,
             Err(err) => {
-                let _ = command.error(&ctx, OSEKAI_ISSUE).await;
+                let _ = command.error_localized(&ctx, ErrorKey::Osekai).await;
 
                 return Err(err.wrap_err("Failed to get badge owners"));
             }
@@ -158,7 +154,7 @@ async fn no_badge_found(ctx: &Context, command: &InteractionCommand, name: &str)
     let badges = match ctx.redis().badges().await {
         Ok(badges) => badges,
         Err(err) => {
-            let _ = command.error(ctx, OSEKAI_ISSUE).await;
+            let _ = command.error_localized(ctx, ErrorKey::Osekai).await;
 
             return Err(err.wrap_err("failed to get cached badges"));
         }
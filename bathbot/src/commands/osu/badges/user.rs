@@ -1,9 +1,6 @@
 use std::{collections::BTreeMap, sync::Arc};
 
-use bathbot_util::{
-    constants::{AVATAR_URL, GENERAL_ISSUE, OSEKAI_ISSUE, OSU_API_ISSUE},
-    MessageBuilder,
-};
+use bathbot_util::{constants::AVATAR_URL, MessageBuilder};
 use eyre::{Report, Result};
 use rkyv::{Deserialize, Infallible};
 use rosu_v2::{prelude::OsuError, request::UserId};
@@ -12,7 +9,7 @@ use super::BadgesUser;
 use crate::{
     active::{impls::BadgesPagination, ActiveMessages},
     commands::osu::{require_link, user_not_found},
-    core::{commands::CommandOrigin, Context},
+    core::{commands::CommandOrigin, locale::ErrorKey, Context},
     manager::redis::{osu::UserArgs, RedisData},
     util::osu::get_combined_thumbnail,
 };
@@ -30,7 +27,7 @@ pub(super) async fn user(
             Ok(Some(id)) => UserId::Id(id),
             Ok(None) => return require_link(&ctx, &orig).await,
             Err(err) => {
-                let _ = orig.error(&ctx, GENERAL_ISSUE).await;
+                let _ = orig.error_localized(&ctx, ErrorKey::General).await;
 
                 return Err(err.wrap_err("failed to get user id"));
             }
@@ -51,7 +48,7 @@ pub(super) async fn user(
             return orig.error(&ctx, content).await;
         }
         UserArgs::Err(err) => {
-            let _ = orig.error(&ctx, OSU_API_ISSUE).await;
+            let _ = orig.error_localized(&ctx, ErrorKey::OsuApi).await;
             let err = Report::new(err).wrap_err("failed to get user");
 
             return Err(err);
@@ -61,7 +58,7 @@ pub(super) async fn user(
     let badges = match badges_res {
         Ok(badges) => badges,
         Err(err) => {
-            let _ = orig.error(&ctx, OSEKAI_ISSUE).await;
+            let _ = orig.error_localized(&ctx, ErrorKey::Osekai).await;
 
             return Err(err.wrap_err("failed to get badges"));
         }
@@ -88,7 +85,7 @@ pub(super) async fn user(
         match owners_fut.await {
             Ok(owners) => owners,
             Err(err) => {
-                let _ = orig.error(&ctx, OSEKAI_ISSUE).await;
+                let _ = orig.error_localized(&ctx, ErrorKey::Osekai).await;
 
                 return Err(err.wrap_err("failed to get badge owners"));
             }
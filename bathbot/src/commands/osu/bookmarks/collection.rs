@@ -0,0 +1,617 @@
+use std::{fmt::Write, sync::Arc};
+
+use bathbot_macros::SlashCommand;
+use bathbot_psql::model::osu::DbBookmarkCollection;
+use bathbot_util::{matcher, MessageBuilder};
+use eyre::Result;
+use twilight_interactions::command::{CommandModel, CommandOption, CreateCommand, CreateOption};
+use twilight_model::id::{marker::UserMarker, Id};
+
+use crate::{
+    active::{impls::CollectionPagination, ActiveMessages},
+    core::{commands::CommandOrigin, locale::ErrorKey},
+    util::{interaction::InteractionCommand, osu::collection_db, InteractionCommandExt},
+    Context,
+};
+
+const NAME_MAX_LEN: usize = 32;
+
+#[derive(CommandModel, CreateCommand, SlashCommand)]
+#[command(
+    name = "collection",
+    desc = "Group your bookmarked maps into collections",
+    help = "Group your bookmarked maps into named collections.\n\
+    Collections can be shared with other users and exported as osu! `collection.db` file \
+    or as list of osu!direct links.\n\
+    Only maps that you bookmarked can be added to a collection. \
+    To bookmark a map, rightclick a bot message that contains a single map, \
+    click on `Apps`, and then on `Bookmark map`."
+)]
+#[flags(EPHEMERAL)]
+pub enum Collection {
+    #[command(name = "create")]
+    Create(CollectionCreate),
+    #[command(name = "delete")]
+    Delete(CollectionDelete),
+    #[command(name = "add")]
+    Add(CollectionAdd),
+    #[command(name = "remove")]
+    Remove(CollectionRemove),
+    #[command(name = "list")]
+    List(CollectionList),
+    #[command(name = "show")]
+    Show(CollectionShow),
+    #[command(name = "share")]
+    Share(CollectionShare),
+    #[command(name = "unshare")]
+    Unshare(CollectionUnshare),
+    #[command(name = "export")]
+    Export(CollectionExport),
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "create", desc = "Create a new collection")]
+pub struct CollectionCreate {
+    #[command(desc = "Specify the name of the collection")]
+    name: String,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "delete", desc = "Delete one of your collections")]
+pub struct CollectionDelete {
+    #[command(desc = "Specify the name of the collection")]
+    name: String,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "add", desc = "Add a bookmarked map to one of your collections")]
+pub struct CollectionAdd {
+    #[command(desc = "Specify the name of the collection")]
+    name: String,
+    #[command(desc = "Specify a map url or map id")]
+    map: String,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "remove", desc = "Remove a map from one of your collections")]
+pub struct CollectionRemove {
+    #[command(desc = "Specify the name of the collection")]
+    name: String,
+    #[command(desc = "Specify a map url or map id")]
+    map: String,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "list",
+    desc = "List your collections and those that were shared with you"
+)]
+pub struct CollectionList;
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "show", desc = "Show the maps of a collection")]
+pub struct CollectionShow {
+    #[command(desc = "Specify the name of the collection")]
+    name: String,
+    #[command(
+        desc = "Specify the owner of the collection if it was shared with you",
+        help = "Specify the owner of the collection if it was shared with you.\n\
+        If not specified, your own collection will be shown."
+    )]
+    owner: Option<Id<UserMarker>>,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "share",
+    desc = "Share one of your collections with another user"
+)]
+pub struct CollectionShare {
+    #[command(desc = "Specify the name of the collection")]
+    name: String,
+    #[command(desc = "Specify the user to share the collection with")]
+    user: Id<UserMarker>,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "unshare",
+    desc = "Stop sharing one of your collections with a user"
+)]
+pub struct CollectionUnshare {
+    #[command(desc = "Specify the name of the collection")]
+    name: String,
+    #[command(desc = "Specify the user to no longer share the collection with")]
+    user: Id<UserMarker>,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "export",
+    desc = "Export a collection",
+    help = "Export a collection either as osu! `collection.db` file or as list of osu!direct links.\n\
+    To import a `collection.db` file, you can use tools like \
+    [Collection Manager](https://github.com/Piotrekol/CollectionManager). \
+    Note that osu! only recognizes maps of a collection that you have downloaded."
+)]
+pub struct CollectionExport {
+    #[command(desc = "Specify the name of the collection")]
+    name: String,
+    #[command(desc = "Specify the export format")]
+    format: CollectionExportFormat,
+    #[command(desc = "Specify the owner of the collection if it was shared with you")]
+    owner: Option<Id<UserMarker>>,
+}
+
+#[derive(Copy, Clone, CommandOption, CreateOption)]
+pub enum CollectionExportFormat {
+    #[option(name = "collection.db", value = "collection_db")]
+    CollectionDb,
+    #[option(name = "osu!direct links", value = "osudirect")]
+    OsuDirect,
+}
+
+async fn slash_collection(ctx: Arc<Context>, mut command: InteractionCommand) -> Result<()> {
+    match Collection::from_interaction(command.input_data())? {
+        Collection::Create(args) => create(ctx, (&mut command).into(), args).await,
+        Collection::Delete(args) => delete(ctx, (&mut command).into(), args).await,
+        Collection::Add(args) => add(ctx, (&mut command).into(), args).await,
+        Collection::Remove(args) => remove(ctx, (&mut command).into(), args).await,
+        Collection::List(_) => list(ctx, (&mut command).into()).await,
+        Collection::Show(args) => show(ctx, (&mut command).into(), args).await,
+        Collection::Share(args) => share(ctx, (&mut command).into(), args).await,
+        Collection::Unshare(args) => unshare(ctx, (&mut command).into(), args).await,
+        Collection::Export(args) => export(ctx, (&mut command).into(), args).await,
+    }
+}
+
+async fn create(ctx: Arc<Context>, orig: CommandOrigin<'_>, args: CollectionCreate) -> Result<()> {
+    let owner = orig.user_id()?;
+    let name = args.name.trim();
+
+    if name.is_empty() || name.chars().count() > NAME_MAX_LEN {
+        let content = format!("The name must be between 1 and {NAME_MAX_LEN} characters long");
+
+        return orig.error(&ctx, content).await;
+    }
+
+    let content = match ctx.bookmarks().create_collection(owner, name).await {
+        Ok(true) => format!(
+            "Created the collection `{name}`.\n\
+            You can now add bookmarked maps to it via `/collection add`."
+        ),
+        Ok(false) => {
+            let content = format!("You already have a collection called `{name}`");
+
+            return orig.error(&ctx, content).await;
+        }
+        Err(err) => {
+            let _ = orig.error_localized(&ctx, ErrorKey::General).await;
+
+            return Err(err);
+        }
+    };
+
+    let builder = MessageBuilder::new().embed(content);
+    orig.create_message(&ctx, builder).await?;
+
+    Ok(())
+}
+
+async fn delete(ctx: Arc<Context>, orig: CommandOrigin<'_>, args: CollectionDelete) -> Result<()> {
+    let owner = orig.user_id()?;
+    let name = args.name.trim();
+
+    let content = match ctx.bookmarks().delete_collection(owner, name).await {
+        Ok(true) => format!("Deleted the collection `{name}`"),
+        Ok(false) => return no_collection(&ctx, &orig, name).await,
+        Err(err) => {
+            let _ = orig.error_localized(&ctx, ErrorKey::General).await;
+
+            return Err(err);
+        }
+    };
+
+    let builder = MessageBuilder::new().embed(content);
+    orig.create_message(&ctx, builder).await?;
+
+    Ok(())
+}
+
+async fn add(ctx: Arc<Context>, orig: CommandOrigin<'_>, args: CollectionAdd) -> Result<()> {
+    let owner = orig.user_id()?;
+    let name = args.name.trim();
+
+    let Some(map_id) = matcher::get_osu_map_id(&args.map) else {
+        let content =
+            "Failed to parse map url. Be sure you specify a valid map id or url to a map.";
+
+        return orig.error(&ctx, content).await;
+    };
+
+    match find_collection(&ctx, owner, owner, name).await {
+        Ok(Some(_)) => {}
+        Ok(None) => return no_collection(&ctx, &orig, name).await,
+        Err(err) => {
+            let _ = orig.error_localized(&ctx, ErrorKey::General).await;
+
+            return Err(err);
+        }
+    }
+
+    let bookmarked = match ctx.bookmarks().get(owner).await {
+        Ok(bookmarks) => bookmarks.iter().any(|bookmark| bookmark.map_id == map_id),
+        Err(err) => {
+            let _ = orig.error_localized(&ctx, ErrorKey::General).await;
+
+            return Err(err);
+        }
+    };
+
+    if !bookmarked {
+        let content = format!(
+            "You did not bookmark the map {map_id}.\n\
+            Only bookmarked maps can be added to collections."
+        );
+
+        return orig.error(&ctx, content).await;
+    }
+
+    let content = match ctx.bookmarks().add_to_collection(owner, name, map_id).await {
+        Ok(true) => format!("Added map {map_id} to the collection `{name}`"),
+        Ok(false) => {
+            let content = format!("The map {map_id} is already in the collection `{name}`");
+
+            return orig.error(&ctx, content).await;
+        }
+        Err(err) => {
+            let _ = orig.error_localized(&ctx, ErrorKey::General).await;
+
+            return Err(err);
+        }
+    };
+
+    let builder = MessageBuilder::new().embed(content);
+    orig.create_message(&ctx, builder).await?;
+
+    Ok(())
+}
+
+async fn remove(ctx: Arc<Context>, orig: CommandOrigin<'_>, args: CollectionRemove) -> Result<()> {
+    let owner = orig.user_id()?;
+    let name = args.name.trim();
+
+    let Some(map_id) = matcher::get_osu_map_id(&args.map) else {
+        let content =
+            "Failed to parse map url. Be sure you specify a valid map id or url to a map.";
+
+        return orig.error(&ctx, content).await;
+    };
+
+    let remove_fut = ctx.bookmarks().remove_from_collection(owner, name, map_id);
+
+    let content = match remove_fut.await {
+        Ok(true) => format!("Removed map {map_id} from the collection `{name}`"),
+        Ok(false) => {
+            let content = format!("The map {map_id} is not in your collection `{name}`");
+
+            return orig.error(&ctx, content).await;
+        }
+        Err(err) => {
+            let _ = orig.error_localized(&ctx, ErrorKey::General).await;
+
+            return Err(err);
+        }
+    };
+
+    let builder = MessageBuilder::new().embed(content);
+    orig.create_message(&ctx, builder).await?;
+
+    Ok(())
+}
+
+async fn list(ctx: Arc<Context>, orig: CommandOrigin<'_>) -> Result<()> {
+    let owner = orig.user_id()?;
+
+    let collections = match ctx.bookmarks().collections(owner).await {
+        Ok(collections) => collections,
+        Err(err) => {
+            let _ = orig.error_localized(&ctx, ErrorKey::General).await;
+
+            return Err(err);
+        }
+    };
+
+    let (own, shared): (Vec<_>, Vec<_>) = collections
+        .iter()
+        .partition(|collection| collection.owner_id == owner.get() as i64);
+
+    let mut content = String::new();
+
+    if own.is_empty() {
+        content.push_str("You don't have any collections yet, create one via `/collection create`");
+    } else {
+        content.push_str("__**Your collections:**__\n");
+
+        for collection in own {
+            let _ = writeln!(
+                content,
+                "- `{}`: {} maps",
+                collection.name, collection.map_count
+            );
+        }
+    }
+
+    if !shared.is_empty() {
+        content.push_str("\n__**Shared with you:**__\n");
+
+        for collection in shared {
+            let _ = writeln!(
+                content,
+                "- `{}` by <@{}>: {} maps",
+                collection.name, collection.owner_id, collection.map_count
+            );
+        }
+    }
+
+    let builder = MessageBuilder::new().embed(content);
+    orig.create_message(&ctx, builder).await?;
+
+    Ok(())
+}
+
+async fn show(ctx: Arc<Context>, orig: CommandOrigin<'_>, args: CollectionShow) -> Result<()> {
+    let author = orig.user_id()?;
+    let owner = args.owner.unwrap_or(author);
+    let name = args.name.trim();
+
+    let collection = match find_collection(&ctx, author, owner, name).await {
+        Ok(Some(collection)) => collection,
+        Ok(None) => return no_collection(&ctx, &orig, name).await,
+        Err(err) => {
+            let _ = orig.error_localized(&ctx, ErrorKey::General).await;
+
+            return Err(err);
+        }
+    };
+
+    let maps = match ctx
+        .bookmarks()
+        .collection_maps(owner, &collection.name)
+        .await
+    {
+        Ok(maps) => maps,
+        Err(err) => {
+            let _ = orig.error_localized(&ctx, ErrorKey::General).await;
+
+            return Err(err);
+        }
+    };
+
+    let pagination = CollectionPagination::builder()
+        .maps(maps.into_boxed_slice())
+        .name(collection.name.into_boxed_str())
+        .owner(owner)
+        .msg_owner(author)
+        .build();
+
+    ActiveMessages::builder(pagination)
+        .start_by_update(true)
+        .begin(ctx, orig)
+        .await
+}
+
+async fn share(ctx: Arc<Context>, orig: CommandOrigin<'_>, args: CollectionShare) -> Result<()> {
+    let owner = orig.user_id()?;
+    let name = args.name.trim();
+
+    if args.user == owner {
+        return orig
+            .error(&ctx, "You can't share a collection with yourself")
+            .await;
+    }
+
+    match find_collection(&ctx, owner, owner, name).await {
+        Ok(Some(_)) => {}
+        Ok(None) => return no_collection(&ctx, &orig, name).await,
+        Err(err) => {
+            let _ = orig.error_localized(&ctx, ErrorKey::General).await;
+
+            return Err(err);
+        }
+    }
+
+    let content = match ctx
+        .bookmarks()
+        .share_collection(owner, name, args.user)
+        .await
+    {
+        Ok(true) => format!(
+            "<@{user}> can now view and export your collection `{name}` \
+            by specifying you as owner",
+            user = args.user
+        ),
+        Ok(false) => format!(
+            "Your collection `{name}` is already shared with <@{user}>",
+            user = args.user
+        ),
+        Err(err) => {
+            let _ = orig.error_localized(&ctx, ErrorKey::General).await;
+
+            return Err(err);
+        }
+    };
+
+    let builder = MessageBuilder::new().embed(content);
+    orig.create_message(&ctx, builder).await?;
+
+    Ok(())
+}
+
+async fn unshare(
+    ctx: Arc<Context>,
+    orig: CommandOrigin<'_>,
+    args: CollectionUnshare,
+) -> Result<()> {
+    let owner = orig.user_id()?;
+    let name = args.name.trim();
+
+    let unshare_fut = ctx.bookmarks().unshare_collection(owner, name, args.user);
+
+    let content = match unshare_fut.await {
+        Ok(true) => format!(
+            "Your collection `{name}` is no longer shared with <@{user}>",
+            user = args.user
+        ),
+        Ok(false) => {
+            let content = format!(
+                "Your collection `{name}` was not shared with <@{user}>",
+                user = args.user
+            );
+
+            return orig.error(&ctx, content).await;
+        }
+        Err(err) => {
+            let _ = orig.error_localized(&ctx, ErrorKey::General).await;
+
+            return Err(err);
+        }
+    };
+
+    let builder = MessageBuilder::new().embed(content);
+    orig.create_message(&ctx, builder).await?;
+
+    Ok(())
+}
+
+async fn export(ctx: Arc<Context>, orig: CommandOrigin<'_>, args: CollectionExport) -> Result<()> {
+    let author = orig.user_id()?;
+    let owner = args.owner.unwrap_or(author);
+    let name = args.name.trim();
+
+    let collection = match find_collection(&ctx, author, owner, name).await {
+        Ok(Some(collection)) => collection,
+        Ok(None) => return no_collection(&ctx, &orig, name).await,
+        Err(err) => {
+            let _ = orig.error_localized(&ctx, ErrorKey::General).await;
+
+            return Err(err);
+        }
+    };
+
+    let maps = match ctx
+        .bookmarks()
+        .collection_maps(owner, &collection.name)
+        .await
+    {
+        Ok(maps) => maps,
+        Err(err) => {
+            let _ = orig.error_localized(&ctx, ErrorKey::General).await;
+
+            return Err(err);
+        }
+    };
+
+    if maps.is_empty() {
+        let content = format!(
+            "The collection `{}` does not contain any maps",
+            collection.name
+        );
+
+        return orig.error(&ctx, content).await;
+    }
+
+    let builder = match args.format {
+        CollectionExportFormat::CollectionDb => {
+            let checksums = maps.iter().map(|map| map.checksum.as_ref());
+            let bytes = collection_db(&collection.name, checksums);
+
+            let content = format!(
+                "Exported {} maps of the collection `{}`",
+                maps.len(),
+                collection.name
+            );
+
+            MessageBuilder::new()
+                .embed(content)
+                .attachment("collection.db", bytes)
+        }
+        CollectionExportFormat::OsuDirect => {
+            #[cfg(not(feature = "server"))]
+            let url = "https://www.google.com";
+
+            #[cfg(feature = "server")]
+            let url = &crate::core::BotConfig::get().server.public_url;
+
+            let mut mapset_ids: Vec<_> = maps.iter().map(|map| map.mapset_id).collect();
+            mapset_ids.sort_unstable();
+            mapset_ids.dedup();
+
+            let mut links = String::with_capacity(mapset_ids.len() * (url.len() + 20));
+
+            for mapset_id in mapset_ids.iter() {
+                let _ = writeln!(links, "{url}/osudirect/{mapset_id}");
+            }
+
+            let content = format!(
+                "Exported {} mapsets of the collection `{}`",
+                mapset_ids.len(),
+                collection.name
+            );
+
+            MessageBuilder::new()
+                .embed(content)
+                .attachment(attachment_name(&collection.name), links.into_bytes())
+        }
+    };
+
+    orig.create_message(&ctx, builder).await?;
+
+    Ok(())
+}
+
+/// File name of an exported collection with path and control characters
+/// replaced.
+fn attachment_name(collection: &str) -> String {
+    let name: String = collection
+        .chars()
+        .map(|c| match c {
+            '/' | '\\' | ':' | '*' | '?' | '"' | '<' | '>' | '|' => '_',
+            c if c.is_control() => '_',
+            c => c,
+        })
+        .collect();
+
+    let name = name.trim_matches(|c: char| c == '.' || c.is_whitespace());
+
+    if name.is_empty() {
+        "collection.txt".to_owned()
+    } else {
+        format!("{name}.txt")
+    }
+}
+
+/// Find a collection of `owner` that is accessible to `user`.
+async fn find_collection(
+    ctx: &Context,
+    user: Id<UserMarker>,
+    owner: Id<UserMarker>,
+    name: &str,
+) -> Result<Option<DbBookmarkCollection>> {
+    let collections = ctx.bookmarks().collections(user).await?;
+
+    let collection = collections
+        .into_iter()
+        .find(|collection| collection.owner_id == owner.get() as i64 && collection.name == name);
+
+    Ok(collection)
+}
+
+async fn no_collection(ctx: &Context, orig: &CommandOrigin<'_>, name: &str) -> Result<()> {
+    let content = format!(
+        "Could not find the collection `{name}`.\n\
+        Use `/collection list` to see all your collections."
+    );
+
+    orig.error(ctx, content).await
+}
@@ -5,14 +5,14 @@ use std::{
 };
 
 use bathbot_macros::msg_command;
-use bathbot_util::{constants::GENERAL_ISSUE, osu::MapIdType, MessageOrigin};
+use bathbot_util::{osu::MapIdType, MessageOrigin};
 use eyre::{Report, Result};
 use rosu_v2::prelude::OsuError;
 use twilight_model::channel::Message;
 
 use crate::{
     active::{impls::BookmarksPagination, ActiveMessages},
-    core::Context,
+    core::{locale::ErrorKey, Context},
     util::{interaction::InteractionCommand, Authored, InteractionCommandExt},
 };
 
@@ -25,7 +25,7 @@ async fn bookmark_map(ctx: Arc<Context>, mut command: InteractionCommand) -> Res
         .and_then(|resolved| resolved.messages.values().next());
 
     let Some(msg) = msg_opt else {
-        let _ = command.error(&ctx, GENERAL_ISSUE).await;
+        let _ = command.error_localized(&ctx, ErrorKey::General).await;
 
         return Err(eyre!("Missing resolved message"));
     };
@@ -72,14 +72,14 @@ async fn bookmark_map(ctx: Arc<Context>, mut command: InteractionCommand) -> Res
             return Ok(());
         }
         Err(err) => {
-            let _ = command.error(&ctx, GENERAL_ISSUE).await;
+            let _ = command.error_localized(&ctx, ErrorKey::General).await;
 
             return Err(Report::new(err).wrap_err("Failed to get mapset"));
         }
     };
 
     if let Err(err) = ctx.osu_map().store(&mapset).await {
-        let _ = command.error(&ctx, GENERAL_ISSUE).await;
+        let _ = command.error_localized(&ctx, ErrorKey::General).await;
 
         return Err(err);
     }
@@ -104,7 +104,7 @@ async fn bookmark_map(ctx: Arc<Context>, mut command: InteractionCommand) -> Res
     let user_id = command.user_id()?;
 
     if let Err(err) = ctx.bookmarks().add(user_id, map_id).await {
-        let _ = command.error(&ctx, GENERAL_ISSUE).await;
+        let _ = command.error_localized(&ctx, ErrorKey::General).await;
 
         return Err(err);
     }
@@ -114,7 +114,7 @@ async fn bookmark_map(ctx: Arc<Context>, mut command: InteractionCommand) -> Res
     let bookmarks = match ctx.bookmarks().get(user_id).await {
         Ok(bookmarks) => bookmarks,
         Err(err) => {
-            let _ = command.error(&ctx, GENERAL_ISSUE).await?;
+            let _ = command.error_localized(&ctx, ErrorKey::General).await?;
 
             return Err(err);
         }
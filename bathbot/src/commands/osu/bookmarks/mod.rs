@@ -1,4 +1,5 @@
+mod collection;
 mod message;
 mod slash;
 
-pub use self::{message::*, slash::*};
+pub use self::{collection::*, message::*, slash::*};
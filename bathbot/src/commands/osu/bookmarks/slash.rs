@@ -2,7 +2,7 @@ use std::{collections::HashMap, fmt::Write, sync::Arc};
 
 use bathbot_macros::SlashCommand;
 use bathbot_psql::model::osu::MapBookmark;
-use bathbot_util::{constants::GENERAL_ISSUE, CowUtils, MessageOrigin};
+use bathbot_util::{CowUtils, MessageOrigin};
 use eyre::Result;
 use rosu_v2::prelude::GameMode;
 use twilight_interactions::command::{CommandModel, CommandOption, CreateCommand, CreateOption};
@@ -10,7 +10,7 @@ use twilight_interactions::command::{CommandModel, CommandOption, CreateCommand,
 use crate::{
     active::{impls::BookmarksPagination, ActiveMessages},
     commands::GameModeOption,
-    core::Context,
+    core::{locale::ErrorKey, Context},
     util::{
         interaction::InteractionCommand,
         query::{BookmarkCriteria, FilterCriteria, IFilterCriteria},
@@ -76,7 +76,7 @@ pub async fn slash_bookmarks(ctx: Arc<Context>, mut command: InteractionCommand)
     let mut bookmarks = match ctx.bookmarks().get(owner).await {
         Ok(bookmarks) => bookmarks,
         Err(err) => {
-            let _ = command.error(&ctx, GENERAL_ISSUE).await?;
+            let _ = command.error_localized(&ctx, ErrorKey::General).await?;
 
             return Err(err);
         }
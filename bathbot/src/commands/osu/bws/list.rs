@@ -0,0 +1,351 @@
+use std::{fmt::Write, mem, sync::Arc};
+
+use bathbot_macros::SlashCommand;
+use bathbot_model::rkyv_util::time::DateTimeRkyv;
+use bathbot_util::{matcher, TourneyBadges};
+use eyre::{Report, Result};
+use futures::{stream, StreamExt};
+use rkyv::{with::DeserializeWith, Infallible};
+use rosu_v2::{prelude::OsuError, request::UserId};
+use time::{Duration, OffsetDateTime};
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::channel::Attachment;
+
+use crate::{
+    active::{impls::BwsListPagination, ActiveMessages},
+    core::locale::ErrorKey,
+    embeds::bws,
+    manager::redis::{osu::UserArgs, RedisData},
+    util::{interaction::InteractionCommand, Authored, InteractionCommandExt},
+    Context,
+};
+
+/// Maximum size in bytes of uploaded player lists
+const MAX_FILE_SIZE: u64 = 65_536;
+
+/// Maximum amount of players per list
+const MAX_PLAYERS: usize = 128;
+
+/// Maximum amount of users that are requested at the same time
+const CONCURRENT_REQUESTS: usize = 8;
+
+#[derive(CommandModel, CreateCommand, SlashCommand)]
+#[command(
+    name = "bwslist",
+    desc = "Show the badge weighted seeding for a list of osu!standard players",
+    help = "Calculate the badge weighted seeding for a whole registration list \
+    and sort the players by it.\n\
+    The list must be a text or CSV file with one player per line. \
+    Only the first column of each line is considered which can be a username, \
+    a user id, or a profile url.\n\
+    The result can be restricted to a bws rank range and the badge count \
+    can be limited to badges awarded within the last few years.\n\
+    The sorted table is also attached as CSV file."
+)]
+pub struct BwsList {
+    #[command(desc = "Specify a text or CSV file containing the players")]
+    file: Attachment,
+    #[command(
+        min_value = 1,
+        desc = "Only include players with at least this bws rank"
+    )]
+    min_rank: Option<u32>,
+    #[command(
+        min_value = 1,
+        desc = "Only include players with at most this bws rank"
+    )]
+    max_rank: Option<u32>,
+    #[command(
+        min_value = 1,
+        max_value = 20,
+        desc = "Only count badges awarded within the last so many years"
+    )]
+    badge_years: Option<u32>,
+}
+
+/// A player of a registration list alongside its badge weighted seeding
+pub struct BwsEntry {
+    pub user_id: u32,
+    pub username: Box<str>,
+    pub global_rank: u32,
+    pub badges: usize,
+    pub bws: u64,
+}
+
+async fn slash_bwslist(ctx: Arc<Context>, mut command: InteractionCommand) -> Result<()> {
+    let BwsList {
+        file,
+        min_rank,
+        max_rank,
+        badge_years,
+    } = BwsList::from_interaction(command.input_data())?;
+
+    if file.size > MAX_FILE_SIZE {
+        let content = "The player list must not be larger than 64KB";
+        command.error(&ctx, content).await?;
+
+        return Ok(());
+    }
+
+    let bytes = match ctx.client().get_discord_attachment(&file).await {
+        Ok(bytes) => bytes,
+        Err(err) => {
+            let _ = command.error_localized(&ctx, ErrorKey::General).await;
+
+            return Err(err.wrap_err("Failed to download attachment"));
+        }
+    };
+
+    let Ok(input) = std::str::from_utf8(&bytes) else {
+        let content = "The player list must be provided as UTF-8 text file";
+        command.error(&ctx, content).await?;
+
+        return Ok(());
+    };
+
+    let players = parse_players(input);
+
+    if players.is_empty() {
+        let content = "The player list must contain at least one player";
+        command.error(&ctx, content).await?;
+
+        return Ok(());
+    } else if players.len() > MAX_PLAYERS {
+        let content = format!("The player list must not contain more than {MAX_PLAYERS} players");
+        command.error(&ctx, content).await?;
+
+        return Ok(());
+    }
+
+    let (min_rank, max_rank) = match (min_rank, max_rank) {
+        (Some(mut min), Some(mut max)) => {
+            if min > max {
+                mem::swap(&mut min, &mut max);
+            }
+
+            (min, max)
+        }
+        (min, max) => (min.unwrap_or(1), max.unwrap_or(u32::MAX)),
+    };
+
+    let cutoff =
+        badge_years.map(|years| OffsetDateTime::now_utc() - Duration::days(365 * years as i64));
+
+    let ctx_ref = ctx.as_ref();
+
+    let mut user_futs = stream::iter(players)
+        .map(|user_id| async move {
+            let user_args = UserArgs::rosu_id(ctx_ref, &user_id).await;
+
+            (user_id, ctx_ref.redis().osu_user(user_args).await)
+        })
+        .buffer_unordered(CONCURRENT_REQUESTS);
+
+    let mut entries = Vec::new();
+    let mut not_found = Vec::new();
+    let mut unranked = 0;
+
+    while let Some((user_id, res)) = user_futs.next().await {
+        let user = match res {
+            Ok(user) => user,
+            Err(err) => {
+                if !matches!(err, OsuError::NotFound) {
+                    let err = Report::new(err).wrap_err("Failed to get user");
+                    warn!(?err, ?user_id, "Skipping player of bws list");
+                }
+
+                let name = match user_id {
+                    UserId::Id(user_id) => user_id.to_string().into_boxed_str(),
+                    UserId::Name(name) => name.as_str().into(),
+                };
+
+                not_found.push(name);
+
+                continue;
+            }
+        };
+
+        let global_rank = user.stats().global_rank();
+
+        if global_rank == 0 {
+            unranked += 1;
+
+            continue;
+        }
+
+        let badges = match user {
+            RedisData::Original(ref user) => {
+                let badges = user
+                    .badges
+                    .iter()
+                    .map(|badge| (badge.awarded_at, &*badge.description));
+
+                count_badges(badges, cutoff)
+            }
+            RedisData::Archive(ref user) => {
+                let badges = user.badges.iter().map(|badge| {
+                    let awarded_at =
+                        DateTimeRkyv::deserialize_with(&badge.awarded_at, &mut Infallible).unwrap();
+
+                    (awarded_at, &*badge.description)
+                });
+
+                count_badges(badges, cutoff)
+            }
+        };
+
+        entries.push(BwsEntry {
+            user_id: user.user_id(),
+            username: user.username().into(),
+            global_rank,
+            badges,
+            bws: bws(global_rank, badges),
+        });
+    }
+
+    let total = entries.len();
+
+    entries.retain(|entry| (min_rank as u64..=max_rank as u64).contains(&entry.bws));
+    entries.sort_unstable_by_key(|entry| (entry.bws, entry.global_rank));
+    not_found.sort_unstable();
+
+    let filtered = total - entries.len();
+    let csv = to_csv(&entries);
+
+    let pagination = BwsListPagination::builder()
+        .entries(entries.into_boxed_slice())
+        .filtered(filtered)
+        .unranked(unranked)
+        .not_found(not_found.into_boxed_slice())
+        .badge_years(badge_years)
+        .msg_owner(command.user_id()?)
+        .build();
+
+    ActiveMessages::builder(pagination)
+        .attachment(Some(("bws.csv".to_owned(), csv.into_bytes())))
+        .start_by_update(true)
+        .begin(ctx, &mut command)
+        .await
+}
+
+/// Parses the first column of each line as username, user id, or profile url.
+///
+/// Empty lines and a header line are skipped, duplicates are removed.
+fn parse_players(input: &str) -> Vec<UserId> {
+    const HEADERS: [&str; 6] = ["username", "name", "user", "user_id", "id", "player"];
+
+    let mut players = Vec::new();
+
+    for (line, i) in input.lines().zip(0..) {
+        let field = line
+            .split([',', ';', '\t'])
+            .next()
+            .unwrap_or_default()
+            .trim()
+            .trim_matches('"')
+            .trim();
+
+        if field.is_empty()
+            || (i == 0
+                && HEADERS
+                    .iter()
+                    .any(|header| field.eq_ignore_ascii_case(header)))
+        {
+            continue;
+        }
+
+        let user_id = match matcher::get_osu_user_id(field) {
+            Some(user_id) => user_id,
+            None => match field.parse() {
+                Ok(user_id) => UserId::Id(user_id),
+                Err(_) => UserId::Name(field.into()),
+            },
+        };
+
+        let is_duplicate = players.iter().any(|other| match (other, &user_id) {
+            (UserId::Id(a), UserId::Id(b)) => a == b,
+            (UserId::Name(a), UserId::Name(b)) => a.eq_ignore_ascii_case(b),
+            _ => false,
+        });
+
+        if !is_duplicate {
+            players.push(user_id);
+        }
+    }
+
+    players
+}
+
+/// Counts tourney badges while ignoring badges awarded before `cutoff`.
+fn count_badges<'b, I>(badges: I, cutoff: Option<OffsetDateTime>) -> usize
+where
+    I: Iterator<Item = (OffsetDateTime, &'b str)>,
+{
+    let badges = badges
+        .filter(|(awarded_at, _)| cutoff.map_or(true, |cutoff| *awarded_at >= cutoff))
+        .map(|(_, description)| description);
+
+    TourneyBadges::count(badges)
+}
+
+fn to_csv(entries: &[BwsEntry]) -> String {
+    let mut csv = String::with_capacity(48 + entries.len() * 48);
+    csv.push_str("seed,user_id,username,global_rank,badges,bws\n");
+
+    for (entry, i) in entries.iter().zip(1..) {
+        let _ = writeln!(
+            csv,
+            "{i},{},{},{},{},{}",
+            entry.user_id, entry.username, entry.global_rank, entry.badges, entry.bws
+        );
+    }
+
+    csv
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn timestamp(secs: i64) -> OffsetDateTime {
+        OffsetDateTime::from_unix_timestamp(secs).unwrap()
+    }
+
+    #[test]
+    fn players_from_csv() {
+        let input = "Username,Discord\n\
+            badewanne3,bade#0001\n\
+            \n\
+            \"mrekk\";something\n\
+            2211396\n\
+            https://osu.ppy.sh/users/7562902\n\
+            BADEWANNE3\n\
+            2211396";
+
+        let players = parse_players(input);
+
+        assert_eq!(
+            players,
+            [
+                UserId::Name("badewanne3".into()),
+                UserId::Name("mrekk".into()),
+                UserId::Id(2211396),
+                UserId::Id(7562902),
+            ]
+        );
+    }
+
+    #[test]
+    fn badges_within_years() {
+        let badges = [
+            (timestamp(1_433_116_800), "osu! World Cup 2015 Winning Team"),
+            (timestamp(1_685_577_600), "Maple Cup 2023 Winner"),
+            (timestamp(1_688_169_600), "Elite Mapper 2023"),
+        ];
+
+        let cutoff = Some(timestamp(1_577_836_800));
+
+        assert_eq!(count_badges(badges.into_iter(), None), 2);
+        assert_eq!(count_badges(badges.into_iter(), cutoff), 1);
+    }
+}
@@ -1,10 +1,7 @@
 use std::{borrow::Cow, mem, sync::Arc};
 
 use bathbot_macros::{command, HasName, SlashCommand};
-use bathbot_util::{
-    constants::{GENERAL_ISSUE, OSU_API_ISSUE},
-    matcher, MessageBuilder, TourneyBadges,
-};
+use bathbot_util::{matcher, MessageBuilder, TourneyBadges};
 use eyre::{Report, Result};
 use rosu_v2::{prelude::OsuError, request::UserId};
 use twilight_interactions::command::{CommandModel, CreateCommand};
@@ -12,13 +9,20 @@ use twilight_model::id::{marker::UserMarker, Id};
 
 use super::{require_link, user_not_found};
 use crate::{
-    core::commands::{prefix::Args, CommandOrigin},
+    core::{
+        commands::{prefix::Args, CommandOrigin},
+        locale::ErrorKey,
+    },
     embeds::{BWSEmbed, EmbedData},
     manager::redis::{osu::UserArgs, RedisData},
     util::{interaction::InteractionCommand, ChannelExt, InteractionCommandExt},
     Context,
 };
 
+pub use self::list::{BwsEntry, BwsList};
+
+mod list;
+
 #[derive(CommandModel, CreateCommand, HasName, SlashCommand)]
 #[command(
     name = "bws",
@@ -148,7 +152,7 @@ async fn bws(ctx: Arc<Context>, orig: CommandOrigin<'_>, args: Bws<'_>) -> Resul
             Ok(Some(user_id)) => UserId::Id(user_id),
             Ok(None) => return require_link(&ctx, &orig).await,
             Err(err) => {
-                let _ = orig.error(&ctx, GENERAL_ISSUE).await;
+                let _ = orig.error_localized(&ctx, ErrorKey::General).await;
 
                 return Err(err);
             }
@@ -167,7 +171,7 @@ async fn bws(ctx: Arc<Context>, orig: CommandOrigin<'_>, args: Bws<'_>) -> Resul
             return orig.error(&ctx, content).await;
         }
         Err(err) => {
-            let _ = orig.error(&ctx, OSU_API_ISSUE).await;
+            let _ = orig.error_localized(&ctx, ErrorKey::OsuApi).await;
             let err = Report::new(err).wrap_err("failed to get user");
 
             return Err(err);
@@ -3,10 +3,7 @@ use std::{collections::HashMap, sync::Arc};
 use bathbot_cards::BathbotCard;
 use bathbot_macros::{HasName, SlashCommand};
 use bathbot_util::{
-    constants::{GENERAL_ISSUE, OSEKAI_ISSUE, OSU_API_ISSUE},
-    datetime::DATE_FORMAT,
-    osu::flag_url_size,
-    EmbedBuilder, IntHasher, MessageBuilder,
+    datetime::DATE_FORMAT, osu::flag_url_size, EmbedBuilder, IntHasher, MessageBuilder,
 };
 use eyre::{Report, Result, WrapErr};
 use futures::{stream::FuturesUnordered, TryStreamExt};
@@ -18,7 +15,7 @@ use twilight_model::id::{marker::UserMarker, Id};
 use super::user_not_found;
 use crate::{
     commands::GameModeOption,
-    core::{commands::CommandOrigin, BotConfig, Context},
+    core::{commands::CommandOrigin, locale::ErrorKey, BotConfig, Context},
     embeds::attachment,
     manager::redis::{osu::UserArgs, RedisData},
     util::{interaction::InteractionCommand, InteractionCommandExt},
@@ -116,13 +113,13 @@ async fn slash_card(ctx: Arc<Context>, mut command: InteractionCommand) -> Resul
             return orig.error(&ctx, content).await;
         }
         (Err(err), _) => {
-            let _ = orig.error(&ctx, OSU_API_ISSUE).await;
+            let _ = orig.error_localized(&ctx, ErrorKey::OsuApi).await;
             let err = Report::new(err).wrap_err("failed to get user");
 
             return Err(err);
         }
         (_, Err(err)) => {
-            let _ = orig.error(&ctx, OSEKAI_ISSUE).await;
+            let _ = orig.error_localized(&ctx, ErrorKey::Osekai).await;
 
             return Err(err.wrap_err("failed to get cached medals"));
         }
@@ -164,12 +161,12 @@ async fn slash_card(ctx: Arc<Context>, mut command: InteractionCommand) -> Resul
     let (pfp, flag) = match tokio::join!(pfp_fut, flag_fut) {
         (Ok(pfp), Ok(flag)) => (pfp, flag),
         (Err(err), _) => {
-            let _ = orig.error(&ctx, GENERAL_ISSUE).await;
+            let _ = orig.error_localized(&ctx, ErrorKey::General).await;
 
             return Err(err.wrap_err("Failed to acquire card avatar"));
         }
         (_, Err(err)) => {
-            let _ = orig.error(&ctx, GENERAL_ISSUE).await;
+            let _ = orig.error_localized(&ctx, ErrorKey::General).await;
 
             return Err(err.wrap_err("Failed to acquire card flag"));
         }
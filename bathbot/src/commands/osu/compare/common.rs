@@ -2,10 +2,7 @@ use std::{borrow::Cow, cmp::Ordering, collections::HashMap, fmt::Write, iter, sy
 
 use bathbot_macros::{command, SlashCommand};
 use bathbot_model::rosu_v2::user::User;
-use bathbot_util::{
-    constants::{GENERAL_ISSUE, OSU_API_ISSUE},
-    matcher, IntHasher,
-};
+use bathbot_util::{matcher, IntHasher};
 use eyre::{Report, Result};
 use rkyv::{Deserialize, Infallible};
 use rosu_v2::{
@@ -27,7 +24,10 @@ use crate::{
         osu::{user_not_found, UserExtraction},
         GameModeOption,
     },
-    core::commands::{prefix::Args, CommandOrigin},
+    core::{
+        commands::{prefix::Args, CommandOrigin},
+        locale::ErrorKey,
+    },
     manager::redis::{osu::UserArgs, RedisData},
     util::{interaction::InteractionCommand, osu::get_combined_thumbnail, InteractionCommandExt},
     Context,
@@ -162,7 +162,7 @@ pub(super) async fn top(
     let user_id1 = match extract_user_id(&ctx, &mut args).await {
         UserExtraction::Id(user_id) => user_id,
         UserExtraction::Err(err) => {
-            let _ = orig.error(&ctx, GENERAL_ISSUE).await;
+            let _ = orig.error_localized(&ctx, ErrorKey::General).await;
 
             return Err(err);
         }
@@ -173,7 +173,7 @@ pub(super) async fn top(
     let user_id2 = match extract_user_id(&ctx, &mut args).await {
         UserExtraction::Id(user_id) => user_id,
         UserExtraction::Err(err) => {
-            let _ = orig.error(&ctx, GENERAL_ISSUE).await;
+            let _ = orig.error_localized(&ctx, ErrorKey::General).await;
 
             return Err(err);
         }
@@ -187,7 +187,7 @@ pub(super) async fn top(
                 return orig.error(&ctx, content).await;
             }
             Err(err) => {
-                let _ = orig.error(&ctx, GENERAL_ISSUE).await;
+                let _ = orig.error_localized(&ctx, ErrorKey::General).await;
 
                 return Err(err);
             }
@@ -203,7 +203,7 @@ pub(super) async fn top(
         None => match ctx.user_config().mode(owner).await {
             Ok(mode) => mode.unwrap_or(GameMode::Osu),
             Err(err) => {
-                let _ = orig.error(&ctx, GENERAL_ISSUE).await;
+                let _ = orig.error_localized(&ctx, ErrorKey::General).await;
 
                 return Err(err);
             }
@@ -226,7 +226,7 @@ pub(super) async fn top(
             return orig.error(&ctx, content).await;
         }
         (Err(err), _) | (_, Err(err)) => {
-            let _ = orig.error(&ctx, OSU_API_ISSUE).await;
+            let _ = orig.error_localized(&ctx, ErrorKey::OsuApi).await;
             let err = Report::new(err).wrap_err("failed to get scores");
 
             return Err(err);
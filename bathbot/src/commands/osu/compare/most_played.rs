@@ -2,10 +2,7 @@ use std::{cmp::Reverse, collections::HashMap, fmt::Write, sync::Arc};
 
 use bathbot_macros::command;
 use bathbot_model::rosu_v2::user::User;
-use bathbot_util::{
-    constants::{GENERAL_ISSUE, OSU_API_ISSUE},
-    matcher, IntHasher, MessageBuilder,
-};
+use bathbot_util::{matcher, IntHasher, MessageBuilder};
 use eyre::{Report, Result};
 use rosu_v2::{
     prelude::{MostPlayedMap, OsuError},
@@ -17,7 +14,7 @@ use super::{CompareMostPlayed, AT_LEAST_ONE};
 use crate::{
     active::{impls::CompareMostPlayedPagination, ActiveMessages},
     commands::osu::{user_not_found, UserExtraction},
-    core::commands::CommandOrigin,
+    core::{commands::CommandOrigin, locale::ErrorKey},
     manager::redis::{osu::UserArgs, RedisData},
     Context,
 };
@@ -78,7 +75,7 @@ pub(super) async fn mostplayed(
     let user_id1 = match extract_user_id(&ctx, &mut args).await {
         UserExtraction::Id(user_id) => user_id,
         UserExtraction::Err(err) => {
-            let _ = orig.error(&ctx, GENERAL_ISSUE).await;
+            let _ = orig.error_localized(&ctx, ErrorKey::General).await;
 
             return Err(err);
         }
@@ -89,7 +86,7 @@ pub(super) async fn mostplayed(
     let user_id2 = match extract_user_id(&ctx, &mut args).await {
         UserExtraction::Id(user_id) => user_id,
         UserExtraction::Err(err) => {
-            let _ = orig.error(&ctx, GENERAL_ISSUE).await;
+            let _ = orig.error_localized(&ctx, ErrorKey::General).await;
 
             return Err(err);
         }
@@ -103,7 +100,7 @@ pub(super) async fn mostplayed(
                 return orig.error(&ctx, content).await;
             }
             Err(err) => {
-                let _ = orig.error(&ctx, GENERAL_ISSUE).await;
+                let _ = orig.error_localized(&ctx, ErrorKey::General).await;
 
                 return Err(err);
             }
@@ -126,7 +123,7 @@ pub(super) async fn mostplayed(
             return orig.error(&ctx, content).await;
         }
         (Err(err), _) | (_, Err(err)) => {
-            let _ = orig.error(&ctx, OSU_API_ISSUE).await;
+            let _ = orig.error_localized(&ctx, ErrorKey::OsuApi).await;
             let err = Report::new(err).wrap_err("failed to get scores");
 
             return Err(err);
@@ -3,7 +3,6 @@ use std::{borrow::Cow, io::Cursor, sync::Arc};
 use bathbot_macros::{command, SlashCommand};
 use bathbot_model::RespektiveUser;
 use bathbot_util::{
-    constants::{GENERAL_ISSUE, OSU_API_ISSUE},
     matcher,
     numbers::MinMaxAvg,
     osu::{BonusPP, UserStats},
@@ -29,7 +28,10 @@ use twilight_model::{
 use super::{CompareProfile, AT_LEAST_ONE};
 use crate::{
     commands::{osu::UserExtraction, GameModeOption},
-    core::commands::{prefix::Args, CommandOrigin},
+    core::{
+        commands::{prefix::Args, CommandOrigin},
+        locale::ErrorKey,
+    },
     embeds::{EmbedData, ProfileCompareEmbed},
     manager::redis::osu::UserArgs,
     util::{interaction::InteractionCommand, InteractionCommandExt},
@@ -94,7 +96,7 @@ pub(super) async fn profile(
     let user_id1 = match extract_user_id(&ctx, &mut args).await {
         UserExtraction::Id(user_id) => user_id,
         UserExtraction::Err(err) => {
-            let _ = orig.error(&ctx, GENERAL_ISSUE).await;
+            let _ = orig.error_localized(&ctx, ErrorKey::General).await;
 
             return Err(err);
         }
@@ -105,7 +107,7 @@ pub(super) async fn profile(
     let user_id2 = match extract_user_id(&ctx, &mut args).await {
         UserExtraction::Id(user_id) => user_id,
         UserExtraction::Err(err) => {
-            let _ = orig.error(&ctx, GENERAL_ISSUE).await;
+            let _ = orig.error_localized(&ctx, ErrorKey::General).await;
 
             return Err(err);
         }
@@ -119,7 +121,7 @@ pub(super) async fn profile(
                 return orig.error(&ctx, content).await;
             }
             Err(err) => {
-                let _ = orig.error(&ctx, GENERAL_ISSUE).await;
+                let _ = orig.error_localized(&ctx, ErrorKey::General).await;
 
                 return Err(err);
             }
@@ -135,7 +137,7 @@ pub(super) async fn profile(
         None => match ctx.user_config().mode(orig.user_id()?).await {
             Ok(mode) => mode.unwrap_or(GameMode::Osu),
             Err(err) => {
-                let _ = orig.error(&ctx, GENERAL_ISSUE).await;
+                let _ = orig.error_localized(&ctx, ErrorKey::General).await;
 
                 return Err(err);
             }
@@ -158,7 +160,7 @@ pub(super) async fn profile(
             return orig.error(&ctx, content).await;
         }
         Err(err) => {
-            let _ = orig.error(&ctx, OSU_API_ISSUE).await;
+            let _ = orig.error_localized(&ctx, ErrorKey::OsuApi).await;
             let err = Report::new(err).wrap_err("Failed to get user and scores");
 
             return Err(err);
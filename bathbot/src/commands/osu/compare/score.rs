@@ -8,7 +8,7 @@ use bathbot_macros::{command, HasMods, HasName, SlashCommand};
 use bathbot_model::{rosu_v2::user::User, ScoreSlim};
 use bathbot_psql::model::osu::{ArchivedMapVersion, MapVersion};
 use bathbot_util::{
-    constants::{AVATAR_URL, GENERAL_ISSUE, OSU_API_ISSUE, OSU_BASE},
+    constants::{AVATAR_URL, OSU_BASE},
     matcher,
     osu::{MapIdType, ModSelection},
     CowUtils, EmbedBuilder, FooterBuilder, MessageBuilder, MessageOrigin,
@@ -34,9 +34,12 @@ use super::{CompareScoreAutocomplete, ScoreOrder};
 use crate::{
     active::{impls::CompareScoresPagination, ActiveMessages},
     commands::osu::{require_link, HasMods, ModsResult},
-    core::commands::{
-        prefix::{Args, ArgsNum},
-        CommandOrigin,
+    core::{
+        commands::{
+            prefix::{Args, ArgsNum},
+            CommandOrigin,
+        },
+        locale::ErrorKey,
     },
     manager::{
         redis::{
@@ -350,7 +353,7 @@ pub(super) async fn score(
                 None => return require_link(&ctx, &orig).await,
             },
             Err(err) => {
-                let _ = orig.error(&ctx, GENERAL_ISSUE).await;
+                let _ = orig.error_localized(&ctx, ErrorKey::General).await;
 
                 return Err(err.wrap_err("Failed to get user config"));
             }
@@ -392,7 +395,7 @@ pub(super) async fn score(
                 let msgs = match ctx.retrieve_channel_history(orig.channel_id()).await {
                     Ok(msgs) => msgs,
                     Err(err) => {
-                        let _ = orig.error(&ctx, GENERAL_ISSUE).await;
+                        let _ = orig.error_localized(&ctx, ErrorKey::General).await;
 
                         return Err(err.wrap_err("failed to retrieve channel history"));
                     }
@@ -442,7 +445,7 @@ pub(super) async fn score(
             return orig.error(&ctx, content).await;
         }
         Err(MapError::Report(err)) => {
-            let _ = orig.error(&ctx, GENERAL_ISSUE).await;
+            let _ = orig.error_localized(&ctx, ErrorKey::General).await;
 
             return Err(err);
         }
@@ -484,7 +487,7 @@ pub(super) async fn score(
             return orig.error(&ctx, content).await;
         }
         (Err(err), _) | (_, Err(err)) => {
-            let _ = orig.error(&ctx, OSU_API_ISSUE).await;
+            let _ = orig.error_localized(&ctx, ErrorKey::OsuApi).await;
             let err = Report::new(err).wrap_err("failed to get user or scores");
 
             return Err(err);
@@ -502,7 +505,7 @@ pub(super) async fn score(
     let entries = match process_fut.await {
         Ok(entries) => entries,
         Err(err) => {
-            let _ = orig.error(&ctx, GENERAL_ISSUE).await;
+            let _ = orig.error_localized(&ctx, ErrorKey::General).await;
 
             return Err(err.wrap_err("Failed to process scores"));
         }
@@ -730,7 +733,7 @@ async fn compare_from_score(
     let mut score = match ctx.osu().score(score_id, mode).await {
         Ok(score) => score,
         Err(err) => {
-            let _ = orig.error(&ctx, OSU_API_ISSUE).await;
+            let _ = orig.error_localized(&ctx, ErrorKey::OsuApi).await;
             let report = Report::new(err).wrap_err("failed to get score");
 
             return Err(report);
@@ -755,7 +758,7 @@ async fn compare_from_score(
     let user = match user_res {
         Ok(user) => user,
         Err(err) => {
-            let _ = orig.error(&ctx, OSU_API_ISSUE).await;
+            let _ = orig.error_localized(&ctx, ErrorKey::OsuApi).await;
             let err = Report::new(err).wrap_err("failed to get user");
 
             return Err(err);
@@ -767,7 +770,7 @@ async fn compare_from_score(
     let map = match map_res {
         Ok(map) => map,
         Err(err) => {
-            let _ = orig.error(&ctx, GENERAL_ISSUE).await;
+            let _ = orig.error_localized(&ctx, ErrorKey::General).await;
 
             return Err(Report::new(err));
         }
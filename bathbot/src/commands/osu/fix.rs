@@ -3,7 +3,6 @@ use std::{borrow::Cow, sync::Arc};
 use bathbot_macros::{command, HasMods, HasName, SlashCommand};
 use bathbot_model::{rosu_v2::user::User, ScoreSlim};
 use bathbot_util::{
-    constants::{GENERAL_ISSUE, OSU_API_ISSUE},
     matcher,
     osu::{MapIdType, ModSelection},
 };
@@ -21,7 +20,10 @@ use twilight_model::{
 
 use super::{require_link, user_not_found, HasMods, ModsResult};
 use crate::{
-    core::commands::{prefix::Args, CommandOrigin},
+    core::{
+        commands::{prefix::Args, CommandOrigin},
+        locale::ErrorKey,
+    },
     embeds::{EmbedData, FixScoreEmbed},
     manager::{
         redis::{
@@ -202,7 +204,7 @@ async fn fix(ctx: Arc<Context>, orig: CommandOrigin<'_>, args: FixArgs<'_>) -> R
             Ok(Some(user_id)) => UserId::Id(user_id),
             Ok(None) => return require_link(&ctx, &orig).await,
             Err(err) => {
-                let _ = orig.error(&ctx, GENERAL_ISSUE).await;
+                let _ = orig.error_localized(&ctx, ErrorKey::General).await;
 
                 return Err(err);
             }
@@ -241,7 +243,7 @@ async fn fix(ctx: Arc<Context>, orig: CommandOrigin<'_>, args: FixArgs<'_>) -> R
             let msgs = match ctx.retrieve_channel_history(orig.channel_id()).await {
                 Ok(msgs) => msgs,
                 Err(err) => {
-                    let _ = orig.error(&ctx, GENERAL_ISSUE).await;
+                    let _ = orig.error_localized(&ctx, ErrorKey::General).await;
 
                     return Err(err);
                 }
@@ -325,7 +327,7 @@ async fn request_by_map(
             };
         }
         Err(MapError::Report(err)) => {
-            let _ = orig.error(ctx, GENERAL_ISSUE).await;
+            let _ = orig.error_localized(ctx, ErrorKey::General).await;
 
             return ScoreResult::Error(err);
         }
@@ -358,7 +360,7 @@ async fn request_by_map(
             };
         }
         Err(err) => {
-            let _ = orig.error(ctx, OSU_API_ISSUE).await;
+            let _ = orig.error_localized(ctx, ErrorKey::OsuApi).await;
             let wrap = "Failed to get user";
 
             return ScoreResult::Error(Report::new(err).wrap_err(wrap));
@@ -379,7 +381,7 @@ async fn request_by_map(
             }),
         },
         Err(err) => {
-            let _ = orig.error(ctx, OSU_API_ISSUE).await;
+            let _ = orig.error_localized(ctx, ErrorKey::OsuApi).await;
             let wrap = "Failed to get scores";
 
             return ScoreResult::Error(Report::new(err).wrap_err(wrap));
@@ -404,7 +406,7 @@ async fn request_by_map(
             let top = match top_res {
                 Ok(scores) => scores,
                 Err(err) => {
-                    let _ = orig.error(ctx, OSU_API_ISSUE).await;
+                    let _ = orig.error_localized(ctx, ErrorKey::OsuApi).await;
                     let wrap = "failed to get top scores";
 
                     return ScoreResult::Error(Report::new(err).wrap_err(wrap));
@@ -455,7 +457,7 @@ async fn request_by_score(
             };
         }
         (Err(err), _) | (_, Err(err)) => {
-            let _ = orig.error(ctx, OSU_API_ISSUE).await;
+            let _ = orig.error_localized(ctx, ErrorKey::OsuApi).await;
             let err = Report::new(err).wrap_err("failed to get user or scores");
 
             return ScoreResult::Error(err);
@@ -480,12 +482,12 @@ async fn request_by_score(
             };
         }
         (Err(MapError::Report(err)), _) => {
-            let _ = orig.error(ctx, GENERAL_ISSUE).await;
+            let _ = orig.error_localized(ctx, ErrorKey::General).await;
 
             return ScoreResult::Error(err);
         }
         (_, Err(err)) => {
-            let _ = orig.error(ctx, OSU_API_ISSUE).await;
+            let _ = orig.error_localized(ctx, ErrorKey::OsuApi).await;
             let err = Report::new(err).wrap_err("failed to get top scores");
 
             return ScoreResult::Error(err);
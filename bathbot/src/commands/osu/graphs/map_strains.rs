@@ -0,0 +1,471 @@
+use std::{
+    fmt::{Display, Formatter, Result as FmtResult, Write},
+    time::Duration,
+};
+
+use bathbot_util::{
+    constants::OSU_BASE, matcher, osu::MapIdType, EmbedBuilder, FooterBuilder, MessageBuilder,
+};
+use eyre::{Result, WrapErr};
+use plotters::{
+    prelude::{ChartBuilder, IntoDrawingArea, PathElement, SeriesLabelPosition},
+    series::LineSeries,
+    style::{Color, RGBColor, CYAN, MAGENTA, RED, WHITE, YELLOW},
+};
+use plotters_backend::FontStyle;
+use plotters_skia::SkiaBackend;
+use rosu_pp::{BeatmapExt, Strains};
+use rosu_v2::prelude::{GameModIntermode, GameModsIntermode};
+use skia_safe::{surfaces, EncodedImageFormat};
+
+use super::{GraphMapStrains, H, W};
+use crate::{
+    commands::osu::{HasMods, ModsResult},
+    core::{commands::CommandOrigin, locale::ErrorKey, Context},
+    embeds::attachment,
+    manager::MapError,
+};
+
+/// Amount of strain sections that are combined into one displayed section
+const SECTION_WINDOW: usize = 10;
+
+/// Amount of displayed hardest sections
+const SECTION_COUNT: usize = 5;
+
+pub async fn map_strains_graph(
+    ctx: &Context,
+    orig: &CommandOrigin<'_>,
+    args: GraphMapStrains,
+) -> Result<()> {
+    let mods = match args.mods() {
+        ModsResult::Mods(mods) => mods.into_mods(),
+        ModsResult::None => GameModsIntermode::new(),
+        ModsResult::Invalid => {
+            let content =
+                "Failed to parse mods. Be sure to specify a valid abbreviation e.g. `hdhr`.";
+
+            return orig.error(ctx, content).await;
+        }
+    };
+
+    let map_id = match args.map.as_deref() {
+        Some(arg) => match matcher::get_osu_map_id(arg) {
+            Some(id) => id,
+            None if matcher::get_osu_mapset_id(arg).is_some() => {
+                let content = "Looks like you gave me a mapset id, I need a map id though";
+
+                return orig.error(ctx, content).await;
+            }
+            None => {
+                let content =
+                    "Failed to parse map url. Be sure you specify a valid map id or url to a map.";
+
+                return orig.error(ctx, content).await;
+            }
+        },
+        None if orig.can_read_history() => {
+            let msgs = match ctx.retrieve_channel_history(orig.channel_id()).await {
+                Ok(msgs) => msgs,
+                Err(err) => {
+                    let _ = orig.error_localized(ctx, ErrorKey::General).await;
+
+                    return Err(err.wrap_err("Failed to retrieve channel history"));
+                }
+            };
+
+            match ctx.find_map_id_in_msgs(&msgs, 0).await {
+                Some(MapIdType::Map(id)) => id,
+                None | Some(MapIdType::Set(_)) => {
+                    let content = "No beatmap specified and none found in recent channel history. \
+                        Try specifying a map either by url to the map, or just by map id.";
+
+                    return orig.error(ctx, content).await;
+                }
+            }
+        }
+        None => {
+            let content =
+                "No beatmap specified and lacking permission to search the channel history for maps.\n\
+                Try specifying a map either by url to the map, or just by map id, \
+                or give me the \"Read Message History\" permission.";
+
+            return orig.error(ctx, content).await;
+        }
+    };
+
+    let map = match ctx.osu_map().map(map_id, None).await {
+        Ok(map) => map,
+        Err(MapError::NotFound) => {
+            let content = format!(
+                "Could not find beatmap with id `{map_id}`. \
+                Did you give me a mapset id instead of a map id?",
+            );
+
+            return orig.error(ctx, content).await;
+        }
+        Err(MapError::Report(err)) => {
+            let _ = orig.error_localized(ctx, ErrorKey::General).await;
+
+            return Err(err);
+        }
+    };
+
+    match mods.clone().with_mode(map.mode()) {
+        Some(mods) if !mods.is_valid() => {
+            let content =
+                format!("Looks like some mods in `{mods}` are incompatible with each other");
+
+            return orig.error(ctx, content).await;
+        }
+        Some(_) => {}
+        None => {
+            let content = format!(
+                "The mods `{mods}` are incompatible with the map's mode {:?}",
+                map.mode()
+            );
+
+            return orig.error(ctx, content).await;
+        }
+    }
+
+    let strains = map.pp_map.strains(mods.bits());
+    let section_len = strains.section_len();
+    let skills = skill_strains(&strains, mods.contains(GameModIntermode::Flashlight));
+
+    let sections = hardest_sections(&skills, section_len);
+
+    let graph = match draw_graph(&skills, section_len) {
+        Ok(graph) => graph,
+        Err(err) => {
+            let _ = orig.error_localized(ctx, ErrorKey::General).await;
+
+            return Err(err.wrap_err("Failed to create map strains graph"));
+        }
+    };
+
+    let mut description = String::with_capacity(256);
+
+    if mods.is_empty() {
+        description.push_str("Hardest sections:\n");
+    } else {
+        let _ = writeln!(description, "Hardest sections with `+{mods}`:");
+    }
+
+    if sections.is_empty() {
+        description.push_str("No strain in the whole map :eyes:");
+    } else {
+        description.push_str("```\n #  | Time          | Skill      | Difficulty\n");
+        description.push_str("----+---------------+------------+-----------\n");
+
+        let max = sections[0].strain;
+
+        for (section, i) in sections.iter().zip(1..) {
+            let _ = writeln!(
+                description,
+                "{i:>2}  | {start:>5} - {end:<5} | {skill:<10} | {percent:>6.2}%",
+                start = MsToMinSec(section.start),
+                end = MsToMinSec(section.end),
+                skill = section.skill,
+                percent = 100.0 * section.strain / max,
+            );
+        }
+
+        description.push_str("```");
+    }
+
+    let title = format!(
+        "{artist} - {title} [{version}]",
+        artist = map.artist(),
+        title = map.title(),
+        version = map.version(),
+    );
+
+    let embed = EmbedBuilder::new()
+        .description(description)
+        .footer(FooterBuilder::new(map.footer_text()))
+        .image(attachment("map_strains.png"))
+        .title(title)
+        .url(format!("{OSU_BASE}b/{map_id}"));
+
+    let builder = MessageBuilder::new()
+        .embed(embed)
+        .attachment("map_strains.png", graph);
+
+    orig.create_message(ctx, builder).await?;
+
+    Ok(())
+}
+
+struct SkillStrains<'s> {
+    name: &'static str,
+    strains: &'s [f64],
+    color: RGBColor,
+}
+
+fn skill_strains(strains: &Strains, flashlight: bool) -> Vec<SkillStrains<'_>> {
+    let mut skills = Vec::with_capacity(3);
+
+    macro_rules! push_skill {
+        ( $name:literal, $strains:expr, $color:ident ) => {
+            skills.push(SkillStrains {
+                name: $name,
+                strains: &$strains,
+                color: $color,
+            })
+        };
+    }
+
+    match strains {
+        Strains::Osu(strains) => {
+            push_skill!("Aim", strains.aim, CYAN);
+            push_skill!("Speed", strains.speed, RED);
+
+            if flashlight {
+                push_skill!("Flashlight", strains.flashlight, MAGENTA);
+            }
+        }
+        Strains::Taiko(strains) => {
+            push_skill!("Stamina", strains.stamina, RED);
+            push_skill!("Color", strains.color, YELLOW);
+            push_skill!("Rhythm", strains.rhythm, CYAN);
+        }
+        Strains::Catch(strains) => push_skill!("Movement", strains.movement, CYAN),
+        Strains::Mania(strains) => push_skill!("Strain", strains.strains, MAGENTA),
+    }
+
+    skills
+}
+
+#[derive(Debug, PartialEq)]
+struct HardSection {
+    /// Start timestamp in milliseconds
+    start: f64,
+    /// End timestamp in milliseconds
+    end: f64,
+    /// Name of the skill that contributed the most strain
+    skill: &'static str,
+    /// Average strain across the section
+    strain: f64,
+}
+
+/// Combines strain sections into windows of [`SECTION_WINDOW`] sections and
+/// returns the [`SECTION_COUNT`] non-overlapping windows with the highest
+/// average strain, sorted in descending order.
+fn hardest_sections(skills: &[SkillStrains<'_>], section_len: f64) -> Vec<HardSection> {
+    let len = skills
+        .iter()
+        .map(|skill| skill.strains.len())
+        .max()
+        .unwrap_or(0);
+
+    if len == 0 {
+        return Vec::new();
+    }
+
+    let window = SECTION_WINDOW.min(len);
+
+    let combined: Vec<_> = (0..len)
+        .map(|i| {
+            skills
+                .iter()
+                .filter_map(|skill| skill.strains.get(i))
+                .fold(0.0_f64, |max, strain| max.max(*strain))
+        })
+        .collect();
+
+    let mut windows: Vec<(usize, f64)> = combined
+        .windows(window)
+        .enumerate()
+        .map(|(start, strains)| (start, strains.iter().sum::<f64>() / window as f64))
+        .filter(|(_, strain)| *strain > f64::EPSILON)
+        .collect();
+
+    windows.sort_unstable_by(|(_, a), (_, b)| b.total_cmp(a));
+
+    let mut picked: Vec<(usize, f64)> = Vec::with_capacity(SECTION_COUNT);
+
+    for (start, strain) in windows {
+        if picked.len() == SECTION_COUNT {
+            break;
+        }
+
+        let overlaps = picked
+            .iter()
+            .any(|(picked_start, _)| start.abs_diff(*picked_start) < window);
+
+        if !overlaps {
+            picked.push((start, strain));
+        }
+    }
+
+    picked
+        .into_iter()
+        .map(|(start, strain)| {
+            let skill = skills
+                .iter()
+                .map(|skill| {
+                    let end = skill.strains.len().min(start + window);
+                    let sum: f64 = skill
+                        .strains
+                        .get(start..end)
+                        .map_or(0.0, |s| s.iter().sum());
+
+                    (skill.name, sum)
+                })
+                .max_by(|(_, a), (_, b)| a.total_cmp(b))
+                .map_or("", |(name, _)| name);
+
+            HardSection {
+                start: start as f64 * section_len,
+                end: (start + window) as f64 * section_len,
+                skill,
+                strain,
+            }
+        })
+        .collect()
+}
+
+fn draw_graph(skills: &[SkillStrains<'_>], section_len: f64) -> Result<Vec<u8>> {
+    let len = skills
+        .iter()
+        .map(|skill| skill.strains.len())
+        .max()
+        .unwrap_or(0);
+
+    let max_strain = skills
+        .iter()
+        .flat_map(|skill| skill.strains.iter())
+        .fold(0.0_f64, |max, strain| max.max(*strain));
+
+    if len == 0 || max_strain <= f64::EPSILON {
+        bail!("no non-zero strain point");
+    }
+
+    let last_timestamp = len as f64 * section_len;
+
+    let mut surface =
+        surfaces::raster_n32_premul((W as i32, H as i32)).wrap_err("Failed to create surface")?;
+
+    {
+        let root = SkiaBackend::new(surface.canvas(), W, H).into_drawing_area();
+
+        let background = RGBColor(19, 43, 33);
+        root.fill(&background)
+            .wrap_err("Failed to fill background")?;
+
+        let mut chart = ChartBuilder::on(&root)
+            .x_label_area_size(40_i32)
+            .margin_top(15_i32)
+            .margin_right(15_i32)
+            .margin_left(15_i32)
+            .build_cartesian_2d(0.0..last_timestamp, 0.0..max_strain * 1.05)
+            .wrap_err("Failed to build chart")?;
+
+        chart
+            .configure_mesh()
+            .disable_y_mesh()
+            .disable_y_axis()
+            .x_labels(15)
+            .x_label_formatter(&|timestamp| MsToMinSec(*timestamp).to_string())
+            .label_style(("sans-serif", 16_i32, &WHITE))
+            .bold_line_style(WHITE.mix(0.3))
+            .axis_style(RGBColor(7, 18, 14))
+            .draw()
+            .wrap_err("Failed to draw mesh")?;
+
+        for skill in skills {
+            let iter = skill
+                .strains
+                .iter()
+                .enumerate()
+                .map(|(i, strain)| (i as f64 * section_len, *strain));
+
+            let series = LineSeries::new(iter, skill.color.stroke_width(2));
+            let color = skill.color;
+
+            chart
+                .draw_series(series)
+                .wrap_err_with(|| format!("Failed to draw {} series", skill.name))?
+                .label(skill.name)
+                .legend(move |(x, y)| {
+                    PathElement::new(vec![(x, y), (x + 20, y)], color.stroke_width(2))
+                });
+        }
+
+        chart
+            .configure_series_labels()
+            .border_style(WHITE.mix(0.6).stroke_width(1))
+            .background_style(RGBColor(7, 23, 17))
+            .position(SeriesLabelPosition::UpperLeft)
+            .label_font(("sans-serif", 16_i32, FontStyle::Bold, &WHITE))
+            .draw()
+            .wrap_err("Failed to draw legend")?;
+    }
+
+    let png_bytes = surface
+        .image_snapshot()
+        .encode(None, EncodedImageFormat::PNG, None)
+        .wrap_err("Failed to encode image")?
+        .to_vec();
+
+    Ok(png_bytes)
+}
+
+struct MsToMinSec(f64);
+
+impl Display for MsToMinSec {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let secs = Duration::from_millis(self.0.max(0.0) as u64).as_secs();
+
+        write!(f, "{}:{:0>2}", secs / 60, secs % 60)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn picks_non_overlapping_peaks() {
+        let mut aim = vec![1.0; 100];
+        let mut speed = vec![1.0; 100];
+
+        aim[20..30].iter_mut().for_each(|strain| *strain = 5.0);
+        speed[60..70].iter_mut().for_each(|strain| *strain = 4.0);
+
+        let skills = [
+            SkillStrains {
+                name: "Aim",
+                strains: &aim,
+                color: CYAN,
+            },
+            SkillStrains {
+                name: "Speed",
+                strains: &speed,
+                color: RED,
+            },
+        ];
+
+        let sections = hardest_sections(&skills, 400.0);
+
+        assert_eq!(sections.len(), SECTION_COUNT);
+
+        assert_eq!(sections[0].skill, "Aim");
+        assert_eq!(sections[0].start, 8000.0);
+        assert_eq!(sections[0].end, 12_000.0);
+
+        assert_eq!(sections[1].skill, "Speed");
+        assert_eq!(sections[1].start, 24_000.0);
+
+        for (i, a) in sections.iter().enumerate() {
+            for b in sections.iter().skip(i + 1) {
+                assert!(a.end <= b.start || b.end <= a.start);
+            }
+        }
+    }
+
+    #[test]
+    fn no_strains() {
+        assert!(hardest_sections(&[], 400.0).is_empty());
+    }
+}
@@ -1,10 +1,7 @@
 use std::mem;
 
 use bathbot_model::rosu_v2::user::{MedalCompact as MedalCompactRkyv, User};
-use bathbot_util::{
-    constants::{GENERAL_ISSUE, OSU_API_ISSUE},
-    MessageBuilder,
-};
+use bathbot_util::MessageBuilder;
 use eyre::{Report, Result};
 use rkyv::{
     with::{DeserializeWith, Map},
@@ -15,7 +12,7 @@ use rosu_v2::{prelude::OsuError, request::UserId};
 use super::{H, W};
 use crate::{
     commands::osu::{medals::stats as medals_stats, user_not_found},
-    core::{commands::CommandOrigin, Context},
+    core::{commands::CommandOrigin, locale::ErrorKey, Context},
     manager::redis::{osu::UserArgs, RedisData},
 };
 
@@ -35,7 +32,7 @@ pub async fn medals_graph(
             return Ok(None);
         }
         Err(err) => {
-            let _ = orig.error(ctx, OSU_API_ISSUE).await;
+            let _ = orig.error_localized(ctx, ErrorKey::OsuApi).await;
             let report = Report::new(err).wrap_err("failed to get user");
 
             return Err(report);
@@ -61,7 +58,7 @@ pub async fn medals_graph(
             return Ok(None);
         }
         Err(err) => {
-            let _ = orig.error(ctx, GENERAL_ISSUE).await;
+            let _ = orig.error_localized(ctx, ErrorKey::General).await;
             warn!(?err, "Failed to create medals graph");
 
             return Ok(None);
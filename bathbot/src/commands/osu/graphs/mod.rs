@@ -1,11 +1,8 @@
 use std::{iter, sync::Arc};
 
-use bathbot_macros::{command, HasName, SlashCommand};
+use bathbot_macros::{command, HasMods, HasName, SlashCommand};
 use bathbot_model::{rosu_v2::user::User, Countries};
-use bathbot_util::{
-    constants::{GENERAL_ISSUE, OSU_API_ISSUE},
-    EmbedBuilder, MessageBuilder,
-};
+use bathbot_util::{EmbedBuilder, MessageBuilder};
 use eyre::{Report, Result, WrapErr};
 use image::{DynamicImage, GenericImageView};
 use plotters::element::{Drawable, PointCollection};
@@ -20,6 +17,7 @@ use twilight_interactions::command::{CommandModel, CommandOption, CreateCommand,
 use twilight_model::id::{marker::UserMarker, Id};
 
 use self::{
+    map_strains::map_strains_graph,
     medals::medals_graph,
     playcount_replays::{playcount_replays_graph, ProfileGraphFlags},
     rank::rank_graph,
@@ -32,12 +30,13 @@ use self::{
 use super::{require_link, user_not_found};
 use crate::{
     commands::{GameModeOption, ShowHideOption, TimezoneOption},
-    core::{commands::CommandOrigin, Context},
+    core::{commands::CommandOrigin, locale::ErrorKey, Context},
     embeds::attachment,
     manager::redis::{osu::UserArgs, RedisData},
     util::{interaction::InteractionCommand, InteractionCommandExt},
 };
 
+mod map_strains;
 mod medals;
 mod playcount_replays;
 mod rank;
@@ -48,8 +47,10 @@ mod top_index;
 mod top_time;
 
 #[derive(CommandModel, CreateCommand, SlashCommand)]
-#[command(name = "graph", desc = "Display graphs about some user data")]
+#[command(name = "graph", desc = "Display graphs about some user or map data")]
 pub enum Graph {
+    #[command(name = "map_strains")]
+    MapStrains(GraphMapStrains),
     #[command(name = "medals")]
     Medals(GraphMedals),
     #[command(name = "playcount_replays")]
@@ -64,6 +65,30 @@ pub enum Graph {
     Top(GraphTop),
 }
 
+#[derive(CommandModel, CreateCommand, HasMods)]
+#[command(
+    name = "map_strains",
+    desc = "Display a map's difficulty over time and its hardest sections",
+    help = "Display a map's difficulty over time and its hardest sections.\n\
+    The graph shows the strain of each skill i.e. aim and speed for osu!standard, \
+    stamina, color, and rhythm for taiko, movement for catch, and the overall strain for mania.\n\
+    Flashlight strain is only included if the `FL` mod is specified."
+)]
+pub struct GraphMapStrains {
+    #[command(
+        desc = "Specify a map url or map id",
+        help = "Specify a map either by map url or map id.\n\
+        If none is specified, it will search in the recent channel history \
+        and pick the first map it can find."
+    )]
+    map: Option<String>,
+    #[command(
+        desc = "Specify mods e.g. hdhr or nm",
+        help = "Specify mods either directly or through the explicit `+mods!` / `+mods` syntax e.g. `hdhr` or `+hdhr!`"
+    )]
+    mods: Option<String>,
+}
+
 #[derive(CommandModel, CreateCommand, HasName)]
 #[command(name = "medals", desc = "Display a user's medal progress over time")]
 pub struct GraphMedals {
@@ -193,6 +218,7 @@ async fn slash_graph(ctx: Arc<Context>, mut command: InteractionCommand) -> Resu
 // `InteractionCommand`
 async fn graph(ctx: Arc<Context>, orig: CommandOrigin<'_>, args: Graph) -> Result<()> {
     let tuple_option = match args {
+        Graph::MapStrains(args) => return map_strains_graph(&ctx, &orig, args).await,
         Graph::Medals(args) => {
             let user_id = match user_id!(ctx, orig, args) {
                 Some(user_id) => user_id,
@@ -200,7 +226,7 @@ async fn graph(ctx: Arc<Context>, orig: CommandOrigin<'_>, args: Graph) -> Resul
                     Ok(Some(user_id)) => UserId::Id(user_id),
                     Ok(None) => return require_link(&ctx, &orig).await,
                     Err(err) => {
-                        let _ = orig.error(&ctx, GENERAL_ISSUE).await;
+                        let _ = orig.error_localized(&ctx, ErrorKey::General).await;
 
                         return Err(err);
                     }
@@ -218,7 +244,7 @@ async fn graph(ctx: Arc<Context>, orig: CommandOrigin<'_>, args: Graph) -> Resul
                     Ok(Some(user_id)) => UserId::Id(user_id),
                     Ok(None) => return require_link(&ctx, &orig).await,
                     Err(err) => {
-                        let _ = orig.error(&ctx, GENERAL_ISSUE).await;
+                        let _ = orig.error_localized(&ctx, ErrorKey::General).await;
 
                         return Err(err);
                     }
@@ -262,7 +288,7 @@ async fn graph(ctx: Arc<Context>, orig: CommandOrigin<'_>, args: Graph) -> Resul
                     Ok(Some(user_id)) => UserId::Id(user_id),
                     Ok(None) => return require_link(&ctx, &orig).await,
                     Err(err) => {
-                        let _ = orig.error(&ctx, GENERAL_ISSUE).await;
+                        let _ = orig.error_localized(&ctx, ErrorKey::General).await;
 
                         return Err(err);
                     }
@@ -280,7 +306,7 @@ async fn graph(ctx: Arc<Context>, orig: CommandOrigin<'_>, args: Graph) -> Resul
                     Ok(Some(user_id)) => UserId::Id(user_id),
                     Ok(None) => return require_link(&ctx, &orig).await,
                     Err(err) => {
-                        let _ = orig.error(&ctx, GENERAL_ISSUE).await;
+                        let _ = orig.error_localized(&ctx, ErrorKey::General).await;
 
                         return Err(err);
                     }
@@ -297,7 +323,7 @@ async fn graph(ctx: Arc<Context>, orig: CommandOrigin<'_>, args: Graph) -> Resul
             let config = match ctx.user_config().with_osu_id(owner).await {
                 Ok(config) => config,
                 Err(err) => {
-                    let _ = orig.error(&ctx, GENERAL_ISSUE).await;
+                    let _ = orig.error_localized(&ctx, ErrorKey::General).await;
 
                     return Err(err.wrap_err("failed to get user config"));
                 }
@@ -370,7 +396,7 @@ async fn top_graph(
             return Ok(None);
         }
         Err(err) => {
-            let _ = orig.error(ctx, OSU_API_ISSUE).await;
+            let _ = orig.error_localized(ctx, ErrorKey::OsuApi).await;
             let err = Report::new(err).wrap_err("failed to get user or scores");
 
             return Err(err);
@@ -429,7 +455,7 @@ async fn top_graph(
     let bytes = match graph_result {
         Ok(graph) => graph,
         Err(err) => {
-            let _ = orig.error(ctx, GENERAL_ISSUE).await;
+            let _ = orig.error_localized(ctx, ErrorKey::General).await;
             warn!("{err:?}");
 
             return Ok(None);
@@ -4,10 +4,7 @@ use bathbot_model::{
     rosu_v2::user::{MonthlyCount as MonthlyCountRkyv, User},
     Either,
 };
-use bathbot_util::{
-    constants::{GENERAL_ISSUE, OSU_API_ISSUE},
-    MessageBuilder,
-};
+use bathbot_util::MessageBuilder;
 use bitflags::bitflags;
 use bytes::Bytes;
 use eyre::{ContextCompat, Report, Result, WrapErr};
@@ -38,7 +35,7 @@ use time::{Date, Month, OffsetDateTime};
 use super::{BitMapElement, H, W};
 use crate::{
     commands::osu::user_not_found,
-    core::{commands::CommandOrigin, Context},
+    core::{commands::CommandOrigin, locale::ErrorKey, Context},
     manager::redis::{osu::UserArgs, RedisData},
     util::Monthly,
 };
@@ -60,7 +57,7 @@ pub async fn playcount_replays_graph(
             return Ok(None);
         }
         Err(err) => {
-            let _ = orig.error(ctx, OSU_API_ISSUE).await;
+            let _ = orig.error_localized(ctx, ErrorKey::OsuApi).await;
             let err = Report::new(err).wrap_err("failed to get user");
 
             return Err(err);
@@ -93,7 +90,7 @@ pub async fn playcount_replays_graph(
             return Ok(None);
         }
         Err(err) => {
-            let _ = orig.error(ctx, GENERAL_ISSUE).await;
+            let _ = orig.error_localized(ctx, ErrorKey::General).await;
             warn!(?err, "Failed to create profile graph");
 
             return Ok(None);
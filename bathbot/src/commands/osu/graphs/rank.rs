@@ -1,10 +1,7 @@
 use std::iter;
 
 use bathbot_model::rosu_v2::user::User;
-use bathbot_util::{
-    constants::{GENERAL_ISSUE, OSU_API_ISSUE},
-    numbers::WithComma,
-};
+use bathbot_util::numbers::WithComma;
 use eyre::{ContextCompat, Report, Result, WrapErr};
 use plotters::{
     prelude::{ChartBuilder, Circle, IntoDrawingArea, SeriesLabelPosition},
@@ -21,7 +18,7 @@ use crate::{
         graphs::{H, W},
         user_not_found,
     },
-    core::{commands::CommandOrigin, Context},
+    core::{commands::CommandOrigin, locale::ErrorKey, Context},
     manager::redis::{osu::UserArgs, RedisData},
 };
 
@@ -40,7 +37,7 @@ pub async fn rank_graph(
             return Ok(None);
         }
         Err(err) => {
-            let _ = orig.error(ctx, OSU_API_ISSUE).await;
+            let _ = orig.error_localized(ctx, ErrorKey::OsuApi).await;
             let err = Report::new(err).wrap_err("Failed to get user");
 
             return Err(err);
@@ -208,7 +205,7 @@ pub async fn rank_graph(
             return Ok(None);
         }
         Err(err) => {
-            let _ = orig.error(ctx, GENERAL_ISSUE).await;
+            let _ = orig.error_localized(ctx, ErrorKey::General).await;
             warn!(?err, "Failed to draw rank graph");
 
             return Ok(None);
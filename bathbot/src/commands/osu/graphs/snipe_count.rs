@@ -1,15 +1,12 @@
 use bathbot_model::rosu_v2::user::User;
-use bathbot_util::{
-    constants::{GENERAL_ISSUE, HUISMETBENEN_ISSUE, OSU_API_ISSUE},
-    MessageBuilder,
-};
+use bathbot_util::MessageBuilder;
 use eyre::{Report, Result};
 use rosu_v2::{prelude::OsuError, request::UserId};
 
 use super::{H, W};
 use crate::{
     commands::osu::{player_snipe_stats, user_not_found},
-    core::{commands::CommandOrigin, Context},
+    core::{commands::CommandOrigin, locale::ErrorKey, Context},
     manager::redis::{osu::UserArgs, RedisData},
 };
 
@@ -29,7 +26,7 @@ pub async fn snipe_count_graph(
             return Ok(None);
         }
         Err(err) => {
-            let _ = orig.error(ctx, OSU_API_ISSUE).await;
+            let _ = orig.error_localized(ctx, ErrorKey::OsuApi).await;
             let err = Report::new(err).wrap_err("failed to get user");
 
             return Err(err);
@@ -53,8 +50,8 @@ pub async fn snipe_count_graph(
         }
     };
 
-    let player = if ctx.huismetbenen().is_supported(country_code).await {
-        let player_fut = ctx.client().get_snipe_player(country_code, user_id);
+    let player = if ctx.snipe().is_supported(country_code).await {
+        let player_fut = ctx.snipe().player(country_code, user_id);
 
         match player_fut.await {
             Ok(Some(player)) => player,
@@ -66,7 +63,7 @@ pub async fn snipe_count_graph(
                 return Ok(None);
             }
             Err(err) => {
-                let _ = orig.error(ctx, HUISMETBENEN_ISSUE).await;
+                let _ = orig.error_localized(ctx, ErrorKey::Huismetbenen).await;
 
                 return Err(err);
             }
@@ -85,7 +82,7 @@ pub async fn snipe_count_graph(
     let bytes = match graph_result {
         Ok(graph) => graph,
         Err(err) => {
-            let _ = orig.error(ctx, GENERAL_ISSUE).await;
+            let _ = orig.error_localized(ctx, ErrorKey::General).await;
             warn!(?err, "Failed to create snipe count graph");
 
             return Ok(None);
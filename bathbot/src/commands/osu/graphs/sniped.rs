@@ -1,8 +1,5 @@
 use bathbot_model::rosu_v2::user::User;
-use bathbot_util::{
-    constants::{GENERAL_ISSUE, HUISMETBENEN_ISSUE, OSU_API_ISSUE},
-    MessageBuilder,
-};
+use bathbot_util::MessageBuilder;
 use eyre::{Report, Result};
 use rosu_v2::{prelude::OsuError, request::UserId};
 use time::{Duration, OffsetDateTime};
@@ -10,7 +7,7 @@ use time::{Duration, OffsetDateTime};
 use super::{H, W};
 use crate::{
     commands::osu::{sniped, user_not_found},
-    core::{commands::CommandOrigin, Context},
+    core::{commands::CommandOrigin, locale::ErrorKey, Context},
     manager::redis::{osu::UserArgs, RedisData},
 };
 
@@ -30,7 +27,7 @@ pub async fn sniped_graph(
             return Ok(None);
         }
         Err(err) => {
-            let _ = orig.error(ctx, OSU_API_ISSUE).await;
+            let _ = orig.error_localized(ctx, ErrorKey::OsuApi).await;
             let err = Report::new(err).wrap_err("failed to get user");
 
             return Err(err);
@@ -54,14 +51,15 @@ pub async fn sniped_graph(
         }
     };
 
-    let (sniper, snipee) = if ctx.huismetbenen().is_supported(country_code).await {
+    let (sniper, snipee) = if ctx.snipe().is_supported(country_code).await {
         let now = OffsetDateTime::now_utc();
-        let sniper_fut =
-            ctx.client()
-                .get_national_snipes(user_id, true, now - Duration::weeks(8), now);
-        let snipee_fut =
-            ctx.client()
-                .get_national_snipes(user_id, false, now - Duration::weeks(8), now);
+        let from = now - Duration::weeks(8);
+        let sniper_fut = ctx
+            .snipe()
+            .national_snipes(country_code, user_id, true, from, now);
+        let snipee_fut = ctx
+            .snipe()
+            .national_snipes(country_code, user_id, false, from, now);
 
         match tokio::try_join!(sniper_fut, snipee_fut) {
             Ok((mut sniper, snipee)) => {
@@ -70,7 +68,7 @@ pub async fn sniped_graph(
                 (sniper, snipee)
             }
             Err(err) => {
-                let _ = orig.error(ctx, HUISMETBENEN_ISSUE).await;
+                let _ = orig.error_localized(ctx, ErrorKey::Huismetbenen).await;
 
                 return Err(err.wrap_err("failed to get sniper or snipee"));
             }
@@ -94,7 +92,7 @@ pub async fn sniped_graph(
             return Ok(None);
         }
         Err(err) => {
-            let _ = orig.error(ctx, GENERAL_ISSUE).await;
+            let _ = orig.error_localized(ctx, ErrorKey::General).await;
             warn!(?err, "Failed to create sniped graph");
 
             return Ok(None);
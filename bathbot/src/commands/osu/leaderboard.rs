@@ -8,7 +8,7 @@ use std::{
 use bathbot_macros::{command, HasMods, SlashCommand};
 use bathbot_model::rosu_v2::user::User;
 use bathbot_util::{
-    constants::{AVATAR_URL, GENERAL_ISSUE, OSU_WEB_ISSUE},
+    constants::AVATAR_URL,
     matcher,
     osu::{MapIdType, ModSelection},
     IntHasher,
@@ -30,7 +30,10 @@ use twilight_model::{
 use super::{HasMods, ModsResult};
 use crate::{
     active::{impls::LeaderboardPagination, ActiveMessages},
-    core::commands::{prefix::Args, CommandOrigin},
+    core::{
+        commands::{prefix::Args, CommandOrigin},
+        locale::ErrorKey,
+    },
     manager::{
         redis::{osu::UserArgs, RedisData},
         MapError, OsuMap, PpManager,
@@ -274,8 +277,8 @@ async fn leaderboard(
     let map_id = match map_id_res {
         Ok(map_id) => map_id,
         Err(GetMapError::Content(content)) => return orig.error(&ctx, content).await,
-        Err(GetMapError::Err { err, content }) => {
-            let _ = orig.error(&ctx, content).await;
+        Err(GetMapError::Err { err, key }) => {
+            let _ = orig.error_localized(&ctx, key).await;
 
             return Err(err);
         }
@@ -293,7 +296,7 @@ async fn leaderboard(
             return orig.error(&ctx, content).await;
         }
         Err(MapError::Report(err)) => {
-            let _ = orig.error(&ctx, GENERAL_ISSUE).await;
+            let _ = orig.error_localized(&ctx, ErrorKey::General).await;
 
             return Err(err);
         }
@@ -335,7 +338,7 @@ async fn leaderboard(
             })
             .collect(),
         Err(err) => {
-            let _ = orig.error(&ctx, OSU_WEB_ISSUE).await;
+            let _ = orig.error_localized(&ctx, ErrorKey::OsuWeb).await;
 
             return Err(err.wrap_err("Failed to get leaderboard"));
         }
@@ -416,7 +419,7 @@ async fn leaderboard(
 
 enum GetMapError {
     Content(&'static str),
-    Err { err: Report, content: &'static str },
+    Err { err: Report, key: ErrorKey },
 }
 
 async fn get_map_id(
@@ -437,7 +440,7 @@ async fn get_map_id(
                 .await
                 .map_err(|err| GetMapError::Err {
                     err,
-                    content: GENERAL_ISSUE,
+                    key: ErrorKey::General,
                 })?;
 
             match ctx.find_map_id_in_msgs(&msgs, 0).await {
@@ -3,12 +3,7 @@ use std::{
 };
 
 use bathbot_macros::{command, HasMods, SlashCommand};
-use bathbot_util::{
-    constants::{GENERAL_ISSUE, OSU_API_ISSUE},
-    matcher,
-    osu::MapIdType,
-    MessageOrigin,
-};
+use bathbot_util::{matcher, osu::MapIdType, MessageOrigin};
 use enterpolation::{linear::Linear, Curve};
 use eyre::{ContextCompat, Report, Result, WrapErr};
 use image::DynamicImage;
@@ -29,7 +24,10 @@ use twilight_model::{
 use super::{BitMapElement, HasMods, ModsResult};
 use crate::{
     active::{impls::MapPagination, ActiveMessages},
-    core::commands::{prefix::Args, CommandOrigin},
+    core::{
+        commands::{prefix::Args, CommandOrigin},
+        locale::ErrorKey,
+    },
     util::{interaction::InteractionCommand, ChannelExt, CheckPermissions, InteractionCommandExt},
     Context,
 };
@@ -266,7 +264,7 @@ async fn map(ctx: Arc<Context>, orig: CommandOrigin<'_>, args: MapArgs<'_>) -> R
         let msgs = match ctx.retrieve_channel_history(orig.channel_id()).await {
             Ok(msgs) => msgs,
             Err(err) => {
-                let _ = orig.error(&ctx, GENERAL_ISSUE).await;
+                let _ = orig.error_localized(&ctx, ErrorKey::General).await;
 
                 return Err(err.wrap_err("failed to retrieve channel history"));
             }
@@ -312,7 +310,7 @@ async fn map(ctx: Arc<Context>, orig: CommandOrigin<'_>, args: MapArgs<'_>) -> R
             return orig.error(&ctx, content).await;
         }
         Err(err) => {
-            let _ = orig.error(&ctx, OSU_API_ISSUE).await;
+            let _ = orig.error_localized(&ctx, ErrorKey::OsuApi).await;
 
             return Err(Report::new(err).wrap_err("failed to get mapset"));
         }
@@ -1,7 +1,6 @@
 use std::{collections::BTreeMap, sync::Arc};
 
 use bathbot_macros::{command, SlashCommand};
-use bathbot_util::constants::OSU_API_ISSUE;
 use eyre::{Report, Result};
 use rosu_v2::prelude::{
     BeatmapsetExtended, BeatmapsetSearchResult, BeatmapsetSearchSort, Genre, Language, Osu,
@@ -12,7 +11,10 @@ use twilight_interactions::command::{CommandModel, CommandOption, CreateCommand,
 use crate::{
     active::{impls::MapSearchPagination, ActiveMessages},
     commands::GameModeOption,
-    core::commands::{prefix::Args, CommandOrigin},
+    core::{
+        commands::{prefix::Args, CommandOrigin},
+        locale::ErrorKey,
+    },
     util::{interaction::InteractionCommand, ChannelExt, InteractionCommandExt},
     Context,
 };
@@ -626,7 +628,7 @@ async fn search(ctx: Arc<Context>, orig: CommandOrigin<'_>, args: Search) -> Res
     let mut search_result = match args.request(ctx.osu()).await {
         Ok(response) => response,
         Err(err) => {
-            let _ = orig.error(&ctx, OSU_API_ISSUE).await;
+            let _ = orig.error_localized(&ctx, ErrorKey::OsuApi).await;
 
             return Err(Report::new(err).wrap_err("Failed to get search results"));
         }
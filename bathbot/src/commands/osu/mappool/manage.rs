@@ -0,0 +1,302 @@
+use std::{fmt::Write, sync::Arc};
+
+use bathbot_util::{matcher, MessageBuilder};
+use eyre::Result;
+use rosu_v2::prelude::GameModsIntermode;
+
+use super::{
+    get_mappool, parse_mods, slot_mods, MappoolAdd, MappoolCreate, MappoolDelete, MappoolRemove,
+    NAME_MAX_LEN, SLOT_MAX_LEN,
+};
+use crate::{
+    core::locale::ErrorKey,
+    manager::MapError,
+    util::{interaction::InteractionCommand, require_authority, InteractionCommandExt},
+    Context,
+};
+
+const MAX_MAPPOOLS: usize = 10;
+const MAX_SLOTS: usize = 32;
+
+pub(super) async fn create(
+    ctx: Arc<Context>,
+    command: InteractionCommand,
+    args: MappoolCreate,
+) -> Result<()> {
+    if !require_authority(&ctx, &command).await? {
+        return Ok(());
+    }
+
+    let guild_id = command.guild_id.unwrap();
+    let name = args.name.trim();
+
+    if name.is_empty() || name.chars().count() > NAME_MAX_LEN {
+        let content = format!("The name must be between 1 and {NAME_MAX_LEN} characters long");
+        command.error(&ctx, content).await?;
+
+        return Ok(());
+    }
+
+    let mappools = match ctx.mappool().mappools(guild_id).await {
+        Ok(mappools) => mappools,
+        Err(err) => {
+            let _ = command.error_localized(&ctx, ErrorKey::General).await;
+
+            return Err(err);
+        }
+    };
+
+    if mappools.len() >= MAX_MAPPOOLS {
+        let content = format!(
+            "Servers can have at most {MAX_MAPPOOLS} mappools. \
+            Delete one via `/mappool delete` first."
+        );
+        command.error(&ctx, content).await?;
+
+        return Ok(());
+    }
+
+    match ctx.mappool().create(guild_id, name).await {
+        Ok(true) => {
+            let content = format!(
+                "Created mappool `{name}`.\n\
+                Add maps to it via `/mappool add`."
+            );
+
+            let builder = MessageBuilder::new().embed(content);
+            command.update(&ctx, builder).await?;
+
+            Ok(())
+        }
+        Ok(false) => {
+            let content = format!("There already is a mappool called `{name}`");
+            command.error(&ctx, content).await?;
+
+            Ok(())
+        }
+        Err(err) => {
+            let _ = command.error_localized(&ctx, ErrorKey::General).await;
+
+            Err(err)
+        }
+    }
+}
+
+pub(super) async fn delete(
+    ctx: Arc<Context>,
+    command: InteractionCommand,
+    args: MappoolDelete,
+) -> Result<()> {
+    if !require_authority(&ctx, &command).await? {
+        return Ok(());
+    }
+
+    let Some(mappool) = get_mappool(&ctx, &command, &args.name).await? else {
+        return Ok(());
+    };
+
+    if let Err(err) = ctx.mappool().delete(mappool.mappool_id).await {
+        let _ = command.error_localized(&ctx, ErrorKey::General).await;
+
+        return Err(err);
+    }
+
+    let content = format!("Deleted mappool `{}`", mappool.name);
+    let builder = MessageBuilder::new().embed(content);
+    command.update(&ctx, builder).await?;
+
+    Ok(())
+}
+
+pub(super) async fn list(ctx: Arc<Context>, command: InteractionCommand) -> Result<()> {
+    let guild_id = command.guild_id.unwrap();
+
+    let mappools = match ctx.mappool().mappools(guild_id).await {
+        Ok(mappools) => mappools,
+        Err(err) => {
+            let _ = command.error_localized(&ctx, ErrorKey::General).await;
+
+            return Err(err);
+        }
+    };
+
+    let content = if mappools.is_empty() {
+        "This server has no mappools yet, create one via `/mappool create`".to_owned()
+    } else {
+        let mut content = String::with_capacity(mappools.len() * 48);
+
+        for mappool in mappools {
+            let _ = writeln!(
+                content,
+                "`{}` • Created <t:{}:d>",
+                mappool.name,
+                mappool.created_at.unix_timestamp(),
+            );
+        }
+
+        content
+    };
+
+    let builder = MessageBuilder::new().embed(content);
+    command.update(&ctx, builder).await?;
+
+    Ok(())
+}
+
+pub(super) async fn add(
+    ctx: Arc<Context>,
+    command: InteractionCommand,
+    args: MappoolAdd,
+) -> Result<()> {
+    if !require_authority(&ctx, &command).await? {
+        return Ok(());
+    }
+
+    let slot = args.slot.trim().to_ascii_uppercase();
+
+    if slot.is_empty()
+        || slot.len() > SLOT_MAX_LEN
+        || !slot.chars().all(|c| c.is_ascii_alphanumeric())
+    {
+        let content = format!("Slots must be up to {SLOT_MAX_LEN} letters and digits, e.g. `NM1`");
+        command.error(&ctx, content).await?;
+
+        return Ok(());
+    }
+
+    let Some(map_id) = matcher::get_osu_map_id(&args.map) else {
+        let content = "Failed to parse map url.\n\
+            Be sure it's a valid map url or a map id.";
+        command.error(&ctx, content).await?;
+
+        return Ok(());
+    };
+
+    let mods = match args
+        .mods
+        .as_deref()
+        .map_or_else(|| slot_mods(&slot), parse_mods)
+    {
+        Some(mods) => mods,
+        None if args.mods.is_some() => {
+            let content = "Failed to parse mods, specify them like `HDHR` or `FM` for freemod";
+            command.error(&ctx, content).await?;
+
+            return Ok(());
+        }
+        None => {
+            let content = format!(
+                "Can't tell the mods of slot `{slot}` from its name, \
+                specify them via the `mods` option"
+            );
+            command.error(&ctx, content).await?;
+
+            return Ok(());
+        }
+    };
+
+    let Some(mappool) = get_mappool(&ctx, &command, &args.name).await? else {
+        return Ok(());
+    };
+
+    let maps = match ctx.mappool().maps(mappool.mappool_id).await {
+        Ok(maps) => maps,
+        Err(err) => {
+            let _ = command.error_localized(&ctx, ErrorKey::General).await;
+
+            return Err(err);
+        }
+    };
+
+    if maps.len() >= MAX_SLOTS && maps.iter().all(|map| map.slot != slot) {
+        let content = format!("Mappools can have at most {MAX_SLOTS} slots");
+        command.error(&ctx, content).await?;
+
+        return Ok(());
+    }
+
+    // Makes sure the map exists and is stored for later lookups
+    let map = match ctx.osu_map().map_slim(map_id).await {
+        Ok(map) => map,
+        Err(MapError::NotFound) => {
+            let content = format!(
+                "Could not find beatmap with id `{map_id}`. \
+                Did you give me a mapset id instead of a map id?"
+            );
+            command.error(&ctx, content).await?;
+
+            return Ok(());
+        }
+        Err(MapError::Report(err)) => {
+            let _ = command.error_localized(&ctx, ErrorKey::General).await;
+
+            return Err(err);
+        }
+    };
+
+    let set_fut = ctx
+        .mappool()
+        .set_map(mappool.mappool_id, &slot, map_id, mods);
+
+    if let Err(err) = set_fut.await {
+        let _ = command.error_localized(&ctx, ErrorKey::General).await;
+
+        return Err(err);
+    }
+
+    let mods = match mods {
+        Some(0) => "NM".to_owned(),
+        Some(bits) => GameModsIntermode::from_bits(bits).to_string(),
+        None => "FM".to_owned(),
+    };
+
+    let content = format!(
+        "Set slot `{slot}` of mappool `{name}` to `{artist} - {title} [{version}]` ({mods})",
+        name = mappool.name,
+        artist = map.artist(),
+        title = map.title(),
+        version = map.version(),
+    );
+
+    let builder = MessageBuilder::new().embed(content);
+    command.update(&ctx, builder).await?;
+
+    Ok(())
+}
+
+pub(super) async fn remove(
+    ctx: Arc<Context>,
+    command: InteractionCommand,
+    args: MappoolRemove,
+) -> Result<()> {
+    if !require_authority(&ctx, &command).await? {
+        return Ok(());
+    }
+
+    let Some(mappool) = get_mappool(&ctx, &command, &args.name).await? else {
+        return Ok(());
+    };
+
+    let slot = args.slot.trim().to_ascii_uppercase();
+
+    match ctx.mappool().remove_map(mappool.mappool_id, &slot).await {
+        Ok(true) => {
+            let content = format!("Removed slot `{slot}` from mappool `{}`", mappool.name);
+            let builder = MessageBuilder::new().embed(content);
+            command.update(&ctx, builder).await?;
+
+            Ok(())
+        }
+        Ok(false) => {
+            let content = format!("The mappool has no slot `{slot}`");
+            command.error(&ctx, content).await?;
+
+            Ok(())
+        }
+        Err(err) => {
+            let _ = command.error_localized(&ctx, ErrorKey::General).await;
+
+            Err(err)
+        }
+    }
+}
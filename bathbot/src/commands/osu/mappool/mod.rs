@@ -0,0 +1,226 @@
+use std::sync::Arc;
+
+use bathbot_macros::SlashCommand;
+use bathbot_psql::model::mappool::{DbMappool, DbMappoolMap};
+use eyre::Result;
+use rosu_v2::prelude::GameModsIntermode;
+use twilight_interactions::command::{CommandModel, CreateCommand};
+
+pub use self::show::MappoolEntry;
+use crate::{
+    core::locale::ErrorKey,
+    util::{interaction::InteractionCommand, InteractionCommandExt},
+    Context,
+};
+
+mod manage;
+mod show;
+
+const NAME_MAX_LEN: usize = 32;
+const SLOT_MAX_LEN: usize = 8;
+
+/// Slot prefixes in the order they're usually listed in
+const SLOT_PREFIXES: [&str; 6] = ["NM", "HD", "HR", "DT", "FM", "TB"];
+
+#[derive(CommandModel, CreateCommand, SlashCommand)]
+#[command(
+    name = "mappool",
+    dm_permission = false,
+    desc = "Manage the mappools of this server",
+    help = "Manage the mappools of this server.\n\
+    A mappool consists of slots such as `NM1` or `HD2`, each with a map and its required mods.\n\
+    Creating or deleting mappools and editing their slots requires authority status.\n\n\
+    Once a mappool is complete, it can be linked to a live tracked match via \
+    `/matchlive mappool` so that played maps are annotated with their slot and \
+    picks and bans can be logged via `/matchlive pick` and `/matchlive ban`."
+)]
+pub enum Mappool {
+    #[command(name = "create")]
+    Create(MappoolCreate),
+    #[command(name = "delete")]
+    Delete(MappoolDelete),
+    #[command(name = "list")]
+    List(MappoolList),
+    #[command(name = "show")]
+    Show(MappoolShow),
+    #[command(name = "add")]
+    Add(MappoolAdd),
+    #[command(name = "remove")]
+    Remove(MappoolRemove),
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "create", desc = "Create a new mappool")]
+pub struct MappoolCreate {
+    #[command(desc = "Specify the name of the mappool")]
+    name: String,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "delete", desc = "Delete a mappool")]
+pub struct MappoolDelete {
+    #[command(desc = "Specify the name of the mappool")]
+    name: String,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "list", desc = "List all mappools of this server")]
+pub struct MappoolList;
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "show",
+    desc = "Show the maps of a mappool",
+    help = "Show the maps of a mappool alongside their star rating and max pp \
+    with the slot's required mods applied."
+)]
+pub struct MappoolShow {
+    #[command(desc = "Specify the name of the mappool")]
+    name: String,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "add",
+    desc = "Add a map to a mappool",
+    help = "Add a map to a mappool or replace the map of an existing slot.\n\
+    If no mods are specified, they're derived from the slot's name i.e. \
+    `NM`, `HD`, `HR`, `DT`, or freemod for `FM` and `TB` slots."
+)]
+pub struct MappoolAdd {
+    #[command(desc = "Specify the name of the mappool")]
+    name: String,
+    #[command(desc = "Specify the slot, e.g. `NM1` or `TB`")]
+    slot: String,
+    #[command(desc = "Specify a map url or map id")]
+    map: String,
+    #[command(desc = "Specify the required mods, e.g. `HDHR`, or `FM` for freemod")]
+    mods: Option<String>,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "remove", desc = "Remove a slot from a mappool")]
+pub struct MappoolRemove {
+    #[command(desc = "Specify the name of the mappool")]
+    name: String,
+    #[command(desc = "Specify the slot, e.g. `NM1` or `TB`")]
+    slot: String,
+}
+
+async fn slash_mappool(ctx: Arc<Context>, mut command: InteractionCommand) -> Result<()> {
+    match Mappool::from_interaction(command.input_data())? {
+        Mappool::Create(args) => manage::create(ctx, command, args).await,
+        Mappool::Delete(args) => manage::delete(ctx, command, args).await,
+        Mappool::List(_) => manage::list(ctx, command).await,
+        Mappool::Show(args) => show::show(ctx, command, args).await,
+        Mappool::Add(args) => manage::add(ctx, command, args).await,
+        Mappool::Remove(args) => manage::remove(ctx, command, args).await,
+    }
+}
+
+/// Returns `None` if the guild has no mappool with the given name in which
+/// case the author has already been notified.
+async fn get_mappool(
+    ctx: &Context,
+    command: &InteractionCommand,
+    name: &str,
+) -> Result<Option<DbMappool>> {
+    let guild_id = command.guild_id.unwrap();
+
+    match ctx.mappool().mappool(guild_id, name.trim()).await {
+        Ok(Some(mappool)) => Ok(Some(mappool)),
+        Ok(None) => {
+            let content = format!(
+                "There is no mappool called `{}` on this server",
+                name.trim()
+            );
+            command.error(ctx, content).await?;
+
+            Ok(None)
+        }
+        Err(err) => {
+            let _ = command.error_localized(ctx, ErrorKey::General).await;
+
+            Err(err)
+        }
+    }
+}
+
+/// Required mods of a slot based on its name. The outer `None` denotes an
+/// unknown prefix, the inner one a freemod slot.
+fn slot_mods(slot: &str) -> Option<Option<u32>> {
+    let prefix = slot.trim_end_matches(|c: char| c.is_ascii_digit());
+
+    match prefix {
+        "NM" => Some(Some(0)),
+        "HD" => Some(Some(8)),
+        "HR" => Some(Some(16)),
+        "DT" => Some(Some(64)),
+        "EZ" => Some(Some(2)),
+        "FL" => Some(Some(1024)),
+        "FM" | "TB" => Some(None),
+        _ => None,
+    }
+}
+
+/// Parses mods like `HDHR` or `+DT` where `FM` denotes freemod.
+fn parse_mods(input: &str) -> Option<Option<u32>> {
+    let input = input.trim().trim_start_matches('+').to_ascii_uppercase();
+
+    match input.as_str() {
+        "FM" | "FREEMOD" => Some(None),
+        "" | "NM" => Some(Some(0)),
+        _ => GameModsIntermode::from_acronyms(&input).map(|mods| Some(mods.bits())),
+    }
+}
+
+/// Sorts slots by their prefix in the usual order, then by their number.
+pub(super) fn sort_slots(maps: &mut [DbMappoolMap]) {
+    maps.sort_by(|a, b| slot_key(&a.slot).cmp(&slot_key(&b.slot)));
+}
+
+fn slot_key(slot: &str) -> (usize, u32, &str) {
+    let prefix = slot.trim_end_matches(|c: char| c.is_ascii_digit());
+    let number = slot[prefix.len()..].parse().unwrap_or(0);
+
+    let idx = SLOT_PREFIXES
+        .iter()
+        .position(|known| *known == prefix)
+        .unwrap_or(SLOT_PREFIXES.len());
+
+    (idx, number, slot)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mods_of_slots() {
+        assert_eq!(slot_mods("NM1"), Some(Some(0)));
+        assert_eq!(slot_mods("HD12"), Some(Some(8)));
+        assert_eq!(slot_mods("TB"), Some(None));
+        assert_eq!(slot_mods("EX1"), None);
+
+        assert_eq!(parse_mods("+HDHR"), Some(Some(24)));
+        assert_eq!(parse_mods("fm"), Some(None));
+        assert_eq!(parse_mods("NM"), Some(Some(0)));
+    }
+
+    #[test]
+    fn slot_order() {
+        let mut maps: Vec<_> = ["TB", "DT1", "NM10", "EX1", "HD1", "NM2", "FM1"]
+            .into_iter()
+            .map(|slot| DbMappoolMap {
+                slot: slot.to_owned(),
+                map_id: 0,
+                mods: None,
+            })
+            .collect();
+
+        sort_slots(&mut maps);
+        let slots: Vec<_> = maps.iter().map(|map| map.slot.as_str()).collect();
+
+        assert_eq!(slots, ["NM2", "NM10", "HD1", "DT1", "FM1", "TB", "EX1"]);
+    }
+}
@@ -0,0 +1,110 @@
+use std::sync::Arc;
+
+use eyre::Result;
+use futures::future;
+use rosu_v2::prelude::GameModsIntermode;
+
+use super::{get_mappool, sort_slots, MappoolShow};
+use crate::{
+    active::{impls::MappoolPagination, ActiveMessages},
+    core::locale::ErrorKey,
+    manager::MapError,
+    util::{interaction::InteractionCommand, Authored, InteractionCommandExt},
+    Context,
+};
+
+/// A mappool slot alongside its map's attributes with the required mods
+pub struct MappoolEntry {
+    pub slot: String,
+    pub map_id: u32,
+    /// `None` if the slot is freemod
+    pub mods: Option<u32>,
+    /// `None` if the map could not be retrieved
+    pub map: Option<MappoolEntryMap>,
+}
+
+pub struct MappoolEntryMap {
+    pub artist: String,
+    pub title: String,
+    pub version: String,
+    pub stars: f32,
+    pub pp: f32,
+    pub seconds_drain: u32,
+    pub bpm: f32,
+}
+
+impl MappoolEntry {
+    async fn new(ctx: &Context, slot: String, map_id: u32, mods: Option<u32>) -> Self {
+        let map = match ctx.osu_map().map(map_id, None).await {
+            Ok(map) => {
+                // Freemod slots are shown without mods
+                let bits = mods.unwrap_or(0);
+                let attrs = ctx.pp(&map).mods(bits).performance().await;
+                let clock_rate = GameModsIntermode::from_bits(bits).legacy_clock_rate();
+
+                Some(MappoolEntryMap {
+                    artist: map.artist().to_owned(),
+                    title: map.title().to_owned(),
+                    version: map.version().to_owned(),
+                    stars: attrs.stars() as f32,
+                    pp: attrs.pp() as f32,
+                    seconds_drain: (map.seconds_drain() as f32 / clock_rate) as u32,
+                    bpm: map.bpm() * clock_rate,
+                })
+            }
+            Err(MapError::NotFound) => None,
+            Err(MapError::Report(err)) => {
+                warn!(map_id, ?err, "Failed to get mappool map");
+
+                None
+            }
+        };
+
+        Self {
+            slot,
+            map_id,
+            mods,
+            map,
+        }
+    }
+}
+
+pub(super) async fn show(
+    ctx: Arc<Context>,
+    mut command: InteractionCommand,
+    args: MappoolShow,
+) -> Result<()> {
+    let Some(mappool) = get_mappool(&ctx, &command, &args.name).await? else {
+        return Ok(());
+    };
+
+    let mut maps = match ctx.mappool().maps(mappool.mappool_id).await {
+        Ok(maps) => maps,
+        Err(err) => {
+            let _ = command.error_localized(&ctx, ErrorKey::General).await;
+
+            return Err(err);
+        }
+    };
+
+    sort_slots(&mut maps);
+
+    let entries = maps.into_iter().map(|map| {
+        let mods = map.mods.map(|mods| mods as u32);
+
+        MappoolEntry::new(&ctx, map.slot, map.map_id as u32, mods)
+    });
+
+    let entries = future::join_all(entries).await;
+
+    let pagination = MappoolPagination::builder()
+        .entries(entries.into_boxed_slice())
+        .name(mappool.name.into_boxed_str())
+        .msg_owner(command.user_id()?)
+        .build();
+
+    ActiveMessages::builder(pagination)
+        .start_by_update(true)
+        .begin(ctx, &mut command)
+        .await
+}
@@ -1,7 +1,7 @@
 use std::{sync::Arc, time::Duration};
 
 use bathbot_macros::SlashCommand;
-use bathbot_util::{constants::OSU_API_ISSUE, matcher, MessageBuilder};
+use bathbot_util::{matcher, MessageBuilder};
 use eyre::{Report, Result};
 use rosu_v2::prelude::{MatchScore, OsuError, Team};
 use tokio::time::interval;
@@ -10,7 +10,7 @@ use twilight_interactions::command::{CommandModel, CommandOption, CreateCommand,
 use super::retrieve_previous;
 use crate::{
     active::{impls::MatchComparePagination, ActiveMessages},
-    core::Context,
+    core::{locale::ErrorKey, Context},
     util::{interaction::InteractionCommand, Authored, ChannelExt, InteractionCommandExt},
 };
 
@@ -117,7 +117,7 @@ async fn matchcompare(
             let previous_fut_2 = retrieve_previous(&mut match2, ctx.osu());
 
             if let Err(err) = tokio::try_join!(previous_fut_1, previous_fut_2) {
-                let _ = command.error(&ctx, OSU_API_ISSUE).await;
+                let _ = command.error_localized(&ctx, ErrorKey::OsuApi).await;
                 let report = Report::new(err)
                     .wrap_err("Failed to get history of at least one of the matches");
 
@@ -142,7 +142,7 @@ async fn matchcompare(
             return Ok(());
         }
         Err(err) => {
-            let _ = command.error(&ctx, OSU_API_ISSUE).await;
+            let _ = command.error_localized(&ctx, ErrorKey::OsuApi).await;
             let report = Report::new(err).wrap_err("failed to get at least one of the matches");
 
             return Err(report);
@@ -0,0 +1,593 @@
+use std::{
+    borrow::Cow,
+    fmt::{Display, Formatter, Result as FmtResult},
+    iter::Peekable,
+    str::CharIndices,
+};
+
+/// Maximum length of a formula's source
+const EXPR_MAX_LEN: usize = 128;
+
+/// Maximum nesting depth of a formula to keep evaluation cheap
+const MAX_DEPTH: usize = 16;
+
+/// A custom match cost formula.
+///
+/// Formulas are arithmetic expressions consisting of numbers, the variables
+/// listed in [`Variable`], the operators `+ - * / ^`, parentheses, and the
+/// functions listed in [`Function`] e.g.
+///
+/// ```text
+/// median_ratio * cbrt(played / avg_played)
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct MatchCostExpr {
+    source: Box<str>,
+    root: Expr,
+}
+
+impl MatchCostExpr {
+    pub fn parse(source: &str) -> Result<Self, ExprError> {
+        let source = source.trim();
+
+        if source.is_empty() {
+            return Err(ExprError::Empty);
+        } else if source.len() > EXPR_MAX_LEN {
+            return Err(ExprError::TooLong);
+        }
+
+        let mut parser = Parser {
+            tokens: Tokens::new(source).peekable(),
+            depth: 0,
+        };
+
+        let root = parser.parse_sum()?;
+
+        match parser.tokens.next().transpose()? {
+            Some(token) => Err(ExprError::Unexpected(token.to_string().into())),
+            None => Ok(Self {
+                source: source.into(),
+                root,
+            }),
+        }
+    }
+
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    /// Evaluates the formula, non-finite results are mapped to zero.
+    pub fn eval(&self, vars: &Variables) -> f32 {
+        let value = self.root.eval(vars);
+
+        if value.is_finite() {
+            value as f32
+        } else {
+            0.0
+        }
+    }
+}
+
+/// Values of a player's performance that can be used in formulas
+#[derive(Copy, Clone, Debug, Default)]
+pub struct Variables {
+    /// Rating of the default formula
+    pub bathbot: f64,
+    /// Average ratio between the player's score and the game's average score
+    pub avg_ratio: f64,
+    /// Average ratio between the player's score and the game's median score
+    pub median_ratio: f64,
+    /// Average z-score of the player's scores
+    pub z: f64,
+    /// Sum of the player's scores divided by the sum of the games' average
+    /// scores
+    pub share: f64,
+    /// Amount of games the player played in
+    pub played: f64,
+    /// Total amount of games
+    pub games: f64,
+    /// Average amount of games that players played in
+    pub avg_played: f64,
+    /// Average score of the player
+    pub avg_score: f64,
+    /// Amount of different mod combinations the player used
+    pub mods: f64,
+    /// `1` if the player played the tiebreaker, `0` otherwise
+    pub tb: f64,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Variable {
+    Bathbot,
+    AvgRatio,
+    MedianRatio,
+    Z,
+    Share,
+    Played,
+    Games,
+    AvgPlayed,
+    AvgScore,
+    Mods,
+    Tiebreaker,
+}
+
+impl Variable {
+    pub const ALL: [Self; 11] = [
+        Self::Bathbot,
+        Self::AvgRatio,
+        Self::MedianRatio,
+        Self::Z,
+        Self::Share,
+        Self::Played,
+        Self::Games,
+        Self::AvgPlayed,
+        Self::AvgScore,
+        Self::Mods,
+        Self::Tiebreaker,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Bathbot => "bathbot",
+            Self::AvgRatio => "avg_ratio",
+            Self::MedianRatio => "median_ratio",
+            Self::Z => "z",
+            Self::Share => "share",
+            Self::Played => "played",
+            Self::Games => "games",
+            Self::AvgPlayed => "avg_played",
+            Self::AvgScore => "avg_score",
+            Self::Mods => "mods",
+            Self::Tiebreaker => "tb",
+        }
+    }
+
+    fn value(self, vars: &Variables) -> f64 {
+        match self {
+            Self::Bathbot => vars.bathbot,
+            Self::AvgRatio => vars.avg_ratio,
+            Self::MedianRatio => vars.median_ratio,
+            Self::Z => vars.z,
+            Self::Share => vars.share,
+            Self::Played => vars.played,
+            Self::Games => vars.games,
+            Self::AvgPlayed => vars.avg_played,
+            Self::AvgScore => vars.avg_score,
+            Self::Mods => vars.mods,
+            Self::Tiebreaker => vars.tb,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Function {
+    Abs,
+    Sqrt,
+    Cbrt,
+    Ln,
+    Log10,
+    Exp,
+    Min,
+    Max,
+}
+
+impl Function {
+    pub const ALL: [Self; 8] = [
+        Self::Abs,
+        Self::Sqrt,
+        Self::Cbrt,
+        Self::Ln,
+        Self::Log10,
+        Self::Exp,
+        Self::Min,
+        Self::Max,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Abs => "abs",
+            Self::Sqrt => "sqrt",
+            Self::Cbrt => "cbrt",
+            Self::Ln => "ln",
+            Self::Log10 => "log10",
+            Self::Exp => "exp",
+            Self::Min => "min",
+            Self::Max => "max",
+        }
+    }
+
+    fn arg_count(self) -> usize {
+        match self {
+            Self::Min | Self::Max => 2,
+            _ => 1,
+        }
+    }
+
+    fn apply(self, args: &[f64]) -> f64 {
+        match self {
+            Self::Abs => args[0].abs(),
+            Self::Sqrt => args[0].sqrt(),
+            Self::Cbrt => args[0].cbrt(),
+            Self::Ln => args[0].ln(),
+            Self::Log10 => args[0].log10(),
+            Self::Exp => args[0].exp(),
+            Self::Min => args[0].min(args[1]),
+            Self::Max => args[0].max(args[1]),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Expr {
+    Num(f64),
+    Var(Variable),
+    Neg(Box<Expr>),
+    Binary(BinaryOp, Box<Expr>, Box<Expr>),
+    Call(Function, Box<[Expr]>),
+}
+
+impl Expr {
+    fn eval(&self, vars: &Variables) -> f64 {
+        match self {
+            Self::Num(n) => *n,
+            Self::Var(var) => var.value(vars),
+            Self::Neg(expr) => -expr.eval(vars),
+            Self::Binary(op, lhs, rhs) => {
+                let lhs = lhs.eval(vars);
+                let rhs = rhs.eval(vars);
+
+                match op {
+                    BinaryOp::Add => lhs + rhs,
+                    BinaryOp::Sub => lhs - rhs,
+                    BinaryOp::Mul => lhs * rhs,
+                    BinaryOp::Div => lhs / rhs,
+                    BinaryOp::Pow => lhs.powf(rhs),
+                }
+            }
+            Self::Call(func, args) => {
+                let args: Vec<_> = args.iter().map(|arg| arg.eval(vars)).collect();
+
+                func.apply(&args)
+            }
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+enum BinaryOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Pow,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ExprError {
+    Empty,
+    TooLong,
+    TooDeep,
+    UnexpectedEnd,
+    Unexpected(Box<str>),
+    UnknownVariable(Box<str>),
+    UnknownFunction(Box<str>),
+    ArgCount(Function),
+}
+
+impl ExprError {
+    pub fn into_str(self) -> Cow<'static, str> {
+        match self {
+            Self::Empty => "The formula must not be empty".into(),
+            Self::TooLong => {
+                format!("The formula must not be longer than {EXPR_MAX_LEN} characters").into()
+            }
+            Self::TooDeep => "The formula is nested too deeply".into(),
+            Self::UnexpectedEnd => "The formula ended unexpectedly".into(),
+            Self::Unexpected(token) => format!("Unexpected `{token}` in the formula").into(),
+            Self::UnknownVariable(name) => format!("Unknown variable `{name}`").into(),
+            Self::UnknownFunction(name) => format!("Unknown function `{name}`").into(),
+            Self::ArgCount(func) => {
+                let count = func.arg_count();
+                let plural = if count == 1 { "" } else { "s" };
+
+                format!(
+                    "Function `{}` requires {count} argument{plural}",
+                    func.name()
+                )
+                .into()
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Token<'s> {
+    Num(f64),
+    Ident(&'s str),
+    Op(char),
+    Open,
+    Close,
+    Comma,
+}
+
+impl Display for Token<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::Num(n) => write!(f, "{n}"),
+            Self::Ident(ident) => f.write_str(ident),
+            Self::Op(op) => write!(f, "{op}"),
+            Self::Open => f.write_str("("),
+            Self::Close => f.write_str(")"),
+            Self::Comma => f.write_str(","),
+        }
+    }
+}
+
+struct Tokens<'s> {
+    source: &'s str,
+    chars: Peekable<CharIndices<'s>>,
+}
+
+impl<'s> Tokens<'s> {
+    fn new(source: &'s str) -> Self {
+        Self {
+            source,
+            chars: source.char_indices().peekable(),
+        }
+    }
+
+    /// Advances while `f` holds and returns the end index
+    fn take_while(&mut self, f: impl Fn(char) -> bool) -> usize {
+        while let Some(&(_, c)) = self.chars.peek() {
+            if !f(c) {
+                break;
+            }
+
+            self.chars.next();
+        }
+
+        self.chars.peek().map_or(self.source.len(), |&(idx, _)| idx)
+    }
+}
+
+impl<'s> Iterator for Tokens<'s> {
+    type Item = Result<Token<'s>, ExprError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (start, c) = loop {
+            let (idx, c) = self.chars.next()?;
+
+            if !c.is_whitespace() {
+                break (idx, c);
+            }
+        };
+
+        let token = match c {
+            '+' | '-' | '*' | '/' | '^' => Token::Op(c),
+            '(' => Token::Open,
+            ')' => Token::Close,
+            ',' => Token::Comma,
+            '0'..='9' | '.' => {
+                let end = self.take_while(|c| c.is_ascii_digit() || c == '.');
+                let num = &self.source[start..end];
+
+                match num.parse() {
+                    Ok(num) => Token::Num(num),
+                    Err(_) => return Some(Err(ExprError::Unexpected(num.into()))),
+                }
+            }
+            _ if c.is_ascii_alphabetic() || c == '_' => {
+                let end = self.take_while(|c| c.is_ascii_alphanumeric() || c == '_');
+
+                Token::Ident(&self.source[start..end])
+            }
+            _ => return Some(Err(ExprError::Unexpected(c.to_string().into()))),
+        };
+
+        Some(Ok(token))
+    }
+}
+
+struct Parser<'s> {
+    tokens: Peekable<Tokens<'s>>,
+    depth: usize,
+}
+
+impl<'s> Parser<'s> {
+    fn next_token(&mut self) -> Result<Token<'s>, ExprError> {
+        self.tokens.next().ok_or(ExprError::UnexpectedEnd)?
+    }
+
+    /// Consumes the next token if it's the given operator
+    fn next_op_if(&mut self, ops: &[char]) -> Option<char> {
+        match self.tokens.peek() {
+            Some(Ok(Token::Op(op))) if ops.contains(op) => {
+                let op = *op;
+                self.tokens.next();
+
+                Some(op)
+            }
+            _ => None,
+        }
+    }
+
+    fn descend(&mut self) -> Result<(), ExprError> {
+        self.depth += 1;
+
+        if self.depth > MAX_DEPTH {
+            Err(ExprError::TooDeep)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// `sum := product (('+' | '-') product)*`
+    fn parse_sum(&mut self) -> Result<Expr, ExprError> {
+        self.descend()?;
+        let mut expr = self.parse_product()?;
+
+        while let Some(op) = self.next_op_if(&['+', '-']) {
+            let op = if op == '+' {
+                BinaryOp::Add
+            } else {
+                BinaryOp::Sub
+            };
+
+            let rhs = self.parse_product()?;
+            expr = Expr::Binary(op, Box::new(expr), Box::new(rhs));
+        }
+
+        self.depth -= 1;
+
+        Ok(expr)
+    }
+
+    /// `product := unary (('*' | '/') unary)*`
+    fn parse_product(&mut self) -> Result<Expr, ExprError> {
+        let mut expr = self.parse_unary()?;
+
+        while let Some(op) = self.next_op_if(&['*', '/']) {
+            let op = if op == '*' {
+                BinaryOp::Mul
+            } else {
+                BinaryOp::Div
+            };
+
+            let rhs = self.parse_unary()?;
+            expr = Expr::Binary(op, Box::new(expr), Box::new(rhs));
+        }
+
+        Ok(expr)
+    }
+
+    /// `unary := '-' unary | power`
+    fn parse_unary(&mut self) -> Result<Expr, ExprError> {
+        if self.next_op_if(&['-']).is_some() {
+            self.descend()?;
+            let expr = self.parse_unary()?;
+            self.depth -= 1;
+
+            Ok(Expr::Neg(Box::new(expr)))
+        } else {
+            self.parse_power()
+        }
+    }
+
+    /// `power := atom ('^' unary)?`
+    fn parse_power(&mut self) -> Result<Expr, ExprError> {
+        let base = self.parse_atom()?;
+
+        if self.next_op_if(&['^']).is_none() {
+            return Ok(base);
+        }
+
+        self.descend()?;
+        let exp = self.parse_unary()?;
+        self.depth -= 1;
+
+        Ok(Expr::Binary(BinaryOp::Pow, Box::new(base), Box::new(exp)))
+    }
+
+    /// `atom := number | variable | function '(' args ')' | '(' sum ')'`
+    fn parse_atom(&mut self) -> Result<Expr, ExprError> {
+        match self.next_token()? {
+            Token::Num(n) => Ok(Expr::Num(n)),
+            Token::Open => {
+                let expr = self.parse_sum()?;
+
+                match self.next_token()? {
+                    Token::Close => Ok(expr),
+                    token => Err(ExprError::Unexpected(token.to_string().into())),
+                }
+            }
+            Token::Ident(ident) => {
+                if let Some(var) = Variable::ALL.into_iter().find(|var| var.name() == ident) {
+                    return Ok(Expr::Var(var));
+                }
+
+                let is_call = matches!(self.tokens.peek(), Some(Ok(Token::Open)));
+
+                let Some(func) = Function::ALL.into_iter().find(|func| func.name() == ident) else {
+                    return if is_call {
+                        Err(ExprError::UnknownFunction(ident.into()))
+                    } else {
+                        Err(ExprError::UnknownVariable(ident.into()))
+                    };
+                };
+
+                match self.next_token()? {
+                    Token::Open => {}
+                    token => return Err(ExprError::Unexpected(token.to_string().into())),
+                }
+
+                let mut args = vec![self.parse_sum()?];
+
+                loop {
+                    match self.next_token()? {
+                        Token::Comma => args.push(self.parse_sum()?),
+                        Token::Close => break,
+                        token => return Err(ExprError::Unexpected(token.to_string().into())),
+                    }
+                }
+
+                if args.len() != func.arg_count() {
+                    return Err(ExprError::ArgCount(func));
+                }
+
+                Ok(Expr::Call(func, args.into_boxed_slice()))
+            }
+            token => Err(ExprError::Unexpected(token.to_string().into())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn eval(source: &str, vars: &Variables) -> f32 {
+        MatchCostExpr::parse(source).unwrap().eval(vars)
+    }
+
+    #[test]
+    fn precedence() {
+        let vars = Variables::default();
+
+        assert_eq!(eval("1 + 2 * 3", &vars), 7.0);
+        assert_eq!(eval("(1 + 2) * 3", &vars), 9.0);
+        assert_eq!(eval("2 ^ 3 ^ 2", &vars), 512.0);
+        assert_eq!(eval("-2 ^ 2", &vars), -4.0);
+        assert_eq!(eval("8 / 4 / 2", &vars), 1.0);
+        assert_eq!(eval("1 - -1", &vars), 2.0);
+    }
+
+    #[test]
+    fn variables_and_functions() {
+        let vars = Variables {
+            median_ratio: 1.5,
+            played: 8.0,
+            avg_played: 1.0,
+            ..Default::default()
+        };
+
+        assert_eq!(eval("median_ratio * cbrt(played / avg_played)", &vars), 3.0);
+        assert_eq!(eval("max(played, 10) - min(1, .5)", &vars), 9.5);
+        assert_eq!(eval("1 / (played - 8)", &vars), 0.0);
+    }
+
+    #[test]
+    fn errors() {
+        let err = |source| MatchCostExpr::parse(source).unwrap_err();
+
+        assert_eq!(err(""), ExprError::Empty);
+        assert_eq!(err("1 +"), ExprError::UnexpectedEnd);
+        assert_eq!(err("(1"), ExprError::UnexpectedEnd);
+        assert_eq!(err("1 2"), ExprError::Unexpected("2".into()));
+        assert_eq!(err("score"), ExprError::UnknownVariable("score".into()));
+        assert_eq!(err("pow(2, 3)"), ExprError::UnknownFunction("pow".into()));
+        assert_eq!(err("min(1)"), ExprError::ArgCount(Function::Min));
+        assert_eq!(err("1 % 2"), ExprError::Unexpected("%".into()));
+        assert_eq!(err(&"(".repeat(20)), ExprError::TooDeep);
+        assert_eq!(err(&"1+".repeat(100)), ExprError::TooLong);
+    }
+}
@@ -0,0 +1,57 @@
+use std::borrow::Cow;
+
+use twilight_interactions::command::{CommandOption, CreateOption};
+
+use super::expr::{MatchCostExpr, Variables};
+
+#[derive(Copy, Clone, CommandOption, CreateOption, Eq, PartialEq)]
+pub enum MatchCostFormulaKind {
+    #[option(name = "Bathbot", value = "bathbot")]
+    Bathbot,
+    #[option(name = "Flashlight", value = "flashlight")]
+    Flashlight,
+    #[option(name = "Z-score", value = "z_score")]
+    ZScore,
+    #[option(name = "Score share", value = "score_share")]
+    ScoreShare,
+    #[option(name = "Custom server formula", value = "custom")]
+    Custom,
+}
+
+/// The formula that combines a player's [`Variables`] into their match cost
+pub enum MatchCostFormula {
+    /// Average score ratio with bonuses for participation, tiebreakers, and
+    /// mod combinations
+    Bathbot,
+    /// Average ratio to the median score, scaled by the cube root of the
+    /// player's participation relative to the average participation
+    Flashlight,
+    /// Average z-score
+    ZScore,
+    /// Share of the total score relative to an average player who played all
+    /// games
+    ScoreShare,
+    Custom(MatchCostExpr),
+}
+
+impl MatchCostFormula {
+    pub fn name(&self) -> Cow<'static, str> {
+        match self {
+            Self::Bathbot => "Bathbot".into(),
+            Self::Flashlight => "Flashlight".into(),
+            Self::ZScore => "Z-score".into(),
+            Self::ScoreShare => "Score share".into(),
+            Self::Custom(expr) => format!("Custom: {}", expr.source()).into(),
+        }
+    }
+
+    pub(super) fn eval(&self, vars: &Variables) -> f32 {
+        match self {
+            Self::Bathbot => vars.bathbot as f32,
+            Self::Flashlight => (vars.median_ratio * (vars.played / vars.avg_played).cbrt()) as f32,
+            Self::ZScore => vars.z as f32,
+            Self::ScoreShare => vars.share as f32,
+            Self::Custom(expr) => expr.eval(vars),
+        }
+    }
+}
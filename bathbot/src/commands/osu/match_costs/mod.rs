@@ -7,31 +7,43 @@ use std::{
 };
 
 use bathbot_macros::{command, SlashCommand};
-use bathbot_util::{constants::OSU_API_ISSUE, matcher, IntHasher, MessageBuilder};
+use bathbot_util::{matcher, IntHasher, MessageBuilder};
 use eyre::{Report, Result};
 use rosu_v2::prelude::{
     GameModIntermode, MatchGame, Osu, OsuError, OsuMatch, OsuResult, Team, TeamType, User,
 };
 use twilight_interactions::command::{CommandModel, CreateCommand};
 
+use self::expr::Variables;
+pub use self::{
+    expr::MatchCostExpr,
+    formula::{MatchCostFormula, MatchCostFormulaKind},
+};
 use crate::{
     commands::ShowHideOption,
-    core::commands::{
-        prefix::{Args, ArgsNum},
-        CommandOrigin,
+    core::{
+        commands::{
+            prefix::{Args, ArgsNum},
+            CommandOrigin,
+        },
+        locale::ErrorKey,
     },
     embeds::{EmbedData, MatchCostEmbed},
     util::{interaction::InteractionCommand, ChannelExt, InteractionCommandExt},
     Context,
 };
 
+mod expr;
+mod formula;
+
 #[derive(CommandModel, CreateCommand, SlashCommand)]
 #[command(
     name = "matchcost",
     desc = "Display performance ratings for a multiplayer match",
     help = "Calculate a performance rating for each player in the given multiplayer match.\n\
     Here's the current [formula](https://i.imgur.com/7KFwcUS.png).\n\
-    Additionally, scores with the EZ mod are multiplied by 1.7 beforehand.\n\n\
+    Additionally, scores with the EZ mod are multiplied by 1.7 beforehand.\n\
+    Other formulas can be chosen via the `formula` option.\n\n\
     Keep in mind that all bots use different formulas \
     so comparing with values from other bots makes no sense."
 )]
@@ -68,6 +80,18 @@ pub struct MatchCost<'a> {
         desc = "Whether the average scores should be shown"
     )]
     avg_scores: Option<ShowHideOption>,
+    #[command(
+        desc = "Specify the formula to calculate ratings with",
+        help = "Specify the formula to calculate ratings with.\n\
+        - `Bathbot`: The default formula, see above\n\
+        - `Flashlight`: Average ratio to the median score, \
+        scaled by the cube root of the participation relative to the average participation\n\
+        - `Z-score`: Average amount of standard deviations above the average score\n\
+        - `Score share`: Total score relative to an average player who played all games\n\
+        - `Custom server formula`: The formula set via `/serverconfig edit match_cost_formula`\n\
+        If the server has a custom formula, it is used by default."
+    )]
+    formula: Option<MatchCostFormulaKind>,
 }
 
 impl<'m> MatchCost<'m> {
@@ -93,6 +117,7 @@ impl<'m> MatchCost<'m> {
             skip_last: None,
             ez_mult: None,
             avg_scores: None,
+            formula: None,
         })
     }
 }
@@ -138,6 +163,7 @@ async fn matchcosts(ctx: Arc<Context>, orig: CommandOrigin<'_>, args: MatchCost<
         skip_last,
         ez_mult,
         avg_scores,
+        formula,
     } = args;
 
     let match_id = match matcher::get_osu_match_id(&match_url) {
@@ -150,6 +176,39 @@ async fn matchcosts(ctx: Arc<Context>, orig: CommandOrigin<'_>, args: MatchCost<
         }
     };
 
+    let custom_formula = match orig.guild_id() {
+        Some(guild_id) => {
+            ctx.guild_config()
+                .peek(guild_id, |config| config.match_cost_formula.clone())
+                .await
+        }
+        None => None,
+    };
+
+    let formula = match (formula, custom_formula) {
+        (Some(MatchCostFormulaKind::Custom) | None, Some(source)) => {
+            match MatchCostExpr::parse(&source) {
+                Ok(expr) => MatchCostFormula::Custom(expr),
+                Err(err) => {
+                    let content =
+                        format!("The server's custom formula is invalid: {}", err.into_str());
+
+                    return orig.error(&ctx, content).await;
+                }
+            }
+        }
+        (Some(MatchCostFormulaKind::Custom), None) => {
+            let content = "This server has no custom formula, \
+                an authority can set one via `/serverconfig edit match_cost_formula`";
+
+            return orig.error(&ctx, content).await;
+        }
+        (Some(MatchCostFormulaKind::Bathbot), _) | (None, None) => MatchCostFormula::Bathbot,
+        (Some(MatchCostFormulaKind::Flashlight), _) => MatchCostFormula::Flashlight,
+        (Some(MatchCostFormulaKind::ZScore), _) => MatchCostFormula::ZScore,
+        (Some(MatchCostFormulaKind::ScoreShare), _) => MatchCostFormula::ScoreShare,
+    };
+
     debug!("Match cost warmups: {warmups:?}");
 
     let warmups = warmups.unwrap_or(2);
@@ -199,7 +258,7 @@ async fn matchcosts(ctx: Arc<Context>, orig: CommandOrigin<'_>, args: MatchCost<
             return orig.error(&ctx, content).await;
         }
         Err(err) => {
-            let _ = orig.error(&ctx, OSU_API_ISSUE).await;
+            let _ = orig.error_localized(&ctx, ErrorKey::OsuApi).await;
             let report = Report::new(err).wrap_err("failed to get match");
 
             return Err(report);
@@ -229,7 +288,8 @@ async fn matchcosts(ctx: Arc<Context>, orig: CommandOrigin<'_>, args: MatchCost<
 
         (Some(description), None)
     } else {
-        let result = process_match(&games, osu_match.end_time.is_some(), &osu_match.users);
+        let finished = osu_match.end_time.is_some();
+        let result = process_match(&games, finished, &osu_match.users, &formula);
 
         (None, Some(result))
     };
@@ -237,9 +297,13 @@ async fn matchcosts(ctx: Arc<Context>, orig: CommandOrigin<'_>, args: MatchCost<
     let show_scores = matches!(avg_scores, Some(ShowHideOption::Show));
 
     // TODO: pagination(?)
-    let Some(embed_data) =
-        MatchCostEmbed::new(&mut osu_match, description, match_result, show_scores)
-    else {
+    let Some(embed_data) = MatchCostEmbed::new(
+        &mut osu_match,
+        description,
+        match_result,
+        show_scores,
+        &formula,
+    ) else {
         return orig.error(&ctx, TOO_MANY_PLAYERS_TEXT).await;
     };
 
@@ -338,6 +402,7 @@ pub fn process_match(
     games: &[MatchGame],
     finished: bool,
     users: &HashMap<u32, User>,
+    formula: &MatchCostFormula,
 ) -> MatchResult {
     let mut teams = HashMap::with_hasher(IntHasher);
     let mut point_costs = HashMap::with_hasher(IntHasher);
@@ -345,6 +410,9 @@ pub fn process_match(
     let team_vs = games[0].team_type == TeamType::TeamVS;
     let mut match_scores = MatchScores(0, 0);
 
+    // Sum of each game's average score
+    let mut avg_sum = 0.0;
+
     // Calculate point scores for each score in each game
     for game in games.iter() {
         let score_sum: f32 = game.scores.iter().map(|s| s.score as f32).sum();
@@ -352,20 +420,28 @@ pub fn process_match(
         let avg = score_sum / game.scores.iter().filter(|s| s.score > 0).count() as f32;
         let mut team_scores = HashMap::with_capacity(team_vs as usize + 1);
 
+        let stats = GameStats::new(game);
+
+        if avg.is_finite() {
+            avg_sum += avg as f64;
+        }
+
         for score in game.scores.iter().filter(|s| s.score > 0) {
             mods.entry(score.user_id)
                 .or_insert_with(HashSet::new)
                 .insert(score.mods.clone() - GameModIntermode::NoFail);
 
-            let mut point_cost = score.score as f32 / avg;
-
-            point_cost += FLAT_PARTICIPATION_BONUS;
+            let ratio = score.score as f32 / avg;
+            let point_cost = ratio + FLAT_PARTICIPATION_BONUS;
 
             point_costs
                 .entry(score.user_id)
                 .or_insert_with(Vec::new)
                 .push(PlayerScore {
                     point_cost,
+                    ratio,
+                    median_ratio: score.score as f64 / stats.median,
+                    z: stats.z(score.score),
                     score: score.score,
                 });
 
@@ -385,14 +461,19 @@ pub fn process_match(
         match_scores.incr(winner_team);
     }
 
-    // Tiebreaker bonus
-    if let Some(game) = games
+    let tiebreaker = games
         .last()
-        .filter(|_| finished && games.len() > 4 && match_scores.difference() == 1)
-    {
+        .filter(|_| finished && games.len() > 4 && match_scores.difference() == 1);
+
+    let played_tiebreaker = |user_id: u32| {
+        tiebreaker.is_some_and(|game| game.scores.iter().any(|score| score.user_id == user_id))
+    };
+
+    // Tiebreaker bonus
+    if tiebreaker.is_some() {
         point_costs
             .iter_mut()
-            .filter(|(&user_id, _)| game.scores.iter().any(|score| score.user_id == user_id))
+            .filter(|(&user_id, _)| played_tiebreaker(user_id))
             .filter_map(|(_, costs)| costs.last_mut())
             .for_each(|value| {
                 value.point_cost -= FLAT_PARTICIPATION_BONUS;
@@ -403,23 +484,26 @@ pub fn process_match(
 
     // Mod combinations bonus
     let mods_count = mods
-        .into_iter()
+        .iter()
         .filter(|(_, mods)| mods.len() > 2)
         .map(|(id, mods)| (id, mods.len() - 2));
 
     for (user_id, count) in mods_count {
         let mult = 1.0 + count as f32 * MOD_BONUS;
 
-        point_costs.entry(user_id).and_modify(|point_costs| {
+        point_costs.entry(*user_id).and_modify(|point_costs| {
             point_costs
                 .iter_mut()
                 .for_each(|value| value.point_cost *= mult);
         });
     }
 
+    let avg_played =
+        point_costs.values().map(Vec::len).sum::<usize>() as f64 / point_costs.len() as f64;
+
     // Calculate match costs by combining point costs
     let mut data = HashMap::with_capacity(team_vs as usize + 1);
-    let mut highest_cost = 0.0;
+    let mut highest_cost = f32::NEG_INFINITY;
     let mut mvp_avatar_url = None;
 
     for (user_id, point_costs) in point_costs {
@@ -431,7 +515,7 @@ pub fn process_match(
                 });
 
         let costs_len = point_costs.len() as f32;
-        let mut match_cost = point_cost_sum / costs_len;
+        let mut bathbot_cost = point_cost_sum / costs_len;
         let avg_score = (score_sum as f32 / costs_len) as u32;
 
         let exp = match games.len() {
@@ -439,7 +523,25 @@ pub fn process_match(
             len => (costs_len - 1.0) / (len as f32 - 1.0),
         };
 
-        match_cost *= BASE_PARTICIPATION_BONUS.powf(exp.powf(EXP_PARTICIPATION_BONUS));
+        bathbot_cost *= BASE_PARTICIPATION_BONUS.powf(exp.powf(EXP_PARTICIPATION_BONUS));
+
+        let played = point_costs.len() as f64;
+
+        let vars = Variables {
+            bathbot: bathbot_cost as f64,
+            avg_ratio: point_costs.iter().map(|s| s.ratio as f64).sum::<f64>() / played,
+            median_ratio: point_costs.iter().map(|s| s.median_ratio).sum::<f64>() / played,
+            z: point_costs.iter().map(|s| s.z).sum::<f64>() / played,
+            share: score_sum as f64 / avg_sum,
+            played,
+            games: games.len() as f64,
+            avg_played,
+            avg_score: avg_score as f64,
+            mods: mods.get(&user_id).map_or(0, HashSet::len) as f64,
+            tb: played_tiebreaker(user_id) as u8 as f64,
+        };
+
+        let match_cost = formula.eval(&vars);
 
         data.entry(*teams.get(&user_id).unwrap())
             .or_insert_with(Vec::new)
@@ -490,9 +592,70 @@ pub fn process_match(
 
 struct PlayerScore {
     point_cost: f32,
+    ratio: f32,
+    median_ratio: f64,
+    z: f64,
     score: u32,
 }
 
+/// Distribution of the non-zero scores of a game
+struct GameStats {
+    median: f64,
+    mean: f64,
+    std_dev: f64,
+}
+
+impl GameStats {
+    fn new(game: &MatchGame) -> Self {
+        let mut scores: Vec<_> = game
+            .scores
+            .iter()
+            .filter(|s| s.score > 0)
+            .map(|s| s.score as f64)
+            .collect();
+
+        if scores.is_empty() {
+            return Self {
+                median: 0.0,
+                mean: 0.0,
+                std_dev: 0.0,
+            };
+        }
+
+        scores.sort_unstable_by(f64::total_cmp);
+
+        let len = scores.len();
+
+        let median = if len % 2 == 0 {
+            (scores[len / 2 - 1] + scores[len / 2]) / 2.0
+        } else {
+            scores[len / 2]
+        };
+
+        let mean = scores.iter().sum::<f64>() / len as f64;
+
+        let variance = scores
+            .iter()
+            .map(|score| (score - mean) * (score - mean))
+            .sum::<f64>()
+            / len as f64;
+
+        Self {
+            median,
+            mean,
+            std_dev: variance.sqrt(),
+        }
+    }
+
+    fn z(&self, score: u32) -> f64 {
+        if self.std_dev > 0.0 {
+            (score as f64 - self.mean) / self.std_dev
+        } else {
+            0.0
+        }
+    }
+}
+
 pub struct PlayerResult {
     pub user_id: u32,
     pub match_cost: f32,
@@ -0,0 +1,284 @@
+use std::{borrow::Cow, fmt::Write, sync::Arc};
+
+use bathbot_util::{EmbedBuilder, MessageBuilder};
+use eyre::Result;
+use twilight_model::id::{marker::ChannelMarker, Id};
+
+use super::{parse_match_id, MatchliveBan, MatchliveMappool, MatchlivePick, MatchlivePicks};
+use crate::{
+    commands::osu::mappool::sort_slots,
+    core::{commands::CommandOrigin, locale::ErrorKey},
+    matchlive::{MatchMappool, PickBanKind, PickBanResult},
+    Context,
+};
+
+const TEAM_MAX_LEN: usize = 32;
+const NOT_TRACKED: &str = "The match is not tracked in this channel";
+
+pub(super) async fn link(
+    ctx: Arc<Context>,
+    orig: CommandOrigin<'_>,
+    args: MatchliveMappool<'_>,
+) -> Result<()> {
+    let Some(guild_id) = orig.guild_id() else {
+        return orig
+            .error(&ctx, "Mappools are only available in servers")
+            .await;
+    };
+
+    let channel = orig.channel_id();
+
+    let match_id = match resolve_match_id(&ctx, channel, args.match_url.as_deref()).await {
+        Ok(match_id) => match_id,
+        Err(content) => return orig.error(&ctx, content).await,
+    };
+
+    let name = args.mappool.trim();
+
+    let mappool = match ctx.mappool().mappool(guild_id, name).await {
+        Ok(Some(mappool)) => mappool,
+        Ok(None) => {
+            let content = format!("There is no mappool called `{name}` on this server");
+
+            return orig.error(&ctx, content).await;
+        }
+        Err(err) => {
+            let _ = orig.error_localized(&ctx, ErrorKey::General).await;
+
+            return Err(err);
+        }
+    };
+
+    let mut maps = match ctx.mappool().maps(mappool.mappool_id).await {
+        Ok(maps) => maps,
+        Err(err) => {
+            let _ = orig.error_localized(&ctx, ErrorKey::General).await;
+
+            return Err(err);
+        }
+    };
+
+    if maps.is_empty() {
+        let content = format!(
+            "Mappool `{}` does not contain any maps yet, add some via `/mappool add`",
+            mappool.name
+        );
+
+        return orig.error(&ctx, content).await;
+    }
+
+    sort_slots(&mut maps);
+
+    let slots = maps
+        .into_iter()
+        .map(|map| (map.slot.into_boxed_str(), map.map_id as u32))
+        .collect();
+
+    let match_mappool = MatchMappool::new(mappool.name.as_str().into(), slots);
+
+    if !ctx
+        .link_match_mappool(channel, match_id, match_mappool)
+        .await
+    {
+        return orig.error(&ctx, NOT_TRACKED).await;
+    }
+
+    let content = format!(
+        "Linked mappool `{}` to the match.\n\
+        Log picks and bans via `/matchlive pick` and `/matchlive ban`.",
+        mappool.name
+    );
+
+    let builder = MessageBuilder::new().embed(content);
+    orig.create_message(&ctx, builder).await?;
+
+    Ok(())
+}
+
+pub(super) async fn pick(
+    ctx: Arc<Context>,
+    orig: CommandOrigin<'_>,
+    args: MatchlivePick<'_>,
+) -> Result<()> {
+    let MatchlivePick {
+        slot,
+        team,
+        match_url,
+    } = args;
+
+    pick_ban(ctx, orig, PickBanKind::Pick, slot, team, match_url).await
+}
+
+pub(super) async fn ban(
+    ctx: Arc<Context>,
+    orig: CommandOrigin<'_>,
+    args: MatchliveBan<'_>,
+) -> Result<()> {
+    let MatchliveBan {
+        slot,
+        team,
+        match_url,
+    } = args;
+
+    pick_ban(ctx, orig, PickBanKind::Ban, slot, team, match_url).await
+}
+
+async fn pick_ban(
+    ctx: Arc<Context>,
+    orig: CommandOrigin<'_>,
+    kind: PickBanKind,
+    slot: Cow<'_, str>,
+    team: Option<Cow<'_, str>>,
+    match_url: Option<Cow<'_, str>>,
+) -> Result<()> {
+    let team = team
+        .as_deref()
+        .map(str::trim)
+        .filter(|team| !team.is_empty());
+
+    if team.is_some_and(|team| team.chars().count() > TEAM_MAX_LEN) {
+        let content = format!("The team name must be at most {TEAM_MAX_LEN} characters long");
+
+        return orig.error(&ctx, content).await;
+    }
+
+    let channel = orig.channel_id();
+
+    let match_id = match resolve_match_id(&ctx, channel, match_url.as_deref()).await {
+        Ok(match_id) => match_id,
+        Err(content) => return orig.error(&ctx, content).await,
+    };
+
+    let slot = slot.trim();
+
+    let add_fut = ctx.add_match_pick_ban(channel, match_id, kind, slot, team.map(Box::from));
+
+    let content = match add_fut.await {
+        PickBanResult::Added => {
+            let action = match kind {
+                PickBanKind::Pick => "Picked",
+                PickBanKind::Ban => "Banned",
+            };
+
+            let mut content = format!("{action} `{}`", slot.to_ascii_uppercase());
+
+            if let Some(team) = team {
+                let _ = write!(content, " for `{team}`");
+            }
+
+            let builder = MessageBuilder::new().embed(content);
+            orig.create_message(&ctx, builder).await?;
+
+            return Ok(());
+        }
+        PickBanResult::NotTracked => NOT_TRACKED.to_owned(),
+        PickBanResult::NoMappool => {
+            "No mappool is linked to the match, link one via `/matchlive mappool`".to_owned()
+        }
+        PickBanResult::UnknownSlot => format!("The linked mappool has no slot `{slot}`"),
+        PickBanResult::Duplicate => format!("Slot `{slot}` was already picked or banned"),
+    };
+
+    orig.error(&ctx, content).await
+}
+
+pub(super) async fn picks(
+    ctx: Arc<Context>,
+    orig: CommandOrigin<'_>,
+    args: MatchlivePicks<'_>,
+) -> Result<()> {
+    let channel = orig.channel_id();
+
+    let match_id = match resolve_match_id(&ctx, channel, args.match_url.as_deref()).await {
+        Ok(match_id) => match_id,
+        Err(content) => return orig.error(&ctx, content).await,
+    };
+
+    let mappool = match ctx.match_mappool(channel, match_id).await {
+        Some(Some(mappool)) => mappool,
+        Some(None) => {
+            let content = "No mappool is linked to the match, link one via `/matchlive mappool`";
+
+            return orig.error(&ctx, content).await;
+        }
+        None => return orig.error(&ctx, NOT_TRACKED).await,
+    };
+
+    let embed = EmbedBuilder::new()
+        .description(pick_ban_log(&mappool))
+        .title(format!("Picks and bans • {}", mappool.name));
+
+    let builder = MessageBuilder::new().embed(embed);
+    orig.create_message(&ctx, builder).await?;
+
+    Ok(())
+}
+
+/// Parses the given match url or, if none is given, uses the match that's
+/// tracked in the channel.
+async fn resolve_match_id(
+    ctx: &Context,
+    channel: Id<ChannelMarker>,
+    match_url: Option<&str>,
+) -> Result<u32, &'static str> {
+    match match_url {
+        Some(match_url) => parse_match_id(match_url),
+        None => ctx.tracks_single_match(channel).await.ok_or(
+            "The channel does not track exactly one match, \
+            specify the match via the `match_url` option",
+        ),
+    }
+}
+
+fn pick_ban_log(mappool: &MatchMappool) -> String {
+    let mut description = String::with_capacity(256);
+
+    for (entry, i) in mappool.log.iter().zip(1..) {
+        let kind = match entry.kind {
+            PickBanKind::Pick => "Pick",
+            PickBanKind::Ban => "Ban",
+        };
+
+        let _ = write!(description, "`{i}.` {kind} `{}`", entry.slot);
+
+        if let Some(ref team) = entry.team {
+            let _ = write!(description, " • {team}");
+        }
+
+        let played = mappool
+            .slots
+            .iter()
+            .find(|(slot, _)| *slot == entry.slot)
+            .is_some_and(|(_, map_id)| {
+                mappool
+                    .played
+                    .iter()
+                    .any(|(_, played_id)| played_id == map_id)
+            });
+
+        if played {
+            description.push_str(" • ✅ Played");
+        }
+
+        description.push('\n');
+    }
+
+    if mappool.log.is_empty() {
+        description.push_str("No picks or bans yet\n");
+    }
+
+    let mut remaining = mappool
+        .slots
+        .iter()
+        .filter(|(slot, _)| mappool.log.iter().all(|entry| entry.slot != *slot));
+
+    if let Some((slot, _)) = remaining.next() {
+        let _ = write!(description, "\n**Remaining:** `{slot}`");
+
+        for (slot, _) in remaining {
+            let _ = write!(description, ", `{slot}`");
+        }
+    }
+
+    description
+}
@@ -4,10 +4,7 @@ use std::{borrow::Cow, sync::Arc};
 
 use bathbot_macros::{command, SlashCommand};
 use bathbot_util::{
-    constants::{
-        GENERAL_ISSUE, INVALID_ACTION_FOR_CHANNEL_TYPE, OSU_API_ISSUE, OSU_BASE,
-        THREADS_UNAVAILABLE,
-    },
+    constants::{INVALID_ACTION_FOR_CHANNEL_TYPE, OSU_BASE},
     matcher, MessageBuilder,
 };
 use eyre::{Report, Result, WrapErr};
@@ -17,12 +14,14 @@ use twilight_model::channel::{thread::AutoArchiveDuration, ChannelType};
 
 use crate::{
     commands::ThreadChannel,
-    core::commands::CommandOrigin,
+    core::{commands::CommandOrigin, locale::ErrorKey},
     matchlive::MatchTrackResult,
     util::{interaction::InteractionCommand, ChannelExt, CheckPermissions, InteractionCommandExt},
     Context,
 };
 
+mod mappool;
+
 #[derive(CommandModel, CreateCommand, SlashCommand)]
 #[command(
     name = "matchlive",
@@ -36,6 +35,14 @@ pub enum Matchlive<'a> {
     Add(MatchliveAdd<'a>),
     #[command(name = "untrack")]
     Remove(MatchliveRemove<'a>),
+    #[command(name = "mappool")]
+    Mappool(MatchliveMappool<'a>),
+    #[command(name = "pick")]
+    Pick(MatchlivePick<'a>),
+    #[command(name = "ban")]
+    Ban(MatchliveBan<'a>),
+    #[command(name = "picks")]
+    Picks(MatchlivePicks<'a>),
 }
 
 #[derive(CommandModel, CreateCommand)]
@@ -54,10 +61,59 @@ pub struct MatchliveRemove<'a> {
     match_url: Cow<'a, str>,
 }
 
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "mappool",
+    desc = "Link a mappool to a tracked match",
+    help = "Link one of the server's mappools to a match that's tracked in this channel.\n\
+    Games of the mappool's maps will be annotated with their slot and \
+    played slots are logged as picks, see `/matchlive picks`.\n\
+    Picks and bans are only kept in memory so they're lost when the bot restarts."
+)]
+pub struct MatchliveMappool<'a> {
+    #[command(desc = "Specify the name of the mappool")]
+    mappool: Cow<'a, str>,
+    #[command(desc = "Specify a match url or match id, only required if multiple are tracked")]
+    match_url: Option<Cow<'a, str>>,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "pick", desc = "Log a pick for a tracked match")]
+pub struct MatchlivePick<'a> {
+    #[command(desc = "Specify the picked slot, e.g. `NM1`")]
+    slot: Cow<'a, str>,
+    #[command(desc = "Specify the team that picked")]
+    team: Option<Cow<'a, str>>,
+    #[command(desc = "Specify a match url or match id, only required if multiple are tracked")]
+    match_url: Option<Cow<'a, str>>,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "ban", desc = "Log a ban for a tracked match")]
+pub struct MatchliveBan<'a> {
+    #[command(desc = "Specify the banned slot, e.g. `HD2`")]
+    slot: Cow<'a, str>,
+    #[command(desc = "Specify the team that banned")]
+    team: Option<Cow<'a, str>>,
+    #[command(desc = "Specify a match url or match id, only required if multiple are tracked")]
+    match_url: Option<Cow<'a, str>>,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "picks", desc = "Show the picks and bans of a tracked match")]
+pub struct MatchlivePicks<'a> {
+    #[command(desc = "Specify a match url or match id, only required if multiple are tracked")]
+    match_url: Option<Cow<'a, str>>,
+}
+
 async fn slash_matchlive(ctx: Arc<Context>, mut command: InteractionCommand) -> Result<()> {
     match Matchlive::from_interaction(command.input_data())? {
         Matchlive::Add(args) => matchlive(ctx, (&mut command).into(), args).await,
         Matchlive::Remove(args) => matchliveremove(ctx, (&mut command).into(), Some(args)).await,
+        Matchlive::Mappool(args) => mappool::link(ctx, (&mut command).into(), args).await,
+        Matchlive::Pick(args) => mappool::pick(ctx, (&mut command).into(), args).await,
+        Matchlive::Ban(args) => mappool::ban(ctx, (&mut command).into(), args).await,
+        Matchlive::Picks(args) => mappool::picks(ctx, (&mut command).into(), args).await,
     }
 }
 
@@ -156,7 +212,9 @@ async fn matchlive(
 
     if let ThreadChannel::Thread = thread {
         if orig.guild_id().is_none() {
-            return orig.error(&ctx, THREADS_UNAVAILABLE).await;
+            return orig
+                .error_localized(&ctx, "error.threads_unavailable")
+                .await;
         }
 
         if !orig.can_create_thread() {
@@ -178,21 +236,21 @@ async fn matchlive(
         match create_fut.await {
             Ok(res) => channel = res.model().await?.id,
             Err(err) => {
-                let content = match err.kind() {
+                let key = match err.kind() {
                     ErrorType::Response {
                         error: ApiError::General(err),
                         ..
                     } => match err.code {
-                        INVALID_ACTION_FOR_CHANNEL_TYPE => Some(THREADS_UNAVAILABLE),
+                        INVALID_ACTION_FOR_CHANNEL_TYPE => Some(ErrorKey::ThreadsUnavailable),
                         _ => None,
                     },
                     _ => None,
                 };
 
-                match content {
-                    Some(content) => return orig.error(&ctx, content).await,
+                match key {
+                    Some(key) => return orig.error_localized(&ctx, key).await,
                     None => {
-                        let _ = orig.error(&ctx, GENERAL_ISSUE).await;
+                        let _ = orig.error_localized(&ctx, ErrorKey::General).await;
                         let report = Report::new(err).wrap_err("failed to create thread");
 
                         return Err(report);
@@ -216,7 +274,7 @@ async fn matchlive(
         },
         MatchTrackResult::Capped => "Channels can track at most three games at a time",
         MatchTrackResult::Duplicate => "That match is already being tracking in this channel",
-        MatchTrackResult::Error => OSU_API_ISSUE,
+        MatchTrackResult::Error => return orig.error_localized(&ctx, ErrorKey::OsuApi).await,
         MatchTrackResult::NotFound => "The osu!api returned a 404 indicating an invalid match id",
         MatchTrackResult::Private => "The match can't be tracked because it is private",
     };
@@ -8,10 +8,7 @@ use bathbot_macros::command;
 use bathbot_model::{
     rkyv_util::time::DateTimeRkyv, rosu_v2::user::User, MedalGroup, OsekaiMedal, Rarity,
 };
-use bathbot_util::{
-    constants::{GENERAL_ISSUE, OSEKAI_ISSUE, OSU_API_ISSUE},
-    matcher, IntHasher,
-};
+use bathbot_util::{matcher, IntHasher};
 use eyre::{Report, Result};
 use hashbrown::HashMap;
 use rkyv::{with::DeserializeWith, Infallible};
@@ -25,7 +22,7 @@ use super::{MedalCommon, MedalCommonFilter, MedalCommonOrder};
 use crate::{
     active::{impls::MedalsCommonPagination, ActiveMessages},
     commands::osu::UserExtraction,
-    core::commands::CommandOrigin,
+    core::{commands::CommandOrigin, locale::ErrorKey},
     manager::redis::{osu::UserArgs, RedisData},
     util::osu::get_combined_thumbnail,
     Context,
@@ -86,7 +83,7 @@ pub(super) async fn common(
     let user_id1 = match extract_user_id(&ctx, &mut args).await {
         UserExtraction::Id(user_id) => user_id,
         UserExtraction::Err(err) => {
-            let _ = orig.error(&ctx, GENERAL_ISSUE).await;
+            let _ = orig.error_localized(&ctx, ErrorKey::General).await;
 
             return Err(err);
         }
@@ -102,7 +99,7 @@ pub(super) async fn common(
     let user_id2 = match extract_user_id(&ctx, &mut args).await {
         UserExtraction::Id(user_id) => user_id,
         UserExtraction::Err(err) => {
-            let _ = orig.error(&ctx, GENERAL_ISSUE).await;
+            let _ = orig.error_localized(&ctx, ErrorKey::General).await;
 
             return Err(err);
         }
@@ -116,7 +113,7 @@ pub(super) async fn common(
                 return orig.error(&ctx, content).await;
             }
             Err(err) => {
-                let _ = orig.error(&ctx, GENERAL_ISSUE).await;
+                let _ = orig.error_localized(&ctx, ErrorKey::General).await;
 
                 return Err(err);
             }
@@ -148,7 +145,7 @@ pub(super) async fn common(
             return orig.error(&ctx, content).await;
         }
         (Err(err), _) | (_, Err(err)) => {
-            let _ = orig.error(&ctx, OSU_API_ISSUE).await;
+            let _ = orig.error_localized(&ctx, ErrorKey::OsuApi).await;
 
             return Err(Report::new(err).wrap_err("failed to get user"));
         }
@@ -157,7 +154,7 @@ pub(super) async fn common(
     let mut all_medals = match all_medals_res {
         Ok(medals) => medals.into_original(),
         Err(err) => {
-            let _ = orig.error(&ctx, OSEKAI_ISSUE).await;
+            let _ = orig.error_localized(&ctx, ErrorKey::Osekai).await;
 
             return Err(err.wrap_err("failed to get cached medals"));
         }
@@ -273,7 +270,7 @@ pub(super) async fn common(
                         });
                     }
                     Err(err) => {
-                        let _ = orig.error(&ctx, OSEKAI_ISSUE).await;
+                        let _ = orig.error_localized(&ctx, ErrorKey::Osekai).await;
 
                         return Err(err.wrap_err("failed to get cached rarity ranking"));
                     }
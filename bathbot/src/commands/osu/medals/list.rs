@@ -4,10 +4,7 @@ use std::{
 };
 
 use bathbot_model::{rkyv_util::time::DateTimeRkyv, OsekaiMedal, Rarity};
-use bathbot_util::{
-    constants::{GENERAL_ISSUE, OSEKAI_ISSUE, OSU_API_ISSUE},
-    IntHasher,
-};
+use bathbot_util::IntHasher;
 use eyre::{Report, Result};
 use hashbrown::HashMap;
 use rkyv::{with::DeserializeWith, Infallible};
@@ -18,7 +15,7 @@ use super::{MedalList, MedalListOrder};
 use crate::{
     active::{impls::MedalsListPagination, ActiveMessages},
     commands::osu::{require_link, user_not_found},
-    core::commands::CommandOrigin,
+    core::{commands::CommandOrigin, locale::ErrorKey},
     manager::redis::{osu::UserArgs, RedisData},
     Context,
 };
@@ -36,7 +33,7 @@ pub(super) async fn list(
             Ok(Some(user_id)) => UserId::Id(user_id),
             Ok(None) => return require_link(&ctx, &orig).await,
             Err(err) => {
-                let _ = orig.error(&ctx, GENERAL_ISSUE).await;
+                let _ = orig.error_localized(&ctx, ErrorKey::General).await;
 
                 return Err(err);
             }
@@ -64,13 +61,13 @@ pub(super) async fn list(
                 return orig.error(&ctx, content).await;
             }
             (Err(err), ..) => {
-                let _ = orig.error(&ctx, OSU_API_ISSUE).await;
+                let _ = orig.error_localized(&ctx, ErrorKey::OsuApi).await;
                 let report = Report::new(err).wrap_err("failed to get user");
 
                 return Err(report);
             }
             (_, Err(err), _) | (.., Err(err)) => {
-                let _ = orig.error(&ctx, OSEKAI_ISSUE).await;
+                let _ = orig.error_localized(&ctx, ErrorKey::Osekai).await;
 
                 return Err(err.wrap_err("failed to get cached rarity ranking"));
             }
@@ -8,7 +8,7 @@ use bathbot_macros::command;
 use bathbot_model::{MedalGroup, OsekaiComment, OsekaiMap, OsekaiMedal};
 use bathbot_psql::model::configs::HideSolutions;
 use bathbot_util::{
-    constants::{FIELD_VALUE_SIZE, OSEKAI_ISSUE, OSU_BASE},
+    constants::{FIELD_VALUE_SIZE, OSU_BASE},
     fields,
     osu::flag_url,
     string_cmp::levenshtein_similarity,
@@ -26,7 +26,7 @@ use twilight_model::{
 
 use super::{MedalAchieved, MedalInfo_};
 use crate::{
-    core::commands::CommandOrigin,
+    core::{commands::CommandOrigin, locale::ErrorKey},
     manager::redis::RedisData,
     util::{interaction::InteractionCommand, ChannelExt, InteractionCommandExt},
     Context,
@@ -69,7 +69,7 @@ pub(super) async fn info(
     let mut medals = match ctx.redis().medals().await {
         Ok(medals) => medals,
         Err(err) => {
-            let _ = orig.error(&ctx, OSEKAI_ISSUE).await;
+            let _ = orig.error_localized(&ctx, ErrorKey::Osekai).await;
 
             return Err(err.wrap_err("failed to get cached medals"));
         }
@@ -113,7 +113,7 @@ pub(super) async fn info(
     let (mut maps, comments) = match tokio::try_join!(map_fut, comment_fut) {
         Ok((maps, comments)) => (maps, comments),
         Err(err) => {
-            let _ = orig.error(&ctx, OSEKAI_ISSUE).await;
+            let _ = orig.error_localized(&ctx, ErrorKey::Osekai).await;
 
             return Err(err.wrap_err("failed to get osekai map or comments"));
         }
@@ -152,7 +152,7 @@ pub(super) async fn info(
 
 const SIMILARITY_THRESHOLD: f32 = 0.6;
 
-async fn no_medal(
+pub(super) async fn no_medal(
     ctx: &Context,
     orig: &CommandOrigin<'_>,
     name: &str,
@@ -13,6 +13,7 @@ pub use self::{
     list::*,
     medal::{handle_autocomplete as handle_medal_autocomplete, *},
     missing::*,
+    planner::*,
     recent::*,
     stats::*,
 };
@@ -25,6 +26,7 @@ mod common;
 mod list;
 mod medal;
 mod missing;
+mod planner;
 mod recent;
 
 pub mod stats;
@@ -46,6 +48,8 @@ pub enum Medal<'a> {
     List(MedalList<'a>),
     #[command(name = "missing")]
     Missing(MedalMissing<'a>),
+    #[command(name = "planner")]
+    Planner(MedalPlanner),
     #[command(name = "recent")]
     Recent(MedalRecent<'a>),
     #[command(name = "stats")]
@@ -62,6 +66,8 @@ enum Medal_<'a> {
     List(MedalList<'a>),
     #[command(name = "missing")]
     Missing(MedalMissing<'a>),
+    #[command(name = "planner")]
+    Planner(MedalPlanner_<'a>),
     #[command(name = "recent")]
     Recent(MedalRecent<'a>),
     #[command(name = "stats")]
@@ -232,6 +238,76 @@ impl Default for MedalMissingOrder {
     }
 }
 
+#[derive(CreateCommand)]
+#[command(
+    name = "planner",
+    desc = "Plan which medals to hunt next",
+    help = "Plan which medals to hunt next.\n\
+    Missing medals are grouped by their rarity so you can tackle the easier ones first. \
+    Medals can be marked as in progress to get beatmap suggestions \
+    or hidden to no longer show up in the planner.\n\
+    Beatmap suggestions are provided by [osekai](https://osekai.net/)."
+)]
+#[allow(dead_code)]
+pub enum MedalPlanner {
+    #[command(name = "view")]
+    View(MedalPlannerView),
+    #[command(name = "mark")]
+    Mark(MedalPlannerMark),
+}
+
+#[derive(CommandModel)]
+enum MedalPlanner_<'a> {
+    #[command(name = "view")]
+    View(MedalPlannerView),
+    #[command(name = "mark")]
+    Mark(MedalPlannerMark_<'a>),
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "view",
+    desc = "Display your missing medals grouped by rarity",
+    help = "Display the missing medals of your linked osu! account grouped by rarity.\n\
+    Medals that were acquired since the last time you checked will be highlighted."
+)]
+pub struct MedalPlannerView {
+    #[command(desc = "Whether hidden medals should be shown too")]
+    show_hidden: Option<bool>,
+}
+
+#[derive(CreateCommand)]
+#[command(name = "mark", desc = "Mark a medal as in progress or hidden")]
+#[allow(dead_code)]
+pub struct MedalPlannerMark {
+    #[command(
+        autocomplete = true,
+        desc = "Specify the name of a medal",
+        help = "Specify the name of a medal.\n\
+        Upper- and lowercase does not matter but punctuation is important."
+    )]
+    name: String,
+    #[command(desc = "Specify the status of the medal")]
+    status: MedalPlanOption,
+}
+
+#[derive(CommandModel)]
+#[command(autocomplete = true)]
+struct MedalPlannerMark_<'a> {
+    name: AutocompleteValue<Cow<'a, str>>,
+    status: Option<MedalPlanOption>,
+}
+
+#[derive(Copy, Clone, CommandOption, CreateOption)]
+pub enum MedalPlanOption {
+    #[option(name = "In progress", value = "in_progress")]
+    InProgress,
+    #[option(name = "Hidden", value = "hidden")]
+    Hidden,
+    #[option(name = "Clear", value = "clear")]
+    Clear,
+}
+
 #[derive(CommandModel, CreateCommand, Default, HasName)]
 #[command(
     name = "recent",
@@ -273,6 +349,12 @@ pub async fn slash_medal(ctx: Arc<Context>, mut command: InteractionCommand) ->
         Medal_::Info(args) => info(ctx, (&mut command).into(), args).await,
         Medal_::List(args) => list(ctx, (&mut command).into(), args).await,
         Medal_::Missing(args) => missing(ctx, (&mut command).into(), args).await,
+        Medal_::Planner(MedalPlanner_::View(args)) => {
+            planner_view(ctx, (&mut command).into(), args).await
+        }
+        Medal_::Planner(MedalPlanner_::Mark(args)) => {
+            planner_mark(ctx, (&mut command).into(), args).await
+        }
         Medal_::Recent(args) => recent(ctx, (&mut command).into(), args).await,
         Medal_::Stats(args) => stats(ctx, (&mut command).into(), args).await,
     }
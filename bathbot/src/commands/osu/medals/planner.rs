@@ -0,0 +1,376 @@
+use std::{
+    cmp::Reverse,
+    fmt::{Display, Formatter, Result as FmtResult},
+    sync::Arc,
+};
+
+use bathbot_model::{rkyv_util::time::DateTimeRkyv, OsekaiMap, OsekaiMedal};
+use bathbot_psql::model::osu::MedalPlanStatus;
+use bathbot_util::{CowUtils, IntHasher, MessageBuilder};
+use eyre::{Report, Result};
+use futures::future;
+use hashbrown::{HashMap, HashSet};
+use rkyv::{with::DeserializeWith, Deserialize, Infallible};
+use rosu_v2::{prelude::OsuError, request::UserId};
+use twilight_interactions::command::AutocompleteValue;
+
+use super::{
+    handle_medal_autocomplete, medal::no_medal, MedalPlanOption, MedalPlannerMark_,
+    MedalPlannerView,
+};
+use crate::{
+    active::{impls::MedalsPlannerPagination, ActiveMessages},
+    commands::osu::{require_link, user_not_found},
+    core::{commands::CommandOrigin, locale::ErrorKey},
+    manager::redis::{osu::UserArgs, RedisData},
+    Context,
+};
+
+/// Maximum amount of in-progress medals to fetch beatmap suggestions for
+const MAX_SUGGESTED_MEDALS: usize = 5;
+
+/// Maximum amount of suggested beatmaps per medal
+const MAX_SUGGESTED_MAPS: usize = 3;
+
+pub(super) async fn planner_view(
+    ctx: Arc<Context>,
+    orig: CommandOrigin<'_>,
+    args: MedalPlannerView,
+) -> Result<()> {
+    let owner = orig.user_id()?;
+
+    let osu_id = match ctx.user_config().osu_id(owner).await {
+        Ok(Some(user_id)) => user_id,
+        Ok(None) => return require_link(&ctx, &orig).await,
+        Err(err) => {
+            let _ = orig.error_localized(&ctx, ErrorKey::General).await;
+
+            return Err(err);
+        }
+    };
+
+    let user_id = UserId::Id(osu_id);
+    let user_args = UserArgs::rosu_id(&ctx, &user_id).await;
+    let user_fut = ctx.redis().osu_user(user_args);
+    let medals_fut = ctx.redis().medals();
+
+    let (user, all_medals) = match tokio::join!(user_fut, medals_fut) {
+        (Ok(user), Ok(medals)) => (user, medals),
+        (Err(OsuError::NotFound), _) => {
+            let content = user_not_found(&ctx, user_id).await;
+
+            return orig.error(&ctx, content).await;
+        }
+        (_, Err(err)) => {
+            let _ = orig.error_localized(&ctx, ErrorKey::Osekai).await;
+
+            return Err(err.wrap_err("failed to get cached medals"));
+        }
+        (Err(err), _) => {
+            let _ = orig.error_localized(&ctx, ErrorKey::OsuApi).await;
+            let report = Report::new(err).wrap_err("failed to get user");
+
+            return Err(report);
+        }
+    };
+
+    let owned: Vec<_> = match &user {
+        RedisData::Original(user) => user
+            .medals
+            .iter()
+            .map(|medal| (medal.medal_id, medal.achieved_at))
+            .collect(),
+        RedisData::Archive(user) => user
+            .medals
+            .iter()
+            .map(|medal| {
+                let achieved_at =
+                    DateTimeRkyv::deserialize_with(&medal.achieved_at, &mut Infallible).unwrap();
+
+                (medal.medal_id, achieved_at)
+            })
+            .collect(),
+    };
+
+    let progress = match ctx
+        .medal_plans()
+        .track_progress(owner, osu_id, &owned)
+        .await
+    {
+        Ok(progress) => progress,
+        Err(err) => {
+            let _ = orig.error_localized(&ctx, ErrorKey::General).await;
+
+            return Err(err);
+        }
+    };
+
+    // Fetching the plans only after tracking the progress so that plans of
+    // newly achieved medals are no longer included
+    let plans: HashMap<_, _, IntHasher> = match ctx.medal_plans().plans(owner).await {
+        Ok(plans) => plans
+            .into_iter()
+            .map(|plan| (plan.medal_id, plan.status))
+            .collect(),
+        Err(err) => {
+            let _ = orig.error_localized(&ctx, ErrorKey::General).await;
+
+            return Err(err);
+        }
+    };
+
+    let owned: HashSet<_, IntHasher> = owned.into_iter().map(|(medal_id, _)| medal_id).collect();
+
+    let all_medals: Vec<OsekaiMedal> = match all_medals {
+        RedisData::Original(all_medals) => all_medals,
+        RedisData::Archive(all_medals) => all_medals
+            .iter()
+            .map(|medal| medal.deserialize(&mut Infallible).unwrap())
+            .collect(),
+    };
+
+    let total_medals = all_medals.len();
+
+    let newly_achieved: Box<[Box<str>]> = progress
+        .newly_achieved
+        .iter()
+        .filter_map(|medal_id| all_medals.iter().find(|medal| medal.medal_id == *medal_id))
+        .map(|medal| medal.name.clone())
+        .collect();
+
+    let mut in_progress = Vec::new();
+    let mut hidden = Vec::new();
+    let mut rest = Vec::new();
+
+    for medal in all_medals {
+        if owned.contains(&medal.medal_id) {
+            continue;
+        }
+
+        match plans.get(&medal.medal_id) {
+            Some(MedalPlanStatus::InProgress) => in_progress.push(medal),
+            Some(MedalPlanStatus::Hidden) => hidden.push(medal),
+            None => rest.push(medal),
+        }
+    }
+
+    let missing_count = in_progress.len() + hidden.len() + rest.len();
+    let hidden_count = hidden.len();
+
+    // Rarer medals are generally more difficult to acquire
+    in_progress.sort_unstable_by(|a, b| b.rarity.total_cmp(&a.rarity));
+    hidden.sort_unstable_by(|a, b| b.rarity.total_cmp(&a.rarity));
+    rest.sort_unstable_by(|a, b| b.rarity.total_cmp(&a.rarity));
+
+    let map_futs = in_progress
+        .iter()
+        .take(MAX_SUGGESTED_MEDALS)
+        .map(|medal| suggested_maps(&ctx, medal));
+
+    let mut suggestions = future::join_all(map_futs).await.into_iter();
+
+    let mut entries = Vec::with_capacity(missing_count + 8);
+
+    if !in_progress.is_empty() {
+        entries.push(MedalPlannerEntry::Section(MedalPlannerSection::InProgress));
+
+        entries.extend(
+            in_progress
+                .into_iter()
+                .map(|medal| MedalPlannerEntry::Medal {
+                    medal,
+                    maps: suggestions.next().unwrap_or_default(),
+                }),
+        );
+    }
+
+    let mut curr_section = None;
+
+    for medal in rest {
+        let section = MedalPlannerSection::from_rarity(medal.rarity);
+
+        if curr_section != Some(section) {
+            entries.push(MedalPlannerEntry::Section(section));
+            curr_section = Some(section);
+        }
+
+        entries.push(MedalPlannerEntry::Medal {
+            medal,
+            maps: Box::default(),
+        });
+    }
+
+    if args.show_hidden == Some(true) && !hidden.is_empty() {
+        entries.push(MedalPlannerEntry::Section(MedalPlannerSection::Hidden));
+
+        entries.extend(hidden.into_iter().map(|medal| MedalPlannerEntry::Medal {
+            medal,
+            maps: Box::default(),
+        }));
+    }
+
+    let pagination = MedalsPlannerPagination::builder()
+        .user(user)
+        .entries(entries.into_boxed_slice())
+        .newly_achieved(newly_achieved)
+        .medal_count((missing_count, total_medals))
+        .hidden_count(hidden_count)
+        .tracked_since(progress.tracked_since)
+        .achieved_since(progress.achieved_since_tracking)
+        .msg_owner(owner)
+        .build();
+
+    ActiveMessages::builder(pagination)
+        .start_by_update(true)
+        .begin(ctx, orig)
+        .await
+}
+
+pub(super) async fn planner_mark(
+    ctx: Arc<Context>,
+    orig: CommandOrigin<'_>,
+    args: MedalPlannerMark_<'_>,
+) -> Result<()> {
+    let MedalPlannerMark_ { name, status } = args;
+
+    let name = match (name, &orig) {
+        (AutocompleteValue::None, CommandOrigin::Interaction { command }) => {
+            return handle_medal_autocomplete(&ctx, command, String::new()).await
+        }
+        (AutocompleteValue::Focused(name), CommandOrigin::Interaction { command }) => {
+            return handle_medal_autocomplete(&ctx, command, name).await
+        }
+        (AutocompleteValue::Completed(name), _) => name,
+        _ => unreachable!(),
+    };
+
+    let owner = orig.user_id()?;
+
+    let medals = match ctx.redis().medals().await {
+        Ok(medals) => medals,
+        Err(err) => {
+            let _ = orig.error_localized(&ctx, ErrorKey::Osekai).await;
+
+            return Err(err.wrap_err("failed to get cached medals"));
+        }
+    };
+
+    let name = name.cow_to_ascii_lowercase();
+
+    let medal = match medals {
+        RedisData::Original(ref original) => original
+            .iter()
+            .find(|m| m.name.to_ascii_lowercase() == name)
+            .map(|m| (m.medal_id, m.name.clone())),
+        RedisData::Archive(ref archived) => archived
+            .iter()
+            .find(|m| m.name.to_ascii_lowercase() == name)
+            .map(|m| (m.medal_id, Box::from(&*m.name))),
+    };
+
+    let Some((medal_id, medal_name)) = medal else {
+        return no_medal(&ctx, &orig, name.as_ref(), medals).await;
+    };
+
+    let status = match status.unwrap_or(MedalPlanOption::InProgress) {
+        MedalPlanOption::InProgress => Some(MedalPlanStatus::InProgress),
+        MedalPlanOption::Hidden => Some(MedalPlanStatus::Hidden),
+        MedalPlanOption::Clear => None,
+    };
+
+    let set_fut = ctx.medal_plans().set_status(owner, medal_id, status);
+
+    let content = match (set_fut.await, status) {
+        (Ok(_), Some(MedalPlanStatus::InProgress)) => {
+            format!("The medal `{medal_name}` is now marked as in progress")
+        }
+        (Ok(_), Some(MedalPlanStatus::Hidden)) => {
+            format!("The medal `{medal_name}` will no longer show up in your planner")
+        }
+        (Ok(true), None) => format!("Cleared the status of the medal `{medal_name}`"),
+        (Ok(false), None) => format!("The medal `{medal_name}` did not have a status"),
+        (Err(err), _) => {
+            let _ = orig.error_localized(&ctx, ErrorKey::General).await;
+
+            return Err(err);
+        }
+    };
+
+    let builder = MessageBuilder::new().embed(content);
+    orig.create_message(&ctx, builder).await?;
+
+    Ok(())
+}
+
+async fn suggested_maps(ctx: &Context, medal: &OsekaiMedal) -> Box<[OsekaiMap]> {
+    let mut maps = match ctx.client().get_osekai_beatmaps(&medal.name).await {
+        Ok(maps) => maps,
+        Err(err) => {
+            warn!(
+                ?err,
+                medal = medal.name.as_ref(),
+                "Failed to get osekai maps"
+            );
+
+            return Box::default();
+        }
+    };
+
+    // Remove all dups
+    maps.sort_unstable_by_key(|map| Reverse(map.map_id));
+    maps.dedup_by_key(|map| map.map_id);
+
+    maps.sort_unstable_by_key(|map| Reverse(map.vote_sum));
+    maps.truncate(MAX_SUGGESTED_MAPS);
+
+    maps.into_boxed_slice()
+}
+
+pub enum MedalPlannerEntry {
+    Section(MedalPlannerSection),
+    Medal {
+        medal: OsekaiMedal,
+        maps: Box<[OsekaiMap]>,
+    },
+}
+
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub enum MedalPlannerSection {
+    InProgress,
+    Common,
+    Uncommon,
+    Rare,
+    VeryRare,
+    Legendary,
+    Hidden,
+}
+
+impl MedalPlannerSection {
+    fn from_rarity(rarity: f32) -> Self {
+        if rarity >= 50.0 {
+            Self::Common
+        } else if rarity >= 20.0 {
+            Self::Uncommon
+        } else if rarity >= 5.0 {
+            Self::Rare
+        } else if rarity >= 1.0 {
+            Self::VeryRare
+        } else {
+            Self::Legendary
+        }
+    }
+}
+
+impl Display for MedalPlannerSection {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::InProgress => f.write_str("In progress"),
+            Self::Common => f.write_str("Common (≥ 50%)"),
+            Self::Uncommon => f.write_str("Uncommon (20-50%)"),
+            Self::Rare => f.write_str("Rare (5-20%)"),
+            Self::VeryRare => f.write_str("Very rare (1-5%)"),
+            Self::Legendary => f.write_str("Legendary (< 1%)"),
+            Self::Hidden => f.write_str("Hidden"),
+        }
+    }
+}
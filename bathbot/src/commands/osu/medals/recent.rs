@@ -3,10 +3,7 @@ use std::{cmp::Reverse, collections::HashMap, mem, sync::Arc};
 use bathbot_macros::command;
 use bathbot_model::rosu_v2::user::{MedalCompact as MedalCompactRkyv, User};
 use bathbot_psql::model::configs::HideSolutions;
-use bathbot_util::{
-    constants::{GENERAL_ISSUE, OSEKAI_ISSUE, OSU_API_ISSUE},
-    matcher, IntHasher, MessageBuilder,
-};
+use bathbot_util::{matcher, IntHasher, MessageBuilder};
 use eyre::{Report, Result};
 use rand::{thread_rng, Rng};
 use rkyv::{
@@ -23,7 +20,7 @@ use super::{MedalEmbed, MedalRecent};
 use crate::{
     active::{impls::MedalsRecentPagination, ActiveMessages},
     commands::osu::{require_link, user_not_found},
-    core::commands::CommandOrigin,
+    core::{commands::CommandOrigin, locale::ErrorKey},
     manager::redis::{osu::UserArgs, RedisData},
     Context,
 };
@@ -68,7 +65,7 @@ pub(super) async fn recent(
             Ok(Some(user_id)) => UserId::Id(user_id),
             Ok(None) => return require_link(&ctx, &orig).await,
             Err(err) => {
-                let _ = orig.error(&ctx, GENERAL_ISSUE).await;
+                let _ = orig.error_localized(&ctx, ErrorKey::General).await;
 
                 return Err(err);
             }
@@ -87,13 +84,13 @@ pub(super) async fn recent(
             return orig.error(&ctx, content).await;
         }
         (Err(err), _) => {
-            let _ = orig.error(&ctx, OSU_API_ISSUE).await;
+            let _ = orig.error_localized(&ctx, ErrorKey::OsuApi).await;
             let report = Report::new(err).wrap_err("failed to get user");
 
             return Err(report);
         }
         (_, Err(err)) => {
-            let _ = orig.error(&ctx, OSEKAI_ISSUE).await;
+            let _ = orig.error_localized(&ctx, ErrorKey::Osekai).await;
 
             return Err(err.wrap_err("failed to get cached medals"));
         }
@@ -156,7 +153,7 @@ pub(super) async fn recent(
     let medal = match all_medals.iter().position(|m| m.medal_id == medal_id) {
         Some(idx) => &all_medals[idx],
         None => {
-            let _ = orig.error(&ctx, GENERAL_ISSUE).await;
+            let _ = orig.error_localized(&ctx, ErrorKey::General).await;
 
             bail!("No medal with id `{medal_id}`");
         }
@@ -12,8 +12,8 @@ macro_rules! user_id {
                     return $orig.error(&$ctx, content).await;
                 }
                 crate::commands::osu::UserIdFutureResult::Err(err) => {
-                    let content = bathbot_util::constants::GENERAL_ISSUE;
-                    let _ = $orig.error(&$ctx, content).await;
+                    let key = crate::core::locale::ErrorKey::General;
+                    let _ = $orig.error_localized(&$ctx, key).await;
 
                     return Err(err);
                 }
@@ -73,9 +73,10 @@ pub use self::link::*;
 #[cfg(feature = "matchlive")]
 pub use self::match_live::*;
 pub use self::{
-    badges::*, claim_name::*, compare::*, fix::*, graphs::*, leaderboard::*, map::*, map_search::*,
-    match_compare::*, match_costs::*, medals::*, nochoke::*, osustats::*, popular::*, profile::*,
-    recent::*, region_top::*, render::*, scores::*, simulate::*, snipe::*, top::*, whatif::*,
+    badges::*, bws::*, claim_name::*, compare::*, fix::*, graphs::*, leaderboard::*, map::*,
+    map_search::*, mappool::*, match_compare::*, match_costs::*, medals::*, nochoke::*,
+    osustats::*, popular::*, profile::*, recent::*, region_top::*, render::*, scores::*,
+    simulate::*, snipe::*, top::*, tournament::*, whatif::*,
 };
 use crate::{
     core::commands::{interaction::InteractionCommands, CommandOrigin},
@@ -97,6 +98,7 @@ mod link;
 mod map;
 mod map_search;
 mod mapper;
+mod mappool;
 mod match_compare;
 mod match_costs;
 mod match_live;
@@ -120,6 +122,7 @@ mod serverleaderboard;
 mod simulate;
 mod snipe;
 mod top;
+mod tournament;
 mod whatif;
 
 pub trait HasMods {
@@ -2,11 +2,7 @@ use std::{borrow::Cow, cmp::Ordering, sync::Arc};
 
 use bathbot_macros::{command, HasName, SlashCommand};
 use bathbot_model::ScoreSlim;
-use bathbot_util::{
-    constants::{GENERAL_ISSUE, OSU_API_ISSUE},
-    matcher,
-    osu::calculate_grade,
-};
+use bathbot_util::{matcher, osu::calculate_grade};
 use eyre::{Report, Result};
 use rosu_pp::{BeatmapExt, DifficultyAttributes, GameMode as Mode};
 use rosu_v2::prelude::{GameMode, GameMods, Grade, OsuError, Score, ScoreStatistics};
@@ -16,7 +12,10 @@ use twilight_model::id::{marker::UserMarker, Id};
 use super::user_not_found;
 use crate::{
     active::{impls::NoChokePagination, ActiveMessages},
-    core::commands::{prefix::Args, CommandOrigin},
+    core::{
+        commands::{prefix::Args, CommandOrigin},
+        locale::ErrorKey,
+    },
     manager::{redis::osu::UserArgs, OsuMap},
     util::{interaction::InteractionCommand, osu::IfFc, InteractionCommandExt},
     Context,
@@ -216,7 +215,7 @@ async fn nochoke(ctx: Arc<Context>, orig: CommandOrigin<'_>, args: Nochoke<'_>)
             return orig.error(&ctx, content).await;
         }
         Err(err) => {
-            let _ = orig.error(&ctx, OSU_API_ISSUE).await;
+            let _ = orig.error_localized(&ctx, ErrorKey::OsuApi).await;
             let err = Report::new(err).wrap_err("failed to get user or scores");
 
             return Err(err);
@@ -228,7 +227,7 @@ async fn nochoke(ctx: Arc<Context>, orig: CommandOrigin<'_>, args: Nochoke<'_>)
     let mut entries = match process_scores(&ctx, scores, miss_limit, version).await {
         Ok(entries) => entries,
         Err(err) => {
-            let _ = orig.error(&ctx, GENERAL_ISSUE).await;
+            let _ = orig.error_localized(&ctx, ErrorKey::General).await;
 
             return Err(err.wrap_err("failed to process scores"));
         }
@@ -1,12 +1,12 @@
 use std::{borrow::Cow, sync::Arc};
 
 use bathbot_model::{Countries, MedalCount};
-use bathbot_util::constants::OSEKAI_ISSUE;
 use eyre::Result;
 
 use super::OsekaiMedalCount;
 use crate::{
     active::{impls::MedalCountPagination, ActiveMessages},
+    core::locale::ErrorKey,
     util::{interaction::InteractionCommand, Authored, InteractionCommandExt},
     Context,
 };
@@ -43,7 +43,7 @@ pub(super) async fn medal_count(
     let mut ranking = match osekai_res {
         Ok(ranking) => ranking.into_original(),
         Err(err) => {
-            let _ = command.error(&ctx, OSEKAI_ISSUE).await;
+            let _ = command.error_localized(&ctx, ErrorKey::Osekai).await;
 
             return Err(err.wrap_err("failed to get cached medal count ranking"));
         }
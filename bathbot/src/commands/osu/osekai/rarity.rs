@@ -1,11 +1,11 @@
 use std::sync::Arc;
 
 use bathbot_model::Rarity;
-use bathbot_util::constants::OSEKAI_ISSUE;
 use eyre::Result;
 
 use crate::{
     active::{impls::MedalRarityPagination, ActiveMessages},
+    core::locale::ErrorKey,
     util::{interaction::InteractionCommand, Authored, InteractionCommandExt},
     Context,
 };
@@ -14,7 +14,7 @@ pub(super) async fn rarity(ctx: Arc<Context>, mut command: InteractionCommand) -
     let ranking = match ctx.redis().osekai_ranking::<Rarity>().await {
         Ok(ranking) => ranking.into_original(),
         Err(err) => {
-            let _ = command.error(&ctx, OSEKAI_ISSUE).await;
+            let _ = command.error_localized(&ctx, ErrorKey::Osekai).await;
 
             return Err(err.wrap_err("failed to get cached rarity ranking"));
         }
@@ -4,12 +4,12 @@ use bathbot_model::{
     ArchivedOsekaiRankingEntry, Countries, OsekaiRanking, OsekaiRankingEntry, RankingEntries,
     RankingEntry, RankingKind,
 };
-use bathbot_util::constants::OSEKAI_ISSUE;
 use eyre::Result;
 use rosu_v2::prelude::Username;
 
 use crate::{
     active::{impls::RankingPagination, ActiveMessages},
+    core::locale::ErrorKey,
     manager::redis::RedisData,
     util::{interaction::InteractionCommand, Authored, InteractionCommandExt},
     Context,
@@ -50,7 +50,7 @@ where
     let ranking = match osekai_res {
         Ok(ranking) => ranking,
         Err(err) => {
-            let _ = command.error(&ctx, OSEKAI_ISSUE).await;
+            let _ = command.error_localized(&ctx, ErrorKey::Osekai).await;
 
             return Err(err.wrap_err("failed to get cached osekai ranking"));
         }
@@ -108,7 +108,7 @@ where
     let ranking = match osekai_res {
         Ok(ranking) => ranking,
         Err(err) => {
-            let _ = command.error(&ctx, OSEKAI_ISSUE).await;
+            let _ = command.error_localized(&ctx, ErrorKey::Osekai).await;
 
             return Err(err.wrap_err("failed to get cached osekai ranking"));
         }
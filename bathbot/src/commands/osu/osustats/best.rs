@@ -1,13 +1,12 @@
 use std::sync::Arc;
 
-use bathbot_util::constants::OSUSTATS_API_ISSUE;
 use eyre::Result;
 use rosu_v2::prelude::GameMode;
 
 use super::{OsuStatsBest, OsuStatsBestSort};
 use crate::{
     active::{impls::OsuStatsBestPagination, ActiveMessages},
-    core::{commands::CommandOrigin, Context},
+    core::{commands::CommandOrigin, locale::ErrorKey, Context},
 };
 
 pub(super) async fn recentbest(
@@ -21,7 +20,7 @@ pub(super) async fn recentbest(
     let mut scores = match scores_fut.await {
         Ok(scores) => scores.into_original(),
         Err(err) => {
-            let _ = orig.error(&ctx, OSUSTATS_API_ISSUE).await;
+            let _ = orig.error_localized(&ctx, ErrorKey::Osustats).await;
 
             return Err(err);
         }
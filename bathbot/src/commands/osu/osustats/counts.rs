@@ -1,10 +1,7 @@
 use std::{borrow::Cow, sync::Arc};
 
 use bathbot_macros::{command, HasName, SlashCommand};
-use bathbot_util::{
-    constants::{OSUSTATS_API_ISSUE, OSU_API_ISSUE},
-    matcher, MessageBuilder,
-};
+use bathbot_util::{matcher, MessageBuilder};
 use eyre::{Report, Result};
 use rosu_v2::prelude::OsuError;
 use twilight_interactions::command::{CommandModel, CreateCommand};
@@ -13,7 +10,10 @@ use twilight_model::id::{marker::UserMarker, Id};
 use super::OsuStatsCount;
 use crate::{
     commands::{osu::user_not_found, GameModeOption},
-    core::commands::{prefix::Args, CommandOrigin},
+    core::{
+        commands::{prefix::Args, CommandOrigin},
+        locale::ErrorKey,
+    },
     embeds::{EmbedData, OsuStatsCountsEmbed},
     manager::redis::osu::UserArgs,
     util::{interaction::InteractionCommand, osu::TopCounts, InteractionCommandExt},
@@ -166,7 +166,7 @@ pub(super) async fn count(
             return orig.error(&ctx, content).await;
         }
         Err(err) => {
-            let _ = orig.error(&ctx, OSU_API_ISSUE).await;
+            let _ = orig.error_localized(&ctx, ErrorKey::OsuApi).await;
             let err = Report::new(err).wrap_err("failed to get user");
 
             return Err(err);
@@ -176,7 +176,7 @@ pub(super) async fn count(
     let counts = match TopCounts::request(&ctx, &user, mode).await {
         Ok(counts) => counts,
         Err(err) => {
-            let _ = orig.error(&ctx, OSUSTATS_API_ISSUE).await;
+            let _ = orig.error_localized(&ctx, ErrorKey::Osustats).await;
 
             return Err(err.wrap_err("failed to get top counts"));
         }
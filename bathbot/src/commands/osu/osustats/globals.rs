@@ -4,12 +4,7 @@ use bathbot_macros::command;
 use bathbot_model::{
     OsuStatsParams, OsuStatsScore, OsuStatsScoresOrder, OsuStatsScoresRaw, ScoreSlim,
 };
-use bathbot_util::{
-    constants::{GENERAL_ISSUE, OSUSTATS_API_ISSUE, OSU_API_ISSUE},
-    matcher,
-    osu::ModSelection,
-    CowUtils,
-};
+use bathbot_util::{matcher, osu::ModSelection, CowUtils};
 use eyre::{Report, Result};
 use rosu_v2::prelude::{GameModIntermode, GameMode, Grade, OsuError, ScoreStatistics, Username};
 
@@ -20,7 +15,10 @@ use crate::{
         osu::{user_not_found, HasMods, ModsResult},
         GameModeOption,
     },
-    core::commands::{prefix::Args, CommandOrigin},
+    core::{
+        commands::{prefix::Args, CommandOrigin},
+        locale::ErrorKey,
+    },
     manager::{redis::osu::UserArgs, OsuMap},
     util::ChannelExt,
     Context,
@@ -193,7 +191,7 @@ pub(super) async fn scores(
             return orig.error(&ctx, content).await;
         }
         Err(err) => {
-            let _ = orig.error(&ctx, OSU_API_ISSUE).await;
+            let _ = orig.error_localized(&ctx, ErrorKey::OsuApi).await;
             let report = Report::new(err).wrap_err("failed to get user");
 
             return Err(report);
@@ -207,7 +205,7 @@ pub(super) async fn scores(
     let (scores, amount) = match scores_fut.await.map(OsuStatsScoresRaw::into_scores) {
         Ok(Ok(scores)) => (scores.scores, scores.count),
         Err(err) | Ok(Err(err)) => {
-            let _ = orig.error(&ctx, OSUSTATS_API_ISSUE).await;
+            let _ = orig.error_localized(&ctx, ErrorKey::Osustats).await;
 
             return Err(err.wrap_err("Failed to get global scores"));
         }
@@ -216,7 +214,7 @@ pub(super) async fn scores(
     let entries = match process_scores(&ctx, scores, mode).await {
         Ok(entries) => entries,
         Err(err) => {
-            let _ = orig.error(&ctx, GENERAL_ISSUE).await;
+            let _ = orig.error_localized(&ctx, ErrorKey::General).await;
 
             return Err(err.wrap_err("Failed to process scores"));
         }
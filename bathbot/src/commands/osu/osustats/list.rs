@@ -2,10 +2,7 @@ use std::{borrow::Cow, collections::HashMap, sync::Arc};
 
 use bathbot_macros::command;
 use bathbot_model::{Countries, OsuStatsPlayer, OsuStatsPlayersArgs};
-use bathbot_util::{
-    constants::{GENERAL_ISSUE, OSUSTATS_API_ISSUE},
-    CowUtils, IntHasher,
-};
+use bathbot_util::{CowUtils, IntHasher};
 use eyre::Result;
 use rosu_v2::{model::GameMode, prelude::CountryCode};
 
@@ -13,7 +10,10 @@ use super::OsuStatsPlayers;
 use crate::{
     active::{impls::OsuStatsPlayersPagination, ActiveMessages},
     commands::GameModeOption,
-    core::commands::{prefix::Args, CommandOrigin},
+    core::{
+        commands::{prefix::Args, CommandOrigin},
+        locale::ErrorKey,
+    },
     util::ChannelExt,
     Context,
 };
@@ -41,7 +41,7 @@ pub(super) async fn players(
         args.mode = match ctx.user_config().mode(owner).await {
             Ok(mode) => mode.map(GameModeOption::from),
             Err(err) => {
-                let _ = orig.error(&ctx, GENERAL_ISSUE).await;
+                let _ = orig.error_localized(&ctx, ErrorKey::General).await;
 
                 return Err(err);
             }
@@ -71,7 +71,7 @@ pub(super) async fn players(
     let (amount, players) = match prepare_players(&ctx, &mut params).await {
         Ok(tuple) => tuple,
         Err(err) => {
-            let _ = orig.error(&ctx, OSUSTATS_API_ISSUE).await;
+            let _ = orig.error_localized(&ctx, ErrorKey::Osustats).await;
 
             return Err(err.wrap_err("failed to prepare players"));
         }
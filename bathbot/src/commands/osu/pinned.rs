@@ -8,11 +8,7 @@ use std::{
 use bathbot_macros::{HasMods, HasName, SlashCommand};
 use bathbot_model::ScoreSlim;
 use bathbot_psql::model::configs::{GuildConfig, ListSize, MinimizedPp, ScoreSize};
-use bathbot_util::{
-    constants::{GENERAL_ISSUE, OSU_API_ISSUE},
-    osu::ModSelection,
-    IntHasher,
-};
+use bathbot_util::{osu::ModSelection, IntHasher};
 use eyre::{Report, Result};
 use rand::{thread_rng, Rng};
 use rosu_v2::{
@@ -36,7 +32,7 @@ use crate::{
         ActiveMessages,
     },
     commands::GameModeOption,
-    core::commands::CommandOrigin,
+    core::{commands::CommandOrigin, locale::ErrorKey},
     manager::{
         redis::{
             osu::{UserArgs, UserArgsSlim},
@@ -124,7 +120,7 @@ async fn pinned(ctx: Arc<Context>, orig: CommandOrigin<'_>, args: Pinned) -> Res
     let mut config = match ctx.user_config().with_osu_id(msg_owner).await {
         Ok(config) => config,
         Err(err) => {
-            let _ = orig.error(&ctx, GENERAL_ISSUE).await;
+            let _ = orig.error_localized(&ctx, ErrorKey::General).await;
 
             return Err(err);
         }
@@ -178,7 +174,7 @@ async fn pinned(ctx: Arc<Context>, orig: CommandOrigin<'_>, args: Pinned) -> Res
             return orig.error(&ctx, content).await;
         }
         UserArgs::Err(err) => {
-            let _ = orig.error(&ctx, OSU_API_ISSUE).await;
+            let _ = orig.error_localized(&ctx, ErrorKey::OsuApi).await;
             let err = Report::new(err).wrap_err("Failed to get user");
 
             return Err(err);
@@ -215,7 +211,7 @@ async fn pinned(ctx: Arc<Context>, orig: CommandOrigin<'_>, args: Pinned) -> Res
             return orig.error(&ctx, content).await;
         }
         Err(err) => {
-            let _ = orig.error(&ctx, OSU_API_ISSUE).await;
+            let _ = orig.error_localized(&ctx, ErrorKey::OsuApi).await;
             let err = Report::new(err).wrap_err("Failed to get user or prepare scores");
 
             return Err(err);
@@ -228,7 +224,7 @@ async fn pinned(ctx: Arc<Context>, orig: CommandOrigin<'_>, args: Pinned) -> Res
         match process_scores(&ctx, pinned, &args, mods.as_ref(), &top100, size_single).await {
             Ok(entries) => entries,
             Err(err) => {
-                let _ = orig.error(&ctx, GENERAL_ISSUE).await;
+                let _ = orig.error_localized(&ctx, ErrorKey::General).await;
 
                 return Err(err.wrap_err("Failed to process scores"));
             }
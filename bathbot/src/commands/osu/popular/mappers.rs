@@ -1,13 +1,12 @@
 use std::{alloc, mem, sync::Arc};
 
 use bathbot_model::OsuTrackerMapperEntry;
-use bathbot_util::constants::OSUTRACKER_ISSUE;
 use eyre::Result;
 use rkyv::{DeserializeUnsized, Infallible};
 
 use crate::{
     active::{impls::PopularMappersPagination, ActiveMessages},
-    core::Context,
+    core::{locale::ErrorKey, Context},
     manager::redis::RedisData,
     util::{interaction::InteractionCommand, Authored, InteractionCommandExt},
 };
@@ -46,7 +45,7 @@ pub(super) async fn mappers(ctx: Arc<Context>, mut command: InteractionCommand)
             }
         }
         Err(err) => {
-            let _ = command.error(&ctx, OSUTRACKER_ISSUE).await;
+            let _ = command.error_localized(&ctx, ErrorKey::Osutracker).await;
 
             return Err(err.wrap_err("failed to get cached osutracker stats"));
         }
@@ -1,14 +1,13 @@
 use std::sync::Arc;
 
 use bathbot_model::OsuTrackerPpEntry;
-use bathbot_util::constants::OSUTRACKER_ISSUE;
 use eyre::Result;
 use rkyv::{Deserialize, Infallible};
 
 use super::PopularMapsPp;
 use crate::{
     active::{impls::PopularMapsPagination, ActiveMessages},
-    core::Context,
+    core::{locale::ErrorKey, Context},
     manager::redis::RedisData,
     util::{interaction::InteractionCommand, Authored, InteractionCommandExt},
 };
@@ -24,7 +23,7 @@ pub(super) async fn maps(
         Ok(RedisData::Original(group)) => group.list,
         Ok(RedisData::Archive(group)) => group.list.deserialize(&mut Infallible).unwrap(),
         Err(err) => {
-            let _ = command.error(&ctx, OSUTRACKER_ISSUE).await;
+            let _ = command.error_localized(&ctx, ErrorKey::Osutracker).await;
 
             return Err(err.wrap_err("failed to get cached osutracker pp groups"));
         }
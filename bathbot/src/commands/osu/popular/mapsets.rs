@@ -1,10 +1,7 @@
 use std::{alloc, collections::HashMap, mem, sync::Arc};
 
 use bathbot_model::OsuTrackerMapsetEntry;
-use bathbot_util::{
-    constants::{GENERAL_ISSUE, OSUTRACKER_ISSUE},
-    IntHasher,
-};
+use bathbot_util::IntHasher;
 use eyre::{Report, Result};
 use rkyv::{DeserializeUnsized, Infallible};
 use rosu_v2::prelude::Username;
@@ -12,7 +9,7 @@ use time::OffsetDateTime;
 
 use crate::{
     active::{impls::PopularMapsetsPagination, ActiveMessages},
-    core::Context,
+    core::{locale::ErrorKey, Context},
     manager::redis::RedisData,
     util::{interaction::InteractionCommand, Authored, InteractionCommandExt},
 };
@@ -50,7 +47,7 @@ pub(super) async fn mapsets(ctx: Arc<Context>, mut command: InteractionCommand)
             }
         }
         Err(err) => {
-            let _ = command.error(&ctx, OSUTRACKER_ISSUE).await;
+            let _ = command.error_localized(&ctx, ErrorKey::Osutracker).await;
 
             return Err(err.wrap_err("failed to get cached osutracker stats"));
         }
@@ -64,7 +61,7 @@ pub(super) async fn mapsets(ctx: Arc<Context>, mut command: InteractionCommand)
         let mapset = match ctx.osu_map().mapset(mapset_id).await {
             Ok(mapset) => mapset,
             Err(err) => {
-                let _ = command.error(&ctx, GENERAL_ISSUE).await;
+                let _ = command.error_localized(&ctx, ErrorKey::General).await;
 
                 return Err(Report::new(err));
             }
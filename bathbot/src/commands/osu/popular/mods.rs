@@ -1,12 +1,11 @@
 use std::sync::Arc;
 
-use bathbot_util::constants::OSUTRACKER_ISSUE;
 use eyre::Result;
 use rkyv::{Deserialize, Infallible};
 
 use crate::{
     active::{impls::PopularModsPagination, ActiveMessages},
-    core::Context,
+    core::{locale::ErrorKey, Context},
     manager::redis::RedisData,
     util::{interaction::InteractionCommand, Authored, InteractionCommandExt},
 };
@@ -18,7 +17,7 @@ pub(super) async fn mods(ctx: Arc<Context>, mut command: InteractionCommand) ->
             stats.user.mods_count.deserialize(&mut Infallible).unwrap()
         }
         Err(err) => {
-            let _ = command.error(&ctx, OSUTRACKER_ISSUE).await;
+            let _ = command.error_localized(&ctx, ErrorKey::Osutracker).await;
 
             return Err(err.wrap_err("Failed to get cached osutracker stats"));
         }
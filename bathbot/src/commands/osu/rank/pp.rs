@@ -9,7 +9,6 @@ use std::{
 use bathbot_macros::command;
 use bathbot_model::{rosu_v2::user::User, Countries};
 use bathbot_util::{
-    constants::{GENERAL_ISSUE, OSU_API_ISSUE},
     matcher,
     numbers::WithComma,
     osu::{approx_more_pp, pp_missing, ExtractablePp, PpListUtil},
@@ -21,7 +20,10 @@ use rosu_v2::prelude::{CountryCode, OsuError, Score, UserId, Username};
 use super::{RankPp, RankValue};
 use crate::{
     commands::{osu::user_not_found, GameModeOption},
-    core::commands::{prefix::Args, CommandOrigin},
+    core::{
+        commands::{prefix::Args, CommandOrigin},
+        locale::ErrorKey,
+    },
     manager::redis::{osu::UserArgs, RedisData},
     util::ChannelExt,
     Context,
@@ -74,7 +76,7 @@ pub(super) async fn pp(ctx: Arc<Context>, orig: CommandOrigin<'_>, args: RankPp<
             return orig.error(&ctx, content).await;
         }
         Err(err) => {
-            let _ = orig.error(&ctx, OSU_API_ISSUE).await;
+            let _ = orig.error_localized(&ctx, ErrorKey::OsuApi).await;
 
             return Err(Report::new(err).wrap_err("Failed to get user"));
         }
@@ -108,7 +110,7 @@ pub(super) async fn pp(ctx: Arc<Context>, orig: CommandOrigin<'_>, args: RankPp<
                     return orig.error(&ctx, content).await;
                 }
                 Err(err) => {
-                    let _ = orig.error(&ctx, OSU_API_ISSUE).await;
+                    let _ = orig.error_localized(&ctx, ErrorKey::OsuApi).await;
 
                     return Err(Report::new(err).wrap_err("Failed to get target user"));
                 }
@@ -141,7 +143,7 @@ pub(super) async fn pp(ctx: Arc<Context>, orig: CommandOrigin<'_>, args: RankPp<
                     return orig.error(&ctx, content).await;
                 }
                 Err(err) => {
-                    let _ = orig.error(&ctx, OSU_API_ISSUE).await;
+                    let _ = orig.error_localized(&ctx, ErrorKey::OsuApi).await;
 
                     return Err(Report::new(err).wrap_err("Failed to get user"));
                 }
@@ -192,7 +194,7 @@ pub(super) async fn pp(ctx: Arc<Context>, orig: CommandOrigin<'_>, args: RankPp<
             let required_pp = match ctx.approx().pp(rank, mode).await {
                 Ok(pp) => pp,
                 Err(err) => {
-                    let _ = orig.error(&ctx, GENERAL_ISSUE).await;
+                    let _ = orig.error_localized(&ctx, ErrorKey::General).await;
 
                     return Err(err);
                 }
@@ -224,7 +226,7 @@ pub(super) async fn pp(ctx: Arc<Context>, orig: CommandOrigin<'_>, args: RankPp<
         match scores_fut.await {
             Ok(scores) => (!scores.is_empty()).then_some(scores),
             Err(err) => {
-                let _ = orig.error(&ctx, OSU_API_ISSUE).await;
+                let _ = orig.error_localized(&ctx, ErrorKey::OsuApi).await;
                 let err = Report::new(err).wrap_err("Failed to get scores");
 
                 return Err(err);
@@ -3,11 +3,8 @@ use std::{borrow::Cow, cmp, fmt::Write, iter, sync::Arc};
 use bathbot_macros::command;
 use bathbot_model::{rosu_v2::user::User, RespektiveUser};
 use bathbot_util::{
-    constants::{GENERAL_ISSUE, OSU_API_ISSUE, OSU_BASE},
-    matcher,
-    numbers::WithComma,
-    osu::flag_url,
-    AuthorBuilder, CowUtils, EmbedBuilder, MessageBuilder,
+    constants::OSU_BASE, matcher, numbers::WithComma, osu::flag_url, AuthorBuilder, CowUtils,
+    EmbedBuilder, MessageBuilder,
 };
 use eyre::{Report, Result};
 use rosu_v2::prelude::{OsuError, UserId, Username};
@@ -15,7 +12,10 @@ use rosu_v2::prelude::{OsuError, UserId, Username};
 use super::{RankScore, RankValue};
 use crate::{
     commands::{osu::user_not_found, GameModeOption},
-    core::commands::{prefix::Args, CommandOrigin},
+    core::{
+        commands::{prefix::Args, CommandOrigin},
+        locale::ErrorKey,
+    },
     manager::redis::{osu::UserArgs, RedisData},
     util::ChannelExt,
     Context,
@@ -178,7 +178,7 @@ pub(super) async fn score(
             return orig.error(&ctx, content).await;
         }
         Err(err) => {
-            let _ = orig.error(&ctx, OSU_API_ISSUE).await;
+            let _ = orig.error_localized(&ctx, ErrorKey::OsuApi).await;
 
             return Err(Report::new(err).wrap_err("Failed to get user"));
         }
@@ -235,7 +235,7 @@ pub(super) async fn score(
                     return orig.error(&ctx, content).await;
                 }
                 UserArgs::Err(err) => {
-                    let _ = orig.error(&ctx, OSU_API_ISSUE).await;
+                    let _ = orig.error_localized(&ctx, ErrorKey::OsuApi).await;
 
                     return Err(Report::new(err).wrap_err("Failed to get target user"));
                 }
@@ -246,7 +246,7 @@ pub(super) async fn score(
             let rank_opt = match user_fut.await {
                 Ok(mut users) => users.next().flatten().and_then(|user| user.rank),
                 Err(err) => {
-                    let _ = orig.error(&ctx, GENERAL_ISSUE).await;
+                    let _ = orig.error_localized(&ctx, ErrorKey::General).await;
 
                     return Err(err.wrap_err("Failed to get respektive user"));
                 }
@@ -297,7 +297,7 @@ pub(super) async fn score(
                 return orig.error(&ctx, content).await;
             }
             Err(err) => {
-                let _ = orig.error(&ctx, OSU_API_ISSUE).await;
+                let _ = orig.error_localized(&ctx, ErrorKey::OsuApi).await;
                 let err = Report::new(err).wrap_err("Failed to get user");
 
                 return Err(err);
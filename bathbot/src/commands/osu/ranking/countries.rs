@@ -1,7 +1,6 @@
 use std::{collections::BTreeMap, sync::Arc};
 
 use bathbot_macros::command;
-use bathbot_util::constants::{GENERAL_ISSUE, OSU_API_ISSUE};
 use eyre::{Report, Result};
 use rosu_v2::prelude::GameMode;
 
@@ -9,7 +8,7 @@ use super::RankingCountry;
 use crate::{
     active::{impls::RankingCountriesPagination, ActiveMessages},
     commands::GameModeOption,
-    core::commands::CommandOrigin,
+    core::{commands::CommandOrigin, locale::ErrorKey},
     Context,
 };
 
@@ -57,7 +56,7 @@ pub(super) async fn country(
         None => match ctx.user_config().mode(owner).await {
             Ok(mode) => mode.unwrap_or(GameMode::Osu),
             Err(err) => {
-                let _ = orig.error(&ctx, GENERAL_ISSUE).await;
+                let _ = orig.error_localized(&ctx, ErrorKey::General).await;
 
                 return Err(err);
             }
@@ -67,7 +66,7 @@ pub(super) async fn country(
     let mut ranking = match ctx.osu().country_rankings(mode).await {
         Ok(ranking) => ranking,
         Err(err) => {
-            let _ = orig.error(&ctx, OSU_API_ISSUE).await;
+            let _ = orig.error_localized(&ctx, ErrorKey::OsuApi).await;
             let err = Report::new(err).wrap_err("Failed to get country ranking");
 
             return Err(err);
@@ -4,7 +4,6 @@ use bathbot_macros::command;
 use bathbot_model::{
     rosu_v2::ranking::Rankings, Countries, Either, RankingEntries, RankingEntry, RankingKind,
 };
-use bathbot_util::constants::{GENERAL_ISSUE, OSU_API_ISSUE};
 use eyre::{Report, Result};
 use rosu_v2::prelude::{CountryCode, GameMode, OsuResult, Rankings as RosuRankings};
 
@@ -12,7 +11,7 @@ use super::{RankingPp, RankingScore};
 use crate::{
     active::{impls::RankingPagination, ActiveMessages},
     commands::GameModeOption,
-    core::commands::CommandOrigin,
+    core::{commands::CommandOrigin, locale::ErrorKey},
     manager::redis::{osu::UserArgs, RedisData},
     util::ChannelExt,
     Context,
@@ -49,7 +48,7 @@ pub(super) async fn pp(
         None => match ctx.user_config().with_osu_id(owner).await {
             Ok(config) => (config.mode.unwrap_or(GameMode::Osu), config.osu),
             Err(err) => {
-                let _ = orig.error(&ctx, GENERAL_ISSUE).await;
+                let _ = orig.error_localized(&ctx, ErrorKey::General).await;
 
                 return Err(err.wrap_err("Failed to get user config"));
             }
@@ -141,7 +140,7 @@ pub(super) async fn score(
         None => match ctx.user_config().with_osu_id(owner).await {
             Ok(config) => (config.mode.unwrap_or(GameMode::Osu), config.osu),
             Err(err) => {
-                let _ = orig.error(&ctx, GENERAL_ISSUE).await;
+                let _ = orig.error_localized(&ctx, ErrorKey::General).await;
 
                 return Err(err.wrap_err("failed to get user config"));
             }
@@ -187,7 +186,7 @@ async fn ranking(
     let mut ranking = match result {
         Ok(ranking) => ranking,
         Err(err) => {
-            let _ = orig.error(&ctx, OSU_API_ISSUE).await;
+            let _ = orig.error_localized(&ctx, ErrorKey::OsuApi).await;
 
             return Err(Report::new(err).wrap_err("failed to get ranking"));
         }
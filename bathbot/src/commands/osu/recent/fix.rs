@@ -1,10 +1,7 @@
 use std::sync::Arc;
 
 use bathbot_model::ScoreSlim;
-use bathbot_util::{
-    constants::{GENERAL_ISSUE, OSU_API_ISSUE},
-    MessageBuilder,
-};
+use bathbot_util::MessageBuilder;
 use eyre::{Report, Result};
 use rand::{thread_rng, Rng};
 use rosu_v2::prelude::{GameMode, OsuError};
@@ -12,7 +9,7 @@ use rosu_v2::prelude::{GameMode, OsuError};
 use super::RecentFix;
 use crate::{
     commands::osu::{user_not_found, FixEntry, FixScore},
-    core::{commands::CommandOrigin, Context},
+    core::{commands::CommandOrigin, locale::ErrorKey, Context},
     embeds::{EmbedData, FixScoreEmbed},
     manager::redis::osu::{UserArgs, UserArgsSlim},
     util::osu::IfFc,
@@ -57,7 +54,7 @@ pub(super) async fn fix(ctx: Arc<Context>, orig: CommandOrigin<'_>, args: Recent
             return orig.error(&ctx, content).await;
         }
         Err(err) => {
-            let _ = orig.error(&ctx, OSU_API_ISSUE).await;
+            let _ = orig.error_localized(&ctx, ErrorKey::OsuApi).await;
             let err = Report::new(err).wrap_err("failed to get user or scores");
 
             return Err(err);
@@ -94,12 +91,12 @@ pub(super) async fn fix(ctx: Arc<Context>, orig: CommandOrigin<'_>, args: Recent
             match tokio::join!(map_fut, best_fut) {
                 (Ok(map), Ok(best)) => (score, map, best),
                 (Err(err), _) => {
-                    let _ = orig.error(&ctx, GENERAL_ISSUE).await;
+                    let _ = orig.error_localized(&ctx, ErrorKey::General).await;
 
                     return Err(Report::new(err));
                 }
                 (_, Err(err)) => {
-                    let _ = orig.error(&ctx, OSU_API_ISSUE).await;
+                    let _ = orig.error_localized(&ctx, ErrorKey::OsuApi).await;
                     let err = Report::new(err).wrap_err("failed to get top scores");
 
                     return Err(err);
@@ -1,11 +1,7 @@
 use std::{collections::HashMap, sync::Arc};
 
 use bathbot_macros::command;
-use bathbot_util::{
-    constants::{AVATAR_URL, GENERAL_ISSUE, OSU_API_ISSUE, OSU_WEB_ISSUE},
-    matcher,
-    osu::ModSelection,
-};
+use bathbot_util::{constants::AVATAR_URL, matcher, osu::ModSelection};
 use eyre::{Report, Result};
 use rand::{thread_rng, Rng};
 use rosu_v2::{
@@ -23,7 +19,10 @@ use crate::{
         },
         GameModeOption,
     },
-    core::commands::{prefix::Args, CommandOrigin},
+    core::{
+        commands::{prefix::Args, CommandOrigin},
+        locale::ErrorKey,
+    },
     manager::redis::osu::UserArgs,
     Context,
 };
@@ -187,7 +186,7 @@ pub(super) async fn leaderboard(
     let config = match ctx.user_config().with_osu_id(owner).await {
         Ok(config) => config,
         Err(err) => {
-            let _ = orig.error(&ctx, GENERAL_ISSUE).await;
+            let _ = orig.error_localized(&ctx, ErrorKey::General).await;
 
             return Err(err.wrap_err("Failed to get user config"));
         }
@@ -258,7 +257,7 @@ pub(super) async fn leaderboard(
             return orig.error(&ctx, content).await;
         }
         Err(err) => {
-            let _ = orig.error(&ctx, OSU_API_ISSUE).await;
+            let _ = orig.error_localized(&ctx, ErrorKey::OsuApi).await;
             let err = Report::new(err).wrap_err("Failed to get scores");
 
             return Err(err);
@@ -284,7 +283,7 @@ pub(super) async fn leaderboard(
     let map = match map_res {
         Ok(map) => map,
         Err(err) => {
-            let _ = orig.error(&ctx, GENERAL_ISSUE).await;
+            let _ = orig.error_localized(&ctx, ErrorKey::General).await;
 
             return Err(Report::new(err));
         }
@@ -306,7 +305,7 @@ pub(super) async fn leaderboard(
             })
             .collect(),
         Err(err) => {
-            let _ = orig.error(&ctx, OSU_WEB_ISSUE).await;
+            let _ = orig.error_localized(&ctx, ErrorKey::OsuWeb).await;
 
             return Err(err.wrap_err("Failed to get scores"));
         }
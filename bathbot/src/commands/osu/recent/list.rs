@@ -8,12 +8,7 @@ use std::{
 
 use bathbot_macros::command;
 use bathbot_model::ScoreSlim;
-use bathbot_util::{
-    constants::{GENERAL_ISSUE, OSU_API_ISSUE},
-    matcher,
-    osu::ModSelection,
-    CowUtils, IntHasher,
-};
+use bathbot_util::{matcher, osu::ModSelection, CowUtils, IntHasher};
 use eyre::{Report, Result};
 use rosu_pp::DifficultyAttributes;
 use rosu_v2::prelude::{GameMode, Grade, OsuError, Score};
@@ -25,7 +20,10 @@ use crate::{
         osu::{user_not_found, HasMods, ModsResult, ScoreOrder},
         GameModeOption, GradeOption,
     },
-    core::commands::{prefix::Args, CommandOrigin},
+    core::{
+        commands::{prefix::Args, CommandOrigin},
+        locale::ErrorKey,
+    },
     manager::{redis::osu::UserArgs, OsuMap},
     util::{
         query::{IFilterCriteria, RegularCriteria, Searchable},
@@ -386,7 +384,7 @@ pub(super) async fn list(
             return orig.error(&ctx, content).await;
         }
         Err(err) => {
-            let _ = orig.error(&ctx, OSU_API_ISSUE).await;
+            let _ = orig.error_localized(&ctx, ErrorKey::OsuApi).await;
             let err = Report::new(err).wrap_err("Failed to get user or scores");
 
             return Err(err);
@@ -396,7 +394,7 @@ pub(super) async fn list(
     let (entries, maps) = match process_scores(&ctx, scores, &args, mode, mods.as_ref()).await {
         Ok(entries) => entries,
         Err(err) => {
-            let _ = orig.error(&ctx, GENERAL_ISSUE).await;
+            let _ = orig.error_localized(&ctx, ErrorKey::General).await;
 
             return Err(err.wrap_err("Failed to process scores"));
         }
@@ -3,10 +3,7 @@ use std::{borrow::Cow, mem, sync::Arc};
 use bathbot_macros::{command, HasName, SlashCommand};
 use bathbot_model::ScoreSlim;
 use bathbot_psql::model::configs::{GuildConfig, MinimizedPp, Retries, ScoreSize};
-use bathbot_util::{
-    constants::{GENERAL_ISSUE, OSU_API_ISSUE},
-    matcher, CowUtils, MessageOrigin,
-};
+use bathbot_util::{matcher, CowUtils, MessageOrigin};
 use eyre::{Report, Result};
 use rand::{thread_rng, Rng};
 use rosu_v2::{
@@ -30,7 +27,10 @@ use crate::{
         osu::{require_link, user_not_found},
         GameModeOption, GradeOption,
     },
-    core::commands::{prefix::Args, CommandOrigin},
+    core::{
+        commands::{prefix::Args, CommandOrigin},
+        locale::ErrorKey,
+    },
     manager::{
         redis::osu::{UserArgs, UserArgsSlim},
         OsuMap, OwnedReplayScore,
@@ -351,7 +351,7 @@ pub(super) async fn score(
     let config = match user_config_res {
         Ok(config) => config,
         Err(err) => {
-            let _ = orig.error(&ctx, GENERAL_ISSUE).await;
+            let _ = orig.error_localized(&ctx, ErrorKey::General).await;
 
             return Err(err.wrap_err("Failed to get user config"));
         }
@@ -432,7 +432,7 @@ pub(super) async fn score(
             return orig.error(&ctx, content).await;
         }
         Err(err) => {
-            let _ = orig.error(&ctx, OSU_API_ISSUE).await;
+            let _ = orig.error_localized(&ctx, ErrorKey::OsuApi).await;
             let err = Report::new(err).wrap_err("failed to get user or scores");
 
             return Err(err);
@@ -502,7 +502,7 @@ pub(super) async fn score(
         let map = match ctx.osu_map().map(map_id, checksum).await {
             Ok(map) => map.convert(mode),
             Err(err) => {
-                let _ = orig.error(&ctx, GENERAL_ISSUE).await;
+                let _ = orig.error_localized(&ctx, ErrorKey::General).await;
 
                 return Err(Report::new(err));
             }
@@ -5,7 +5,7 @@ use std::{
 use bathbot_macros::{HasMods, SlashCommand};
 use bathbot_model::Countries;
 use bathbot_psql::model::osu::{DbScoreBeatmap, DbScoreBeatmapset, DbTopScore, DbTopScores};
-use bathbot_util::{constants::GENERAL_ISSUE, osu::ModSelection, CowUtils, IntHasher};
+use bathbot_util::{osu::ModSelection, CowUtils, IntHasher};
 use compact_str::CompactString;
 use eyre::Result;
 use rkyv::collections::ArchivedHashMap;
@@ -20,7 +20,7 @@ use crate::{
         osu::{HasMods, ModsResult, ScoresOrder},
         GameModeOption,
     },
-    core::Context,
+    core::{locale::ErrorKey, Context},
     manager::redis::RedisData,
     util::{
         interaction::InteractionCommand,
@@ -196,7 +196,7 @@ async fn regiontop_global(
     let scores = match ctx.osu_scores().db_top_scores(args.mode, None, None).await {
         Ok(scores) => scores,
         Err(err) => {
-            let _ = command.error(&ctx, GENERAL_ISSUE).await;
+            let _ = command.error_localized(&ctx, ErrorKey::General).await;
 
             return Err(err);
         }
@@ -218,7 +218,7 @@ async fn regiontop_country(
     let scores = match ctx.osu_scores().db_top_scores(args.mode, None, code).await {
         Ok(scores) => scores,
         Err(err) => {
-            let _ = command.error(&ctx, GENERAL_ISSUE).await;
+            let _ = command.error_localized(&ctx, ErrorKey::General).await;
 
             return Err(err);
         }
@@ -239,7 +239,7 @@ async fn regiontop_region(
     let user_ids = match ctx.client().get_region_user_ids(&region.code).await {
         Ok(user_ids) => user_ids,
         Err(err) => {
-            let _ = command.error(&ctx, GENERAL_ISSUE).await;
+            let _ = command.error_localized(&ctx, ErrorKey::General).await;
 
             return Err(err);
         }
@@ -253,7 +253,7 @@ async fn regiontop_region(
     let scores = match scores_fut.await {
         Ok(scores) => scores,
         Err(err) => {
-            let _ = command.error(&ctx, GENERAL_ISSUE).await;
+            let _ = command.error_localized(&ctx, ErrorKey::General).await;
 
             return Err(err);
         }
@@ -729,7 +729,8 @@ trait RegionsExt<Code, Name> {
 }
 
 impl<Code, Name> RegionsExt<Code, Name> for HashMap<Code, Name> {
-    type Iter<'a> = std::collections::hash_map::Iter<'a, Code, Name>
+    type Iter<'a>
+        = std::collections::hash_map::Iter<'a, Code, Name>
     where
         Code: 'a,
         Name: 'a,
@@ -741,7 +742,8 @@ impl<Code, Name> RegionsExt<Code, Name> for HashMap<Code, Name> {
 }
 
 impl<Code, Name> RegionsExt<Code, Name> for ArchivedHashMap<Code, Name> {
-    type Iter<'a> = rkyv::collections::hash_map::Iter<'a, Code, Name>
+    type Iter<'a>
+        = rkyv::collections::hash_map::Iter<'a, Code, Name>
     where
         Code: 'a,
         Name: 'a,
@@ -5,10 +5,7 @@ use std::{
 };
 
 use bathbot_macros::SlashCommand;
-use bathbot_util::{
-    constants::{GENERAL_ISSUE, ORDR_ISSUE, OSU_API_ISSUE},
-    EmbedBuilder, MessageBuilder,
-};
+use bathbot_util::{EmbedBuilder, MessageBuilder};
 use eyre::{Report, Result, WrapErr};
 use rosu_render::{
     client::error::{
@@ -28,7 +25,7 @@ use crate::{
         impls::{CachedRender, RenderSettingsActive, SettingsImport},
         ActiveMessages,
     },
-    core::{buckets::BucketName, commands::OwnedCommandOrigin, Context},
+    core::{buckets::BucketName, commands::OwnedCommandOrigin, locale::ErrorKey, Context},
     manager::{ReplayScore, ReplaySettings},
     tracking::OrdrReceivers,
     util::{interaction::InteractionCommand, Authored, InteractionCommandExt},
@@ -159,7 +156,7 @@ async fn render_replay(
     let settings = match ctx.replay().get_settings(owner).await {
         Ok(settings) => settings,
         Err(err) => {
-            let _ = command.error(&ctx, GENERAL_ISSUE).await;
+            let _ = command.error_localized(&ctx, ErrorKey::General).await;
 
             return Err(err);
         }
@@ -214,7 +211,7 @@ async fn render_replay(
                     Ok(())
                 }
                 _ => {
-                    let _ = command.error(&ctx, ORDR_ISSUE).await;
+                    let _ = command.error_localized(&ctx, ErrorKey::Ordr).await;
 
                     Err(Report::new(err).wrap_err("Failed to commission render"))
                 }
@@ -274,7 +271,7 @@ async fn render_score(
             return Ok(());
         }
         Err(err) => {
-            let _ = command.error(&ctx, OSU_API_ISSUE).await;
+            let _ = command.error_localized(&ctx, ErrorKey::OsuApi).await;
 
             return Err(Report::new(err).wrap_err("Failed to get score"));
         }
@@ -306,7 +303,7 @@ async fn render_score(
             return Ok(());
         }
         Err(err) => {
-            let _ = command.error(&ctx, GENERAL_ISSUE).await;
+            let _ = command.error_localized(&ctx, ErrorKey::General).await;
 
             return Err(err.wrap_err("Failed to get replay"));
         }
@@ -315,7 +312,7 @@ async fn render_score(
     let settings = match settings_res {
         Ok(settings) => settings,
         Err(err) => {
-            let _ = command.error(&ctx, GENERAL_ISSUE).await;
+            let _ = command.error_localized(&ctx, ErrorKey::General).await;
 
             return Err(err);
         }
@@ -346,7 +343,7 @@ async fn render_score(
     let render = match render_fut.await {
         Ok(render) => render,
         Err(err) => {
-            let _ = command.error(&ctx, ORDR_ISSUE).await;
+            let _ = command.error_localized(&ctx, ErrorKey::Ordr).await;
 
             return Err(Report::new(err).wrap_err("Failed to commission render"));
         }
@@ -632,7 +629,7 @@ async fn render_settings_modify(ctx: Arc<Context>, command: &mut InteractionComm
     let settings = match ctx.replay().get_settings(owner).await {
         Ok(settings) => settings,
         Err(err) => {
-            let _ = command.error(&ctx, GENERAL_ISSUE).await;
+            let _ = command.error_localized(&ctx, ErrorKey::General).await;
 
             return Err(err);
         }
@@ -668,14 +665,14 @@ async fn render_settings_copy(
     let settings = match replay_manager.get_settings(args.user).await {
         Ok(settings) => settings,
         Err(err) => {
-            let _ = command.error(&ctx, GENERAL_ISSUE).await;
+            let _ = command.error_localized(&ctx, ErrorKey::General).await;
 
             return Err(err);
         }
     };
 
     if let Err(err) = replay_manager.set_settings(owner, &settings).await {
-        let _ = command.error(&ctx, GENERAL_ISSUE).await;
+        let _ = command.error_localized(&ctx, ErrorKey::General).await;
 
         return Err(err);
     }
@@ -703,7 +700,7 @@ async fn render_settings_default(
     let settings = ReplaySettings::default();
 
     if let Err(err) = replay_manager.set_settings(owner, &settings).await {
-        let _ = command.error(&ctx, GENERAL_ISSUE).await;
+        let _ = command.error_localized(&ctx, ErrorKey::General).await;
 
         return Err(err);
     }
@@ -2,7 +2,6 @@ use std::{borrow::Cow, fmt::Write, sync::Arc};
 
 use bathbot_model::Countries;
 use bathbot_util::{
-    constants::GENERAL_ISSUE,
     matcher,
     osu::{MapIdType, ModSelection},
     CowUtils, MessageBuilder,
@@ -18,7 +17,7 @@ use crate::{
         compare::{slash_compare_score, ScoreOrder},
         CompareScoreAutocomplete, HasMods, ModsResult,
     },
-    core::Context,
+    core::{locale::ErrorKey, Context},
     util::{
         interaction::InteractionCommand,
         query::{FilterCriteria, IFilterCriteria, ScoresCriteria},
@@ -132,7 +131,7 @@ pub async fn map_scores(
             let msgs = match ctx.retrieve_channel_history(command.channel_id()).await {
                 Ok(msgs) => msgs,
                 Err(err) => {
-                    let _ = command.error(&ctx, GENERAL_ISSUE).await;
+                    let _ = command.error_localized(&ctx, ErrorKey::General).await;
 
                     return Err(err.wrap_err("Failed to retrieve channel history"));
                 }
@@ -192,7 +191,7 @@ pub async fn map_scores(
     let members: Vec<_> = match members_res {
         Ok(members) => members.into_iter().map(|id| id as i64).collect(),
         Err(err) => {
-            let _ = command.error(&ctx, GENERAL_ISSUE).await;
+            let _ = command.error_localized(&ctx, ErrorKey::General).await;
 
             return Err(err);
         }
@@ -218,7 +217,7 @@ pub async fn map_scores(
     let mut scores = match scores_fut.await {
         Ok(scores) => scores,
         Err(err) => {
-            let _ = command.error(&ctx, GENERAL_ISSUE).await;
+            let _ = command.error_localized(&ctx, ErrorKey::General).await;
 
             return Err(err);
         }
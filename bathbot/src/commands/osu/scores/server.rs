@@ -1,11 +1,7 @@
 use std::{borrow::Cow, fmt::Write, sync::Arc};
 
 use bathbot_model::Countries;
-use bathbot_util::{
-    constants::{GENERAL_ISSUE, OSU_API_ISSUE},
-    osu::ModSelection,
-    CowUtils,
-};
+use bathbot_util::{osu::ModSelection, CowUtils};
 use eyre::{Report, Result};
 use rosu_v2::{
     prelude::{Grade, OsuError},
@@ -16,7 +12,7 @@ use super::{get_mode, process_scores, separate_content, MapStatus, ServerScores}
 use crate::{
     active::{impls::ScoresServerPagination, ActiveMessages},
     commands::osu::{user_not_found, HasMods, ModsResult, ScoresOrder},
-    core::Context,
+    core::{locale::ErrorKey, Context},
     manager::redis::osu::UserArgs,
     util::{
         interaction::InteractionCommand,
@@ -83,7 +79,7 @@ pub async fn server_scores(
     let members: Vec<_> = match members_res {
         Ok(members) => members.into_iter().map(|id| id as i64).collect(),
         Err(err) => {
-            let _ = command.error(&ctx, GENERAL_ISSUE).await;
+            let _ = command.error_localized(&ctx, ErrorKey::General).await;
 
             return Err(err);
         }
@@ -109,7 +105,7 @@ pub async fn server_scores(
     let mut scores = match scores_fut.await {
         Ok(scores) => scores,
         Err(err) => {
-            let _ = command.error(&ctx, GENERAL_ISSUE).await;
+            let _ = command.error_localized(&ctx, ErrorKey::General).await;
 
             return Err(err);
         }
@@ -126,7 +122,7 @@ pub async fn server_scores(
                 return Ok(());
             }
             UserArgs::Err(err) => {
-                let _ = command.error(&ctx, OSU_API_ISSUE).await;
+                let _ = command.error_localized(&ctx, ErrorKey::OsuApi).await;
 
                 return Err(Report::new(err).wrap_err("Failed to get mapper"));
             }
@@ -1,10 +1,7 @@
 use std::{fmt::Write, sync::Arc};
 
 use bathbot_model::rosu_v2::user::User;
-use bathbot_util::{
-    constants::{GENERAL_ISSUE, OSU_API_ISSUE},
-    osu::ModSelection,
-};
+use bathbot_util::osu::ModSelection;
 use eyre::{Report, Result};
 use rosu_v2::{
     prelude::{GameMode, Grade, OsuError},
@@ -15,7 +12,7 @@ use super::{process_scores, separate_content, MapStatus, ScoresOrder, UserScores
 use crate::{
     active::{impls::ScoresUserPagination, ActiveMessages},
     commands::osu::{require_link, user_not_found, HasMods, ModsResult},
-    core::{commands::CommandOrigin, Context},
+    core::{commands::CommandOrigin, locale::ErrorKey, Context},
     manager::redis::{osu::UserArgs, RedisData},
     util::{
         interaction::InteractionCommand,
@@ -69,7 +66,7 @@ pub async fn user_scores(
             return Ok(());
         }
         Err(err) => {
-            let _ = command.error(&ctx, OSU_API_ISSUE).await;
+            let _ = command.error_localized(&ctx, ErrorKey::OsuApi).await;
 
             return Err(Report::new(err).wrap_err("Failed to get user"));
         }
@@ -85,7 +82,7 @@ pub async fn user_scores(
     let mut scores = match scores_fut.await {
         Ok(scores) => scores,
         Err(err) => {
-            let _ = command.error(&ctx, GENERAL_ISSUE).await;
+            let _ = command.error_localized(&ctx, ErrorKey::General).await;
 
             return Err(err);
         }
@@ -102,7 +99,7 @@ pub async fn user_scores(
                 return Ok(());
             }
             UserArgs::Err(err) => {
-                let _ = command.error(&ctx, OSU_API_ISSUE).await;
+                let _ = command.error_localized(&ctx, ErrorKey::OsuApi).await;
 
                 return Err(Report::new(err).wrap_err("Failed to get mapper"));
             }
@@ -2,14 +2,13 @@ use std::{borrow::Cow, sync::Arc};
 
 use bathbot_macros::SlashCommand;
 use bathbot_model::{Countries, RankingKind, UserModeStatsColumn, UserStatsColumn, UserStatsKind};
-use bathbot_util::constants::GENERAL_ISSUE;
 use eyre::Result;
 use rosu_v2::prelude::GameMode;
 use twilight_interactions::command::{CommandModel, CreateCommand};
 
 use crate::{
     active::{impls::RankingPagination, ActiveMessages},
-    core::commands::interaction::InteractionCommands,
+    core::{commands::interaction::InteractionCommands, locale::ErrorKey},
     util::{interaction::InteractionCommand, Authored, InteractionCommandExt},
     Context,
 };
@@ -150,7 +149,7 @@ async fn slash_serverleaderboard(ctx: Arc<Context>, mut command: InteractionComm
     let members: Vec<_> = match ctx.cache.members(guild_id).await {
         Ok(members) => members.into_iter().map(|id| id as i64).collect(),
         Err(err) => {
-            let _ = command.error(&ctx, GENERAL_ISSUE).await;
+            let _ = command.error_localized(&ctx, ErrorKey::General).await;
 
             return Err(err);
         }
@@ -296,7 +295,7 @@ async fn slash_serverleaderboard(ctx: Arc<Context>, mut command: InteractionComm
     let entries = match entries_res {
         Ok(entries) => entries,
         Err(err) => {
-            let _ = command.error(&ctx, GENERAL_ISSUE).await;
+            let _ = command.error_localized(&ctx, ErrorKey::General).await;
 
             return Err(err);
         }
@@ -4,7 +4,7 @@ pub mod parsed_map;
 use std::{borrow::Cow, sync::Arc};
 
 use bathbot_macros::{command, HasMods, SlashCommand};
-use bathbot_util::{constants::GENERAL_ISSUE, matcher, osu::MapIdType};
+use bathbot_util::{matcher, osu::MapIdType};
 use eyre::Result;
 use rosu_v2::prelude::{GameMode, GameModsIntermode};
 use twilight_interactions::command::{CommandModel, CreateCommand};
@@ -26,6 +26,7 @@ use crate::{
     commands::{osu::parsed_map::AttachedSimulateMap, GameModeOption},
     core::{
         commands::{prefix::Args, CommandOrigin},
+        locale::ErrorKey,
         Context,
     },
     manager::MapError,
@@ -351,7 +352,7 @@ async fn prepare_map(
             let msgs = match ctx.retrieve_channel_history(orig.channel_id()).await {
                 Ok(msgs) => msgs,
                 Err(err) => {
-                    let _ = orig.error(ctx, GENERAL_ISSUE).await;
+                    let _ = orig.error_localized(ctx, ErrorKey::General).await;
 
                     return Err(err.wrap_err("Failed to retrieve channel history"));
                 }
@@ -391,7 +392,7 @@ async fn prepare_map(
             return orig.error(ctx, content).await.map(|_| None);
         }
         Err(MapError::Report(err)) => {
-            let _ = orig.error(ctx, GENERAL_ISSUE).await;
+            let _ = orig.error_localized(ctx, ErrorKey::General).await;
 
             return Err(err);
         }
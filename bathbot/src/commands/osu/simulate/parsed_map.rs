@@ -1,12 +1,11 @@
 use std::borrow::Cow;
 
-use bathbot_util::constants::GENERAL_ISSUE;
 use eyre::{Report, Result};
 use rosu_pp::{Beatmap, BeatmapExt, GameMode as Mode};
 use rosu_v2::prelude::GameMode;
 use twilight_model::channel::Attachment;
 
-use crate::core::{commands::CommandOrigin, Context};
+use crate::core::{commands::CommandOrigin, locale::ErrorKey, Context};
 
 pub struct AttachedSimulateMap {
     pub pp_map: Beatmap,
@@ -32,7 +31,7 @@ impl AttachedSimulateMap {
         let bytes = match ctx.client().get_discord_attachment(&attachment).await {
             Ok(bytes) => bytes,
             Err(err) => {
-                let _ = orig.error(ctx, GENERAL_ISSUE).await;
+                let _ = orig.error_localized(ctx, ErrorKey::General).await;
 
                 return Err(err.wrap_err("Failed to download attachment"));
             }
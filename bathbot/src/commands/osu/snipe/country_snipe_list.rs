@@ -2,10 +2,7 @@ use std::{borrow::Cow, cmp::Ordering::Equal, sync::Arc};
 
 use bathbot_macros::command;
 use bathbot_model::{Countries, SnipeCountryPlayer as SCP};
-use bathbot_util::{
-    constants::{HUISMETBENEN_ISSUE, OSU_API_ISSUE},
-    CowUtils,
-};
+use bathbot_util::CowUtils;
 use eyre::{Report, Result};
 use rosu_v2::{
     prelude::{CountryCode, OsuError},
@@ -16,7 +13,10 @@ use super::{SnipeCountryList, SnipeCountryListOrder};
 use crate::{
     active::{impls::SnipeCountryListPagination, ActiveMessages},
     commands::osu::user_not_found,
-    core::commands::{prefix::Args, CommandOrigin},
+    core::{
+        commands::{prefix::Args, CommandOrigin},
+        locale::ErrorKey,
+    },
     manager::redis::{osu::UserArgs, RedisData},
     util::ChannelExt,
     Context,
@@ -73,7 +73,7 @@ pub(super) async fn country_list(
                     return orig.error(&ctx, content).await;
                 }
                 Err(err) => {
-                    let _ = orig.error(&ctx, OSU_API_ISSUE).await;
+                    let _ = orig.error_localized(&ctx, ErrorKey::OsuApi).await;
                     let err = Report::new(err).wrap_err("failed to get user");
 
                     return Err(err);
@@ -112,18 +112,18 @@ pub(super) async fn country_list(
         },
     };
 
-    // Check if huisemetbenen supports the country
-    if !ctx.huismetbenen().is_supported(country_code.as_str()).await {
+    // Check if the country is supported by huismetbenen or tracked locally
+    if !ctx.snipe().is_supported(country_code.as_str()).await {
         let content = format!("The country code `{country_code}` is not supported :(",);
 
         return orig.error(&ctx, content).await;
     }
 
     // Request players
-    let mut players = match ctx.client().get_snipe_country(&country_code).await {
+    let mut players = match ctx.snipe().country_players(&country_code).await {
         Ok(players) => players,
         Err(err) => {
-            let _ = orig.error(&ctx, HUISMETBENEN_ISSUE).await;
+            let _ = orig.error_localized(&ctx, ErrorKey::Huismetbenen).await;
 
             return Err(err.wrap_err("failed to get snipe country"));
         }
@@ -2,10 +2,7 @@ use std::{borrow::Cow, cmp::Ordering::Equal, sync::Arc};
 
 use bathbot_macros::command;
 use bathbot_model::{Countries, SnipeCountryPlayer};
-use bathbot_util::{
-    constants::{GENERAL_ISSUE, HUISMETBENEN_ISSUE, OSU_API_ISSUE},
-    MessageBuilder,
-};
+use bathbot_util::MessageBuilder;
 use eyre::{ContextCompat, Report, Result, WrapErr};
 use plotters::prelude::*;
 use plotters_skia::SkiaBackend;
@@ -19,7 +16,7 @@ use twilight_model::guild::Permissions;
 use super::SnipeCountryStats;
 use crate::{
     commands::osu::user_not_found,
-    core::commands::CommandOrigin,
+    core::{commands::CommandOrigin, locale::ErrorKey},
     embeds::{CountrySnipeStatsEmbed, EmbedData},
     manager::redis::{osu::UserArgs, RedisData},
     Context,
@@ -81,7 +78,7 @@ pub(super) async fn country_stats(
                         return orig.error(&ctx, content).await;
                     }
                     Err(err) => {
-                        let _ = orig.error(&ctx, OSU_API_ISSUE).await;
+                        let _ = orig.error_localized(&ctx, ErrorKey::OsuApi).await;
                         let err = Report::new(err).wrap_err("failed to get user");
 
                         return Err(err);
@@ -99,30 +96,30 @@ pub(super) async fn country_stats(
                 return orig.error(&ctx, content).await;
             }
             Err(err) => {
-                let _ = orig.error(&ctx, GENERAL_ISSUE).await;
+                let _ = orig.error_localized(&ctx, ErrorKey::General).await;
 
                 return Err(err.wrap_err("failed to get username"));
             }
         },
     };
 
-    // Check if huisemetbenen supports the country
-    if !ctx.huismetbenen().is_supported(country_code.as_str()).await {
+    // Check if the country is supported by huismetbenen or tracked locally
+    if !ctx.snipe().is_supported(country_code.as_str()).await {
         let content = format!("The country code `{country_code}` is not supported :(",);
 
         return orig.error(&ctx, content).await;
     }
 
-    let client = &ctx.client();
+    let snipe = ctx.snipe();
 
     let (players, statistics) = {
         match tokio::try_join!(
-            client.get_snipe_country(&country_code),
-            client.get_country_statistics(&country_code),
+            snipe.country_players(&country_code),
+            snipe.country_statistics(&country_code),
         ) {
             Ok((players, statistics)) => (players, statistics),
             Err(err) => {
-                let _ = orig.error(&ctx, HUISMETBENEN_ISSUE).await;
+                let _ = orig.error_localized(&ctx, ErrorKey::Huismetbenen).await;
 
                 return Err(err.wrap_err("failed to get country data"));
             }
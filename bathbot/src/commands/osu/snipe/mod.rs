@@ -17,6 +17,7 @@ use crate::{
 
 mod country_snipe_list;
 mod country_snipe_stats;
+mod notify;
 mod player_snipe_list;
 mod sniped_difference;
 
@@ -36,6 +37,8 @@ pub enum Snipe<'a> {
     Country(SnipeCountry<'a>),
     #[command(name = "player")]
     Player(SnipePlayer<'a>),
+    #[command(name = "notify")]
+    Notify(SnipeNotify),
 }
 
 #[derive(CommandModel, CreateCommand)]
@@ -198,6 +201,29 @@ pub struct SnipePlayerSniped<'a> {
     discord: Option<Id<UserMarker>>,
 }
 
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "notify",
+    desc = "Get notified when one of your national #1s is sniped",
+    help = "Get notified whenever someone takes one of your national #1s.\n\
+    Notifications are sent either via DM or in the channel in which this command was used.\n\
+    You must be linked to an osu! profile via `/link` to use this."
+)]
+pub struct SnipeNotify {
+    #[command(desc = "Where notifications should be sent")]
+    target: SnipeNotifyTarget,
+}
+
+#[derive(Copy, Clone, CommandOption, CreateOption, Eq, PartialEq)]
+pub enum SnipeNotifyTarget {
+    #[option(name = "DM", value = "dm")]
+    Dm,
+    #[option(name = "This channel", value = "channel")]
+    Channel,
+    #[option(name = "Disable", value = "disable")]
+    Disable,
+}
+
 async fn slash_snipe(ctx: Arc<Context>, mut command: InteractionCommand) -> Result<()> {
     match Snipe::from_interaction(command.input_data())? {
         Snipe::Country(SnipeCountry::List(args)) => {
@@ -221,6 +247,7 @@ async fn slash_snipe(ctx: Arc<Context>, mut command: InteractionCommand) -> Resu
         Snipe::Player(SnipePlayer::Sniped(args)) => {
             player_sniped(ctx, (&mut command).into(), args).await
         }
+        Snipe::Notify(args) => notify::notify(ctx, (&mut command).into(), args).await,
     }
 }
 
@@ -0,0 +1,105 @@
+use std::sync::Arc;
+
+use bathbot_util::MessageBuilder;
+use eyre::{Report, Result};
+use rosu_v2::{prelude::OsuError, request::UserId};
+
+use super::{SnipeNotify, SnipeNotifyTarget};
+use crate::{
+    commands::osu::{require_link, user_not_found},
+    core::{commands::CommandOrigin, locale::ErrorKey},
+    manager::redis::{osu::UserArgs, RedisData},
+    Context,
+};
+
+pub(super) async fn notify(
+    ctx: Arc<Context>,
+    orig: CommandOrigin<'_>,
+    args: SnipeNotify,
+) -> Result<()> {
+    let author_id = orig.user_id()?;
+
+    if let SnipeNotifyTarget::Disable = args.target {
+        let content = match ctx.snipe().remove_notification(author_id).await {
+            Ok(true) => "You will no longer be notified about lost national #1s",
+            Ok(false) => "You did not have snipe notifications enabled",
+            Err(err) => {
+                let _ = orig.error_localized(&ctx, ErrorKey::General).await;
+
+                return Err(err);
+            }
+        };
+
+        let builder = MessageBuilder::new().embed(content);
+        orig.create_message(&ctx, builder).await?;
+
+        return Ok(());
+    }
+
+    let osu_id = match ctx.user_config().osu_id(author_id).await {
+        Ok(Some(user_id)) => user_id,
+        Ok(None) => return require_link(&ctx, &orig).await,
+        Err(err) => {
+            let _ = orig.error_localized(&ctx, ErrorKey::General).await;
+
+            return Err(err);
+        }
+    };
+
+    let user_id = UserId::Id(osu_id);
+    let user_args = UserArgs::rosu_id(&ctx, &user_id).await;
+
+    let user = match ctx.redis().osu_user(user_args).await {
+        Ok(user) => user,
+        Err(OsuError::NotFound) => {
+            let content = user_not_found(&ctx, user_id).await;
+
+            return orig.error(&ctx, content).await;
+        }
+        Err(err) => {
+            let _ = orig.error_localized(&ctx, ErrorKey::OsuApi).await;
+            let err = Report::new(err).wrap_err("failed to get user");
+
+            return Err(err);
+        }
+    };
+
+    let (username, country_code) = match &user {
+        RedisData::Original(user) => (user.username.as_str(), user.country_code.as_str()),
+        RedisData::Archive(user) => (user.username.as_str(), user.country_code.as_str()),
+    };
+
+    if !ctx.snipe().is_supported(country_code).await {
+        let content = format!("`{username}`'s country {country_code} is not supported :(");
+
+        return orig.error(&ctx, content).await;
+    }
+
+    // Notifications in a DM channel are the same as DM notifications
+    let channel_id = match args.target {
+        SnipeNotifyTarget::Channel => orig.guild_id().map(|_| orig.channel_id()),
+        SnipeNotifyTarget::Dm | SnipeNotifyTarget::Disable => None,
+    };
+
+    let set_fut = ctx
+        .snipe()
+        .set_notification(author_id, osu_id, country_code, channel_id);
+
+    if let Err(err) = set_fut.await {
+        let _ = orig.error_localized(&ctx, ErrorKey::General).await;
+
+        return Err(err);
+    }
+
+    let content = match channel_id {
+        Some(channel_id) => format!(
+            "Whenever `{username}` loses a national #1, you will be notified in <#{channel_id}>"
+        ),
+        None => format!("Whenever `{username}` loses a national #1, you will be notified via DM"),
+    };
+
+    let builder = MessageBuilder::new().embed(content);
+    orig.create_message(&ctx, builder).await?;
+
+    Ok(())
+}
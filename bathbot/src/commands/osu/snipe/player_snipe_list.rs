@@ -7,12 +7,7 @@ use std::{
 
 use bathbot_macros::command;
 use bathbot_model::SnipeScoreParams;
-use bathbot_util::{
-    constants::{GENERAL_ISSUE, HUISMETBENEN_ISSUE, OSU_API_ISSUE},
-    matcher,
-    osu::ModSelection,
-    CowUtils,
-};
+use bathbot_util::{matcher, osu::ModSelection, CowUtils};
 use eyre::{Report, Result};
 use rosu_v2::{prelude::OsuError, request::UserId};
 
@@ -20,7 +15,10 @@ use super::{SnipePlayerList, SnipePlayerListOrder};
 use crate::{
     active::{impls::SnipePlayerListPagination, ActiveMessages},
     commands::osu::{require_link, HasMods, ModsResult},
-    core::commands::{prefix::Args, CommandOrigin},
+    core::{
+        commands::{prefix::Args, CommandOrigin},
+        locale::ErrorKey,
+    },
     manager::redis::{osu::UserArgs, RedisData},
     util::ChannelExt,
     Context,
@@ -85,7 +83,7 @@ pub(super) async fn player_list(
             Ok(Some(user_id)) => UserId::Id(user_id),
             Ok(None) => return require_link(&ctx, &orig).await,
             Err(err) => {
-                let _ = orig.error(&ctx, GENERAL_ISSUE).await;
+                let _ = orig.error_localized(&ctx, ErrorKey::General).await;
 
                 return Err(err);
             }
@@ -105,7 +103,7 @@ pub(super) async fn player_list(
             return orig.error(&ctx, content).await;
         }
         Err(err) => {
-            let _ = orig.error(&ctx, OSU_API_ISSUE).await;
+            let _ = orig.error_localized(&ctx, ErrorKey::OsuApi).await;
             let report = Report::new(err).wrap_err("failed to get user");
 
             return Err(report);
@@ -129,7 +127,7 @@ pub(super) async fn player_list(
         }
     };
 
-    let country = if ctx.huismetbenen().is_supported(country_code).await {
+    let country = if ctx.snipe().is_supported(country_code).await {
         country_code.to_owned()
     } else {
         let content = format!("`{username}`'s country {country_code} is not supported :(");
@@ -142,8 +140,8 @@ pub(super) async fn player_list(
         .descending(args.reverse.map_or(true, |b| !b))
         .mods(mods);
 
-    let scores_fut = ctx.client().get_national_firsts(&params);
-    let count_fut = ctx.client().get_national_firsts_count(&params);
+    let scores_fut = ctx.snipe().national_firsts(&params);
+    let count_fut = ctx.snipe().national_firsts_count(&params);
 
     let (scores, count) = match tokio::try_join!(scores_fut, count_fut) {
         Ok((scores, count)) => {
@@ -152,7 +150,7 @@ pub(super) async fn player_list(
             (scores, count)
         }
         Err(err) => {
-            let _ = orig.error(&ctx, HUISMETBENEN_ISSUE).await;
+            let _ = orig.error_localized(&ctx, ErrorKey::Huismetbenen).await;
 
             return Err(err.wrap_err("failed to get scores or counts"));
         }
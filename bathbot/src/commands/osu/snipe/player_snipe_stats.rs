@@ -1,10 +1,7 @@
 use std::{collections::BTreeMap, sync::Arc};
 
 use bathbot_macros::command;
-use bathbot_util::{
-    constants::{GENERAL_ISSUE, HUISMETBENEN_ISSUE, OSU_API_ISSUE},
-    matcher, MessageBuilder,
-};
+use bathbot_util::{matcher, MessageBuilder};
 use eyre::{ContextCompat, Report, Result, WrapErr};
 use plotters::prelude::*;
 use plotters_skia::SkiaBackend;
@@ -19,7 +16,7 @@ use twilight_model::guild::Permissions;
 use super::SnipePlayerStats;
 use crate::{
     commands::osu::require_link,
-    core::commands::CommandOrigin,
+    core::{commands::CommandOrigin, locale::ErrorKey},
     embeds::{EmbedData, PlayerSnipeStatsEmbed},
     manager::redis::{osu::UserArgs, RedisData},
     util::Monthly,
@@ -71,7 +68,7 @@ pub(super) async fn player_stats(
             Ok(Some(user_id)) => UserId::Id(user_id),
             Ok(None) => return require_link(&ctx, &orig).await,
             Err(err) => {
-                let _ = orig.error(&ctx, GENERAL_ISSUE).await;
+                let _ = orig.error_localized(&ctx, ErrorKey::General).await;
 
                 return Err(err);
             }
@@ -91,7 +88,7 @@ pub(super) async fn player_stats(
             return orig.error(&ctx, content).await;
         }
         Err(err) => {
-            let _ = orig.error(&ctx, OSU_API_ISSUE).await;
+            let _ = orig.error_localized(&ctx, ErrorKey::OsuApi).await;
             let report = Report::new(err).wrap_err("failed to get user");
 
             return Err(report);
@@ -115,8 +112,8 @@ pub(super) async fn player_stats(
         }
     };
 
-    let player_fut = if ctx.huismetbenen().is_supported(country_code).await {
-        ctx.client().get_snipe_player(country_code, user_id)
+    let player_fut = if ctx.snipe().is_supported(country_code).await {
+        ctx.snipe().player(country_code, user_id)
     } else {
         let content = format!("`{username}`'s country {country_code} is not supported :(");
 
@@ -133,7 +130,7 @@ pub(super) async fn player_stats(
             return Ok(());
         }
         Err(err) => {
-            let _ = orig.error(&ctx, HUISMETBENEN_ISSUE).await;
+            let _ = orig.error_localized(&ctx, ErrorKey::Huismetbenen).await;
 
             return Err(err);
         }
@@ -158,12 +155,12 @@ pub(super) async fn player_stats(
     let (oldest_score, oldest_map) = match tokio::join!(score_fut, map_fut) {
         (Ok(score), Ok(map)) => (score.score, map),
         (Err(err), _) => {
-            let _ = orig.error(&ctx, OSU_API_ISSUE).await;
+            let _ = orig.error_localized(&ctx, ErrorKey::OsuApi).await;
 
             return Err(Report::new(err).wrap_err("Failed to get oldest score"));
         }
         (_, Err(err)) => {
-            let _ = orig.error(&ctx, GENERAL_ISSUE).await;
+            let _ = orig.error_localized(&ctx, ErrorKey::General).await;
 
             return Err(Report::new(err).wrap_err("Failed to get map of oldest score"));
         }
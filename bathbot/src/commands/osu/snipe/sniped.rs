@@ -6,11 +6,7 @@ use std::{
 
 use bathbot_macros::command;
 use bathbot_model::SnipeRecent;
-use bathbot_util::{
-    constants::{GENERAL_ISSUE, HUISMETBENEN_ISSUE, OSU_API_ISSUE},
-    datetime::DATE_FORMAT,
-    matcher, IntHasher, MessageBuilder,
-};
+use bathbot_util::{datetime::DATE_FORMAT, matcher, IntHasher, MessageBuilder};
 use eyre::{ContextCompat, Report, Result, WrapErr};
 use itertools::Itertools;
 use plotters::{
@@ -30,7 +26,7 @@ use twilight_model::guild::Permissions;
 use super::SnipePlayerSniped;
 use crate::{
     commands::osu::require_link,
-    core::commands::CommandOrigin,
+    core::{commands::CommandOrigin, locale::ErrorKey},
     embeds::{EmbedData, SnipedEmbed},
     manager::redis::{osu::UserArgs, RedisData},
     Context,
@@ -81,7 +77,7 @@ pub(super) async fn player_sniped(
             Ok(Some(user_id)) => UserId::Id(user_id),
             Ok(None) => return require_link(&ctx, &orig).await,
             Err(err) => {
-                let _ = orig.error(&ctx, GENERAL_ISSUE).await;
+                let _ = orig.error_localized(&ctx, ErrorKey::General).await;
 
                 return Err(err);
             }
@@ -101,14 +97,14 @@ pub(super) async fn player_sniped(
             return orig.error(&ctx, content).await;
         }
         Err(err) => {
-            let _ = orig.error(&ctx, OSU_API_ISSUE).await;
+            let _ = orig.error_localized(&ctx, ErrorKey::OsuApi).await;
             let err = Report::new(err).wrap_err("failed to get user");
 
             return Err(err);
         }
     };
 
-    let client = &ctx.client();
+    let snipe = ctx.snipe();
     let now = OffsetDateTime::now_utc();
 
     let (user_id, username, country_code) = match &user {
@@ -124,9 +120,10 @@ pub(super) async fn player_sniped(
         ),
     };
 
-    let (sniper, snipee) = if ctx.huismetbenen().is_supported(country_code).await {
-        let sniper_fut = client.get_national_snipes(user_id, true, now - Duration::weeks(8), now);
-        let snipee_fut = client.get_national_snipes(user_id, false, now - Duration::weeks(8), now);
+    let (sniper, snipee) = if snipe.is_supported(country_code).await {
+        let from = now - Duration::weeks(8);
+        let sniper_fut = snipe.national_snipes(country_code, user_id, true, from, now);
+        let snipee_fut = snipe.national_snipes(country_code, user_id, false, from, now);
 
         match tokio::try_join!(sniper_fut, snipee_fut) {
             Ok((mut sniper, snipee)) => {
@@ -135,7 +132,7 @@ pub(super) async fn player_sniped(
                 (sniper, snipee)
             }
             Err(err) => {
-                let _ = orig.error(&ctx, HUISMETBENEN_ISSUE).await;
+                let _ = orig.error_localized(&ctx, ErrorKey::Huismetbenen).await;
 
                 return Err(err.wrap_err("failed to get sniper or snipee"));
             }
@@ -1,10 +1,7 @@
 use std::{cmp::Reverse, collections::HashMap, sync::Arc};
 
 use bathbot_macros::command;
-use bathbot_util::{
-    constants::{GENERAL_ISSUE, HUISMETBENEN_ISSUE, OSU_API_ISSUE},
-    matcher, IntHasher, MessageBuilder,
-};
+use bathbot_util::{matcher, IntHasher, MessageBuilder};
 use eyre::{Report, Result};
 use rosu_v2::{prelude::OsuError, request::UserId};
 use time::{Duration, OffsetDateTime};
@@ -13,7 +10,7 @@ use super::{SnipePlayerGain, SnipePlayerLoss};
 use crate::{
     active::{impls::SnipeDifferencePagination, ActiveMessages},
     commands::osu::require_link,
-    core::commands::CommandOrigin,
+    core::{commands::CommandOrigin, locale::ErrorKey},
     manager::redis::{osu::UserArgs, RedisData},
     Context,
 };
@@ -117,7 +114,7 @@ async fn sniped_diff(
             Ok(Some(user_id)) => UserId::Id(user_id),
             Ok(None) => return require_link(&ctx, &orig).await,
             Err(err) => {
-                let _ = orig.error(&ctx, GENERAL_ISSUE).await;
+                let _ = orig.error_localized(&ctx, ErrorKey::General).await;
 
                 return Err(err);
             }
@@ -138,7 +135,7 @@ async fn sniped_diff(
             return orig.error(&ctx, content).await;
         }
         Err(err) => {
-            let _ = orig.error(&ctx, OSU_API_ISSUE).await;
+            let _ = orig.error_localized(&ctx, ErrorKey::OsuApi).await;
             let report = Report::new(err).wrap_err("failed to get user");
 
             return Err(report);
@@ -162,26 +159,25 @@ async fn sniped_diff(
         }
     };
 
-    if !ctx.huismetbenen().is_supported(country_code).await {
+    if !ctx.snipe().is_supported(country_code).await {
         let content = format!("`{username}`'s country {country_code} is not supported :(");
 
         return orig.error(&ctx, content).await;
     }
 
-    let client = &ctx.client();
     let now = OffsetDateTime::now_utc();
     let week_ago = now - Duration::weeks(1);
+    let sniper = matches!(diff, Difference::Gain);
 
     // Request the scores
-    let scores_fut = match diff {
-        Difference::Gain => client.get_national_snipes(user_id, true, week_ago, now),
-        Difference::Loss => client.get_national_snipes(user_id, false, week_ago, now),
-    };
+    let scores_fut = ctx
+        .snipe()
+        .national_snipes(country_code, user_id, sniper, week_ago, now);
 
     let mut scores = match scores_fut.await {
         Ok(scores) => scores,
         Err(err) => {
-            let _ = orig.error(&ctx, HUISMETBENEN_ISSUE).await;
+            let _ = orig.error_localized(&ctx, ErrorKey::Huismetbenen).await;
 
             return Err(err.wrap_err("failed to get snipes"));
         }
@@ -3,13 +3,7 @@ use std::{borrow::Cow, cmp::Reverse, collections::HashMap, fmt::Write, mem, sync
 use bathbot_macros::{command, HasMods, HasName, SlashCommand};
 use bathbot_model::{OsuTrackerMapsetEntry, ScoreSlim};
 use bathbot_psql::model::configs::{GuildConfig, ListSize, MinimizedPp, ScoreSize};
-use bathbot_util::{
-    constants::{GENERAL_ISSUE, OSUTRACKER_ISSUE, OSU_API_ISSUE},
-    matcher,
-    numbers::round,
-    osu::ModSelection,
-    CowUtils, IntHasher,
-};
+use bathbot_util::{matcher, numbers::round, osu::ModSelection, CowUtils, IntHasher};
 use eyre::{Report, Result};
 use rand::{thread_rng, Rng};
 use rkyv::{Deserialize, Infallible};
@@ -39,7 +33,10 @@ use crate::{
         ActiveMessages,
     },
     commands::{GameModeOption, GradeOption},
-    core::commands::{prefix::Args, CommandOrigin},
+    core::{
+        commands::{prefix::Args, CommandOrigin},
+        locale::ErrorKey,
+    },
     manager::{
         redis::{osu::UserArgs, RedisData},
         OsuMap, OwnedReplayScore,
@@ -754,7 +751,7 @@ pub(super) async fn top(
     let mut config = match ctx.user_config().with_osu_id(msg_owner).await {
         Ok(config) => config,
         Err(err) => {
-            let _ = orig.error(&ctx, GENERAL_ISSUE).await;
+            let _ = orig.error_localized(&ctx, ErrorKey::General).await;
 
             return Err(err);
         }
@@ -853,7 +850,7 @@ pub(super) async fn top(
             return orig.error(&ctx, content).await;
         }
         Err(err) => {
-            let _ = orig.error(&ctx, OSU_API_ISSUE).await;
+            let _ = orig.error_localized(&ctx, ErrorKey::OsuApi).await;
             let err = Report::new(err).wrap_err("failed to get user or scores");
 
             return Err(err);
@@ -863,7 +860,7 @@ pub(super) async fn top(
     let farm = match farm_res {
         Some(Ok(mapsets)) => mapsets,
         Some(Err(err)) => {
-            let _ = orig.error(&ctx, OSUTRACKER_ISSUE).await;
+            let _ = orig.error_localized(&ctx, ErrorKey::Osutracker).await;
 
             return Err(err.wrap_err("failed to get farm"));
         }
@@ -876,7 +873,7 @@ pub(super) async fn top(
     let entries = match process_scores(&ctx, scores, &args, &farm).await {
         Ok(entries) => entries,
         Err(err) => {
-            let _ = orig.error(&ctx, GENERAL_ISSUE).await;
+            let _ = orig.error_localized(&ctx, ErrorKey::General).await;
 
             return Err(err.wrap_err("failed to process scores"));
         }
@@ -2,12 +2,7 @@ use std::{borrow::Cow, cmp::Ordering, sync::Arc};
 
 use bathbot_macros::{command, HasMods, HasName, SlashCommand};
 use bathbot_model::ScoreSlim;
-use bathbot_util::{
-    constants::{GENERAL_ISSUE, OSU_API_ISSUE},
-    matcher,
-    numbers::round,
-    osu::ModSelection,
-};
+use bathbot_util::{matcher, numbers::round, osu::ModSelection};
 use eyre::{Report, Result};
 use rosu_pp_older::*;
 use rosu_v2::{
@@ -22,7 +17,10 @@ use super::TopIfEntry;
 use crate::{
     active::{impls::TopIfPagination, ActiveMessages},
     commands::osu::{require_link, user_not_found, HasMods, ModsResult, TopIfScoreOrder},
-    core::commands::{prefix::Args, CommandOrigin},
+    core::{
+        commands::{prefix::Args, CommandOrigin},
+        locale::ErrorKey,
+    },
     manager::{redis::osu::UserArgs, OsuMap},
     util::{
         interaction::InteractionCommand,
@@ -707,8 +705,8 @@ macro_rules! user_id_ref {
                     return $orig.error(&$ctx, content).await;
                 }
                 crate::commands::osu::UserIdFutureResult::Err(err) => {
-                    let content = bathbot_util::constants::GENERAL_ISSUE;
-                    let _ = $orig.error(&$ctx, content).await;
+                    let key = crate::core::locale::ErrorKey::General;
+                    let _ = $orig.error_localized(&$ctx, key).await;
 
                     return Err(err);
                 }
@@ -743,7 +741,7 @@ async fn topold(ctx: Arc<Context>, orig: CommandOrigin<'_>, args: TopOld<'_>) ->
             Ok(Some(user_id)) => UserId::Id(user_id),
             Ok(None) => return require_link(&ctx, &orig).await,
             Err(err) => {
-                let _ = orig.error(&ctx, GENERAL_ISSUE).await;
+                let _ = orig.error_localized(&ctx, ErrorKey::General).await;
 
                 return Err(err);
             }
@@ -762,7 +760,7 @@ async fn topold(ctx: Arc<Context>, orig: CommandOrigin<'_>, args: TopOld<'_>) ->
             return orig.error(&ctx, content).await;
         }
         Err(err) => {
-            let _ = orig.error(&ctx, OSU_API_ISSUE).await;
+            let _ = orig.error_localized(&ctx, ErrorKey::OsuApi).await;
             let err = Report::new(err).wrap_err("failed to get user or scores");
 
             return Err(err);
@@ -782,7 +780,7 @@ async fn topold(ctx: Arc<Context>, orig: CommandOrigin<'_>, args: TopOld<'_>) ->
     let mut entries = match process_scores(&ctx, scores, &args).await {
         Ok(scores) => scores,
         Err(err) => {
-            let _ = orig.error(&ctx, GENERAL_ISSUE).await;
+            let _ = orig.error_localized(&ctx, ErrorKey::General).await;
 
             return Err(err.wrap_err("failed to process scores"));
         }
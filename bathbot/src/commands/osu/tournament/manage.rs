@@ -0,0 +1,350 @@
+use std::{fmt::Write, sync::Arc};
+
+use bathbot_psql::model::tournament::DbTournamentPlayer;
+use bathbot_util::MessageBuilder;
+use eyre::{Report, Result};
+use rosu_v2::prelude::OsuError;
+
+use super::{
+    get_tournament, TournamentCreate, TournamentDelete, TournamentTeamAdd, TournamentTeamRemove,
+    TournamentTeams, NAME_MAX_LEN,
+};
+use crate::{
+    active::{impls::TournamentTeamsPagination, ActiveMessages},
+    core::locale::ErrorKey,
+    manager::redis::osu::UserArgs,
+    util::{interaction::InteractionCommand, require_authority, Authored, InteractionCommandExt},
+    Context,
+};
+
+const MAX_TOURNAMENTS: usize = 10;
+const MAX_PLAYERS_PER_ADD: usize = 16;
+
+/// A team alongside the ids and names of its players
+pub struct TeamPlayers {
+    pub name: String,
+    pub players: Vec<(u32, String)>,
+}
+
+impl TeamPlayers {
+    /// Groups players that are sorted by their team.
+    fn group(players: Vec<DbTournamentPlayer>) -> Vec<Self> {
+        let mut teams: Vec<Self> = Vec::new();
+
+        for player in players {
+            let entry = (player.user_id as u32, player.username);
+
+            match teams.last_mut() {
+                Some(team) if team.name == player.team => team.players.push(entry),
+                _ => teams.push(Self {
+                    name: player.team,
+                    players: vec![entry],
+                }),
+            }
+        }
+
+        teams
+    }
+}
+
+pub(super) async fn create(
+    ctx: Arc<Context>,
+    command: InteractionCommand,
+    args: TournamentCreate,
+) -> Result<()> {
+    if !require_authority(&ctx, &command).await? {
+        return Ok(());
+    }
+
+    let guild_id = command.guild_id.unwrap();
+    let name = args.name.trim();
+
+    if name.is_empty() || name.chars().count() > NAME_MAX_LEN {
+        let content = format!("The name must be between 1 and {NAME_MAX_LEN} characters long");
+        command.error(&ctx, content).await?;
+
+        return Ok(());
+    }
+
+    let tournaments = match ctx.tournament().tournaments(guild_id).await {
+        Ok(tournaments) => tournaments,
+        Err(err) => {
+            let _ = command.error_localized(&ctx, ErrorKey::General).await;
+
+            return Err(err);
+        }
+    };
+
+    if tournaments.len() >= MAX_TOURNAMENTS {
+        let content = format!(
+            "Servers can have at most {MAX_TOURNAMENTS} tournaments. \
+            Delete one via `/tournament delete` first."
+        );
+        command.error(&ctx, content).await?;
+
+        return Ok(());
+    }
+
+    match ctx.tournament().create(guild_id, args.channel, name).await {
+        Ok(true) => {
+            let content = format!(
+                "Created tournament `{name}`, reminders and results will be posted in <#{}>.\n\
+                Register teams via `/tournament team add`.",
+                args.channel
+            );
+
+            let builder = MessageBuilder::new().embed(content);
+            command.update(&ctx, builder).await?;
+
+            Ok(())
+        }
+        Ok(false) => {
+            let content = format!("There already is a tournament called `{name}`");
+            command.error(&ctx, content).await?;
+
+            Ok(())
+        }
+        Err(err) => {
+            let _ = command.error_localized(&ctx, ErrorKey::General).await;
+
+            Err(err)
+        }
+    }
+}
+
+pub(super) async fn delete(
+    ctx: Arc<Context>,
+    command: InteractionCommand,
+    args: TournamentDelete,
+) -> Result<()> {
+    if !require_authority(&ctx, &command).await? {
+        return Ok(());
+    }
+
+    let Some(tournament) = get_tournament(&ctx, &command, &args.name).await? else {
+        return Ok(());
+    };
+
+    let matches = match ctx.tournament().matches(tournament.tournament_id).await {
+        Ok(matches) => matches,
+        Err(err) => {
+            let _ = command.error_localized(&ctx, ErrorKey::General).await;
+
+            return Err(err);
+        }
+    };
+
+    for reminder_id in matches.iter().filter_map(|entry| entry.reminder_id) {
+        if let Err(err) = ctx.schedule().remove(reminder_id).await {
+            warn!(?err, "Failed to remove tournament match reminder");
+        }
+    }
+
+    if let Err(err) = ctx.tournament().delete(tournament.tournament_id).await {
+        let _ = command.error_localized(&ctx, ErrorKey::General).await;
+
+        return Err(err);
+    }
+
+    let content = format!("Deleted tournament `{}`", tournament.name);
+    let builder = MessageBuilder::new().embed(content);
+    command.update(&ctx, builder).await?;
+
+    Ok(())
+}
+
+pub(super) async fn list(ctx: Arc<Context>, command: InteractionCommand) -> Result<()> {
+    let guild_id = command.guild_id.unwrap();
+
+    let tournaments = match ctx.tournament().tournaments(guild_id).await {
+        Ok(tournaments) => tournaments,
+        Err(err) => {
+            let _ = command.error_localized(&ctx, ErrorKey::General).await;
+
+            return Err(err);
+        }
+    };
+
+    let content = if tournaments.is_empty() {
+        "This server has no tournaments yet, create one via `/tournament create`".to_owned()
+    } else {
+        let mut content = String::with_capacity(tournaments.len() * 64);
+
+        for tournament in tournaments {
+            let _ = writeln!(
+                content,
+                "`{}` • <#{}> • Created <t:{}:d>",
+                tournament.name,
+                tournament.channel_id,
+                tournament.created_at.unix_timestamp(),
+            );
+        }
+
+        content
+    };
+
+    let builder = MessageBuilder::new().embed(content);
+    command.update(&ctx, builder).await?;
+
+    Ok(())
+}
+
+pub(super) async fn teams(
+    ctx: Arc<Context>,
+    mut command: InteractionCommand,
+    args: TournamentTeams,
+) -> Result<()> {
+    let Some(tournament) = get_tournament(&ctx, &command, &args.tournament).await? else {
+        return Ok(());
+    };
+
+    let players = match ctx.tournament().players(tournament.tournament_id).await {
+        Ok(players) => players,
+        Err(err) => {
+            let _ = command.error_localized(&ctx, ErrorKey::General).await;
+
+            return Err(err);
+        }
+    };
+
+    let pagination = TournamentTeamsPagination::builder()
+        .teams(TeamPlayers::group(players).into_boxed_slice())
+        .tournament(tournament.name.into_boxed_str())
+        .msg_owner(command.user_id()?)
+        .build();
+
+    ActiveMessages::builder(pagination)
+        .start_by_update(true)
+        .begin(ctx, &mut command)
+        .await
+}
+
+pub(super) async fn team_add(
+    ctx: Arc<Context>,
+    command: InteractionCommand,
+    args: TournamentTeamAdd,
+) -> Result<()> {
+    if !require_authority(&ctx, &command).await? {
+        return Ok(());
+    }
+
+    let team = args.team.trim();
+
+    if team.is_empty() || team.chars().count() > NAME_MAX_LEN {
+        let content = format!("The team name must be between 1 and {NAME_MAX_LEN} characters long");
+        command.error(&ctx, content).await?;
+
+        return Ok(());
+    }
+
+    let names: Vec<_> = args
+        .players
+        .split(',')
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .collect();
+
+    if names.is_empty() || names.len() > MAX_PLAYERS_PER_ADD {
+        let content = format!("You can add between 1 and {MAX_PLAYERS_PER_ADD} players at once");
+        command.error(&ctx, content).await?;
+
+        return Ok(());
+    }
+
+    let Some(tournament) = get_tournament(&ctx, &command, &args.tournament).await? else {
+        return Ok(());
+    };
+
+    let mut users = Vec::with_capacity(names.len());
+
+    for name in names {
+        let user_args = UserArgs::username(&ctx, name).await;
+
+        match ctx.redis().osu_user(user_args).await {
+            Ok(user) => users.push((user.user_id(), user.username().to_owned())),
+            Err(OsuError::NotFound) => {
+                let content = format!("User `{name}` was not found");
+                command.error(&ctx, content).await?;
+
+                return Ok(());
+            }
+            Err(err) => {
+                let _ = command.error_localized(&ctx, ErrorKey::OsuApi).await;
+
+                return Err(Report::new(err).wrap_err("Failed to get user"));
+            }
+        }
+    }
+
+    let players: Vec<_> = users
+        .iter()
+        .map(|(user_id, username)| (*user_id, username.as_str()))
+        .collect();
+
+    let add_fut = ctx
+        .tournament()
+        .add_team(tournament.tournament_id, team, &players);
+
+    if let Err(err) = add_fut.await {
+        let _ = command.error_localized(&ctx, ErrorKey::General).await;
+
+        return Err(err);
+    }
+
+    let mut content = format!("Added to team `{team}`: ");
+    let mut names = users.iter().map(|(_, username)| username);
+
+    if let Some(name) = names.next() {
+        let _ = write!(content, "`{name}`");
+
+        for name in names {
+            let _ = write!(content, ", `{name}`");
+        }
+    }
+
+    let builder = MessageBuilder::new().embed(content);
+    command.update(&ctx, builder).await?;
+
+    Ok(())
+}
+
+pub(super) async fn team_remove(
+    ctx: Arc<Context>,
+    command: InteractionCommand,
+    args: TournamentTeamRemove,
+) -> Result<()> {
+    if !require_authority(&ctx, &command).await? {
+        return Ok(());
+    }
+
+    let Some(tournament) = get_tournament(&ctx, &command, &args.tournament).await? else {
+        return Ok(());
+    };
+
+    let team = args.team.trim();
+
+    match ctx
+        .tournament()
+        .remove_team(tournament.tournament_id, team)
+        .await
+    {
+        Ok(0) => {
+            let content = format!("The tournament has no team called `{team}`");
+            command.error(&ctx, content).await?;
+
+            Ok(())
+        }
+        Ok(_) => {
+            let content = format!("Removed team `{team}` from the tournament");
+            let builder = MessageBuilder::new().embed(content);
+            command.update(&ctx, builder).await?;
+
+            Ok(())
+        }
+        Err(err) => {
+            let _ = command.error_localized(&ctx, ErrorKey::General).await;
+
+            Err(err)
+        }
+    }
+}
@@ -0,0 +1,470 @@
+use std::{fmt::Write, sync::Arc};
+
+use bathbot_psql::model::tournament::{DbTournament, DbTournamentMatch, DbTournamentPlayer};
+use bathbot_util::{constants::OSU_BASE, matcher, MessageBuilder};
+use eyre::{Report, Result};
+use rosu_v2::prelude::OsuError;
+use time::{Duration, OffsetDateTime, UtcOffset};
+use twilight_model::id::Id;
+
+use super::{
+    get_tournament, TournamentMatchCancel, TournamentMatchLink, TournamentMatchReferee,
+    TournamentMatchSchedule, TournamentMatches, NAME_MAX_LEN,
+};
+use crate::{
+    active::{impls::TournamentMatchesPagination, ActiveMessages},
+    commands::utility::parse_time,
+    core::locale::ErrorKey,
+    tracking::{match_result, MatchTeams},
+    util::{interaction::InteractionCommand, require_authority, Authored, InteractionCommandExt},
+    Context,
+};
+
+/// How long before a match its players are reminded
+const REMINDER_AHEAD: Duration = Duration::minutes(15);
+
+/// Maximum length of a scheduled message
+const MAX_REMINDER_LEN: usize = 1000;
+
+pub(super) async fn matches(
+    ctx: Arc<Context>,
+    mut command: InteractionCommand,
+    args: TournamentMatches,
+) -> Result<()> {
+    let Some(tournament) = get_tournament(&ctx, &command, &args.tournament).await? else {
+        return Ok(());
+    };
+
+    let matches = match ctx.tournament().matches(tournament.tournament_id).await {
+        Ok(matches) => matches,
+        Err(err) => {
+            let _ = command.error_localized(&ctx, ErrorKey::General).await;
+
+            return Err(err);
+        }
+    };
+
+    let pagination = TournamentMatchesPagination::builder()
+        .matches(matches.into_boxed_slice())
+        .tournament(tournament.name.into_boxed_str())
+        .msg_owner(command.user_id()?)
+        .build();
+
+    ActiveMessages::builder(pagination)
+        .start_by_update(true)
+        .begin(ctx, &mut command)
+        .await
+}
+
+pub(super) async fn schedule(
+    ctx: Arc<Context>,
+    command: InteractionCommand,
+    args: TournamentMatchSchedule,
+) -> Result<()> {
+    if !require_authority(&ctx, &command).await? {
+        return Ok(());
+    }
+
+    let user_id = command.user_id()?;
+    let round = args.round.trim();
+    let team_a = args.team_a.trim();
+    let team_b = args.team_b.trim();
+
+    if round.is_empty() || round.chars().count() > NAME_MAX_LEN {
+        let content = format!("The round must be between 1 and {NAME_MAX_LEN} characters long");
+        command.error(&ctx, content).await?;
+
+        return Ok(());
+    }
+
+    if team_a == team_b {
+        command
+            .error(&ctx, "A team can't play against itself")
+            .await?;
+
+        return Ok(());
+    }
+
+    let Some(tournament) = get_tournament(&ctx, &command, &args.tournament).await? else {
+        return Ok(());
+    };
+
+    let tz = match ctx.user_config().timezone(user_id).await {
+        Ok(tz) => tz.unwrap_or(UtcOffset::UTC),
+        Err(err) => {
+            let _ = command.error_localized(&ctx, ErrorKey::General).await;
+
+            return Err(err);
+        }
+    };
+
+    let now = OffsetDateTime::now_utc();
+
+    let scheduled_at = match parse_time(&args.when, now, tz) {
+        Ok(scheduled_at) => scheduled_at,
+        Err(content) => {
+            command.error(&ctx, content).await?;
+
+            return Ok(());
+        }
+    };
+
+    let players = match ctx.tournament().players(tournament.tournament_id).await {
+        Ok(players) => players,
+        Err(err) => {
+            let _ = command.error_localized(&ctx, ErrorKey::General).await;
+
+            return Err(err);
+        }
+    };
+
+    if let Some(team) = [team_a, team_b]
+        .into_iter()
+        .find(|team| players.iter().all(|player| player.team != *team))
+    {
+        let content = format!(
+            "The tournament has no team called `{team}`, register it via `/tournament team add`"
+        );
+        command.error(&ctx, content).await?;
+
+        return Ok(());
+    }
+
+    let add_fut = ctx.tournament().add_match(
+        tournament.tournament_id,
+        round,
+        team_a,
+        team_b,
+        scheduled_at,
+    );
+
+    let match_id = match add_fut.await {
+        Ok(match_id) => match_id,
+        Err(err) => {
+            let _ = command.error_localized(&ctx, ErrorKey::General).await;
+
+            return Err(err);
+        }
+    };
+
+    let remind_at = scheduled_at - REMINDER_AHEAD;
+
+    let reminder_note = if remind_at > now {
+        // Mention all players that linked their discord account
+        let mut mentions = String::new();
+
+        for player in players.iter() {
+            if player.team != team_a && player.team != team_b {
+                continue;
+            }
+
+            match ctx
+                .user_config()
+                .discord_from_osu_id(player.user_id as u32)
+                .await
+            {
+                Ok(Some(discord_id)) => {
+                    let _ = write!(mentions, "<@{discord_id}> ");
+                }
+                Ok(None) => {}
+                Err(err) => warn!(?err, "Failed to get discord id of tournament player"),
+            }
+        }
+
+        let mut content = format!(
+            "**{name}** • {round}: `{team_a}` vs `{team_b}` starts <t:{timestamp}:R>",
+            name = tournament.name,
+            timestamp = scheduled_at.unix_timestamp(),
+        );
+
+        if !mentions.is_empty() && content.len() + mentions.len() < MAX_REMINDER_LEN {
+            content.push('\n');
+            content.push_str(mentions.trim_end());
+        }
+
+        let guild_id = command.guild_id.unwrap();
+        let channel_id = Id::new(tournament.channel_id as u64);
+
+        let reminder_fut = ctx
+            .schedule()
+            .add_announcement(user_id, guild_id, channel_id, &content, remind_at);
+
+        match reminder_fut.await {
+            Ok(reminder_id) => {
+                if let Err(err) = ctx
+                    .tournament()
+                    .set_reminder(match_id, Some(reminder_id))
+                    .await
+                {
+                    warn!(?err, "Failed to store tournament match reminder");
+                }
+
+                format!(
+                    "\nPlayers will be reminded in <#{}>.",
+                    tournament.channel_id
+                )
+            }
+            Err(err) => {
+                warn!(?err, "Failed to schedule tournament match reminder");
+
+                String::new()
+            }
+        }
+    } else {
+        String::new()
+    };
+
+    let content = format!(
+        "Scheduled match `{match_id}` ({round}): `{team_a}` vs `{team_b}` \
+        <t:{timestamp}:F>.{reminder_note}",
+        timestamp = scheduled_at.unix_timestamp(),
+    );
+
+    let builder = MessageBuilder::new().embed(content);
+    command.update(&ctx, builder).await?;
+
+    Ok(())
+}
+
+pub(super) async fn link(
+    ctx: Arc<Context>,
+    command: InteractionCommand,
+    args: TournamentMatchLink,
+) -> Result<()> {
+    if !require_authority(&ctx, &command).await? {
+        return Ok(());
+    }
+
+    let Some(mp_id) = matcher::get_osu_match_id(&args.mp) else {
+        let content = "Failed to parse match url.\n\
+            Be sure it's a valid mp url or a match id.";
+        command.error(&ctx, content).await?;
+
+        return Ok(());
+    };
+
+    let Some(tournament_match) = get_match(&ctx, &command, args.id).await? else {
+        return Ok(());
+    };
+
+    let players = match ctx
+        .tournament()
+        .players(tournament_match.tournament_id)
+        .await
+    {
+        Ok(players) => players,
+        Err(err) => {
+            let _ = command.error_localized(&ctx, ErrorKey::General).await;
+
+            return Err(err);
+        }
+    };
+
+    let teams = MatchTeams::new(&tournament_match, &players);
+    let warmups = args.warmups.map_or(0, |warmups| warmups.clamp(0, 10) as u8);
+
+    let result = match match_result(ctx.osu(), mp_id, warmups as usize, &teams).await {
+        Ok(result) => result,
+        Err(OsuError::NotFound) => {
+            let content = format!("No match with id `{mp_id}` was found");
+            command.error(&ctx, content).await?;
+
+            return Ok(());
+        }
+        Err(OsuError::Response { status, .. }) if status == 401 => {
+            let content = "I can't access the match because it was set as private";
+            command.error(&ctx, content).await?;
+
+            return Ok(());
+        }
+        Err(err) => {
+            let _ = command.error_localized(&ctx, ErrorKey::OsuApi).await;
+
+            return Err(Report::new(err).wrap_err("Failed to get match result"));
+        }
+    };
+
+    let match_id = tournament_match.match_id;
+
+    if let Err(err) = ctx.tournament().link_mp(match_id, mp_id, warmups).await {
+        let _ = command.error_localized(&ctx, ErrorKey::General).await;
+
+        return Err(err);
+    }
+
+    let DbTournamentMatch { team_a, team_b, .. } = tournament_match;
+
+    let mut content = format!(
+        "Linked [lobby]({OSU_BASE}community/matches/{mp_id}) to match `{match_id}`: \
+        `{team_a}` vs `{team_b}`\n"
+    );
+
+    if let Some((score_a, score_b)) = result {
+        if let Err(err) = ctx
+            .tournament()
+            .set_result(match_id, score_a, score_b)
+            .await
+        {
+            let _ = command.error_localized(&ctx, ErrorKey::General).await;
+
+            return Err(err);
+        }
+
+        let _ = write!(
+            content,
+            "The lobby is already closed, result: **{team_a}** {score_a} - {score_b} **{team_b}**"
+        );
+    } else {
+        content.push_str("The result will be posted once the lobby is closed.");
+    }
+
+    let builder = MessageBuilder::new().embed(content);
+    command.update(&ctx, builder).await?;
+
+    Ok(())
+}
+
+pub(super) async fn referee(
+    ctx: Arc<Context>,
+    command: InteractionCommand,
+    args: TournamentMatchReferee,
+) -> Result<()> {
+    let Some(tournament_match) = get_match(&ctx, &command, args.id).await? else {
+        return Ok(());
+    };
+
+    let tournament_id = tournament_match.tournament_id;
+
+    let players_fut = ctx.tournament().players(tournament_id);
+    let tournament_fut = ctx.tournament().tournament_by_id(tournament_id);
+
+    let (players, tournament) = match tokio::try_join!(players_fut, tournament_fut) {
+        Ok((players, Some(tournament))) => (players, tournament),
+        Ok((_, None)) => {
+            command
+                .error(&ctx, "The tournament no longer exists")
+                .await?;
+
+            return Ok(());
+        }
+        Err(err) => {
+            let _ = command.error_localized(&ctx, ErrorKey::General).await;
+
+            return Err(err);
+        }
+    };
+
+    let content = referee_commands(&tournament, &tournament_match, &players);
+    let builder = MessageBuilder::new().embed(content);
+    command.update(&ctx, builder).await?;
+
+    Ok(())
+}
+
+fn referee_commands(
+    tournament: &DbTournament,
+    tournament_match: &DbTournamentMatch,
+    players: &[DbTournamentPlayer],
+) -> String {
+    let DbTournamentMatch {
+        match_id,
+        team_a,
+        team_b,
+        ..
+    } = tournament_match;
+
+    let match_players: Vec<_> = players
+        .iter()
+        .filter(|player| player.team == *team_a || player.team == *team_b)
+        .collect();
+
+    let mut content = format!(
+        "Lobby commands for match `{match_id}`:\n\
+        ```\n\
+        !mp make {name}: ({team_a}) vs ({team_b})\n\
+        !mp set 2 3 {slots}\n",
+        name = tournament.name,
+        // Team vs, ScoreV2, one slot per registered player
+        slots = match_players.len().clamp(2, 16),
+    );
+
+    for player in match_players {
+        let _ = writeln!(content, "!mp invite {}", player.username.replace(' ', "_"));
+    }
+
+    content.push_str(
+        "```\n\
+        Once the lobby is created, link it via `/tournament match link`.",
+    );
+
+    content
+}
+
+pub(super) async fn cancel(
+    ctx: Arc<Context>,
+    command: InteractionCommand,
+    args: TournamentMatchCancel,
+) -> Result<()> {
+    if !require_authority(&ctx, &command).await? {
+        return Ok(());
+    }
+
+    let Some(tournament_match) = get_match(&ctx, &command, args.id).await? else {
+        return Ok(());
+    };
+
+    if let Some(reminder_id) = tournament_match.reminder_id {
+        if let Err(err) = ctx.schedule().remove(reminder_id).await {
+            warn!(?err, "Failed to remove tournament match reminder");
+        }
+    }
+
+    if let Err(err) = ctx
+        .tournament()
+        .remove_match(tournament_match.match_id)
+        .await
+    {
+        let _ = command.error_localized(&ctx, ErrorKey::General).await;
+
+        return Err(err);
+    }
+
+    let content = format!(
+        "Cancelled match `{}`: `{}` vs `{}`",
+        tournament_match.match_id, tournament_match.team_a, tournament_match.team_b
+    );
+
+    let builder = MessageBuilder::new().embed(content);
+    command.update(&ctx, builder).await?;
+
+    Ok(())
+}
+
+/// Returns `None` if no tournament of the guild has a match with the given id
+/// in which case the author has already been notified.
+async fn get_match(
+    ctx: &Context,
+    command: &InteractionCommand,
+    match_id: i64,
+) -> Result<Option<DbTournamentMatch>> {
+    let guild_id = command.guild_id.unwrap();
+    let match_id = i32::try_from(match_id).unwrap_or(i32::MAX);
+
+    match ctx.tournament().get_match(guild_id, match_id).await {
+        Ok(Some(tournament_match)) => Ok(Some(tournament_match)),
+        Ok(None) => {
+            let content = format!(
+                "There is no tournament match with id `{match_id}` on this server, \
+                check `/tournament matches`"
+            );
+            command.error(ctx, content).await?;
+
+            Ok(None)
+        }
+        Err(err) => {
+            let _ = command.error_localized(ctx, ErrorKey::General).await;
+
+            Err(err)
+        }
+    }
+}
@@ -0,0 +1,265 @@
+use std::sync::Arc;
+
+use bathbot_macros::SlashCommand;
+use bathbot_psql::model::tournament::DbTournament;
+use eyre::Result;
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::id::{marker::ChannelMarker, Id};
+
+pub use self::{manage::TeamPlayers, standings::TournamentStanding};
+use crate::{
+    core::locale::ErrorKey,
+    util::{interaction::InteractionCommand, InteractionCommandExt},
+    Context,
+};
+
+mod manage;
+mod matches;
+mod standings;
+
+const NAME_MAX_LEN: usize = 32;
+
+#[derive(CommandModel, CreateCommand, SlashCommand)]
+#[command(
+    name = "tournament",
+    dm_permission = false,
+    desc = "Organize tournaments on this server",
+    help = "Organize tournaments with teams, scheduled matches, and standings.\n\
+    Creating or deleting tournaments, managing teams, and scheduling or linking matches \
+    requires authority status.\n\n\
+    A typical workflow looks like this:\n\
+    1. Create a tournament via `/tournament create`\n\
+    2. Register teams and their players via `/tournament team add`\n\
+    3. Schedule matches via `/tournament match schedule`. \
+    Players will be reminded 15 minutes before the match starts.\n\
+    4. Referees can get the lobby commands via `/tournament match referee`\n\
+    5. Link the multiplayer lobby via `/tournament match link`. \
+    Once the lobby closes, the result is computed and posted automatically.\n\
+    6. Check the results via `/tournament matches` and `/tournament standings`"
+)]
+pub enum Tournament {
+    #[command(name = "create")]
+    Create(TournamentCreate),
+    #[command(name = "delete")]
+    Delete(TournamentDelete),
+    #[command(name = "list")]
+    List(TournamentList),
+    #[command(name = "teams")]
+    Teams(TournamentTeams),
+    #[command(name = "matches")]
+    Matches(TournamentMatches),
+    #[command(name = "standings")]
+    Standings(TournamentStandings),
+    #[command(name = "team")]
+    Team(TournamentTeam),
+    #[command(name = "match")]
+    Match(TournamentMatch),
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "create", desc = "Create a new tournament")]
+pub struct TournamentCreate {
+    #[command(desc = "Specify the name of the tournament")]
+    name: String,
+    #[command(
+        desc = "Specify the channel in which reminders and results are posted",
+        channel_types = "guild_text guild_announcement"
+    )]
+    channel: Id<ChannelMarker>,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "delete",
+    desc = "Delete a tournament alongside its teams and matches"
+)]
+pub struct TournamentDelete {
+    #[command(desc = "Specify the name of the tournament")]
+    name: String,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "list", desc = "List all tournaments of this server")]
+pub struct TournamentList;
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "teams", desc = "Show the teams of a tournament")]
+pub struct TournamentTeams {
+    #[command(desc = "Specify the name of the tournament")]
+    tournament: String,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "matches", desc = "Show the matches of a tournament")]
+pub struct TournamentMatches {
+    #[command(desc = "Specify the name of the tournament")]
+    tournament: String,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "standings", desc = "Show the standings of a tournament")]
+pub struct TournamentStandings {
+    #[command(desc = "Specify the name of the tournament")]
+    tournament: String,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "team", desc = "Manage the teams of a tournament")]
+pub enum TournamentTeam {
+    #[command(name = "add")]
+    Add(TournamentTeamAdd),
+    #[command(name = "remove")]
+    Remove(TournamentTeamRemove),
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "add",
+    desc = "Register a team or add players to it",
+    help = "Register a team or add players to it.\n\
+    Players that are already in a different team of the tournament will be moved over."
+)]
+pub struct TournamentTeamAdd {
+    #[command(desc = "Specify the name of the tournament")]
+    tournament: String,
+    #[command(desc = "Specify the name of the team")]
+    team: String,
+    #[command(desc = "Specify comma-separated osu! usernames")]
+    players: String,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "remove", desc = "Remove a team from a tournament")]
+pub struct TournamentTeamRemove {
+    #[command(desc = "Specify the name of the tournament")]
+    tournament: String,
+    #[command(desc = "Specify the name of the team")]
+    team: String,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "match", desc = "Manage the matches of a tournament")]
+pub enum TournamentMatch {
+    #[command(name = "schedule")]
+    Schedule(TournamentMatchSchedule),
+    #[command(name = "link")]
+    Link(TournamentMatchLink),
+    #[command(name = "referee")]
+    Referee(TournamentMatchReferee),
+    #[command(name = "cancel")]
+    Cancel(TournamentMatchCancel),
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "schedule",
+    desc = "Schedule a match between two teams",
+    help = "Schedule a match between two teams.\n\
+    Times can either be relative like `2d` or absolute like `2023-12-24 18:00`. \
+    Absolute times use the timezone of your `/config`, defaulting to UTC.\n\
+    If the match is more than 15 minutes away, the players will be reminded \
+    in the tournament channel 15 minutes before it starts."
+)]
+pub struct TournamentMatchSchedule {
+    #[command(desc = "Specify the name of the tournament")]
+    tournament: String,
+    #[command(desc = "Specify the round, e.g. `Quarterfinals`")]
+    round: String,
+    #[command(desc = "Specify the first team")]
+    team_a: String,
+    #[command(desc = "Specify the second team")]
+    team_b: String,
+    #[command(desc = "Specify when the match starts, e.g. `2023-12-24 18:00`")]
+    when: String,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "link",
+    desc = "Link a multiplayer lobby to a scheduled match",
+    help = "Link a multiplayer lobby to a scheduled match.\n\
+    Once the lobby is closed, the result will be computed and posted in the tournament channel.\n\
+    A map is won by the team with the higher total score."
+)]
+pub struct TournamentMatchLink {
+    #[command(min_value = 1, desc = "Specify the id of the match")]
+    id: i64,
+    #[command(desc = "Specify a multiplayer match url or match id")]
+    mp: String,
+    #[command(
+        min_value = 0,
+        max_value = 10,
+        desc = "Specify the amount of warmups to ignore (defaults to 0)"
+    )]
+    warmups: Option<i64>,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "referee",
+    desc = "Show the lobby commands for a scheduled match"
+)]
+pub struct TournamentMatchReferee {
+    #[command(min_value = 1, desc = "Specify the id of the match")]
+    id: i64,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "cancel", desc = "Cancel a scheduled match")]
+pub struct TournamentMatchCancel {
+    #[command(min_value = 1, desc = "Specify the id of the match")]
+    id: i64,
+}
+
+async fn slash_tournament(ctx: Arc<Context>, mut command: InteractionCommand) -> Result<()> {
+    match Tournament::from_interaction(command.input_data())? {
+        Tournament::Create(args) => manage::create(ctx, command, args).await,
+        Tournament::Delete(args) => manage::delete(ctx, command, args).await,
+        Tournament::List(_) => manage::list(ctx, command).await,
+        Tournament::Teams(args) => manage::teams(ctx, command, args).await,
+        Tournament::Matches(args) => matches::matches(ctx, command, args).await,
+        Tournament::Standings(args) => standings::standings(ctx, command, args).await,
+        Tournament::Team(TournamentTeam::Add(args)) => manage::team_add(ctx, command, args).await,
+        Tournament::Team(TournamentTeam::Remove(args)) => {
+            manage::team_remove(ctx, command, args).await
+        }
+        Tournament::Match(TournamentMatch::Schedule(args)) => {
+            matches::schedule(ctx, command, args).await
+        }
+        Tournament::Match(TournamentMatch::Link(args)) => matches::link(ctx, command, args).await,
+        Tournament::Match(TournamentMatch::Referee(args)) => {
+            matches::referee(ctx, command, args).await
+        }
+        Tournament::Match(TournamentMatch::Cancel(args)) => {
+            matches::cancel(ctx, command, args).await
+        }
+    }
+}
+
+/// Returns `None` if the guild has no tournament with the given name in which
+/// case the author has already been notified.
+async fn get_tournament(
+    ctx: &Context,
+    command: &InteractionCommand,
+    name: &str,
+) -> Result<Option<DbTournament>> {
+    let guild_id = command.guild_id.unwrap();
+
+    match ctx.tournament().tournament(guild_id, name.trim()).await {
+        Ok(Some(tournament)) => Ok(Some(tournament)),
+        Ok(None) => {
+            let content = format!(
+                "There is no tournament called `{}` on this server",
+                name.trim()
+            );
+            command.error(ctx, content).await?;
+
+            Ok(None)
+        }
+        Err(err) => {
+            let _ = command.error_localized(ctx, ErrorKey::General).await;
+
+            Err(err)
+        }
+    }
+}
@@ -0,0 +1,180 @@
+use std::{cmp::Reverse, sync::Arc};
+
+use bathbot_psql::model::tournament::{DbTournamentMatch, DbTournamentPlayer};
+use eyre::Result;
+
+use super::{get_tournament, TournamentStandings};
+use crate::{
+    active::{impls::TournamentStandingsPagination, ActiveMessages},
+    core::locale::ErrorKey,
+    util::{interaction::InteractionCommand, Authored, InteractionCommandExt},
+    Context,
+};
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct TournamentStanding {
+    pub team: String,
+    pub wins: u32,
+    pub losses: u32,
+    pub maps_won: u32,
+    pub maps_lost: u32,
+}
+
+impl TournamentStanding {
+    fn new(team: String) -> Self {
+        Self {
+            team,
+            wins: 0,
+            losses: 0,
+            maps_won: 0,
+            maps_lost: 0,
+        }
+    }
+
+    /// Sorted by wins, then map difference, then won maps.
+    fn compute(players: &[DbTournamentPlayer], matches: &[DbTournamentMatch]) -> Vec<Self> {
+        let mut standings: Vec<Self> = Vec::new();
+
+        let teams = players.iter().map(|player| player.team.as_str()).chain(
+            matches
+                .iter()
+                .flat_map(|m| [m.team_a.as_str(), m.team_b.as_str()]),
+        );
+
+        for team in teams {
+            if standings.iter().all(|standing| standing.team != team) {
+                standings.push(Self::new(team.to_owned()));
+            }
+        }
+
+        for m in matches {
+            let (Some(score_a), Some(score_b)) = (m.score_a, m.score_b) else {
+                continue;
+            };
+
+            let (score_a, score_b) = (score_a as u32, score_b as u32);
+
+            for standing in standings.iter_mut() {
+                let (won, lost) = if standing.team == m.team_a {
+                    (score_a, score_b)
+                } else if standing.team == m.team_b {
+                    (score_b, score_a)
+                } else {
+                    continue;
+                };
+
+                standing.maps_won += won;
+                standing.maps_lost += lost;
+                standing.wins += (won > lost) as u32;
+                standing.losses += (won < lost) as u32;
+            }
+        }
+
+        standings.sort_by_key(|standing| {
+            let diff = standing.maps_won as i64 - standing.maps_lost as i64;
+
+            (
+                Reverse(standing.wins),
+                Reverse(diff),
+                Reverse(standing.maps_won),
+            )
+        });
+
+        standings
+    }
+}
+
+pub(super) async fn standings(
+    ctx: Arc<Context>,
+    mut command: InteractionCommand,
+    args: TournamentStandings,
+) -> Result<()> {
+    let Some(tournament) = get_tournament(&ctx, &command, &args.tournament).await? else {
+        return Ok(());
+    };
+
+    let players_fut = ctx.tournament().players(tournament.tournament_id);
+    let matches_fut = ctx.tournament().matches(tournament.tournament_id);
+
+    let (players, matches) = match tokio::try_join!(players_fut, matches_fut) {
+        Ok(tuple) => tuple,
+        Err(err) => {
+            let _ = command.error_localized(&ctx, ErrorKey::General).await;
+
+            return Err(err);
+        }
+    };
+
+    let standings = TournamentStanding::compute(&players, &matches);
+
+    let pagination = TournamentStandingsPagination::builder()
+        .standings(standings.into_boxed_slice())
+        .tournament(tournament.name.into_boxed_str())
+        .msg_owner(command.user_id()?)
+        .build();
+
+    ActiveMessages::builder(pagination)
+        .start_by_update(true)
+        .begin(ctx, &mut command)
+        .await
+}
+
+#[cfg(test)]
+mod tests {
+    use time::OffsetDateTime;
+
+    use super::*;
+
+    fn player(team: &str) -> DbTournamentPlayer {
+        DbTournamentPlayer {
+            user_id: 2,
+            username: "player".to_owned(),
+            team: team.to_owned(),
+        }
+    }
+
+    fn played(team_a: &str, team_b: &str, score: Option<(i16, i16)>) -> DbTournamentMatch {
+        DbTournamentMatch {
+            match_id: 1,
+            tournament_id: 1,
+            round: "Finals".to_owned(),
+            team_a: team_a.to_owned(),
+            team_b: team_b.to_owned(),
+            scheduled_at: OffsetDateTime::UNIX_EPOCH,
+            reminder_id: None,
+            mp_id: None,
+            warmups: 0,
+            score_a: score.map(|(a, _)| a),
+            score_b: score.map(|(_, b)| b),
+        }
+    }
+
+    #[test]
+    fn standings_order() {
+        let players = [player("A"), player("B"), player("C"), player("D")];
+
+        let matches = [
+            played("A", "B", Some((5, 3))),
+            played("C", "D", Some((5, 0))),
+            played("B", "D", Some((5, 4))),
+            played("A", "C", None),
+        ];
+
+        let standings = TournamentStanding::compute(&players, &matches);
+        let order: Vec<_> = standings.iter().map(|s| s.team.as_str()).collect();
+
+        // C and A both have one win but C has the better map difference
+        assert_eq!(order, ["C", "A", "B", "D"]);
+
+        assert_eq!(
+            standings[2],
+            TournamentStanding {
+                team: "B".to_owned(),
+                wins: 1,
+                losses: 1,
+                maps_won: 8,
+                maps_lost: 9,
+            }
+        );
+    }
+}
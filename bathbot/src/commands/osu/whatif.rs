@@ -2,7 +2,6 @@ use std::{borrow::Cow, iter, sync::Arc};
 
 use bathbot_macros::{command, HasName, SlashCommand};
 use bathbot_util::{
-    constants::OSU_API_ISSUE,
     matcher,
     osu::{approx_more_pp, ExtractablePp, PpListUtil},
     MessageBuilder,
@@ -15,7 +14,10 @@ use twilight_model::id::{marker::UserMarker, Id};
 use super::user_not_found;
 use crate::{
     commands::GameModeOption,
-    core::commands::{prefix::Args, CommandOrigin},
+    core::{
+        commands::{prefix::Args, CommandOrigin},
+        locale::ErrorKey,
+    },
     embeds::{EmbedData, WhatIfEmbed},
     manager::redis::osu::UserArgs,
     util::{interaction::InteractionCommand, ChannelExt, InteractionCommandExt},
@@ -214,7 +216,7 @@ async fn whatif(ctx: Arc<Context>, orig: CommandOrigin<'_>, args: WhatIf<'_>) ->
             return orig.error(&ctx, content).await;
         }
         Err(err) => {
-            let _ = orig.error(&ctx, OSU_API_ISSUE).await;
+            let _ = orig.error_localized(&ctx, ErrorKey::OsuApi).await;
             let err = Report::new(err).wrap_err("Failed to get user or scores");
 
             return Err(err);
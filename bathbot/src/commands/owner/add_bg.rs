@@ -1,10 +1,7 @@
 use std::{str::FromStr, sync::Arc};
 
 use bathbot_psql::model::osu::ArtistTitle;
-use bathbot_util::{
-    constants::{GENERAL_ISSUE, OSU_BASE},
-    MessageBuilder,
-};
+use bathbot_util::{constants::OSU_BASE, MessageBuilder};
 use eyre::{Report, Result};
 use rosu_v2::prelude::GameMode;
 use tokio::{
@@ -14,7 +11,7 @@ use tokio::{
 
 use super::OwnerAddBg;
 use crate::{
-    core::BotConfig,
+    core::{locale::ErrorKey, BotConfig},
     util::{interaction::InteractionCommand, InteractionCommandExt},
     Context,
 };
@@ -67,7 +64,7 @@ pub async fn addbg(ctx: Arc<Context>, command: InteractionCommand, bg: OwnerAddB
             let mut file = match File::create(&path).await {
                 Ok(file) => file,
                 Err(err) => {
-                    let _ = command.error(&ctx, GENERAL_ISSUE).await;
+                    let _ = command.error_localized(&ctx, ErrorKey::General).await;
                     let err = Report::new(err).wrap_err("failed to create file for new bg");
 
                     return Err(err);
@@ -76,7 +73,7 @@ pub async fn addbg(ctx: Arc<Context>, command: InteractionCommand, bg: OwnerAddB
 
             // Store in file
             if let Err(err) = file.write_all(&content).await {
-                let _ = command.error(&ctx, GENERAL_ISSUE).await;
+                let _ = command.error_localized(&ctx, ErrorKey::General).await;
                 let err = Report::new(err).wrap_err("failed writing to bg file");
 
                 return Err(err);
@@ -84,7 +81,7 @@ pub async fn addbg(ctx: Arc<Context>, command: InteractionCommand, bg: OwnerAddB
             path
         }
         Err(err) => {
-            let _ = command.error(&ctx, GENERAL_ISSUE).await;
+            let _ = command.error_localized(&ctx, ErrorKey::General).await;
 
             return Err(err.wrap_err("failed to get discord attachment"));
         }
@@ -95,10 +92,15 @@ pub async fn addbg(ctx: Arc<Context>, command: InteractionCommand, bg: OwnerAddB
         Ok(ArtistTitle { artist, title }) => format!(
             "Background for [{artist} - {title}]({OSU_BASE}s/{mapset_id}) successfully added ({mode})",
         ),
-        Err(err_msg) => {
+        Err(PrepareError::Key(key)) => {
+            let _ = remove_file(path).await;
+
+            key.text(command.language(&ctx).await).to_owned()
+        }
+        Err(PrepareError::Content(content)) => {
             let _ = remove_file(path).await;
 
-            err_msg.to_owned()
+            content.to_owned()
         }
     };
 
@@ -108,18 +110,23 @@ pub async fn addbg(ctx: Arc<Context>, command: InteractionCommand, bg: OwnerAddB
     Ok(())
 }
 
+enum PrepareError {
+    Key(ErrorKey),
+    Content(&'static str),
+}
+
 async fn prepare_mapset(
     ctx: &Context,
     mapset_id: u32,
     filename: &str,
     mode: GameMode,
-) -> Result<ArtistTitle, &'static str> {
+) -> Result<ArtistTitle, PrepareError> {
     let artist_title = match ctx.osu_map().artist_title(mapset_id).await {
         Ok(artist_title) => artist_title,
         Err(err) => {
             warn!("{:?}", Report::new(err));
 
-            return Err(GENERAL_ISSUE);
+            return Err(PrepareError::Key(ErrorKey::General));
         }
     };
 
@@ -128,7 +135,9 @@ async fn prepare_mapset(
     if let Err(err) = upsert_fut.await {
         warn!("{err:?}");
 
-        return Err("There is already an entry with this mapset id");
+        return Err(PrepareError::Content(
+            "There is already an entry with this mapset id",
+        ));
     }
 
     Ok(artist_title)
@@ -1,11 +1,11 @@
 use std::sync::Arc;
 
-use bathbot_util::{constants::GENERAL_ISSUE, MessageBuilder};
+use bathbot_util::MessageBuilder;
 use eyre::{Report, Result};
 use twilight_model::id::Id;
 
 use crate::{
-    core::Context,
+    core::{locale::ErrorKey, Context},
     util::{interaction::InteractionCommand, InteractionCommandExt},
 };
 
@@ -44,7 +44,9 @@ pub async fn request_members(
             Ok(())
         }
         Err(err) => {
-            let _ = command.error_callback(&ctx, GENERAL_ISSUE).await;
+            let _ = command
+                .error_callback_localized(&ctx, ErrorKey::General)
+                .await;
 
             Err(Report::new(err).wrap_err("Failed to forward member request"))
         }
@@ -3,6 +3,7 @@ use std::sync::Arc;
 use bathbot_macros::command;
 use eyre::Result;
 
+use super::BuiltinSong;
 use crate::Context;
 
 #[command]
@@ -10,21 +11,5 @@ use crate::Context;
 #[group(Songs)]
 #[flags(SKIP_DEFER)]
 pub async fn prefix_bombsaway(ctx: Arc<Context>, msg: &Message) -> Result<()> {
-    let (lyrics, delay) = bombsaway_();
-
-    super::song(lyrics, delay, ctx, msg.into()).await
-}
-
-pub fn bombsaway_() -> (&'static [&'static str], u64) {
-    let lyrics = &[
-        "Tick tick tock and it's bombs awayyyy",
-        "Come ooon, it's the only way",
-        "Save your-self for a better dayyyy",
-        "No, no, we are falling dooo-ooo-ooo-ooown",
-        "I know, you know - this is over",
-        "Tick tick tock and it's bombs awayyyy",
-        "Now we're falling -- now we're falling doooown",
-    ];
-
-    (lyrics, 2750)
+    super::builtin_song(BuiltinSong::Bombsaway, ctx, msg.into()).await
 }
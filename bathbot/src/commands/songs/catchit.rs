@@ -3,6 +3,7 @@ use std::sync::Arc;
 use bathbot_macros::command;
 use eyre::Result;
 
+use super::BuiltinSong;
 use crate::core::Context;
 
 #[command]
@@ -10,18 +11,5 @@ use crate::core::Context;
 #[group(Songs)]
 #[flags(SKIP_DEFER)]
 async fn prefix_catchit(ctx: Arc<Context>, msg: &Message) -> Result<()> {
-    let (lyrics, delay) = catchit_();
-
-    super::song(lyrics, delay, ctx, msg.into()).await
-}
-
-pub fn catchit_() -> (&'static [&'static str], u64) {
-    let lyrics = &[
-        "This song is one you won't forget",
-        "It will get stuck -- in your head",
-        "If it does, then you can't blame me",
-        "Just like I said - too catchy",
-    ];
-
-    (lyrics, 2500)
+    super::builtin_song(BuiltinSong::Catchit, ctx, msg.into()).await
 }
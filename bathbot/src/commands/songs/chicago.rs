@@ -1,10 +0,0 @@
-pub fn chicago_() -> (&'static [&'static str], u64) {
-    let lyrics = &[
-        "Kimi no koe ga itsumo hibii teru yo",
-        "Doramachikku na tenkai matenrou-sa",
-        "Kimi no kage ga itsumo jama shi teru yo",
-        "Romanchikku na koukai me no mae sa",
-    ];
-
-    (lyrics, 4500)
-}
@@ -3,6 +3,7 @@ use std::sync::Arc;
 use bathbot_macros::command;
 use eyre::Result;
 
+use super::BuiltinSong;
 use crate::core::Context;
 
 #[command]
@@ -10,22 +11,5 @@ use crate::core::Context;
 #[group(Songs)]
 #[flags(SKIP_DEFER)]
 async fn prefix_ding(ctx: Arc<Context>, msg: &Message) -> Result<()> {
-    let (lyrics, delay) = ding_();
-
-    super::song(lyrics, delay, ctx, msg.into()).await
-}
-
-pub fn ding_() -> (&'static [&'static str], u64) {
-    let lyrics = &[
-        "Oh-oh-oh, hübsches Ding",
-        "Ich versteck' mein' Ehering",
-        "Klinglingeling, wir könnten's bring'n",
-        "Doch wir nuckeln nur am Drink",
-        "Oh-oh-oh, hübsches Ding",
-        "Du bist Queen und ich bin King",
-        "Wenn ich dich seh', dann muss ich sing'n",
-        "Tingalingaling, you pretty thing!",
-    ];
-
-    (lyrics, 2500)
+    super::builtin_song(BuiltinSong::Ding, ctx, msg.into()).await
 }
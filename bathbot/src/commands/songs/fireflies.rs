@@ -3,6 +3,7 @@ use std::sync::Arc;
 use bathbot_macros::command;
 use eyre::Result;
 
+use super::BuiltinSong;
 use crate::Context;
 
 #[command]
@@ -10,20 +11,5 @@ use crate::Context;
 #[group(Songs)]
 #[flags(SKIP_DEFER)]
 async fn prefix_fireflies(ctx: Arc<Context>, msg: &Message) -> Result<()> {
-    let (lyrics, delay) = fireflies_();
-
-    super::song(lyrics, delay, ctx, msg.into()).await
-}
-
-pub fn fireflies_() -> (&'static [&'static str], u64) {
-    let lyrics = &[
-        "You would not believe your eyes",
-        "If ten million fireflies",
-        "Lit up the world as I fell asleep",
-        "'Cause they'd fill the open air",
-        "And leave teardrops everywhere",
-        "You'd think me rude, but I would just stand and -- stare",
-    ];
-
-    (lyrics, 2500)
+    super::builtin_song(BuiltinSong::Fireflies, ctx, msg.into()).await
 }
@@ -1,13 +0,0 @@
-pub fn glorydays_() -> (&'static [&'static str], u64) {
-    let lyrics = &[
-        "To seek the glory days",
-        "We'll fight the lion's way",
-        "Then let the rain wash",
-        "All of our pride away",
-        "So if this victory",
-        "Is our last odyssey",
-        "Then let the power within us deciiiide",
-    ];
-
-    (lyrics, 2750)
-}
@@ -1,14 +0,0 @@
-pub fn harumachi_() -> (&'static [&'static str], u64) {
-    let lyrics = &[
-        "Me no mae no tobira o ake tara harukaze",
-        "Tori tachi mo kigi de machiawase",
-        "Kimi e mukau shingō wa aozora iro",
-        "Kakedase ba ii",
-        "Usotsuki kakuritsu ron toka",
-        "Ichi purasu ichi ga mugen toka",
-        "Oshie te kure ta kimi to sagashi ni ikou",
-        "Haru machi cloveeeeer",
-    ];
-
-    (lyrics, 3650)
-}
@@ -1,16 +0,0 @@
-pub fn hitorigoto_() -> (&'static [&'static str], u64) {
-    let lyrics = &[
-        "Futo shita toki ni sagashiteiru yo",
-        "Kimi no egao wo sagashiteiru yo",
-        "Muishiki no naka sono riyuu -",
-        "wa mada ienai kedo",
-        "Hitori de iru to aitakunaru yo",
-        "Dare to itatte aitakunaru yo",
-        "Tatta hitokoto nee, doushite Aah",
-        "Ienai sono kotoba -",
-        "ienai kono kimochi Aaah",
-        "Hayaku kizuite hoshii no niiii",
-    ];
-
-    (lyrics, 3200)
-}
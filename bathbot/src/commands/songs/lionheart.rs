@@ -1,10 +0,0 @@
-pub fn lionheart_() -> (&'static [&'static str], u64) {
-    let lyrics = &[
-        "Like a lion we fight, together we will die",
-        "For the glory of our god --",
-        "Justice on our side, this cross will lead the light",
-        "Follow Richard Lionheart --",
-    ];
-
-    (lyrics, 5400)
-}
@@ -0,0 +1,195 @@
+use std::borrow::Cow;
+
+/// Delay in milliseconds for lines until one specifies a delay itself
+const DEFAULT_DELAY: u64 = 2500;
+
+const MIN_DELAY: u64 = 500;
+const MAX_DELAY: u64 = 10_000;
+
+const MAX_LINES: usize = 30;
+
+/// Discord's limit for the content of a message
+const MAX_CHARS: usize = 2000;
+
+/// Each line is wrapped in `♫ {line} ♫\n`
+const LINE_OVERHEAD: usize = 5;
+
+/// Lyrics in the line/delay format.
+///
+/// Each non-empty line of the input is one line of the song. A line may be
+/// prefixed with `<delay> | ` to specify how many milliseconds the line is
+/// shown before the next one follows. Lines without such a prefix use the
+/// delay of the previous line.
+///
+/// ```text
+/// 2750 | Tick tick tock and it's bombs awayyyy
+/// Come ooon, it's the only way
+/// 4000 | Save your-self for a better dayyyy
+/// ```
+#[derive(Debug, PartialEq)]
+pub struct Lyrics<'a> {
+    pub lines: Vec<LyricsLine<'a>>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct LyricsLine<'a> {
+    pub text: &'a str,
+    pub delay: u64,
+}
+
+impl<'a> Lyrics<'a> {
+    pub fn parse(input: &'a str) -> Result<Self, LyricsError> {
+        let mut lines = Vec::new();
+        let mut delay = DEFAULT_DELAY;
+        let mut chars = 0;
+
+        let non_empty = input
+            .lines()
+            .enumerate()
+            .map(|(i, line)| (i + 1, line.trim()))
+            .filter(|(_, line)| !line.is_empty());
+
+        for (line_num, line) in non_empty {
+            let text = match line.split_once('|') {
+                Some((prefix, text)) => match prefix.trim().parse() {
+                    Ok(line_delay) => {
+                        if !(MIN_DELAY..=MAX_DELAY).contains(&line_delay) {
+                            return Err(LyricsError::Delay(line_num));
+                        }
+
+                        delay = line_delay;
+
+                        text.trim()
+                    }
+                    Err(_) => line,
+                },
+                None => line,
+            };
+
+            if text.is_empty() {
+                return Err(LyricsError::EmptyLine(line_num));
+            }
+
+            if lines.len() == MAX_LINES {
+                return Err(LyricsError::TooManyLines);
+            }
+
+            chars += text.chars().count() + LINE_OVERHEAD;
+
+            if chars > MAX_CHARS {
+                return Err(LyricsError::TooLong);
+            }
+
+            lines.push(LyricsLine { text, delay });
+        }
+
+        if lines.is_empty() {
+            return Err(LyricsError::Empty);
+        }
+
+        Ok(Self { lines })
+    }
+
+    /// The combined length of all lines
+    pub fn text_len(&self) -> usize {
+        self.lines.iter().map(|line| line.text.len()).sum()
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum LyricsError {
+    Delay(usize),
+    Empty,
+    EmptyLine(usize),
+    TooLong,
+    TooManyLines,
+}
+
+impl LyricsError {
+    pub fn into_str(self) -> Cow<'static, str> {
+        match self {
+            Self::Delay(line) => format!(
+                "The delay in line {line} must be between {MIN_DELAY} and {MAX_DELAY} milliseconds"
+            )
+            .into(),
+            Self::Empty => "The lyrics must contain at least one line".into(),
+            Self::EmptyLine(line) => format!("Line {line} has a delay but no lyrics").into(),
+            Self::TooLong => format!(
+                "The lyrics are too long, all lines combined must fit into {MAX_CHARS} characters"
+            )
+            .into(),
+            Self::TooManyLines => {
+                format!("The lyrics must not have more than {MAX_LINES} lines").into()
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::songs::BuiltinSong;
+
+    #[test]
+    fn delays() {
+        let input = "3000 | first\nsecond\n\n 1000|third \nfourth";
+
+        let expected = Lyrics {
+            lines: vec![
+                LyricsLine {
+                    text: "first",
+                    delay: 3000,
+                },
+                LyricsLine {
+                    text: "second",
+                    delay: 3000,
+                },
+                LyricsLine {
+                    text: "third",
+                    delay: 1000,
+                },
+                LyricsLine {
+                    text: "fourth",
+                    delay: 1000,
+                },
+            ],
+        };
+
+        assert_eq!(Lyrics::parse(input), Ok(expected));
+    }
+
+    #[test]
+    fn default_delay() {
+        let lyrics = Lyrics::parse("a | b").unwrap();
+
+        assert_eq!(
+            lyrics.lines,
+            vec![LyricsLine {
+                text: "a | b",
+                delay: DEFAULT_DELAY,
+            }]
+        );
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(Lyrics::parse(" \n "), Err(LyricsError::Empty));
+        assert_eq!(Lyrics::parse("a\n100 | b"), Err(LyricsError::Delay(2)));
+        assert_eq!(Lyrics::parse("a\n\n1000 |"), Err(LyricsError::EmptyLine(3)));
+        assert_eq!(
+            Lyrics::parse(&"a\n".repeat(MAX_LINES + 1)),
+            Err(LyricsError::TooManyLines)
+        );
+        assert_eq!(
+            Lyrics::parse(&"a".repeat(MAX_CHARS)),
+            Err(LyricsError::TooLong)
+        );
+    }
+
+    #[test]
+    fn builtin_songs() {
+        for song in BuiltinSong::ALL {
+            assert!(Lyrics::parse(song.lyrics()).is_ok(), "{}", song.name());
+        }
+    }
+}
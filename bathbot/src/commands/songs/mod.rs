@@ -1,53 +1,51 @@
 mod bombsaway;
 mod catchit;
-mod chicago;
 mod ding;
 mod fireandflames;
 mod fireflies;
 mod flamingo;
-mod glorydays;
-mod harumachi;
-mod hitorigoto;
-mod lionheart;
-mod mylove;
-mod padoru;
+mod lyrics;
 mod pretender;
 mod rockefeller;
 mod saygoodbye;
 mod startagain;
 mod tijdmachine;
-mod time_traveler;
-mod wordsneversaid;
-mod zenzenzense;
 
 use std::{fmt::Write, sync::Arc};
 
 use bathbot_macros::SlashCommand;
-use bathbot_util::MessageBuilder;
-use eyre::{ContextCompat, Result};
-use tokio::time::{interval, Duration};
-use twilight_interactions::command::{CommandModel, CommandOption, CreateCommand, CreateOption};
+use bathbot_util::{EmbedBuilder, MessageBuilder};
+use eyre::{Result, WrapErr};
+use tokio::time::{sleep_until, Duration, Instant};
+use twilight_interactions::command::{AutocompleteValue, CommandModel, CreateCommand};
+use twilight_model::{
+    application::command::{CommandOptionChoice, CommandOptionChoiceValue},
+    channel::Attachment,
+};
 
+use self::lyrics::Lyrics;
 pub use self::{
-    bombsaway::*, catchit::*, chicago::*, ding::*, fireandflames::*, fireflies::*, flamingo::*,
-    glorydays::*, harumachi::*, hitorigoto::*, lionheart::*, mylove::*, padoru::*, pretender::*,
-    rockefeller::*, saygoodbye::*, startagain::*, tijdmachine::*, time_traveler::*,
-    wordsneversaid::*, zenzenzense::*,
+    bombsaway::*, catchit::*, ding::*, fireandflames::*, fireflies::*, flamingo::*, pretender::*,
+    rockefeller::*, saygoodbye::*, startagain::*, tijdmachine::*,
 };
 use crate::{
-    core::{buckets::BucketName, commands::CommandOrigin},
-    util::{interaction::InteractionCommand, CheckPermissions, InteractionCommandExt, MessageExt},
+    core::{buckets::BucketName, commands::CommandOrigin, locale::ErrorKey},
+    util::{
+        interaction::InteractionCommand, require_authority, Authored, CheckPermissions,
+        InteractionCommandExt, MessageExt,
+    },
     Context,
 };
 
-async fn song(
-    lyrics: &[&str],
-    delay: u64,
-    ctx: Arc<Context>,
-    orig: CommandOrigin<'_>,
-) -> Result<()> {
-    debug_assert!(lyrics.len() > 1);
+/// Maximum amount of custom songs per guild
+const MAX_GUILD_SONGS: usize = 25;
+
+const MAX_NAME_LEN: usize = 32;
 
+/// Maximum size in bytes of uploaded lyrics files
+const MAX_FILE_SIZE: u64 = 16_384;
+
+async fn song(lyrics: &Lyrics<'_>, ctx: Arc<Context>, orig: CommandOrigin<'_>) -> Result<()> {
     if !orig.can_view_channel() {
         let content = "I'm lacking the \"View Channel\" permission \
             required to update message.";
@@ -75,14 +73,18 @@ async fn song(
     }
 
     if allow {
-        let mut interval = interval(Duration::from_millis(delay));
-        let len: usize = lyrics.iter().map(|line| line.len()).sum();
-        let mut content = String::with_capacity(len + lyrics.len() * 5);
+        // parsed lyrics always contain at least one line
+        let [first, rest @ ..] = lyrics.lines.as_slice() else {
+            return Ok(());
+        };
+
+        let mut content = String::with_capacity(lyrics.text_len() + lyrics.lines.len() * 5);
 
-        let _ = writeln!(content, "♫ {} ♫", lyrics[0]);
+        let _ = writeln!(content, "♫ {} ♫", first.text);
         let builder = MessageBuilder::new().content(&content);
 
-        interval.tick().await;
+        let mut next = Instant::now();
+        let mut delay = first.delay;
 
         let mut response = orig
             .callback_with_response(&ctx, builder)
@@ -90,9 +92,12 @@ async fn song(
             .model()
             .await?;
 
-        for line in &lyrics[1..] {
-            interval.tick().await;
-            let _ = writeln!(content, "♫ {line} ♫");
+        for line in rest {
+            next += Duration::from_millis(delay);
+            delay = line.delay;
+            sleep_until(next).await;
+
+            let _ = writeln!(content, "♫ {} ♫", line.text);
 
             let builder = MessageBuilder::new().content(&content);
 
@@ -113,12 +118,56 @@ async fn song(
     Ok(())
 }
 
-#[derive(CommandModel, CreateCommand, SlashCommand)]
+async fn builtin_song(
+    builtin: BuiltinSong,
+    ctx: Arc<Context>,
+    orig: CommandOrigin<'_>,
+) -> Result<()> {
+    let lyrics = Lyrics::parse(builtin.lyrics())
+        .map_err(|err| eyre!("Invalid lyrics for {}: {}", builtin.name(), err.into_str()))?;
+
+    song(&lyrics, ctx, orig).await
+}
+
+#[derive(CreateCommand, SlashCommand)]
 #[command(
     name = "song",
     desc = "Let me sing a song for you",
     help = "Let me sing a song for you.\n\
-    Currently available: \
+    Besides the built-in songs, server authorities can add their own songs \
+    to the server's song library."
+)]
+#[flags(SKIP_DEFER)]
+#[allow(dead_code)]
+pub enum Song {
+    #[command(name = "sing")]
+    Sing(SongSing),
+    #[command(name = "add")]
+    Add(SongAdd),
+    #[command(name = "remove")]
+    Remove(SongRemove),
+    #[command(name = "list")]
+    List(SongList),
+}
+
+#[derive(CommandModel)]
+enum Song_ {
+    #[command(name = "sing")]
+    Sing(SongSing_),
+    #[command(name = "add")]
+    Add(SongAdd),
+    #[command(name = "remove")]
+    Remove(SongRemove),
+    #[command(name = "list")]
+    List(SongList),
+}
+
+#[derive(CreateCommand)]
+#[command(
+    name = "sing",
+    desc = "Let me sing a song for you",
+    help = "Let me sing a song for you.\n\
+    Available are all songs of the server's song library and the built-in songs: \
     [Bombs away](https://youtu.be/xpkkakkDhN4?t=65), \
     [Catchit](https://youtu.be/BjFWk0ncr70?t=12), \
     [Chicago](https://www.youtube.com/watch?v=MWserASk0Jg&t=60s), \
@@ -141,89 +190,444 @@ async fn song(
     [Through the Fire and Flames](https://youtu.be/0jgrCKhxE1s?t=77), \
     [Zen Zen Zense](https://www.youtube.com/watch?v=607QsB38hn8&t=71s)"
 )]
-#[flags(SKIP_DEFER)]
-pub struct Song {
-    #[command(desc = "Choose a song title")]
-    title: SongTitle,
+#[allow(dead_code)]
+pub struct SongSing {
+    #[command(autocomplete = true, desc = "Choose a song title")]
+    title: String,
 }
 
-#[derive(CommandOption, CreateOption)]
-pub enum SongTitle {
-    #[option(name = "Bombs away", value = "bombsaway")]
+#[derive(CommandModel)]
+#[command(autocomplete = true)]
+struct SongSing_ {
+    title: AutocompleteValue<String>,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "add",
+    desc = "Add a song to the server's song library",
+    help = "Add a song to the server's song library or replace the lyrics of an existing one.\n\
+    The lyrics must be provided as text file in which each line is one line of the song.\n\
+    A line can be prefixed with `<delay> | ` to specify for how many milliseconds \
+    the line is shown before the next one follows. \
+    Lines without such a prefix use the delay of the previous line.\n\
+    ```\n\
+    2750 | Tick tick tock and it's bombs awayyyy\n\
+    Come ooon, it's the only way\n\
+    4000 | Save your-self for a better dayyyy\n\
+    ```\n\
+    Delays must be between 500 and 10,000 milliseconds and a song can have at most 30 lines.\n\
+    Only server authorities can use this command."
+)]
+pub struct SongAdd {
+    #[command(desc = "Specify the name of the song")]
+    name: String,
+    #[command(desc = "Specify a text file containing the lyrics")]
+    lyrics: Attachment,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "remove",
+    desc = "Remove a song from the server's song library",
+    help = "Remove a song from the server's song library.\n\
+    Only server authorities can use this command."
+)]
+pub struct SongRemove {
+    #[command(desc = "Specify the name of the song")]
+    name: String,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "list", desc = "List all available songs")]
+pub struct SongList;
+
+#[derive(Copy, Clone)]
+pub enum BuiltinSong {
     Bombsaway,
-    #[option(name = "Catchit", value = "catchit")]
     Catchit,
-    #[option(name = "Chicago", value = "chicago")]
     Chicago,
-    #[option(name = "Ding", value = "ding")]
     Ding,
-    #[option(name = "Fireflies", value = "fireflies")]
     Fireflies,
-    #[option(name = "Flamingo", value = "flamingo")]
     Flamingo,
-    #[option(name = "Glory Days", value = "glorydays")]
     GloryDays,
-    #[option(name = "Harumachi Clover", value = "harumachi")]
     Harumachi,
-    #[option(name = "Hitorigoto", value = "hitorigoto")]
     Hitorigoto,
-    #[option(name = "Lionheart", value = "lionheart")]
     Lionheart,
-    #[option(name = "My Love", value = "mylove")]
     MyLove,
-    #[option(name = "Padoru", value = "padoru")]
     Padoru,
-    #[option(name = "Pretender", value = "pretender")]
     Pretender,
-    #[option(name = "Rockefeller Street", value = "rockefeller")]
     Rockefeller,
-    #[option(name = "Say Goodbye", value = "saygoodbye")]
     SayGoodbye,
-    #[option(name = "Start Again", value = "startagain")]
     StartAgain,
-    #[option(name = "Tijdmachine", value = "tijdmachine")]
     Tijdmachine,
-    #[option(name = "Time Traveler", value = "time_traveler")]
     TimeTraveler,
-    #[option(name = "The words I never said", value = "wordsneversaid")]
     WordsNeverSaid,
-    #[option(name = "Through the Fire and Flames", value = "fireandflames")]
     FireAndFlames,
-    #[option(name = "Zen Zen Zense", value = "zenzenzense")]
     ZenZenZense,
 }
 
-impl SongTitle {
-    fn get(self) -> (&'static [&'static str], u64) {
+impl BuiltinSong {
+    pub const ALL: [Self; 21] = [
+        Self::Bombsaway,
+        Self::Catchit,
+        Self::Chicago,
+        Self::Ding,
+        Self::Fireflies,
+        Self::Flamingo,
+        Self::GloryDays,
+        Self::Harumachi,
+        Self::Hitorigoto,
+        Self::Lionheart,
+        Self::MyLove,
+        Self::Padoru,
+        Self::Pretender,
+        Self::Rockefeller,
+        Self::SayGoodbye,
+        Self::StartAgain,
+        Self::Tijdmachine,
+        Self::TimeTraveler,
+        Self::WordsNeverSaid,
+        Self::FireAndFlames,
+        Self::ZenZenZense,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Bombsaway => "Bombs away",
+            Self::Catchit => "Catchit",
+            Self::Chicago => "Chicago",
+            Self::Ding => "Ding",
+            Self::Fireflies => "Fireflies",
+            Self::Flamingo => "Flamingo",
+            Self::GloryDays => "Glory Days",
+            Self::Harumachi => "Harumachi Clover",
+            Self::Hitorigoto => "Hitorigoto",
+            Self::Lionheart => "Lionheart",
+            Self::MyLove => "My Love",
+            Self::Padoru => "Padoru",
+            Self::Pretender => "Pretender",
+            Self::Rockefeller => "Rockefeller Street",
+            Self::SayGoodbye => "Say Goodbye",
+            Self::StartAgain => "Start Again",
+            Self::Tijdmachine => "Tijdmachine",
+            Self::TimeTraveler => "Time Traveler",
+            Self::WordsNeverSaid => "The words I never said",
+            Self::FireAndFlames => "Through the Fire and Flames",
+            Self::ZenZenZense => "Zen Zen Zense",
+        }
+    }
+
+    /// Lyrics in the same format as songs of a server's song library
+    pub fn lyrics(self) -> &'static str {
         match self {
-            Self::Bombsaway => bombsaway_(),
-            Self::Catchit => catchit_(),
-            Self::Chicago => chicago_(),
-            Self::Ding => ding_(),
-            Self::Fireflies => fireflies_(),
-            Self::Flamingo => flamingo_(),
-            Self::GloryDays => glorydays_(),
-            Self::Harumachi => harumachi_(),
-            Self::Hitorigoto => hitorigoto_(),
-            Self::Lionheart => lionheart_(),
-            Self::MyLove => mylove_(),
-            Self::Padoru => padoru_(),
-            Self::Pretender => pretender_(),
-            Self::Rockefeller => rockefeller_(),
-            Self::SayGoodbye => saygoodbye_(),
-            Self::StartAgain => startagain_(),
-            Self::Tijdmachine => tijdmachine_(),
-            Self::TimeTraveler => time_traveler_(),
-            Self::WordsNeverSaid => wordsneversaid_(),
-            Self::FireAndFlames => fireandflames_(),
-            Self::ZenZenZense => zenzenzense_(),
+            Self::Bombsaway => include_str!("builtin/bombsaway.txt"),
+            Self::Catchit => include_str!("builtin/catchit.txt"),
+            Self::Chicago => include_str!("builtin/chicago.txt"),
+            Self::Ding => include_str!("builtin/ding.txt"),
+            Self::Fireflies => include_str!("builtin/fireflies.txt"),
+            Self::Flamingo => include_str!("builtin/flamingo.txt"),
+            Self::GloryDays => include_str!("builtin/glorydays.txt"),
+            Self::Harumachi => include_str!("builtin/harumachi.txt"),
+            Self::Hitorigoto => include_str!("builtin/hitorigoto.txt"),
+            Self::Lionheart => include_str!("builtin/lionheart.txt"),
+            Self::MyLove => include_str!("builtin/mylove.txt"),
+            Self::Padoru => include_str!("builtin/padoru.txt"),
+            Self::Pretender => include_str!("builtin/pretender.txt"),
+            Self::Rockefeller => include_str!("builtin/rockefeller.txt"),
+            Self::SayGoodbye => include_str!("builtin/saygoodbye.txt"),
+            Self::StartAgain => include_str!("builtin/startagain.txt"),
+            Self::Tijdmachine => include_str!("builtin/tijdmachine.txt"),
+            Self::TimeTraveler => include_str!("builtin/time_traveler.txt"),
+            Self::WordsNeverSaid => include_str!("builtin/wordsneversaid.txt"),
+            Self::FireAndFlames => include_str!("builtin/fireandflames.txt"),
+            Self::ZenZenZense => include_str!("builtin/zenzenzense.txt"),
         }
     }
+
+    /// Case-insensitive lookup by name
+    fn find(name: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|song| song.name().eq_ignore_ascii_case(name))
+    }
 }
 
 pub async fn slash_song(ctx: Arc<Context>, mut command: InteractionCommand) -> Result<()> {
-    let args = Song::from_interaction(command.input_data())?;
-    let (lyrics, delay) = args.title.get();
+    match Song_::from_interaction(command.input_data())? {
+        Song_::Sing(args) => match args.title {
+            AutocompleteValue::None => handle_autocomplete(&ctx, &command, String::new()).await,
+            AutocompleteValue::Focused(title) => handle_autocomplete(&ctx, &command, title).await,
+            AutocompleteValue::Completed(title) => sing(ctx, command, title).await,
+        },
+        Song_::Add(args) => add(ctx, command, args).await,
+        Song_::Remove(args) => remove(ctx, command, args).await,
+        Song_::List(_) => list(ctx, command).await,
+    }
+}
+
+async fn sing(ctx: Arc<Context>, mut command: InteractionCommand, title: String) -> Result<()> {
+    if let Some(song) = BuiltinSong::find(&title) {
+        return builtin_song(song, ctx, (&mut command).into()).await;
+    }
+
+    let guild_song = match command.guild_id {
+        Some(guild) => match ctx.songs().guild_song(guild, &title.to_lowercase()).await {
+            Ok(song) => song,
+            Err(err) => {
+                let _ = command
+                    .error_callback_localized(&ctx, ErrorKey::General)
+                    .await;
+
+                return Err(err);
+            }
+        },
+        None => None,
+    };
+
+    let Some(guild_song) = guild_song else {
+        let content = format!("There is no song with the name `{title}`");
+        command.error_callback(&ctx, content).await?;
+
+        return Ok(());
+    };
+
+    let lyrics = Lyrics::parse(&guild_song.lyrics).map_err(|err| {
+        eyre!(
+            "Invalid stored lyrics for {}: {}",
+            guild_song.name,
+            err.into_str()
+        )
+    })?;
+
+    song(&lyrics, ctx, (&mut command).into()).await
+}
+
+async fn add(ctx: Arc<Context>, command: InteractionCommand, args: SongAdd) -> Result<()> {
+    command.defer(&ctx, false).await?;
+
+    let Some(guild) = command.guild_id else {
+        command
+            .error(&ctx, "Songs can only be added on servers")
+            .await?;
+
+        return Ok(());
+    };
+
+    if !require_authority(&ctx, &command).await? {
+        return Ok(());
+    }
+
+    let name = args.name.trim().to_lowercase();
+
+    if name.is_empty() || name.chars().count() > MAX_NAME_LEN {
+        let content = format!("Song names must have between 1 and {MAX_NAME_LEN} characters");
+        command.error(&ctx, content).await?;
+
+        return Ok(());
+    }
+
+    if BuiltinSong::find(&name).is_some() {
+        let content = format!("`{name}` is already the name of a built-in song");
+        command.error(&ctx, content).await?;
+
+        return Ok(());
+    }
+
+    if args.lyrics.size > MAX_FILE_SIZE {
+        let content = "The lyrics file must not be larger than 16KB";
+        command.error(&ctx, content).await?;
+
+        return Ok(());
+    }
+
+    let songs = match ctx.songs().guild_songs(guild).await {
+        Ok(songs) => songs,
+        Err(err) => {
+            let _ = command.error_localized(&ctx, ErrorKey::General).await;
+
+            return Err(err);
+        }
+    };
 
-    song(lyrics, delay, ctx, (&mut command).into()).await
+    let is_new = songs.iter().all(|song| song.name != name);
+
+    if is_new && songs.len() >= MAX_GUILD_SONGS {
+        let content = format!(
+            "The server's song library is full, it can't have more than {MAX_GUILD_SONGS} songs"
+        );
+        command.error(&ctx, content).await?;
+
+        return Ok(());
+    }
+
+    let bytes = match ctx.client().get_discord_attachment(&args.lyrics).await {
+        Ok(bytes) => bytes,
+        Err(err) => {
+            let _ = command.error_localized(&ctx, ErrorKey::General).await;
+
+            return Err(err.wrap_err("Failed to download attachment"));
+        }
+    };
+
+    let Ok(input) = std::str::from_utf8(&bytes) else {
+        let content = "The lyrics must be provided as UTF-8 text file";
+        command.error(&ctx, content).await?;
+
+        return Ok(());
+    };
+
+    let lyrics = match Lyrics::parse(input) {
+        Ok(lyrics) => lyrics,
+        Err(err) => {
+            command.error(&ctx, err.into_str()).await?;
+
+            return Ok(());
+        }
+    };
+
+    if let Err(err) = ctx.songs().store(guild, &name, input).await {
+        let _ = command.error_localized(&ctx, ErrorKey::General).await;
+
+        return Err(err);
+    }
+
+    let action = if is_new { "Added" } else { "Updated" };
+
+    let content = format!(
+        "{action} the song `{name}` with {} line(s).\n\
+        Use `/song sing` to hear it.",
+        lyrics.lines.len()
+    );
+
+    let builder = MessageBuilder::new().embed(content);
+    command.update(&ctx, builder).await?;
+
+    Ok(())
+}
+
+async fn remove(ctx: Arc<Context>, command: InteractionCommand, args: SongRemove) -> Result<()> {
+    command.defer(&ctx, false).await?;
+
+    let Some(guild) = command.guild_id else {
+        command
+            .error(&ctx, "Songs can only be removed on servers")
+            .await?;
+
+        return Ok(());
+    };
+
+    if !require_authority(&ctx, &command).await? {
+        return Ok(());
+    }
+
+    let name = args.name.trim().to_lowercase();
+
+    match ctx.songs().remove(guild, &name).await {
+        Ok(true) => {
+            let content = format!("Removed the song `{name}` from the server's song library");
+            let builder = MessageBuilder::new().embed(content);
+            command.update(&ctx, builder).await?;
+        }
+        Ok(false) => {
+            let content = format!("The server's song library has no song with the name `{name}`");
+            command.error(&ctx, content).await?;
+        }
+        Err(err) => {
+            let _ = command.error_localized(&ctx, ErrorKey::General).await;
+
+            return Err(err);
+        }
+    }
+
+    Ok(())
+}
+
+async fn list(ctx: Arc<Context>, command: InteractionCommand) -> Result<()> {
+    command.defer(&ctx, false).await?;
+
+    let songs = match command.guild_id {
+        Some(guild) => match ctx.songs().guild_songs(guild).await {
+            Ok(songs) => songs,
+            Err(err) => {
+                let _ = command.error_localized(&ctx, ErrorKey::General).await;
+
+                return Err(err);
+            }
+        },
+        None => Vec::new(),
+    };
+
+    let mut description = String::from("**Built-in songs:**\n");
+
+    for (i, song) in BuiltinSong::ALL.iter().enumerate() {
+        if i > 0 {
+            description.push_str(", ");
+        }
+
+        description.push_str(song.name());
+    }
+
+    if command.guild_id.is_some() {
+        description.push_str("\n\n**Server songs:**\n");
+
+        if songs.is_empty() {
+            description.push_str("None yet, server authorities can add some via `/song add`");
+        }
+
+        for (i, song) in songs.iter().enumerate() {
+            if i > 0 {
+                description.push_str(", ");
+            }
+
+            let _ = write!(description, "`{}`", song.name);
+        }
+    }
+
+    let embed = EmbedBuilder::new()
+        .description(description)
+        .title("Available songs");
+
+    let builder = MessageBuilder::new().embed(embed);
+    command.update(&ctx, builder).await?;
+
+    Ok(())
+}
+
+async fn handle_autocomplete(
+    ctx: &Context,
+    command: &InteractionCommand,
+    title: String,
+) -> Result<()> {
+    let title = title.to_lowercase();
+    let mut choices = Vec::with_capacity(25);
+
+    if let Some(guild) = command.guild_id {
+        let songs = ctx.songs().guild_songs(guild).await?;
+
+        for song in songs {
+            if song.name.contains(&title) {
+                choices.push(new_choice(song.name));
+            }
+        }
+    }
+
+    for song in BuiltinSong::ALL {
+        if song.name().to_lowercase().contains(&title) {
+            choices.push(new_choice(song.name().to_owned()));
+        }
+    }
+
+    choices.truncate(25);
+    command.autocomplete(ctx, choices).await?;
+
+    Ok(())
+}
+
+fn new_choice(name: String) -> CommandOptionChoice {
+    CommandOptionChoice {
+        name: name.clone(),
+        name_localizations: None,
+        value: CommandOptionChoiceValue::String(name),
+    }
 }
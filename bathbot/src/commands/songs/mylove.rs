@@ -1,14 +0,0 @@
-pub fn mylove_() -> (&'static [&'static str], u64) {
-    let lyrics = &[
-        "I wanna be your man,",
-        "your lover and your friend.",
-        "I'm gonna love you true.",
-        "I wanna be the one -",
-        "you come - home - to",
-        "I'm gonna treat you right.",
-        "I'll do ya every night,",
-        "myyyy looove",
-    ];
-
-    (lyrics, 1800)
-}
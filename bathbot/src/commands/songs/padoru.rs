@@ -1,10 +0,0 @@
-pub fn padoru_() -> (&'static [&'static str], u64) {
-    let lyrics = &[
-        "HASHIRE SORI YO",
-        "KAZE NO YOU NI",
-        "TSUKIMIHARA WO",
-        "PADORU PADORUUUU",
-    ];
-
-    (lyrics, 2500)
-}
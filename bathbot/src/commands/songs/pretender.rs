@@ -3,6 +3,7 @@ use std::sync::Arc;
 use bathbot_macros::command;
 use eyre::Result;
 
+use super::BuiltinSong;
 use crate::Context;
 
 #[command]
@@ -10,18 +11,5 @@ use crate::Context;
 #[group(Songs)]
 #[flags(SKIP_DEFER)]
 async fn prefix_pretender(ctx: Arc<Context>, msg: &Message) -> Result<()> {
-    let (lyrics, delay) = pretender_();
-
-    super::song(lyrics, delay, ctx, msg.into()).await
-}
-
-pub fn pretender_() -> (&'static [&'static str], u64) {
-    let lyrics = &[
-        "What if I say I'm not like the others?",
-        "What if I say I'm not just another oooone of your plays?",
-        "You're the pretender",
-        "What if I say that I will never surrender?",
-    ];
-
-    (lyrics, 3000)
+    super::builtin_song(BuiltinSong::Pretender, ctx, msg.into()).await
 }
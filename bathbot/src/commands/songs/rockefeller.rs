@@ -3,6 +3,7 @@ use std::sync::Arc;
 use bathbot_macros::command;
 use eyre::Result;
 
+use super::BuiltinSong;
 use crate::Context;
 
 #[command]
@@ -11,20 +12,5 @@ use crate::Context;
 #[alias("1273")]
 #[flags(SKIP_DEFER)]
 pub async fn prefix_rockefeller(ctx: Arc<Context>, msg: &Message) -> Result<()> {
-    let (lyrics, delay) = rockefeller_();
-
-    super::song(lyrics, delay, ctx, msg.into()).await
-}
-
-pub fn rockefeller_() -> (&'static [&'static str], u64) {
-    let lyrics = &[
-        "1 - 2 - 7 - 3",
-        "down the Rockefeller street.",
-        "Life is marchin' on, do you feel that?",
-        "1 - 2 - 7 - 3",
-        "down the Rockefeller street.",
-        "Everything is more than surreal",
-    ];
-
-    (lyrics, 2250)
+    super::builtin_song(BuiltinSong::Rockefeller, ctx, msg.into()).await
 }
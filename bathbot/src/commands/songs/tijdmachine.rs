@@ -3,6 +3,7 @@ use std::sync::Arc;
 use bathbot_macros::command;
 use eyre::Result;
 
+use super::BuiltinSong;
 use crate::Context;
 
 #[command]
@@ -10,21 +11,5 @@ use crate::Context;
 #[group(Songs)]
 #[flags(SKIP_DEFER)]
 async fn prefix_tijdmachine(ctx: Arc<Context>, msg: &Message) -> Result<()> {
-    let (lyrics, delay) = tijdmachine_();
-
-    super::song(lyrics, delay, ctx, msg.into()).await
-}
-
-pub fn tijdmachine_() -> (&'static [&'static str], u64) {
-    let lyrics = &[
-        "Als ik denk aan al die dagen,",
-        "dat ik mij zo heb misdragen.",
-        "Dan denk ik, - had ik maar een tijdmachine -- tijdmachine",
-        "Maar die heb ik niet,",
-        "dus zal ik mij gedragen,",
-        "en zal ik blijven sparen,",
-        "sparen voor een tiiijdmaaachine.",
-    ];
-
-    (lyrics, 2500)
+    super::builtin_song(BuiltinSong::Tijdmachine, ctx, msg.into()).await
 }
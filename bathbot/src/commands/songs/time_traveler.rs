@@ -1,10 +0,0 @@
-pub fn time_traveler_() -> (&'static [&'static str], u64) {
-    let lyrics = &[
-        "You're like a \\~\\~ time traveler",
-        "You like to \\~\\~ go backwards",
-        "You're like a \\~\\~ time traveler",
-        "Running from \\~\\~ the future",
-    ];
-
-    (lyrics, 3100)
-}
@@ -1,11 +0,0 @@
-pub fn wordsneversaid_() -> (&'static [&'static str], u64) {
-    let lyrics = &[
-        "It's so loooouuud insiiide my head.",
-        "With words that I - should have said.",
-        "As I drooooown in my regrets.",
-        "I can't take back - the words I never said.",
-        "I never saaaiid...",
-    ];
-
-    (lyrics, 6000)
-}
@@ -1,11 +0,0 @@
-pub fn zenzenzense_() -> (&'static [&'static str], u64) {
-    let lyrics = &[
-        "Kimi no zen zen zense kara boku wa kimi wo sagashi hajimeta yo",
-        "Sono bukiccho na waraikata wo megakete yatte kitanda yo",
-        "Kimi ga zenzen zenbu naku natte chirijiri ni nattatte",
-        "Mou mayowanai mata ichi kara sagashi hajimeru sa",
-        "Mushiro zero kara mata uchuu wo hajimete miyou ka",
-    ];
-
-    (lyrics, 4900)
-}
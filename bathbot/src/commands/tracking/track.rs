@@ -1,14 +1,14 @@
 use std::sync::Arc;
 
 use bathbot_macros::command;
-use bathbot_util::{constants::OSU_API_ISSUE, MessageBuilder};
+use bathbot_util::MessageBuilder;
 use eyre::{Report, Result};
 use rosu_v2::prelude::{GameMode, OsuError};
 use time::OffsetDateTime;
 
 use super::TrackArgs;
 use crate::{
-    core::commands::CommandOrigin,
+    core::{commands::CommandOrigin, locale::ErrorKey},
     embeds::{EmbedData, TrackEmbed},
     util::ChannelExt,
     Context,
@@ -61,7 +61,7 @@ pub(super) async fn track(
             return orig.error(&ctx, content).await;
         }
         Err((err, _)) => {
-            let _ = orig.error(&ctx, OSU_API_ISSUE).await;
+            let _ = orig.error_localized(&ctx, ErrorKey::OsuApi).await;
             let err = Report::new(err).wrap_err("failed to get names");
 
             return Err(err);
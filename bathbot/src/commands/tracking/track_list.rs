@@ -2,7 +2,7 @@ use std::{collections::HashMap, sync::Arc};
 
 use bathbot_macros::command;
 use bathbot_psql::model::osu::TrackedOsuUserKey;
-use bathbot_util::{constants::OSU_API_ISSUE, MessageBuilder};
+use bathbot_util::MessageBuilder;
 use eyre::{Report, Result};
 use rosu_v2::{
     prelude::{GameMode, OsuError, Username},
@@ -11,7 +11,7 @@ use rosu_v2::{
 use twilight_model::id::{marker::ChannelMarker, Id};
 
 use crate::{
-    core::commands::CommandOrigin,
+    core::{commands::CommandOrigin, locale::ErrorKey},
     embeds::{EmbedData, TrackListEmbed},
     manager::redis::osu::UserArgs,
     Context,
@@ -39,7 +39,7 @@ pub async fn tracklist(ctx: Arc<Context>, orig: CommandOrigin<'_>) -> Result<()>
     let mut users = match get_users(&ctx, orig.channel_id(), tracked).await {
         Ok(entries) => entries,
         Err(err) => {
-            let _ = orig.error(&ctx, OSU_API_ISSUE).await;
+            let _ = orig.error_localized(&ctx, ErrorKey::OsuApi).await;
 
             return Err(Report::new(err).wrap_err("failed to get users"));
         }
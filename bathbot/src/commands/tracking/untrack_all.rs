@@ -1,11 +1,15 @@
 use std::sync::Arc;
 
 use bathbot_macros::command;
-use bathbot_util::{constants::GENERAL_ISSUE, MessageBuilder};
+use bathbot_util::MessageBuilder;
 use eyre::Result;
 use rosu_v2::model::GameMode;
 
-use crate::{core::commands::CommandOrigin, util::ChannelExt, Context};
+use crate::{
+    core::{commands::CommandOrigin, locale::ErrorKey},
+    util::ChannelExt,
+    Context,
+};
 
 #[command]
 #[desc("Untrack all users in a channel")]
@@ -58,7 +62,7 @@ pub async fn untrackall(
             Ok(())
         }
         Err(err) => {
-            let _ = orig.error(&ctx, GENERAL_ISSUE).await;
+            let _ = orig.error_localized(&ctx, ErrorKey::General).await;
 
             Err(err.wrap_err("failed to remove channel from osu tracking"))
         }
@@ -1,13 +1,14 @@
 use std::sync::Arc;
 
 use bathbot_macros::command;
-use bathbot_util::{
-    constants::{GENERAL_ISSUE, TWITCH_API_ISSUE},
-    CowUtils, MessageBuilder,
-};
+use bathbot_util::{CowUtils, MessageBuilder};
 use eyre::Result;
 
-use crate::{core::commands::CommandOrigin, util::ChannelExt, Context};
+use crate::{
+    core::{commands::CommandOrigin, locale::ErrorKey},
+    util::ChannelExt,
+    Context,
+};
 
 #[command]
 #[flags(AUTHORITY, ONLY_GUILDS)]
@@ -39,7 +40,7 @@ pub async fn addstream(ctx: Arc<Context>, orig: CommandOrigin<'_>, name: &'_ str
             return orig.error(&ctx, content).await;
         }
         Err(err) => {
-            let _ = orig.error(&ctx, TWITCH_API_ISSUE).await;
+            let _ = orig.error_localized(&ctx, ErrorKey::Twitch).await;
 
             return Err(err.wrap_err("failed to get twitch user"));
         }
@@ -50,6 +51,12 @@ pub async fn addstream(ctx: Arc<Context>, orig: CommandOrigin<'_>, name: &'_ str
 
     match ctx.twitch().track(channel, twitch_id).await {
         Ok(true) => {
+            #[cfg(feature = "server")]
+            if let Err(err) = ctx.subscribe_twitch_events(twitch_id).await {
+                // Polling will still pick up the stream
+                warn!(?err, "Failed to subscribe to twitch stream events");
+            }
+
             let content = format!("I'm now tracking `{name}`'s twitch stream in this channel");
             let builder = MessageBuilder::new().embed(content);
 
@@ -65,7 +72,7 @@ pub async fn addstream(ctx: Arc<Context>, orig: CommandOrigin<'_>, name: &'_ str
             orig.error(&ctx, content).await
         }
         Err(err) => {
-            let _ = orig.error(&ctx, GENERAL_ISSUE).await;
+            let _ = orig.error_localized(&ctx, ErrorKey::General).await;
 
             Err(err.wrap_err("failed to add stream track"))
         }
@@ -5,8 +5,9 @@ use std::sync::Arc;
 use bathbot_macros::SlashCommand;
 use eyre::Result;
 use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::id::{marker::RoleMarker, Id};
 
-pub use self::{addstream::*, removestream::*, tracked::*};
+pub use self::{addstream::*, removestream::*, settings::*, tracked::*};
 use crate::{
     util::{interaction::InteractionCommand, InteractionCommandExt},
     Context,
@@ -14,6 +15,7 @@ use crate::{
 
 pub mod addstream;
 pub mod removestream;
+pub mod settings;
 pub mod tracked;
 
 #[derive(CommandModel, CreateCommand, SlashCommand)]
@@ -22,7 +24,9 @@ pub mod tracked;
     dm_permission = false,
     desc = "Track a twitch stream or list all tracked streams in this channel",
     help = "Track a twitch stream in this channel.\n\
-    When the stream goes online, a notification will be send to this channel within a few minutes."
+    When the stream goes online, a notification will be send to this channel \
+    within a few minutes.\n\
+    Notifications can be adjusted with `/trackstream settings`."
 )]
 #[flags(AUTHORITY)]
 pub enum TrackStream {
@@ -32,6 +36,8 @@ pub enum TrackStream {
     Remove(TrackStreamRemove),
     #[command(name = "list")]
     List(TrackStreamList),
+    #[command(name = "settings")]
+    Settings(TrackStreamSettings),
 }
 
 #[derive(CommandModel, CreateCommand)]
@@ -60,6 +66,40 @@ pub struct TrackStreamRemove {
 )]
 pub struct TrackStreamList;
 
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "settings",
+    desc = "Adjust the notifications of a tracked twitch stream",
+    help = "Adjust the notifications of a twitch stream that is tracked in this channel.\n\
+    - `role`: Mention this role in the notification\n\
+    - `message`: Custom text that is sent along with the notification. \
+    The placeholders `{name}`, `{title}`, `{game}`, and `{url}` will be replaced accordingly.\n\
+    - `keywords`: Comma-separated list of keywords. \
+    If specified, notifications are only sent if the stream's title or game contain one of them.\n\
+    - `edit_offline`: Whether the notification should be edited to show the stream's duration \
+    and VOD once the stream goes offline. Enabled by default.\n\
+    - `reset`: Reset all settings to their defaults before applying the specified ones.\n\
+    Without any options, the current settings will be shown."
+)]
+pub struct TrackStreamSettings {
+    #[command(desc = "Name of the twitch channel")]
+    name: String,
+    #[command(desc = "Specify a role to mention in the notification")]
+    role: Option<Id<RoleMarker>>,
+    #[command(
+        desc = "Specify a custom message, {name}, {title}, {game}, and {url} are placeholders"
+    )]
+    message: Option<String>,
+    #[command(
+        desc = "Only notify if the title or game contain one of these comma-separated keywords"
+    )]
+    keywords: Option<String>,
+    #[command(desc = "Whether the notification should be edited once the stream goes offline")]
+    edit_offline: Option<bool>,
+    #[command(desc = "Reset all settings before applying the specified ones")]
+    reset: Option<bool>,
+}
+
 pub async fn slash_trackstream(ctx: Arc<Context>, mut command: InteractionCommand) -> Result<()> {
     match TrackStream::from_interaction(command.input_data())? {
         TrackStream::Add(add) => addstream(ctx, (&mut command).into(), add.name.as_ref()).await,
@@ -67,5 +107,6 @@ pub async fn slash_trackstream(ctx: Arc<Context>, mut command: InteractionComman
             removestream(ctx, (&mut command).into(), remove.name.as_ref()).await
         }
         TrackStream::List(_) => tracked(ctx, (&mut command).into()).await,
+        TrackStream::Settings(args) => streamsettings(ctx, command, args).await,
     }
 }
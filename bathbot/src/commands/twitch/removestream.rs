@@ -1,13 +1,14 @@
 use std::sync::Arc;
 
 use bathbot_macros::command;
-use bathbot_util::{
-    constants::{GENERAL_ISSUE, TWITCH_API_ISSUE},
-    CowUtils, MessageBuilder,
-};
+use bathbot_util::{CowUtils, MessageBuilder};
 use eyre::Result;
 
-use crate::{core::commands::CommandOrigin, util::ChannelExt, Context};
+use crate::{
+    core::{commands::CommandOrigin, locale::ErrorKey},
+    util::ChannelExt,
+    Context,
+};
 
 #[command]
 #[flags(AUTHORITY, ONLY_GUILDS)]
@@ -40,7 +41,7 @@ pub async fn removestream(ctx: Arc<Context>, orig: CommandOrigin<'_>, name: &'_
             return Ok(());
         }
         Err(err) => {
-            let _ = orig.error(&ctx, TWITCH_API_ISSUE).await;
+            let _ = orig.error_localized(&ctx, ErrorKey::Twitch).await;
 
             return Err(err.wrap_err("failed to get twitch user"));
         }
@@ -53,6 +54,11 @@ pub async fn removestream(ctx: Arc<Context>, orig: CommandOrigin<'_>, name: &'_
         Ok(true) => {
             trace!("No longer tracking {name}'s twitch for channel {channel}");
 
+            #[cfg(feature = "server")]
+            if let Err(err) = ctx.unsubscribe_twitch_events(twitch_id).await {
+                warn!(?err, "Failed to unsubscribe from twitch stream events");
+            }
+
             let content =
                 format!("I'm no longer tracking `{name}`'s twitch stream in this channel");
 
@@ -67,7 +73,7 @@ pub async fn removestream(ctx: Arc<Context>, orig: CommandOrigin<'_>, name: &'_
             orig.error(&ctx, content).await
         }
         Err(err) => {
-            let _ = orig.error(&ctx, GENERAL_ISSUE).await;
+            let _ = orig.error_localized(&ctx, ErrorKey::General).await;
 
             Err(err.wrap_err("failed to remove stream track"))
         }
@@ -0,0 +1,183 @@
+use std::{fmt::Write, sync::Arc};
+
+use bathbot_util::{CowUtils, EmbedBuilder, MessageBuilder};
+use eyre::Result;
+
+use super::TrackStreamSettings;
+use crate::{
+    core::locale::ErrorKey,
+    util::{interaction::InteractionCommand, InteractionCommandExt},
+    Context,
+};
+
+const MAX_MESSAGE_LEN: usize = 500;
+const MAX_KEYWORDS: usize = 10;
+const MAX_KEYWORD_LEN: usize = 32;
+
+pub async fn streamsettings(
+    ctx: Arc<Context>,
+    command: InteractionCommand,
+    args: TrackStreamSettings,
+) -> Result<()> {
+    let TrackStreamSettings {
+        name,
+        role,
+        message,
+        keywords,
+        edit_offline,
+        reset,
+    } = args;
+
+    let name = name.cow_to_ascii_lowercase();
+
+    let twitch_id = match ctx.client().get_twitch_user(name.as_ref()).await {
+        Ok(Some(user)) => user.user_id,
+        Ok(None) => {
+            let content = format!("Twitch user `{name}` was not found");
+            command.error(&ctx, content).await?;
+
+            return Ok(());
+        }
+        Err(err) => {
+            let _ = command.error_localized(&ctx, ErrorKey::Twitch).await;
+
+            return Err(err.wrap_err("failed to get twitch user"));
+        }
+    };
+
+    let channel = command.channel_id;
+
+    let mut settings = match ctx
+        .twitch()
+        .channel_notif_settings(channel, twitch_id)
+        .await
+    {
+        Ok(Some(settings)) => settings,
+        Ok(None) => {
+            let content = format!("Twitch user `{name}` is not tracked in this channel");
+            command.error(&ctx, content).await?;
+
+            return Ok(());
+        }
+        Err(err) => {
+            let _ = command.error_localized(&ctx, ErrorKey::General).await;
+
+            return Err(err);
+        }
+    };
+
+    let changed = role.is_some()
+        || message.is_some()
+        || keywords.is_some()
+        || edit_offline.is_some()
+        || reset.is_some();
+
+    if reset == Some(true) {
+        settings.role_id = None;
+        settings.message = None;
+        settings.keywords.clear();
+        settings.edit_offline = true;
+    }
+
+    if let Some(role) = role {
+        settings.role_id = Some(role.get() as i64);
+    }
+
+    if let Some(message) = message {
+        if message.chars().count() > MAX_MESSAGE_LEN {
+            let content =
+                format!("The custom message must not be longer than {MAX_MESSAGE_LEN} characters");
+            command.error(&ctx, content).await?;
+
+            return Ok(());
+        }
+
+        settings.message = Some(message);
+    }
+
+    if let Some(keywords) = keywords {
+        let keywords: Vec<_> = keywords
+            .split(',')
+            .map(str::trim)
+            .filter(|keyword| !keyword.is_empty())
+            .map(str::to_lowercase)
+            .collect();
+
+        if keywords.len() > MAX_KEYWORDS {
+            let content = format!("There can be at most {MAX_KEYWORDS} keywords");
+            command.error(&ctx, content).await?;
+
+            return Ok(());
+        }
+
+        if keywords
+            .iter()
+            .any(|keyword| keyword.chars().count() > MAX_KEYWORD_LEN)
+        {
+            let content = format!("Keywords must not be longer than {MAX_KEYWORD_LEN} characters");
+            command.error(&ctx, content).await?;
+
+            return Ok(());
+        }
+
+        settings.keywords = keywords;
+    }
+
+    if let Some(edit_offline) = edit_offline {
+        settings.edit_offline = edit_offline;
+    }
+
+    if changed {
+        if let Err(err) = ctx
+            .twitch()
+            .update_notif_settings(twitch_id, &settings)
+            .await
+        {
+            let _ = command.error_localized(&ctx, ErrorKey::General).await;
+
+            return Err(err);
+        }
+    }
+
+    let mut description = String::from("Role: ");
+
+    match settings.role_id {
+        Some(role) => {
+            let _ = write!(description, "<@&{role}>");
+        }
+        None => description.push_str("None"),
+    }
+
+    description.push_str("\nMessage: ");
+
+    match settings.message {
+        Some(ref message) => description.push_str(message),
+        None => description.push_str("None"),
+    }
+
+    description.push_str("\nKeywords: ");
+
+    if settings.keywords.is_empty() {
+        description.push_str("None");
+    }
+
+    for (i, keyword) in settings.keywords.iter().enumerate() {
+        if i > 0 {
+            description.push_str(", ");
+        }
+
+        let _ = write!(description, "`{keyword}`");
+    }
+
+    let edit_offline = if settings.edit_offline { "Yes" } else { "No" };
+    let _ = write!(description, "\nEdit when offline: {edit_offline}");
+
+    let embed = EmbedBuilder::new().description(description).title(format!(
+        "Notification settings for `{name}` in this channel"
+    ));
+
+    let builder = MessageBuilder::new().embed(embed);
+    command.update(&ctx, builder).await?;
+
+    Ok(())
+}
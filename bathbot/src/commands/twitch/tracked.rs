@@ -1,10 +1,13 @@
 use std::{fmt::Write, sync::Arc};
 
 use bathbot_macros::command;
-use bathbot_util::{constants::GENERAL_ISSUE, MessageBuilder};
+use bathbot_util::MessageBuilder;
 use eyre::Result;
 
-use crate::{core::commands::CommandOrigin, Context};
+use crate::{
+    core::{commands::CommandOrigin, locale::ErrorKey},
+    Context,
+};
 
 #[command]
 #[desc("List all streams that are tracked in a channel")]
@@ -20,7 +23,7 @@ pub async fn tracked(ctx: Arc<Context>, orig: CommandOrigin<'_>) -> Result<()> {
     let mut twitch_users: Vec<_> = match ctx.client().get_twitch_users(&twitch_ids).await {
         Ok(users) => users.into_iter().map(|user| user.display_name).collect(),
         Err(err) => {
-            let _ = orig.error(&ctx, GENERAL_ISSUE).await;
+            let _ = orig.error_localized(&ctx, ErrorKey::General).await;
 
             return Err(err.wrap_err("failed to get twitch users"));
         }
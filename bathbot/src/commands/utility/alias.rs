@@ -0,0 +1,223 @@
+use std::{fmt::Write, sync::Arc};
+
+use bathbot_macros::command;
+use bathbot_psql::model::configs::{CommandAlias, CommandAliases, GuildConfig};
+use bathbot_util::{CowUtils, MessageBuilder};
+use eyre::Result;
+use twilight_model::guild::Permissions;
+
+use crate::{
+    core::{
+        commands::{checks::check_authority, prefix::PrefixCommands, CommandOrigin},
+        locale::ErrorKey,
+    },
+    util::ChannelExt,
+    Context,
+};
+
+const MAX_NAME_LEN: usize = 16;
+const MAX_ARGS_LEN: usize = 100;
+
+#[command]
+#[desc("Manage custom command aliases for a server")]
+#[help(
+    "Manage custom command aliases for a server.\n\
+    An alias expands to a prefix command and preset arguments. \
+    Any arguments given when using the alias will be appended to the preset ones.\n\
+    To check the current aliases for this server, don't pass any arguments.\n\
+    Otherwise, the first argument must be either `add` or `remove`.\n\
+    When adding, the following arguments must be the alias name, \
+    the command name, and optionally the preset arguments.\n\
+    When removing, the following argument must be the alias name.\n\
+    Alias names must be alphanumeric and cannot be names of existing commands.\n\
+    Servers can have up to 25 aliases."
+)]
+#[usage("[add / remove] [alias name] [command name] [arguments]")]
+#[example(
+    "add ttd top mode=taiko +DT",
+    "add mrs recent mode=mania",
+    "remove ttd"
+)]
+#[alias("aliases")]
+#[flags(ONLY_GUILDS, SKIP_DEFER)] // authority check is done manually
+#[group(Utility)]
+async fn prefix_alias(
+    ctx: Arc<Context>,
+    msg: &Message,
+    mut args: Args<'_>,
+    permissions: Option<Permissions>,
+) -> Result<()> {
+    let guild_id = msg.guild_id.unwrap();
+
+    let Some(action) = args.next() else {
+        let mut content = String::new();
+
+        let f = |config: &GuildConfig| current_aliases(&mut content, &config.command_aliases);
+        ctx.guild_config().peek(guild_id, f).await;
+
+        let builder = MessageBuilder::new().embed(content);
+        msg.create_message(&ctx, builder, permissions).await?;
+
+        return Ok(());
+    };
+
+    match check_authority(&ctx, msg.author.id, msg.guild_id).await {
+        Ok(None) => {}
+        Ok(Some(content)) => {
+            msg.error(&ctx, content).await?;
+
+            return Ok(());
+        }
+        Err(err) => {
+            let _ = CommandOrigin::from(msg)
+                .error_localized(&ctx, ErrorKey::General)
+                .await;
+
+            return Err(err.wrap_err("Failed to check authority status"));
+        }
+    }
+
+    let Some(name) = args.next().map(CowUtils::cow_to_ascii_lowercase) else {
+        let content = "After the first argument you should specify the alias name";
+        msg.error(&ctx, content).await?;
+
+        return Ok(());
+    };
+
+    let update_res = match action {
+        "add" | "a" => {
+            if name.len() > MAX_NAME_LEN || !name.chars().all(|c| c.is_ascii_alphanumeric()) {
+                let content = format!(
+                    "Alias names must be alphanumeric and can have at most {MAX_NAME_LEN} characters"
+                );
+                msg.error(&ctx, content).await?;
+
+                return Ok(());
+            }
+
+            if PrefixCommands::get().command(name.as_ref()).is_some() {
+                let content = format!("`{name}` is already the name of a command");
+                msg.error(&ctx, content).await?;
+
+                return Ok(());
+            }
+
+            let command = args
+                .next()
+                .map(CowUtils::cow_to_ascii_lowercase)
+                .and_then(|command| PrefixCommands::get().command(command.as_ref()));
+
+            let Some(command) = command else {
+                let content = "After the alias name you should specify the name \
+                    of an existing prefix command";
+                msg.error(&ctx, content).await?;
+
+                return Ok(());
+            };
+
+            let preset = args.rest().trim();
+
+            if preset.len() > MAX_ARGS_LEN {
+                let content =
+                    format!("The preset arguments can have at most {MAX_ARGS_LEN} characters");
+                msg.error(&ctx, content).await?;
+
+                return Ok(());
+            }
+
+            let alias = CommandAlias {
+                name: name.as_ref().into(),
+                command: command.name().into(),
+                args: preset.to_owned(),
+            };
+
+            let update_fut = ctx
+                .guild_config()
+                .update(guild_id, |config| config.command_aliases.insert(alias));
+
+            match update_fut.await {
+                Ok(true) => Ok(()),
+                Ok(false) => {
+                    let content = format!(
+                        "Cannot add more aliases, the limit of {} is already reached",
+                        CommandAliases::LEN
+                    );
+                    msg.error(&ctx, content).await?;
+
+                    return Ok(());
+                }
+                Err(err) => Err(err),
+            }
+        }
+        "remove" | "r" => {
+            let update_fut = ctx
+                .guild_config()
+                .update(guild_id, |config| config.command_aliases.remove(&name));
+
+            match update_fut.await {
+                Ok(Some(_)) => Ok(()),
+                Ok(None) => {
+                    let content = format!("There is no alias `{name}` in this server");
+                    msg.error(&ctx, content).await?;
+
+                    return Ok(());
+                }
+                Err(err) => Err(err),
+            }
+        }
+        other => {
+            let content = format!(
+                "If any arguments are provided, the first one \
+                must be either `add` or `remove`, not `{other}`"
+            );
+
+            msg.error(&ctx, content).await?;
+
+            return Ok(());
+        }
+    };
+
+    if let Err(err) = update_res {
+        let _ = CommandOrigin::from(msg)
+            .error_localized(&ctx, ErrorKey::General)
+            .await;
+
+        return Err(err.wrap_err("failed to update guild config"));
+    }
+
+    let mut content = "Aliases updated!\n".to_owned();
+
+    let f = |config: &GuildConfig| current_aliases(&mut content, &config.command_aliases);
+    ctx.guild_config().peek(guild_id, f).await;
+
+    let builder = MessageBuilder::new().embed(content);
+    msg.create_message(&ctx, builder, permissions).await?;
+
+    Ok(())
+}
+
+fn current_aliases(content: &mut String, aliases: &CommandAliases) {
+    if aliases.is_empty() {
+        content.push_str("This server has no custom aliases");
+    } else {
+        content.push_str("Aliases for this server:\n");
+        write_aliases(content, aliases);
+    }
+}
+
+/// Write each alias and its expansion on a separate line.
+pub fn write_aliases(content: &mut String, aliases: &CommandAliases) {
+    for (alias, i) in aliases.iter().zip(0..) {
+        if i > 0 {
+            content.push('\n');
+        }
+
+        let _ = write!(content, "`{}` → `{}", alias.name, alias.command);
+
+        if !alias.args.is_empty() {
+            let _ = write!(content, " {}", alias.args);
+        }
+
+        content.push('`');
+    }
+}
@@ -2,7 +2,7 @@ use std::{fmt::Write, sync::Arc};
 
 use bathbot_macros::command;
 use bathbot_psql::model::configs::{Authorities, GuildConfig};
-use bathbot_util::{constants::GENERAL_ISSUE, matcher, MessageBuilder};
+use bathbot_util::{matcher, MessageBuilder};
 use eyre::Result;
 use twilight_model::{
     guild::Permissions,
@@ -12,6 +12,7 @@ use twilight_model::{
 use crate::{
     core::{
         commands::{prefix::Args, CommandOrigin},
+        locale::ErrorKey,
         BotConfig, Context,
     },
     util::ChannelExt,
@@ -70,7 +71,7 @@ pub async fn authorities(
             };
 
             if let Err(err) = ctx.guild_config().update(guild_id, f).await {
-                let _ = orig.error_callback(&ctx, GENERAL_ISSUE).await;
+                let _ = orig.error_callback_localized(&ctx, ErrorKey::General).await;
 
                 return Err(err.wrap_err("failed to update guild config"));
             }
@@ -107,7 +108,7 @@ pub async fn authorities(
                     Ok(Some(member)) => member.roles().to_vec(),
                     Ok(None) => Vec::new(),
                     Err(err) => {
-                        let _ = orig.error_callback(&ctx, GENERAL_ISSUE).await;
+                        let _ = orig.error_callback_localized(&ctx, ErrorKey::General).await;
 
                         return Err(err);
                     }
@@ -119,7 +120,7 @@ pub async fn authorities(
                             || roles.iter().any(|&new| new == role.id && new != role_id)
                     }),
                     Err(err) => {
-                        let _ = orig.error_callback(&ctx, GENERAL_ISSUE).await;
+                        let _ = orig.error_callback_localized(&ctx, ErrorKey::General).await;
 
                         return Err(err);
                     }
@@ -136,7 +137,7 @@ pub async fn authorities(
             let f = |config: &mut GuildConfig| config.authorities.retain(|id| *id != role_id);
 
             if let Err(err) = ctx.guild_config().update(guild_id, f).await {
-                let _ = orig.error_callback(&ctx, GENERAL_ISSUE).await;
+                let _ = orig.error_callback_localized(&ctx, ErrorKey::General).await;
 
                 return Err(err.wrap_err("failed to update guild config"));
             }
@@ -160,7 +161,7 @@ pub async fn authorities(
                     Ok(Some(member)) => member.roles().to_vec(),
                     Ok(None) => Vec::new(),
                     Err(err) => {
-                        let _ = orig.error_callback(&ctx, GENERAL_ISSUE).await;
+                        let _ = orig.error_callback_localized(&ctx, ErrorKey::General).await;
 
                         return Err(err);
                     }
@@ -172,7 +173,7 @@ pub async fn authorities(
                             || roles.iter().any(|&new| new == role.id)
                     }),
                     Err(err) => {
-                        let _ = orig.error_callback(&ctx, GENERAL_ISSUE).await;
+                        let _ = orig.error_callback_localized(&ctx, ErrorKey::General).await;
 
                         return Err(err);
                     }
@@ -189,7 +190,7 @@ pub async fn authorities(
             let f = |config: &mut GuildConfig| config.authorities = roles.into_iter().collect();
 
             if let Err(err) = ctx.guild_config().update(guild_id, f).await {
-                let _ = orig.error_callback(&ctx, GENERAL_ISSUE).await;
+                let _ = orig.error_callback_localized(&ctx, ErrorKey::General).await;
 
                 return Err(err.wrap_err("failed to update guild config"));
             }
@@ -5,14 +5,14 @@ use std::{
 
 use bathbot_macros::SlashCommand;
 use bathbot_model::{PullRequests, PullRequestsAndTags, ReferencedIssue, Tag};
-use bathbot_util::constants::{FIELD_VALUE_SIZE, GENERAL_ISSUE};
+use bathbot_util::constants::FIELD_VALUE_SIZE;
 use eyre::{ContextCompat, Result, WrapErr};
 use time::OffsetDateTime;
 use twilight_interactions::command::{CommandModel, CreateCommand};
 
 use crate::{
     active::{impls::ChangelogPagination, ActiveMessages},
-    core::Context,
+    core::{locale::ErrorKey, Context},
     util::{interaction::InteractionCommand, Authored, InteractionCommandExt},
 };
 
@@ -24,14 +24,14 @@ async fn slash_changelog(ctx: Arc<Context>, mut command: InteractionCommand) ->
     let mut data = match ctx.github().tags_and_prs().await {
         Ok(res) => res,
         Err(err) => {
-            let _ = command.error(&ctx, GENERAL_ISSUE).await;
+            let _ = command.error_localized(&ctx, ErrorKey::General).await;
 
             return Err(err);
         }
     };
 
     if data.tags.len() != 25 {
-        let _ = command.error(&ctx, GENERAL_ISSUE).await;
+        let _ = command.error_localized(&ctx, ErrorKey::General).await;
 
         bail!("Expected 25 tags, got {}", data.tags.len());
     }
@@ -67,7 +67,7 @@ async fn create_pages(
     match pages_fut.await {
         Ok(res) => Ok(res),
         Err(err) => {
-            let _ = command.error(ctx, GENERAL_ISSUE).await;
+            let _ = command.error_localized(ctx, ErrorKey::General).await;
 
             Err(err.wrap_err("Failed to build pages"))
         }
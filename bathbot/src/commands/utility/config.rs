@@ -7,7 +7,6 @@ use bathbot_psql::model::configs::{
 };
 #[cfg(feature = "server")]
 use bathbot_server::AuthenticationStandbyError;
-use bathbot_util::constants::GENERAL_ISSUE;
 #[cfg(feature = "server")]
 use bathbot_util::{EmbedBuilder, MessageBuilder};
 use eyre::{Report, Result};
@@ -17,7 +16,8 @@ use twilight_model::id::{marker::UserMarker, Id};
 
 use super::{SkinValidation, ValidationStatus};
 use crate::{
-    commands::{ShowHideOption, TimezoneOption},
+    commands::{LanguageOption, ShowHideOption, TimezoneOption},
+    core::locale::{self, ErrorKey},
     embeds::{ConfigEmbed, EmbedData},
     util::{interaction::InteractionCommand, Authored, InteractionCommandExt},
     Context,
@@ -101,6 +101,14 @@ pub struct Config {
         In servers, this requires that the render button is not disabled in `/serverconfigs`."
     )]
     render_button: Option<ShowHideOption>,
+    #[command(
+        desc = "Specify the language in which the bot should respond to you",
+        help = "Specify the language in which the bot should respond to you.\n\
+        If no language is specified, the language of the server will be used instead.\n\
+        Choose `Default` to remove your language again.\n\
+        Not everything has been translated yet so some responses may still be in english."
+    )]
+    language: Option<LanguageOption>,
 }
 
 // FIXME: Some attribute command does not register the #[cfg(feature = "")]
@@ -161,6 +169,14 @@ pub struct Config {
         In servers, this requires that the render button is not disabled in `/serverconfigs`."
     )]
     render_button: Option<ShowHideOption>,
+    #[command(
+        desc = "Specify the language in which the bot should respond to you",
+        help = "Specify the language in which the bot should respond to you.\n\
+        If no language is specified, the language of the server will be used instead.\n\
+        Choose `Default` to remove your language again.\n\
+        Not everything has been translated yet so some responses may still be in english."
+    )]
+    language: Option<LanguageOption>,
 }
 
 #[derive(CommandOption, CreateOption)]
@@ -217,6 +233,7 @@ pub async fn config(ctx: Arc<Context>, command: InteractionCommand, config: Conf
         timezone,
         mut skin_url,
         render_button,
+        language,
     } = config;
 
     if let Some(ref skin_url) = skin_url {
@@ -231,7 +248,7 @@ pub async fn config(ctx: Arc<Context>, command: InteractionCommand, config: Conf
     let mut config = match ctx.user_config().with_osu_id(author.id).await {
         Ok(config) => config,
         Err(err) => {
-            let _ = command.error(&ctx, GENERAL_ISSUE).await;
+            let _ = command.error_localized(&ctx, ErrorKey::General).await;
 
             return Err(err);
         }
@@ -270,6 +287,10 @@ pub async fn config(ctx: Arc<Context>, command: InteractionCommand, config: Conf
         config.render_button = Some(matches!(render_button, ShowHideOption::Show));
     }
 
+    if let Some(language) = language {
+        config.language = language.into();
+    }
+
     #[cfg(feature = "server")]
     if let Some(ConfigLink::Unlink) = osu {
         config.osu.take();
@@ -301,7 +322,7 @@ pub async fn config(ctx: Arc<Context>, command: InteractionCommand, config: Conf
                 let update_fut = ctx.user_config().update_skin(author.id, Some(skin_url));
 
                 if let Err(err) = update_fut.await {
-                    command.error(&ctx, GENERAL_ISSUE).await?;
+                    command.error_localized(&ctx, ErrorKey::General).await?;
 
                     return Err(err);
                 }
@@ -320,7 +341,15 @@ pub async fn config(ctx: Arc<Context>, command: InteractionCommand, config: Conf
                 config
             };
 
-            let embed_data = ConfigEmbed::new(author, config, twitch_name, skin_url);
+            let lang = locale::language(
+                &ctx,
+                Some(author.id),
+                command.guild_id,
+                command.locale.as_deref(),
+            )
+            .await;
+
+            let embed_data = ConfigEmbed::new(author, config, twitch_name, skin_url, lang);
             let builder = embed_data.build().into();
             command.update(&ctx, builder).await?;
 
@@ -402,7 +431,7 @@ async fn handle_both_links(
     };
 
     if let Err(err) = ctx.user_config().store(author.id, config).await {
-        let _ = command.error(ctx, GENERAL_ISSUE).await;
+        let _ = command.error_localized(ctx, ErrorKey::General).await;
 
         return HandleResult::Err(err);
     }
@@ -440,7 +469,7 @@ async fn handle_twitch_link(
     };
 
     if let Err(err) = ctx.user_config().store(author.id, config).await {
-        let _ = command.error(ctx, GENERAL_ISSUE).await;
+        let _ = command.error_localized(ctx, ErrorKey::General).await;
 
         return HandleResult::Err(err);
     }
@@ -489,9 +518,7 @@ async fn handle_osu_link(
                 config.twitch_id.take();
             }
             Err(err) => {
-                let _ = command
-                    .error(ctx, bathbot_util::constants::TWITCH_API_ISSUE)
-                    .await;
+                let _ = command.error_localized(ctx, ErrorKey::Twitch).await;
 
                 return HandleResult::Err(err.wrap_err("failed to get twitch user by id"));
             }
@@ -499,7 +526,7 @@ async fn handle_osu_link(
     }
 
     if let Err(err) = ctx.user_config().store(author.id, config).await {
-        let _ = command.error(ctx, GENERAL_ISSUE).await;
+        let _ = command.error_localized(ctx, ErrorKey::General).await;
 
         return HandleResult::Err(err);
     }
@@ -518,14 +545,24 @@ async fn handle_ephemeral<T>(
         return Some(Err(eyre::Report::new(err)));
     }
 
-    let content = match fut.await {
+    let res = match fut.await {
         Ok(res) => return Some(Ok(res)),
-        Err(AuthenticationStandbyError::Timeout) => "You did not authenticate in time",
-        Err(AuthenticationStandbyError::Canceled) => GENERAL_ISSUE,
+        Err(AuthenticationStandbyError::Timeout) => {
+            let content = "You did not authenticate in time";
+
+            command
+                .error(ctx, content)
+                .await
+                .map(|_| ())
+                .map_err(Into::into)
+        }
+        Err(AuthenticationStandbyError::Canceled) => {
+            command.error_localized(ctx, ErrorKey::General).await
+        }
     };
 
-    if let Err(err) = command.error(ctx, content).await {
-        return Some(Err(err.into()));
+    if let Err(err) = res {
+        return Some(Err(err));
     }
 
     None
@@ -552,9 +589,7 @@ async fn handle_no_links(
                 config.twitch_id.take();
             }
             Err(err) => {
-                let _ = command
-                    .error(ctx, bathbot_util::constants::TWITCH_API_ISSUE)
-                    .await;
+                let _ = command.error_localized(ctx, ErrorKey::Twitch).await;
 
                 return HandleResult::Err(err.wrap_err("failed to get twitch user by id"));
             }
@@ -567,7 +602,7 @@ async fn handle_no_links(
     }
 
     if let Err(err) = ctx.user_config().store(author.id, config).await {
-        let _ = command.error(ctx, GENERAL_ISSUE).await;
+        let _ = command.error_localized(ctx, ErrorKey::General).await;
 
         return HandleResult::Err(err);
     }
@@ -604,6 +639,7 @@ async fn convert_config(
         twitch_id,
         timezone,
         render_button,
+        language,
     } = config;
 
     UserConfig {
@@ -616,6 +652,7 @@ async fn convert_config(
         twitch_id,
         timezone,
         render_button,
+        language,
     }
 }
 
@@ -1,3 +1,4 @@
+mod alias;
 mod authorities;
 mod changelog;
 mod commands;
@@ -6,10 +7,11 @@ mod invite;
 mod ping;
 mod prefix;
 mod roll;
+mod schedule;
 mod server_config;
 mod skin;
 
 pub use self::{
-    authorities::*, changelog::*, commands::*, config::*, invite::*, ping::*, prefix::*, roll::*,
-    server_config::*, skin::*,
+    alias::*, authorities::*, changelog::*, commands::*, config::*, invite::*, ping::*, prefix::*,
+    roll::*, schedule::*, server_config::*, skin::*,
 };
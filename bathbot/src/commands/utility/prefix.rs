@@ -3,12 +3,19 @@ use std::{cmp::Ordering, fmt::Write, sync::Arc};
 use aho_corasick::{AhoCorasick, AhoCorasickBuilder};
 use bathbot_macros::command;
 use bathbot_psql::model::configs::{GuildConfig, Prefix, Prefixes, DEFAULT_PREFIX};
-use bathbot_util::{constants::GENERAL_ISSUE, matcher, MessageBuilder};
+use bathbot_util::{matcher, MessageBuilder};
 use eyre::Result;
 use once_cell::sync::OnceCell;
 use twilight_model::guild::Permissions;
 
-use crate::{core::commands::checks::check_authority, util::ChannelExt, Context};
+use crate::{
+    core::{
+        commands::{checks::check_authority, CommandOrigin},
+        locale::ErrorKey,
+    },
+    util::ChannelExt,
+    Context,
+};
 
 #[command]
 #[desc("Change my prefixes for a server")]
@@ -54,7 +61,9 @@ async fn prefix_prefix(
             return Ok(());
         }
         Err(err) => {
-            let _ = msg.error(&ctx, GENERAL_ISSUE).await;
+            let _ = CommandOrigin::from(msg)
+                .error_localized(&ctx, ErrorKey::General)
+                .await;
 
             return Err(err.wrap_err("Failed to check authority status"));
         }
@@ -159,7 +168,9 @@ async fn prefix_prefix(
             Ok(())
         }
         Err(err) => {
-            let _ = msg.error(&ctx, GENERAL_ISSUE).await;
+            let _ = CommandOrigin::from(msg)
+                .error_localized(&ctx, ErrorKey::General)
+                .await;
 
             Err(err.wrap_err("failed to update guild config"))
         }
@@ -0,0 +1,211 @@
+use std::sync::Arc;
+
+use bathbot_macros::SlashCommand;
+use bathbot_util::MessageBuilder;
+use eyre::Result;
+use time::{OffsetDateTime, UtcOffset};
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::id::{marker::ChannelMarker, Id};
+
+use super::{parse::parse_time, write_scheduled, MAX_CONTENT_LEN};
+use crate::{
+    core::locale::ErrorKey,
+    util::{interaction::InteractionCommand, Authored, InteractionCommandExt},
+    Context,
+};
+
+/// Maximum amount of pending announcements per guild
+const MAX_ANNOUNCEMENTS: usize = 25;
+
+#[derive(CommandModel, CreateCommand, SlashCommand)]
+#[command(
+    name = "schedule",
+    dm_permission = false,
+    desc = "Schedule announcements for this server",
+    help = "Schedule a message to be sent in a channel of this server at a later time.\n\
+    Times can either be relative like `1h30m` or `in 2 days`, \
+    or absolute like `18:00`, `2023-12-24`, or `2023-12-24 18:00`.\n\
+    Absolute times use the timezone of your `/config`, defaulting to UTC.\n\
+    Servers can have up to 25 pending announcements."
+)]
+#[flags(AUTHORITY)]
+pub enum Schedule {
+    #[command(name = "add")]
+    Add(ScheduleAdd),
+    #[command(name = "list")]
+    List(ScheduleList),
+    #[command(name = "cancel")]
+    Cancel(ScheduleCancel),
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "add", desc = "Schedule a new announcement")]
+pub struct ScheduleAdd {
+    #[command(
+        desc = "Channel to send the announcement in",
+        channel_types = "guild_text guild_announcement"
+    )]
+    channel: Id<ChannelMarker>,
+    #[command(
+        desc = "When to send the announcement, e.g. `1h30m`, `18:00`, or `2023-12-24 18:00`"
+    )]
+    when: String,
+    #[command(desc = "Content of the announcement")]
+    message: String,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "list", desc = "List pending announcements of this server")]
+pub struct ScheduleList;
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "cancel", desc = "Cancel a pending announcement")]
+pub struct ScheduleCancel {
+    #[command(min_value = 1, desc = "Id of the announcement, see `/schedule list`")]
+    id: i64,
+}
+
+async fn slash_schedule(ctx: Arc<Context>, mut command: InteractionCommand) -> Result<()> {
+    match Schedule::from_interaction(command.input_data())? {
+        Schedule::Add(args) => add(ctx, command, args).await,
+        Schedule::List(_) => list(ctx, command).await,
+        Schedule::Cancel(args) => cancel(ctx, command, args).await,
+    }
+}
+
+async fn add(ctx: Arc<Context>, command: InteractionCommand, args: ScheduleAdd) -> Result<()> {
+    let guild_id = command.guild_id.unwrap();
+    let user_id = command.user_id()?;
+
+    if args.message.chars().count() > MAX_CONTENT_LEN {
+        let content = format!("The message can have at most {MAX_CONTENT_LEN} characters");
+        command.error(&ctx, content).await?;
+
+        return Ok(());
+    }
+
+    let tz = match ctx.user_config().timezone(user_id).await {
+        Ok(tz) => tz.unwrap_or(UtcOffset::UTC),
+        Err(err) => {
+            let _ = command.error_localized(&ctx, ErrorKey::General).await;
+
+            return Err(err);
+        }
+    };
+
+    let trigger_at = match parse_time(&args.when, OffsetDateTime::now_utc(), tz) {
+        Ok(trigger_at) => trigger_at,
+        Err(content) => {
+            command.error(&ctx, content).await?;
+
+            return Ok(());
+        }
+    };
+
+    let announcements = match ctx.schedule().announcements(guild_id).await {
+        Ok(announcements) => announcements,
+        Err(err) => {
+            let _ = command.error_localized(&ctx, ErrorKey::General).await;
+
+            return Err(err);
+        }
+    };
+
+    if announcements.len() >= MAX_ANNOUNCEMENTS {
+        let content = format!(
+            "Servers can have at most {MAX_ANNOUNCEMENTS} pending announcements. \
+            Cancel one via `/schedule cancel` first."
+        );
+        command.error(&ctx, content).await?;
+
+        return Ok(());
+    }
+
+    let add_fut =
+        ctx.schedule()
+            .add_announcement(user_id, guild_id, args.channel, &args.message, trigger_at);
+
+    let schedule_id = match add_fut.await {
+        Ok(schedule_id) => schedule_id,
+        Err(err) => {
+            let _ = command.error_localized(&ctx, ErrorKey::General).await;
+
+            return Err(err);
+        }
+    };
+
+    let timestamp = trigger_at.unix_timestamp();
+
+    let content = format!(
+        "The announcement will be sent in <#{channel}> <t:{timestamp}:R> (<t:{timestamp}:F>).\n\
+        Announcement id: `{schedule_id}`",
+        channel = args.channel,
+    );
+
+    let builder = MessageBuilder::new().embed(content);
+    command.update(&ctx, builder).await?;
+
+    Ok(())
+}
+
+async fn list(ctx: Arc<Context>, command: InteractionCommand) -> Result<()> {
+    let guild_id = command.guild_id.unwrap();
+
+    let announcements = match ctx.schedule().announcements(guild_id).await {
+        Ok(announcements) => announcements,
+        Err(err) => {
+            let _ = command.error_localized(&ctx, ErrorKey::General).await;
+
+            return Err(err);
+        }
+    };
+
+    let content = if announcements.is_empty() {
+        "This server has no pending announcements".to_owned()
+    } else {
+        let mut content = String::with_capacity(announcements.len() * 96);
+        write_scheduled(&mut content, &announcements);
+
+        content
+    };
+
+    let builder = MessageBuilder::new().embed(content);
+    command.update(&ctx, builder).await?;
+
+    Ok(())
+}
+
+async fn cancel(
+    ctx: Arc<Context>,
+    command: InteractionCommand,
+    args: ScheduleCancel,
+) -> Result<()> {
+    let guild_id = command.guild_id.unwrap();
+    let schedule_id = i32::try_from(args.id).unwrap_or(i32::MAX);
+
+    match ctx
+        .schedule()
+        .remove_announcement(guild_id, schedule_id)
+        .await
+    {
+        Ok(true) => {
+            let content = format!("Cancelled announcement `{schedule_id}`");
+            let builder = MessageBuilder::new().embed(content);
+            command.update(&ctx, builder).await?;
+
+            Ok(())
+        }
+        Ok(false) => {
+            let content =
+                format!("This server has no pending announcement with id `{schedule_id}`");
+            command.error(&ctx, content).await?;
+
+            Ok(())
+        }
+        Err(err) => {
+            let _ = command.error_localized(&ctx, ErrorKey::General).await;
+
+            Err(err)
+        }
+    }
+}
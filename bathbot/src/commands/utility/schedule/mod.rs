@@ -0,0 +1,43 @@
+use std::fmt::Write;
+
+use bathbot_psql::model::schedule::DbScheduledMessage;
+use bathbot_util::CowUtils;
+
+pub use self::{announce::*, parse::parse_time, remind::*};
+
+mod announce;
+mod parse;
+mod remind;
+
+/// Maximum amount of characters of a scheduled message
+const MAX_CONTENT_LEN: usize = 1000;
+
+/// Amount of characters shown when listing scheduled messages
+const PREVIEW_LEN: usize = 50;
+
+fn write_scheduled(content: &mut String, entries: &[DbScheduledMessage]) {
+    for entry in entries {
+        let timestamp = entry.trigger_at.unix_timestamp();
+
+        let _ = write!(
+            content,
+            "`{}` • <t:{timestamp}:F> (<t:{timestamp}:R>) in <#{}>",
+            entry.schedule_id, entry.channel_id
+        );
+
+        let preview: String = entry
+            .content
+            .chars()
+            .take(PREVIEW_LEN)
+            .map(|c| if c == '\n' { ' ' } else { c })
+            .collect();
+
+        let ellipsis = if entry.content.chars().count() > PREVIEW_LEN {
+            "..."
+        } else {
+            ""
+        };
+
+        let _ = writeln!(content, "\n> {}{ellipsis}", preview.cow_escape_markdown());
+    }
+}
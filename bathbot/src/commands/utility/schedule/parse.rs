@@ -0,0 +1,187 @@
+use time::{Date, Duration, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset};
+
+/// Furthest point in the future that can be scheduled
+const MAX_AHEAD: Duration = Duration::days(365);
+
+/// Parses either a relative time such as `1h30m` or `in 2 days`, or an
+/// absolute time such as `18:00`, `2023-12-24`, or `2023-12-24 18:00`.
+///
+/// Absolute times are interpreted in the given timezone. A time of day
+/// without date refers to its next occurrence.
+pub fn parse_time(
+    input: &str,
+    now: OffsetDateTime,
+    tz: UtcOffset,
+) -> Result<OffsetDateTime, &'static str> {
+    let input = input.trim();
+
+    let time = match parse_relative(input) {
+        Some(duration) => now
+            .checked_add(duration?)
+            .ok_or("The time must be within the next 365 days")?,
+        None => parse_absolute(input, now, tz)?,
+    };
+
+    if time <= now {
+        Err("The time must be in the future")
+    } else if time - now > MAX_AHEAD {
+        Err("The time must be within the next 365 days")
+    } else {
+        Ok(time)
+    }
+}
+
+/// Returns `None` if the input is not a relative time.
+fn parse_relative(input: &str) -> Option<Result<Duration, &'static str>> {
+    let input = input.strip_prefix("in ").unwrap_or(input).trim_start();
+
+    // Dates and times of day are absolute
+    if !input.starts_with(|c: char| c.is_ascii_digit()) || input.contains([':', '-']) {
+        return None;
+    }
+
+    let mut rest = input;
+    let mut total = Duration::ZERO;
+
+    while !rest.is_empty() {
+        let digits = rest
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(rest.len());
+
+        if digits == 0 {
+            return Some(Err("Expected a number before each unit, e.g. `1h30m`"));
+        }
+
+        let Ok(amount) = rest[..digits].parse::<i64>() else {
+            return Some(Err("The given amount is too large"));
+        };
+
+        rest = rest[digits..].trim_start();
+
+        let unit_len = rest
+            .find(|c: char| !c.is_ascii_alphabetic())
+            .unwrap_or(rest.len());
+
+        let unit_seconds = match rest[..unit_len].to_ascii_lowercase().as_str() {
+            "s" | "sec" | "secs" | "second" | "seconds" => 1,
+            "m" | "min" | "mins" | "minute" | "minutes" => 60,
+            "h" | "hr" | "hrs" | "hour" | "hours" => 60 * 60,
+            "d" | "day" | "days" => 24 * 60 * 60,
+            "w" | "week" | "weeks" => 7 * 24 * 60 * 60,
+            "" => return Some(Err("Expected a unit after each number, e.g. `1h30m`")),
+            _ => return Some(Err("Unknown unit, expected one of s, m, h, d, or w")),
+        };
+
+        let Some(seconds) = amount.checked_mul(unit_seconds) else {
+            return Some(Err("The given amount is too large"));
+        };
+
+        total = total.saturating_add(Duration::seconds(seconds));
+        rest = rest[unit_len..].trim_start_matches([' ', ',']);
+    }
+
+    Some(Ok(total))
+}
+
+fn parse_absolute(
+    input: &str,
+    now: OffsetDateTime,
+    tz: UtcOffset,
+) -> Result<OffsetDateTime, &'static str> {
+    const INVALID: &str = "Could not parse the time. \
+        Expected a relative time like `1h30m` or an absolute one like `18:00`, \
+        `2023-12-24`, or `2023-12-24 18:00`";
+
+    let (date, time) = match input.split_once([' ', 'T']) {
+        Some((date, time)) => (Some(date), Some(time.trim())),
+        None if input.contains(':') => (None, Some(input)),
+        None => (Some(input), None),
+    };
+
+    let time = match time {
+        Some(time) => parse_time_of_day(time).ok_or(INVALID)?,
+        None => Time::MIDNIGHT,
+    };
+
+    match date {
+        Some(date) => {
+            let date = parse_date(date).ok_or(INVALID)?;
+
+            Ok(PrimitiveDateTime::new(date, time).assume_offset(tz))
+        }
+        None => {
+            let local_now = now.to_offset(tz);
+            let today = local_now.replace_time(time);
+
+            if today > local_now {
+                Ok(today)
+            } else {
+                Ok(today + Duration::days(1))
+            }
+        }
+    }
+}
+
+/// Parses `HH:MM`
+fn parse_time_of_day(input: &str) -> Option<Time> {
+    let (hour, minute) = input.split_once(':')?;
+
+    Time::from_hms(hour.parse().ok()?, minute.parse().ok()?, 0).ok()
+}
+
+/// Parses `YYYY-MM-DD`
+fn parse_date(input: &str) -> Option<Date> {
+    let mut split = input.splitn(3, '-');
+
+    let year = split.next()?.parse().ok()?;
+    let month = split.next()?.parse::<u8>().ok()?;
+    let day = split.next()?.parse().ok()?;
+
+    Date::from_calendar_date(year, Month::try_from(month).ok()?, day).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn utc(day: u8, hour: u8, minute: u8) -> OffsetDateTime {
+        let date = Date::from_calendar_date(2023, Month::December, day).unwrap();
+        let time = Time::from_hms(hour, minute, 0).unwrap();
+
+        PrimitiveDateTime::new(date, time).assume_utc()
+    }
+
+    #[test]
+    fn relative() {
+        let now = utc(2, 15, 30);
+        let parse = |input| parse_time(input, now, UtcOffset::UTC);
+
+        assert_eq!(parse("1h30m"), Ok(now + Duration::minutes(90)));
+        assert_eq!(parse("in 2 days"), Ok(now + Duration::days(2)));
+        assert_eq!(
+            parse("1w, 3 hours 5s"),
+            Ok(now + Duration::seconds(615_605))
+        );
+        assert!(parse("5").is_err());
+        assert!(parse("5 years").is_err());
+        assert!(parse("0s").is_err());
+        assert!(parse("400d").is_err());
+    }
+
+    #[test]
+    fn absolute() {
+        let now = utc(2, 15, 30);
+        let tz = UtcOffset::from_hms(2, 0, 0).unwrap();
+        let parse = |input| parse_time(input, now, tz);
+
+        // 15:30 UTC is 17:30 in the given timezone
+        assert_eq!(parse("18:00"), Ok(utc(2, 16, 0)));
+        assert_eq!(parse("17:00"), Ok(utc(3, 15, 0)));
+        assert_eq!(parse("2023-12-24"), Ok(utc(23, 22, 0)));
+        assert_eq!(parse("2023-12-24 18:00"), Ok(utc(24, 16, 0)));
+        assert!(parse("2023-12-01 18:00").is_err());
+        assert!(parse("25:00").is_err());
+        assert!(parse("2023-13-01").is_err());
+        assert!(parse("tomorrow").is_err());
+    }
+}
@@ -0,0 +1,196 @@
+use std::sync::Arc;
+
+use bathbot_macros::SlashCommand;
+use bathbot_util::MessageBuilder;
+use eyre::Result;
+use time::{OffsetDateTime, UtcOffset};
+use twilight_interactions::command::{CommandModel, CreateCommand};
+
+use super::{parse::parse_time, write_scheduled, MAX_CONTENT_LEN};
+use crate::{
+    core::locale::ErrorKey,
+    util::{interaction::InteractionCommand, Authored, InteractionCommandExt},
+    Context,
+};
+
+/// Maximum amount of pending reminders per user
+const MAX_REMINDERS: usize = 25;
+
+#[derive(CommandModel, CreateCommand, SlashCommand)]
+#[command(
+    name = "remind",
+    desc = "Set, list, or cancel reminders",
+    help = "Let me remind you of something at a later time.\n\
+    Times can either be relative like `1h30m` or `in 2 days`, \
+    or absolute like `18:00`, `2023-12-24`, or `2023-12-24 18:00`.\n\
+    Absolute times use the timezone of your `/config`, defaulting to UTC.\n\
+    The reminder will be sent in the channel where it was set.\n\
+    You can have up to 25 pending reminders."
+)]
+#[flags(EPHEMERAL)]
+pub enum Remind {
+    #[command(name = "set")]
+    Set(RemindSet),
+    #[command(name = "list")]
+    List(RemindList),
+    #[command(name = "cancel")]
+    Cancel(RemindCancel),
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "set", desc = "Set a new reminder")]
+pub struct RemindSet {
+    #[command(desc = "When to remind you, e.g. `1h30m`, `18:00`, or `2023-12-24 18:00`")]
+    when: String,
+    #[command(desc = "What to remind you of")]
+    message: String,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "list", desc = "List your pending reminders")]
+pub struct RemindList;
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "cancel", desc = "Cancel a pending reminder")]
+pub struct RemindCancel {
+    #[command(min_value = 1, desc = "Id of the reminder, see `/remind list`")]
+    id: i64,
+}
+
+async fn slash_remind(ctx: Arc<Context>, mut command: InteractionCommand) -> Result<()> {
+    match Remind::from_interaction(command.input_data())? {
+        Remind::Set(args) => set(ctx, command, args).await,
+        Remind::List(_) => list(ctx, command).await,
+        Remind::Cancel(args) => cancel(ctx, command, args).await,
+    }
+}
+
+async fn set(ctx: Arc<Context>, command: InteractionCommand, args: RemindSet) -> Result<()> {
+    let user_id = command.user_id()?;
+
+    if args.message.chars().count() > MAX_CONTENT_LEN {
+        let content = format!("The message can have at most {MAX_CONTENT_LEN} characters");
+        command.error(&ctx, content).await?;
+
+        return Ok(());
+    }
+
+    let tz = match ctx.user_config().timezone(user_id).await {
+        Ok(tz) => tz.unwrap_or(UtcOffset::UTC),
+        Err(err) => {
+            let _ = command.error_localized(&ctx, ErrorKey::General).await;
+
+            return Err(err);
+        }
+    };
+
+    let trigger_at = match parse_time(&args.when, OffsetDateTime::now_utc(), tz) {
+        Ok(trigger_at) => trigger_at,
+        Err(content) => {
+            command.error(&ctx, content).await?;
+
+            return Ok(());
+        }
+    };
+
+    let reminders = match ctx.schedule().reminders(user_id).await {
+        Ok(reminders) => reminders,
+        Err(err) => {
+            let _ = command.error_localized(&ctx, ErrorKey::General).await;
+
+            return Err(err);
+        }
+    };
+
+    if reminders.len() >= MAX_REMINDERS {
+        let content = format!(
+            "You can have at most {MAX_REMINDERS} pending reminders. \
+            Cancel one via `/remind cancel` first."
+        );
+        command.error(&ctx, content).await?;
+
+        return Ok(());
+    }
+
+    let add_fut = ctx.schedule().add_reminder(
+        user_id,
+        command.guild_id,
+        command.channel_id,
+        &args.message,
+        trigger_at,
+    );
+
+    let schedule_id = match add_fut.await {
+        Ok(schedule_id) => schedule_id,
+        Err(err) => {
+            let _ = command.error_localized(&ctx, ErrorKey::General).await;
+
+            return Err(err);
+        }
+    };
+
+    let timestamp = trigger_at.unix_timestamp();
+
+    let content = format!(
+        "I will remind you <t:{timestamp}:R> (<t:{timestamp}:F>) in this channel.\n\
+        Reminder id: `{schedule_id}`"
+    );
+
+    let builder = MessageBuilder::new().embed(content);
+    command.update(&ctx, builder).await?;
+
+    Ok(())
+}
+
+async fn list(ctx: Arc<Context>, command: InteractionCommand) -> Result<()> {
+    let user_id = command.user_id()?;
+
+    let reminders = match ctx.schedule().reminders(user_id).await {
+        Ok(reminders) => reminders,
+        Err(err) => {
+            let _ = command.error_localized(&ctx, ErrorKey::General).await;
+
+            return Err(err);
+        }
+    };
+
+    let content = if reminders.is_empty() {
+        "You have no pending reminders".to_owned()
+    } else {
+        let mut content = String::with_capacity(reminders.len() * 96);
+        write_scheduled(&mut content, &reminders);
+
+        content
+    };
+
+    let builder = MessageBuilder::new().embed(content);
+    command.update(&ctx, builder).await?;
+
+    Ok(())
+}
+
+async fn cancel(ctx: Arc<Context>, command: InteractionCommand, args: RemindCancel) -> Result<()> {
+    let user_id = command.user_id()?;
+    let schedule_id = i32::try_from(args.id).unwrap_or(i32::MAX);
+
+    match ctx.schedule().remove_reminder(user_id, schedule_id).await {
+        Ok(true) => {
+            let content = format!("Cancelled reminder `{schedule_id}`");
+            let builder = MessageBuilder::new().embed(content);
+            command.update(&ctx, builder).await?;
+
+            Ok(())
+        }
+        Ok(false) => {
+            let content = format!("You have no pending reminder with id `{schedule_id}`");
+            command.error(&ctx, content).await?;
+
+            Ok(())
+        }
+        Err(err) => {
+            let _ = command.error_localized(&ctx, ErrorKey::General).await;
+
+            Err(err)
+        }
+    }
+}
@@ -4,16 +4,16 @@ use bathbot_macros::{command, SlashCommand};
 use bathbot_psql::model::configs::{
     GuildConfig, HideSolutions, ListSize, MinimizedPp, Retries, ScoreSize,
 };
-use bathbot_util::constants::GENERAL_ISSUE;
 use eyre::Result;
 use twilight_interactions::command::{CommandModel, CreateCommand};
 use twilight_model::id::{marker::RoleMarker, Id};
 
 use super::AuthorityCommandKind;
 use crate::{
-    commands::{EnableDisable, ShowHideOption},
+    commands::{osu::MatchCostExpr, EnableDisable, LanguageOption, ShowHideOption},
+    core::locale::{self, ErrorKey},
     embeds::{EmbedData, ServerConfigEmbed},
-    util::{interaction::InteractionCommand, InteractionCommandExt},
+    util::{interaction::InteractionCommand, Authored, InteractionCommandExt},
     Context,
 };
 
@@ -144,6 +144,43 @@ pub struct ServerConfigEdit {
     allow_custom_skins: Option<bool>,
     #[command(desc = "Should medal solutions should be hidden behind spoiler tags?")]
     hide_medal_solutions: Option<HideSolutions>,
+    #[command(
+        desc = "Specify the language in which the bot should respond in this server",
+        help = "Specify the language in which the bot should respond in this server.\n\
+        Members can still choose their own language via `/config`.\n\
+        Choose `Default` to remove the server's language again."
+    )]
+    language: Option<LanguageOption>,
+    #[command(
+        desc = "Should unknown prefix commands be answered with suggestions?",
+        help = "Should unknown prefix commands be answered with suggestions?\n\
+        If enabled, using a prefix followed by a misspelled command name will \
+        reply with the most similar command names.\n\
+        Disabled by default."
+    )]
+    command_suggestions: Option<EnableDisable>,
+    #[command(
+        desc = "Specify a custom formula for the matchcost command or `default`",
+        help = "Specify a custom formula for the `matchcost` command.\n\
+        The formula calculates a player's rating and may consist of numbers, \
+        the operators `+ - * / ^`, parentheses, the functions \
+        `abs`, `sqrt`, `cbrt`, `ln`, `log10`, `exp`, `min(a, b)`, `max(a, b)`, \
+        and the following variables:\n\
+        - `bathbot`: Rating of the default formula\n\
+        - `avg_ratio`: Average ratio between the player's score and the map's average score\n\
+        - `median_ratio`: Average ratio between the player's score and the map's median score\n\
+        - `z`: Average z-score of the player's scores\n\
+        - `share`: Sum of the player's scores divided by the sum of all maps' average scores\n\
+        - `played`: Amount of maps the player played\n\
+        - `games`: Total amount of maps\n\
+        - `avg_played`: Average amount of maps played per player\n\
+        - `avg_score`: Average score of the player\n\
+        - `mods`: Amount of different mod combinations the player used\n\
+        - `tb`: `1` if the player played the tiebreaker, `0` otherwise\n\
+        E.g. `median_ratio * cbrt(played / avg_played)`.\n\
+        Specify `default` to remove the custom formula."
+    )]
+    match_cost_formula: Option<String>,
 }
 
 impl ServerConfigEdit {
@@ -158,6 +195,9 @@ impl ServerConfigEdit {
             render_button,
             allow_custom_skins,
             hide_medal_solutions,
+            language,
+            command_suggestions,
+            match_cost_formula,
         } = self;
 
         song_commands.is_some()
@@ -169,6 +209,9 @@ impl ServerConfigEdit {
             || render_button.is_some()
             || allow_custom_skins.is_some()
             || hide_medal_solutions.is_some()
+            || language.is_some()
+            || command_suggestions.is_some()
+            || match_cost_formula.is_some()
     }
 }
 
@@ -181,12 +224,12 @@ async fn slash_serverconfig(ctx: Arc<Context>, mut command: InteractionCommand)
         Ok(Some(guild)) => guild,
         Ok(None) => {
             warn!("Missing guild {guild_id} in cache");
-            command.error(&ctx, GENERAL_ISSUE).await?;
+            command.error_localized(&ctx, ErrorKey::General).await?;
 
             return Ok(());
         }
         Err(err) => {
-            let _ = command.error(&ctx, GENERAL_ISSUE).await;
+            let _ = command.error_localized(&ctx, ErrorKey::General).await;
 
             return Err(err);
         }
@@ -199,6 +242,21 @@ async fn slash_serverconfig(ctx: Arc<Context>, mut command: InteractionCommand)
         ServerConfig::Edit(edit) => edit,
     };
 
+    // `Some(None)` if the custom formula should be removed
+    let match_cost_formula = match args.match_cost_formula.as_deref().map(str::trim) {
+        Some(formula) if formula.eq_ignore_ascii_case("default") => Some(None),
+        Some(formula) => match MatchCostExpr::parse(formula) {
+            Ok(expr) => Some(Some(expr.source().to_owned())),
+            Err(err) => {
+                let content = format!("Invalid match cost formula: {}", err.into_str());
+                command.error_callback(&ctx, content).await?;
+
+                return Ok(());
+            }
+        },
+        None => None,
+    };
+
     if args.any() {
         let f = |config: &mut GuildConfig| {
             let ServerConfigEdit {
@@ -211,6 +269,9 @@ async fn slash_serverconfig(ctx: Arc<Context>, mut command: InteractionCommand)
                 render_button,
                 allow_custom_skins,
                 hide_medal_solutions,
+                language,
+                command_suggestions,
+                match_cost_formula: _,
             } = args;
 
             if let Some(score_embeds) = score_embeds {
@@ -248,10 +309,24 @@ async fn slash_serverconfig(ctx: Arc<Context>, mut command: InteractionCommand)
             if let Some(hide_medal_solutions) = hide_medal_solutions {
                 config.hide_medal_solution = Some(hide_medal_solutions);
             }
+
+            if let Some(language) = language {
+                config.language = language.into();
+            }
+
+            if let Some(suggestions) = command_suggestions {
+                config.command_suggestions = Some(suggestions == EnableDisable::Enable);
+            }
+
+            if let Some(formula) = match_cost_formula {
+                config.match_cost_formula = formula;
+            }
         };
 
         if let Err(err) = ctx.guild_config().update(guild_id, f).await {
-            let _ = command.error_callback(&ctx, GENERAL_ISSUE).await;
+            let _ = command
+                .error_callback_localized(&ctx, ErrorKey::General)
+                .await;
 
             return Err(err.wrap_err("failed to update guild config"));
         }
@@ -270,7 +345,15 @@ async fn slash_serverconfig(ctx: Arc<Context>, mut command: InteractionCommand)
         }
     }
 
-    let embed = ServerConfigEmbed::new(guild, config, &authorities);
+    let lang = locale::language(
+        &ctx,
+        command.user_id().ok(),
+        Some(guild_id),
+        command.locale.as_deref(),
+    )
+    .await;
+
+    let embed = ServerConfigEmbed::new(guild, config, &authorities, lang);
     let builder = embed.build().into();
     command.callback(&ctx, builder, false).await?;
 
@@ -7,7 +7,7 @@ use std::{
 };
 
 use bathbot_macros::{HasName, SlashCommand};
-use bathbot_util::{constants::GENERAL_ISSUE, matcher, EmbedBuilder, MessageBuilder};
+use bathbot_util::{matcher, EmbedBuilder, MessageBuilder};
 use eyre::{Report, Result, WrapErr};
 use twilight_interactions::command::{CommandModel, CreateCommand};
 use twilight_model::id::{marker::UserMarker, Id};
@@ -15,7 +15,7 @@ use url::{SyntaxViolation, Url};
 
 use crate::{
     active::{self, ActiveMessages},
-    core::{commands::CommandOrigin, Context},
+    core::{commands::CommandOrigin, locale::ErrorKey, Context},
     util::{interaction::InteractionCommand, Authored, InteractionCommandExt},
 };
 
@@ -71,7 +71,7 @@ impl CheckSkin {
                     command.update(ctx, builder).await?;
                 }
                 Err(err) => {
-                    let _ = command.error(ctx, GENERAL_ISSUE).await;
+                    let _ = command.error_localized(ctx, ErrorKey::General).await;
 
                     return Err(err);
                 }
@@ -90,7 +90,7 @@ impl CheckSkin {
                     command.update(ctx, builder).await?;
                 }
                 Err(err) => {
-                    let _ = command.error(ctx, GENERAL_ISSUE).await;
+                    let _ = command.error_localized(ctx, ErrorKey::General).await;
 
                     return Err(err);
                 }
@@ -115,7 +115,7 @@ impl CheckSkin {
                     command.update(ctx, builder).await?;
                 }
                 Err(err) => {
-                    let _ = command.error(ctx, GENERAL_ISSUE).await;
+                    let _ = command.error_localized(ctx, ErrorKey::General).await;
 
                     return Err(err);
                 }
@@ -146,7 +146,7 @@ impl AllSkin {
                     .wrap_err("Failed to begin active message")
             }
             Err(err) => {
-                let _ = command.error(&ctx, GENERAL_ISSUE).await;
+                let _ = command.error_localized(&ctx, ErrorKey::General).await;
 
                 Err(err)
             }
@@ -191,7 +191,7 @@ impl SetSkin {
             .update_skin(command.user_id()?, Some(&url));
 
         if let Err(err) = update_fut.await {
-            let _ = command.error(ctx, GENERAL_ISSUE).await;
+            let _ = command.error_localized(ctx, ErrorKey::General).await;
 
             return Err(err);
         }
@@ -219,7 +219,7 @@ impl UnsetSkin {
         let update_fut = ctx.user_config().update_skin(command.user_id()?, None);
 
         if let Err(err) = update_fut.await {
-            let _ = command.error(ctx, GENERAL_ISSUE).await;
+            let _ = command.error_localized(ctx, ErrorKey::General).await;
 
             return Err(err);
         }
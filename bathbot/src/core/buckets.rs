@@ -3,7 +3,7 @@ use std::{collections::HashMap, hash::Hash, sync::Mutex};
 use bathbot_util::IntHasher;
 use time::OffsetDateTime;
 
-pub struct Buckets([Mutex<Bucket>; 8]);
+pub struct Buckets([Mutex<Bucket>; 9]);
 
 impl Buckets {
     #[allow(clippy::new_without_default)]
@@ -22,6 +22,7 @@ impl Buckets {
             make_bucket(1, 8, 2),    // BgBigger
             make_bucket(0, 10, 4),   // BgHint
             make_bucket(2, 20, 3),   // BgSkip
+            make_bucket(30, 0, 1),   // CommandSuggestion
             make_bucket(15, 0, 1),   // MatchCompare
             make_bucket(5, 900, 3),  // MatchLive
             make_bucket(60, 600, 2), // Render
@@ -35,10 +36,11 @@ impl Buckets {
             BucketName::BgBigger => &self.0[1],
             BucketName::BgHint => &self.0[2],
             BucketName::BgSkip => &self.0[3],
-            BucketName::MatchCompare => &self.0[4],
-            BucketName::MatchLive => &self.0[5],
-            BucketName::Render => &self.0[6],
-            BucketName::Songs => &self.0[7],
+            BucketName::CommandSuggestion => &self.0[4],
+            BucketName::MatchCompare => &self.0[5],
+            BucketName::MatchLive => &self.0[6],
+            BucketName::Render => &self.0[7],
+            BucketName::Songs => &self.0[8],
         }
     }
 }
@@ -109,6 +111,7 @@ pub enum BucketName {
     BgBigger,
     BgHint,
     BgSkip,
+    CommandSuggestion,
     MatchCompare,
     MatchLive,
     Render,
@@ -1,3 +1,4 @@
+use bathbot_psql::model::configs::Language;
 use bathbot_util::{EmbedBuilder, MessageBuilder};
 use eyre::{ContextCompat, Result, WrapErr};
 use twilight_http::Response;
@@ -11,7 +12,10 @@ use twilight_model::{
 };
 
 use crate::{
-    core::Context,
+    core::{
+        locale::{self, ErrorKey},
+        Context,
+    },
     util::{
         interaction::{InteractionCommand, InteractionComponent},
         Authored, ChannelExt, InteractionCommandExt, MessageExt,
@@ -50,6 +54,16 @@ impl CommandOrigin<'_> {
         }
     }
 
+    /// The language in which to respond to the author.
+    pub async fn language(&self, ctx: &Context) -> Language {
+        let locale = match self {
+            CommandOrigin::Message { .. } => None,
+            CommandOrigin::Interaction { command } => command.locale.as_deref(),
+        };
+
+        locale::language(ctx, self.user_id().ok(), self.guild_id(), locale).await
+    }
+
     /// Respond to something.
     ///
     /// In case of a message, discard the response message created.
@@ -203,6 +217,27 @@ impl CommandOrigin<'_> {
                 .wrap_err("failed to callback with error"),
         }
     }
+
+    /// Respond with the error message of the key in a red embed, translated
+    /// into the author's language.
+    ///
+    /// In case of an interaction, be sure you already called back beforehand.
+    pub async fn error_localized(&self, ctx: &Context, key: ErrorKey) -> Result<()> {
+        let language = self.language(ctx).await;
+
+        self.error(ctx, key.text(language)).await
+    }
+
+    /// Respond with the error message of the key in a red embed, translated
+    /// into the author's language.
+    ///
+    /// In case of an interaction, be sure this is the first and only time you
+    /// call this. The response will not be ephemeral.
+    pub async fn error_callback_localized(&self, ctx: &Context, key: ErrorKey) -> Result<()> {
+        let language = self.language(ctx).await;
+
+        self.error_callback(ctx, key.text(language)).await
+    }
 }
 
 impl<'d> CommandOrigin<'d> {
@@ -21,12 +21,12 @@ pub struct BotConfig {
     pub server: Server,
     grades: Box<[Box<str>; 9]>,
     emotes: Box<[CustomEmote; 13]>,
-    pub redis_host: Box<str>,
-    pub redis_port: u16,
-    pub redis_db_idx: u8,
+    pub cache: CacheConfig,
     pub owner: Id<UserMarker>,
     pub dev_guild: Id<GuildMarker>,
     pub hl_channel: Id<ChannelMarker>,
+    /// Uppercase country codes for which national #1s are tracked locally
+    pub snipe_countries: Box<[Box<str>]>,
 }
 
 #[derive(Debug)]
@@ -43,6 +43,46 @@ pub struct Paths {
 pub struct Server {
     pub port: u16,
     pub public_url: Box<str>,
+    /// Stream events are only received through EventSub if this is set
+    pub twitch_eventsub_secret: Option<Box<str>>,
+    /// Interactions are only received through the server if this is set
+    pub discord_public_key: Option<Box<str>>,
+}
+
+#[derive(Debug)]
+pub enum CacheConfig {
+    Redis {
+        host: Box<str>,
+        port: u16,
+        db_idx: u8,
+    },
+    /// Keeps the cache in-process; resume data won't survive restarts.
+    Memory {
+        /// Memory limit in megabytes
+        limit: u64,
+    },
+}
+
+impl CacheConfig {
+    fn from_env() -> Result<Self> {
+        // Redis is the default to not require adjusting existing setups
+        let backend = env::var("CACHE_BACKEND").unwrap_or_else(|_| String::from("redis"));
+
+        match backend.as_str() {
+            "redis" => Ok(Self::Redis {
+                host: env_var("REDIS_HOST")?,
+                port: env_var("REDIS_PORT")?,
+                db_idx: env_var("REDIS_DB_IDX")?,
+            }),
+            "memory" => Ok(Self::Memory {
+                limit: env_var("CACHE_MEMORY_LIMIT")?,
+            }),
+            _ => bail!(
+                "failed to parse env variable `CACHE_BACKEND={backend}`; \
+                expected `redis` or `memory`"
+            ),
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -112,15 +152,16 @@ impl BotConfig {
             server: Server {
                 port: env_var("SERVER_PORT")?,
                 public_url: env_var("PUBLIC_URL")?,
+                twitch_eventsub_secret: optional_env_var("TWITCH_EVENTSUB_SECRET")?,
+                discord_public_key: optional_env_var("DISCORD_PUBLIC_KEY")?,
             },
             grades,
             emotes,
-            redis_host: env_var("REDIS_HOST")?,
-            redis_port: env_var("REDIS_PORT")?,
-            redis_db_idx: env_var("REDIS_DB_IDX")?,
+            cache: CacheConfig::from_env()?,
             owner: env_var("OWNER_USER_ID")?,
             dev_guild: env_var("DEV_GUILD_ID")?,
             hl_channel: env_var("HL_IMAGE_CHANNEL")?,
+            snipe_countries: env_var("SNIPE_COUNTRIES")?,
         };
 
         if CONFIG.set(config).is_err() {
@@ -190,6 +231,28 @@ env_kind! {
     Id<ChannelMarker>: |s| { s.parse().map(Id::new).map_err(|_| s) },
 }
 
+impl EnvKind for Box<[Box<str>]> {
+    const EXPECTED: &'static str = "a comma-separated list of country codes";
+
+    fn from_str(s: String) -> Result<Self, String> {
+        let country_codes = s
+            .split(',')
+            .map(str::trim)
+            .filter(|country_code| !country_code.is_empty())
+            .map(|country_code| {
+                if country_code.len() == 2 && country_code.chars().all(|c| c.is_ascii_alphabetic())
+                {
+                    Ok(Box::from(country_code.to_ascii_uppercase()))
+                } else {
+                    Err(())
+                }
+            })
+            .collect::<Result<_, _>>();
+
+        country_codes.map_err(|_| s)
+    }
+}
+
 impl EnvKind for CustomEmote {
     const EXPECTED: &'static str = "an emote of the form `<:name:id>`";
 
@@ -216,6 +279,14 @@ fn env_var<T: EnvKind>(name: &str) -> Result<T> {
     })
 }
 
+/// Same as [`env_var`] but unset or empty variables are `None`.
+fn optional_env_var<T: EnvKind>(name: &str) -> Result<Option<T>> {
+    match env::var(name) {
+        Ok(value) if !value.is_empty() => env_var(name).map(Some),
+        _ => Ok(None),
+    }
+}
+
 trait AsUsize {
     fn to_usize(self) -> usize;
 }
@@ -0,0 +1,142 @@
+use bathbot_model::TwitchSubscription;
+use eyre::{Result, WrapErr};
+use hashbrown::HashSet;
+
+use crate::{core::BotConfig, Context};
+
+const STREAM_EVENTS: [&str; 2] = ["stream.online", "stream.offline"];
+
+impl Context {
+    /// Subscribe to the stream events of the twitch user so that the server
+    /// is notified as soon as they go live or offline.
+    pub async fn subscribe_twitch_events(&self, twitch_id: u64) -> Result<()> {
+        let Some((callback, secret)) = eventsub_transport() else {
+            return Ok(());
+        };
+
+        let subscriptions = self
+            .client()
+            .get_twitch_subscriptions(Some(twitch_id))
+            .await
+            .wrap_err("Failed to get twitch subscriptions")?;
+
+        for sub in subscriptions.iter() {
+            if is_own(sub, &callback) && !sub.is_active() {
+                self.delete_twitch_subscription(sub).await?;
+            }
+        }
+
+        for kind in STREAM_EVENTS {
+            let subscribed = subscriptions
+                .iter()
+                .any(|sub| &*sub.kind == kind && sub.is_active() && is_own(sub, &callback));
+
+            if !subscribed {
+                self.client()
+                    .subscribe_twitch_event(twitch_id, kind, &callback, secret)
+                    .await
+                    .wrap_err_with(|| format!("Failed to subscribe to {kind}"))?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Remove the stream event subscriptions of the twitch user if no
+    /// channel tracks them anymore.
+    pub async fn unsubscribe_twitch_events(&self, twitch_id: u64) -> Result<()> {
+        let Some((callback, _)) = eventsub_transport() else {
+            return Ok(());
+        };
+
+        let still_tracked = self
+            .tracked_channels_for(twitch_id)
+            .is_some_and(|channels| !channels.is_empty());
+
+        if still_tracked {
+            return Ok(());
+        }
+
+        let subscriptions = self
+            .client()
+            .get_twitch_subscriptions(Some(twitch_id))
+            .await
+            .wrap_err("Failed to get twitch subscriptions")?;
+
+        for sub in subscriptions.iter().filter(|sub| is_own(sub, &callback)) {
+            self.delete_twitch_subscription(sub).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Make sure exactly the tracked twitch users have active stream event
+    /// subscriptions.
+    pub async fn sync_twitch_subscriptions(&self) -> Result<()> {
+        let Some((callback, secret)) = eventsub_transport() else {
+            return Ok(());
+        };
+
+        let subscriptions = self
+            .client()
+            .get_twitch_subscriptions(None)
+            .await
+            .wrap_err("Failed to get twitch subscriptions")?;
+
+        let tracked: HashSet<u64> = self
+            .data
+            .tracked_streams
+            .pin()
+            .iter()
+            .filter_map(|(user, channels)| (!channels.is_empty()).then_some(*user))
+            .collect();
+
+        let mut subscribed = HashSet::with_capacity(subscriptions.len());
+
+        for sub in subscriptions.iter().filter(|sub| is_own(sub, &callback)) {
+            if sub.is_active() && tracked.contains(&sub.condition.user_id) {
+                subscribed.insert((sub.condition.user_id, &*sub.kind));
+            } else {
+                self.delete_twitch_subscription(sub).await?;
+            }
+        }
+
+        for &user in tracked.iter() {
+            for kind in STREAM_EVENTS {
+                if subscribed.contains(&(user, kind)) {
+                    continue;
+                }
+
+                self.client()
+                    .subscribe_twitch_event(user, kind, &callback, secret)
+                    .await
+                    .wrap_err_with(|| format!("Failed to subscribe to {kind} of {user}"))?;
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn delete_twitch_subscription(&self, sub: &TwitchSubscription) -> Result<()> {
+        self.client()
+            .delete_twitch_subscription(&sub.id)
+            .await
+            .wrap_err("Failed to delete twitch subscription")
+    }
+}
+
+/// Callback url and secret for EventSub subscriptions or `None` if no
+/// secret is configured.
+fn eventsub_transport() -> Option<(String, &'static str)> {
+    let config = &BotConfig::get().server;
+    let secret = config.twitch_eventsub_secret.as_deref()?;
+    let callback = format!("{}/twitch/eventsub", config.public_url);
+
+    Some((callback, secret))
+}
+
+/// Subscriptions might have been created by other instances with the same
+/// twitch client id so only those with our callback are considered.
+fn is_own(sub: &TwitchSubscription, callback: &str) -> bool {
+    sub.transport.callback.as_deref() == Some(callback)
+}
@@ -1,8 +1,134 @@
-use super::BgGames;
-use crate::Context;
+use std::sync::Arc;
+
+use bathbot_model::{BgGameSnapshot, HlGameSnapshot};
+use bathbot_util::MessageBuilder;
+use twilight_model::id::Id;
+
+use super::{BgGames, HigherLowerChannels};
+use crate::{
+    active::{
+        impls::{BackgroundGame, HigherLowerGame},
+        ActiveMessages,
+    },
+    util::{ChannelExt, MessageExt},
+    Context,
+};
 
 impl Context {
     pub fn bg_games(&self) -> &BgGames {
         &self.data.games.bg
     }
+
+    /// Channels with a running multiplayer higherlower game
+    pub fn higherlower_channels(&self) -> &HigherLowerChannels {
+        &self.data.games.higherlower_channels
+    }
+
+    /// Resume all games that were snapshotted during the last shutdown.
+    #[cold]
+    pub async fn resume_games(ctx: Arc<Self>) {
+        let (bg_count, hl_count) = tokio::join!(
+            Self::resume_bg_games(Arc::clone(&ctx)),
+            Self::resume_higherlower_games(ctx),
+        );
+
+        if bg_count > 0 || hl_count > 0 {
+            info!("Resumed {bg_count} bg games and {hl_count} higherlower games");
+        }
+    }
+
+    async fn resume_bg_games(ctx: Arc<Self>) -> usize {
+        // Remove the snapshots so that they can't be resumed twice
+        let fetch_fut = ctx.cache.fetch_del::<_, Vec<BgGameSnapshot>>("bg_games");
+
+        let snapshots = match fetch_fut.await {
+            Ok(Some(snapshots)) => snapshots,
+            Ok(None) => return 0,
+            Err(err) => {
+                warn!(?err, "Failed to fetch bg game snapshots");
+
+                return 0;
+            }
+        };
+
+        let mut count = 0;
+
+        for snapshot in snapshots.iter() {
+            match BackgroundGame::resume(Arc::clone(&ctx), snapshot).await {
+                Ok((channel, game)) => {
+                    ctx.bg_games().own(channel).await.insert(game);
+                    count += 1;
+                }
+                Err(err) => warn!(channel = snapshot.channel, ?err, "Failed to resume bg game"),
+            }
+        }
+
+        count
+    }
+
+    async fn resume_higherlower_games(ctx: Arc<Self>) -> usize {
+        let fetch_fut = ctx
+            .cache
+            .fetch_del::<_, Vec<HlGameSnapshot>>("higherlower_games");
+
+        let snapshots = match fetch_fut.await {
+            Ok(Some(snapshots)) => snapshots,
+            Ok(None) => return 0,
+            Err(err) => {
+                warn!(?err, "Failed to fetch higherlower game snapshots");
+
+                return 0;
+            }
+        };
+
+        let mut count = 0;
+
+        for snapshot in snapshots.iter() {
+            let (Some(channel), Some(msg)) = (
+                Id::new_checked(snapshot.channel),
+                Id::new_checked(snapshot.msg),
+            ) else {
+                continue;
+            };
+
+            // The components of the previous message no longer do anything
+            let builder = MessageBuilder::new().components(Vec::new());
+
+            if let Some(update_fut) = (msg, channel).update(&ctx, builder, None) {
+                if let Err(err) = update_fut.await {
+                    warn!(
+                        ?err,
+                        "Failed to remove components of previous higherlower game"
+                    );
+                }
+            }
+
+            let game = match HigherLowerGame::resume(&ctx, snapshot).await {
+                Ok(game) => game,
+                Err(err) => {
+                    warn!(%channel, ?err, "Failed to resume higherlower game");
+
+                    continue;
+                }
+            };
+
+            let content = format!(
+                "<@{}> I'm back, here's a new pair to continue your game with a score of {}",
+                snapshot.user, snapshot.score
+            );
+
+            if let Err(err) = channel.plain_message(&ctx, &content).await {
+                warn!(%channel, ?err, "Failed to send higherlower resume notice");
+            }
+
+            let begin_fut = ActiveMessages::builder(game).begin(Arc::clone(&ctx), channel);
+
+            match begin_fut.await {
+                Ok(_) => count += 1,
+                Err(err) => warn!(%channel, ?err, "Failed to begin resumed higherlower game"),
+            }
+        }
+
+        count
+    }
 }
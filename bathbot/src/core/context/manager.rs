@@ -4,8 +4,9 @@ use rosu_v2::prelude::GameMode;
 use super::Context;
 use crate::manager::{
     redis::RedisManager, ApproxManager, BookmarkManager, GameManager, GithubManager,
-    GuildConfigManager, HuismetbenenCountryManager, MapManager, OsuMap, OsuTrackingManager,
-    OsuUserManager, PpManager, ReplayManager, ScoresManager, TwitchManager, UserConfigManager,
+    GuildConfigManager, HuismetbenenCountryManager, MapManager, MappoolManager, MedalPlanManager,
+    OsuMap, OsuTrackingManager, OsuUserManager, PpManager, ReplayManager, ScheduleManager,
+    ScoresManager, SnipeManager, SongManager, TournamentManager, TwitchManager, UserConfigManager,
 };
 
 impl Context {
@@ -41,6 +42,10 @@ impl Context {
         HuismetbenenCountryManager::new(self)
     }
 
+    pub fn snipe(&self) -> SnipeManager<'_> {
+        SnipeManager::new(self, &self.clients.psql)
+    }
+
     pub fn pp<'d, 'm>(&'d self, map: &'m OsuMap) -> PpManager<'d, 'm> {
         PpManager::new(map, &self.clients.psql)
     }
@@ -71,6 +76,26 @@ impl Context {
         BookmarkManager::new(&self.clients.psql)
     }
 
+    pub fn medal_plans(&self) -> MedalPlanManager<'_> {
+        MedalPlanManager::new(&self.clients.psql)
+    }
+
+    pub fn songs(&self) -> SongManager<'_> {
+        SongManager::new(&self.clients.psql)
+    }
+
+    pub fn schedule(&self) -> ScheduleManager<'_> {
+        ScheduleManager::new(&self.clients.psql)
+    }
+
+    pub fn tournament(&self) -> TournamentManager<'_> {
+        TournamentManager::new(&self.clients.psql)
+    }
+
+    pub fn mappool(&self) -> MappoolManager<'_> {
+        MappoolManager::new(&self.clients.psql)
+    }
+
     pub fn replay(&self) -> ReplayManager<'_> {
         ReplayManager::new(&self.clients.psql, &self.clients.custom, &self.cache)
     }
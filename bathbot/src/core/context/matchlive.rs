@@ -10,7 +10,10 @@ use twilight_model::id::{marker::ChannelMarker, Id};
 use super::Context;
 use crate::{
     embeds::MatchLiveEmbed,
-    matchlive::{send_match_messages, Channel, MatchEntry, MatchTrackResult, TrackedMatch},
+    matchlive::{
+        send_match_messages, Channel, MatchEntry, MatchMappool, MatchTrackResult, PickBanKind,
+        PickBanResult, TrackedMatch,
+    },
     util::ChannelExt,
 };
 
@@ -62,8 +65,9 @@ impl Context {
                 }
 
                 let embeds = &entry.tracked.embeds;
+                let mappool = entry.tracked.mappool.as_ref();
 
-                let channel = match send_match_messages(self, channel, embeds).await {
+                let channel = match send_match_messages(self, channel, embeds, mappool).await {
                     Ok(msg) => Channel::new(channel, msg),
                     Err(err) => {
                         error!("{err:?}");
@@ -83,7 +87,7 @@ impl Context {
                 Ok(osu_match) => {
                     let embeds = MatchLiveEmbed::new(&osu_match);
 
-                    let channel = match send_match_messages(self, channel, &embeds).await {
+                    let channel = match send_match_messages(self, channel, &embeds, None).await {
                         Ok(msg) => Channel::new(channel, msg),
                         Err(err) => {
                             error!("{err:?}");
@@ -144,6 +148,66 @@ impl Context {
         false
     }
 
+    /// Links the mappool to the match, replacing the previously linked one.
+    ///
+    /// Returns false if the match wasn't tracked in the channel
+    pub async fn link_match_mappool(
+        &self,
+        channel: Id<ChannelMarker>,
+        match_id: u32,
+        mut mappool: MatchMappool,
+    ) -> bool {
+        let mut match_live = self.data.matchlive.inner.lock().await;
+
+        match match_live.match_channels.get_mut(&match_id) {
+            Some(entry) if entry.channels.iter().any(|c| c.id == channel) => {
+                mappool.update(&entry.tracked.osu_match);
+                entry.tracked.mappool = Some(mappool);
+
+                true
+            }
+            _ => false,
+        }
+    }
+
+    pub async fn add_match_pick_ban(
+        &self,
+        channel: Id<ChannelMarker>,
+        match_id: u32,
+        kind: PickBanKind,
+        slot: &str,
+        team: Option<Box<str>>,
+    ) -> PickBanResult {
+        let mut match_live = self.data.matchlive.inner.lock().await;
+
+        match match_live.match_channels.get_mut(&match_id) {
+            Some(entry) if entry.channels.iter().any(|c| c.id == channel) => {
+                match entry.tracked.mappool {
+                    Some(ref mut mappool) => mappool.add(kind, slot, team),
+                    None => PickBanResult::NoMappool,
+                }
+            }
+            _ => PickBanResult::NotTracked,
+        }
+    }
+
+    /// Returns the mappool linked to the match.
+    ///
+    /// The outer `None` denotes that the match wasn't tracked in the channel
+    pub async fn match_mappool(
+        &self,
+        channel: Id<ChannelMarker>,
+        match_id: u32,
+    ) -> Option<Option<MatchMappool>> {
+        let match_live = self.data.matchlive.inner.lock().await;
+
+        match_live
+            .match_channels
+            .get(&match_id)
+            .filter(|entry| entry.channels.iter().any(|c| c.id == channel))
+            .map(|entry| entry.tracked.mappool.clone())
+    }
+
     /// Returns how many channels tracked the match before it ended
     async fn remove_all_match_tracks(&self, match_id: u32) -> usize {
         let mut match_live = self.data.matchlive.inner.lock().await;
@@ -198,6 +262,10 @@ impl Context {
                         .expect("no last live embed")
                         .update(&next_match);
 
+                    if let Some(ref mut mappool) = tracked_match.mappool {
+                        mappool.update(&next_match);
+                    }
+
                     if next_match.end_time.is_some() {
                         remove.push(next_match.match_id);
                     }
@@ -207,10 +275,11 @@ impl Context {
                     // If there was an update for the last embed
                     if update {
                         let data = tracked_match.embeds.last().unwrap();
+                        let mappool = tracked_match.mappool.as_ref();
 
                         // For every channel that's tracking the match
                         for Channel { id, msg_id } in entry.channels.iter() {
-                            let embed = Some(data.as_embed());
+                            let embed = Some(data.as_embed(mappool));
 
                             // Update the last message
                             let update_result = ctx
@@ -235,8 +304,10 @@ impl Context {
 
                     // For all new embeds, send them to all channels
                     if let Some(embeds) = new_embeds {
+                        let mappool = tracked_match.mappool.as_ref();
+
                         for Channel { id, msg_id } in entry.channels.iter_mut() {
-                            match send_match_messages(&ctx, *id, &embeds).await {
+                            match send_match_messages(&ctx, *id, &embeds, mappool).await {
                                 Ok(msg) => *msg_id = msg,
                                 Err(err) => {
                                     error!(channel = id.get(), ?err, "Failed to send last msg")
@@ -39,13 +39,15 @@ use twilight_standby::Standby;
 
 use super::{
     buckets::{BucketName, Buckets},
-    BotConfig, BotMetrics,
+    BotConfig, BotMetrics, CacheConfig,
 };
 use crate::{
     active::{impls::BackgroundGame, ActiveMessages},
     tracking::Ordr,
 };
 
+#[cfg(feature = "server")]
+mod eventsub;
 mod games;
 mod manager;
 mod matchlive;
@@ -58,9 +60,22 @@ type GuildConfigs = FlurryMap<Id<GuildMarker>, GuildConfig, IntHasher>;
 type TrackedStreams = FlurryMap<u64, Vec<Id<ChannelMarker>>, IntHasher>;
 type MissAnalyzerGuilds = FlurrySet<Id<GuildMarker>, IntHasher>;
 
+#[cfg(feature = "server")]
+type TwitchStreamEvents = tokio::sync::mpsc::UnboundedReceiver<bathbot_model::TwitchStreamEvent>;
+
+#[cfg(feature = "server")]
+type HttpInteractions =
+    tokio::sync::mpsc::UnboundedReceiver<twilight_model::application::interaction::Interaction>;
+
 pub struct Context {
     #[cfg(feature = "server")]
     pub auth_standby: Arc<bathbot_server::AuthenticationStandby>,
+    /// Stream events received through the server, taken by the twitch loop
+    #[cfg(feature = "server")]
+    pub twitch_events: Mutex<Option<TwitchStreamEvents>>,
+    /// Interactions received through the server, taken by the interaction loop
+    #[cfg(feature = "server")]
+    pub http_interactions: Mutex<Option<HttpInteractions>>,
     pub buckets: Buckets,
     pub cache: Cache,
     pub shard_senders: RwLock<HashMap<u64, MessageSender>>,
@@ -161,9 +176,16 @@ impl Context {
             .await
             .wrap_err("Failed to create osu client")?;
 
-        let cache = Cache::new(&config.redis_host, config.redis_port, config.redis_db_idx)
-            .await
-            .wrap_err("Failed to create redis cache")?;
+        let cache = match config.cache {
+            CacheConfig::Redis {
+                ref host,
+                port,
+                db_idx,
+            } => Cache::new(host, port, db_idx)
+                .await
+                .wrap_err("Failed to create redis cache")?,
+            CacheConfig::Memory { limit } => Cache::in_memory(limit as usize * 1024 * 1024),
+        };
 
         let data = ContextData::new(&psql, &cache, application_id)
             .await
@@ -212,9 +234,10 @@ impl Context {
         let shard_senders = RwLock::new(shard_senders);
 
         #[cfg(feature = "server")]
-        let (auth_standby, server_tx) = bathbot_server(config, _prometheus, reader.clone())
-            .await
-            .wrap_err("Failed to create server")?;
+        let (auth_standby, server_tx, twitch_events, http_interactions) =
+            bathbot_server(config, _prometheus, reader.clone())
+                .await
+                .wrap_err("Failed to create server")?;
 
         let ctx = Self {
             cache,
@@ -225,6 +248,10 @@ impl Context {
             standby: Standby::new(),
             #[cfg(feature = "server")]
             auth_standby,
+            #[cfg(feature = "server")]
+            twitch_events: Mutex::new(Some(twitch_events)),
+            #[cfg(feature = "server")]
+            http_interactions: Mutex::new(Some(http_interactions)),
             buckets: Buckets::new(),
             member_requests: MemberRequests::new(tx),
             active_msgs: ActiveMessages::new(),
@@ -418,17 +445,20 @@ impl ContextData {
 
 struct Games {
     bg: BgGames,
+    higherlower_channels: HigherLowerChannels,
 }
 
 impl Games {
     fn new() -> Self {
         Self {
             bg: BgGames::with_shard_amount_and_hasher(16, IntHasher),
+            higherlower_channels: HigherLowerChannels::with_hasher(IntHasher),
         }
     }
 }
 
 type BgGames = TokioRwLockMap<Id<ChannelMarker>, BackgroundGame, IntHasher>;
+type HigherLowerChannels = FlurrySet<Id<ChannelMarker>, IntHasher>;
 
 async fn discord_http(config: &BotConfig) -> Result<(Arc<Client>, Id<ApplicationMarker>)> {
     let mentions = AllowedMentions {
@@ -533,6 +563,8 @@ async fn bathbot_server(
 ) -> Result<(
     Arc<bathbot_server::AuthenticationStandby>,
     tokio::sync::oneshot::Sender<()>,
+    TwitchStreamEvents,
+    HttpInteractions,
 )> {
     let builder = bathbot_server::AppStateBuilder {
         website_path: config.paths.website.clone(),
@@ -542,12 +574,22 @@ async fn bathbot_server(
         osu_client_secret: config.tokens.osu_client_secret.to_string(),
         twitch_client_id: config.tokens.twitch_client_id.to_string(),
         twitch_token: config.tokens.twitch_token.to_string(),
+        twitch_eventsub_secret: config
+            .server
+            .twitch_eventsub_secret
+            .as_deref()
+            .map(str::to_owned),
+        discord_public_key: config
+            .server
+            .discord_public_key
+            .as_deref()
+            .map(str::to_owned),
         redirect_base: config.server.public_url.to_string(),
     };
 
-    let (server, standby, tx) = bathbot_server::Server::new(builder)?;
+    let (server, standby, tx, twitch_events, interactions) = bathbot_server::Server::new(builder)?;
 
     tokio::spawn(server.run(config.server.port));
 
-    Ok((standby, tx))
+    Ok((standby, tx, twitch_events, interactions))
 }
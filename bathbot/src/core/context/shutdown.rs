@@ -12,6 +12,9 @@ use twilight_model::id::{marker::GuildMarker, Id};
 
 use crate::{util::ChannelExt, Context};
 
+const ABORT_CONTENT: &str = "I'll abort this game because I'm about to reboot, \
+    you can start a new game again in just a moment...";
+
 impl Context {
     #[cold]
     pub async fn shutdown(&self, shards: &mut [Shard]) {
@@ -19,11 +22,25 @@ impl Context {
         #[cfg(feature = "osutracking")]
         self.tracking().set_stop_tracking(true);
 
+        const STORE_DURATION: usize = 240;
+
+        match self.store_higherlower_games(STORE_DURATION).await {
+            Ok(len) => info!("Stored {len} higherlower games"),
+            Err(err) => error!(?err, "Failed to store higherlower games"),
+        }
+
         // Prevent non-minimized msgs from getting minimized
         self.active_msgs.clear().await;
 
-        let count = self.stop_all_games().await;
-        info!("Stopped {count} bg games");
+        match self.store_bg_games(STORE_DURATION).await {
+            Ok(len) => info!("Stored {len} bg games"),
+            Err(err) => {
+                error!(?err, "Failed to store bg games");
+
+                let count = self.stop_all_games().await;
+                info!("Stopped {count} bg games");
+            }
+        }
 
         #[cfg(feature = "matchlive")]
         {
@@ -41,8 +58,6 @@ impl Context {
             error!(?err, "Failed to freeze cache");
         }
 
-        const STORE_DURATION: usize = 240;
-
         match self.store_guild_shards(STORE_DURATION).await {
             Ok(len) => info!("Stored {len} guild shards"),
             Err(err) => error!(?err, "Failed to store guild shards"),
@@ -73,13 +88,10 @@ impl Context {
 
         let mut count = 0;
 
-        let content = "I'll abort this game because I'm about to reboot, \
-            you can start a new game again in just a moment...";
-
         for (channel, game) in active_games {
             match game.stop() {
                 Ok(_) => {
-                    let _ = channel.plain_message(self, content).await;
+                    let _ = channel.plain_message(self, ABORT_CONTENT).await;
                     count += 1;
                 }
                 Err(err) => warn!(%channel, ?err, "Error while stopping game"),
@@ -89,6 +101,77 @@ impl Context {
         count
     }
 
+    /// Serialize the state of all active bg games and store it in redis so
+    /// that the games can be resumed after the restart.
+    ///
+    /// The games are only suspended if storing succeeded.
+    #[cold]
+    async fn store_bg_games(&self, store_duration: usize) -> Result<usize> {
+        let mut active_games = Vec::new();
+        let mut stream = self.bg_games().iter();
+
+        while let Some(guard) = stream.next().await {
+            active_games.push((*guard.key(), guard.value().to_owned()));
+        }
+
+        if active_games.is_empty() {
+            return Ok(0);
+        }
+
+        let mut snapshots = Vec::with_capacity(active_games.len());
+        let mut snapshotted = Vec::with_capacity(active_games.len());
+
+        for (channel, game) in active_games {
+            match game.snapshot(channel).await {
+                Ok(snapshot) => {
+                    snapshots.push(snapshot);
+                    snapshotted.push((channel, game));
+                }
+                Err(err) => {
+                    warn!(%channel, ?err, "Failed to snapshot game");
+
+                    if game.stop().is_ok() {
+                        let _ = channel.plain_message(self, ABORT_CONTENT).await;
+                    }
+                }
+            }
+        }
+
+        self.cache
+            .store_new::<_, _, 256>("bg_games", &snapshots, store_duration)
+            .await
+            .wrap_err("Failed to store in redis")?;
+
+        let content = "I'm about to reboot, this game will continue in just a moment...";
+
+        for (channel, game) in snapshotted {
+            match game.suspend() {
+                Ok(_) => {
+                    let _ = channel.plain_message(self, content).await;
+                }
+                Err(err) => warn!(%channel, ?err, "Error while suspending game"),
+            }
+        }
+
+        Ok(snapshots.len())
+    }
+
+    #[cold]
+    async fn store_higherlower_games(&self, store_duration: usize) -> Result<usize> {
+        let snapshots = self.active_msgs.higherlower_snapshots().await;
+
+        if snapshots.is_empty() {
+            return Ok(0);
+        }
+
+        self.cache
+            .store_new::<_, _, 64>("higherlower_games", &snapshots, store_duration)
+            .await
+            .wrap_err("Failed to store in redis")?;
+
+        Ok(snapshots.len())
+    }
+
     /// Serialize guild shards and store them in redis for 240 seconds
     #[cold]
     async fn store_guild_shards(&self, store_duration: usize) -> Result<usize> {
@@ -4,6 +4,7 @@ use crate::{
     commands::{
         help::slash_help,
         osu::{slash_badges, slash_cs, slash_medal, slash_regiontop},
+        songs::slash_song,
     },
     core::{events::EventKind, BotMetrics, Context},
     util::interaction::InteractionCommand,
@@ -21,6 +22,7 @@ pub async fn handle_autocomplete(ctx: Arc<Context>, mut command: InteractionComm
         "medal" => slash_medal(ctx, command).await,
         "cs" | "compare" | "score" => slash_cs(ctx, command).await,
         "regiontop" => slash_regiontop(ctx, command).await,
+        "song" => slash_song(ctx, command).await,
         _ => return error!(name, "Unknown autocomplete command"),
     };
 
@@ -12,7 +12,9 @@ use crate::{
 mod autocomplete;
 mod command;
 
-pub async fn handle_interaction(ctx: Arc<Context>, interaction: Interaction) {
+/// `acked` specifies whether the interaction was already acknowledged by the
+/// server's interactions endpoint.
+pub async fn handle_interaction(ctx: Arc<Context>, interaction: Interaction, acked: bool) {
     let Interaction {
         app_permissions: permissions,
         channel,
@@ -20,6 +22,7 @@ pub async fn handle_interaction(ctx: Arc<Context>, interaction: Interaction) {
         guild_id,
         id,
         kind,
+        locale,
         member,
         message,
         token,
@@ -35,10 +38,12 @@ pub async fn handle_interaction(ctx: Arc<Context>, interaction: Interaction) {
         Some(InteractionData::ApplicationCommand(data)) => {
             let cmd = InteractionCommand {
                 permissions,
+                acked,
                 channel_id,
                 data,
                 guild_id,
                 id,
+                locale,
                 member,
                 token,
                 user,
@@ -59,10 +64,12 @@ pub async fn handle_interaction(ctx: Arc<Context>, interaction: Interaction) {
 
             let component = InteractionComponent {
                 permissions,
+                acked,
                 channel_id,
                 data,
                 guild_id,
                 id,
+                locale,
                 member,
                 message,
                 token,
@@ -74,6 +81,7 @@ pub async fn handle_interaction(ctx: Arc<Context>, interaction: Interaction) {
         Some(InteractionData::ModalSubmit(data)) => {
             let modal = InteractionModal {
                 permissions,
+                acked,
                 channel_id,
                 data,
                 guild_id,
@@ -1,6 +1,7 @@
-use std::{sync::Arc, time::Instant};
+use std::{fmt::Write, sync::Arc, time::Instant};
 
 use bathbot_psql::model::configs::{GuildConfig, Prefix, DEFAULT_PREFIX};
+use bathbot_util::MessageBuilder;
 use eyre::Result;
 use nom::{
     bytes::complete as by,
@@ -52,9 +53,41 @@ pub async fn handle_message(ctx: Arc<Context>, msg: Message) {
         return;
     };
 
-    // Parse msg content for commands
-    let Some(invoke) = Invoke::parse(content) else {
-        return;
+    // Expanded custom alias, declared here so that the invoke can borrow it
+    let expanded;
+
+    // Parse msg content for commands, considering custom aliases of the guild
+    // if no command matches
+    let invoke = match (Invoke::parse(content), msg.guild_id) {
+        (Some(invoke), _) => invoke,
+        (None, Some(guild_id)) => {
+            let f = |config: &GuildConfig| {
+                let alias = expand_alias(content, &config.command_aliases);
+
+                (alias, config.command_suggestions.unwrap_or(false))
+            };
+
+            let (alias, suggest) = ctx.guild_config().peek(guild_id, f).await;
+
+            let Some(alias) = alias else {
+                if suggest {
+                    if let Err(err) = suggest_commands(&ctx, &msg, content).await {
+                        warn!(?err, "Failed to suggest commands");
+                    }
+                }
+
+                return;
+            };
+
+            expanded = alias;
+
+            let Some(invoke) = Invoke::parse(&expanded) else {
+                return;
+            };
+
+            invoke
+        }
+        (None, None) => return,
     };
 
     let name = invoke.cmd.name();
@@ -156,3 +189,49 @@ async fn process_command<'m>(
 
     Ok(ProcessResult::Success)
 }
+
+/// Reply to an unknown command with the names of similar commands.
+async fn suggest_commands(ctx: &Context, msg: &Message, content: &str) -> Result<()> {
+    let Some((name, similar)) = similar_commands(content) else {
+        return Ok(());
+    };
+
+    if ctx
+        .check_ratelimit(msg.author.id, BucketName::CommandSuggestion)
+        .is_some()
+    {
+        return Ok(());
+    }
+
+    let permissions = match (msg.guild_id, ctx.cache.current_user().await) {
+        (Some(guild), Ok(Some(user))) => {
+            let permissions =
+                check_channel_permissions(&ctx.cache, user.id, msg.channel_id, guild).await;
+
+            if !permissions.contains(Permissions::SEND_MESSAGES) {
+                return Ok(());
+            }
+
+            Some(permissions)
+        }
+        _ => None,
+    };
+
+    let mut content = format!("There is no command `{name}`, did you mean ");
+    let mut names = similar.iter();
+
+    if let Some(first) = names.next() {
+        let _ = write!(content, "`{first}`");
+
+        for name in names {
+            let _ = write!(content, ", `{name}`");
+        }
+    }
+
+    content.push('?');
+
+    let builder = MessageBuilder::new().embed(content);
+    msg.create_message(ctx, builder, permissions).await?;
+
+    Ok(())
+}
@@ -1,4 +1,5 @@
-use bathbot_util::CowUtils;
+use bathbot_psql::model::configs::CommandAliases;
+use bathbot_util::{string_cmp::levenshtein_similarity, CowUtils};
 use nom::{
     branch::alt,
     character::complete as ch,
@@ -8,6 +9,12 @@ use nom::{
 
 use crate::core::commands::prefix::{Args, ArgsNum, PrefixCommand, PrefixCommands};
 
+/// Minimum similarity for a command name to be suggested
+const MIN_SIMILARITY: f32 = 0.6;
+
+/// Maximum amount of suggested command names
+const MAX_SUGGESTIONS: usize = 3;
+
 pub struct Invoke<'i> {
     pub cmd: &'static PrefixCommand,
     pub args: Args<'i>,
@@ -48,3 +55,83 @@ impl<'i> Invoke<'i> {
         Some(Self { cmd, args })
     }
 }
+
+/// Expand a guild's custom alias at the start of the input to the aliased
+/// command followed by its preset arguments and the remaining input.
+pub fn expand_alias(input: &str, aliases: &CommandAliases) -> Option<String> {
+    if aliases.is_empty() {
+        return None;
+    }
+
+    let (rest, alias) = terminated::<_, _, _, (), _, _>(
+        map_opt(ch::alphanumeric1, |name: &str| {
+            aliases.get(name.cow_to_ascii_lowercase().as_ref())
+        }),
+        alt((ch::space1, eof)),
+    )(input)
+    .ok()?;
+
+    let mut expanded =
+        String::with_capacity(alias.command.len() + alias.args.len() + rest.len() + 2);
+
+    expanded.push_str(&alias.command);
+
+    if !alias.args.is_empty() {
+        expanded.push(' ');
+        expanded.push_str(&alias.args);
+    }
+
+    if !rest.is_empty() {
+        expanded.push(' ');
+        expanded.push_str(rest);
+    }
+
+    Some(expanded)
+}
+
+/// Find the names of the commands most similar to the name at the start of
+/// the input.
+///
+/// Returns the name from the input alongside the suggested names which are
+/// sorted by descending similarity.
+pub fn similar_commands(input: &str) -> Option<(&str, Vec<&'static str>)> {
+    let (_, name) =
+        terminated::<_, _, _, (), _, _>(ch::alpha1, alt((ch::space1, eof)))(input).ok()?;
+
+    // Very short names are likely not meant to be commands
+    if name.len() < 3 {
+        return None;
+    }
+
+    let lowercase = name.cow_to_ascii_lowercase();
+
+    let mut similar: Vec<_> = PrefixCommands::get()
+        .iter()
+        .filter(|cmd| !cmd.flags.only_owner())
+        .filter_map(|cmd| {
+            cmd.names
+                .iter()
+                .map(|&name| (levenshtein_similarity(&lowercase, name), name))
+                .max_by(|(a, _), (b, _)| a.total_cmp(b))
+        })
+        .filter(|(similarity, _)| *similarity >= MIN_SIMILARITY)
+        .collect();
+
+    if similar.is_empty() {
+        return None;
+    }
+
+    // Commands are contained once for each of their names
+    similar.sort_unstable_by(|(a, a_name), (b, b_name)| {
+        b.total_cmp(a).then_with(|| a_name.cmp(b_name))
+    });
+    similar.dedup_by_key(|(_, name)| *name);
+
+    let names = similar
+        .into_iter()
+        .map(|(_, name)| name)
+        .take(MAX_SUGGESTIONS)
+        .collect();
+
+    Some((name, names))
+}
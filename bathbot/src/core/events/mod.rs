@@ -179,6 +179,24 @@ pub async fn event_loop(ctx: Arc<Context>, shards: &mut Vec<Shard>, mut reshard_
     }
 }
 
+/// Handles interactions that were received through the server's interactions
+/// endpoint instead of through the gateway.
+#[cfg(feature = "server")]
+pub async fn http_interaction_loop(ctx: Arc<Context>) {
+    let Some(mut interactions) = ctx.http_interactions.lock().unwrap().take() else {
+        return warn!("Interaction receiver has already been taken");
+    };
+
+    while let Some(interaction) = interactions.recv().await {
+        // Standby only waits for messages and reactions so updating the cache
+        // is all that gateway interactions would have required
+        let change = ctx.cache.update_interaction(&interaction).await;
+        BotMetrics::cache_change(change);
+
+        tokio::spawn(handle_interaction(Arc::clone(&ctx), interaction, true));
+    }
+}
+
 async fn handle_event(ctx: Arc<Context>, event: Event, shard_id: u64) -> Result<()> {
     match event {
         Event::GatewayClose(Some(frame)) => {
@@ -212,7 +230,7 @@ async fn handle_event(ctx: Arc<Context>, event: Event, shard_id: u64) -> Result<
                 warn!(?err, "Failed to forward member request");
             }
         }
-        Event::InteractionCreate(e) => handle_interaction(ctx, e.0).await,
+        Event::InteractionCreate(e) => handle_interaction(ctx, e.0, false).await,
         Event::MemberAdd(e) if e.member.user.id == MISS_ANALYZER_ID => {
             ctx.miss_analyzer_guilds().pin().insert(e.guild_id);
         }
@@ -0,0 +1,286 @@
+//! Bundled message catalogs to localize responses, embeds, and slash
+//! commands.
+//!
+//! Each catalog is a flat JSON object mapping keys to translated texts.
+//! Texts may contain `{name}` placeholders which are filled via [`format`].
+//! Missing keys fall back to the english catalog.
+//!
+//! Slash command descriptions are looked up through the keys
+//! `command.<path>.description` and `command.<path>.name` where `<path>` is
+//! the dot-separated path of the command and its options, e.g.
+//! `command.serverconfig.edit.language.description`.
+
+use std::{collections::HashMap, fmt::Display};
+
+use bathbot_psql::model::configs::Language;
+use once_cell::sync::Lazy;
+use twilight_interactions::command::{ApplicationCommandData, CommandOptionExt};
+use twilight_model::id::{
+    marker::{GuildMarker, UserMarker},
+    Id,
+};
+
+use crate::core::Context;
+
+type Catalog = HashMap<Box<str>, Box<str>>;
+
+struct Catalogs {
+    english: Catalog,
+    german: Catalog,
+    french: Catalog,
+}
+
+static CATALOGS: Lazy<Catalogs> = Lazy::new(|| {
+    fn parse(language: Language, json: &str) -> Catalog {
+        serde_json::from_str(json)
+            .unwrap_or_else(|err| panic!("invalid `{}` catalog: {err}", language.discord_locale()))
+    }
+
+    Catalogs {
+        english: parse(Language::English, include_str!("../../locales/en-US.json")),
+        german: parse(Language::German, include_str!("../../locales/de.json")),
+        french: parse(Language::French, include_str!("../../locales/fr.json")),
+    }
+});
+
+impl Catalogs {
+    fn get(&self, language: Language) -> &Catalog {
+        match language {
+            Language::English => &self.english,
+            Language::German => &self.german,
+            Language::French => &self.french,
+        }
+    }
+}
+
+/// Catalog keys of generic error messages.
+///
+/// Every key is required to be present in all catalogs.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ErrorKey {
+    General,
+    OsuApi,
+    OsuWeb,
+    Ordr,
+    Osekai,
+    Huismetbenen,
+    Osustats,
+    Osutracker,
+    Twitch,
+    ThreadsUnavailable,
+}
+
+impl ErrorKey {
+    pub const ALL: [Self; 10] = [
+        Self::General,
+        Self::OsuApi,
+        Self::OsuWeb,
+        Self::Ordr,
+        Self::Osekai,
+        Self::Huismetbenen,
+        Self::Osustats,
+        Self::Osutracker,
+        Self::Twitch,
+        Self::ThreadsUnavailable,
+    ];
+
+    pub fn key(self) -> &'static str {
+        match self {
+            Self::General => "error.general",
+            Self::OsuApi => "error.osu_api",
+            Self::OsuWeb => "error.osu_web",
+            Self::Ordr => "error.ordr",
+            Self::Osekai => "error.osekai",
+            Self::Huismetbenen => "error.huismetbenen",
+            Self::Osustats => "error.osustats",
+            Self::Osutracker => "error.osutracker",
+            Self::Twitch => "error.twitch",
+            Self::ThreadsUnavailable => "error.threads_unavailable",
+        }
+    }
+
+    /// The error message in the given language.
+    pub fn text(self, language: Language) -> &'static str {
+        text(language, self.key())
+    }
+}
+
+/// Look up the text for a key in the given language.
+///
+/// Falls back to english if the key is not translated and to the key itself
+/// if it's not present at all.
+pub fn text(language: Language, key: &str) -> &str {
+    lookup(language, key).unwrap_or(key)
+}
+
+/// Look up the text for a key and fill in its `{name}` placeholders.
+pub fn format(language: Language, key: &str, args: &[(&str, &dyn Display)]) -> String {
+    let mut text = text(language, key).to_owned();
+
+    for (name, value) in args {
+        text = text.replace(&format!("{{{name}}}"), &value.to_string());
+    }
+
+    text
+}
+
+fn lookup(language: Language, key: &str) -> Option<&'static str> {
+    let catalogs = &*CATALOGS;
+
+    catalogs
+        .get(language)
+        .get(key)
+        .or_else(|| catalogs.english.get(key))
+        .map(Box::as_ref)
+}
+
+/// Determine the language to respond in.
+///
+/// Prefers the user's configured language, then the guild's, and lastly
+/// the discord locale of the user if available.
+pub async fn language(
+    ctx: &Context,
+    user: Option<Id<UserMarker>>,
+    guild: Option<Id<GuildMarker>>,
+    locale: Option<&str>,
+) -> Language {
+    if let Some(user) = user {
+        match ctx.user_config().language(user).await {
+            Ok(Some(language)) => return language,
+            Ok(None) => {}
+            Err(err) => warn!(?err, "Failed to get user language"),
+        }
+    }
+
+    if let Some(guild) = guild {
+        let language_fut = ctx.guild_config().peek(guild, |config| config.language);
+
+        if let Some(language) = language_fut.await {
+            return language;
+        }
+    }
+
+    locale
+        .and_then(Language::from_discord_locale)
+        .unwrap_or_default()
+}
+
+/// Add name and description localizations of all bundled catalogs to the
+/// command and its options.
+pub fn localize_command(command: &mut ApplicationCommandData) {
+    let path = command.name.clone();
+
+    for language in Language::ALL {
+        if language == Language::English {
+            continue;
+        }
+
+        let locale = language.discord_locale();
+
+        if let Some(desc) = localized(language, &path, "description") {
+            command
+                .description_localizations
+                .get_or_insert_with(HashMap::new)
+                .insert(locale.to_owned(), desc.to_owned());
+        }
+
+        if let Some(name) = localized(language, &path, "name") {
+            command
+                .name_localizations
+                .get_or_insert_with(HashMap::new)
+                .insert(locale.to_owned(), name.to_owned());
+        }
+    }
+
+    for option in command.options.iter_mut() {
+        localize_option(option, &path);
+    }
+}
+
+fn localize_option(option: &mut CommandOptionExt, parent: &str) {
+    let path = format!("{parent}.{}", option.inner.name);
+    let inner = &mut option.inner;
+
+    for language in Language::ALL {
+        if language == Language::English {
+            continue;
+        }
+
+        let locale = language.discord_locale();
+
+        if let Some(desc) = localized(language, &path, "description") {
+            inner
+                .description_localizations
+                .get_or_insert_with(HashMap::new)
+                .insert(locale.to_owned(), desc.to_owned());
+        }
+
+        if let Some(name) = localized(language, &path, "name") {
+            inner
+                .name_localizations
+                .get_or_insert_with(HashMap::new)
+                .insert(locale.to_owned(), name.to_owned());
+        }
+    }
+
+    if let Some(ref mut options) = inner.options {
+        for option in options.iter_mut() {
+            localize_option(option, &path);
+        }
+    }
+}
+
+/// Only considers the catalog of the given language, no english fallback.
+fn localized(language: Language, path: &str, field: &str) -> Option<&'static str> {
+    CATALOGS
+        .get(language)
+        .get(format!("command.{path}.{field}").as_str())
+        .map(Box::as_ref)
+}
+
+#[cfg(test)]
+mod tests {
+    use bathbot_util::constants::{GENERAL_ISSUE, OSU_API_ISSUE};
+
+    use super::*;
+
+    #[test]
+    fn catalog_keys_exist_in_english() {
+        let english = &CATALOGS.english;
+
+        for language in [Language::German, Language::French] {
+            for key in CATALOGS.get(language).keys() {
+                if key.starts_with("command.") {
+                    continue;
+                }
+
+                assert!(
+                    english.contains_key(key),
+                    "`{key}` of `{}` missing in english catalog",
+                    language.discord_locale()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn error_keys_exist_in_all_catalogs() {
+        for language in Language::ALL {
+            for key in ErrorKey::ALL {
+                assert!(
+                    CATALOGS.get(language).contains_key(key.key()),
+                    "`{}` missing in `{}` catalog",
+                    key.key(),
+                    language.discord_locale()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn error_keys_match_constants() {
+        assert_eq!(ErrorKey::General.text(Language::English), GENERAL_ISSUE);
+        assert_eq!(ErrorKey::OsuApi.text(Language::English), OSU_API_ISSUE);
+        assert_ne!(ErrorKey::General.text(Language::German), GENERAL_ISSUE);
+    }
+}
@@ -85,6 +85,14 @@ impl BotMetrics {
     }
 
     pub fn event(event: &Event, change: Option<CacheChange>) {
+        Self::cache_change(change);
+
+        if let Some(name) = event.kind().name() {
+            increment_counter!(GATEWAY_EVENTS, "event" => name);
+        }
+    }
+
+    pub fn cache_change(change: Option<CacheChange>) {
         if let Some(change) = change {
             increment_gauge!(CACHE_ENTRIES, change.guilds as f64, "kind" => "Guilds");
             increment_gauge!(CACHE_ENTRIES, change.channels as f64, "kind" => "Channels");
@@ -92,9 +100,5 @@ impl BotMetrics {
             increment_gauge!(CACHE_ENTRIES, change.roles as f64, "kind" => "Roles");
             increment_gauge!(CACHE_ENTRIES, change.unavailable_guilds as f64, "kind" => "Unavailable guilds");
         }
-
-        if let Some(name) = event.kind().name() {
-            increment_counter!(GATEWAY_EVENTS, "event" => name);
-        }
     }
 }
@@ -1,10 +1,13 @@
 pub use self::{
-    config::BotConfig,
+    config::{BotConfig, CacheConfig},
     context::Context,
     events::{event_loop, EventKind},
     metrics::BotMetrics,
 };
 
+#[cfg(feature = "server")]
+pub use self::events::http_interaction_loop;
+
 mod config;
 mod context;
 mod events;
@@ -12,4 +15,5 @@ mod metrics;
 
 pub mod buckets;
 pub mod commands;
+pub mod locale;
 pub mod logging;
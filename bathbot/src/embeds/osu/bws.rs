@@ -172,7 +172,8 @@ impl BWSEmbed {
     }
 }
 
-fn bws(rank: u32, badges: usize) -> u64 {
+/// Badge weighted seeding value via `rank^(0.9937^(badges^2))`
+pub fn bws(rank: u32, badges: usize) -> u64 {
     let rank = rank as f64;
     let badges = badges as i32;
 
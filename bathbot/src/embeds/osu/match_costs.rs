@@ -12,7 +12,7 @@ use bathbot_util::{
 };
 use rosu_v2::model::matches::OsuMatch;
 
-use crate::commands::osu::{MatchResult, PlayerResult};
+use crate::commands::osu::{MatchCostFormula, MatchResult, PlayerResult};
 
 #[derive(EmbedData)]
 pub struct MatchCostEmbed {
@@ -29,6 +29,7 @@ impl MatchCostEmbed {
         description: Option<String>,
         match_result: Option<MatchResult>,
         show_scores: bool,
+        formula: &MatchCostFormula,
     ) -> Option<Self> {
         let mut thumbnail = String::new();
 
@@ -91,7 +92,7 @@ impl MatchCostEmbed {
 
                             while idx < medals.len() {
                                 let red_cost =
-                                    red.get(idx).map(|res| res.match_cost).unwrap_or(0.0);
+                                    red.get(idx).map_or(f32::NEG_INFINITY, |res| res.match_cost);
 
                                 if match_cost > red_cost {
                                     break;
@@ -195,7 +196,13 @@ impl MatchCostEmbed {
             .into_owned();
 
         title.retain(|c| c != '(' && c != ')');
-        let footer = FooterBuilder::new("Note: Formula is subject to change; values are volatile");
+
+        let footer = match formula {
+            MatchCostFormula::Bathbot => {
+                FooterBuilder::new("Note: Formula is subject to change; values are volatile")
+            }
+            _ => FooterBuilder::new(format!("Formula: {}", formula.name())),
+        };
 
         Some(Self {
             title,
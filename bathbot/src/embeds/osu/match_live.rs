@@ -22,7 +22,10 @@ use rosu_v2::prelude::{
 use smallvec::SmallVec;
 use twilight_model::channel::message::embed::Embed;
 
-use crate::util::{osu::grade_emote, Emote};
+use crate::{
+    matchlive::MatchMappool,
+    util::{osu::grade_emote, Emote},
+};
 
 const DESCRIPTION_BUFFER: usize = 45;
 
@@ -40,6 +43,7 @@ pub struct MatchLiveEmbed {
 #[derive(Copy, Clone, Debug)]
 struct GameState {
     game_id: u64,
+    map_id: u32,
     finished: bool,
 }
 
@@ -128,6 +132,7 @@ impl MatchLiveEmbed {
                 MatchEvent::Game { game, .. } => {
                     let next_state = GameState {
                         game_id: game.game_id,
+                        map_id: game.map_id,
                         finished: game.end_time.is_some(),
                     };
 
@@ -259,6 +264,7 @@ impl MatchLiveEmbed {
                     MatchEvent::Game { game, .. } => {
                         let curr_state = GameState {
                             game_id: game.game_id,
+                            map_id: game.map_id,
                             finished: game.end_time.is_some(),
                         };
 
@@ -373,6 +379,7 @@ impl MatchLiveEmbed {
 
                         let state = GameState {
                             game_id: game.game_id,
+                            map_id: game.map_id,
                             finished: game.end_time.is_some(),
                         };
 
@@ -434,9 +441,20 @@ impl MatchLiveEmbed {
         )
     }
 
-    pub fn as_embed(&self) -> Embed {
+    /// Games of the linked mappool's maps are annotated with their slot.
+    pub fn as_embed(&self, mappool: Option<&MatchMappool>) -> Embed {
+        let slot = self
+            .state
+            .zip(mappool)
+            .and_then(|(state, mappool)| mappool.slot(state.map_id));
+
+        let description = match slot {
+            Some(slot) => Cow::Owned(format!("`{slot}` • {}", self.description)),
+            None => Cow::Borrowed(self.description.as_str()),
+        };
+
         let mut builder = EmbedBuilder::new()
-            .description(&self.description)
+            .description(description)
             .title(&self.title)
             .url(&self.url);
 
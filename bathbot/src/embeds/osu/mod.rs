@@ -11,6 +11,7 @@ mod player_snipe_stats;
 mod pp_missing;
 mod profile_compare;
 mod ratio;
+mod snipe_notification;
 mod sniped;
 mod whatif;
 
@@ -23,7 +24,7 @@ pub use self::match_live::*;
 pub use self::{
     attributes::*, bws::*, claim_name::*, country_snipe_stats::*, fix_score::*, match_costs::*,
     medal_stats::*, osustats_counts::*, player_snipe_stats::*, pp_missing::*, profile_compare::*,
-    ratio::*, sniped::*, whatif::*,
+    ratio::*, snipe_notification::*, sniped::*, whatif::*,
 };
 
 pub struct ModsFormatter<'m> {
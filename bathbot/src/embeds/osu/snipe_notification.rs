@@ -0,0 +1,103 @@
+use std::{borrow::Cow, fmt::Write};
+
+use bathbot_macros::EmbedData;
+use bathbot_model::SnipeRecent;
+use bathbot_psql::model::configs::Language;
+use bathbot_util::{
+    constants::{AVATAR_URL, OSU_BASE},
+    datetime::HowLongAgoDynamic,
+    numbers::round,
+    AuthorBuilder, CowUtils, FooterBuilder,
+};
+use rosu_v2::prelude::Username;
+
+use crate::{core::locale, embeds::ModsFormatter};
+
+const MAX_SCORES: usize = 10;
+
+#[derive(EmbedData)]
+pub struct SnipeNotificationEmbed {
+    author: AuthorBuilder,
+    description: String,
+    footer: FooterBuilder,
+    title: String,
+}
+
+impl SnipeNotificationEmbed {
+    /// The scores should be sorted by date, most recent first.
+    pub fn new(username: &Username, user_id: u32, scores: &[SnipeRecent], lang: Language) -> Self {
+        let author = AuthorBuilder::new(username.as_str())
+            .url(format!("{OSU_BASE}u/{user_id}"))
+            .icon_url(format!("{AVATAR_URL}{user_id}"));
+
+        let mut description = String::with_capacity(256);
+        let sniped_by = locale::text(lang, "snipe_notification.sniped_by");
+
+        for score in scores.iter().take(MAX_SCORES) {
+            let mods = score.mods.as_ref().map(Cow::Borrowed).unwrap_or_default();
+
+            let _ = write!(
+                description,
+                "**[{artist} - {title} [{version}]]({OSU_BASE}b/{id}) {mods}**\n{acc}% • ",
+                artist = score.artist.cow_escape_markdown(),
+                title = score.title.cow_escape_markdown(),
+                version = score.version.cow_escape_markdown(),
+                id = score.map_id,
+                mods = ModsFormatter::new(mods.as_ref()),
+                acc = round(score.accuracy),
+            );
+
+            let _ = match score.sniper.as_deref() {
+                Some(name) => write!(
+                    description,
+                    "{sniped_by} [{name}]({OSU_BASE}u/{user_id}) ",
+                    name = name.cow_escape_markdown(),
+                    user_id = score.sniper_id,
+                ),
+                None => write!(
+                    description,
+                    "{sniped_by} [<unknown user>]({OSU_BASE}u/{}) ",
+                    score.sniper_id
+                ),
+            };
+
+            if let Some(ref date) = score.date {
+                let _ = write!(description, "{}", HowLongAgoDynamic::new(date));
+            }
+
+            description.push('\n');
+        }
+
+        if scores.len() > MAX_SCORES {
+            let more = scores.len() - MAX_SCORES;
+            description.push_str(&locale::format(
+                lang,
+                "snipe_notification.more",
+                &[("count", &more)],
+            ));
+        } else {
+            description.pop();
+        }
+
+        let title = if scores.len() == 1 {
+            locale::text(lang, "snipe_notification.title_single").to_owned()
+        } else {
+            let count = scores.len();
+
+            locale::format(
+                lang,
+                "snipe_notification.title_multiple",
+                &[("count", &count)],
+            )
+        };
+
+        let footer = FooterBuilder::new(locale::text(lang, "snipe_notification.footer"));
+
+        Self {
+            author,
+            description,
+            footer,
+            title,
+        }
+    }
+}
@@ -2,13 +2,13 @@ use std::fmt::{Display, Write};
 
 use ::time::UtcOffset;
 use bathbot_psql::model::configs::{
-    ListSize, MinimizedPp, OsuUsername, Retries, ScoreSize, UserConfig,
+    Language, ListSize, MinimizedPp, OsuUsername, Retries, ScoreSize, UserConfig,
 };
 use bathbot_util::{AuthorBuilder, EmbedBuilder, FooterBuilder};
 use rosu_v2::prelude::GameMode;
 use twilight_model::{channel::message::embed::EmbedField, user::User};
 
-use crate::embeds::EmbedData;
+use crate::{core::locale, embeds::EmbedData};
 
 pub struct ConfigEmbed {
     author: AuthorBuilder,
@@ -23,6 +23,7 @@ impl ConfigEmbed {
         config: UserConfig<OsuUsername>,
         twitch: Option<Box<str>>,
         skin_url: Option<String>,
+        lang: Language,
     ) -> Self {
         let author_img = match author.avatar {
             Some(ref hash) if hash.is_animated() => format!(
@@ -40,7 +41,7 @@ impl ConfigEmbed {
         };
 
         let author = AuthorBuilder::new(&author.name).icon_url(author_img);
-        let title = "Current user configuration:";
+        let title = locale::text(lang, "config.title");
 
         let account_value = format!(
             "```\n\
@@ -62,11 +63,11 @@ impl ConfigEmbed {
         let mut fields = vec![
             EmbedField {
                 inline: false,
-                name: "Accounts".to_owned(),
+                name: locale::text(lang, "config.accounts").to_owned(),
                 value: account_value,
             },
             create_field(
-                "Retries",
+                locale::text(lang, "config.retries"),
                 config.retries.unwrap_or(Retries::ConsiderMods),
                 &[
                     (Retries::Hide, "hide"),
@@ -75,17 +76,17 @@ impl ConfigEmbed {
                 ],
             ),
             create_field(
-                "Minimized PP",
+                locale::text(lang, "config.minimized_pp"),
                 config.minimized_pp.unwrap_or_default(),
                 &[(MinimizedPp::MaxPp, "max pp"), (MinimizedPp::IfFc, "if FC")],
             ),
             create_field(
-                "Render button",
+                locale::text(lang, "config.render_button"),
                 config.render_button,
                 &[(Some(true), "show"), (Some(false), "hide")],
             ),
             create_field(
-                "Score embeds",
+                locale::text(lang, "config.score_embeds"),
                 config.score_size.unwrap_or_default(),
                 &[
                     (ScoreSize::AlwaysMinimized, "always minimized"),
@@ -94,7 +95,7 @@ impl ConfigEmbed {
                 ],
             ),
             create_field(
-                "List embeds",
+                locale::text(lang, "config.list_embeds"),
                 config.list_size.unwrap_or_default(),
                 &[
                     (ListSize::Condensed, "condensed"),
@@ -103,7 +104,7 @@ impl ConfigEmbed {
                 ],
             ),
             create_field(
-                "Mode",
+                locale::text(lang, "config.mode"),
                 config.mode,
                 &[
                     (Some(GameMode::Osu), "osu"),
@@ -112,12 +113,21 @@ impl ConfigEmbed {
                     (Some(GameMode::Mania), "mania"),
                 ],
             ),
+            create_field(
+                locale::text(lang, "config.language"),
+                config.language,
+                &[
+                    (Some(Language::English), "english"),
+                    (Some(Language::German), "deutsch"),
+                    (Some(Language::French), "français"),
+                ],
+            ),
         ];
 
         if let Some(skin_url) = skin_url {
             fields.push(EmbedField {
                 inline: false,
-                name: "Skin".to_owned(),
+                name: locale::text(lang, "config.skin").to_owned(),
                 value: skin_url,
             });
         }
@@ -125,7 +135,7 @@ impl ConfigEmbed {
         let footer = config
             .timezone
             .map(UtcOffset::whole_hours)
-            .map(|tz| format!("Timezone: UTC{tz:+}"))
+            .map(|tz| locale::format(lang, "config.timezone", &[("offset", &format!("{tz:+}"))]))
             .map(FooterBuilder::new);
 
         Self {
@@ -137,11 +147,7 @@ impl ConfigEmbed {
     }
 }
 
-pub(super) fn create_field<T: Eq>(
-    name: &'static str,
-    val: T,
-    options: &[(T, &'static str)],
-) -> EmbedField {
+pub(super) fn create_field<T: Eq>(name: &str, val: T, options: &[(T, &'static str)]) -> EmbedField {
     let longest = options.iter().fold(0, |len, (_, text)| len.max(text.len()));
     let capacity = 3 + 1 + options.len() * (longest + 2) + 3;
     let mut value = String::with_capacity(capacity);
@@ -4,12 +4,13 @@ use bathbot_cache::model::CachedArchive;
 use bathbot_macros::EmbedData;
 use bathbot_model::twilight_model::guild::Guild;
 use bathbot_psql::model::configs::{
-    GuildConfig, HideSolutions, ListSize, MinimizedPp, Retries, ScoreSize,
+    GuildConfig, HideSolutions, Language, ListSize, MinimizedPp, Retries, ScoreSize,
 };
 use bathbot_util::AuthorBuilder;
 use twilight_model::channel::message::embed::EmbedField;
 
 use super::config::create_field;
+use crate::core::locale;
 
 #[derive(EmbedData)]
 pub struct ServerConfigEmbed {
@@ -21,7 +22,12 @@ pub struct ServerConfigEmbed {
 }
 
 impl ServerConfigEmbed {
-    pub fn new(guild: CachedArchive<Guild>, config: GuildConfig, authorities: &[String]) -> Self {
+    pub fn new(
+        guild: CachedArchive<Guild>,
+        config: GuildConfig,
+        authorities: &[String],
+        lang: Language,
+    ) -> Self {
         let mut author = AuthorBuilder::new(guild.name.as_ref());
 
         if let Some(hash) = guild.icon.as_ref() {
@@ -34,7 +40,7 @@ impl ServerConfigEmbed {
             author = author.icon_url(url);
         }
 
-        let title = "Current server configuration:";
+        let title = locale::text(lang, "serverconfig.title");
 
         let mut description = String::with_capacity(256);
 
@@ -64,26 +70,34 @@ impl ServerConfigEmbed {
         }
 
         let track_limit = config.track_limit.unwrap_or(50);
-        let _ = writeln!(description, "\nDefault track limit: {track_limit}\n```");
+        let _ = write!(description, "\nDefault track limit: {track_limit}");
+
+        let formula = config.match_cost_formula.as_deref().unwrap_or("default");
+        let _ = writeln!(description, "\nMatch cost formula: {formula}\n```");
 
         let fields = vec![
             create_field(
-                "Render button",
+                locale::text(lang, "config.render_button"),
                 config.render_button.unwrap_or(true),
                 &[(false, "hide"), (true, "let user decide")],
             ),
             create_field(
-                "Song commands",
+                locale::text(lang, "serverconfig.song_commands"),
                 config.allow_songs.unwrap_or(true),
                 &[(true, "enabled"), (false, "disabled")],
             ),
             create_field(
-                "Custom render skins",
+                locale::text(lang, "serverconfig.command_suggestions"),
+                config.command_suggestions.unwrap_or(false),
+                &[(true, "enabled"), (false, "disabled")],
+            ),
+            create_field(
+                locale::text(lang, "serverconfig.custom_skins"),
                 config.allow_custom_skins.unwrap_or(true),
                 &[(true, "allow"), (false, "deny")],
             ),
             create_field(
-                "Score embeds*",
+                &format!("{}*", locale::text(lang, "config.score_embeds")),
                 config.score_size.unwrap_or_default(),
                 &[
                     (ScoreSize::AlwaysMinimized, "always minimized"),
@@ -92,7 +106,7 @@ impl ServerConfigEmbed {
                 ],
             ),
             create_field(
-                "List embeds*",
+                &format!("{}*", locale::text(lang, "config.list_embeds")),
                 config.list_size.unwrap_or_default(),
                 &[
                     (ListSize::Condensed, "condensed"),
@@ -101,7 +115,7 @@ impl ServerConfigEmbed {
                 ],
             ),
             create_field(
-                "Medal solutions",
+                locale::text(lang, "serverconfig.medal_solutions"),
                 config.hide_medal_solution.unwrap_or(HideSolutions::ShowAll),
                 &[
                     (HideSolutions::ShowAll, "show"),
@@ -110,12 +124,21 @@ impl ServerConfigEmbed {
                 ],
             ),
             create_field(
-                "Minimized PP*",
+                locale::text(lang, "config.language"),
+                config.language.unwrap_or_default(),
+                &[
+                    (Language::English, "english"),
+                    (Language::German, "deutsch"),
+                    (Language::French, "français"),
+                ],
+            ),
+            create_field(
+                &format!("{}*", locale::text(lang, "config.minimized_pp")),
                 config.minimized_pp.unwrap_or_default(),
                 &[(MinimizedPp::MaxPp, "max pp"), (MinimizedPp::IfFc, "if FC")],
             ),
             create_field(
-                "Retries*",
+                &format!("{}*", locale::text(lang, "config.retries")),
                 config.retries.unwrap_or(Retries::ConsiderMods),
                 &[
                     (Retries::Hide, "hide"),
@@ -129,7 +152,7 @@ impl ServerConfigEmbed {
             author,
             description,
             fields,
-            footer: "*: Only applies if not set in the member's user config",
+            footer: locale::text(lang, "serverconfig.footer"),
             title,
         }
     }
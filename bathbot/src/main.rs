@@ -127,6 +127,29 @@ async fn async_main() -> Result<()> {
         tokio::spawn(tracking::osu_tracking_loop(osu_tracking_ctx));
     }
 
+    #[cfg(feature = "server")]
+    {
+        // Spawn worker for interactions received through the server
+        let interaction_ctx = Arc::clone(&ctx);
+        tokio::spawn(core::http_interaction_loop(interaction_ctx));
+    }
+
+    // Resume games that were running before the last shutdown
+    let games_ctx = Arc::clone(&ctx);
+    tokio::spawn(Context::resume_games(games_ctx));
+
+    // Spawn snipe notification worker
+    let snipe_ctx = Arc::clone(&ctx);
+    tokio::spawn(tracking::snipe_notification_loop(snipe_ctx));
+
+    // Spawn reminder and announcement worker
+    let schedule_ctx = Arc::clone(&ctx);
+    tokio::spawn(tracking::scheduled_message_loop(schedule_ctx));
+
+    // Spawn tournament result worker
+    let tournament_ctx = Arc::clone(&ctx);
+    tokio::spawn(tracking::tournament_result_loop(tournament_ctx));
+
     #[cfg(feature = "matchlive")]
     {
         // Spawn osu match ticker worker
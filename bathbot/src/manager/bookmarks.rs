@@ -1,4 +1,7 @@
-use bathbot_psql::{model::osu::MapBookmark, Database};
+use bathbot_psql::{
+    model::osu::{DbBookmarkCollection, DbCollectionMap, MapBookmark},
+    Database,
+};
 use eyre::{Result, WrapErr};
 use twilight_model::id::{marker::UserMarker, Id};
 
@@ -32,4 +35,85 @@ impl<'d> BookmarkManager<'d> {
             .await
             .wrap_err("Failed to delete user bookmark")
     }
+
+    /// Collections owned by or shared with the user
+    pub async fn collections(self, user: Id<UserMarker>) -> Result<Vec<DbBookmarkCollection>> {
+        self.psql
+            .select_bookmark_collections(user)
+            .await
+            .wrap_err("Failed to get bookmark collections")
+    }
+
+    pub async fn create_collection(self, owner: Id<UserMarker>, name: &str) -> Result<bool> {
+        self.psql
+            .insert_bookmark_collection(owner, name)
+            .await
+            .wrap_err("Failed to insert bookmark collection")
+    }
+
+    pub async fn delete_collection(self, owner: Id<UserMarker>, name: &str) -> Result<bool> {
+        self.psql
+            .delete_bookmark_collection(owner, name)
+            .await
+            .wrap_err("Failed to delete bookmark collection")
+    }
+
+    pub async fn collection_maps(
+        self,
+        owner: Id<UserMarker>,
+        name: &str,
+    ) -> Result<Vec<DbCollectionMap>> {
+        self.psql
+            .select_bookmark_collection_maps(owner, name)
+            .await
+            .wrap_err("Failed to get bookmark collection maps")
+    }
+
+    pub async fn add_to_collection(
+        self,
+        owner: Id<UserMarker>,
+        name: &str,
+        map_id: u32,
+    ) -> Result<bool> {
+        self.psql
+            .insert_bookmark_collection_map(owner, name, map_id)
+            .await
+            .wrap_err("Failed to insert bookmark collection map")
+    }
+
+    pub async fn remove_from_collection(
+        self,
+        owner: Id<UserMarker>,
+        name: &str,
+        map_id: u32,
+    ) -> Result<bool> {
+        self.psql
+            .delete_bookmark_collection_map(owner, name, map_id)
+            .await
+            .wrap_err("Failed to delete bookmark collection map")
+    }
+
+    pub async fn share_collection(
+        self,
+        owner: Id<UserMarker>,
+        name: &str,
+        user: Id<UserMarker>,
+    ) -> Result<bool> {
+        self.psql
+            .insert_bookmark_collection_share(owner, name, user)
+            .await
+            .wrap_err("Failed to insert bookmark collection share")
+    }
+
+    pub async fn unshare_collection(
+        self,
+        owner: Id<UserMarker>,
+        name: &str,
+        user: Id<UserMarker>,
+    ) -> Result<bool> {
+        self.psql
+            .delete_bookmark_collection_share(owner, name, user)
+            .await
+            .wrap_err("Failed to delete bookmark collection share")
+    }
 }
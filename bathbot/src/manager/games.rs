@@ -1,11 +1,14 @@
-use bathbot_model::{BgGameScore, HlGameScore, HlVersion};
+use bathbot_model::{BgGameScore, HlGameScore, HlVersion, TriviaScore};
 use bathbot_psql::{
-    model::games::{DbMapTagsParams, MapsetTagsEntries},
+    model::games::{DbHlMap, DbMapTagsParams, DbTriviaMapset, MapsetTagsEntries},
     Database,
 };
 use eyre::{Result, WrapErr};
 use rosu_v2::prelude::GameMode;
-use twilight_model::id::{marker::UserMarker, Id};
+use twilight_model::id::{
+    marker::{GuildMarker, UserMarker},
+    Id,
+};
 
 #[derive(Copy, Clone)]
 pub struct GameManager<'d> {
@@ -48,6 +51,13 @@ impl GameManager<'_> {
             .await
             .wrap_err("Failed to upsert higherlower score")
     }
+
+    pub async fn higherlower_maps(self, amount: usize) -> Result<Vec<DbHlMap>> {
+        self.psql
+            .select_higherlower_maps(amount)
+            .await
+            .wrap_err("failed to get higherlower maps")
+    }
 }
 
 impl GameManager<'_> {
@@ -89,3 +99,56 @@ impl GameManager<'_> {
             .wrap_err("failed to upsert mapset")
     }
 }
+
+impl GameManager<'_> {
+    pub async fn minesweeper_best_time(
+        self,
+        user_id: Id<UserMarker>,
+        difficulty: i16,
+    ) -> Result<Option<u32>> {
+        self.psql
+            .select_minesweeper_best_time(user_id, difficulty)
+            .await
+            .wrap_err("failed to get minesweeper best time")
+    }
+
+    pub async fn upsert_minesweeper_time(
+        self,
+        user_id: Id<UserMarker>,
+        difficulty: i16,
+        time_ms: u32,
+    ) -> Result<bool> {
+        self.psql
+            .upsert_minesweeper_time(user_id, difficulty, time_ms)
+            .await
+            .wrap_err("Failed to upsert minesweeper time")
+    }
+}
+
+impl GameManager<'_> {
+    pub async fn trivia_leaderboard(self, guild_id: Id<GuildMarker>) -> Result<Vec<TriviaScore>> {
+        self.psql
+            .select_trivia_scores(guild_id)
+            .await
+            .wrap_err("failed to get trivia leaderboard")
+    }
+
+    pub async fn trivia_increment_score(
+        self,
+        guild_id: Id<GuildMarker>,
+        user_id: Id<UserMarker>,
+        amount: u32,
+    ) -> Result<()> {
+        self.psql
+            .increment_trivia_score(guild_id, user_id, amount as i32)
+            .await
+            .wrap_err("failed to increment trivia score")
+    }
+
+    pub async fn trivia_mapsets(self, amount: usize) -> Result<Vec<DbTriviaMapset>> {
+        self.psql
+            .select_trivia_mapsets(amount)
+            .await
+            .wrap_err("failed to get trivia mapsets")
+    }
+}
@@ -0,0 +1,75 @@
+use bathbot_psql::{
+    model::mappool::{DbMappool, DbMappoolMap},
+    Database,
+};
+use eyre::{Result, WrapErr};
+use twilight_model::id::{marker::GuildMarker, Id};
+
+#[derive(Copy, Clone)]
+pub struct MappoolManager<'d> {
+    psql: &'d Database,
+}
+
+impl<'d> MappoolManager<'d> {
+    pub fn new(psql: &'d Database) -> Self {
+        Self { psql }
+    }
+
+    pub async fn mappools(self, guild: Id<GuildMarker>) -> Result<Vec<DbMappool>> {
+        self.psql
+            .select_mappools(guild)
+            .await
+            .wrap_err("Failed to get mappools")
+    }
+
+    pub async fn mappool(self, guild: Id<GuildMarker>, name: &str) -> Result<Option<DbMappool>> {
+        self.psql
+            .select_mappool(guild, name)
+            .await
+            .wrap_err("Failed to get mappool")
+    }
+
+    /// Returns `false` if the guild already has a mappool with that name.
+    pub async fn create(self, guild: Id<GuildMarker>, name: &str) -> Result<bool> {
+        self.psql
+            .insert_mappool(guild, name)
+            .await
+            .wrap_err("Failed to insert mappool")
+    }
+
+    pub async fn delete(self, mappool_id: i32) -> Result<()> {
+        self.psql
+            .delete_mappool(mappool_id)
+            .await
+            .wrap_err("Failed to delete mappool")
+    }
+
+    pub async fn maps(self, mappool_id: i32) -> Result<Vec<DbMappoolMap>> {
+        self.psql
+            .select_mappool_maps(mappool_id)
+            .await
+            .wrap_err("Failed to get mappool maps")
+    }
+
+    /// Mods of `None` denote a freemod slot.
+    pub async fn set_map(
+        self,
+        mappool_id: i32,
+        slot: &str,
+        map_id: u32,
+        mods: Option<u32>,
+    ) -> Result<()> {
+        self.psql
+            .upsert_mappool_map(mappool_id, slot, map_id, mods)
+            .await
+            .wrap_err("Failed to upsert mappool map")
+    }
+
+    /// Returns `false` if the mappool had no such slot.
+    pub async fn remove_map(self, mappool_id: i32, slot: &str) -> Result<bool> {
+        self.psql
+            .delete_mappool_map(mappool_id, slot)
+            .await
+            .wrap_err("Failed to delete mappool map")
+    }
+}
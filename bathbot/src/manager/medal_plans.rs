@@ -0,0 +1,124 @@
+use bathbot_psql::{
+    model::osu::{DbMedalPlan, MedalPlanStatus},
+    Database,
+};
+use bathbot_util::IntHasher;
+use eyre::{Result, WrapErr};
+use hashbrown::HashSet;
+use time::OffsetDateTime;
+use twilight_model::id::{marker::UserMarker, Id};
+
+#[derive(Copy, Clone)]
+pub struct MedalPlanManager<'d> {
+    psql: &'d Database,
+}
+
+impl<'d> MedalPlanManager<'d> {
+    pub fn new(psql: &'d Database) -> Self {
+        Self { psql }
+    }
+
+    pub async fn plans(self, user: Id<UserMarker>) -> Result<Vec<DbMedalPlan>> {
+        self.psql
+            .select_medal_plans(user)
+            .await
+            .wrap_err("Failed to get medal plans")
+    }
+
+    /// Set the status of a medal or remove it if `status` is `None`.
+    ///
+    /// Returns `false` if the status was to be removed but there was none.
+    pub async fn set_status(
+        self,
+        user: Id<UserMarker>,
+        medal_id: u32,
+        status: Option<MedalPlanStatus>,
+    ) -> Result<bool> {
+        match status {
+            Some(status) => self
+                .psql
+                .upsert_medal_plan(user, medal_id, status)
+                .await
+                .wrap_err("Failed to upsert medal plan")
+                .map(|_| true),
+            None => self
+                .psql
+                .delete_medal_plan(user, medal_id)
+                .await
+                .wrap_err("Failed to delete medal plan"),
+        }
+    }
+
+    /// Compare the given medals of the osu! user with the previously stored
+    /// ones and store all medals that weren't tracked yet.
+    ///
+    /// Plans for newly achieved medals will be removed.
+    pub async fn track_progress(
+        self,
+        user: Id<UserMarker>,
+        osu_id: u32,
+        owned: &[(u32, OffsetDateTime)],
+    ) -> Result<MedalProgress> {
+        let tracked = self
+            .psql
+            .select_medal_progress(user, osu_id)
+            .await
+            .wrap_err("Failed to get medal progress")?;
+
+        let tracked_since = tracked.iter().map(|entry| entry.tracked_at).min();
+
+        let tracked_ids: HashSet<_, IntHasher> =
+            tracked.iter().map(|entry| entry.medal_id).collect();
+
+        let (medal_ids, achieved_at): (Vec<_>, Vec<_>) = owned
+            .iter()
+            .filter(|(medal_id, _)| !tracked_ids.contains(medal_id))
+            .map(|(medal_id, achieved_at)| (*medal_id as i32, *achieved_at))
+            .unzip();
+
+        if !medal_ids.is_empty() {
+            self.psql
+                .insert_medal_progress(user, osu_id, &medal_ids, &achieved_at)
+                .await
+                .wrap_err("Failed to insert medal progress")?;
+
+            self.psql
+                .delete_medal_plans(user, &medal_ids)
+                .await
+                .wrap_err("Failed to delete medal plans")?;
+        }
+
+        // On the first run every medal is new so they're only
+        // considered as baseline
+        let newly_achieved = match tracked_since {
+            Some(_) => medal_ids
+                .into_iter()
+                .map(|medal_id| medal_id as u32)
+                .collect(),
+            None => Vec::new(),
+        };
+
+        let tracked_since = tracked_since.unwrap_or_else(OffsetDateTime::now_utc);
+
+        let achieved_since_tracking = tracked
+            .iter()
+            .filter(|entry| entry.achieved_at > tracked_since)
+            .count()
+            + newly_achieved.len();
+
+        Ok(MedalProgress {
+            newly_achieved,
+            tracked_since,
+            achieved_since_tracking,
+        })
+    }
+}
+
+pub struct MedalProgress {
+    /// Medals that were achieved since the last check
+    pub newly_achieved: Vec<u32>,
+    /// Date of the first check
+    pub tracked_since: OffsetDateTime,
+    /// Amount of medals achieved since the first check
+    pub achieved_since_tracking: usize,
+}
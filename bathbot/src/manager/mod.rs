@@ -4,6 +4,8 @@ pub use self::{
     github::GithubManager,
     guild_config::GuildConfigManager,
     huismetbenen_country::HuismetbenenCountryManager,
+    mappool::MappoolManager,
+    medal_plans::MedalPlanManager,
     osu_map::{MapError, MapManager, OsuMap, OsuMapSlim},
     osu_scores::ScoresManager,
     osu_tracking::OsuTrackingManager,
@@ -11,6 +13,10 @@ pub use self::{
     pp::PpManager,
     rank_pp_approx::ApproxManager,
     replay::{OwnedReplayScore, ReplayManager, ReplayScore, ReplaySettings, ReplaySkin},
+    schedule::ScheduleManager,
+    snipe::SnipeManager,
+    songs::SongManager,
+    tournament::TournamentManager,
     twitch::TwitchManager,
     user_config::UserConfigManager,
 };
@@ -22,6 +28,8 @@ mod games;
 mod github;
 mod guild_config;
 mod huismetbenen_country;
+mod mappool;
+mod medal_plans;
 mod osu_map;
 mod osu_scores;
 mod osu_tracking;
@@ -29,5 +37,9 @@ mod osu_user;
 mod pp;
 mod rank_pp_approx;
 mod replay;
+mod schedule;
+mod snipe;
+mod songs;
+mod tournament;
 mod twitch;
 mod user_config;
@@ -102,6 +102,8 @@ impl<'c> ScoresManager<'c> {
         mods: Option<GameModsIntermode>,
         limit: u32,
     ) -> Result<Vec<Score>> {
+        let track_national_firsts = mode == GameMode::Osu && mods.is_none();
+
         let mut req = self
             .ctx
             .osu()
@@ -117,6 +119,8 @@ impl<'c> ScoresManager<'c> {
 
         if let Err(err) = self.store(&scores).await {
             warn!(?err, "Failed to store leaderboard scores");
+        } else if track_national_firsts {
+            self.ctx.snipe().process_leaderboard(&scores);
         }
 
         Ok(scores)
@@ -283,6 +287,8 @@ impl<'c> ScoreArgs<'c> {
 
         if let Err(err) = store_res {
             warn!(?err, "Failed to store top scores");
+        } else if mode == GameMode::Osu {
+            ctx.snipe().process_scores(&scores);
         }
 
         Ok(scores)
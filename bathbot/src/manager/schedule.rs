@@ -0,0 +1,97 @@
+use bathbot_psql::{model::schedule::DbScheduledMessage, Database};
+use eyre::{Result, WrapErr};
+use time::OffsetDateTime;
+use twilight_model::id::{
+    marker::{ChannelMarker, GuildMarker, UserMarker},
+    Id,
+};
+
+#[derive(Copy, Clone)]
+pub struct ScheduleManager<'d> {
+    psql: &'d Database,
+}
+
+impl<'d> ScheduleManager<'d> {
+    pub fn new(psql: &'d Database) -> Self {
+        Self { psql }
+    }
+
+    pub async fn due(self, until: OffsetDateTime) -> Result<Vec<DbScheduledMessage>> {
+        self.psql
+            .select_due_scheduled_messages(until)
+            .await
+            .wrap_err("Failed to get due scheduled messages")
+    }
+
+    pub async fn reminders(self, user: Id<UserMarker>) -> Result<Vec<DbScheduledMessage>> {
+        self.psql
+            .select_user_reminders(user)
+            .await
+            .wrap_err("Failed to get user reminders")
+    }
+
+    pub async fn announcements(self, guild: Id<GuildMarker>) -> Result<Vec<DbScheduledMessage>> {
+        self.psql
+            .select_guild_announcements(guild)
+            .await
+            .wrap_err("Failed to get guild announcements")
+    }
+
+    /// Returns the id of the new reminder.
+    pub async fn add_reminder(
+        self,
+        user: Id<UserMarker>,
+        guild: Option<Id<GuildMarker>>,
+        channel: Id<ChannelMarker>,
+        content: &str,
+        trigger_at: OffsetDateTime,
+    ) -> Result<i32> {
+        self.psql
+            .insert_scheduled_message(user, guild, channel, content, true, trigger_at)
+            .await
+            .wrap_err("Failed to insert reminder")
+    }
+
+    /// Returns the id of the new announcement.
+    pub async fn add_announcement(
+        self,
+        author: Id<UserMarker>,
+        guild: Id<GuildMarker>,
+        channel: Id<ChannelMarker>,
+        content: &str,
+        trigger_at: OffsetDateTime,
+    ) -> Result<i32> {
+        self.psql
+            .insert_scheduled_message(author, Some(guild), channel, content, false, trigger_at)
+            .await
+            .wrap_err("Failed to insert announcement")
+    }
+
+    pub async fn remove(self, schedule_id: i32) -> Result<()> {
+        self.psql
+            .delete_scheduled_message(schedule_id)
+            .await
+            .wrap_err("Failed to delete scheduled message")
+            .map(|_| ())
+    }
+
+    /// Returns `false` if the user has no reminder with the given id.
+    pub async fn remove_reminder(self, user: Id<UserMarker>, schedule_id: i32) -> Result<bool> {
+        self.psql
+            .delete_user_reminder(user, schedule_id)
+            .await
+            .wrap_err("Failed to delete reminder")
+    }
+
+    /// Returns `false` if the guild has no announcement with the given id.
+    pub async fn remove_announcement(
+        self,
+        guild: Id<GuildMarker>,
+        schedule_id: i32,
+    ) -> Result<bool> {
+        self.psql
+            .delete_guild_announcement(guild, schedule_id)
+            .await
+            .wrap_err("Failed to delete announcement")
+    }
+}
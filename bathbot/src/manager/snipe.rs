@@ -0,0 +1,681 @@
+use std::{
+    cmp::Ordering,
+    collections::{BTreeMap, HashMap, HashSet},
+    future::Future,
+};
+
+use bathbot_model::{
+    ModsCount, SnipeBeatmap, SnipeCountryPlayer, SnipeCountryStatistics, SnipePlayer,
+    SnipePlayerListOrder, SnipePlayerOldest, SnipeRecent, SnipeScore, SnipeScoreParams,
+    SnipeTopNationalDifference,
+};
+use bathbot_psql::{
+    model::osu::{DbSnipeChange, DbSnipeDifference, DbSnipeNotification, DbSnipeScore},
+    Database,
+};
+use bathbot_util::{osu::ModSelection, IntHasher};
+use eyre::{Result, WrapErr};
+use rosu_v2::prelude::{GameMode, GameModsIntermode, RankStatus, Score, Username};
+use time::{Duration, OffsetDateTime};
+use twilight_model::id::{
+    marker::{ChannelMarker, UserMarker},
+    Id,
+};
+
+use crate::core::{BotConfig, Context};
+
+/// Amount of scores per page when requesting national #1s.
+/// Matches the page size of huismetbenen.
+const FIRSTS_PER_PAGE: usize = 50;
+
+/// Provides snipe data through huismetbenen and falls back to locally tracked
+/// national #1s for countries configured in `SNIPE_COUNTRIES`.
+#[derive(Copy, Clone)]
+pub struct SnipeManager<'c> {
+    ctx: &'c Context,
+    psql: &'c Database,
+}
+
+impl<'c> SnipeManager<'c> {
+    pub fn new(ctx: &'c Context, psql: &'c Database) -> Self {
+        Self { ctx, psql }
+    }
+
+    /// Whether national #1s of the country are tracked locally.
+    pub fn is_tracked(country_code: &str) -> bool {
+        BotConfig::get()
+            .snipe_countries
+            .iter()
+            .any(|tracked| tracked.eq_ignore_ascii_case(country_code))
+    }
+
+    /// Whether snipe data is available for the country, either through
+    /// huismetbenen or through local tracking.
+    pub async fn is_supported(self, country_code: &str) -> bool {
+        Self::is_tracked(country_code) || self.ctx.huismetbenen().is_supported(country_code).await
+    }
+
+    /// Use huismetbenen's data if it supports the country and fall back to the
+    /// local data on failure.
+    async fn fetch<T>(
+        self,
+        country_code: &str,
+        remote: impl Future<Output = Result<T>>,
+        local: impl Future<Output = Result<T>>,
+    ) -> Result<T> {
+        let is_tracked = Self::is_tracked(country_code);
+
+        if self.ctx.huismetbenen().is_supported(country_code).await {
+            match remote.await {
+                Ok(data) => return Ok(data),
+                Err(err) if is_tracked => {
+                    warn!(
+                        ?err,
+                        "Failed to get huismetbenen data, using local snipe data"
+                    );
+                }
+                Err(err) => return Err(err),
+            }
+        } else if !is_tracked {
+            bail!("Country code {country_code} is not supported");
+        }
+
+        local.await
+    }
+
+    pub async fn player(self, country_code: &str, user_id: u32) -> Result<Option<SnipePlayer>> {
+        let remote = self.ctx.client().get_snipe_player(country_code, user_id);
+        let local = self.local_player(country_code, user_id);
+
+        self.fetch(country_code, remote, local).await
+    }
+
+    pub async fn country_players(self, country_code: &str) -> Result<Vec<SnipeCountryPlayer>> {
+        let remote = self.ctx.client().get_snipe_country(country_code);
+        let local = self.local_country_players(country_code);
+
+        self.fetch(country_code, remote, local).await
+    }
+
+    pub async fn country_statistics(self, country_code: &str) -> Result<SnipeCountryStatistics> {
+        let remote = self.ctx.client().get_country_statistics(country_code);
+        let local = self.local_country_statistics(country_code);
+
+        self.fetch(country_code, remote, local).await
+    }
+
+    /// National #1s the user gained if `sniper` is `true`, otherwise national
+    /// #1s the user lost.
+    pub async fn national_snipes(
+        self,
+        country_code: &str,
+        user_id: u32,
+        sniper: bool,
+        from: OffsetDateTime,
+        until: OffsetDateTime,
+    ) -> Result<Vec<SnipeRecent>> {
+        let remote = self
+            .ctx
+            .client()
+            .get_national_snipes(user_id, sniper, from, until);
+
+        let local = self.local_national_snipes(country_code, user_id, sniper, from, until);
+
+        self.fetch(country_code, remote, local).await
+    }
+
+    pub async fn national_firsts(self, params: &SnipeScoreParams) -> Result<Vec<SnipeScore>> {
+        let remote = self.ctx.client().get_national_firsts(params);
+        let local = self.local_national_firsts(params);
+
+        self.fetch(params.country.as_str(), remote, local).await
+    }
+
+    pub async fn national_firsts_count(self, params: &SnipeScoreParams) -> Result<usize> {
+        let remote = self.ctx.client().get_national_firsts_count(params);
+
+        let local = async {
+            let scores = self.local_filtered_firsts(params).await?;
+
+            Ok(scores.len())
+        };
+
+        self.fetch(params.country.as_str(), remote, local).await
+    }
+
+    /// Update local national #1s in the background based on an unmodded
+    /// osu!standard map leaderboard.
+    ///
+    /// The scores must already be stored in the database.
+    pub fn process_leaderboard(self, scores: &[Score]) {
+        if BotConfig::get().snipe_countries.is_empty() {
+            return;
+        }
+
+        let mut handled = HashSet::new();
+
+        // Leaderboards are sorted by score so the first score of
+        // each country is its national #1
+        let firsts: Vec<_> = scores
+            .iter()
+            .filter_map(|score| {
+                let user = score.user.as_ref()?;
+                let score_id = score.score_id?;
+                let country_code = user.country_code.as_str();
+
+                if !Self::is_tracked(country_code) || !handled.insert(country_code) {
+                    return None;
+                }
+
+                Some((
+                    Box::<str>::from(country_code),
+                    user.username.clone(),
+                    score.map_id,
+                    score.user_id,
+                    score_id,
+                    score.score,
+                ))
+            })
+            .collect();
+
+        if firsts.is_empty() {
+            return;
+        }
+
+        let psql = self.psql.clone();
+
+        tokio::spawn(async move {
+            for (country_code, username, map_id, user_id, score_id, score) in firsts {
+                if let Err(err) = psql.upsert_osu_username(user_id, &username).await {
+                    warn!(?err, "Failed to upsert username");
+                }
+
+                let upsert_fut = psql.upsert_snipe_national_first(
+                    &country_code,
+                    map_id,
+                    user_id,
+                    score_id,
+                    score,
+                    true,
+                );
+
+                if let Err(err) = upsert_fut.await {
+                    warn!(?err, "Failed to upsert national first");
+                }
+            }
+        });
+    }
+
+    /// Update local national #1s in the background if any of the given
+    /// osu!standard scores beat a stored national #1.
+    ///
+    /// The scores must already be stored in the database.
+    pub fn process_scores(self, scores: &[Score]) {
+        if BotConfig::get().snipe_countries.is_empty() {
+            return;
+        }
+
+        let candidates: Vec<_> = scores
+            .iter()
+            .filter(|score| score.passed && score.mode == GameMode::Osu)
+            .filter_map(|score| {
+                let user = score.user.as_ref()?;
+                let score_id = score.score_id?;
+                let country_code = user.country_code.as_str();
+
+                Self::is_tracked(country_code).then(|| {
+                    let country_code = Box::<str>::from(country_code);
+
+                    (
+                        country_code,
+                        score.map_id,
+                        score.user_id,
+                        score_id,
+                        score.score,
+                    )
+                })
+            })
+            .collect();
+
+        if candidates.is_empty() {
+            return;
+        }
+
+        let psql = self.psql.clone();
+
+        tokio::spawn(async move {
+            for (country_code, map_id, user_id, score_id, score) in candidates {
+                let upsert_fut = psql.upsert_snipe_national_first(
+                    &country_code,
+                    map_id,
+                    user_id,
+                    score_id,
+                    score,
+                    false,
+                );
+
+                if let Err(err) = upsert_fut.await {
+                    warn!(?err, "Failed to upsert national first");
+                }
+            }
+        });
+    }
+
+    pub async fn notifications(self) -> Result<Vec<DbSnipeNotification>> {
+        self.psql
+            .select_snipe_notifications()
+            .await
+            .wrap_err("Failed to get snipe notifications")
+    }
+
+    /// Notify the user about lost national #1s either in the given channel or,
+    /// if no channel is specified, via DM.
+    pub async fn set_notification(
+        self,
+        user_id: Id<UserMarker>,
+        osu_id: u32,
+        country_code: &str,
+        channel_id: Option<Id<ChannelMarker>>,
+    ) -> Result<()> {
+        self.psql
+            .upsert_snipe_notification(user_id, osu_id, country_code, channel_id)
+            .await
+            .wrap_err("Failed to upsert snipe notification")
+    }
+
+    pub async fn update_notification_date(
+        self,
+        user_id: Id<UserMarker>,
+        last_notified: OffsetDateTime,
+    ) -> Result<()> {
+        self.psql
+            .update_snipe_notification_date(user_id, last_notified)
+            .await
+            .wrap_err("Failed to update snipe notification date")
+    }
+
+    /// Returns whether the user had notifications enabled.
+    pub async fn remove_notification(self, user_id: Id<UserMarker>) -> Result<bool> {
+        self.psql
+            .delete_snipe_notification(user_id)
+            .await
+            .wrap_err("Failed to delete snipe notification")
+    }
+
+    async fn local_player(self, country_code: &str, user_id: u32) -> Result<Option<SnipePlayer>> {
+        let country_code = country_code.to_ascii_uppercase();
+        let psql = self.psql;
+
+        let scores = psql
+            .select_snipe_player_scores(&country_code, user_id)
+            .await
+            .wrap_err("Failed to get snipe player scores")?;
+
+        let Some(oldest) = scores.iter().min_by_key(|score| score.ended_at) else {
+            return Ok(None);
+        };
+
+        let oldest_first = SnipePlayerOldest {
+            map_id: oldest.map_id,
+            map: format!("{} - {} [{}]", oldest.artist, oldest.title, oldest.version).into(),
+            date: oldest.ended_at,
+        };
+
+        let username = psql
+            .select_osu_name_by_osu_id(user_id)
+            .await
+            .wrap_err("Failed to get username")?
+            .unwrap_or_else(|| Username::from(user_id.to_string().as_str()));
+
+        let count_first_history = psql
+            .select_snipe_player_history(&country_code, user_id)
+            .await
+            .wrap_err("Failed to get snipe player history")?;
+
+        let now = OffsetDateTime::now_utc();
+        let week_ago = now - Duration::weeks(1);
+
+        let gains_fut = psql.select_snipe_changes(&country_code, user_id, true, week_ago, now);
+        let losses_fut = psql.select_snipe_changes(&country_code, user_id, false, week_ago, now);
+
+        let (gains, losses) =
+            tokio::try_join!(gains_fut, losses_fut).wrap_err("Failed to get snipe changes")?;
+
+        let len = scores.len() as f32;
+        let mut count_loved = 0;
+        let mut count_ranked = 0;
+        let mut count_mods = HashMap::<_, u32, IntHasher>::default();
+        let mut count_sr_spread = BTreeMap::new();
+        let (mut pp_sum, mut acc_sum, mut stars_sum, mut score_sum) = (0.0, 0.0, 0.0, 0.0);
+
+        for score in scores.iter() {
+            match score.status {
+                RankStatus::Loved => count_loved += 1,
+                RankStatus::Ranked | RankStatus::Approved => count_ranked += 1,
+                _ => {}
+            }
+
+            *count_mods.entry(score.mods).or_default() += 1;
+
+            let stars = score.stars.unwrap_or(0.0);
+
+            let count = count_sr_spread.entry(stars as i8).or_insert(Some(0));
+            *count.get_or_insert(0) += 1;
+
+            pp_sum += score.pp.unwrap_or(0.0);
+            acc_sum += accuracy(
+                score.count_300,
+                score.count_100,
+                score.count_50,
+                score.count_miss,
+            );
+            stars_sum += stars;
+            score_sum += score.score as f32;
+        }
+
+        let count_mods: ModsCount = count_mods
+            .into_iter()
+            .map(|(mods, count)| {
+                let mods = if mods == 0 {
+                    Box::from("NM")
+                } else {
+                    GameModsIntermode::from_bits(mods)
+                        .to_string()
+                        .into_boxed_str()
+                };
+
+                (mods, count)
+            })
+            .collect();
+
+        let player = SnipePlayer {
+            username,
+            user_id,
+            avg_pp: pp_sum / len,
+            avg_acc: acc_sum / len,
+            avg_stars: stars_sum / len,
+            avg_score: score_sum / len,
+            count_first: scores.len() as u32,
+            count_loved,
+            count_ranked,
+            difference: gains.len() as i32 - losses.len() as i32,
+            count_mods: Some(count_mods),
+            count_first_history,
+            count_sr_spread,
+            oldest_first,
+        };
+
+        Ok(Some(player))
+    }
+
+    async fn local_country_players(self, country_code: &str) -> Result<Vec<SnipeCountryPlayer>> {
+        let scores = self
+            .psql
+            .select_snipe_country_scores(&country_code.to_ascii_uppercase())
+            .await
+            .wrap_err("Failed to get snipe country scores")?;
+
+        let mut users = HashMap::<_, (Box<str>, Vec<f32>, f32), IntHasher>::default();
+
+        for score in scores {
+            let (_, pps, stars) = users
+                .entry(score.user_id)
+                .or_insert_with(|| (score.username, Vec::new(), 0.0));
+
+            pps.push(score.pp.unwrap_or(0.0));
+            *stars += score.stars.unwrap_or(0.0);
+        }
+
+        let players = users
+            .into_iter()
+            .map(|(user_id, (username, mut pps, stars))| {
+                pps.sort_unstable_by(|a, b| b.partial_cmp(a).unwrap_or(Ordering::Equal));
+
+                let len = pps.len() as f32;
+                let avg_pp = pps.iter().sum::<f32>() / len;
+
+                let pp = pps
+                    .iter()
+                    .zip(0..)
+                    .map(|(pp, i)| pp * 0.95_f32.powi(i))
+                    .sum();
+
+                SnipeCountryPlayer {
+                    username: username.as_ref().into(),
+                    user_id,
+                    avg_pp,
+                    avg_sr: stars / len,
+                    pp,
+                    count_first: pps.len() as u32,
+                }
+            })
+            .collect();
+
+        Ok(players)
+    }
+
+    async fn local_country_statistics(self, country_code: &str) -> Result<SnipeCountryStatistics> {
+        let country_code = country_code.to_ascii_uppercase();
+        let psql = self.psql;
+
+        let stats_fut = psql.select_snipe_country_statistics(&country_code);
+        let differences_fut = psql.select_snipe_country_differences(&country_code);
+
+        let (stats, differences) = tokio::try_join!(stats_fut, differences_fut)
+            .wrap_err("Failed to get snipe country statistics")?;
+
+        let top_gain = differences
+            .iter()
+            .filter(|diff| diff.difference > 0)
+            .max_by_key(|diff| diff.difference);
+
+        let top_loss = differences
+            .iter()
+            .filter(|diff| diff.difference < 0)
+            .min_by_key(|diff| diff.difference);
+
+        let to_top_difference = |diff: &DbSnipeDifference| SnipeTopNationalDifference {
+            top_national: Some(diff.count as usize),
+            username: diff.username.as_ref().into(),
+            difference: diff.difference,
+        };
+
+        Ok(SnipeCountryStatistics {
+            total_maps: (stats.total_maps + stats.unplayed_maps) as usize,
+            unplayed_maps: stats.unplayed_maps as usize,
+            top_gain: top_gain.map(to_top_difference),
+            top_loss: top_loss.map(to_top_difference),
+        })
+    }
+
+    async fn local_national_snipes(
+        self,
+        country_code: &str,
+        user_id: u32,
+        sniper: bool,
+        from: OffsetDateTime,
+        until: OffsetDateTime,
+    ) -> Result<Vec<SnipeRecent>> {
+        let country_code = country_code.to_ascii_uppercase();
+
+        let changes = self
+            .psql
+            .select_snipe_changes(&country_code, user_id, sniper, from, until)
+            .await
+            .wrap_err("Failed to get snipe changes")?;
+
+        Ok(changes.into_iter().map(snipe_recent).collect())
+    }
+
+    async fn local_filtered_firsts(self, params: &SnipeScoreParams) -> Result<Vec<DbSnipeScore>> {
+        let mut scores = self
+            .psql
+            .select_snipe_player_scores(&params.country.to_ascii_uppercase(), params.user_id)
+            .await
+            .wrap_err("Failed to get snipe player scores")?;
+
+        match params.mods {
+            Some(ModSelection::Include(ref mods)) if mods.is_empty() => {
+                scores.retain(|score| score.mods == 0)
+            }
+            Some(ModSelection::Include(ref mods)) => {
+                let bits = mods.bits();
+                scores.retain(|score| score.mods & bits == bits)
+            }
+            Some(ModSelection::Exact(ref mods)) => {
+                let bits = mods.bits();
+                scores.retain(|score| score.mods == bits)
+            }
+            Some(ModSelection::Exclude(ref mods)) => {
+                let bits = mods.bits();
+                scores.retain(|score| score.mods & bits == 0)
+            }
+            None => {}
+        }
+
+        Ok(scores)
+    }
+
+    async fn local_national_firsts(self, params: &SnipeScoreParams) -> Result<Vec<SnipeScore>> {
+        let mut scores = self.local_filtered_firsts(params).await?;
+
+        let username = self
+            .psql
+            .select_osu_name_by_osu_id(params.user_id)
+            .await
+            .wrap_err("Failed to get username")?
+            .unwrap_or_else(|| Username::from(params.user_id.to_string().as_str()));
+
+        fn cmp_f32(a: f32, b: f32) -> Ordering {
+            a.partial_cmp(&b).unwrap_or(Ordering::Equal)
+        }
+
+        scores.sort_unstable_by(|a, b| {
+            let ordering = match params.order {
+                SnipePlayerListOrder::Acc => cmp_f32(
+                    accuracy(a.count_300, a.count_100, a.count_50, a.count_miss),
+                    accuracy(b.count_300, b.count_100, b.count_50, b.count_miss),
+                ),
+                SnipePlayerListOrder::Date => a.ended_at.cmp(&b.ended_at),
+                SnipePlayerListOrder::Misses => a.count_miss.cmp(&b.count_miss),
+                SnipePlayerListOrder::Pp => cmp_f32(a.pp.unwrap_or(0.0), b.pp.unwrap_or(0.0)),
+                SnipePlayerListOrder::Stars => {
+                    cmp_f32(a.stars.unwrap_or(0.0), b.stars.unwrap_or(0.0))
+                }
+            };
+
+            if params.descending {
+                ordering.reverse()
+            } else {
+                ordering
+            }
+        });
+
+        let skip = (params.page.max(1) as usize - 1) * FIRSTS_PER_PAGE;
+
+        let scores = scores
+            .into_iter()
+            .skip(skip)
+            .take(FIRSTS_PER_PAGE)
+            .map(|score| snipe_score(score, &username, params))
+            .collect();
+
+        Ok(scores)
+    }
+}
+
+fn accuracy(n300: u32, n100: u32, n50: u32, n_miss: u32) -> f32 {
+    let total = n300 + n100 + n50 + n_miss;
+
+    if total == 0 {
+        return 0.0;
+    }
+
+    let numerator = 6 * n300 + 2 * n100 + n50;
+
+    100.0 * numerator as f32 / (6 * total) as f32
+}
+
+fn snipe_score(score: DbSnipeScore, username: &Username, params: &SnipeScoreParams) -> SnipeScore {
+    SnipeScore {
+        uid: score.score_id,
+        user_id: score.user_id,
+        username: username.clone(),
+        country: params.country.clone(),
+        score: score.score,
+        pp: score.pp,
+        stars: score.stars.unwrap_or(0.0),
+        accuracy: accuracy(
+            score.count_300,
+            score.count_100,
+            score.count_50,
+            score.count_miss,
+        ),
+        count_300: Some(score.count_300),
+        count_100: Some(score.count_100),
+        count_50: Some(score.count_50),
+        count_miss: Some(score.count_miss),
+        date_set: Some(score.ended_at),
+        mods: GameModsIntermode::from_bits(score.mods).with_mode(GameMode::Osu),
+        max_combo: Some(score.max_combo),
+        ar: score.ar,
+        hp: score.hp,
+        cs: score.cs,
+        od: score.od,
+        bpm: score.bpm,
+        is_global: false,
+        map: SnipeBeatmap {
+            map_id: score.map_id,
+            mapset_id: score.mapset_id,
+            artist: score.artist,
+            title: score.title,
+            version: score.version,
+            count_circles: score.count_circles,
+            count_sliders: score.count_sliders,
+            count_spinners: score.count_spinners,
+            ranked_status: score.status,
+            ar: score.ar,
+            cs: score.cs,
+            od: score.od,
+            hp: score.hp,
+            bpm: score.bpm,
+            max_combo: score.map_max_combo.unwrap_or(0),
+        },
+    }
+}
+
+fn snipe_recent(change: DbSnipeChange) -> SnipeRecent {
+    SnipeRecent {
+        uid: change.score_id,
+        map_id: change.map_id,
+        user_id: change.sniper_id,
+        country: change.country_code.as_ref().into(),
+        pp: change.pp,
+        stars: change.stars,
+        accuracy: accuracy(
+            change.count_300,
+            change.count_100,
+            change.count_50,
+            change.count_miss,
+        ),
+        count_300: Some(change.count_300),
+        count_100: Some(change.count_100),
+        count_50: Some(change.count_50),
+        count_miss: Some(change.count_miss),
+        date: Some(change.changed_at),
+        mods: GameModsIntermode::from_bits(change.mods).with_mode(GameMode::Osu),
+        max_combo: Some(change.max_combo),
+        ar: change.ar,
+        cs: change.cs,
+        od: change.od,
+        hp: change.hp,
+        bpm: change.bpm,
+        artist: change.artist,
+        title: change.title,
+        version: change.version,
+        sniper: change.sniper_name,
+        sniper_id: change.sniper_id,
+        sniped: change.sniped_name,
+        sniped_id: change.sniped_id,
+    }
+}
@@ -0,0 +1,47 @@
+use bathbot_psql::{model::songs::DbGuildSong, Database};
+use eyre::{Result, WrapErr};
+use twilight_model::id::{marker::GuildMarker, Id};
+
+#[derive(Copy, Clone)]
+pub struct SongManager<'d> {
+    psql: &'d Database,
+}
+
+impl<'d> SongManager<'d> {
+    pub fn new(psql: &'d Database) -> Self {
+        Self { psql }
+    }
+
+    pub async fn guild_songs(self, guild: Id<GuildMarker>) -> Result<Vec<DbGuildSong>> {
+        self.psql
+            .select_guild_songs(guild)
+            .await
+            .wrap_err("Failed to get guild songs")
+    }
+
+    pub async fn guild_song(
+        self,
+        guild: Id<GuildMarker>,
+        name: &str,
+    ) -> Result<Option<DbGuildSong>> {
+        self.psql
+            .select_guild_song(guild, name)
+            .await
+            .wrap_err("Failed to get guild song")
+    }
+
+    pub async fn store(self, guild: Id<GuildMarker>, name: &str, lyrics: &str) -> Result<()> {
+        self.psql
+            .upsert_guild_song(guild, name, lyrics)
+            .await
+            .wrap_err("Failed to upsert guild song")
+    }
+
+    /// Returns `false` if there was no song with the given name.
+    pub async fn remove(self, guild: Id<GuildMarker>, name: &str) -> Result<bool> {
+        self.psql
+            .delete_guild_song(guild, name)
+            .await
+            .wrap_err("Failed to delete guild song")
+    }
+}
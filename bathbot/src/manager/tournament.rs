@@ -0,0 +1,161 @@
+use bathbot_psql::{
+    model::tournament::{DbTournament, DbTournamentMatch, DbTournamentPlayer},
+    Database,
+};
+use eyre::{Result, WrapErr};
+use time::OffsetDateTime;
+use twilight_model::id::{
+    marker::{ChannelMarker, GuildMarker},
+    Id,
+};
+
+#[derive(Copy, Clone)]
+pub struct TournamentManager<'d> {
+    psql: &'d Database,
+}
+
+impl<'d> TournamentManager<'d> {
+    pub fn new(psql: &'d Database) -> Self {
+        Self { psql }
+    }
+
+    pub async fn tournaments(self, guild: Id<GuildMarker>) -> Result<Vec<DbTournament>> {
+        self.psql
+            .select_tournaments(guild)
+            .await
+            .wrap_err("Failed to get tournaments")
+    }
+
+    pub async fn tournament(
+        self,
+        guild: Id<GuildMarker>,
+        name: &str,
+    ) -> Result<Option<DbTournament>> {
+        self.psql
+            .select_tournament(guild, name)
+            .await
+            .wrap_err("Failed to get tournament")
+    }
+
+    pub async fn tournament_by_id(self, tournament_id: i32) -> Result<Option<DbTournament>> {
+        self.psql
+            .select_tournament_by_id(tournament_id)
+            .await
+            .wrap_err("Failed to get tournament by id")
+    }
+
+    /// Returns `false` if the guild already has a tournament with that name.
+    pub async fn create(
+        self,
+        guild: Id<GuildMarker>,
+        channel: Id<ChannelMarker>,
+        name: &str,
+    ) -> Result<bool> {
+        self.psql
+            .insert_tournament(guild, channel, name)
+            .await
+            .wrap_err("Failed to insert tournament")
+    }
+
+    pub async fn delete(self, tournament_id: i32) -> Result<()> {
+        self.psql
+            .delete_tournament(tournament_id)
+            .await
+            .wrap_err("Failed to delete tournament")
+    }
+
+    pub async fn players(self, tournament_id: i32) -> Result<Vec<DbTournamentPlayer>> {
+        self.psql
+            .select_tournament_players(tournament_id)
+            .await
+            .wrap_err("Failed to get tournament players")
+    }
+
+    pub async fn add_team(
+        self,
+        tournament_id: i32,
+        team: &str,
+        players: &[(u32, &str)],
+    ) -> Result<()> {
+        self.psql
+            .upsert_tournament_team(tournament_id, team, players)
+            .await
+            .wrap_err("Failed to upsert tournament team")
+    }
+
+    /// Returns the amount of removed players.
+    pub async fn remove_team(self, tournament_id: i32, team: &str) -> Result<u64> {
+        self.psql
+            .delete_tournament_team(tournament_id, team)
+            .await
+            .wrap_err("Failed to delete tournament team")
+    }
+
+    pub async fn matches(self, tournament_id: i32) -> Result<Vec<DbTournamentMatch>> {
+        self.psql
+            .select_tournament_matches(tournament_id)
+            .await
+            .wrap_err("Failed to get tournament matches")
+    }
+
+    pub async fn get_match(
+        self,
+        guild: Id<GuildMarker>,
+        match_id: i32,
+    ) -> Result<Option<DbTournamentMatch>> {
+        self.psql
+            .select_tournament_match(guild, match_id)
+            .await
+            .wrap_err("Failed to get tournament match")
+    }
+
+    pub async fn pending_matches(self) -> Result<Vec<DbTournamentMatch>> {
+        self.psql
+            .select_pending_tournament_matches()
+            .await
+            .wrap_err("Failed to get pending tournament matches")
+    }
+
+    /// Returns the id of the new match.
+    pub async fn add_match(
+        self,
+        tournament_id: i32,
+        round: &str,
+        team_a: &str,
+        team_b: &str,
+        scheduled_at: OffsetDateTime,
+    ) -> Result<i32> {
+        self.psql
+            .insert_tournament_match(tournament_id, round, team_a, team_b, scheduled_at)
+            .await
+            .wrap_err("Failed to insert tournament match")
+    }
+
+    pub async fn set_reminder(self, match_id: i32, reminder_id: Option<i32>) -> Result<()> {
+        self.psql
+            .update_tournament_match_reminder(match_id, reminder_id)
+            .await
+            .wrap_err("Failed to update tournament match reminder")
+    }
+
+    pub async fn link_mp(self, match_id: i32, mp_id: u32, warmups: u8) -> Result<()> {
+        self.psql
+            .update_tournament_match_mp(match_id, mp_id, warmups)
+            .await
+            .wrap_err("Failed to link multiplayer match")
+    }
+
+    pub async fn set_result(self, match_id: i32, score_a: u8, score_b: u8) -> Result<()> {
+        self.psql
+            .update_tournament_match_result(match_id, score_a, score_b)
+            .await
+            .wrap_err("Failed to update tournament match result")
+    }
+
+    pub async fn remove_match(self, match_id: i32) -> Result<()> {
+        self.psql
+            .delete_tournament_match(match_id)
+            .await
+            .wrap_err("Failed to delete tournament match")
+    }
+}
@@ -1,4 +1,4 @@
-use bathbot_psql::Database;
+use bathbot_psql::{model::twitch::DbTrackedStream, Database};
 use bathbot_util::CowUtils;
 use eyre::{Result, WrapErr};
 use rosu_v2::request::UserId;
@@ -54,4 +54,35 @@ impl<'d> TwitchManager<'d> {
             .await
             .wrap_err("failed to remove tracked twitch streams")
     }
+
+    /// Notification settings of all channels that track the twitch user
+    pub async fn notif_settings(self, twitch_id: u64) -> Result<Vec<DbTrackedStream>> {
+        self.psql
+            .select_tracked_twitch_stream_settings(twitch_id)
+            .await
+            .wrap_err("failed to get tracked twitch stream settings")
+    }
+
+    /// Returns `None` if the twitch user is not tracked in the channel
+    pub async fn channel_notif_settings(
+        self,
+        channel: Id<ChannelMarker>,
+        twitch_id: u64,
+    ) -> Result<Option<DbTrackedStream>> {
+        self.psql
+            .select_tracked_twitch_stream(channel, twitch_id)
+            .await
+            .wrap_err("failed to get tracked twitch stream")
+    }
+
+    pub async fn update_notif_settings(
+        self,
+        twitch_id: u64,
+        settings: &DbTrackedStream,
+    ) -> Result<()> {
+        self.psql
+            .update_tracked_twitch_stream(twitch_id, settings)
+            .await
+            .wrap_err("failed to update tracked twitch stream")
+    }
 }
@@ -1,10 +1,11 @@
 use bathbot_psql::{
-    model::configs::{OsuUserId, OsuUsername, ScoreSize, SkinEntry, UserConfig},
+    model::configs::{Language, OsuUserId, OsuUsername, ScoreSize, SkinEntry, UserConfig},
     Database,
 };
 use bathbot_util::CowUtils;
 use eyre::{Result, WrapErr};
 use rosu_v2::prelude::{GameMode, Username};
+use time::UtcOffset;
 use twilight_model::id::{marker::UserMarker, Id};
 
 #[derive(Copy, Clone)]
@@ -87,6 +88,20 @@ impl<'d> UserConfigManager<'d> {
             .wrap_err("failed to get user score size from DB")
     }
 
+    pub async fn language(self, user_id: Id<UserMarker>) -> Result<Option<Language>> {
+        self.psql
+            .select_user_language(user_id)
+            .await
+            .wrap_err("failed to get user language from DB")
+    }
+
+    pub async fn timezone(self, user_id: Id<UserMarker>) -> Result<Option<UtcOffset>> {
+        self.psql
+            .select_user_timezone(user_id)
+            .await
+            .wrap_err("failed to get user timezone from DB")
+    }
+
     pub async fn skin(self, user_id: Id<UserMarker>) -> Result<Option<String>> {
         self.psql
             .select_skin_url(user_id)
@@ -22,6 +22,7 @@ pub async fn send_match_messages(
     ctx: &Context,
     channel: Id<ChannelMarker>,
     embeds: &[MatchLiveEmbed],
+    mappool: Option<&MatchMappool>,
 ) -> Result<Id<MessageMarker>> {
     let mut iter = embeds.iter();
 
@@ -29,7 +30,7 @@ pub async fn send_match_messages(
     let last = iter
         .next_back()
         .expect("no embed on fresh match")
-        .as_embed();
+        .as_embed(mappool);
 
     let mut last_msg_fut = ctx
         .http
@@ -42,7 +43,7 @@ pub async fn send_match_messages(
         interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
 
         for embed in iter {
-            let embed = embed.as_embed();
+            let embed = embed.as_embed(mappool);
             interval.tick().await;
 
             match ctx.http.create_message(channel).embeds(&[embed]) {
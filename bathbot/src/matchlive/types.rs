@@ -1,6 +1,6 @@
 use bathbot_util::IntHasher;
 use hashbrown::HashMap;
-use rosu_v2::prelude::OsuMatch;
+use rosu_v2::prelude::{MatchEvent, OsuMatch};
 use smallvec::SmallVec;
 use tokio::sync::Mutex;
 use twilight_model::id::{
@@ -80,6 +80,8 @@ pub struct TrackedMatch {
     pub osu_match: OsuMatch,
     /// All embeds of the match
     pub embeds: Vec<MatchLiveEmbed>,
+    /// Mappool that was linked to the match
+    pub mappool: Option<MatchMappool>,
 }
 
 impl TrackedMatch {
@@ -87,6 +89,119 @@ impl TrackedMatch {
         Self {
             osu_match,
             embeds: embeds.into_vec(),
+            mappool: None,
         }
     }
 }
+
+#[derive(Clone)]
+pub struct MatchMappool {
+    pub name: Box<str>,
+    /// Slot names alongside their map id
+    pub slots: Vec<(Box<str>, u32)>,
+    /// Picks and bans in the order they happened
+    pub log: Vec<PickBan>,
+    /// Game ids of finished games since the mappool was linked alongside
+    /// their map id
+    pub played: Vec<(u64, u32)>,
+}
+
+impl MatchMappool {
+    pub fn new(name: Box<str>, slots: Vec<(Box<str>, u32)>) -> Self {
+        Self {
+            name,
+            slots,
+            log: Vec::new(),
+            played: Vec::new(),
+        }
+    }
+
+    /// Returns the slot of the map if it's part of the mappool
+    pub fn slot(&self, map_id: u32) -> Option<&str> {
+        self.slots
+            .iter()
+            .find(|(_, id)| *id == map_id)
+            .map(|(slot, _)| slot.as_ref())
+    }
+
+    pub fn add(&mut self, kind: PickBanKind, slot: &str, team: Option<Box<str>>) -> PickBanResult {
+        let Some((slot, _)) = self
+            .slots
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(slot))
+        else {
+            return PickBanResult::UnknownSlot;
+        };
+
+        if self.log.iter().any(|entry| entry.slot == *slot) {
+            return PickBanResult::Duplicate;
+        }
+
+        self.log.push(PickBan {
+            kind,
+            slot: slot.clone(),
+            team,
+        });
+
+        PickBanResult::Added
+    }
+
+    /// Keeps track of finished games of mappool maps. Picks that have not
+    /// been logged beforehand are logged without a team.
+    pub fn update(&mut self, osu_match: &OsuMatch) {
+        for event in osu_match.events.iter() {
+            let MatchEvent::Game { game, .. } = event else {
+                continue;
+            };
+
+            if game.end_time.is_none()
+                || self
+                    .played
+                    .iter()
+                    .any(|(game_id, _)| *game_id == game.game_id)
+            {
+                continue;
+            }
+
+            let Some(slot) = self.slot(game.map_id).map(Box::from) else {
+                continue;
+            };
+
+            self.played.push((game.game_id, game.map_id));
+
+            if self.log.iter().all(|entry| entry.slot != slot) {
+                self.log.push(PickBan {
+                    kind: PickBanKind::Pick,
+                    slot,
+                    team: None,
+                });
+            }
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct PickBan {
+    pub kind: PickBanKind,
+    pub slot: Box<str>,
+    pub team: Option<Box<str>>,
+}
+
+#[derive(Copy, Clone)]
+pub enum PickBanKind {
+    Pick,
+    Ban,
+}
+
+pub enum PickBanResult {
+    /// The pick or ban was logged
+    Added,
+    /// The match is not tracked in the channel
+    NotTracked,
+    /// No mappool was linked to the match
+    NoMappool,
+    /// The mappool has no such slot
+    UnknownSlot,
+    /// The slot was already picked or banned
+    Duplicate,
+}
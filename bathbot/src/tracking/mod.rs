@@ -8,7 +8,15 @@ pub use self::osu::{
 pub use self::twitch::online_streams::OnlineTwitchStreams;
 #[cfg(feature = "twitchtracking")]
 pub use self::twitch::twitch_loop::twitch_tracking_loop;
+pub use self::{
+    schedule::scheduled_message_loop,
+    snipe::snipe_notification_loop,
+    tournament::{match_result, tournament_result_loop, MatchTeams},
+};
 
 mod ordr;
 mod osu;
+mod schedule;
+mod snipe;
+mod tournament;
 mod twitch;
@@ -0,0 +1,123 @@
+use std::{sync::Arc, time::Duration};
+
+use bathbot_psql::model::schedule::DbScheduledMessage;
+use bathbot_util::constants::{
+    CANNOT_DM_USER, MISSING_ACCESS, MISSING_PERMISSIONS, UNKNOWN_CHANNEL,
+};
+use eyre::{Report, Result, WrapErr};
+use time::{Duration as TimeDuration, OffsetDateTime};
+use tokio::time::{self, MissedTickBehavior};
+use twilight_http::{
+    api_error::{ApiError, GeneralApiError},
+    error::ErrorType as TwilightErrorType,
+};
+use twilight_model::{
+    channel::message::{AllowedMentions, MentionType},
+    id::Id,
+};
+
+use crate::Context;
+
+/// Seconds between checks for due scheduled messages
+const INTERVAL: u64 = 15;
+
+/// Messages that could not be delivered for this long are discarded
+const MAX_DELAY: TimeDuration = TimeDuration::hours(1);
+
+#[cold]
+pub async fn scheduled_message_loop(ctx: Arc<Context>) {
+    let mut interval = time::interval(Duration::from_secs(INTERVAL));
+    interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+    interval.tick().await;
+
+    loop {
+        interval.tick().await;
+
+        let now = OffsetDateTime::now_utc();
+
+        let due = match ctx.schedule().due(now).await {
+            Ok(due) => due,
+            Err(err) => {
+                warn!(?err, "Failed to get due scheduled messages");
+
+                continue;
+            }
+        };
+
+        for scheduled in due {
+            let schedule_id = scheduled.schedule_id;
+
+            if let Err(err) = send(&ctx, scheduled, now).await {
+                warn!(schedule_id, ?err, "Failed to process scheduled message");
+            }
+        }
+    }
+}
+
+async fn send(ctx: &Context, scheduled: DbScheduledMessage, now: OffsetDateTime) -> Result<()> {
+    let DbScheduledMessage {
+        schedule_id,
+        author_id,
+        guild_id: _,
+        channel_id,
+        content,
+        is_reminder,
+        trigger_at,
+    } = scheduled;
+
+    let channel = Id::new(channel_id as u64);
+
+    let (content, mentions) = if is_reminder {
+        // Only ping the user that set the reminder
+        let mentions = AllowedMentions {
+            users: vec![Id::new(author_id as u64)],
+            ..Default::default()
+        };
+
+        (format!("<@{author_id}> Reminder: {content}"), mentions)
+    } else {
+        // Announcements can only be scheduled by authorities so they may ping
+        let mentions = AllowedMentions {
+            parse: vec![
+                MentionType::Everyone,
+                MentionType::Roles,
+                MentionType::Users,
+            ],
+            ..Default::default()
+        };
+
+        (content, mentions)
+    };
+
+    let msg_fut = ctx
+        .http
+        .create_message(channel)
+        .allowed_mentions(Some(&mentions))
+        .content(&content)
+        .wrap_err("Invalid content for scheduled message")?;
+
+    if let Err(err) = msg_fut.await {
+        let permanent = matches!(
+            err.kind(),
+            TwilightErrorType::Response {
+                error: ApiError::General(GeneralApiError { code, .. }),
+                ..
+            } if matches!(
+                *code,
+                UNKNOWN_CHANNEL | MISSING_ACCESS | MISSING_PERMISSIONS | CANNOT_DM_USER
+            )
+        );
+
+        // Keep the message around for another attempt unless it can no
+        // longer be delivered
+        if !permanent && now - trigger_at < MAX_DELAY {
+            return Err(Report::new(err).wrap_err("Failed to send scheduled message"));
+        }
+
+        ctx.schedule().remove(schedule_id).await?;
+
+        return Err(Report::new(err).wrap_err("Discarded undeliverable scheduled message"));
+    }
+
+    ctx.schedule().remove(schedule_id).await
+}
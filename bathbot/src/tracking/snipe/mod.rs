@@ -0,0 +1,168 @@
+use std::{cmp::Reverse, slice, sync::Arc, time::Duration};
+
+use bathbot_psql::model::osu::DbSnipeNotification;
+use bathbot_util::constants::{CANNOT_DM_USER, UNKNOWN_CHANNEL};
+use eyre::{Report, Result, WrapErr};
+use rosu_v2::prelude::Username;
+use time::{Duration as TimeDuration, OffsetDateTime};
+use tokio::time::{self, MissedTickBehavior};
+use twilight_http::{
+    api_error::{ApiError, GeneralApiError},
+    error::ErrorType as TwilightErrorType,
+};
+use twilight_model::id::{
+    marker::{ChannelMarker, GuildMarker},
+    Id,
+};
+
+use crate::{
+    core::locale,
+    embeds::{EmbedData, SnipeNotificationEmbed},
+    Context,
+};
+
+/// Seconds between checks for lost national #1s
+const INTERVAL: u64 = 30 * 60;
+
+#[cold]
+pub async fn snipe_notification_loop(ctx: Arc<Context>) {
+    let mut interval = time::interval(Duration::from_secs(INTERVAL));
+    interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+    interval.tick().await;
+
+    loop {
+        interval.tick().await;
+
+        let notifications = match ctx.snipe().notifications().await {
+            Ok(notifications) => notifications,
+            Err(err) => {
+                warn!(?err, "Failed to get snipe notifications");
+
+                continue;
+            }
+        };
+
+        for notification in notifications {
+            let discord_id = notification.discord_id;
+
+            if let Err(err) = notify(&ctx, notification).await {
+                warn!(discord_id, ?err, "Failed to process snipe notification");
+            }
+        }
+    }
+}
+
+async fn notify(ctx: &Context, notification: DbSnipeNotification) -> Result<()> {
+    let DbSnipeNotification {
+        discord_id,
+        osu_id,
+        country_code,
+        channel_id,
+        last_notified,
+    } = notification;
+
+    let user_id = Id::new(discord_id as u64);
+    let osu_id = osu_id as u32;
+
+    // Snipe data is not necessarily updated immediately so lost
+    // national #1s are checked for the whole week
+    let now = OffsetDateTime::now_utc();
+    let week_ago = now - TimeDuration::weeks(1);
+
+    let mut scores = ctx
+        .snipe()
+        .national_snipes(&country_code, osu_id, false, week_ago, now)
+        .await
+        .wrap_err("Failed to get national snipes")?;
+
+    scores.retain(|score| score.date.is_some_and(|date| date > last_notified));
+
+    if scores.is_empty() {
+        return Ok(());
+    }
+
+    scores.sort_unstable_by_key(|score| Reverse(score.date));
+
+    let newest = scores[0].date.unwrap_or(now);
+
+    let username = match ctx.osu_user().name(osu_id).await {
+        Ok(Some(name)) => name,
+        Ok(None) => Username::from(osu_id.to_string().as_str()),
+        Err(err) => {
+            warn!(?err, "Failed to get username");
+
+            Username::from(osu_id.to_string().as_str())
+        }
+    };
+
+    let channel = match channel_id {
+        Some(channel_id) => Id::new(channel_id as u64),
+        None => match ctx.http.create_private_channel(user_id).await {
+            Ok(channel_res) => {
+                channel_res
+                    .model()
+                    .await
+                    .wrap_err("Failed to deserialize private channel")?
+                    .id
+            }
+            Err(err) => return Err(Report::new(err).wrap_err("Failed to create private channel")),
+        },
+    };
+
+    // Notifications in a channel are in the guild's language, DMs in the user's
+    let lang = match channel_id {
+        Some(_) => {
+            let guild = channel_guild(ctx, channel).await;
+
+            locale::language(ctx, None, guild, None).await
+        }
+        None => locale::language(ctx, Some(user_id), None, None).await,
+    };
+
+    let embed = SnipeNotificationEmbed::new(&username, osu_id, &scores, lang).build();
+
+    let msg_fut = ctx
+        .http
+        .create_message(channel)
+        .embeds(slice::from_ref(&embed))
+        .wrap_err("Invalid embed for snipe notification")?;
+
+    if let Err(err) = msg_fut.await {
+        if let TwilightErrorType::Response {
+            error: ApiError::General(GeneralApiError { code, .. }),
+            ..
+        } = err.kind()
+        {
+            if matches!(*code, UNKNOWN_CHANNEL | CANNOT_DM_USER) {
+                // The notification can no longer be delivered
+                ctx.snipe().remove_notification(user_id).await?;
+
+                return Ok(());
+            }
+        }
+
+        return Err(Report::new(err).wrap_err("Failed to send snipe notification"));
+    }
+
+    ctx.snipe().update_notification_date(user_id, newest).await
+}
+
+async fn channel_guild(ctx: &Context, channel: Id<ChannelMarker>) -> Option<Id<GuildMarker>> {
+    let channel_res = match ctx.http.channel(channel).await {
+        Ok(channel_res) => channel_res,
+        Err(err) => {
+            warn!(?err, "Failed to get notification channel");
+
+            return None;
+        }
+    };
+
+    match channel_res.model().await {
+        Ok(channel) => channel.guild_id,
+        Err(err) => {
+            warn!(?err, "Failed to deserialize notification channel");
+
+            None
+        }
+    }
+}
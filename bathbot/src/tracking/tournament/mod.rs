@@ -0,0 +1,189 @@
+use std::{slice, sync::Arc, time::Duration};
+
+use bathbot_psql::model::tournament::{DbTournamentMatch, DbTournamentPlayer};
+use bathbot_util::{constants::OSU_BASE, EmbedBuilder};
+use eyre::{Report, Result, WrapErr};
+use rosu_v2::prelude::{Osu, OsuResult};
+use tokio::time::{self, MissedTickBehavior};
+use twilight_model::id::Id;
+
+use crate::{commands::osu::retrieve_previous, Context};
+
+/// Seconds between checks for finished tournament matches
+const INTERVAL: u64 = 5 * 60;
+
+#[cold]
+pub async fn tournament_result_loop(ctx: Arc<Context>) {
+    let mut interval = time::interval(Duration::from_secs(INTERVAL));
+    interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+    interval.tick().await;
+
+    loop {
+        interval.tick().await;
+
+        let pending = match ctx.tournament().pending_matches().await {
+            Ok(pending) => pending,
+            Err(err) => {
+                warn!(?err, "Failed to get pending tournament matches");
+
+                continue;
+            }
+        };
+
+        for tournament_match in pending {
+            let match_id = tournament_match.match_id;
+
+            if let Err(err) = process_match(&ctx, tournament_match).await {
+                warn!(match_id, ?err, "Failed to process tournament match");
+            }
+        }
+    }
+}
+
+async fn process_match(ctx: &Context, tournament_match: DbTournamentMatch) -> Result<()> {
+    let Some(mp_id) = tournament_match.mp_id else {
+        return Ok(());
+    };
+
+    let players = ctx
+        .tournament()
+        .players(tournament_match.tournament_id)
+        .await?;
+
+    let teams = MatchTeams::new(&tournament_match, &players);
+    let warmups = tournament_match.warmups as usize;
+
+    let (score_a, score_b) = match match_result(ctx.osu(), mp_id as u32, warmups, &teams).await {
+        Ok(Some(score)) => score,
+        Ok(None) => return Ok(()),
+        Err(err) => return Err(Report::new(err).wrap_err("Failed to get match result")),
+    };
+
+    ctx.tournament()
+        .set_result(tournament_match.match_id, score_a, score_b)
+        .await?;
+
+    let Some(tournament) = ctx
+        .tournament()
+        .tournament_by_id(tournament_match.tournament_id)
+        .await?
+    else {
+        return Ok(());
+    };
+
+    let DbTournamentMatch {
+        round,
+        team_a,
+        team_b,
+        ..
+    } = tournament_match;
+
+    let description = format!(
+        "**{team_a}** {score_a} - {score_b} **{team_b}**\n\
+        [Match link]({OSU_BASE}community/matches/{mp_id})"
+    );
+
+    let embed = EmbedBuilder::new()
+        .description(description)
+        .title(format!("{} • {round}", tournament.name))
+        .build();
+
+    ctx.http
+        .create_message(Id::new(tournament.channel_id as u64))
+        .embeds(slice::from_ref(&embed))
+        .wrap_err("Invalid embed for tournament result")?
+        .await
+        .wrap_err("Failed to send tournament result")?;
+
+    Ok(())
+}
+
+/// osu! user ids of both teams of a tournament match
+pub struct MatchTeams {
+    pub team_a: Vec<u32>,
+    pub team_b: Vec<u32>,
+}
+
+impl MatchTeams {
+    pub fn new(tournament_match: &DbTournamentMatch, players: &[DbTournamentPlayer]) -> Self {
+        let team = |name: &str| {
+            players
+                .iter()
+                .filter(|player| player.team == name)
+                .map(|player| player.user_id as u32)
+                .collect()
+        };
+
+        Self {
+            team_a: team(&tournament_match.team_a),
+            team_b: team(&tournament_match.team_b),
+        }
+    }
+}
+
+/// Returns the amount of won maps for both teams or `None` if the match is
+/// not over yet.
+pub async fn match_result(
+    osu: &Osu,
+    mp_id: u32,
+    warmups: usize,
+    teams: &MatchTeams,
+) -> OsuResult<Option<(u8, u8)>> {
+    let mut osu_match = osu.osu_match(mp_id).await?;
+
+    if osu_match.end_time.is_none() {
+        return Ok(None);
+    }
+
+    retrieve_previous(&mut osu_match, osu).await?;
+
+    let games = osu_match
+        .drain_games()
+        .filter(|game| game.end_time.is_some())
+        .skip(warmups)
+        .map(|game| {
+            let total = |team: &[u32]| {
+                game.scores
+                    .iter()
+                    .filter(|score| team.contains(&score.user_id))
+                    .map(|score| score.score as u64)
+                    .sum::<u64>()
+            };
+
+            (total(&teams.team_a), total(&teams.team_b))
+        });
+
+    Ok(Some(count_points(games)))
+}
+
+/// Each game with a higher total score is a point for that team. Games
+/// without scores of either team, e.g. aborted ones, are ignored.
+fn count_points(games: impl IntoIterator<Item = (u64, u64)>) -> (u8, u8) {
+    games
+        .into_iter()
+        .fold((0, 0), |(a, b), (total_a, total_b)| {
+            if total_a > total_b {
+                (a.saturating_add(1), b)
+            } else if total_b > total_a {
+                (a, b.saturating_add(1))
+            } else {
+                (a, b)
+            }
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn points() {
+        let games = [(900_000, 800_000), (0, 0), (500_000, 600_000), (1, 0)];
+        assert_eq!(count_points(games), (2, 1));
+
+        let games = [(700_000, 700_000), (0, 400_000)];
+        assert_eq!(count_points(games), (0, 1));
+
+        assert_eq!(count_points([]), (0, 0));
+    }
+}
@@ -1,182 +1,467 @@
-use std::{fmt::Write, sync::Arc};
+use std::{fmt::Write, slice, sync::Arc};
 
-use bathbot_model::TwitchUser;
+use bathbot_model::{TwitchStream, TwitchStreamEvent, TwitchUser};
+use bathbot_psql::model::twitch::DbTrackedStream;
 use bathbot_util::{
     constants::{TWITCH_BASE, UNKNOWN_CHANNEL},
     AuthorBuilder, EmbedBuilder, IntHasher,
 };
-use hashbrown::{HashMap, HashSet};
+use futures::future;
+use hashbrown::HashMap;
 use rand::Rng;
-use tokio::time::{interval, Duration};
+use time::OffsetDateTime;
+use tokio::{
+    sync::mpsc::UnboundedReceiver,
+    time::{interval, sleep, Duration},
+};
 use twilight_http::{
     api_error::{ApiError, GeneralApiError},
     error::ErrorType,
 };
-use twilight_model::id::{marker::ChannelMarker, Id};
+use twilight_model::{
+    channel::message::{AllowedMentions, Embed},
+    id::{
+        marker::{ChannelMarker, MessageMarker, RoleMarker},
+        Id,
+    },
+};
 
 use crate::Context;
 
+/// Attempts to retrieve a stream after twitch notified that it went online
+const ONLINE_EVENT_ATTEMPTS: usize = 3;
+
 #[cold]
 pub async fn twitch_tracking_loop(ctx: Arc<Context>) {
-    let mut online_streams = HashSet::with_hasher(IntHasher);
+    let mut online_streams: OnlineStreams = HashMap::default();
     let mut interval = interval(Duration::from_secs(10 * 60));
     interval.tick().await;
 
+    // Stream events are received through the server; polling is kept as
+    // fallback in case events are missed or the server is not reachable.
+    #[cfg(feature = "server")]
+    let mut events = ctx.twitch_events.lock().unwrap().take();
+
+    #[cfg(not(feature = "server"))]
+    let mut events: Option<UnboundedReceiver<TwitchStreamEvent>> = None;
+
+    #[cfg(feature = "server")]
+    if let Err(err) = ctx.sync_twitch_subscriptions().await {
+        warn!(?err, "Failed to sync twitch subscriptions");
+    }
+
     loop {
-        interval.tick().await;
+        tokio::select! {
+            _ = interval.tick() => poll_streams(&ctx, &mut online_streams).await,
+            Some(event) = recv_event(&mut events) => {
+                handle_event(&ctx, &mut online_streams, event).await
+            }
+        }
+    }
+}
 
-        // Get data about what needs to be tracked for which channel
-        let user_ids = ctx.tracked_users();
+async fn recv_event(
+    events: &mut Option<UnboundedReceiver<TwitchStreamEvent>>,
+) -> Option<TwitchStreamEvent> {
+    match events {
+        Some(events) => events.recv().await,
+        None => future::pending().await,
+    }
+}
 
-        // Get stream data about all streams that need to be tracked
-        let mut streams = match ctx.client().get_twitch_streams(&user_ids).await {
-            Ok(streams) => streams,
-            Err(err) => {
-                warn!(?err, "Failed to retrieve streams");
+async fn poll_streams(ctx: &Context, online_streams: &mut OnlineStreams) {
+    // Get data about what needs to be tracked for which channel
+    let user_ids = ctx.tracked_users();
 
-                continue;
+    // Get stream data about all streams that need to be tracked
+    let mut streams = match ctx.client().get_twitch_streams(&user_ids).await {
+        Ok(streams) => streams,
+        Err(err) => {
+            warn!(?err, "Failed to retrieve streams");
+
+            return;
+        }
+    };
+
+    // Filter streams whether they're live
+    {
+        let guard = ctx.online_twitch_streams().guard();
+
+        streams.retain(|stream| {
+            if stream.live {
+                ctx.online_twitch_streams().set_online(stream, &guard);
+            } else {
+                ctx.online_twitch_streams().set_offline(stream, &guard);
+            }
+
+            stream.live
+        });
+    }
+
+    // Streams that were live last time but are no longer
+    let went_offline: Vec<_> = online_streams
+        .keys()
+        .copied()
+        .filter(|&user| streams.iter().all(|stream| stream.user_id != user))
+        .collect();
+
+    for user in went_offline {
+        ctx.online_twitch_streams().set_offline_by_user(user);
+
+        if let Some(stream) = online_streams.remove(&user) {
+            notify_offline(ctx, user, stream).await;
+        }
+    }
+
+    process_new_streams(ctx, online_streams, streams).await;
+}
+
+async fn handle_event(ctx: &Context, online_streams: &mut OnlineStreams, event: TwitchStreamEvent) {
+    match event {
+        TwitchStreamEvent::Online { user_id } => {
+            if online_streams.contains_key(&user_id) {
+                return;
             }
-        };
 
-        // Filter streams whether they're live
-        {
-            let guard = ctx.online_twitch_streams().guard();
+            // The stream might not be available right away
+            for attempt in 1..=ONLINE_EVENT_ATTEMPTS {
+                match ctx.client().get_twitch_stream(user_id).await {
+                    Ok(Some(stream)) if stream.live => {
+                        {
+                            let guard = ctx.online_twitch_streams().guard();
+                            ctx.online_twitch_streams().set_online(&stream, &guard);
+                        }
+
+                        process_new_streams(ctx, online_streams, vec![stream]).await;
+
+                        return;
+                    }
+                    Ok(_) if attempt < ONLINE_EVENT_ATTEMPTS => {
+                        sleep(Duration::from_secs(10)).await;
+                    }
+                    Ok(_) => debug!(user_id, "Stream of online event is not available"),
+                    Err(err) => {
+                        warn!(?err, "Failed to retrieve stream");
 
-            streams.retain(|stream| {
-                if stream.live {
-                    ctx.online_twitch_streams().set_online(stream, &guard);
-                } else {
-                    ctx.online_twitch_streams().set_offline(stream, &guard);
+                        return;
+                    }
                 }
+            }
+        }
+        TwitchStreamEvent::Offline { user_id } => {
+            ctx.online_twitch_streams().set_offline_by_user(user_id);
 
-                stream.live
-            });
+            if let Some(stream) = online_streams.remove(&user_id) {
+                notify_offline(ctx, user_id, stream).await;
+            }
+        }
+        #[cfg(feature = "server")]
+        TwitchStreamEvent::Revoked { user_id } => {
+            let still_tracked = ctx
+                .tracked_channels_for(user_id)
+                .is_some_and(|channels| !channels.is_empty());
+
+            if !still_tracked {
+                return;
+            }
+
+            if let Err(err) = ctx.subscribe_twitch_events(user_id).await {
+                warn!(user_id, ?err, "Failed to resubscribe to twitch events");
+            }
         }
+        #[cfg(not(feature = "server"))]
+        TwitchStreamEvent::Revoked { .. } => {}
+    }
+}
 
-        let now_online: HashSet<_, IntHasher> =
-            streams.iter().map(|stream| stream.user_id).collect();
+/// Notify channels about streams that just went live
+async fn process_new_streams(
+    ctx: &Context,
+    online_streams: &mut OnlineStreams,
+    mut streams: Vec<TwitchStream>,
+) {
+    // Filter streams whether its already known they're live
+    streams.retain(|stream| !online_streams.contains_key(&stream.user_id));
+
+    // Nothing to do if streams is empty
+    // (i.e. there was no change or streamers went offline)
+    if streams.is_empty() {
+        return;
+    }
 
-        // If there was no activity change since last time, don't do anything
-        if now_online == online_streams {
-            continue;
+    let ids: Vec<_> = streams.iter().map(|s| s.user_id).collect();
+
+    let users: HashMap<_, _, IntHasher> = match ctx.client().get_twitch_users(&ids).await {
+        Ok(users) => users
+            .into_iter()
+            .map(|u| (u.user_id, TwitchUserCompact::from(u)))
+            .collect(),
+        Err(err) => {
+            warn!(?err, "Failed to retrieve twitch users");
+
+            return;
         }
+    };
+
+    // Generate random width and height to avoid discord caching the thumbnail url
+    let (width, height) = {
+        let mut rng = rand::thread_rng();
 
-        // Filter streams whether its already known they're live
-        streams.retain(|stream| !online_streams.contains(&stream.user_id));
+        let width: u32 = rng.gen_range(350..=370);
+        let height: u32 = rng.gen_range(175..=185);
 
-        // Nothing to do if streams is empty
-        // (i.e. the change was that streamers went offline)
-        if streams.is_empty() {
-            online_streams = now_online;
+        (width, height)
+    };
 
+    // Process each stream by notifying all corresponding channels
+    for mut stream in streams {
+        let Some(user) = users.get(&stream.user_id) else {
             continue;
+        };
+
+        // Adjust streams' thumbnail url
+        let url_len = stream.thumbnail_url.len();
+        stream.thumbnail_url.truncate(url_len - 20); // cut off "{width}x{height}.jpg"
+        let _ = write!(stream.thumbnail_url, "{width}x{height}.jpg");
+
+        let notifs = notify_online(ctx, &stream, user).await;
+        let live_stream = LiveStream::new(stream, user, notifs);
+        online_streams.insert(live_stream.user_id, live_stream);
+    }
+}
+
+/// Send a notification to all channels that track the stream and whose
+/// settings match the stream.
+async fn notify_online(
+    ctx: &Context,
+    stream: &TwitchStream,
+    user: &TwitchUserCompact,
+) -> Vec<SentNotif> {
+    let Some(channels) = ctx.tracked_channels_for(stream.user_id) else {
+        return Vec::new();
+    };
+
+    let settings_res = ctx.twitch().notif_settings(stream.user_id).await;
+
+    let settings: HashMap<_, _, IntHasher> = match settings_res {
+        Ok(settings) => settings
+            .into_iter()
+            .map(|settings| (settings.channel_id as u64, settings))
+            .collect(),
+        Err(err) => {
+            warn!(?err, "Failed to get twitch notification settings");
+
+            HashMap::default()
         }
+    };
 
-        let ids: Vec<_> = streams.iter().map(|s| s.user_id).collect();
+    let url = format!("{TWITCH_BASE}{}", user.display_name);
 
-        let users: HashMap<_, _, IntHasher> = match ctx.client().get_twitch_users(&ids).await {
-            Ok(users) => users
-                .into_iter()
-                .map(|u| (u.user_id, TwitchUserCompact::from(u)))
-                .collect(),
-            Err(err) => {
-                warn!(?err, "Failed to retrieve twitch users");
+    let embed = EmbedBuilder::new()
+        .author(AuthorBuilder::new("Now live on twitch:"))
+        .description(stream.title.as_ref())
+        .image(&stream.thumbnail_url)
+        .thumbnail(user.image_url.as_ref())
+        .title(stream.username.as_ref())
+        .url(&url)
+        .build();
 
-                continue;
-            }
-        };
+    let mut notifs = Vec::with_capacity(channels.len());
 
-        // Generate random width and height to avoid discord caching the thumbnail url
-        let (width, height) = {
-            let mut rng = rand::thread_rng();
+    for channel in channels {
+        let settings = settings.get(&channel.get());
 
-            let width: u32 = rng.gen_range(350..=370);
-            let height: u32 = rng.gen_range(175..=185);
+        if settings.is_some_and(|settings| !matches_keywords(&settings.keywords, stream)) {
+            continue;
+        }
 
-            (width, height)
-        };
+        let content = settings.and_then(|settings| notif_content(settings, stream, &url));
+        let role = settings.and_then(|settings| settings.role_id);
+        let role = role.map(|role| Id::new(role as u64));
 
-        // Process each stream by notifying all corresponding channels
-        for mut stream in streams {
-            let Some(channels) = ctx.tracked_channels_for(stream.user_id) else {
-                continue;
-            };
+        if let Some(msg) = send_notif(ctx, &embed, content.as_deref(), role, channel).await {
+            notifs.push(SentNotif {
+                channel,
+                msg,
+                edit_offline: settings.map_or(true, |settings| settings.edit_offline),
+            });
+        }
+    }
 
-            // Adjust streams' thumbnail url
-            let url_len = stream.thumbnail_url.len();
-            stream.thumbnail_url.truncate(url_len - 20); // cut off "{width}x{height}.jpg"
-            let _ = write!(stream.thumbnail_url, "{width}x{height}.jpg");
+    notifs
+}
 
-            let user = &users[&stream.user_id];
+/// Edit previously sent notifications to show the stream's duration and VOD.
+async fn notify_offline(ctx: &Context, user_id: u64, stream: LiveStream) {
+    if stream.notifs.iter().all(|notif| !notif.edit_offline) {
+        return;
+    }
 
-            let embed = EmbedBuilder::new()
-                .author(AuthorBuilder::new("Now live on twitch:"))
-                .description(stream.title.as_ref())
-                .image(&stream.thumbnail_url)
-                .thumbnail(user.image_url.as_ref())
-                .title(stream.username.as_ref())
-                .url(format!("{TWITCH_BASE}{}", user.display_name));
+    let duration = OffsetDateTime::now_utc() - stream.started_at;
 
-            let mut channels = channels.into_iter();
-            let last = channels.next_back();
+    let mut description = format!(
+        "{title}\n\nStreamed for **{hours}h {minutes}m**",
+        title = stream.title,
+        hours = duration.whole_hours(),
+        minutes = duration.whole_minutes() % 60,
+    );
 
-            for channel in channels {
-                send_notif(&ctx, embed.clone(), channel).await;
+    match ctx.client().get_last_twitch_vod(user_id).await {
+        Ok(Some(vod)) => {
+            // Only consider the VOD if it belongs to this stream
+            if (vod.created_at - stream.started_at).abs() < time::Duration::minutes(10) {
+                let _ = write!(description, "\n[Watch the VOD]({})", vod.url);
             }
+        }
+        Ok(None) => {}
+        Err(err) => warn!(?err, "Failed to get last twitch vod"),
+    }
 
-            // doing last one separately so we don't clone embed
-            if let Some(channel) = last {
-                send_notif(&ctx, embed, channel).await;
+    let embed = EmbedBuilder::new()
+        .author(AuthorBuilder::new("Was live on twitch:"))
+        .description(description)
+        .thumbnail(stream.image_url.as_ref())
+        .title(stream.username.as_ref())
+        .url(format!("{TWITCH_BASE}{}", stream.login))
+        .build();
+
+    for notif in stream.notifs.iter().filter(|notif| notif.edit_offline) {
+        let update_res = ctx
+            .http
+            .update_message(notif.channel, notif.msg)
+            .embeds(Some(slice::from_ref(&embed)));
+
+        let update_fut = match update_res {
+            Ok(update_fut) => update_fut,
+            Err(err) => {
+                warn!(?err, "Invalid embed for offline twitch notif");
+
+                return;
             }
+        };
+
+        if let Err(err) = update_fut.await {
+            warn!(channel = %notif.channel, ?err, "Failed to edit twitch notif");
         }
+    }
+}
 
-        online_streams = now_online;
+/// Whether the stream's title or game contain one of the lowercase keywords.
+/// No keywords match everything.
+fn matches_keywords(keywords: &[String], stream: &TwitchStream) -> bool {
+    if keywords.is_empty() {
+        return true;
     }
+
+    let title = stream.title.to_lowercase();
+    let game = stream.game_name.to_lowercase();
+
+    keywords
+        .iter()
+        .any(|keyword| title.contains(keyword.as_str()) || game.contains(keyword.as_str()))
 }
 
-async fn send_notif(ctx: &Context, embed: EmbedBuilder, channel: Id<ChannelMarker>) {
-    let embed = embed.build();
-
-    match ctx.http.create_message(channel).embeds(&[embed]) {
-        Ok(msg_fut) => {
-            if let Err(err) = msg_fut.await {
-                if let ErrorType::Response { error, .. } = err.kind() {
-                    match error {
-                        ApiError::General(GeneralApiError {
-                            code: UNKNOWN_CHANNEL,
-                            ..
-                        }) => {
-                            if let Err(err) = ctx.twitch().untrack_all(channel).await {
-                                warn!(
-                                    %channel,
-                                    ?err,
-                                    "Failed to remove stream tracks from unknown channel"
-                                );
-                            } else {
-                                debug!("Removed twitch tracking of unknown channel {channel}");
-                            }
+/// Role mention and custom message of the notification
+fn notif_content(settings: &DbTrackedStream, stream: &TwitchStream, url: &str) -> Option<String> {
+    let mut content = String::new();
+
+    if let Some(role) = settings.role_id {
+        let _ = write!(content, "<@&{role}>");
+    }
+
+    if let Some(ref message) = settings.message {
+        if !content.is_empty() {
+            content.push(' ');
+        }
+
+        let message = message
+            .replace("{name}", &stream.username)
+            .replace("{title}", &stream.title)
+            .replace("{game}", &stream.game_name)
+            .replace("{url}", url);
+
+        content.push_str(&message);
+    }
+
+    (!content.is_empty()).then_some(content)
+}
+
+async fn send_notif(
+    ctx: &Context,
+    embed: &Embed,
+    content: Option<&str>,
+    role: Option<Id<RoleMarker>>,
+    channel: Id<ChannelMarker>,
+) -> Option<Id<MessageMarker>> {
+    // Only ping the configured role, never anything from the stream's title
+    let mentions = AllowedMentions {
+        roles: role.into_iter().collect(),
+        ..Default::default()
+    };
+
+    let req = ctx
+        .http
+        .create_message(channel)
+        .allowed_mentions(Some(&mentions))
+        .embeds(slice::from_ref(embed));
+
+    let req = match (req, content) {
+        (Ok(req), Some(content)) => req.content(content),
+        (req, _) => req,
+    };
+
+    let msg_fut = match req {
+        Ok(msg_fut) => msg_fut,
+        Err(err) => {
+            warn!(?err, "Invalid twitch notif");
+
+            return None;
+        }
+    };
+
+    match msg_fut.await {
+        Ok(response) => match response.model().await {
+            Ok(msg) => return Some(msg.id),
+            Err(err) => warn!(?err, "Failed to deserialize twitch notif"),
+        },
+        Err(err) => {
+            if let ErrorType::Response { error, .. } = err.kind() {
+                match error {
+                    ApiError::General(GeneralApiError {
+                        code: UNKNOWN_CHANNEL,
+                        ..
+                    }) => {
+                        if let Err(err) = ctx.twitch().untrack_all(channel).await {
+                            warn!(
+                                %channel,
+                                ?err,
+                                "Failed to remove stream tracks from unknown channel"
+                            );
+                        } else {
+                            debug!("Removed twitch tracking of unknown channel {channel}");
                         }
-                        err => warn!(
-                            %channel,
-                            ?err,
-                            "Error from API while sending twitch notif"
-                        ),
                     }
-                } else {
-                    warn!(
+                    err => warn!(
                         %channel,
                         ?err,
-                        "Error while sending twitch notif"
-                    );
+                        "Error from API while sending twitch notif"
+                    ),
                 }
+            } else {
+                warn!(
+                    %channel,
+                    ?err,
+                    "Error while sending twitch notif"
+                );
             }
         }
-        Err(err) => {
-            warn!(?err, "Invalid embed for twitch notif");
-        }
     }
+
+    None
 }
 
+type OnlineStreams = HashMap<u64, LiveStream, IntHasher>;
+
 struct TwitchUserCompact {
     display_name: Box<str>,
     image_url: Box<str>,
@@ -190,3 +475,34 @@ impl From<TwitchUser> for TwitchUserCompact {
         }
     }
 }
+
+/// A stream that was live during the last check
+struct LiveStream {
+    user_id: u64,
+    username: Box<str>,
+    login: Box<str>,
+    title: Box<str>,
+    image_url: Box<str>,
+    started_at: OffsetDateTime,
+    notifs: Vec<SentNotif>,
+}
+
+impl LiveStream {
+    fn new(stream: TwitchStream, user: &TwitchUserCompact, notifs: Vec<SentNotif>) -> Self {
+        Self {
+            user_id: stream.user_id,
+            username: stream.username,
+            login: stream.login,
+            title: stream.title,
+            image_url: user.image_url.clone(),
+            started_at: stream.started_at,
+            notifs,
+        }
+    }
+}
+
+struct SentNotif {
+    channel: Id<ChannelMarker>,
+    msg: Id<MessageMarker>,
+    edit_offline: bool,
+}
@@ -0,0 +1,25 @@
+use eyre::Result;
+
+use super::{interaction::InteractionCommand, Authored, InteractionCommandExt};
+use crate::core::{commands::checks::check_authority, locale::ErrorKey, Context};
+
+/// For subcommands that require authority status while their parent command
+/// does not.
+///
+/// Returns `false` if the author is not an authority in which case they have
+/// already been notified. The command must have been deferred beforehand.
+pub async fn require_authority(ctx: &Context, command: &InteractionCommand) -> Result<bool> {
+    match check_authority(ctx, command.user_id()?, command.guild_id).await {
+        Ok(None) => Ok(true),
+        Ok(Some(content)) => {
+            command.error(ctx, content).await?;
+
+            Ok(false)
+        }
+        Err(err) => {
+            let _ = command.error_localized(ctx, ErrorKey::General).await;
+
+            Err(err.wrap_err("Failed to check authority status"))
+        }
+    }
+}
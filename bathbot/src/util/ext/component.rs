@@ -1,21 +1,26 @@
 use std::{borrow::Cow, future::IntoFuture, slice};
 
+use bathbot_psql::model::configs::Language;
 use bathbot_util::{modal::ModalBuilder, MessageBuilder};
-use twilight_http::response::{marker::EmptyBody, ResponseFuture};
+use futures::{future, TryFutureExt};
+use twilight_http::response::ResponseFuture;
 use twilight_model::{
     channel::Message,
     guild::Permissions,
     http::interaction::{InteractionResponse, InteractionResponseData, InteractionResponseType},
 };
 
-use crate::{core::Context, util::interaction::InteractionComponent};
+use crate::{
+    core::{locale, Context},
+    util::{interaction::InteractionComponent, AckFuture, Authored},
+};
 
 pub trait ComponentExt {
     /// Ackowledge the component and respond immediatly by updating the message.
-    fn callback(&self, ctx: &Context, builder: MessageBuilder<'_>) -> ResponseFuture<EmptyBody>;
+    fn callback(&self, ctx: &Context, builder: MessageBuilder<'_>) -> AckFuture;
 
     /// Ackownledge the component but don't respond yet.
-    fn defer(&self, ctx: &Context) -> ResponseFuture<EmptyBody>;
+    fn defer(&self, ctx: &Context) -> AckFuture;
 
     /// After having already ackowledged the component either via
     /// [`ComponentExt::callback`] or [`ComponentExt::defer`],
@@ -23,12 +28,16 @@ pub trait ComponentExt {
     fn update(&self, ctx: &Context, builder: MessageBuilder<'_>) -> ResponseFuture<Message>;
 
     /// Acknowledge a component by responding with a modal.
-    fn modal(&self, ctx: &Context, modal: ModalBuilder) -> ResponseFuture<EmptyBody>;
+    fn modal(&self, ctx: &Context, modal: ModalBuilder) -> AckFuture;
 }
 
 impl ComponentExt for InteractionComponent {
     #[inline]
-    fn callback(&self, ctx: &Context, builder: MessageBuilder<'_>) -> ResponseFuture<EmptyBody> {
+    fn callback(&self, ctx: &Context, builder: MessageBuilder<'_>) -> AckFuture {
+        if self.acked {
+            return Box::pin(self.update(ctx, builder).map_ok(|_| ()));
+        }
+
         let attachments = builder
             .attachment
             .filter(|_| {
@@ -51,21 +60,31 @@ impl ComponentExt for InteractionComponent {
             data: Some(data),
         };
 
-        ctx.interaction()
+        let fut = ctx
+            .interaction()
             .create_response(self.id, &self.token, &response)
-            .into_future()
+            .into_future();
+
+        Box::pin(fut.map_ok(|_| ()))
     }
 
     #[inline]
-    fn defer(&self, ctx: &Context) -> ResponseFuture<EmptyBody> {
+    fn defer(&self, ctx: &Context) -> AckFuture {
+        if self.acked {
+            return Box::pin(future::ok(()));
+        }
+
         let response = InteractionResponse {
             kind: InteractionResponseType::DeferredUpdateMessage,
             data: None,
         };
 
-        ctx.interaction()
+        let fut = ctx
+            .interaction()
             .create_response(self.id, &self.token, &response)
-            .into_future()
+            .into_future();
+
+        Box::pin(fut.map_ok(|_| ()))
     }
 
     #[inline]
@@ -103,14 +122,34 @@ impl ComponentExt for InteractionComponent {
     }
 
     #[inline]
-    fn modal(&self, ctx: &Context, modal: ModalBuilder) -> ResponseFuture<EmptyBody> {
+    fn modal(&self, ctx: &Context, modal: ModalBuilder) -> AckFuture {
+        // Modals must be the first response so they're not available after
+        // the server deferred the component
+        if self.acked {
+            warn!("Cannot respond with modal to acknowledged component");
+
+            return Box::pin(future::ok(()));
+        }
+
         let response = InteractionResponse {
             kind: InteractionResponseType::Modal,
             data: Some(modal.build()),
         };
 
-        ctx.interaction()
+        let fut = ctx
+            .interaction()
             .create_response(self.id, &self.token, &response)
-            .into_future()
+            .into_future();
+
+        Box::pin(fut.map_ok(|_| ()))
+    }
+}
+
+impl InteractionComponent {
+    /// Determine the language to respond in, see [`locale::language`].
+    pub async fn language(&self, ctx: &Context) -> Language {
+        let user = self.user_id().ok();
+
+        locale::language(ctx, user, self.guild_id, self.locale.as_deref()).await
     }
 }
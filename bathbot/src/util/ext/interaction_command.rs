@@ -1,7 +1,10 @@
 use std::{borrow::Cow, future::IntoFuture, mem, slice};
 
+use bathbot_psql::model::configs::Language;
 use bathbot_util::{EmbedBuilder, MessageBuilder};
-use twilight_http::response::{marker::EmptyBody, ResponseFuture};
+use eyre::{Result, WrapErr};
+use futures::{future, TryFutureExt};
+use twilight_http::response::ResponseFuture;
 use twilight_interactions::command::CommandInputData;
 use twilight_model::{
     application::{
@@ -13,7 +16,13 @@ use twilight_model::{
     http::interaction::{InteractionResponse, InteractionResponseData, InteractionResponseType},
 };
 
-use crate::{core::Context, util::interaction::InteractionCommand};
+use crate::{
+    core::{
+        locale::{self, ErrorKey},
+        Context,
+    },
+    util::{interaction::InteractionCommand, AckFuture, Authored},
+};
 
 pub trait InteractionCommandExt {
     /// Extract input data containing options and resolved values
@@ -26,17 +35,12 @@ pub trait InteractionCommandExt {
     fn group_sub(&self) -> Option<(Cow<'static, str>, Cow<'static, str>)>;
 
     /// Ackowledge the command and respond immediatly.
-    fn callback(
-        &self,
-        ctx: &Context,
-        builder: MessageBuilder<'_>,
-        ephemeral: bool,
-    ) -> ResponseFuture<EmptyBody>;
+    fn callback(&self, ctx: &Context, builder: MessageBuilder<'_>, ephemeral: bool) -> AckFuture;
 
     /// Ackownledge the command but don't respond yet.
     ///
     /// Must use [`InteractionCommandExt::update`] afterwards!
-    fn defer(&self, ctx: &Context, ephemeral: bool) -> ResponseFuture<EmptyBody>;
+    fn defer(&self, ctx: &Context, ephemeral: bool) -> AckFuture;
 
     /// After having already ackowledged the command either via
     /// [`InteractionCommandExt::callback`] or [`InteractionCommandExt::defer`],
@@ -56,11 +60,7 @@ pub trait InteractionCommandExt {
     /// Respond to a command with some content in a red embed.
     ///
     /// Be sure the command was **not** deferred beforehand.
-    fn error_callback(
-        &self,
-        ctx: &Context,
-        content: impl Into<String>,
-    ) -> ResponseFuture<EmptyBody> {
+    fn error_callback(&self, ctx: &Context, content: impl Into<String>) -> AckFuture {
         let embed = EmbedBuilder::new().description(content).color_red();
         let builder = MessageBuilder::new().embed(embed);
 
@@ -68,11 +68,7 @@ pub trait InteractionCommandExt {
     }
 
     /// Callback to an autocomplete action.
-    fn autocomplete(
-        &self,
-        ctx: &Context,
-        choices: Vec<CommandOptionChoice>,
-    ) -> ResponseFuture<EmptyBody>;
+    fn autocomplete(&self, ctx: &Context, choices: Vec<CommandOptionChoice>) -> AckFuture;
 }
 
 impl InteractionCommandExt for InteractionCommand {
@@ -113,12 +109,11 @@ impl InteractionCommandExt for InteractionCommand {
     }
 
     #[inline]
-    fn callback(
-        &self,
-        ctx: &Context,
-        builder: MessageBuilder<'_>,
-        ephemeral: bool,
-    ) -> ResponseFuture<EmptyBody> {
+    fn callback(&self, ctx: &Context, builder: MessageBuilder<'_>, ephemeral: bool) -> AckFuture {
+        if self.acked {
+            return Box::pin(self.update(ctx, builder).map_ok(|_| ()));
+        }
+
         let attachments = builder
             .attachment
             .filter(|_| {
@@ -142,13 +137,20 @@ impl InteractionCommandExt for InteractionCommand {
             data: Some(data),
         };
 
-        ctx.interaction()
+        let fut = ctx
+            .interaction()
             .create_response(self.id, &self.token, &response)
-            .into_future()
+            .into_future();
+
+        Box::pin(fut.map_ok(|_| ()))
     }
 
     #[inline]
-    fn defer(&self, ctx: &Context, ephemeral: bool) -> ResponseFuture<EmptyBody> {
+    fn defer(&self, ctx: &Context, ephemeral: bool) -> AckFuture {
+        if self.acked {
+            return Box::pin(future::ok(()));
+        }
+
         let data = InteractionResponseData {
             flags: ephemeral.then_some(MessageFlags::EPHEMERAL),
             ..Default::default()
@@ -159,9 +161,12 @@ impl InteractionCommandExt for InteractionCommand {
             data: Some(data),
         };
 
-        ctx.interaction()
+        let fut = ctx
+            .interaction()
             .create_response(self.id, &self.token, &response)
-            .into_future()
+            .into_future();
+
+        Box::pin(fut.map_ok(|_| ()))
     }
 
     #[inline]
@@ -203,11 +208,12 @@ impl InteractionCommandExt for InteractionCommand {
     }
 
     #[inline]
-    fn autocomplete(
-        &self,
-        ctx: &Context,
-        choices: Vec<CommandOptionChoice>,
-    ) -> ResponseFuture<EmptyBody> {
+    fn autocomplete(&self, ctx: &Context, choices: Vec<CommandOptionChoice>) -> AckFuture {
+        // The server already responded with empty choices
+        if self.acked {
+            return Box::pin(future::ok(()));
+        }
+
         let data = InteractionResponseData {
             choices: Some(choices),
             ..Default::default()
@@ -218,8 +224,46 @@ impl InteractionCommandExt for InteractionCommand {
             data: Some(data),
         };
 
-        ctx.interaction()
+        let fut = ctx
+            .interaction()
             .create_response(self.id, &self.token, &response)
-            .into_future()
+            .into_future();
+
+        Box::pin(fut.map_ok(|_| ()))
+    }
+}
+
+impl InteractionCommand {
+    /// Determine the language to respond in, see [`locale::language`].
+    pub async fn language(&self, ctx: &Context) -> Language {
+        let user = self.user_id().ok();
+
+        locale::language(ctx, user, self.guild_id, self.locale.as_deref()).await
+    }
+
+    /// Update a command to the error message of the key in a red embed,
+    /// translated into the author's language.
+    ///
+    /// Be sure the command was deferred beforehand.
+    pub async fn error_localized(&self, ctx: &Context, key: ErrorKey) -> Result<()> {
+        let language = self.language(ctx).await;
+
+        self.error(ctx, key.text(language))
+            .await
+            .map(|_| ())
+            .wrap_err("Failed to respond with error")
+    }
+
+    /// Respond to a command with the error message of the key in a red embed,
+    /// translated into the author's language.
+    ///
+    /// Be sure the command was **not** deferred beforehand.
+    pub async fn error_callback_localized(&self, ctx: &Context, key: ErrorKey) -> Result<()> {
+        let language = self.language(ctx).await;
+
+        self.error_callback(ctx, key.text(language))
+            .await
+            .map(|_| ())
+            .wrap_err("Failed to callback with error")
     }
 }
@@ -1,3 +1,5 @@
+use futures::future::BoxFuture;
+
 pub use self::{
     authored::Authored, channel::ChannelExt, component::ComponentExt,
     interaction_command::InteractionCommandExt, map::BeatmapExt, message::MessageExt, modal::*,
@@ -12,3 +14,9 @@ mod map;
 mod message;
 mod modal;
 mod score;
+
+/// Acknowledging or responding to an interaction.
+///
+/// Interactions that were already acknowledged by the server's interactions
+/// endpoint are responded to by updating the deferred response instead.
+pub type AckFuture = BoxFuture<'static, Result<(), twilight_http::Error>>;
@@ -1,21 +1,25 @@
 use std::{future::IntoFuture, slice};
 
 use bathbot_util::MessageBuilder;
-use twilight_http::response::{marker::EmptyBody, ResponseFuture};
+use futures::{future, TryFutureExt};
+use twilight_http::response::ResponseFuture;
 use twilight_model::{
     channel::Message,
     guild::Permissions,
     http::interaction::{InteractionResponse, InteractionResponseData, InteractionResponseType},
 };
 
-use crate::{core::Context, util::interaction::InteractionModal};
+use crate::{
+    core::Context,
+    util::{interaction::InteractionModal, AckFuture},
+};
 
 pub trait ModalExt {
     /// Ackowledge the modal and respond immediatly by updating the message.
-    fn callback(&self, ctx: &Context, builder: MessageBuilder<'_>) -> ResponseFuture<EmptyBody>;
+    fn callback(&self, ctx: &Context, builder: MessageBuilder<'_>) -> AckFuture;
 
     /// Ackownledge the modal but don't respond yet.
-    fn defer(&self, ctx: &Context) -> ResponseFuture<EmptyBody>;
+    fn defer(&self, ctx: &Context) -> AckFuture;
 
     /// After having already ackowledged the modal either via
     /// [`ModalExt::callback`] or [`ModalExt::defer`],
@@ -25,7 +29,11 @@ pub trait ModalExt {
 
 impl ModalExt for InteractionModal {
     #[inline]
-    fn callback(&self, ctx: &Context, builder: MessageBuilder<'_>) -> ResponseFuture<EmptyBody> {
+    fn callback(&self, ctx: &Context, builder: MessageBuilder<'_>) -> AckFuture {
+        if self.acked {
+            return Box::pin(self.update(ctx, builder).map_ok(|_| ()));
+        }
+
         let attachments = builder
             .attachment
             .filter(|_| {
@@ -47,21 +55,31 @@ impl ModalExt for InteractionModal {
             data: Some(data),
         };
 
-        ctx.interaction()
+        let fut = ctx
+            .interaction()
             .create_response(self.id, &self.token, &response)
-            .into_future()
+            .into_future();
+
+        Box::pin(fut.map_ok(|_| ()))
     }
 
     #[inline]
-    fn defer(&self, ctx: &Context) -> ResponseFuture<EmptyBody> {
+    fn defer(&self, ctx: &Context) -> AckFuture {
+        if self.acked {
+            return Box::pin(future::ok(()));
+        }
+
         let response = InteractionResponse {
             kind: InteractionResponseType::DeferredUpdateMessage,
             data: None,
         };
 
-        ctx.interaction()
+        let fut = ctx
+            .interaction()
             .create_response(self.id, &self.token, &response)
-            .into_future()
+            .into_future();
+
+        Box::pin(fut.map_ok(|_| ()))
     }
 
     #[inline]
@@ -18,10 +18,14 @@ use super::Authored;
 #[derive(Debug)]
 pub struct InteractionCommand {
     pub permissions: Option<Permissions>,
+    /// Whether the server's interactions endpoint already responded with a
+    /// deferred response
+    pub acked: bool,
     pub channel_id: Id<ChannelMarker>,
     pub data: Box<CommandData>,
     pub guild_id: Option<Id<GuildMarker>>,
     pub id: Id<InteractionMarker>,
+    pub locale: Option<String>,
     pub member: Option<PartialMember>,
     pub token: String,
     pub user: Option<User>,
@@ -30,10 +34,14 @@ pub struct InteractionCommand {
 #[derive(Debug)]
 pub struct InteractionComponent {
     pub permissions: Option<Permissions>,
+    /// Whether the server's interactions endpoint already responded with a
+    /// deferred response
+    pub acked: bool,
     pub channel_id: Id<ChannelMarker>,
     pub data: MessageComponentInteractionData,
     pub guild_id: Option<Id<GuildMarker>>,
     pub id: Id<InteractionMarker>,
+    pub locale: Option<String>,
     pub member: Option<PartialMember>,
     pub message: Message,
     pub token: String,
@@ -43,6 +51,9 @@ pub struct InteractionComponent {
 #[derive(Debug)]
 pub struct InteractionModal {
     pub permissions: Option<Permissions>,
+    /// Whether the server's interactions endpoint already responded with a
+    /// deferred response
+    pub acked: bool,
     pub channel_id: Id<ChannelMarker>,
     pub data: ModalInteractionData,
     pub guild_id: Option<Id<GuildMarker>>,
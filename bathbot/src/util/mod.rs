@@ -1,4 +1,5 @@
 pub use self::{
+    authority::require_authority,
     check_permissions::CheckPermissions,
     emote::{CustomEmote, Emote},
     ext::*,
@@ -9,6 +10,7 @@ pub mod interaction;
 pub mod osu;
 pub mod query;
 
+mod authority;
 mod check_permissions;
 mod emote;
 mod ext;
@@ -748,3 +748,109 @@ impl PersonalBestIndex {
         }
     }
 }
+
+/// Version written into exported `collection.db` files
+const COLLECTION_DB_VERSION: i32 = 20231112;
+
+/// Serialize a single collection in the format of osu!'s `collection.db`.
+pub fn collection_db<'c>(name: &str, checksums: impl ExactSizeIterator<Item = &'c str>) -> Vec<u8> {
+    fn write_string(bytes: &mut Vec<u8>, s: &str) {
+        bytes.push(0x0b);
+
+        // ULEB128 encoded length
+        let mut len = s.len();
+
+        loop {
+            let byte = (len & 0x7f) as u8;
+            len >>= 7;
+
+            if len == 0 {
+                bytes.push(byte);
+
+                break;
+            }
+
+            bytes.push(byte | 0x80);
+        }
+
+        bytes.extend_from_slice(s.as_bytes());
+    }
+
+    let mut bytes = Vec::with_capacity(16 + name.len() + checksums.len() * 34);
+
+    bytes.extend_from_slice(&COLLECTION_DB_VERSION.to_le_bytes());
+    bytes.extend_from_slice(&1_i32.to_le_bytes());
+    write_string(&mut bytes, name);
+    bytes.extend_from_slice(&(checksums.len() as i32).to_le_bytes());
+
+    for checksum in checksums {
+        write_string(&mut bytes, checksum);
+    }
+
+    bytes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Reader<'b> {
+        bytes: &'b [u8],
+    }
+
+    impl<'b> Reader<'b> {
+        fn take(&mut self, n: usize) -> &'b [u8] {
+            let (front, back) = self.bytes.split_at(n);
+            self.bytes = back;
+
+            front
+        }
+
+        fn read_i32(&mut self) -> i32 {
+            i32::from_le_bytes(self.take(4).try_into().unwrap())
+        }
+
+        fn read_string(&mut self) -> &'b str {
+            assert_eq!(self.take(1), [0x0b]);
+
+            let mut len = 0;
+            let mut shift = 0;
+
+            loop {
+                let byte = self.take(1)[0];
+                len |= ((byte & 0x7f) as usize) << shift;
+                shift += 7;
+
+                if byte & 0x80 == 0 {
+                    break;
+                }
+            }
+
+            std::str::from_utf8(self.take(len)).unwrap()
+        }
+    }
+
+    #[test]
+    fn collection_db_roundtrip() {
+        // Long enough to require a multi-byte ULEB128 length
+        let name = "a".repeat(200);
+        let checksums = [
+            "a5b8ef4a4d5bb9de3aa7dbc6ec9f9e2e",
+            "0ed2a0a4fe0bd1efb9e79a2a6ee46d3c",
+        ];
+
+        let bytes = collection_db(&name, checksums.iter().copied());
+        let mut reader = Reader { bytes: &bytes };
+
+        assert_eq!(reader.read_i32(), COLLECTION_DB_VERSION);
+        assert_eq!(reader.read_i32(), 1);
+        assert_eq!(reader.read_string(), name);
+        assert_eq!(reader.read_i32(), checksums.len() as i32);
+
+        for checksum in checksums {
+            assert_eq!(reader.read_string(), checksum);
+        }
+
+        assert!(reader.bytes.is_empty());
+    }
+}